@@ -6,10 +6,10 @@
 use bacnet_rs::{
     network::Npdu,
     object::{Device, Segmentation},
-    service::{IAmRequest, UnconfirmedServiceChoice, WhoIsRequest},
+    service::{respond_to_whois, IAmRequest, UnconfirmedServiceChoice, WhoIsRequest},
 };
 use std::{
-    net::{SocketAddr, UdpSocket},
+    net::UdpSocket,
     sync::atomic::{AtomicBool, Ordering},
     sync::Arc,
     time::Duration,
@@ -59,6 +59,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     })
     .expect("Error setting Ctrl-C handler");
 
+    // The I-Am we answer matching Who-Is requests with
+    let iam = IAmRequest::for_device(
+        device_id,
+        1476, // Max APDU length
+        Segmentation::Both,
+        device.vendor_identifier,
+    );
+
     let mut recv_buffer = [0u8; 1500];
     let mut response_count = 0;
 
@@ -67,23 +75,19 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             Ok((len, source)) => {
                 // Process received message
                 if let Some(whois) = process_whois(&recv_buffer[..len]) {
-                    // Check if this Who-Is is for us
-                    if whois.matches(device_id) {
+                    // Only answer if this Who-Is is for us
+                    if let Some(apdu) = respond_to_whois(&whois, &iam) {
                         println!("Received Who-Is from {} (matches our device)", source);
 
                         // Send I-Am response
-                        if let Ok(response) = create_iam_response(&device, source) {
-                            match socket.send_to(&response, source) {
-                                Ok(_) => {
-                                    response_count += 1;
-                                    println!(
-                                        "Sent I-Am response #{} to {}",
-                                        response_count, source
-                                    );
-                                }
-                                Err(e) => {
-                                    eprintln!("Failed to send I-Am: {}", e);
-                                }
+                        let response = create_iam_message(&apdu);
+                        match socket.send_to(&response, source) {
+                            Ok(_) => {
+                                response_count += 1;
+                                println!("Sent I-Am response #{} to {}", response_count, source);
+                            }
+                            Err(e) => {
+                                eprintln!("Failed to send I-Am: {}", e);
                             }
                         }
                     } else {
@@ -157,43 +161,24 @@ fn process_whois(data: &[u8]) -> Option<WhoIsRequest> {
 
     // Decode Who-Is request
     if apdu.len() > 2 {
-        WhoIsRequest::decode(&apdu[2..]).ok()
+        WhoIsRequest::decode_lenient(&apdu[2..]).ok()
     } else {
         // Empty Who-Is (broadcast to all)
         Some(WhoIsRequest::new())
     }
 }
 
-/// Create an I-Am response message
-fn create_iam_response(
-    device: &Device,
-    _destination: SocketAddr,
-) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-    // Create I-Am request
-    let iam = IAmRequest::new(
-        device.identifier,
-        1476, // Max APDU length
-        Segmentation::Both,
-        device.vendor_identifier,
-    );
-
-    let mut iam_buffer = Vec::new();
-    iam.encode(&mut iam_buffer)?;
-
+/// Wrap an I-Am APDU in NPDU and BVLC headers
+fn create_iam_message(apdu: &[u8]) -> Vec<u8> {
     // Create NPDU
     let mut npdu = Npdu::new();
     npdu.control.priority = 0; // Normal priority
 
     let npdu_buffer = npdu.encode();
 
-    // Create unconfirmed service request APDU
-    let mut apdu = vec![0x10]; // Unconfirmed-Request PDU type
-    apdu.push(UnconfirmedServiceChoice::IAm as u8);
-    apdu.extend_from_slice(&iam_buffer);
-
     // Combine NPDU and APDU
     let mut message = npdu_buffer;
-    message.extend_from_slice(&apdu);
+    message.extend_from_slice(apdu);
 
     // Wrap in BVLC header for BACnet/IP
     let mut bvlc_message = vec![
@@ -208,5 +193,5 @@ fn create_iam_response(
     bvlc_message[2] = (total_len >> 8) as u8;
     bvlc_message[3] = (total_len & 0xFF) as u8;
 
-    Ok(bvlc_message)
+    bvlc_message
 }
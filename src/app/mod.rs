@@ -47,14 +47,21 @@ use std::{fmt, time::Duration};
 use core::fmt;
 
 #[cfg(not(feature = "std"))]
-use alloc::{string::String, vec::Vec};
+use alloc::{collections::BTreeMap, format, string::String, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
 
 #[cfg(not(feature = "std"))]
 use core::time::Duration;
 
-use crate::encoding::{decode_enumerated, encode_enumerated};
+use crate::encoding::{decode_enumerated, write_enumerated};
+use crate::network::{NetworkError, NetworkLayerMessage, Npdu};
 use crate::object::Segmentation;
-use crate::service::{AbortReason, ConfirmedServiceChoice, RejectReason, UnconfirmedServiceChoice};
+use crate::service::{
+    AbortReason, BacnetDateTime, ConfirmedServiceChoice, ErrorClass, ErrorCode, RejectReason,
+    TimeSynchronizationRequest, UnconfirmedServiceChoice, UtcTimeSynchronizationRequest,
+};
 
 /// Result type for application layer operations
 #[cfg(feature = "std")]
@@ -80,6 +87,19 @@ pub enum ApplicationError {
     Timeout,
     /// Maximum APDU length exceeded
     MaxApduLengthExceeded,
+    /// Request failed with the given error class and code
+    ///
+    /// Service processors return this to answer a confirmed request with an
+    /// Error PDU carrying these values.
+    ErrorResponse(ErrorClass, ErrorCode),
+    /// Invalid NPDU or network layer message
+    Network(NetworkError),
+}
+
+impl From<NetworkError> for ApplicationError {
+    fn from(error: NetworkError) -> Self {
+        ApplicationError::Network(error)
+    }
 }
 
 impl fmt::Display for ApplicationError {
@@ -92,6 +112,10 @@ impl fmt::Display for ApplicationError {
             ApplicationError::ServiceError(msg) => write!(f, "Service error: {}", msg),
             ApplicationError::Timeout => write!(f, "Application timeout"),
             ApplicationError::MaxApduLengthExceeded => write!(f, "Maximum APDU length exceeded"),
+            ApplicationError::ErrorResponse(class, code) => {
+                write!(f, "Error response: {} / {}", class, code)
+            }
+            ApplicationError::Network(error) => write!(f, "Network error: {}", error),
         }
     }
 }
@@ -163,8 +187,8 @@ pub enum Apdu {
     Error {
         invoke_id: u8,
         service_choice: ConfirmedServiceChoice,
-        error_class: u8,
-        error_code: u8,
+        error_class: ErrorClass,
+        error_code: ErrorCode,
     },
 
     /// Reject PDU
@@ -194,6 +218,55 @@ pub enum MaxSegments {
     GreaterThan64 = 7,
 }
 
+impl MaxSegments {
+    /// Get the number of segments, `None` for Unspecified and GreaterThan64
+    pub fn count(&self) -> Option<u8> {
+        match self {
+            MaxSegments::Unspecified | MaxSegments::GreaterThan64 => None,
+            MaxSegments::Two => Some(2),
+            MaxSegments::Four => Some(4),
+            MaxSegments::Eight => Some(8),
+            MaxSegments::Sixteen => Some(16),
+            MaxSegments::ThirtyTwo => Some(32),
+            MaxSegments::SixtyFour => Some(64),
+        }
+    }
+
+    /// Get the smallest variant that fits `count` segments
+    ///
+    /// A count of 0 is Unspecified and anything above 64 is GreaterThan64.
+    pub fn from_count(count: u8) -> Self {
+        match count {
+            0 => MaxSegments::Unspecified,
+            1..=2 => MaxSegments::Two,
+            3..=4 => MaxSegments::Four,
+            5..=8 => MaxSegments::Eight,
+            9..=16 => MaxSegments::Sixteen,
+            17..=32 => MaxSegments::ThirtyTwo,
+            33..=64 => MaxSegments::SixtyFour,
+            _ => MaxSegments::GreaterThan64,
+        }
+    }
+
+    /// Get the largest variant that does not exceed `count` segments
+    ///
+    /// Use this when advertising a limit, so a peer never sends more segments
+    /// than `count`. Counts of 0 and 1 are Unspecified, since no variant is
+    /// smaller than Two, and anything above 64 is GreaterThan64.
+    pub fn from_count_floor(count: u8) -> Self {
+        match count {
+            0..=1 => MaxSegments::Unspecified,
+            2..=3 => MaxSegments::Two,
+            4..=7 => MaxSegments::Four,
+            8..=15 => MaxSegments::Eight,
+            16..=31 => MaxSegments::Sixteen,
+            32..=63 => MaxSegments::ThirtyTwo,
+            64 => MaxSegments::SixtyFour,
+            _ => MaxSegments::GreaterThan64,
+        }
+    }
+}
+
 /// Maximum APDU size that can be accepted
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MaxApduSize {
@@ -251,6 +324,9 @@ pub enum TransactionState {
 
 impl Apdu {
     /// Encode APDU to bytes
+    ///
+    /// The APDU is written as given; see [`Apdu::try_encode`] for an encoder
+    /// that rejects segments without a sequence number or window size.
     pub fn encode(&self) -> Vec<u8> {
         let mut buffer = Vec::new();
 
@@ -404,8 +480,9 @@ impl Apdu {
                 buffer.push(*invoke_id);
                 // Service choice
                 buffer.push(*service_choice as u8);
-                encode_enumerated(&mut buffer, *error_class as u32);
-                encode_enumerated(&mut buffer, *error_code as u32);
+                // Writing to a Vec cannot fail
+                let _ = write_enumerated(&mut buffer, u16::from(*error_class) as u32);
+                let _ = write_enumerated(&mut buffer, u16::from(*error_code) as u32);
             }
 
             Apdu::Reject {
@@ -442,6 +519,109 @@ impl Apdu {
         buffer
     }
 
+    /// Encode APDU to bytes, checking that a segment carries its sequence
+    /// number and proposed window size
+    ///
+    /// [`Apdu::encode`] leaves both octets out when they are `None`, which
+    /// produces a malformed segment.
+    pub fn try_encode(&self) -> Result<Vec<u8>> {
+        if let Apdu::ConfirmedRequest {
+            segmented: true,
+            sequence_number,
+            proposed_window_size,
+            ..
+        }
+        | Apdu::ComplexAck {
+            segmented: true,
+            sequence_number,
+            proposed_window_size,
+            ..
+        } = self
+        {
+            if sequence_number.is_none() || proposed_window_size.is_none() {
+                return Err(ApplicationError::InvalidApdu(
+                    "Segmented APDU requires a sequence number and proposed window size"
+                        .to_string(),
+                ));
+            }
+        }
+        Ok(self.encode())
+    }
+
+    /// Encode APDU to bytes for a peer that accepts at most `max_apdu_length`
+    /// octets
+    ///
+    /// An unsegmented APDU that does not fit fails with
+    /// [`ApplicationError::MaxApduLengthExceeded`]; the message has to be sent
+    /// segmented instead.
+    pub fn encode_with_max_length(&self, max_apdu_length: usize) -> Result<Vec<u8>> {
+        let buffer = self.try_encode()?;
+        if !self.is_segmented() && buffer.len() > max_apdu_length {
+            return Err(ApplicationError::MaxApduLengthExceeded);
+        }
+        Ok(buffer)
+    }
+
+    /// Get the invoke ID of the transaction the APDU belongs to
+    ///
+    /// Every PDU type except UnconfirmedRequest carries one.
+    pub fn invoke_id(&self) -> Option<u8> {
+        match self {
+            Apdu::ConfirmedRequest { invoke_id, .. }
+            | Apdu::SimpleAck { invoke_id, .. }
+            | Apdu::ComplexAck { invoke_id, .. }
+            | Apdu::SegmentAck { invoke_id, .. }
+            | Apdu::Error { invoke_id, .. }
+            | Apdu::Reject { invoke_id, .. }
+            | Apdu::Abort { invoke_id, .. } => Some(*invoke_id),
+            Apdu::UnconfirmedRequest { .. } => None,
+        }
+    }
+
+    /// Get the service choice of the APDU as its raw number
+    ///
+    /// Confirmed and unconfirmed requests, SimpleAck, ComplexAck and Error
+    /// carry one; SegmentAck, Reject and Abort do not.
+    pub fn service_choice(&self) -> Option<u8> {
+        match self {
+            Apdu::ConfirmedRequest { service_choice, .. }
+            | Apdu::ComplexAck { service_choice, .. }
+            | Apdu::Error { service_choice, .. } => Some(*service_choice as u8),
+            Apdu::UnconfirmedRequest { service_choice, .. } => Some(*service_choice as u8),
+            Apdu::SimpleAck { service_choice, .. } => Some(*service_choice),
+            Apdu::SegmentAck { .. } | Apdu::Reject { .. } | Apdu::Abort { .. } => None,
+        }
+    }
+
+    /// Check if this APDU is sent by a server in reply to a confirmed request
+    ///
+    /// SegmentAck and Abort count only when sent by the server, since a
+    /// client sends them too while receiving a segmented response.
+    pub fn is_response(&self) -> bool {
+        match self {
+            Apdu::SimpleAck { .. }
+            | Apdu::ComplexAck { .. }
+            | Apdu::Error { .. }
+            | Apdu::Reject { .. } => true,
+            Apdu::SegmentAck { server, .. } | Apdu::Abort { server, .. } => *server,
+            Apdu::ConfirmedRequest { .. } | Apdu::UnconfirmedRequest { .. } => false,
+        }
+    }
+
+    /// Check if this APDU is one segment of a segmented message
+    pub fn is_segmented(&self) -> bool {
+        matches!(
+            self,
+            Apdu::ConfirmedRequest {
+                segmented: true,
+                ..
+            } | Apdu::ComplexAck {
+                segmented: true,
+                ..
+            }
+        )
+    }
+
     /// Decode APDU from bytes
     pub fn decode(data: &[u8]) -> Result<Self> {
         if data.is_empty() {
@@ -683,18 +863,25 @@ impl Apdu {
                     ApplicationError::InvalidApdu("Unknown confirmed service choice".to_string())
                 })?;
                 pos += 1;
-                let (error_class, offset) = decode_enumerated(&data[pos..]).map_err(|_| {
-                    ApplicationError::InvalidApdu("Invalid error class".to_string())
-                })?;
+                let (error_class, offset) = decode_enumerated(&data[pos..])
+                    .ok()
+                    .and_then(|(value, offset)| Some((u16::try_from(value).ok()?, offset)))
+                    .ok_or_else(|| {
+                        ApplicationError::InvalidApdu("Invalid error class".to_string())
+                    })?;
                 pos += offset;
                 let (error_code, _) = decode_enumerated(&data[pos..])
-                    .map_err(|_| ApplicationError::InvalidApdu("Invalid error code".to_string()))?;
+                    .ok()
+                    .and_then(|(value, offset)| Some((u16::try_from(value).ok()?, offset)))
+                    .ok_or_else(|| {
+                        ApplicationError::InvalidApdu("Invalid error code".to_string())
+                    })?;
 
                 Ok(Apdu::Error {
                     invoke_id,
                     service_choice,
-                    error_class: error_class as u8,
-                    error_code: error_code as u8,
+                    error_class: error_class.into(),
+                    error_code: error_code.into(),
                 })
             }
 
@@ -735,6 +922,58 @@ impl Apdu {
     }
 }
 
+/// Build the Error PDU answering a confirmed request
+pub fn encode_error_response(
+    invoke_id: u8,
+    service_choice: ConfirmedServiceChoice,
+    error_class: ErrorClass,
+    error_code: ErrorCode,
+) -> Apdu {
+    Apdu::Error {
+        invoke_id,
+        service_choice,
+        error_class,
+        error_code,
+    }
+}
+
+/// A message received from the network, decoded past its NPDU
+#[derive(Debug, Clone)]
+pub enum BacnetMessage {
+    /// Network layer message, e.g. Who-Is-Router-To-Network
+    Network {
+        /// NPDU header, with the source and destination addresses
+        npdu: Npdu,
+        /// Network layer message carried by the NPDU
+        message: NetworkLayerMessage,
+    },
+    /// Application layer message
+    Application {
+        /// NPDU header, with the source and destination addresses
+        npdu: Npdu,
+        /// APDU carried by the NPDU
+        apdu: Apdu,
+    },
+}
+
+/// Decode an NPDU and the network or application message it carries
+///
+/// `data` starts at the NPDU, i.e. after any data link framing such as the
+/// BVLC header. The network message bit of the NPDU control octet selects
+/// whether the payload is a network layer message or an APDU.
+pub fn decode_bacnet_message(data: &[u8]) -> Result<BacnetMessage> {
+    let (npdu, npdu_len) = Npdu::decode(data)?;
+    let payload = &data[npdu_len..];
+
+    if npdu.is_network_message() {
+        let message = NetworkLayerMessage::decode(payload)?;
+        Ok(BacnetMessage::Network { npdu, message })
+    } else {
+        let apdu = Apdu::decode(payload)?;
+        Ok(BacnetMessage::Application { npdu, apdu })
+    }
+}
+
 /// Invoke ID manager for handling transaction IDs
 #[derive(Debug)]
 pub struct InvokeIdManager {
@@ -789,6 +1028,59 @@ impl Default for InvokeIdManager {
     }
 }
 
+/// Invoke ID manager with a separate ID space per peer
+///
+/// Invoke IDs only have to be unique per peer, so each peer gets its own
+/// [`InvokeIdManager`] and up to 256 transactions can be outstanding with
+/// every peer at once.
+#[derive(Debug)]
+pub struct PeerInvokeIdManager<A> {
+    peers: BTreeMap<A, InvokeIdManager>,
+}
+
+impl<A: Ord + Clone> PeerInvokeIdManager<A> {
+    /// Create a new per-peer invoke ID manager
+    pub fn new() -> Self {
+        Self {
+            peers: BTreeMap::new(),
+        }
+    }
+
+    /// Get the next available invoke ID for `peer`
+    pub fn next_id(&mut self, peer: &A) -> Option<u8> {
+        if let Some(manager) = self.peers.get_mut(peer) {
+            return manager.next_id();
+        }
+
+        self.peers.entry(peer.clone()).or_default().next_id()
+    }
+
+    /// Release an invoke ID of `peer`
+    pub fn release_id(&mut self, peer: &A, id: u8) {
+        if let Some(manager) = self.peers.get_mut(peer) {
+            manager.release_id(id);
+        }
+    }
+
+    /// Check if an invoke ID of `peer` is active
+    pub fn is_active(&self, peer: &A, id: u8) -> bool {
+        self.peers
+            .get(peer)
+            .is_some_and(|manager| manager.is_active(id))
+    }
+
+    /// Forget a peer and all of its invoke IDs
+    pub fn remove_peer(&mut self, peer: &A) {
+        self.peers.remove(peer);
+    }
+}
+
+impl<A: Ord + Clone> Default for PeerInvokeIdManager<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Segmentation information for large APDUs
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SegmentationInfo {
@@ -857,6 +1149,10 @@ pub struct SegmentReassemblyBuffer {
     /// Timestamp of last received segment (for timeout handling)
     #[cfg(feature = "std")]
     pub last_activity: std::time::Instant,
+    /// Number of in-order segments covered by the last SegmentAck
+    acknowledged: usize,
+    /// Value of the manager's activity counter when a segment last arrived
+    activity: u64,
 }
 
 impl SegmentReassemblyBuffer {
@@ -869,9 +1165,20 @@ impl SegmentReassemblyBuffer {
             max_apdu_length,
             #[cfg(feature = "std")]
             last_activity: std::time::Instant::now(),
+            acknowledged: 0,
+            activity: 0,
         }
     }
 
+    /// Number of segments received in order, without gaps, from segment 0
+    pub fn in_order_count(&self) -> usize {
+        self.segments
+            .iter()
+            .enumerate()
+            .take_while(|(i, (seq, _))| *seq as usize == *i)
+            .count()
+    }
+
     /// Add a segment to the buffer
     pub fn add_segment(&mut self, sequence_number: u8, data: Vec<u8>, is_last: bool) -> Result<()> {
         // Update activity timestamp
@@ -934,18 +1241,19 @@ impl SegmentReassemblyBuffer {
     }
 
     /// Get missing segment numbers
+    ///
+    /// Until the final segment has arrived only the gaps below the highest
+    /// received sequence number are known.
     pub fn missing_segments(&self) -> Vec<u8> {
-        if let Some(total) = self.total_segments {
-            let mut missing = Vec::new();
-            for i in 0..total {
-                if !self.segments.iter().any(|(seq, _)| *seq == i) {
-                    missing.push(i);
-                }
-            }
-            missing
-        } else {
-            Vec::new()
-        }
+        let total = match (self.total_segments, self.segments.last()) {
+            (Some(total), _) => total,
+            (None, Some((highest, _))) => *highest,
+            (None, None) => return Vec::new(),
+        };
+
+        (0..total)
+            .filter(|i| !self.segments.iter().any(|(seq, _)| seq == i))
+            .collect()
     }
 
     /// Check if the buffer has timed out
@@ -962,6 +1270,9 @@ pub struct SegmentationManager {
     reassembly_buffers: Vec<SegmentReassemblyBuffer>,
     /// Maximum number of concurrent reassembly operations
     max_concurrent_reassemblies: usize,
+    /// Incremented for every received segment, to order buffers by activity
+    /// without a clock
+    activity: u64,
     /// Segment timeout duration
     #[cfg(feature = "std")]
     segment_timeout: std::time::Duration,
@@ -973,6 +1284,7 @@ impl SegmentationManager {
         Self {
             reassembly_buffers: Vec::new(),
             max_concurrent_reassemblies: 16,
+            activity: 0,
             #[cfg(feature = "std")]
             segment_timeout: std::time::Duration::from_secs(60),
         }
@@ -1009,6 +1321,61 @@ impl SegmentationManager {
         Ok(segments)
     }
 
+    /// Build the ComplexAck APDUs for a response to a peer that accepts at
+    /// most `max_apdu` octets
+    ///
+    /// A response that fits is returned as a single unsegmented ComplexAck.
+    /// Otherwise every APDU is a segment carrying its sequence number, the
+    /// proposed window size and the more-follows flag, ready to be handed to a
+    /// [`SegmentedTransmission`]. At most 255 segments are produced.
+    pub fn build_complex_ack_segments(
+        &self,
+        invoke_id: u8,
+        service_choice: ConfirmedServiceChoice,
+        data: &[u8],
+        max_apdu: usize,
+        window_size: u8,
+    ) -> Result<Vec<Apdu>> {
+        if data.len() + COMPLEX_ACK_HEADER_LEN <= max_apdu {
+            return Ok(vec![Apdu::ComplexAck {
+                segmented: false,
+                more_follows: false,
+                invoke_id,
+                sequence_number: None,
+                proposed_window_size: None,
+                service_choice,
+                service_data: data.to_vec(),
+            }]);
+        }
+
+        if !(1..=MAX_WINDOW_SIZE).contains(&window_size) {
+            return Err(ApplicationError::SegmentationError(format!(
+                "Invalid window size {window_size}"
+            )));
+        }
+        if max_apdu <= SEGMENTED_COMPLEX_ACK_HEADER_LEN {
+            return Err(ApplicationError::MaxApduLengthExceeded);
+        }
+
+        let segments =
+            self.segment_message(data, max_apdu - SEGMENTED_COMPLEX_ACK_HEADER_LEN, u8::MAX)?;
+        let last = segments.len() - 1;
+
+        Ok(segments
+            .into_iter()
+            .enumerate()
+            .map(|(index, service_data)| Apdu::ComplexAck {
+                segmented: true,
+                more_follows: index != last,
+                invoke_id,
+                sequence_number: Some(index as u8),
+                proposed_window_size: Some(window_size),
+                service_choice,
+                service_data,
+            })
+            .collect())
+    }
+
     /// Process an incoming segment
     pub fn process_segment(
         &mut self,
@@ -1018,7 +1385,128 @@ impl SegmentationManager {
         more_follows: bool,
         max_apdu_length: u16,
     ) -> Result<Option<Vec<u8>>> {
-        // Find or create reassembly buffer
+        let buffer = self.buffer_for(invoke_id, max_apdu_length);
+
+        // Add the segment
+        buffer.add_segment(sequence_number, data, !more_follows)?;
+
+        // Check if reassembly is complete
+        if buffer.is_complete() {
+            let result = buffer.reassemble()?;
+            // Remove the completed buffer
+            self.reassembly_buffers.retain(|b| b.invoke_id != invoke_id);
+            Ok(Some(result))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Process a segment of a segmented ConfirmedRequest or ComplexAck
+    ///
+    /// Besides reassembling the message, this works out the SegmentAck the
+    /// sender is waiting for, using the proposed window size of the segment:
+    ///
+    /// - a positive ack once a whole window, or the final segment, has been
+    ///   received in order;
+    /// - a positive ack for the last in-order segment when a duplicate
+    ///   arrives, since the sender is resending after a lost ack;
+    /// - a negative ack for the last in-order segment when a segment arrives
+    ///   out of order. [`Self::get_missing_segments`] lists the gaps.
+    pub fn receive_segment(&mut self, apdu: &Apdu, max_apdu_length: u16) -> Result<SegmentReceipt> {
+        let (server, invoke_id, sequence_number, window_size, more_follows, data) = match apdu {
+            Apdu::ConfirmedRequest {
+                segmented: true,
+                more_follows,
+                invoke_id,
+                sequence_number: Some(sequence_number),
+                proposed_window_size: Some(window_size),
+                service_data,
+                ..
+            } => (
+                true,
+                invoke_id,
+                sequence_number,
+                window_size,
+                more_follows,
+                service_data,
+            ),
+            Apdu::ComplexAck {
+                segmented: true,
+                more_follows,
+                invoke_id,
+                sequence_number: Some(sequence_number),
+                proposed_window_size: Some(window_size),
+                service_data,
+                ..
+            } => (
+                false,
+                invoke_id,
+                sequence_number,
+                window_size,
+                more_follows,
+                service_data,
+            ),
+            _ => {
+                return Err(ApplicationError::SegmentationError(
+                    "Not a segment".to_string(),
+                ))
+            }
+        };
+        let window_size = (*window_size).clamp(1, MAX_WINDOW_SIZE) as usize;
+
+        let buffer = self.buffer_for(*invoke_id, max_apdu_length);
+        let duplicate = buffer
+            .segments
+            .iter()
+            .any(|(seq, _)| seq == sequence_number);
+        buffer.add_segment(*sequence_number, data.clone(), !more_follows)?;
+
+        let in_order = buffer.in_order_count();
+        let complete = buffer.is_complete();
+        let out_of_order = buffer.segments.len() > in_order;
+
+        let negative = if complete {
+            false
+        } else if out_of_order {
+            true
+        } else if duplicate || in_order - buffer.acknowledged >= window_size {
+            false
+        } else {
+            return Ok(SegmentReceipt {
+                message: None,
+                ack: None,
+            });
+        };
+
+        buffer.acknowledged = in_order;
+        let ack = Apdu::SegmentAck {
+            negative,
+            server,
+            invoke_id: *invoke_id,
+            // Wraps to 255 when not even segment 0 has arrived
+            sequence_number: (in_order as u8).wrapping_sub(1),
+            window_size: window_size as u8,
+        };
+
+        let message = if complete {
+            let message = buffer.reassemble()?;
+            self.reassembly_buffers
+                .retain(|b| b.invoke_id != *invoke_id);
+            Some(message)
+        } else {
+            None
+        };
+
+        Ok(SegmentReceipt {
+            message,
+            ack: Some(ack),
+        })
+    }
+
+    /// Find or create the reassembly buffer for `invoke_id`, marking it as
+    /// the most recently active
+    fn buffer_for(&mut self, invoke_id: u8, max_apdu_length: u16) -> &mut SegmentReassemblyBuffer {
+        self.activity += 1;
         let buffer_index = self
             .reassembly_buffers
             .iter()
@@ -1037,19 +1525,8 @@ impl SegmentationManager {
                 .push(SegmentReassemblyBuffer::new(invoke_id, max_apdu_length));
             self.reassembly_buffers.last_mut().unwrap()
         };
-
-        // Add the segment
-        buffer.add_segment(sequence_number, data, !more_follows)?;
-
-        // Check if reassembly is complete
-        if buffer.is_complete() {
-            let result = buffer.reassemble()?;
-            // Remove the completed buffer
-            self.reassembly_buffers.retain(|b| b.invoke_id != invoke_id);
-            Ok(Some(result))
-        } else {
-            Ok(None)
-        }
+        buffer.activity = self.activity;
+        buffer
     }
 
     /// Get missing segments for a reassembly operation
@@ -1068,26 +1545,19 @@ impl SegmentationManager {
             .retain(|buffer| !buffer.is_timed_out(self.segment_timeout));
     }
 
-    /// Remove the oldest reassembly buffer
+    /// Remove the reassembly buffer that has gone longest without a segment
+    ///
+    /// Uses the activity counter rather than `last_activity`, so it behaves
+    /// the same without std and never ties.
     fn cleanup_oldest_buffer(&mut self) {
-        if !self.reassembly_buffers.is_empty() {
-            #[cfg(feature = "std")]
-            {
-                // Find the buffer with the oldest last_activity
-                let oldest_index = self
-                    .reassembly_buffers
-                    .iter()
-                    .enumerate()
-                    .min_by_key(|(_, buffer)| buffer.last_activity)
-                    .map(|(index, _)| index)
-                    .unwrap_or(0);
-                self.reassembly_buffers.remove(oldest_index);
-            }
-            #[cfg(not(feature = "std"))]
-            {
-                // Without std, just remove the first buffer
-                self.reassembly_buffers.remove(0);
-            }
+        if let Some(oldest_index) = self
+            .reassembly_buffers
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, buffer)| buffer.activity)
+            .map(|(index, _)| index)
+        {
+            self.reassembly_buffers.remove(oldest_index);
         }
     }
 
@@ -1097,6 +1567,12 @@ impl SegmentationManager {
         self.segment_timeout = timeout;
     }
 
+    /// Drop the reassembly buffer of `invoke_id`, if any
+    pub fn discard(&mut self, invoke_id: u8) {
+        self.reassembly_buffers
+            .retain(|buffer| buffer.invoke_id != invoke_id);
+    }
+
     /// Get the number of active reassembly operations
     pub fn active_reassemblies(&self) -> usize {
         self.reassembly_buffers.len()
@@ -1109,6 +1585,120 @@ impl Default for SegmentationManager {
     }
 }
 
+/// Result of receiving one segment with [`SegmentationManager::receive_segment`]
+#[derive(Debug)]
+pub struct SegmentReceipt {
+    /// The reassembled service data, once the final segment is in
+    pub message: Option<Vec<u8>>,
+    /// SegmentAck to send to the peer, if one is due
+    pub ack: Option<Apdu>,
+}
+
+/// Header length of an unsegmented ComplexAck
+const COMPLEX_ACK_HEADER_LEN: usize = 3;
+
+/// Header length of a segmented ComplexAck, including sequence number and
+/// proposed window size
+const SEGMENTED_COMPLEX_ACK_HEADER_LEN: usize = 5;
+
+/// Largest window size allowed (Clause 20.1.2.9)
+const MAX_WINDOW_SIZE: u8 = 127;
+
+/// Window size proposed when sending a segmented response
+const RESPONSE_WINDOW_SIZE: u8 = 16;
+
+/// Sending side of a segmented message
+///
+/// Hands out the segments one window at a time. Each SegmentAck from the
+/// peer acknowledges the segments up to its sequence number and sets the
+/// window size for the next window; a negative SegmentAck makes the
+/// unacknowledged segments go out again.
+#[derive(Debug)]
+pub struct SegmentedTransmission {
+    segments: Vec<Apdu>,
+    invoke_id: u8,
+    /// Index of the first unacknowledged segment
+    window_start: usize,
+    window_size: u8,
+}
+
+impl SegmentedTransmission {
+    /// Start sending `segments`, e.g. from
+    /// [`SegmentationManager::build_complex_ack_segments`]
+    ///
+    /// The first window uses `window_size`, normally the proposed window
+    /// size carried in the segments.
+    pub fn new(segments: Vec<Apdu>, window_size: u8) -> Self {
+        let invoke_id = match segments.first() {
+            Some(Apdu::ConfirmedRequest { invoke_id, .. } | Apdu::ComplexAck { invoke_id, .. }) => {
+                *invoke_id
+            }
+            _ => 0,
+        };
+
+        Self {
+            segments,
+            invoke_id,
+            window_start: 0,
+            window_size: window_size.clamp(1, MAX_WINDOW_SIZE),
+        }
+    }
+
+    /// Segments of the current window, to be sent before waiting for the
+    /// next SegmentAck
+    pub fn window(&self) -> &[Apdu] {
+        let end = (self.window_start + self.window_size as usize).min(self.segments.len());
+        &self.segments[self.window_start..end]
+    }
+
+    /// Check if every segment has been acknowledged
+    pub fn is_complete(&self) -> bool {
+        self.window_start == self.segments.len()
+    }
+
+    /// Process a SegmentAck from the peer and return the next window
+    ///
+    /// The returned window is empty once the final segment has been
+    /// acknowledged. A SegmentAck for another transaction, or one that
+    /// acknowledges a segment that was never sent, is an error.
+    pub fn process_segment_ack(&mut self, ack: &Apdu) -> Result<&[Apdu]> {
+        let Apdu::SegmentAck {
+            invoke_id,
+            sequence_number,
+            window_size,
+            ..
+        } = ack
+        else {
+            return Err(ApplicationError::SegmentationError(
+                "Expected a SegmentAck".to_string(),
+            ));
+        };
+
+        if *invoke_id != self.invoke_id {
+            return Err(ApplicationError::SegmentationError(format!(
+                "SegmentAck for invoke ID {invoke_id}, expected {}",
+                self.invoke_id
+            )));
+        }
+
+        // Sequence numbers wrap at 256. An ack for the segment before the
+        // window acknowledges nothing new.
+        let acknowledged = sequence_number
+            .wrapping_sub(self.window_start as u8)
+            .wrapping_add(1) as usize;
+        if acknowledged > self.window().len() {
+            return Err(ApplicationError::SegmentationError(format!(
+                "SegmentAck for unsent segment {sequence_number}"
+            )));
+        }
+
+        self.window_start += acknowledged;
+        self.window_size = (*window_size).clamp(1, MAX_WINDOW_SIZE);
+
+        Ok(self.window())
+    }
+}
+
 /// Application layer service handler
 #[derive(Debug)]
 pub struct ApplicationLayerHandler {
@@ -1120,6 +1710,18 @@ pub struct ApplicationLayerHandler {
     transaction_manager: TransactionManager,
     /// Service processors
     service_processors: ServiceProcessors,
+    /// How oversized responses are handled
+    segmentation_policy: SegmentationPolicy,
+    /// Largest APDU this device transmits
+    max_apdu_length: usize,
+    /// Largest segmented response this device reassembles
+    max_response_length: u16,
+    /// Reassembly of segmented ComplexAcks
+    segmentation_manager: SegmentationManager,
+    /// Segmented responses waiting for SegmentAcks
+    transmissions: Vec<SegmentedTransmission>,
+    /// APDUs to send after the one returned by `process_apdu`
+    pending_apdus: Vec<Apdu>,
     /// Application statistics
     pub stats: ApplicationStatistics,
 }
@@ -1154,18 +1756,42 @@ impl Default for SupportedServices {
 /// Type alias for service processor function
 type ServiceProcessor = Box<dyn Fn(&[u8]) -> Result<Vec<u8>> + Send + Sync>;
 
+/// Type alias for a processor of a confirmed service answered with a SimpleAck
+type AckServiceProcessor = Box<dyn Fn(&[u8]) -> Result<()> + Send + Sync>;
+
 /// Type alias for optional service processor function
 type OptionalServiceProcessor = Box<dyn Fn(&[u8]) -> Result<Option<Vec<u8>>> + Send + Sync>;
 
+/// Type alias for an unconfirmed service processor, which may answer with any APDU
+type UnconfirmedServiceProcessor = Box<dyn Fn(&[u8]) -> Result<Option<Apdu>> + Send + Sync>;
+
+/// Type alias for a processor of ComplexAck service data, called with the
+/// invoke ID and service choice of the transaction
+type ComplexAckProcessor = Box<dyn Fn(u8, ConfirmedServiceChoice, &[u8]) + Send + Sync>;
+
+/// Type alias for a time synchronization processor, called with the decoded
+/// date and time and whether it is UTC
+type TimeSyncProcessor = Box<dyn Fn(BacnetDateTime, bool) + Send + Sync>;
+
 /// Service processors for handling different service types
 #[derive(Default)]
 struct ServiceProcessors {
     /// Read property processor
     read_property: Option<ServiceProcessor>,
     /// Write property processor
-    write_property: Option<ServiceProcessor>,
+    write_property: Option<AckServiceProcessor>,
+    /// Read property multiple processor
+    read_property_multiple: Option<ServiceProcessor>,
+    /// Subscribe COV processor
+    subscribe_cov: Option<AckServiceProcessor>,
     /// Who-Is processor
     who_is: Option<OptionalServiceProcessor>,
+    /// Time Synchronization and UTC Time Synchronization processor
+    time_sync: Option<TimeSyncProcessor>,
+    /// ComplexAck processor
+    complex_ack: Option<ComplexAckProcessor>,
+    /// Processors for other unconfirmed services
+    unconfirmed: Vec<(UnconfirmedServiceChoice, UnconfirmedServiceProcessor)>,
 }
 
 impl fmt::Debug for ServiceProcessors {
@@ -1173,7 +1799,22 @@ impl fmt::Debug for ServiceProcessors {
         f.debug_struct("ServiceProcessors")
             .field("read_property", &self.read_property.is_some())
             .field("write_property", &self.write_property.is_some())
+            .field(
+                "read_property_multiple",
+                &self.read_property_multiple.is_some(),
+            )
+            .field("subscribe_cov", &self.subscribe_cov.is_some())
             .field("who_is", &self.who_is.is_some())
+            .field("time_sync", &self.time_sync.is_some())
+            .field("complex_ack", &self.complex_ack.is_some())
+            .field(
+                "unconfirmed",
+                &self
+                    .unconfirmed
+                    .iter()
+                    .map(|(choice, _)| choice)
+                    .collect::<Vec<_>>(),
+            )
             .finish()
     }
 }
@@ -1186,10 +1827,59 @@ impl ApplicationLayerHandler {
             supported_services: SupportedServices::default(),
             transaction_manager: TransactionManager::new(),
             service_processors: ServiceProcessors::default(),
+            segmentation_policy: SegmentationPolicy::default(),
+            max_apdu_length: MaxApduSize::Up1476.size(),
+            max_response_length: u16::MAX,
+            segmentation_manager: SegmentationManager::new(),
+            transmissions: Vec::new(),
+            pending_apdus: Vec::new(),
             stats: ApplicationStatistics::default(),
         }
     }
 
+    /// Create a new application layer handler using the maximum APDU length,
+    /// maximum segments and segmentation policy of `config`
+    ///
+    /// Segmented responses are reassembled up to `max_segments` APDUs of
+    /// `max_apdu_length` bytes, or 65535 bytes if `max_segments` is 0.
+    pub fn with_config(device_instance: u32, config: &ApplicationConfig) -> Self {
+        let max_response_length = match config.max_segments {
+            0 => u16::MAX,
+            count => (count as u32 * config.max_apdu_length as u32).min(u16::MAX as u32) as u16,
+        };
+        Self {
+            segmentation_policy: config.segmentation_policy,
+            max_apdu_length: config.max_apdu_length as usize,
+            max_response_length,
+            ..Self::new(device_instance)
+        }
+    }
+
+    /// Set how responses too large for a single APDU are handled
+    pub fn set_segmentation_policy(&mut self, policy: SegmentationPolicy) {
+        self.segmentation_policy = policy;
+    }
+
+    /// Decode and process an incoming APDU
+    ///
+    /// An unconfirmed request with a service choice this crate does not know
+    /// is ignored, as the standard requires, rather than reported as
+    /// malformed.
+    pub fn process_apdu_data(&mut self, data: &[u8], source: &[u8]) -> Result<Option<Apdu>> {
+        if let [pdu_type, service_choice, ..] = data {
+            if pdu_type >> 4 == ApduType::UnconfirmedRequest as u8
+                && UnconfirmedServiceChoice::try_from(*service_choice).is_err()
+            {
+                self.stats.apdus_received += 1;
+                self.stats.unconfirmed_requests += 1;
+                return Ok(None);
+            }
+        }
+
+        let apdu = Apdu::decode(data)?;
+        self.process_apdu(&apdu, source)
+    }
+
     /// Process an incoming APDU
     pub fn process_apdu(&mut self, apdu: &Apdu, _source: &[u8]) -> Result<Option<Apdu>> {
         self.stats.apdus_received += 1;
@@ -1199,8 +1889,8 @@ impl ApplicationLayerHandler {
                 segmented,
                 more_follows,
                 segmented_response_accepted,
-                max_segments: _,
-                max_response_size: _,
+                max_segments,
+                max_response_size,
                 invoke_id,
                 sequence_number: _,
                 proposed_window_size: _,
@@ -1212,7 +1902,14 @@ impl ApplicationLayerHandler {
                     more_follows: *more_follows,
                     segmented_response_accepted: *segmented_response_accepted,
                 };
-                self.process_confirmed_request(pdu_flags, *invoke_id, *service_choice, service_data)
+                self.process_confirmed_request(
+                    pdu_flags,
+                    *max_segments,
+                    *max_response_size,
+                    *invoke_id,
+                    *service_choice,
+                    service_data,
+                )
             }
             Apdu::UnconfirmedRequest {
                 service_choice,
@@ -1223,21 +1920,17 @@ impl ApplicationLayerHandler {
                 service_choice,
             } => self.process_simple_ack(*invoke_id, *service_choice),
             Apdu::ComplexAck {
-                segmented,
-                more_follows,
+                segmented: true,
+                invoke_id,
+                service_choice,
+                ..
+            } => self.process_complex_ack_segment(apdu, *invoke_id, *service_choice),
+            Apdu::ComplexAck {
                 invoke_id,
-                sequence_number: _,
-                proposed_window_size: _,
                 service_choice,
                 service_data,
-            } => {
-                let pdu_flags = PduFlags {
-                    segmented: *segmented,
-                    more_follows: *more_follows,
-                    segmented_response_accepted: false,
-                };
-                self.process_complex_ack(pdu_flags, *invoke_id, *service_choice, service_data)
-            }
+                ..
+            } => self.process_complex_ack(*invoke_id, *service_choice, service_data),
             Apdu::Error {
                 invoke_id,
                 service_choice,
@@ -1253,6 +1946,11 @@ impl ApplicationLayerHandler {
                 invoke_id,
                 abort_reason,
             } => self.process_abort(*server, *invoke_id, *abort_reason),
+            Apdu::SegmentAck {
+                server: false,
+                invoke_id,
+                ..
+            } => self.process_segment_ack(apdu, *invoke_id),
             _ => {
                 self.stats.unknown_apdus += 1;
                 Err(ApplicationError::UnsupportedApduType)
@@ -1263,7 +1961,9 @@ impl ApplicationLayerHandler {
     /// Process a confirmed request
     fn process_confirmed_request(
         &mut self,
-        _pdu_flags: PduFlags,
+        pdu_flags: PduFlags,
+        max_segments: MaxSegments,
+        max_response_size: MaxApduSize,
         invoke_id: u8,
         service_choice: ConfirmedServiceChoice,
         service_data: &[u8],
@@ -1277,42 +1977,175 @@ impl ApplicationLayerHandler {
             }));
         }
 
-        // Process based on service type
-        match service_choice {
-            ConfirmedServiceChoice::ReadProperty => {
-                if let Some(ref processor) = self.service_processors.read_property {
-                    match processor(service_data) {
-                        Ok(response_data) => Ok(Some(Apdu::ComplexAck {
-                            segmented: false,
-                            more_follows: false,
-                            invoke_id,
-                            sequence_number: None,
-                            proposed_window_size: None,
-                            service_choice,
-                            service_data: response_data,
-                        })),
-                        Err(_) => {
-                            Ok(Some(Apdu::Error {
-                                invoke_id,
-                                service_choice,
-                                error_class: 0, // Object
-                                error_code: 0,  // Unknown object
-                            }))
-                        }
-                    }
-                } else {
-                    Ok(Some(Apdu::Abort {
-                        server: true,
-                        invoke_id,
-                        abort_reason: u8::from(AbortReason::Other),
-                    }))
-                }
+        // Process based on service type. `Some(data)` is answered with a
+        // ComplexAck and `None` with a SimpleAck.
+        let processors = &self.service_processors;
+        let result = match service_choice {
+            ConfirmedServiceChoice::ReadProperty => processors
+                .read_property
+                .as_ref()
+                .map(|processor| processor(service_data).map(Some)),
+            ConfirmedServiceChoice::ReadPropertyMultiple => processors
+                .read_property_multiple
+                .as_ref()
+                .map(|processor| processor(service_data).map(Some)),
+            ConfirmedServiceChoice::WriteProperty => processors
+                .write_property
+                .as_ref()
+                .map(|processor| processor(service_data).map(|()| None)),
+            ConfirmedServiceChoice::SubscribeCOV => processors
+                .subscribe_cov
+                .as_ref()
+                .map(|processor| processor(service_data).map(|()| None)),
+            _ => {
+                return Ok(Some(Apdu::Reject {
+                    invoke_id,
+                    reject_reason: RejectReason::UnrecognizedService,
+                }))
             }
-            _ => Ok(Some(Apdu::Reject {
+        };
+
+        let apdu = match result {
+            Some(Ok(Some(response_data))) => self.complex_ack(
+                pdu_flags,
+                max_segments,
+                max_response_size,
                 invoke_id,
-                reject_reason: RejectReason::UnrecognizedService,
-            })),
+                service_choice,
+                response_data,
+            ),
+            Some(Ok(None)) => Apdu::SimpleAck {
+                invoke_id,
+                service_choice: service_choice as u8,
+            },
+            Some(Err(ApplicationError::ErrorResponse(class, code))) => {
+                encode_error_response(invoke_id, service_choice, class, code)
+            }
+            Some(Err(_)) => encode_error_response(
+                invoke_id,
+                service_choice,
+                ErrorClass::Services,
+                ErrorCode::Other,
+            ),
+            None => Apdu::Abort {
+                server: true,
+                invoke_id,
+                abort_reason: u8::from(AbortReason::Other),
+            },
+        };
+        Ok(Some(apdu))
+    }
+
+    /// Build the ComplexAck answering a confirmed request, applying the
+    /// segmentation policy when it does not fit in one APDU
+    ///
+    /// A response the policy lets go out segmented is split into segments.
+    /// The first is returned and the rest of the first window is queued for
+    /// [`take_pending_apdus`](Self::take_pending_apdus); later windows follow
+    /// the requester's SegmentAcks. A response that needs more segments than
+    /// the requester accepts is aborted with `BufferOverflow`.
+    fn complex_ack(
+        &mut self,
+        pdu_flags: PduFlags,
+        max_segments: MaxSegments,
+        max_response_size: MaxApduSize,
+        invoke_id: u8,
+        service_choice: ConfirmedServiceChoice,
+        service_data: Vec<u8>,
+    ) -> Apdu {
+        let apdu = Apdu::ComplexAck {
+            segmented: false,
+            more_follows: false,
+            invoke_id,
+            sequence_number: None,
+            proposed_window_size: None,
+            service_choice,
+            service_data,
+        };
+
+        let max_apdu_length = self.max_apdu_length.min(max_response_size.size());
+        if apdu.encode().len() <= max_apdu_length {
+            return apdu;
         }
+
+        let segment = self.segmentation_policy == SegmentationPolicy::SegmentIfNeeded
+            && pdu_flags.segmented_response_accepted;
+        let abort = |abort_reason: AbortReason| Apdu::Abort {
+            server: true,
+            invoke_id,
+            abort_reason: u8::from(abort_reason),
+        };
+        let segments = match apdu {
+            Apdu::ComplexAck { service_data, .. } if segment => {
+                self.segmentation_manager.build_complex_ack_segments(
+                    invoke_id,
+                    service_choice,
+                    &service_data,
+                    max_apdu_length,
+                    RESPONSE_WINDOW_SIZE,
+                )
+            }
+            _ => return abort(AbortReason::SegmentationNotSupported),
+        };
+
+        // Unspecified and more than 64 leave the 255 segments of a sequence
+        // number as the only limit
+        let max_segments = max_segments.count().unwrap_or(u8::MAX) as usize;
+        let segments = match segments {
+            Ok(segments) if segments.len() <= max_segments => segments,
+            Ok(_) | Err(ApplicationError::SegmentationError(_)) => {
+                return abort(AbortReason::BufferOverflow)
+            }
+            Err(_) => return abort(AbortReason::Other),
+        };
+
+        self.transmissions.retain(|t| t.invoke_id != invoke_id);
+        let transmission = SegmentedTransmission::new(segments, RESPONSE_WINDOW_SIZE);
+        let mut window = transmission.window().to_vec();
+        self.transmissions.push(transmission);
+        let first = window.remove(0);
+        self.pending_apdus.extend(window);
+        first
+    }
+
+    /// Process a SegmentAck for a segmented response, queueing the next
+    /// window
+    fn process_segment_ack(&mut self, ack: &Apdu, invoke_id: u8) -> Result<Option<Apdu>> {
+        let Some(index) = self
+            .transmissions
+            .iter()
+            .position(|t| t.invoke_id == invoke_id)
+        else {
+            return Ok(None);
+        };
+
+        let window = match self.transmissions[index].process_segment_ack(ack) {
+            Ok(window) => window.to_vec(),
+            Err(_) => {
+                self.stats.segmentation_errors += 1;
+                self.transmissions.remove(index);
+                return Ok(Some(Apdu::Abort {
+                    server: true,
+                    invoke_id,
+                    abort_reason: u8::from(AbortReason::InvalidApduInThisState),
+                }));
+            }
+        };
+        if self.transmissions[index].is_complete() {
+            self.transmissions.remove(index);
+        }
+
+        let mut window = window.into_iter();
+        let first = window.next();
+        self.pending_apdus.extend(window);
+        Ok(first)
+    }
+
+    /// Take the APDUs queued to be sent after the one returned by
+    /// [`process_apdu`](Self::process_apdu), e.g. the rest of a window of
+    /// response segments
+    pub fn take_pending_apdus(&mut self) -> Vec<Apdu> {
+        core::mem::take(&mut self.pending_apdus)
     }
 
     /// Process an unconfirmed request
@@ -1332,10 +2165,36 @@ impl ApplicationLayerHandler {
                         service_data: response_data,
                     }));
                 }
+                return Ok(None);
             }
         }
 
-        Ok(None)
+        let utc = match service_choice {
+            UnconfirmedServiceChoice::TimeSynchronization => Some(false),
+            UnconfirmedServiceChoice::UtcTimeSynchronization => Some(true),
+            _ => None,
+        };
+        if let (Some(utc), Some(processor)) = (utc, &self.service_processors.time_sync) {
+            let date_time = if utc {
+                UtcTimeSynchronizationRequest::decode(service_data).map(|r| r.utc_date_time)
+            } else {
+                TimeSynchronizationRequest::decode(service_data).map(|r| r.date_time)
+            }
+            .map_err(|e| ApplicationError::InvalidApdu(e.to_string()))?;
+            processor(date_time, utc);
+            return Ok(None);
+        }
+
+        // Anything without a registered processor is ignored
+        match self
+            .service_processors
+            .unconfirmed
+            .iter()
+            .find(|(choice, _)| *choice == service_choice)
+        {
+            Some((_, processor)) => processor(service_data),
+            None => Ok(None),
+        }
     }
 
     /// Process a simple ACK
@@ -1348,23 +2207,72 @@ impl ApplicationLayerHandler {
     /// Process a complex ACK
     fn process_complex_ack(
         &mut self,
-        _pdu_flags: PduFlags,
         invoke_id: u8,
-        _service_choice: ConfirmedServiceChoice,
-        _service_data: &[u8],
+        service_choice: ConfirmedServiceChoice,
+        service_data: &[u8],
     ) -> Result<Option<Apdu>> {
         self.stats.complex_acks += 1;
         self.transaction_manager.complete_transaction(invoke_id);
+        if let Some(processor) = &self.service_processors.complex_ack {
+            processor(invoke_id, service_choice, service_data);
+        }
         Ok(None)
     }
 
+    /// Process one segment of a segmented complex ACK
+    ///
+    /// The transaction completes with the final segment, when the reassembled
+    /// service data is passed on. Any SegmentAck due is returned. Segments for
+    /// no outstanding transaction are ignored; a segment that cannot be
+    /// reassembled aborts the transaction.
+    fn process_complex_ack_segment(
+        &mut self,
+        apdu: &Apdu,
+        invoke_id: u8,
+        service_choice: ConfirmedServiceChoice,
+    ) -> Result<Option<Apdu>> {
+        if !self.transaction_manager.is_active(invoke_id) {
+            return Ok(None);
+        }
+
+        let receipt = match self
+            .segmentation_manager
+            .receive_segment(apdu, self.max_response_length)
+        {
+            Ok(receipt) => receipt,
+            Err(error) => {
+                self.stats.segmentation_errors += 1;
+                self.segmentation_manager.discard(invoke_id);
+                let abort_reason = match error {
+                    ApplicationError::MaxApduLengthExceeded => AbortReason::BufferOverflow,
+                    _ => AbortReason::InvalidApduInThisState,
+                };
+                self.transaction_manager
+                    .abort_transaction(invoke_id, u8::from(abort_reason));
+                return Ok(Some(Apdu::Abort {
+                    server: false,
+                    invoke_id,
+                    abort_reason: u8::from(abort_reason),
+                }));
+            }
+        };
+
+        match receipt.message {
+            Some(service_data) => {
+                self.process_complex_ack(invoke_id, service_choice, &service_data)?;
+            }
+            None => self.transaction_manager.segment_transaction(invoke_id),
+        }
+        Ok(receipt.ack)
+    }
+
     /// Process an error PDU
     fn process_error(
         &mut self,
         invoke_id: u8,
         _service_choice: ConfirmedServiceChoice,
-        error_class: u8,
-        error_code: u8,
+        error_class: ErrorClass,
+        error_code: ErrorCode,
     ) -> Result<Option<Apdu>> {
         self.stats.errors += 1;
         self.transaction_manager
@@ -1405,6 +2313,36 @@ impl ApplicationLayerHandler {
         self.service_processors.read_property = Some(Box::new(handler));
     }
 
+    /// Set WriteProperty processor
+    ///
+    /// A successful write is answered with a SimpleAck.
+    pub fn set_write_property_handler<F>(&mut self, handler: F)
+    where
+        F: Fn(&[u8]) -> Result<()> + Send + Sync + 'static,
+    {
+        self.service_processors.write_property = Some(Box::new(handler));
+    }
+
+    /// Set ReadPropertyMultiple processor
+    ///
+    /// The returned service data is sent in a ComplexAck.
+    pub fn set_read_property_multiple_handler<F>(&mut self, handler: F)
+    where
+        F: Fn(&[u8]) -> Result<Vec<u8>> + Send + Sync + 'static,
+    {
+        self.service_processors.read_property_multiple = Some(Box::new(handler));
+    }
+
+    /// Set SubscribeCOV processor
+    ///
+    /// An accepted subscription is answered with a SimpleAck.
+    pub fn set_subscribe_cov_handler<F>(&mut self, handler: F)
+    where
+        F: Fn(&[u8]) -> Result<()> + Send + Sync + 'static,
+    {
+        self.service_processors.subscribe_cov = Some(Box::new(handler));
+    }
+
     /// Set Who-Is processor
     pub fn set_who_is_handler<F>(&mut self, handler: F)
     where
@@ -1412,6 +2350,69 @@ impl ApplicationLayerHandler {
     {
         self.service_processors.who_is = Some(Box::new(handler));
     }
+
+    /// Set the processor for ComplexAck responses
+    ///
+    /// The processor receives the invoke ID, service choice and service data.
+    /// A segmented response is passed on once, after the final segment.
+    pub fn set_complex_ack_handler<F>(&mut self, handler: F)
+    where
+        F: Fn(u8, ConfirmedServiceChoice, &[u8]) + Send + Sync + 'static,
+    {
+        self.service_processors.complex_ack = Some(Box::new(handler));
+    }
+
+    /// Set the processor for Time Synchronization and UTC Time Synchronization
+    ///
+    /// The processor receives the decoded date and time, and `true` if it is
+    /// UTC. Registering it adds both services to the supported unconfirmed
+    /// services.
+    pub fn set_time_sync_handler<F>(&mut self, handler: F)
+    where
+        F: Fn(BacnetDateTime, bool) + Send + Sync + 'static,
+    {
+        self.service_processors.time_sync = Some(Box::new(handler));
+
+        for service_choice in [
+            UnconfirmedServiceChoice::TimeSynchronization,
+            UnconfirmedServiceChoice::UtcTimeSynchronization,
+        ] {
+            if !self
+                .supported_services
+                .unconfirmed
+                .contains(&service_choice)
+            {
+                self.supported_services.unconfirmed.push(service_choice);
+            }
+        }
+    }
+
+    /// Set the processor for an unconfirmed service, e.g. Who-Am-I or
+    /// Write-Group
+    ///
+    /// The processor receives the service data and may return an APDU to send
+    /// in reply. Registering a processor adds the service to the supported
+    /// unconfirmed services; a Who-Is handler set with
+    /// [`set_who_is_handler`](Self::set_who_is_handler) takes precedence.
+    pub fn set_unconfirmed_handler<F>(
+        &mut self,
+        service_choice: UnconfirmedServiceChoice,
+        handler: F,
+    ) where
+        F: Fn(&[u8]) -> Result<Option<Apdu>> + Send + Sync + 'static,
+    {
+        let processors = &mut self.service_processors.unconfirmed;
+        processors.retain(|(choice, _)| *choice != service_choice);
+        processors.push((service_choice, Box::new(handler)));
+
+        if !self
+            .supported_services
+            .unconfirmed
+            .contains(&service_choice)
+        {
+            self.supported_services.unconfirmed.push(service_choice);
+        }
+    }
 }
 
 /// Transaction manager for tracking active transactions
@@ -1478,8 +2479,31 @@ impl TransactionManager {
         }
     }
 
+    /// Check whether a transaction is still outstanding
+    pub fn is_active(&self, invoke_id: u8) -> bool {
+        self.transactions
+            .iter()
+            .any(|t| t.invoke_id == invoke_id && t.state != TransactionState::Complete)
+    }
+
+    /// Mark a transaction as receiving a segmented response
+    pub fn segment_transaction(&mut self, invoke_id: u8) {
+        if let Some(transaction) = self
+            .transactions
+            .iter_mut()
+            .find(|t| t.invoke_id == invoke_id)
+        {
+            transaction.state = TransactionState::SegmentedResponse;
+        }
+    }
+
     /// Mark transaction as error
-    pub fn error_transaction(&mut self, invoke_id: u8, _error_class: u8, _error_code: u8) {
+    pub fn error_transaction(
+        &mut self,
+        invoke_id: u8,
+        _error_class: ErrorClass,
+        _error_code: ErrorCode,
+    ) {
         if let Some(transaction) = self
             .transactions
             .iter_mut()
@@ -1674,6 +2698,29 @@ pub struct PduFlags {
     pub segmented_response_accepted: bool,
 }
 
+/// How a responder handles a response too large for a single APDU
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SegmentationPolicy {
+    /// Abort oversized responses with `SegmentationNotSupported`
+    NeverSegment,
+    /// Send oversized responses segmented if the requester accepts segmented
+    /// responses, otherwise abort with `SegmentationNotSupported`
+    #[default]
+    SegmentIfNeeded,
+}
+
+impl From<Segmentation> for SegmentationPolicy {
+    /// The policy matching a device's advertised Segmentation_Supported
+    fn from(segmentation: Segmentation) -> Self {
+        match segmentation {
+            Segmentation::Both | Segmentation::Transmit => SegmentationPolicy::SegmentIfNeeded,
+            Segmentation::Receive | Segmentation::NoSegmentation => {
+                SegmentationPolicy::NeverSegment
+            }
+        }
+    }
+}
+
 /// Application layer configuration
 #[derive(Debug, Clone)]
 pub struct ApplicationConfig {
@@ -1681,6 +2728,8 @@ pub struct ApplicationConfig {
     pub max_apdu_length: u16,
     /// Segmentation support
     pub segmentation: Segmentation,
+    /// How oversized responses are handled
+    pub segmentation_policy: SegmentationPolicy,
     /// APDU timeout (milliseconds)
     pub apdu_timeout: u16,
     /// Number of APDU retries
@@ -1691,11 +2740,19 @@ pub struct ApplicationConfig {
     pub invoke_id_start: u8,
 }
 
+impl ApplicationConfig {
+    /// The max-segments-accepted field to send in confirmed requests
+    pub fn max_segments_accepted(&self) -> MaxSegments {
+        MaxSegments::from_count_floor(self.max_segments)
+    }
+}
+
 impl Default for ApplicationConfig {
     fn default() -> Self {
         Self {
             max_apdu_length: 1476,
             segmentation: Segmentation::Both,
+            segmentation_policy: SegmentationPolicy::default(),
             apdu_timeout: 6000,
             apdu_retries: 3,
             max_segments: 64,
@@ -1708,6 +2765,44 @@ impl Default for ApplicationConfig {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_max_segments_count() {
+        assert_eq!(MaxSegments::from_count(2), MaxSegments::Two);
+        assert_eq!(MaxSegments::from_count(15), MaxSegments::Sixteen);
+        assert_eq!(MaxSegments::from_count(64), MaxSegments::SixtyFour);
+        assert_eq!(MaxSegments::from_count(100), MaxSegments::GreaterThan64);
+        assert_eq!(MaxSegments::from_count(0), MaxSegments::Unspecified);
+
+        assert_eq!(MaxSegments::from_count_floor(15), MaxSegments::Eight);
+        assert_eq!(MaxSegments::from_count_floor(16), MaxSegments::Sixteen);
+        assert_eq!(MaxSegments::from_count_floor(63), MaxSegments::ThirtyTwo);
+        assert_eq!(MaxSegments::from_count_floor(1), MaxSegments::Unspecified);
+        assert_eq!(
+            MaxSegments::from_count_floor(65),
+            MaxSegments::GreaterThan64
+        );
+
+        assert_eq!(MaxSegments::Sixteen.count(), Some(16));
+        assert_eq!(MaxSegments::SixtyFour.count(), Some(64));
+        assert_eq!(MaxSegments::GreaterThan64.count(), None);
+        assert_eq!(MaxSegments::Unspecified.count(), None);
+
+        let config = ApplicationConfig {
+            max_segments: 100,
+            ..ApplicationConfig::default()
+        };
+        assert_eq!(config.max_segments_accepted(), MaxSegments::GreaterThan64);
+        let config = ApplicationConfig {
+            max_segments: 15,
+            ..ApplicationConfig::default()
+        };
+        assert_eq!(config.max_segments_accepted(), MaxSegments::Eight);
+        assert_eq!(
+            ApplicationConfig::default().max_segments_accepted(),
+            MaxSegments::SixtyFour
+        );
+    }
+
     #[test]
     fn test_unconfirmed_request_encode_decode() {
         let apdu = Apdu::UnconfirmedRequest {
@@ -1785,6 +2880,97 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_apdu_accessors() {
+        let request = Apdu::ConfirmedRequest {
+            segmented: false,
+            more_follows: false,
+            segmented_response_accepted: true,
+            max_segments: MaxSegments::Unspecified,
+            max_response_size: MaxApduSize::Up1476,
+            invoke_id: 1,
+            sequence_number: None,
+            proposed_window_size: None,
+            service_choice: ConfirmedServiceChoice::ReadProperty,
+            service_data: vec![],
+        };
+        let unconfirmed = Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::WhoIs,
+            service_data: vec![],
+        };
+        let simple_ack = Apdu::SimpleAck {
+            invoke_id: 2,
+            service_choice: 15,
+        };
+        let complex_ack = Apdu::ComplexAck {
+            segmented: false,
+            more_follows: false,
+            invoke_id: 3,
+            sequence_number: None,
+            proposed_window_size: None,
+            service_choice: ConfirmedServiceChoice::ReadProperty,
+            service_data: vec![],
+        };
+        let segment_ack = |server| Apdu::SegmentAck {
+            negative: false,
+            server,
+            invoke_id: 4,
+            sequence_number: 0,
+            window_size: 1,
+        };
+        let error = Apdu::Error {
+            invoke_id: 5,
+            service_choice: ConfirmedServiceChoice::ReadProperty,
+            error_class: ErrorClass::Object,
+            error_code: ErrorCode::UnknownObject,
+        };
+        let reject = Apdu::Reject {
+            invoke_id: 6,
+            reject_reason: RejectReason::UnrecognizedService,
+        };
+        let abort = |server| Apdu::Abort {
+            server,
+            invoke_id: 7,
+            abort_reason: 0,
+        };
+
+        assert_eq!(request.invoke_id(), Some(1));
+        assert_eq!(unconfirmed.invoke_id(), None);
+        assert_eq!(simple_ack.invoke_id(), Some(2));
+        assert_eq!(complex_ack.invoke_id(), Some(3));
+        assert_eq!(segment_ack(true).invoke_id(), Some(4));
+        assert_eq!(error.invoke_id(), Some(5));
+        assert_eq!(reject.invoke_id(), Some(6));
+        assert_eq!(abort(false).invoke_id(), Some(7));
+
+        assert!(!request.is_response());
+        assert!(!unconfirmed.is_response());
+        assert!(simple_ack.is_response());
+        assert!(complex_ack.is_response());
+        assert!(segment_ack(true).is_response());
+        assert!(!segment_ack(false).is_response());
+        assert!(error.is_response());
+        assert!(reject.is_response());
+        assert!(abort(true).is_response());
+        assert!(!abort(false).is_response());
+
+        // Survives a round trip through the wire format
+        let decoded = Apdu::decode(&complex_ack.encode()).unwrap();
+        assert_eq!(decoded.invoke_id(), Some(3));
+        assert!(decoded.is_response());
+
+        // ReadProperty (12) is reported the same by the request and its ack
+        assert_eq!(request.service_choice(), Some(12));
+        assert_eq!(complex_ack.service_choice(), Some(12));
+        assert_eq!(decoded.service_choice(), request.service_choice());
+        assert_eq!(error.service_choice(), Some(12));
+        assert_eq!(simple_ack.service_choice(), Some(15));
+        assert_eq!(unconfirmed.service_choice(), Some(8));
+        assert_eq!(segment_ack(true).service_choice(), None);
+        assert_eq!(reject.service_choice(), None);
+        assert_eq!(abort(true).service_choice(), None);
+    }
+
     #[test]
     fn test_invoke_id_manager() {
         let mut manager = InvokeIdManager::new();
@@ -1810,6 +2996,130 @@ mod tests {
         assert!(manager.is_active(id3));
     }
 
+    #[test]
+    fn test_peer_invoke_id_manager() {
+        let mut manager = PeerInvokeIdManager::new();
+
+        // Both peers hold invoke ID 0 at the same time
+        assert_eq!(manager.next_id(&"a"), Some(0));
+        assert_eq!(manager.next_id(&"b"), Some(0));
+        assert_eq!(manager.next_id(&"a"), Some(1));
+        assert!(manager.is_active(&"a", 0));
+        assert!(manager.is_active(&"b", 0));
+        assert!(!manager.is_active(&"b", 1));
+        assert!(!manager.is_active(&"c", 0));
+
+        manager.release_id(&"a", 0);
+        assert!(!manager.is_active(&"a", 0));
+        assert!(manager.is_active(&"b", 0));
+
+        // Exhausting one peer leaves the other untouched
+        for _ in 0..255 {
+            manager.next_id(&"b").unwrap();
+        }
+        assert_eq!(manager.next_id(&"b"), None);
+        assert_eq!(manager.next_id(&"a"), Some(2));
+
+        manager.remove_peer(&"b");
+        assert_eq!(manager.next_id(&"b"), Some(0));
+    }
+
+    #[test]
+    fn test_encode_with_max_length() {
+        let max_apdu = MaxApduSize::Up1476.size();
+        let mut apdu = Apdu::ConfirmedRequest {
+            segmented: false,
+            more_follows: false,
+            segmented_response_accepted: true,
+            max_segments: MaxSegments::Unspecified,
+            max_response_size: MaxApduSize::Up1476,
+            invoke_id: 1,
+            sequence_number: None,
+            proposed_window_size: None,
+            service_choice: ConfirmedServiceChoice::WriteProperty,
+            service_data: vec![0u8; 2000],
+        };
+        assert!(matches!(
+            apdu.encode_with_max_length(max_apdu),
+            Err(ApplicationError::MaxApduLengthExceeded)
+        ));
+
+        // Segments are not checked against the whole-message limit
+        if let Apdu::ConfirmedRequest {
+            segmented,
+            sequence_number,
+            proposed_window_size,
+            ..
+        } = &mut apdu
+        {
+            *segmented = true;
+            *sequence_number = Some(0);
+            *proposed_window_size = Some(1);
+        }
+        assert!(apdu.encode_with_max_length(max_apdu).is_ok());
+
+        let apdu = Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::WhoIs,
+            service_data: vec![0u8; 1474],
+        };
+        assert_eq!(apdu.encode_with_max_length(max_apdu).unwrap().len(), 1476);
+        let apdu = Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::WhoIs,
+            service_data: vec![0u8; 1475],
+        };
+        assert!(apdu.encode_with_max_length(max_apdu).is_err());
+    }
+
+    #[test]
+    fn test_try_encode_segmented() {
+        let mut apdu = Apdu::ConfirmedRequest {
+            segmented: true,
+            more_follows: true,
+            segmented_response_accepted: true,
+            max_segments: MaxSegments::Unspecified,
+            max_response_size: MaxApduSize::Up1476,
+            invoke_id: 9,
+            sequence_number: None,
+            proposed_window_size: Some(4),
+            service_choice: ConfirmedServiceChoice::WriteProperty,
+            service_data: vec![0xAA],
+        };
+        assert!(matches!(
+            apdu.try_encode(),
+            Err(ApplicationError::InvalidApdu(_))
+        ));
+        assert!(apdu.encode_with_max_length(1476).is_err());
+
+        if let Apdu::ConfirmedRequest {
+            sequence_number, ..
+        } = &mut apdu
+        {
+            *sequence_number = Some(2);
+        }
+        assert_eq!(
+            apdu.try_encode().unwrap(),
+            vec![0x0E, 0x05, 9, 2, 4, 15, 0xAA]
+        );
+
+        let ack = Apdu::ComplexAck {
+            segmented: true,
+            more_follows: false,
+            invoke_id: 9,
+            sequence_number: Some(0),
+            proposed_window_size: None,
+            service_choice: ConfirmedServiceChoice::ReadProperty,
+            service_data: vec![],
+        };
+        assert!(ack.try_encode().is_err());
+
+        // Unsegmented APDUs need neither field
+        let ack = Apdu::SimpleAck {
+            invoke_id: 9,
+            service_choice: 15,
+        };
+        assert_eq!(ack.try_encode().unwrap(), ack.encode());
+    }
+
     #[test]
     fn test_max_apdu_size() {
         assert_eq!(MaxApduSize::Up50.size(), 50);
@@ -1879,6 +3189,39 @@ mod tests {
         assert_eq!(incomplete_buffer.missing_segments(), vec![1]);
     }
 
+    #[test]
+    fn test_segmentation_manager_evicts_least_active_buffer() {
+        let mut manager = SegmentationManager::new();
+        for invoke_id in 0..16 {
+            manager
+                .process_segment(invoke_id, 0, vec![invoke_id], true, 1024)
+                .unwrap();
+        }
+        assert_eq!(manager.active_reassemblies(), 16);
+
+        // Buffer 0 was created first but is still receiving
+        manager.process_segment(0, 1, vec![0], true, 1024).unwrap();
+
+        manager
+            .process_segment(16, 0, vec![16], true, 1024)
+            .unwrap();
+        assert_eq!(manager.active_reassemblies(), 16);
+        let pending: Vec<u8> = manager
+            .reassembly_buffers
+            .iter()
+            .map(|buffer| buffer.invoke_id)
+            .collect();
+        assert!(pending.contains(&0));
+        assert!(!pending.contains(&1));
+        assert!(pending.contains(&16));
+
+        // Buffer 0 completes with everything it received
+        assert_eq!(
+            manager.process_segment(0, 2, vec![9], false, 1024).unwrap(),
+            Some(vec![0, 0, 9])
+        );
+    }
+
     #[test]
     fn test_segmentation_manager() {
         let mut manager = SegmentationManager::new();
@@ -1923,6 +3266,185 @@ mod tests {
         assert_eq!(missing, vec![1]);
     }
 
+    #[test]
+    fn test_build_complex_ack_segments() {
+        let manager = SegmentationManager::new();
+        let data: Vec<u8> = (0..3000).map(|i| i as u8).collect();
+        let choice = ConfirmedServiceChoice::ReadPropertyMultiple;
+
+        let segments = manager
+            .build_complex_ack_segments(7, choice, &data, 1476, 4)
+            .unwrap();
+        assert_eq!(segments.len(), 3);
+
+        let mut payload = Vec::new();
+        for (index, segment) in segments.iter().enumerate() {
+            let Apdu::ComplexAck {
+                segmented: true,
+                more_follows,
+                invoke_id: 7,
+                sequence_number: Some(sequence_number),
+                proposed_window_size: Some(4),
+                service_data,
+                ..
+            } = segment
+            else {
+                panic!("expected a ComplexAck segment, got {segment:?}");
+            };
+            assert_eq!(*more_follows, index < 2);
+            assert_eq!(*sequence_number as usize, index);
+            assert!(segment.encode().len() <= 1476);
+            payload.extend_from_slice(service_data);
+        }
+        assert_eq!(payload, data);
+
+        // Fits without segmentation
+        let segments = manager
+            .build_complex_ack_segments(7, choice, &data[..100], 1476, 4)
+            .unwrap();
+        assert!(matches!(
+            &segments[..],
+            [Apdu::ComplexAck {
+                segmented: false,
+                more_follows: false,
+                sequence_number: None,
+                ..
+            }]
+        ));
+
+        assert!(manager
+            .build_complex_ack_segments(7, choice, &data, 1476, 0)
+            .is_err());
+        assert!(manager
+            .build_complex_ack_segments(7, choice, &data, 5, 4)
+            .is_err());
+    }
+
+    #[test]
+    fn test_segmented_transmission_windows() {
+        let manager = SegmentationManager::new();
+        let data = vec![0x55; 3000];
+        let segments = manager
+            .build_complex_ack_segments(9, ConfirmedServiceChoice::ReadProperty, &data, 480, 4)
+            .unwrap();
+        assert_eq!(segments.len(), 7);
+
+        let ack = |sequence_number, negative| Apdu::SegmentAck {
+            negative,
+            server: false,
+            invoke_id: 9,
+            sequence_number,
+            window_size: 4,
+        };
+        let sequence_numbers = |window: &[Apdu]| -> Vec<u8> {
+            window
+                .iter()
+                .map(|apdu| match apdu {
+                    Apdu::ComplexAck {
+                        sequence_number: Some(sequence_number),
+                        ..
+                    } => *sequence_number,
+                    other => panic!("unexpected {other:?}"),
+                })
+                .collect()
+        };
+
+        let mut transmission = SegmentedTransmission::new(segments, 4);
+        assert_eq!(sequence_numbers(transmission.window()), vec![0, 1, 2, 3]);
+
+        // Segment 2 was lost: the peer acknowledges up to 1
+        let window = transmission.process_segment_ack(&ack(1, true)).unwrap();
+        assert_eq!(sequence_numbers(window), vec![2, 3, 4, 5]);
+
+        let window = transmission.process_segment_ack(&ack(5, false)).unwrap();
+        assert_eq!(sequence_numbers(window), vec![6]);
+        assert!(!transmission.is_complete());
+
+        // Not sent yet
+        assert!(transmission.process_segment_ack(&ack(7, false)).is_err());
+        // Another transaction
+        let other = Apdu::SegmentAck {
+            negative: false,
+            server: false,
+            invoke_id: 10,
+            sequence_number: 6,
+            window_size: 4,
+        };
+        assert!(transmission.process_segment_ack(&other).is_err());
+
+        assert!(transmission
+            .process_segment_ack(&ack(6, false))
+            .unwrap()
+            .is_empty());
+        assert!(transmission.is_complete());
+    }
+
+    #[test]
+    fn test_receive_segment_acks_windows() {
+        let segment = |sequence_number: u8, more_follows| Apdu::ComplexAck {
+            segmented: true,
+            more_follows,
+            invoke_id: 3,
+            sequence_number: Some(sequence_number),
+            proposed_window_size: Some(4),
+            service_choice: ConfirmedServiceChoice::ReadProperty,
+            service_data: vec![sequence_number],
+        };
+        let ack_of = |receipt: &SegmentReceipt| match receipt.ack {
+            Some(Apdu::SegmentAck {
+                negative,
+                server,
+                invoke_id: 3,
+                sequence_number,
+                window_size: 4,
+            }) => Some((negative, server, sequence_number)),
+            None => None,
+            ref other => panic!("unexpected ack {other:?}"),
+        };
+
+        let mut manager = SegmentationManager::new();
+        for sequence_number in 0..3 {
+            let receipt = manager
+                .receive_segment(&segment(sequence_number, true), 1476)
+                .unwrap();
+            assert_eq!(ack_of(&receipt), None);
+        }
+
+        // The window of 4 is full
+        let receipt = manager.receive_segment(&segment(3, true), 1476).unwrap();
+        assert_eq!(ack_of(&receipt), Some((false, false, 3)));
+        assert!(receipt.message.is_none());
+
+        let receipt = manager.receive_segment(&segment(4, false), 1476).unwrap();
+        assert_eq!(ack_of(&receipt), Some((false, false, 4)));
+        assert_eq!(receipt.message, Some(vec![0, 1, 2, 3, 4]));
+        assert_eq!(manager.active_reassemblies(), 0);
+
+        // Segment 2 is lost
+        let mut manager = SegmentationManager::new();
+        manager.receive_segment(&segment(0, true), 1476).unwrap();
+        manager.receive_segment(&segment(1, true), 1476).unwrap();
+        let receipt = manager.receive_segment(&segment(3, true), 1476).unwrap();
+        assert_eq!(ack_of(&receipt), Some((true, false, 1)));
+        assert_eq!(manager.get_missing_segments(3), vec![2]);
+
+        // The sender resends from segment 2
+        let receipt = manager.receive_segment(&segment(2, true), 1476).unwrap();
+        assert_eq!(ack_of(&receipt), None);
+        let receipt = manager.receive_segment(&segment(3, true), 1476).unwrap();
+        assert_eq!(ack_of(&receipt), Some((false, false, 3)));
+
+        assert!(manager
+            .receive_segment(
+                &Apdu::SimpleAck {
+                    invoke_id: 3,
+                    service_choice: 12
+                },
+                1476
+            )
+            .is_err());
+    }
+
     #[test]
     fn test_segmentation_error_cases() {
         let manager = SegmentationManager::new();
@@ -1973,4 +3495,644 @@ mod tests {
         let reassembled = buffer.reassemble().unwrap();
         assert_eq!(reassembled, vec![1, 2, 3, 7, 8, 9]);
     }
+
+    #[test]
+    fn test_time_sync_handler() {
+        use crate::object::{Date, Time};
+        use std::sync::{Arc, Mutex};
+
+        let mut handler = ApplicationLayerHandler::new(1234);
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = received.clone();
+        handler.set_time_sync_handler(move |date_time, utc| {
+            sink.lock().unwrap().push((date_time, utc));
+        });
+
+        let local = BacnetDateTime::new(
+            Date {
+                year: 2024,
+                month: 3,
+                day: 15,
+                weekday: 5,
+            },
+            Time {
+                hour: 14,
+                minute: 30,
+                second: 0,
+                hundredths: 0,
+            },
+        );
+        let utc = BacnetDateTime::new(
+            local.date,
+            Time {
+                hour: 13,
+                ..local.time
+            },
+        );
+
+        let mut service_data = Vec::new();
+        TimeSynchronizationRequest::new(local)
+            .encode(&mut service_data)
+            .unwrap();
+        let request = Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::TimeSynchronization,
+            service_data,
+        };
+        assert!(handler.process_apdu(&request, &[]).unwrap().is_none());
+
+        let mut service_data = Vec::new();
+        UtcTimeSynchronizationRequest::new(utc)
+            .encode(&mut service_data)
+            .unwrap();
+        let request = Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::UtcTimeSynchronization,
+            service_data,
+        };
+        assert!(handler.process_apdu(&request, &[]).unwrap().is_none());
+
+        assert_eq!(*received.lock().unwrap(), vec![(local, false), (utc, true)]);
+
+        // A malformed date and time is not passed on
+        let request = Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::TimeSynchronization,
+            service_data: vec![0xA4, 0x7C],
+        };
+        assert!(handler.process_apdu(&request, &[]).is_err());
+        assert_eq!(received.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_unconfirmed_handler_dispatch() {
+        use std::sync::{Arc, Mutex};
+
+        let mut handler = ApplicationLayerHandler::new(1234);
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = received.clone();
+        handler.set_unconfirmed_handler(UnconfirmedServiceChoice::WhoAmI, move |data| {
+            sink.lock().unwrap().push(data.to_vec());
+            Ok(None)
+        });
+        assert!(handler
+            .supported_services
+            .unconfirmed
+            .contains(&UnconfirmedServiceChoice::WhoAmI));
+
+        // Who-Am-I carrying vendor id 260
+        let who_am_i = Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::WhoAmI,
+            service_data: vec![0x22, 0x01, 0x04],
+        };
+        let decoded = Apdu::decode(&who_am_i.encode()).unwrap();
+        assert!(handler.process_apdu(&decoded, &[]).unwrap().is_none());
+        assert_eq!(*received.lock().unwrap(), vec![vec![0x22, 0x01, 0x04]]);
+
+        // Assigned services without a processor are ignored
+        let write_group = Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::WriteGroup,
+            service_data: vec![0x09, 0x01],
+        };
+        assert!(handler.process_apdu(&write_group, &[]).unwrap().is_none());
+        assert_eq!(received.lock().unwrap().len(), 1);
+        assert_eq!(handler.stats.unconfirmed_requests, 2);
+
+        // So are unassigned service choices
+        assert!(Apdu::decode(&[0x10, 42, 0x09, 0x01]).is_err());
+        assert!(handler
+            .process_apdu_data(&[0x10, 42, 0x09, 0x01], &[])
+            .unwrap()
+            .is_none());
+        assert_eq!(received.lock().unwrap().len(), 1);
+        assert_eq!(handler.stats.unconfirmed_requests, 3);
+
+        // A processor can answer
+        handler.set_unconfirmed_handler(UnconfirmedServiceChoice::WhoAmI, |_| {
+            Ok(Some(Apdu::UnconfirmedRequest {
+                service_choice: UnconfirmedServiceChoice::YouAre,
+                service_data: vec![],
+            }))
+        });
+        assert!(matches!(
+            handler.process_apdu(&decoded, &[]).unwrap(),
+            Some(Apdu::UnconfirmedRequest {
+                service_choice: UnconfirmedServiceChoice::YouAre,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_segmentation_policy_oversized_response() {
+        let read_property = |segmented_response_accepted| Apdu::ConfirmedRequest {
+            segmented: false,
+            more_follows: false,
+            segmented_response_accepted,
+            max_segments: MaxSegments::Unspecified,
+            max_response_size: MaxApduSize::Up480,
+            invoke_id: 7,
+            sequence_number: None,
+            proposed_window_size: None,
+            service_choice: ConfirmedServiceChoice::ReadProperty,
+            service_data: vec![0x0C, 0x00, 0x00, 0x00, 0x01, 0x19, 0x55],
+        };
+        let is_segmentation_abort = |response: Option<Apdu>| {
+            matches!(
+                response,
+                Some(Apdu::Abort {
+                    server: true,
+                    invoke_id: 7,
+                    abort_reason,
+                }) if abort_reason == u8::from(AbortReason::SegmentationNotSupported)
+            )
+        };
+
+        let response_data: Vec<u8> = (0..1000).map(|i| i as u8).collect();
+        let mut handler = ApplicationLayerHandler::new(1234);
+        let data = response_data.clone();
+        handler.set_read_property_handler(move |_| Ok(data.clone()));
+
+        // Segment if needed, the default: only if the requester accepts segments
+        let response = handler.process_apdu(&read_property(false), &[]).unwrap();
+        assert!(is_segmentation_abort(response));
+
+        let first = handler.process_apdu(&read_property(true), &[]).unwrap();
+        let mut segments = vec![first.unwrap()];
+        segments.extend(handler.take_pending_apdus());
+        // 1000 octets in segments of at most 480 - 5
+        assert_eq!(segments.len(), 3);
+        let ack = Apdu::SegmentAck {
+            negative: false,
+            server: false,
+            invoke_id: 7,
+            sequence_number: 2,
+            window_size: 16,
+        };
+        assert!(handler.process_apdu(&ack, &[]).unwrap().is_none());
+        assert!(handler.take_pending_apdus().is_empty());
+
+        let mut reassembled = Vec::new();
+        for (index, segment) in segments.iter().enumerate() {
+            assert!(segment.encode().len() <= MaxApduSize::Up480.size());
+            let Apdu::ComplexAck {
+                segmented: true,
+                more_follows,
+                sequence_number: Some(sequence_number),
+                service_data,
+                ..
+            } = segment
+            else {
+                panic!("unexpected segment {segment:?}");
+            };
+            assert_eq!(*sequence_number as usize, index);
+            assert_eq!(*more_follows, index < 2);
+            reassembled.extend_from_slice(service_data);
+        }
+        assert_eq!(reassembled, response_data);
+
+        // A response needing more segments than the requester accepts overflows
+        let mut request = read_property(true);
+        if let Apdu::ConfirmedRequest { max_segments, .. } = &mut request {
+            *max_segments = MaxSegments::Two;
+        }
+        let response = handler.process_apdu(&request, &[]).unwrap();
+        assert!(matches!(
+            response,
+            Some(Apdu::Abort {
+                server: true,
+                invoke_id: 7,
+                abort_reason,
+            }) if abort_reason == u8::from(AbortReason::BufferOverflow)
+        ));
+        if let Apdu::ConfirmedRequest { max_segments, .. } = &mut request {
+            *max_segments = MaxSegments::Four;
+        }
+        let response = handler.process_apdu(&request, &[]).unwrap();
+        assert!(matches!(
+            response,
+            Some(Apdu::ComplexAck {
+                segmented: true,
+                ..
+            })
+        ));
+        handler.take_pending_apdus();
+
+        // Never segment: aborted even though the requester accepts segments
+        handler.set_segmentation_policy(SegmentationPolicy::NeverSegment);
+        let response = handler.process_apdu(&read_property(true), &[]).unwrap();
+        assert!(is_segmentation_abort(response));
+        let response = handler.process_apdu(&read_property(false), &[]).unwrap();
+        assert!(is_segmentation_abort(response));
+
+        // The policy follows Segmentation_Supported, and small responses are unaffected
+        let config = ApplicationConfig {
+            segmentation_policy: Segmentation::NoSegmentation.into(),
+            ..ApplicationConfig::default()
+        };
+        let mut handler = ApplicationLayerHandler::with_config(1234, &config);
+        handler.set_read_property_handler(|_| Ok(vec![0u8; 100]));
+        let response = handler.process_apdu(&read_property(false), &[]).unwrap();
+        assert!(matches!(response, Some(Apdu::ComplexAck { .. })));
+    }
+
+    #[test]
+    fn test_segmented_complex_ack_reassembly() {
+        use std::sync::{Arc, Mutex};
+
+        let segment = |sequence_number: u8, more_follows, service_data: &[u8]| Apdu::ComplexAck {
+            segmented: true,
+            more_follows,
+            invoke_id: 9,
+            sequence_number: Some(sequence_number),
+            proposed_window_size: Some(1),
+            service_choice: ConfirmedServiceChoice::ReadProperty,
+            service_data: service_data.to_vec(),
+        };
+
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let mut handler = ApplicationLayerHandler::new(1234);
+        let sink = received.clone();
+        handler.set_complex_ack_handler(move |invoke_id, service_choice, service_data| {
+            sink.lock()
+                .unwrap()
+                .push((invoke_id, service_choice, service_data.to_vec()));
+        });
+        handler
+            .transaction_manager
+            .start_transaction(9, 12)
+            .unwrap();
+
+        // The first segment is acknowledged but does not end the transaction
+        let response = handler
+            .process_apdu(&segment(0, true, &[1, 2]), &[])
+            .unwrap();
+        assert!(matches!(
+            response,
+            Some(Apdu::SegmentAck {
+                negative: false,
+                server: false,
+                invoke_id: 9,
+                sequence_number: 0,
+                window_size: 1,
+            })
+        ));
+        assert_eq!(handler.transaction_manager.active_count(), 1);
+        assert!(received.lock().unwrap().is_empty());
+        assert_eq!(handler.stats.complex_acks, 0);
+
+        let response = handler.process_apdu(&segment(1, false, &[3]), &[]).unwrap();
+        assert!(matches!(
+            response,
+            Some(Apdu::SegmentAck {
+                sequence_number: 1,
+                ..
+            })
+        ));
+        assert_eq!(handler.transaction_manager.active_count(), 0);
+        assert_eq!(handler.stats.complex_acks, 1);
+        assert_eq!(
+            *received.lock().unwrap(),
+            [(9, ConfirmedServiceChoice::ReadProperty, vec![1, 2, 3])]
+        );
+
+        // An unsegmented ComplexAck is passed on as is
+        let response = handler
+            .process_apdu(
+                &Apdu::ComplexAck {
+                    segmented: false,
+                    more_follows: false,
+                    invoke_id: 10,
+                    sequence_number: None,
+                    proposed_window_size: None,
+                    service_choice: ConfirmedServiceChoice::ReadProperty,
+                    service_data: vec![4],
+                },
+                &[],
+            )
+            .unwrap();
+        assert!(response.is_none());
+        assert_eq!(received.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_segmented_complex_ack_errors() {
+        let segment = |invoke_id, sequence_number: u8, more_follows, length| Apdu::ComplexAck {
+            segmented: true,
+            more_follows,
+            invoke_id,
+            sequence_number: Some(sequence_number),
+            proposed_window_size: Some(1),
+            service_choice: ConfirmedServiceChoice::ReadProperty,
+            service_data: vec![0; length],
+        };
+
+        let config = ApplicationConfig {
+            max_segments: 2,
+            ..Default::default()
+        };
+        let mut handler = ApplicationLayerHandler::with_config(1234, &config);
+        assert_eq!(handler.max_response_length, 2 * 1476);
+        let length = handler.max_apdu_length;
+
+        // Segments for no outstanding transaction are ignored
+        let response = handler.process_apdu(&segment(4, 0, true, 10), &[]).unwrap();
+        assert!(response.is_none());
+        assert_eq!(handler.segmentation_manager.active_reassemblies(), 0);
+
+        // A reassembled response larger than the limit aborts the transaction
+        handler
+            .transaction_manager
+            .start_transaction(4, 12)
+            .unwrap();
+        for sequence_number in 0..2 {
+            handler
+                .process_apdu(&segment(4, sequence_number, true, length), &[])
+                .unwrap();
+        }
+        let response = handler
+            .process_apdu(&segment(4, 2, false, length), &[])
+            .unwrap();
+        assert!(matches!(
+            response,
+            Some(Apdu::Abort {
+                server: false,
+                invoke_id: 4,
+                abort_reason,
+            }) if abort_reason == u8::from(AbortReason::BufferOverflow)
+        ));
+        assert_eq!(handler.stats.segmentation_errors, 1);
+        assert_eq!(handler.segmentation_manager.active_reassemblies(), 0);
+        assert_eq!(handler.transaction_manager.active_count(), 0);
+
+        // Later segments of the aborted transaction are ignored
+        let response = handler
+            .process_apdu(&segment(4, 3, false, 10), &[])
+            .unwrap();
+        assert!(response.is_none());
+    }
+
+    #[test]
+    fn test_segmented_complex_ack_larger_than_apdu() {
+        use std::sync::{Arc, Mutex};
+
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let mut handler = ApplicationLayerHandler::with_config(1234, &ApplicationConfig::default());
+        let sink = received.clone();
+        handler.set_complex_ack_handler(move |_, _, service_data| {
+            sink.lock().unwrap().push(service_data.to_vec());
+        });
+        handler
+            .transaction_manager
+            .start_transaction(7, 12)
+            .unwrap();
+
+        // Eight full segments reassemble to about 11.8 kB
+        let length = handler.max_apdu_length;
+        for sequence_number in 0..8u8 {
+            let response = handler
+                .process_apdu(
+                    &Apdu::ComplexAck {
+                        segmented: true,
+                        more_follows: sequence_number < 7,
+                        invoke_id: 7,
+                        sequence_number: Some(sequence_number),
+                        proposed_window_size: Some(1),
+                        service_choice: ConfirmedServiceChoice::ReadPropertyMultiple,
+                        service_data: vec![sequence_number; length],
+                    },
+                    &[],
+                )
+                .unwrap();
+            assert!(matches!(
+                response,
+                Some(Apdu::SegmentAck {
+                    negative: false,
+                    ..
+                })
+            ));
+        }
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].len(), 8 * length);
+        assert_eq!(received[0][7 * length], 7);
+        assert_eq!(handler.stats.segmentation_errors, 0);
+    }
+
+    #[test]
+    fn test_confirmed_service_dispatch() {
+        let request = |service_choice, service_data: &[u8]| Apdu::ConfirmedRequest {
+            segmented: false,
+            more_follows: false,
+            segmented_response_accepted: false,
+            max_segments: MaxSegments::Unspecified,
+            max_response_size: MaxApduSize::Up480,
+            invoke_id: 3,
+            sequence_number: None,
+            proposed_window_size: None,
+            service_choice,
+            service_data: service_data.to_vec(),
+        };
+        let write = request(
+            ConfirmedServiceChoice::WriteProperty,
+            &[
+                0x0C, 0x00, 0x80, 0x00, 0x01, 0x19, 0x55, 0x3E, 0x44, 0x42, 0x48, 0x00, 0x00, 0x3F,
+            ],
+        );
+
+        let mut handler = ApplicationLayerHandler::new(1234);
+
+        // Supported but no processor registered
+        assert!(matches!(
+            handler.process_apdu(&write, &[]).unwrap(),
+            Some(Apdu::Abort { invoke_id: 3, .. })
+        ));
+
+        let written = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = written.clone();
+        handler.set_write_property_handler(move |data| {
+            sink.lock().unwrap().extend_from_slice(data);
+            Ok(())
+        });
+        let response = handler.process_apdu(&write, &[]).unwrap();
+        assert!(matches!(
+            response,
+            Some(Apdu::SimpleAck {
+                invoke_id: 3,
+                service_choice,
+            }) if service_choice == ConfirmedServiceChoice::WriteProperty as u8
+        ));
+        let Apdu::ConfirmedRequest { service_data, .. } = &write else {
+            unreachable!()
+        };
+        assert_eq!(*written.lock().unwrap(), *service_data);
+
+        handler.set_write_property_handler(|_| {
+            Err(ApplicationError::ErrorResponse(
+                ErrorClass::Property,
+                ErrorCode::WriteAccessDenied,
+            ))
+        });
+        assert!(matches!(
+            handler.process_apdu(&write, &[]).unwrap(),
+            Some(Apdu::Error {
+                error_class: ErrorClass::Property,
+                error_code: ErrorCode::WriteAccessDenied,
+                ..
+            })
+        ));
+
+        handler.set_read_property_multiple_handler(|_| Ok(vec![0x0C]));
+        let response = handler
+            .process_apdu(
+                &request(ConfirmedServiceChoice::ReadPropertyMultiple, &[]),
+                &[],
+            )
+            .unwrap();
+        assert!(matches!(
+            response,
+            Some(Apdu::ComplexAck {
+                service_choice: ConfirmedServiceChoice::ReadPropertyMultiple,
+                ..
+            })
+        ));
+
+        handler.set_subscribe_cov_handler(|_| Ok(()));
+        let response = handler
+            .process_apdu(&request(ConfirmedServiceChoice::SubscribeCOV, &[]), &[])
+            .unwrap();
+        assert!(matches!(
+            response,
+            Some(Apdu::SimpleAck { service_choice, .. })
+                if service_choice == ConfirmedServiceChoice::SubscribeCOV as u8
+        ));
+    }
+
+    #[test]
+    fn test_decode_bacnet_message() {
+        use crate::network::{NetworkAddress, NetworkMessageType};
+
+        // Who-Is for devices 100-200
+        let who_is = [0x01, 0x00, 0x10, 0x08, 0x09, 0x64, 0x19, 0xC8];
+        match decode_bacnet_message(&who_is).unwrap() {
+            BacnetMessage::Application { npdu, apdu } => {
+                assert!(!npdu.is_network_message());
+                assert!(matches!(
+                    apdu,
+                    Apdu::UnconfirmedRequest {
+                        service_choice: UnconfirmedServiceChoice::WhoIs,
+                        ref service_data,
+                    } if service_data == &[0x09, 0x64, 0x19, 0xC8]
+                ));
+            }
+            other => panic!("expected an APDU, got {:?}", other),
+        }
+
+        // Who-Is-Router-To-Network for network 5
+        let who_is_router = [0x01, 0x80, 0x00, 0x00, 0x05];
+        match decode_bacnet_message(&who_is_router).unwrap() {
+            BacnetMessage::Network { npdu, message } => {
+                assert!(npdu.is_network_message());
+                assert_eq!(
+                    message.message_type,
+                    NetworkMessageType::WhoIsRouterToNetwork
+                );
+                assert_eq!(message.data(), Some(&[0x00, 0x05][..]));
+            }
+            other => panic!("expected a network message, got {:?}", other),
+        }
+
+        // I-Am-Router-To-Network for network 5, routed from network 7 MAC 0x0A
+        let i_am_router = [0x01, 0x88, 0x00, 0x07, 0x01, 0x0A, 0x01, 0x00, 0x05];
+        match decode_bacnet_message(&i_am_router).unwrap() {
+            BacnetMessage::Network { npdu, message } => {
+                assert_eq!(
+                    npdu.source,
+                    Some(NetworkAddress {
+                        network: 7,
+                        address: vec![0x0A],
+                    })
+                );
+                assert_eq!(message.message_type, NetworkMessageType::IAmRouterToNetwork);
+            }
+            other => panic!("expected a network message, got {:?}", other),
+        }
+
+        assert!(matches!(
+            decode_bacnet_message(&[0x01]),
+            Err(ApplicationError::Network(_))
+        ));
+        assert!(matches!(
+            decode_bacnet_message(&[0x01, 0x00]),
+            Err(ApplicationError::InvalidApdu(_))
+        ));
+    }
+
+    #[test]
+    fn test_error_response_for_missing_object() {
+        let request = Apdu::ConfirmedRequest {
+            segmented: false,
+            more_follows: false,
+            segmented_response_accepted: false,
+            max_segments: MaxSegments::Unspecified,
+            max_response_size: MaxApduSize::Up480,
+            invoke_id: 9,
+            sequence_number: None,
+            proposed_window_size: None,
+            service_choice: ConfirmedServiceChoice::ReadProperty,
+            service_data: vec![0x0C, 0x00, 0x00, 0x00, 0x01, 0x19, 0x55],
+        };
+
+        let mut handler = ApplicationLayerHandler::new(1234);
+        handler.set_read_property_handler(|_| {
+            Err(ApplicationError::ErrorResponse(
+                ErrorClass::Object,
+                ErrorCode::UnknownObject,
+            ))
+        });
+        let response = handler.process_apdu(&request, &[]).unwrap().unwrap();
+        assert!(matches!(
+            response,
+            Apdu::Error {
+                invoke_id: 9,
+                service_choice: ConfirmedServiceChoice::ReadProperty,
+                error_class: ErrorClass::Object,
+                error_code: ErrorCode::UnknownObject,
+            }
+        ));
+
+        // The PDU survives a round trip
+        let decoded = Apdu::decode(&response.encode()).unwrap();
+        assert!(matches!(
+            decoded,
+            Apdu::Error {
+                error_class: ErrorClass::Object,
+                error_code: ErrorCode::UnknownObject,
+                ..
+            }
+        ));
+
+        // Vendor codes above 255 keep their full value
+        let vendor = encode_error_response(
+            9,
+            ConfirmedServiceChoice::ReadProperty,
+            ErrorClass::Property,
+            ErrorCode::from(300u16),
+        );
+        let encoded = vendor.encode();
+        assert_eq!(&encoded[3..], [0x91, 0x02, 0x92, 0x01, 0x2C]);
+        match Apdu::decode(&encoded).unwrap() {
+            Apdu::Error { error_code, .. } => assert_eq!(u16::from(error_code), 300),
+            other => panic!("unexpected APDU {other:?}"),
+        }
+
+        // Other processor failures are reported as a generic service error
+        handler.set_read_property_handler(|_| Err(ApplicationError::Timeout));
+        assert!(matches!(
+            handler.process_apdu(&request, &[]).unwrap(),
+            Some(Apdu::Error {
+                error_class: ErrorClass::Services,
+                error_code: ErrorCode::Other,
+                ..
+            })
+        ));
+    }
 }
@@ -0,0 +1,350 @@
+//! Asynchronous BACnet client for Tokio.
+//!
+//! [`AsyncBacnetClient`] offers the core operations of
+//! [`BacnetClient`](super::BacnetClient) as futures. A background task owns
+//! the receive side of the socket and hands every reply to the request that is
+//! waiting for it, looked up by peer and invoke ID in a shared pending-request
+//! map. Any number of requests can therefore be in flight on one client.
+//!
+//! Frames are built and interpreted by the same code as the blocking client,
+//! so both report failures with the same [`ClientError`] variants. Each
+//! operation takes its own timeout; confirmed requests that time out are
+//! resent up to [`AsyncBacnetClient::set_retries`] times.
+//!
+//! ```no_run
+//! use bacnet_rs::client::AsyncBacnetClient;
+//! use bacnet_rs::object::{ObjectIdentifier, ObjectType, PropertyIdentifier};
+//! use std::time::Duration;
+//!
+//! # async fn example() -> Result<(), bacnet_rs::client::ClientError> {
+//! let client = AsyncBacnetClient::bind("0.0.0.0:0").await?;
+//! let target = "192.168.1.100:47808".parse().unwrap();
+//! let timeout = Duration::from_secs(3);
+//!
+//! let device = client.discover_device(target, timeout).await?;
+//! let object = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+//! let values = client
+//!     .read_property(target, object, PropertyIdentifier::PresentValue, timeout)
+//!     .await?;
+//! println!("{}: {values:?}", device.device_id);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use tokio::net::{ToSocketAddrs, UdpSocket};
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+
+use super::{
+    transaction::{attempt_timeout, CorrelationKey},
+    BacnetClient, ClientError, DeviceInfo, BVLC_ORIGINAL_UNICAST,
+};
+use crate::{
+    app::{MaxApduSize, PeerInvokeIdManager},
+    object::{ObjectIdentifier, PropertyIdentifier},
+    property::PropertyValue,
+    service::{
+        ConfirmedServiceChoice, ReadPropertyRequest, ReadPropertyResponse,
+        UnconfirmedServiceChoice, WhoIsRequest,
+    },
+};
+
+/// Operations waiting for a frame from the receive task.
+#[derive(Default)]
+struct Waiters {
+    /// Confirmed requests, keyed by peer and invoke ID.
+    confirmed: HashMap<CorrelationKey, oneshot::Sender<Vec<u8>>>,
+    /// Discoveries waiting for an I-Am from the given address.
+    i_am: Vec<(SocketAddr, oneshot::Sender<DeviceInfo>)>,
+}
+
+/// Shared pending-request map.
+type SharedWaiters = Arc<Mutex<Waiters>>;
+
+fn lock(waiters: &SharedWaiters) -> MutexGuard<'_, Waiters> {
+    // The map holds no invariants a panicking holder could break.
+    waiters
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// High-level asynchronous BACnet client.
+///
+/// Must be created inside a Tokio runtime. The receive task is stopped when
+/// the client is dropped.
+pub struct AsyncBacnetClient {
+    socket: Arc<UdpSocket>,
+    /// Invoke IDs of the confirmed requests in flight, per peer.
+    invoke_ids: Mutex<PeerInvokeIdManager<SocketAddr>>,
+    /// Number of times a confirmed request is resent after a timeout.
+    retries: u8,
+    waiters: SharedWaiters,
+    receiver: JoinHandle<()>,
+}
+
+/// Removes a confirmed request from the pending map and frees its invoke ID
+/// when the request completes, times out or is cancelled.
+struct PendingGuard<'a> {
+    client: &'a AsyncBacnetClient,
+    peer: SocketAddr,
+    invoke_id: u8,
+}
+
+impl PendingGuard<'_> {
+    fn key(&self) -> CorrelationKey {
+        CorrelationKey::Confirmed {
+            peer: self.peer,
+            invoke_id: self.invoke_id,
+        }
+    }
+}
+
+impl Drop for PendingGuard<'_> {
+    fn drop(&mut self) {
+        lock(&self.client.waiters).confirmed.remove(&self.key());
+        self.client
+            .invoke_ids()
+            .release_id(&self.peer, self.invoke_id);
+    }
+}
+
+impl AsyncBacnetClient {
+    /// Bind a client to the given local address and start its receive task.
+    pub async fn bind<A: ToSocketAddrs>(addr: A) -> Result<Self, ClientError> {
+        let socket = Arc::new(UdpSocket::bind(addr).await?);
+        let waiters = SharedWaiters::default();
+        let receiver = tokio::spawn(receive_loop(socket.clone(), waiters.clone()));
+
+        Ok(Self {
+            socket,
+            invoke_ids: Mutex::new(PeerInvokeIdManager::new()),
+            retries: 0,
+            waiters,
+            receiver,
+        })
+    }
+
+    /// Set how many times a confirmed request is resent after a timeout
+    /// (default `0`).
+    ///
+    /// Resends keep the invoke ID of the original request, and each one waits
+    /// twice as long as the attempt before, up to eight times the timeout
+    /// passed to the operation.
+    pub fn set_retries(&mut self, retries: u8) {
+        self.retries = retries;
+    }
+
+    /// The local socket address the client is bound to.
+    pub fn local_addr(&self) -> Result<SocketAddr, ClientError> {
+        Ok(self.socket.local_addr()?)
+    }
+
+    /// Discover a device by IP address.
+    ///
+    /// Sends a Who-Is to `target_addr` and resolves with the first I-Am that
+    /// comes back from it.
+    pub async fn discover_device(
+        &self,
+        target_addr: SocketAddr,
+        timeout: Duration,
+    ) -> Result<DeviceInfo, ClientError> {
+        let mut buffer = Vec::new();
+        WhoIsRequest::new().encode(&mut buffer)?;
+        let message = BacnetClient::create_unconfirmed_bvlc(
+            UnconfirmedServiceChoice::WhoIs,
+            &buffer,
+            BVLC_ORIGINAL_UNICAST,
+            MaxApduSize::Up1476.size(),
+        )?;
+
+        let (sender, receiver) = oneshot::channel();
+        {
+            let mut waiters = lock(&self.waiters);
+            // Drop discoveries that have already given up.
+            waiters.i_am.retain(|(_, sender)| !sender.is_closed());
+            waiters.i_am.push((target_addr, sender));
+        }
+
+        self.socket.send_to(&message, target_addr).await?;
+
+        match tokio::time::timeout(timeout, receiver).await {
+            Ok(Ok(device)) => Ok(device),
+            Ok(Err(_)) => Err(ClientError::NoResponse),
+            Err(_) => Err(ClientError::Timeout),
+        }
+    }
+
+    /// Read a property of an object and return all decoded values.
+    ///
+    /// See [`BacnetClient::read_property`].
+    pub async fn read_property(
+        &self,
+        target_addr: SocketAddr,
+        object: ObjectIdentifier,
+        property: PropertyIdentifier,
+        timeout: Duration,
+    ) -> Result<Vec<PropertyValue>, ClientError> {
+        let request = ReadPropertyRequest::new(object, property);
+        let mut service_data = Vec::new();
+        request.encode(&mut service_data)?;
+
+        let response_data = self
+            .send_confirmed_request(
+                target_addr,
+                ConfirmedServiceChoice::ReadProperty,
+                &service_data,
+                timeout,
+            )
+            .await?;
+
+        Ok(ReadPropertyResponse::decode(&response_data)?.property_values)
+    }
+
+    /// Write a single property of an object.
+    ///
+    /// See [`BacnetClient::write_property`].
+    pub async fn write_property(
+        &self,
+        target_addr: SocketAddr,
+        object: ObjectIdentifier,
+        property: PropertyIdentifier,
+        value: &PropertyValue,
+        priority: Option<u8>,
+        timeout: Duration,
+    ) -> Result<(), ClientError> {
+        let service_data = BacnetClient::encode_write_property(object, property, value, priority)?;
+
+        self.send_confirmed_request(
+            target_addr,
+            ConfirmedServiceChoice::WriteProperty,
+            &service_data,
+            timeout,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Read the device's object list.
+    ///
+    /// See [`BacnetClient::read_object_list`].
+    pub async fn read_object_list(
+        &self,
+        target_addr: SocketAddr,
+        device_id: u32,
+        timeout: Duration,
+    ) -> Result<Vec<ObjectIdentifier>, ClientError> {
+        let response_data = self
+            .send_confirmed_request(
+                target_addr,
+                ConfirmedServiceChoice::ReadPropertyMultiple,
+                &BacnetClient::encode_object_list_request(device_id)?,
+                timeout,
+            )
+            .await?;
+
+        Ok(BacnetClient::object_list_from_response(&response_data))
+    }
+
+    /// Send a confirmed request and wait for the matching response.
+    ///
+    /// Returns the ComplexAck service data, an empty `Vec` for a SimpleAck, or
+    /// a typed [`ClientError`] for Error/Reject/Abort and when every attempt
+    /// times out.
+    async fn send_confirmed_request(
+        &self,
+        target_addr: SocketAddr,
+        service_choice: ConfirmedServiceChoice,
+        service_data: &[u8],
+        timeout: Duration,
+    ) -> Result<Vec<u8>, ClientError> {
+        let invoke_id = self
+            .invoke_ids()
+            .next_id(&target_addr)
+            .ok_or(ClientError::InvokeIdsExhausted)?;
+        let guard = PendingGuard {
+            client: self,
+            peer: target_addr,
+            invoke_id,
+        };
+
+        // Without a device cache every peer is assumed to accept 1476 octets
+        let message = BacnetClient::encode_confirmed_request(
+            invoke_id,
+            service_choice,
+            service_data,
+            MaxApduSize::Up1476.size(),
+        )?;
+
+        let (sender, mut receiver) = oneshot::channel();
+        lock(&self.waiters).confirmed.insert(guard.key(), sender);
+
+        // The first reply to any attempt completes the request; the receive
+        // task drops duplicates since the request is no longer pending.
+        let mut attempt = 0;
+        let frame = loop {
+            self.socket.send_to(&message, target_addr).await?;
+
+            match tokio::time::timeout(attempt_timeout(timeout, attempt), &mut receiver).await {
+                Ok(Ok(frame)) => break frame,
+                Ok(Err(_)) => return Err(ClientError::NoResponse),
+                Err(_) if attempt < self.retries => attempt += 1,
+                Err(_) => return Err(ClientError::Timeout),
+            }
+        };
+
+        BacnetClient::interpret_confirmed_response(&frame, invoke_id)?
+            .ok_or(ClientError::NoResponse)
+    }
+
+    fn invoke_ids(&self) -> MutexGuard<'_, PeerInvokeIdManager<SocketAddr>> {
+        // The manager holds no invariants a panicking holder could break.
+        self.invoke_ids
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Drop for AsyncBacnetClient {
+    fn drop(&mut self) {
+        self.receiver.abort();
+    }
+}
+
+/// Receive frames and hand each one to the operation waiting for it.
+///
+/// Frames nobody is waiting for, such as late replies to requests that timed
+/// out, are dropped.
+async fn receive_loop(socket: Arc<UdpSocket>, waiters: SharedWaiters) {
+    let mut buffer = [0u8; 1500];
+
+    loop {
+        let (len, source) = match socket.recv_from(&mut buffer).await {
+            Ok(received) => received,
+            // Errors such as an ICMP port unreachable from an earlier send
+            // only affect that exchange.
+            Err(_) => continue,
+        };
+        let frame = &buffer[..len];
+
+        if let Some(key) = BacnetClient::correlation_key(frame, source) {
+            if let Some(sender) = lock(&waiters).confirmed.remove(&key) {
+                let _ = sender.send(frame.to_vec());
+            }
+        } else if let Some(device) = BacnetClient::parse_iam_response(frame, source) {
+            let mut waiters = lock(&waiters);
+            for (addr, sender) in std::mem::take(&mut waiters.i_am) {
+                if addr == source {
+                    let _ = sender.send(device.clone());
+                } else {
+                    waiters.i_am.push((addr, sender));
+                }
+            }
+        }
+    }
+}
@@ -46,8 +46,8 @@ pub struct ClientConfig {
     pub timeout: Duration,
     /// Number of times to retry a request after the first attempt times out.
     ///
-    /// Currently stored for use by later request paths; the existing methods
-    /// do not yet retry.
+    /// A confirmed request that times out is resent with the same invoke ID,
+    /// waiting longer after each resend.
     pub retries: u8,
 }
 
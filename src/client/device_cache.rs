@@ -0,0 +1,131 @@
+//! Devices the client has discovered.
+//!
+//! [`BacnetClient`](super::BacnetClient) records every device that answers a
+//! Who-Is in a [`DeviceCache`], keyed by device instance. The device's
+//! Object_Name is filled in later, when the client reads it, so a device can
+//! then be found by name instead of by address.
+
+use std::{collections::BTreeMap, net::SocketAddr};
+
+use super::DeviceInfo;
+
+/// Discovered devices, keyed by device instance.
+#[derive(Debug, Clone, Default)]
+pub struct DeviceCache {
+    devices: BTreeMap<u32, DeviceInfo>,
+}
+
+impl DeviceCache {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a device from an I-Am.
+    ///
+    /// Replaces any earlier entry for the same instance, keeping its object
+    /// name when the new entry does not carry one.
+    pub fn insert(&mut self, mut device: DeviceInfo) {
+        if let Some(existing) = self.devices.get(&device.device_id) {
+            if device.object_name.is_none() {
+                device.object_name = existing.object_name.clone();
+            }
+        }
+        self.devices.insert(device.device_id, device);
+    }
+
+    /// Record the Object_Name read from a device.
+    ///
+    /// Returns `false` if the device has not been discovered.
+    pub fn set_object_name(&mut self, device_id: u32, name: impl Into<String>) -> bool {
+        match self.devices.get_mut(&device_id) {
+            Some(device) => {
+                device.object_name = Some(name.into());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Find a device by its Object_Name.
+    pub fn find_by_name(&self, name: &str) -> Option<&DeviceInfo> {
+        self.devices
+            .values()
+            .find(|device| device.object_name.as_deref() == Some(name))
+    }
+
+    /// Find a device by its device instance.
+    pub fn find_by_instance(&self, device_id: u32) -> Option<&DeviceInfo> {
+        self.devices.get(&device_id)
+    }
+
+    /// Find a device by its B/IP address.
+    pub fn find_by_address(&self, address: SocketAddr) -> Option<&DeviceInfo> {
+        self.devices
+            .values()
+            .find(|device| device.address == address)
+    }
+
+    /// Iterate over every cached device, in ascending instance order.
+    pub fn all(&self) -> impl Iterator<Item = &DeviceInfo> {
+        self.devices.values()
+    }
+
+    /// Number of cached devices.
+    pub fn len(&self) -> usize {
+        self.devices.len()
+    }
+
+    /// Check if no device has been cached.
+    pub fn is_empty(&self) -> bool {
+        self.devices.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::Segmentation;
+
+    fn device(device_id: u32, object_name: Option<&str>) -> DeviceInfo {
+        DeviceInfo {
+            device_id,
+            address: "127.0.0.1:47808".parse().unwrap(),
+            vendor_id: 260,
+            vendor_name: "BACnet Stack at SourceForge".to_string(),
+            max_apdu: 1476,
+            segmentation: Segmentation::Both,
+            object_name: object_name.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn cache_finds_devices_by_name_and_instance() {
+        let mut cache = DeviceCache::new();
+        cache.insert(device(20, None));
+        cache.insert(device(10, Some("AHU-1")));
+        assert!(cache.set_object_name(20, "Boiler"));
+        assert!(!cache.set_object_name(30, "Chiller"));
+
+        assert_eq!(cache.find_by_name("Boiler").unwrap().device_id, 20);
+        assert_eq!(
+            cache.find_by_instance(10).unwrap().object_name.as_deref(),
+            Some("AHU-1")
+        );
+        assert!(cache.find_by_name("Chiller").is_none());
+        assert!(cache
+            .find_by_address("127.0.0.1:47808".parse().unwrap())
+            .is_some());
+        assert!(cache
+            .find_by_address("127.0.0.1:47809".parse().unwrap())
+            .is_none());
+
+        // A repeated I-Am keeps the name read earlier.
+        cache.insert(device(20, None));
+        assert_eq!(cache.find_by_name("Boiler").unwrap().device_id, 20);
+
+        let ids: Vec<u32> = cache.all().map(|device| device.device_id).collect();
+        assert_eq!(ids, [10, 20]);
+        assert_eq!(cache.len(), 2);
+    }
+}
@@ -5,8 +5,9 @@
 //! lets callers match on specific failure modes (timeouts, protocol-level
 //! rejects/aborts, per-property errors, etc.) instead of inspecting strings.
 
+use crate::app::ApplicationError;
 use crate::encoding::EncodingError;
-use crate::service::{AbortReason, RejectReason};
+use crate::service::{AbortReason, ErrorClass, ErrorCode, RejectReason};
 use thiserror::Error;
 
 /// Errors that can occur while using the high-level [`BacnetClient`](super::BacnetClient).
@@ -21,6 +22,11 @@ pub enum ClientError {
     #[error("encoding error: {0}")]
     Encoding(#[from] EncodingError),
 
+    /// A request could not be framed at the application layer, e.g. it is
+    /// larger than the maximum unsegmented APDU.
+    #[error("application layer error: {0}")]
+    Application(#[from] ApplicationError),
+
     /// A response was malformed or could not be interpreted.
     #[error("failed to decode response: {0}")]
     Decode(String),
@@ -46,64 +52,33 @@ pub enum ClientError {
     #[error("{}", describe_bacnet_error(*class, *code))]
     PropertyError {
         /// BACnet error class.
-        class: u32,
+        class: ErrorClass,
         /// BACnet error code.
-        code: u32,
+        code: ErrorCode,
     },
 
+    /// Every invoke ID for the peer is taken by a request that is still
+    /// outstanding.
+    #[error("no free invoke ID")]
+    InvokeIdsExhausted,
+
     /// A supplied address could not be parsed or resolved.
     #[error("invalid address: {0}")]
     AddressParse(String),
 }
 
-/// Human-readable name for a BACnet error class (ASHRAE 135 `BACnetErrorClass`).
-fn error_class_name(class: u32) -> Option<&'static str> {
-    Some(match class {
-        0 => "device",
-        1 => "object",
-        2 => "property",
-        3 => "resources",
-        4 => "security",
-        5 => "services",
-        6 => "vt",
-        7 => "communication",
-        _ => return None,
-    })
-}
-
-/// Human-readable name for the common BACnet error codes (ASHRAE 135
-/// `BACnetErrorCode`). Not exhaustive — unknown codes fall back to the number.
-fn error_code_name(code: u32) -> Option<&'static str> {
-    Some(match code {
-        0 => "other",
-        9 => "invalid-data-type",
-        20 => "no-space-to-write-property",
-        23 => "object-deletion-not-permitted",
-        27 => "read-access-denied",
-        29 => "service-request-denied",
-        30 => "timeout",
-        31 => "unknown-object",
-        32 => "unknown-property",
-        37 => "value-out-of-range",
-        40 => "write-access-denied",
-        44 => "not-cov-property",
-        45 => "optional-functionality-not-supported",
-        47 => "datatype-not-supported",
-        50 => "property-is-not-an-array",
-        70 => "unknown-device",
-        _ => return None,
-    })
-}
-
 /// Render a BACnet error class/code pair, naming both where known and always
 /// including the raw numbers, e.g. `write-access-denied (class property[2], code 40)`.
-fn describe_bacnet_error(class: u32, code: u32) -> String {
-    let class_label = match error_class_name(class) {
-        Some(name) => format!("{name}[{class}]"),
-        None => format!("{class}"),
+fn describe_bacnet_error(class: ErrorClass, code: ErrorCode) -> String {
+    let class_label = match class {
+        ErrorClass::Custom(_) | ErrorClass::Reserved(_) => format!("{}", u16::from(class)),
+        _ => format!("{}[{}]", class.name(), u16::from(class)),
     };
-    match error_code_name(code) {
-        Some(name) => format!("{name} (class {class_label}, code {code})"),
-        None => format!("BACnet error (class {class_label}, code {code})"),
+    let code_number = u16::from(code);
+    match code {
+        ErrorCode::Custom(_) | ErrorCode::Reserved(_) => {
+            format!("BACnet error (class {class_label}, code {code_number})")
+        }
+        _ => format!("{} (class {class_label}, code {code_number})", code.name()),
     }
 }
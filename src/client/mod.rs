@@ -7,19 +7,30 @@
 //! for defaults, or with [`BacnetClient::builder`] to customize the local
 //! interface, port, timeout, and retries. All methods return [`ClientError`] on
 //! failure.
+//!
+//! With the `async` feature, [`AsyncBacnetClient`] provides the same core
+//! operations as futures on a Tokio runtime.
 
+#[cfg(feature = "async")]
+mod async_client;
 mod config;
+mod device_cache;
 mod error;
 mod transaction;
 
+#[cfg(feature = "async")]
+pub use async_client::AsyncBacnetClient;
 pub use config::{ClientBuilder, ClientConfig, DEFAULT_HOST, DEFAULT_TIMEOUT};
+pub use device_cache::DeviceCache;
 pub use error::ClientError;
 
-use transaction::InvokeIdAllocator;
+use transaction::{attempt_timeout, CorrelationKey, InvokeIdAllocator, ReorderBuffer};
 
 #[cfg(feature = "std")]
 use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
 #[cfg(feature = "std")]
+use std::sync::Mutex;
+#[cfg(feature = "std")]
 use std::time::{Duration, Instant};
 
 #[cfg(not(feature = "std"))]
@@ -28,15 +39,16 @@ use alloc::{collections::BTreeMap as HashMap, string::String, vec::Vec};
 use crate::{
     app::{Apdu, MaxApduSize, MaxSegments},
     datalink::bip::BACNET_IP_PORT,
-    encoding::decode_object_identifier,
+    encoding::{decode_context_unsigned, decode_object_identifier},
     network::Npdu,
     object::{EngineeringUnits, ObjectIdentifier, ObjectType, PropertyIdentifier, Segmentation},
-    property::{encode_property_value, PropertyValue},
+    property::{decode_known, encode_property_value, PropertyValue},
     service::{
-        AbortReason, ConfirmedServiceChoice, IAmRequest, PropertyReference, PropertyResultValue,
-        ReadAccessResult, ReadAccessSpecification, ReadPropertyMultipleRequest,
-        ReadPropertyMultipleResponse, ReadPropertyRequest, ReadPropertyResponse,
-        UnconfirmedServiceChoice, WhoIsRequest, WritePropertyRequest,
+        AbortReason, ConfirmedServiceChoice, ErrorClass, ErrorCode, IAmRequest, LogRecord,
+        PropertyReference, PropertyResultValue, ReadAccessResult, ReadAccessSpecification,
+        ReadPropertyMultipleRequest, ReadPropertyMultipleResponse, ReadPropertyRequest,
+        ReadPropertyResponse, ReadRangeRequest, ReadRangeResponse, UnconfirmedServiceChoice,
+        WhoIsRequest, WritePropertyRequest,
     },
 };
 
@@ -45,17 +57,50 @@ const BVLC_ORIGINAL_UNICAST: u8 = 0x0A;
 /// BVLC function code: Original-Broadcast-NPDU (local subnet broadcast).
 const BVLC_ORIGINAL_BROADCAST: u8 = 0x0B;
 
+/// Largest response this client accepts, advertised in every confirmed
+/// request.
+const MAX_RESPONSE_SIZE: MaxApduSize = MaxApduSize::Up1476;
+/// Most Object_List entries reserved up front, whatever length the device
+/// reports.
+const OBJECT_LIST_PREALLOCATION: usize = 4096;
+
+/// ComplexAck bytes of a Read Range response other than its items.
+const READ_RANGE_OVERHEAD: usize = 24;
+/// ComplexAck bytes of a ReadPropertyMultiple response other than its
+/// per-index results.
+const RPM_OVERHEAD: usize = 10;
+/// Encoded size of one Object_List entry in a Read Range response.
+const OBJECT_ID_SIZE: usize = 5;
+/// Encoded size of one Object_List entry read by array index in a
+/// ReadPropertyMultiple response.
+const INDEXED_OBJECT_ID_SIZE: usize = 12;
+
 /// High-level BACnet client for device communication
 #[cfg(feature = "std")]
 pub struct BacnetClient {
     socket: UdpSocket,
     timeout: Duration,
-    /// Number of retries after an initial timeout (reserved for future use by
-    /// the request paths; not yet applied by the existing methods).
-    #[allow(dead_code)]
+    /// Number of times a confirmed request is resent after a timeout.
     retries: u8,
     /// Allocates invoke IDs for confirmed-request transactions.
     invoke_ids: InvokeIdAllocator,
+    /// Replies and COV notifications that arrived while another operation was
+    /// being waited on.
+    reorder: Mutex<ReorderBuffer>,
+    /// Devices discovered by Who-Is, with names learned from later reads.
+    devices: Mutex<DeviceCache>,
+}
+
+/// A confirmed request that has been sent and is awaiting its reply.
+///
+/// Returned by [`BacnetClient::start_confirmed_request`]; pass it to
+/// [`BacnetClient::wait_for_response`] to collect the result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PendingRequest {
+    /// Address the request was sent to.
+    pub peer: SocketAddr,
+    /// Invoke ID the reply will carry.
+    pub invoke_id: u8,
 }
 
 /// Discovered BACnet device information
@@ -67,6 +112,8 @@ pub struct DeviceInfo {
     pub vendor_name: String,
     pub max_apdu: u32,
     pub segmentation: Segmentation,
+    /// Object_Name of the device object, once the client has read it
+    pub object_name: Option<String>,
 }
 
 /// Object information with common properties
@@ -80,6 +127,15 @@ pub struct ObjectInfo {
     pub status_flags: Option<Vec<bool>>,
 }
 
+impl PendingRequest {
+    fn correlation_key(&self) -> CorrelationKey {
+        CorrelationKey::Confirmed {
+            peer: self.peer,
+            invoke_id: self.invoke_id,
+        }
+    }
+}
+
 /// Result of a verified write (see [`BacnetClient::write_property_verified`]).
 ///
 /// A BACnet `SimpleAck` only confirms the device *accepted* the WriteProperty
@@ -120,6 +176,8 @@ impl BacnetClient {
             timeout: DEFAULT_TIMEOUT,
             retries: 0,
             invoke_ids: InvokeIdAllocator::new(),
+            reorder: Mutex::new(ReorderBuffer::new(DEFAULT_TIMEOUT)),
+            devices: Mutex::new(DeviceCache::new()),
         })
     }
 
@@ -149,6 +207,8 @@ impl BacnetClient {
             timeout: config.timeout,
             retries: config.retries,
             invoke_ids: InvokeIdAllocator::new(),
+            reorder: Mutex::new(ReorderBuffer::new(config.timeout)),
+            devices: Mutex::new(DeviceCache::new()),
         })
     }
 
@@ -161,7 +221,7 @@ impl BacnetClient {
 
         // Create and send message
         let message =
-            self.create_unconfirmed_message(UnconfirmedServiceChoice::WhoIs as u8, &buffer);
+            self.create_unconfirmed_message(target_addr, UnconfirmedServiceChoice::WhoIs, &buffer)?;
         self.socket.send_to(&message, target_addr)?;
 
         // Wait for I-Am response
@@ -173,8 +233,9 @@ impl BacnetClient {
                 Ok((len, source)) => {
                     if source == target_addr {
                         if let Some(device_info) =
-                            self.parse_iam_response(&recv_buffer[..len], source)
+                            Self::parse_iam_response(&recv_buffer[..len], source)
                         {
+                            self.device_cache_mut().insert(device_info.clone());
                             return Ok(device_info);
                         }
                     }
@@ -202,7 +263,8 @@ impl BacnetClient {
     ///
     /// `low_limit` and `high_limit` bound the device-instance range; pass both
     /// to target a range, or `None`/`None` to ask every device. Results are
-    /// de-duplicated by device id.
+    /// de-duplicated by device id, and I-Ams from devices outside the range
+    /// are ignored.
     ///
     /// Unlike [`discover_device`](Self::discover_device) (which unicasts to a
     /// single known address), this reaches all devices on the local network.
@@ -237,11 +299,12 @@ impl BacnetClient {
         let mut buffer = Vec::new();
         whois.encode(&mut buffer)?;
 
-        let message = self.create_unconfirmed_bvlc(
-            UnconfirmedServiceChoice::WhoIs as u8,
+        let message = Self::create_unconfirmed_bvlc(
+            UnconfirmedServiceChoice::WhoIs,
             &buffer,
             BVLC_ORIGINAL_BROADCAST,
-        );
+            MaxApduSize::Up1476.size(),
+        )?;
         self.socket.send_to(&message, target_addr)?;
 
         // Collect every distinct device that replies before the timeout.
@@ -253,8 +316,11 @@ impl BacnetClient {
         while start_time.elapsed() < self.timeout {
             match self.socket.recv_from(&mut recv_buffer) {
                 Ok((len, source)) => {
-                    if let Some(info) = self.parse_iam_response(&recv_buffer[..len], source) {
-                        if seen.insert(info.device_id) {
+                    if let Some(info) = Self::parse_iam_response(&recv_buffer[..len], source) {
+                        // Devices answer Who-Is requests from other clients
+                        // too, so replies outside the range are not ours.
+                        if whois.matches(info.device_id) && seen.insert(info.device_id) {
+                            self.device_cache_mut().insert(info.clone());
                             devices.push(info);
                         }
                     }
@@ -278,27 +344,188 @@ impl BacnetClient {
     }
 
     /// Read the device's object list
+    ///
+    /// The device's Object_Name is read in the same request and recorded in
+    /// the [device cache](Self::device_cache).
     pub fn read_object_list(
         &self,
         target_addr: SocketAddr,
         device_id: u32,
     ) -> Result<Vec<ObjectIdentifier>, ClientError> {
-        let device_object = ObjectIdentifier::new(ObjectType::Device, device_id);
-        let property_ref = PropertyReference::new(PropertyIdentifier::ObjectList); // Object_List property
-        let read_spec = ReadAccessSpecification::new(device_object, vec![property_ref]);
-        let rpm_request = ReadPropertyMultipleRequest::new(vec![read_spec]);
-
         let response_data = self.send_confirmed_request(
             target_addr,
             ConfirmedServiceChoice::ReadPropertyMultiple,
-            &self.encode_rpm_request(&rpm_request)?,
+            &Self::encode_object_list_request(device_id)?,
         )?;
 
+        if let Some(name) = Self::object_name_from_response(&response_data) {
+            self.device_cache_mut().set_object_name(device_id, name);
+        }
+        Ok(Self::object_list_from_response(&response_data))
+    }
+
+    /// Read a large device's object list in chunks.
+    ///
+    /// Object_List array index 0 is read for the number of entries, then the
+    /// entries are fetched with Read Range by position, as many per request
+    /// as fit in the device's maximum APDU (1476 bytes unless the device is
+    /// in the [device cache](Self::device_cache)). Devices that refuse Read
+    /// Range are read with ReadPropertyMultiple requests for batches of array
+    /// indices, and devices that refuse array index 0 with
+    /// [`read_object_list`](Self::read_object_list).
+    ///
+    /// Like [`read_object_list`](Self::read_object_list), the device object
+    /// itself is left out.
+    pub fn read_object_list_chunked(
+        &self,
+        target_addr: SocketAddr,
+        device_id: u32,
+    ) -> Result<Vec<ObjectIdentifier>, ClientError> {
+        let device = ObjectIdentifier::new(ObjectType::Device, device_id);
+
+        let request =
+            ReadPropertyRequest::with_array_index(device, PropertyIdentifier::ObjectList, 0);
+        let mut service_data = Vec::new();
+        request.encode(&mut service_data)?;
+        let count = match self.send_confirmed_request(
+            target_addr,
+            ConfirmedServiceChoice::ReadProperty,
+            &service_data,
+        ) {
+            Ok(response_data) => {
+                match ReadPropertyResponse::decode(&response_data)?.property_values[..] {
+                    [PropertyValue::Unsigned(count)] => count as usize,
+                    _ => return Err(ClientError::Decode("unexpected Object_List length".into())),
+                }
+            }
+            Err(e) if is_refusal(&e) => return self.read_object_list(target_addr, device_id),
+            Err(e) => return Err(e),
+        };
+
+        // Responses are bounded by both the device's and this client's
+        // max-APDU
+        let max_apdu = self
+            .device_cache_mut()
+            .find_by_instance(device_id)
+            .map_or(MAX_RESPONSE_SIZE.size(), |info| {
+                (info.max_apdu as usize).min(MAX_RESPONSE_SIZE.size())
+            });
+
+        let mut objects = Vec::with_capacity(count.min(OBJECT_LIST_PREALLOCATION));
+        let page_size = (max_apdu.saturating_sub(READ_RANGE_OVERHEAD) / OBJECT_ID_SIZE)
+            .clamp(1, i16::MAX as usize);
+        while objects.len() < count {
+            let request = ReadRangeRequest::by_position(
+                device,
+                PropertyIdentifier::ObjectList,
+                objects.len() as u32 + 1,
+                page_size.min(count - objects.len()) as i16,
+            );
+            let mut service_data = Vec::new();
+            request.encode(&mut service_data)?;
+
+            let response_data = match self.send_confirmed_request(
+                target_addr,
+                ConfirmedServiceChoice::ReadRange,
+                &service_data,
+            ) {
+                Ok(response_data) => response_data,
+                Err(e) if is_refusal(&e) && objects.is_empty() => break,
+                Err(e) => return Err(e),
+            };
+            let page = Self::decode_object_identifiers(
+                &ReadRangeResponse::decode(&response_data)?.item_data,
+            )?;
+            if page.is_empty() {
+                break;
+            }
+            objects.extend(page);
+        }
+
+        // Read Range refused: read batches of array indices instead
+        let batch_size = (max_apdu.saturating_sub(RPM_OVERHEAD) / INDEXED_OBJECT_ID_SIZE).max(1);
+        while objects.len() < count {
+            let first = objects.len() + 1;
+            let last = count.min(first + batch_size - 1);
+            let property_refs = (first..=last)
+                .map(|index| {
+                    PropertyReference::with_array_index(
+                        PropertyIdentifier::ObjectList,
+                        index as u32,
+                    )
+                })
+                .collect();
+            let rpm_request = ReadPropertyMultipleRequest::new(vec![ReadAccessSpecification::new(
+                device,
+                property_refs,
+            )]);
+
+            let response_data = self.send_confirmed_request(
+                target_addr,
+                ConfirmedServiceChoice::ReadPropertyMultiple,
+                &Self::encode_rpm_request(&rpm_request)?,
+            )?;
+            let response = ReadPropertyMultipleResponse::decode(&response_data)?;
+            for result in response
+                .read_access_results
+                .into_iter()
+                .flat_map(|access| access.results)
+            {
+                match result.value {
+                    PropertyResultValue::Value(values) => {
+                        objects.extend(values.into_iter().filter_map(|value| match value {
+                            PropertyValue::ObjectIdentifier(oid) => Some(oid),
+                            _ => None,
+                        }))
+                    }
+                    PropertyResultValue::Error(class, code) => {
+                        return Err(property_error(class, code))
+                    }
+                }
+            }
+            if objects.len() < last {
+                return Err(ClientError::Decode("missing Object_List entries".into()));
+            }
+        }
+
+        objects.retain(|oid| oid.object_type != ObjectType::Device);
+        Ok(objects)
+    }
+
+    /// Decode a sequence of application-tagged object identifiers.
+    fn decode_object_identifiers(data: &[u8]) -> Result<Vec<ObjectIdentifier>, ClientError> {
+        let mut objects = Vec::new();
+        let mut pos = 0;
+        while pos < data.len() {
+            let (oid, consumed) = decode_object_identifier(&data[pos..])?;
+            objects.push(oid);
+            pos += consumed;
+        }
+        Ok(objects)
+    }
+
+    /// Encode the ReadPropertyMultiple request for a device's Object_List and
+    /// Object_Name.
+    fn encode_object_list_request(device_id: u32) -> Result<Vec<u8>, ClientError> {
+        let device_object = ObjectIdentifier::new(ObjectType::Device, device_id);
+        let property_refs = vec![
+            PropertyReference::new(PropertyIdentifier::ObjectList),
+            PropertyReference::new(PropertyIdentifier::ObjectName),
+        ];
+        let read_spec = ReadAccessSpecification::new(device_object, property_refs);
+        let rpm_request = ReadPropertyMultipleRequest::new(vec![read_spec]);
+
+        Self::encode_rpm_request(&rpm_request)
+    }
+
+    /// Extract the object identifiers from an Object_List
+    /// ReadPropertyMultiple response.
+    fn object_list_from_response(response_data: &[u8]) -> Vec<ObjectIdentifier> {
         // The Object_List property comes back as a list of object identifiers
         // inside the ReadPropertyMultiple result. Prefer the structured decoder;
         // the device object itself is dropped.
         let mut objects = Vec::new();
-        if let Ok(response) = ReadPropertyMultipleResponse::decode(&response_data) {
+        if let Ok(response) = ReadPropertyMultipleResponse::decode(response_data) {
             for access in response.read_access_results {
                 for result in access.results {
                     if let PropertyResultValue::Value(values) = result.value {
@@ -318,10 +545,30 @@ impl BacnetClient {
         // variant it doesn't yet fully handle), scan the raw response for object
         // identifiers so discovery still works.
         if objects.is_empty() {
-            objects = Self::scan_object_identifiers(&response_data);
+            objects = Self::scan_object_identifiers(response_data);
         }
 
-        Ok(objects)
+        objects
+    }
+
+    /// Extract the Object_Name from a ReadPropertyMultiple response, if the
+    /// device returned one.
+    fn object_name_from_response(response_data: &[u8]) -> Option<String> {
+        let response = ReadPropertyMultipleResponse::decode(response_data).ok()?;
+        response
+            .read_access_results
+            .into_iter()
+            .flat_map(|access| access.results)
+            .filter(|result| result.property_identifier == PropertyIdentifier::ObjectName)
+            .find_map(|result| match result.value {
+                PropertyResultValue::Value(values) => {
+                    values.into_iter().find_map(|value| match value {
+                        PropertyValue::CharacterString(name) => Some(name),
+                        _ => None,
+                    })
+                }
+                PropertyResultValue::Error(..) => None,
+            })
     }
 
     /// Scan a raw response buffer for application-tagged object identifiers
@@ -407,7 +654,7 @@ impl BacnetClient {
             match self.send_confirmed_request(
                 target_addr,
                 ConfirmedServiceChoice::ReadPropertyMultiple,
-                &self.encode_rpm_request(&rpm_request)?,
+                &Self::encode_rpm_request(&rpm_request)?,
             ) {
                 Ok(response_data) => {
                     match ReadPropertyMultipleResponse::decode(&response_data) {
@@ -460,6 +707,9 @@ impl BacnetClient {
     /// returned. Returns [`ClientError::PropertyError`] if the device reports
     /// the property as unknown, or [`ClientError::Timeout`] if there is no
     /// response.
+    ///
+    /// Reading a device's Object_Name records it in the
+    /// [device cache](Self::device_cache).
     pub fn read_property(
         &self,
         target_addr: SocketAddr,
@@ -476,7 +726,111 @@ impl BacnetClient {
             &service_data,
         )?;
 
-        Ok(ReadPropertyResponse::decode(&response_data)?.property_values)
+        let values = ReadPropertyResponse::decode(&response_data)?.property_values;
+        if object.object_type == ObjectType::Device && property == PropertyIdentifier::ObjectName {
+            if let Some(PropertyValue::CharacterString(name)) = values.first() {
+                self.device_cache_mut()
+                    .set_object_name(object.instance, name.clone());
+            }
+        }
+        Ok(values)
+    }
+
+    /// Read a property of an object and decode it as the property's type.
+    ///
+    /// The value is decoded with [`decode_known`], which knows the datatype of
+    /// common properties, including ones that depend on the object type such
+    /// as Present_Value (Real for analog objects, Enumerated for binary ones).
+    /// A value of the wrong type is reported as [`ClientError::Encoding`].
+    /// Other properties are decoded from their application tags, with several
+    /// values returned as [`PropertyValue::Array`].
+    pub fn read_property_typed(
+        &self,
+        target_addr: SocketAddr,
+        object: ObjectIdentifier,
+        property: PropertyIdentifier,
+    ) -> Result<PropertyValue, ClientError> {
+        let request = ReadPropertyRequest::new(object, property);
+        let mut service_data = Vec::new();
+        request.encode(&mut service_data)?;
+
+        let response_data = self.send_confirmed_request(
+            target_addr,
+            ConfirmedServiceChoice::ReadProperty,
+            &service_data,
+        )?;
+
+        let value_data = ReadPropertyResponse::value_data(&response_data)?;
+        Ok(decode_known(property, object.object_type, value_data)?)
+    }
+
+    /// Download the complete log buffer of a trend log object.
+    ///
+    /// `Record_Count` is read first to bound the transfer, then the buffer is
+    /// fetched with Read Range by position, one page per request, until the
+    /// device clears the more-items flag. Records are returned oldest first.
+    pub fn read_trend_log(
+        &self,
+        target_addr: SocketAddr,
+        object: ObjectIdentifier,
+    ) -> Result<Vec<LogRecord>, ClientError> {
+        let record_count =
+            match self.read_property(target_addr, object, PropertyIdentifier::RecordCount)?[..] {
+                [PropertyValue::Unsigned(count)] => count,
+                _ => return Err(ClientError::Decode("unexpected Record_Count value".into())),
+            };
+
+        let mut records = Vec::new();
+        let mut reference_index = 1u32;
+
+        while (records.len() as u64) < record_count {
+            let remaining = record_count - records.len() as u64;
+            let count = remaining.min(i16::MAX as u64) as i16;
+
+            let request = ReadRangeRequest::by_position(
+                object,
+                PropertyIdentifier::LogBuffer,
+                reference_index,
+                count,
+            );
+            let mut service_data = Vec::new();
+            request.encode(&mut service_data)?;
+
+            let response_data = self.send_confirmed_request(
+                target_addr,
+                ConfirmedServiceChoice::ReadRange,
+                &service_data,
+            )?;
+            let response = ReadRangeResponse::decode(&response_data)?;
+            let page = LogRecord::decode_list(&response.item_data)?;
+
+            if page.is_empty() {
+                break;
+            }
+            reference_index += page.len() as u32;
+            records.extend(page);
+
+            if !response.result_flags.more_items {
+                break;
+            }
+        }
+
+        Ok(records)
+    }
+
+    /// Check that a device is reachable by reading its Device object's
+    /// `Object_Name`, returning the measured round-trip time.
+    ///
+    /// The duration can be fed straight into
+    /// [`NetworkDiagnostics::record_latency`](crate::network::NetworkDiagnostics::record_latency).
+    /// Returns [`ClientError::Timeout`] if the device does not answer.
+    pub fn ping(&self, target_addr: SocketAddr, device_id: u32) -> Result<Duration, ClientError> {
+        let device = ObjectIdentifier::new(ObjectType::Device, device_id);
+
+        let started = Instant::now();
+        self.read_property(target_addr, device, PropertyIdentifier::ObjectName)?;
+
+        Ok(started.elapsed())
     }
 
     /// Write a single property of an object.
@@ -494,6 +848,26 @@ impl BacnetClient {
         value: &PropertyValue,
         priority: Option<u8>,
     ) -> Result<(), ClientError> {
+        let service_data = Self::encode_write_property(object, property, value, priority)?;
+
+        // A successful WriteProperty is a SimpleAck (empty service data); any
+        // Error/Reject/Abort is surfaced as a typed error by the request path.
+        self.send_confirmed_request(
+            target_addr,
+            ConfirmedServiceChoice::WriteProperty,
+            &service_data,
+        )?;
+
+        Ok(())
+    }
+
+    /// Encode the service data of a WriteProperty request.
+    fn encode_write_property(
+        object: ObjectIdentifier,
+        property: PropertyIdentifier,
+        value: &PropertyValue,
+        priority: Option<u8>,
+    ) -> Result<Vec<u8>, ClientError> {
         let mut encoded_value = Vec::new();
         encode_property_value(value, &mut encoded_value)?;
 
@@ -506,15 +880,7 @@ impl BacnetClient {
         let mut service_data = Vec::new();
         request.encode(&mut service_data)?;
 
-        // A successful WriteProperty is a SimpleAck (empty service data); any
-        // Error/Reject/Abort is surfaced as a typed error by the request path.
-        self.send_confirmed_request(
-            target_addr,
-            ConfirmedServiceChoice::WriteProperty,
-            &service_data,
-        )?;
-
-        Ok(())
+        Ok(service_data)
     }
 
     /// Write a property and then read it back to confirm it took effect.
@@ -566,18 +932,29 @@ impl BacnetClient {
     }
 
     /// Create an unconfirmed message
-    fn create_unconfirmed_message(&self, service_choice: u8, service_data: &[u8]) -> Vec<u8> {
-        self.create_unconfirmed_bvlc(service_choice, service_data, BVLC_ORIGINAL_UNICAST)
+    fn create_unconfirmed_message(
+        &self,
+        target_addr: SocketAddr,
+        service_choice: UnconfirmedServiceChoice,
+        service_data: &[u8],
+    ) -> Result<Vec<u8>, ClientError> {
+        Self::create_unconfirmed_bvlc(
+            service_choice,
+            service_data,
+            BVLC_ORIGINAL_UNICAST,
+            self.max_apdu_for(target_addr),
+        )
     }
 
     /// Build a BACnet/IP frame for an unconfirmed request, wrapped with the
-    /// given BVLC function (`0x0A` unicast or `0x0B` broadcast).
+    /// given BVLC function (`0x0A` unicast or `0x0B` broadcast), for peers that
+    /// accept APDUs of up to `max_apdu` octets.
     fn create_unconfirmed_bvlc(
-        &self,
-        service_choice: u8,
+        service_choice: UnconfirmedServiceChoice,
         service_data: &[u8],
         bvlc_function: u8,
-    ) -> Vec<u8> {
+        max_apdu: usize,
+    ) -> Result<Vec<u8>, ClientError> {
         // Create NPDU
         let mut npdu = Npdu::new();
         npdu.control.expecting_reply = false;
@@ -585,9 +962,11 @@ impl BacnetClient {
         let npdu_buffer = npdu.encode();
 
         // Create unconfirmed service request APDU
-        let mut apdu = vec![0x10]; // Unconfirmed-Request PDU type
-        apdu.push(service_choice);
-        apdu.extend_from_slice(service_data);
+        let apdu = Apdu::UnconfirmedRequest {
+            service_choice,
+            service_data: service_data.to_vec(),
+        }
+        .encode_with_max_length(max_apdu)?;
 
         // Combine NPDU and APDU
         let mut message = npdu_buffer;
@@ -602,28 +981,108 @@ impl BacnetClient {
         bvlc_message[2] = (total_len >> 8) as u8;
         bvlc_message[3] = (total_len & 0xFF) as u8;
 
-        bvlc_message
+        Ok(bvlc_message)
     }
 
     /// Send a confirmed request and wait for the matching response.
     ///
-    /// A fresh invoke ID is allocated for the transaction. Returns the
-    /// ComplexAck service data on success, an empty `Vec` for a SimpleAck, or a
-    /// typed [`ClientError`] if the device responds with Error/Reject/Abort or
-    /// the request times out.
+    /// A fresh invoke ID is allocated for the transaction. If no reply arrives
+    /// in time the request is resent, with the same invoke ID, up to the
+    /// configured number of retries. Returns the ComplexAck service data on
+    /// success, an empty `Vec` for a SimpleAck, or a typed [`ClientError`] if
+    /// the device responds with Error/Reject/Abort or every attempt times out.
     fn send_confirmed_request(
         &self,
         target_addr: SocketAddr,
         service_choice: ConfirmedServiceChoice,
         service_data: &[u8],
     ) -> Result<Vec<u8>, ClientError> {
+        let (pending, message) =
+            self.prepare_confirmed_request(target_addr, service_choice, service_data)?;
+
+        let mut attempt = 0;
+        loop {
+            self.socket.send_to(&message, target_addr)?;
+
+            // A reply to an earlier attempt answers the resent request too.
+            // Duplicates arriving later are buffered under this transaction's
+            // key and dropped when they expire or the invoke ID is reused.
+            match self.wait_for_response_within(pending, attempt_timeout(self.timeout, attempt)) {
+                Err(ClientError::Timeout) if attempt < self.retries => attempt += 1,
+                result => return result,
+            }
+        }
+    }
+
+    /// Send a confirmed request without waiting for the reply.
+    ///
+    /// Several requests may be outstanding at once; their replies can be
+    /// collected with [`wait_for_response`](Self::wait_for_response) in any
+    /// order, regardless of the order in which they arrive.
+    pub fn start_confirmed_request(
+        &self,
+        target_addr: SocketAddr,
+        service_choice: ConfirmedServiceChoice,
+        service_data: &[u8],
+    ) -> Result<PendingRequest, ClientError> {
+        let (pending, message) =
+            self.prepare_confirmed_request(target_addr, service_choice, service_data)?;
+        self.socket.send_to(&message, target_addr)?;
+
+        Ok(pending)
+    }
+
+    /// Allocate an invoke ID and build the frame for a confirmed request.
+    fn prepare_confirmed_request(
+        &self,
+        target_addr: SocketAddr,
+        service_choice: ConfirmedServiceChoice,
+        service_data: &[u8],
+    ) -> Result<(PendingRequest, Vec<u8>), ClientError> {
         let invoke_id = self.invoke_ids.next_id();
+        let message = Self::encode_confirmed_request(
+            invoke_id,
+            service_choice,
+            service_data,
+            self.max_apdu_for(target_addr),
+        )?;
+
+        let pending = PendingRequest {
+            peer: target_addr,
+            invoke_id,
+        };
+
+        // A reply still buffered from an earlier transaction that used this
+        // invoke ID is stale now.
+        self.reorder_buffer().discard(pending.correlation_key());
+
+        Ok((pending, message))
+    }
+
+    /// Largest APDU `peer` accepts: the max-APDU of its I-Am if it has been
+    /// discovered, otherwise 1476.
+    fn max_apdu_for(&self, peer: SocketAddr) -> usize {
+        self.device_cache_mut()
+            .find_by_address(peer)
+            .map_or(MaxApduSize::Up1476.size(), |info| {
+                (info.max_apdu as usize).min(MaxApduSize::Up1476.size())
+            })
+    }
+
+    /// Build the BACnet/IP frame for a confirmed request to a peer that
+    /// accepts APDUs of up to `max_apdu` octets.
+    fn encode_confirmed_request(
+        invoke_id: u8,
+        service_choice: ConfirmedServiceChoice,
+        service_data: &[u8],
+        max_apdu: usize,
+    ) -> Result<Vec<u8>, ClientError> {
         let apdu = Apdu::ConfirmedRequest {
             segmented: false,
             more_follows: false,
             segmented_response_accepted: true,
             max_segments: MaxSegments::Unspecified,
-            max_response_size: MaxApduSize::Up1476,
+            max_response_size: MAX_RESPONSE_SIZE,
             invoke_id,
             sequence_number: None,
             proposed_window_size: None,
@@ -631,7 +1090,7 @@ impl BacnetClient {
             service_data: service_data.to_vec(),
         };
 
-        let apdu_data = apdu.encode();
+        let apdu_data = apdu.encode_with_max_length(max_apdu)?;
         let mut npdu = Npdu::new();
         npdu.control.expecting_reply = true;
         npdu.control.priority = 0;
@@ -647,23 +1106,55 @@ impl BacnetClient {
         bvlc_message[2] = (total_len >> 8) as u8;
         bvlc_message[3] = (total_len & 0xFF) as u8;
 
-        self.socket.send_to(&bvlc_message, target_addr)?;
+        Ok(bvlc_message)
+    }
+
+    /// Wait for the reply to a request sent with
+    /// [`start_confirmed_request`](Self::start_confirmed_request).
+    ///
+    /// Returns the same results as the blocking request methods. Replies to
+    /// other outstanding requests, and COV notifications, that arrive in the
+    /// meantime are buffered rather than dropped. The request is not resent.
+    pub fn wait_for_response(&self, pending: PendingRequest) -> Result<Vec<u8>, ClientError> {
+        self.wait_for_response_within(pending, self.timeout)
+    }
+
+    /// Wait up to `timeout` for the reply to `pending`.
+    fn wait_for_response_within(
+        &self,
+        pending: PendingRequest,
+        timeout: Duration,
+    ) -> Result<Vec<u8>, ClientError> {
+        let key = pending.correlation_key();
+
+        // The reply may already have arrived while waiting on another request.
+        let buffered = self.reorder_buffer().take(key);
+        if let Some(frame) = buffered {
+            if let Some(response_data) =
+                Self::interpret_confirmed_response(&frame, pending.invoke_id)?
+            {
+                return Ok(response_data);
+            }
+        }
 
-        // Wait for response
         let mut recv_buffer = [0u8; 1500];
         let start_time = Instant::now();
 
-        while start_time.elapsed() < self.timeout {
+        while start_time.elapsed() < timeout {
             match self.socket.recv_from(&mut recv_buffer) {
                 Ok((len, source)) => {
-                    if source == target_addr {
-                        // A matching Error/Reject/Abort surfaces as Err here; an
-                        // unrelated frame yields None so we keep waiting.
-                        if let Some(response_data) =
-                            self.interpret_confirmed_response(&recv_buffer[..len], invoke_id)?
-                        {
-                            return Ok(response_data);
+                    let frame = &recv_buffer[..len];
+                    match Self::correlation_key(frame, source) {
+                        // A matching Error/Reject/Abort surfaces as Err here.
+                        Some(frame_key) if frame_key == key => {
+                            if let Some(response_data) =
+                                Self::interpret_confirmed_response(frame, pending.invoke_id)?
+                            {
+                                return Ok(response_data);
+                            }
                         }
+                        Some(frame_key) => self.reorder_buffer().push(frame_key, frame.to_vec()),
+                        None => {}
                     }
                 }
                 // A per-recv socket timeout is WouldBlock on Unix and TimedOut
@@ -684,8 +1175,94 @@ impl BacnetClient {
         Err(ClientError::Timeout)
     }
 
+    /// Take the COV notifications buffered for `subscriber_process_id`.
+    ///
+    /// Unconfirmed COV notifications that arrive while the client is waiting
+    /// for a confirmed reply are kept here instead of being dropped. Each entry
+    /// is the notification's service data, in arrival order.
+    pub fn take_cov_notifications(&self, subscriber_process_id: u32) -> Vec<Vec<u8>> {
+        self.reorder_buffer()
+            .take_all(CorrelationKey::Cov {
+                subscriber_process_id,
+            })
+            .iter()
+            .filter_map(|frame| match Self::decode_frame_apdu(frame) {
+                Some(Apdu::UnconfirmedRequest { service_data, .. }) => Some(service_data),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// A snapshot of the devices this client has discovered.
+    ///
+    /// Devices are added by [`discover_device`](Self::discover_device) and
+    /// [`who_is`](Self::who_is); their names once
+    /// [`read_object_list`](Self::read_object_list) or a
+    /// [`read_property`](Self::read_property) of the device's Object_Name
+    /// succeeds.
+    pub fn device_cache(&self) -> DeviceCache {
+        self.device_cache_mut().clone()
+    }
+
+    fn device_cache_mut(&self) -> std::sync::MutexGuard<'_, DeviceCache> {
+        // The cache holds no invariants a panicking holder could break.
+        self.devices
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn reorder_buffer(&self) -> std::sync::MutexGuard<'_, ReorderBuffer> {
+        // The buffer holds no invariants a panicking holder could break.
+        self.reorder
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Decode the APDU carried in a BACnet/IP frame, if it is well formed.
+    fn decode_frame_apdu(data: &[u8]) -> Option<Apdu> {
+        if data.len() < 4 || data[0] != 0x81 {
+            return None;
+        }
+
+        let bvlc_length = ((data[2] as u16) << 8) | (data[3] as u16);
+        if data.len() != bvlc_length as usize {
+            return None;
+        }
+
+        let (_npdu, npdu_len) = Npdu::decode(&data[4..]).ok()?;
+        Apdu::decode(&data[4 + npdu_len..]).ok()
+    }
+
+    /// Work out which pending operation a received frame belongs to.
+    ///
+    /// Confirmed-request replies are keyed by sender and invoke ID, and
+    /// unconfirmed COV notifications by subscriber process ID. Anything else
+    /// yields `None`.
+    fn correlation_key(data: &[u8], source: SocketAddr) -> Option<CorrelationKey> {
+        match Self::decode_frame_apdu(data)? {
+            Apdu::ComplexAck { invoke_id, .. }
+            | Apdu::SimpleAck { invoke_id, .. }
+            | Apdu::Error { invoke_id, .. }
+            | Apdu::Reject { invoke_id, .. }
+            | Apdu::Abort { invoke_id, .. } => Some(CorrelationKey::Confirmed {
+                peer: source,
+                invoke_id,
+            }),
+            Apdu::UnconfirmedRequest {
+                service_choice: UnconfirmedServiceChoice::UnconfirmedCOVNotification,
+                service_data,
+            } => {
+                let (subscriber_process_id, _) = decode_context_unsigned(&service_data, 0).ok()?;
+                Some(CorrelationKey::Cov {
+                    subscriber_process_id,
+                })
+            }
+            _ => None,
+        }
+    }
+
     /// Parse I-Am response
-    fn parse_iam_response(&self, data: &[u8], source: SocketAddr) -> Option<DeviceInfo> {
+    fn parse_iam_response(data: &[u8], source: SocketAddr) -> Option<DeviceInfo> {
         // Check BVLC header
         if data.len() < 4 || data[0] != 0x81 {
             return None;
@@ -721,6 +1298,7 @@ impl BacnetClient {
                     vendor_name,
                     max_apdu: iam.max_apdu_length_accepted,
                     segmentation: iam.segmentation_supported,
+                    object_name: None,
                 })
             }
             Err(_) => None,
@@ -736,39 +1314,18 @@ impl BacnetClient {
     /// - `Ok(None)` when the frame is unrelated (wrong invoke ID, not a
     ///   response, or unparseable) and the caller should keep waiting.
     ///
-    /// `Ok(None)` (rather than an error) is deliberate: this is called from a
-    /// per-request receive loop with a single transaction in flight, so frames
-    /// that don't match are simply other traffic on the socket and must be
-    /// skipped, not treated as failures. If this ever moves behind a shared
-    /// event loop that demultiplexes all incoming messages, that loop would need
-    /// to dispatch frames to the waiting transaction by invoke ID instead of
-    /// dropping non-matching ones here.
+    /// `Ok(None)` (rather than an error) is deliberate: frames that don't match
+    /// are other traffic on the socket, not failures. The receive loop in
+    /// [`wait_for_response`](Self::wait_for_response) routes replies for other
+    /// invoke IDs to the reorder buffer before they reach this point.
     fn interpret_confirmed_response(
-        &self,
         data: &[u8],
         expected_invoke_id: u8,
     ) -> Result<Option<Vec<u8>>, ClientError> {
-        // Check BVLC header
-        if data.len() < 4 || data[0] != 0x81 {
-            return Ok(None);
-        }
-
-        let bvlc_length = ((data[2] as u16) << 8) | (data[3] as u16);
-        if data.len() != bvlc_length as usize {
-            return Ok(None);
-        }
-
-        // Decode NPDU and APDU; a frame we can't parse is simply not our reply.
-        let npdu_start = 4;
-        let (_npdu, npdu_len) = match Npdu::decode(&data[npdu_start..]) {
-            Ok(decoded) => decoded,
-            Err(_) => return Ok(None),
-        };
-
-        let apdu_start = npdu_start + npdu_len;
-        let apdu = match Apdu::decode(&data[apdu_start..]) {
-            Ok(apdu) => apdu,
-            Err(_) => return Ok(None),
+        // A frame we can't parse is simply not our reply.
+        let apdu = match Self::decode_frame_apdu(data) {
+            Some(apdu) => apdu,
+            None => return Ok(None),
         };
 
         match apdu {
@@ -786,8 +1343,8 @@ impl BacnetClient {
                 error_code,
                 ..
             } if invoke_id == expected_invoke_id => Err(ClientError::PropertyError {
-                class: error_class as u32,
-                code: error_code as u32,
+                class: error_class,
+                code: error_code,
             }),
             Apdu::Reject {
                 invoke_id,
@@ -805,10 +1362,7 @@ impl BacnetClient {
     }
 
     /// Encode ReadPropertyMultiple request
-    fn encode_rpm_request(
-        &self,
-        request: &ReadPropertyMultipleRequest,
-    ) -> Result<Vec<u8>, ClientError> {
+    fn encode_rpm_request(request: &ReadPropertyMultipleRequest) -> Result<Vec<u8>, ClientError> {
         let mut buffer = Vec::new();
 
         request.encode(&mut buffer)?;
@@ -870,6 +1424,30 @@ impl BacnetClient {
     }
 }
 
+/// Whether the device answered but declined the request, so another way of
+/// asking may still succeed.
+#[cfg(feature = "std")]
+fn is_refusal(error: &ClientError) -> bool {
+    matches!(
+        error,
+        ClientError::PropertyError { .. } | ClientError::Rejected(_) | ClientError::Abort(_)
+    )
+}
+
+/// Build the error for a per-property failure in a ReadPropertyMultiple result.
+///
+/// Error classes and codes are 16-bit; anything wider is a malformed response.
+#[cfg(feature = "std")]
+fn property_error(class: u32, code: u32) -> ClientError {
+    match (u16::try_from(class), u16::try_from(code)) {
+        (Ok(class), Ok(code)) => ClientError::PropertyError {
+            class: ErrorClass::from(class),
+            code: ErrorCode::from(code),
+        },
+        _ => ClientError::Decode(format!("error class {class} / code {code} out of range")),
+    }
+}
+
 /// Compare a written value against a read-back value when verifying a write,
 /// tolerating floating-point rounding for Real/Double (including a Real written
 /// value read back as a Double, or vice versa).
@@ -956,14 +1534,20 @@ mod tests {
         assert_eq!(ClientError::Timeout.to_string(), "request timed out");
 
         // Known class + code are named, with the raw numbers retained.
-        let known = ClientError::PropertyError { class: 1, code: 31 };
+        let known = ClientError::PropertyError {
+            class: ErrorClass::Object,
+            code: ErrorCode::UnknownObject,
+        };
         assert_eq!(
             known.to_string(),
             "unknown-object (class object[1], code 31)"
         );
 
         // Property/write-access-denied — the case seen against real hardware.
-        let denied = ClientError::PropertyError { class: 2, code: 40 };
+        let denied = ClientError::PropertyError {
+            class: ErrorClass::Property,
+            code: ErrorCode::WriteAccessDenied,
+        };
         assert_eq!(
             denied.to_string(),
             "write-access-denied (class property[2], code 40)"
@@ -971,8 +1555,8 @@ mod tests {
 
         // Unknown code falls back to the numeric form.
         let unknown = ClientError::PropertyError {
-            class: 2,
-            code: 222,
+            class: ErrorClass::Property,
+            code: ErrorCode::from(222),
         };
         assert_eq!(
             unknown.to_string(),
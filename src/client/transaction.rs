@@ -5,12 +5,38 @@
 //! ComplexAck / SimpleAck / Error / Reject / Abort PDU. This module owns the
 //! allocation of those IDs.
 //!
-//! The current client issues one request at a time and blocks for the reply, so
-//! a monotonic wrapping counter is sufficient. It is kept behind its own type
-//! (with interior mutability) so that a future concurrent or async client can
-//! grow it into a full outstanding-transaction table without changing callers.
+//! A monotonic wrapping counter is sufficient for allocation. It is kept behind
+//! its own type (with interior mutability) so that a future concurrent or async
+//! client can grow it into a full outstanding-transaction table without
+//! changing callers.
+//!
+//! UDP gives no ordering guarantee, so a reply can arrive while the client is
+//! waiting on a different transaction. [`ReorderBuffer`] holds such frames,
+//! timestamped on arrival, until the operation they belong to claims them.
 
+use std::collections::VecDeque;
+use std::net::SocketAddr;
 use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::{Duration, Instant};
+
+/// Maximum number of frames held before the oldest is dropped.
+const MAX_BUFFERED_FRAMES: usize = 64;
+
+/// Longest wait for a single attempt, as a multiple of the base timeout.
+const MAX_BACKOFF_FACTOR: u32 = 8;
+
+/// How long to wait for the reply to the given attempt of a request.
+///
+/// The first attempt waits `timeout` and every retry twice as long as the one
+/// before, up to eight times the base timeout, so a busy device gets more time
+/// to answer each resend.
+pub(crate) fn attempt_timeout(timeout: Duration, attempt: u8) -> Duration {
+    let factor = 1u32
+        .checked_shl(attempt as u32)
+        .unwrap_or(u32::MAX)
+        .min(MAX_BACKOFF_FACTOR);
+    timeout.saturating_mul(factor)
+}
 
 /// Allocates invoke IDs for confirmed-request transactions.
 #[derive(Debug, Default)]
@@ -32,6 +58,92 @@ impl InvokeIdAllocator {
     }
 }
 
+/// The pending operation a buffered frame belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum CorrelationKey {
+    /// A reply to a confirmed request, identified by peer and invoke ID.
+    Confirmed { peer: SocketAddr, invoke_id: u8 },
+    /// A COV notification, identified by the subscriber process ID.
+    Cov { subscriber_process_id: u32 },
+}
+
+/// A received frame waiting to be claimed.
+#[derive(Debug)]
+struct BufferedFrame {
+    key: CorrelationKey,
+    received_at: Instant,
+    data: Vec<u8>,
+}
+
+/// Holds frames that arrived out of order until their operation claims them.
+///
+/// Frames are kept in arrival order and expire after `max_age`, so a reply to
+/// a transaction that has already given up does not linger forever.
+#[derive(Debug)]
+pub(crate) struct ReorderBuffer {
+    max_age: Duration,
+    frames: VecDeque<BufferedFrame>,
+}
+
+impl ReorderBuffer {
+    /// Create an empty buffer whose frames expire after `max_age`.
+    pub(crate) fn new(max_age: Duration) -> Self {
+        Self {
+            max_age,
+            frames: VecDeque::new(),
+        }
+    }
+
+    /// Buffer a frame for `key`, dropping the oldest frame when full.
+    pub(crate) fn push(&mut self, key: CorrelationKey, data: Vec<u8>) {
+        self.expire();
+        if self.frames.len() == MAX_BUFFERED_FRAMES {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(BufferedFrame {
+            key,
+            received_at: Instant::now(),
+            data,
+        });
+    }
+
+    /// Claim the earliest frame buffered for `key`.
+    pub(crate) fn take(&mut self, key: CorrelationKey) -> Option<Vec<u8>> {
+        self.expire();
+        let index = self.frames.iter().position(|frame| frame.key == key)?;
+        self.frames.remove(index).map(|frame| frame.data)
+    }
+
+    /// Claim every frame buffered for `key`, in arrival order.
+    pub(crate) fn take_all(&mut self, key: CorrelationKey) -> Vec<Vec<u8>> {
+        self.expire();
+        let mut taken = Vec::new();
+        self.frames.retain(|frame| {
+            if frame.key == key {
+                taken.push(frame.data.clone());
+                false
+            } else {
+                true
+            }
+        });
+        taken
+    }
+
+    /// Drop any frame buffered for `key`.
+    ///
+    /// Called when an invoke ID is reused, so a stale reply to the previous
+    /// transaction with that ID is never handed to the new one.
+    pub(crate) fn discard(&mut self, key: CorrelationKey) {
+        self.frames.retain(|frame| frame.key != key);
+    }
+
+    fn expire(&mut self) {
+        let max_age = self.max_age;
+        self.frames
+            .retain(|frame| frame.received_at.elapsed() < max_age);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -55,4 +167,71 @@ mod tests {
         // The next allocation must wrap back to 0 rather than overflow-panic.
         assert_eq!(alloc.next_id(), 0);
     }
+
+    #[test]
+    fn attempt_timeout_backs_off() {
+        let base = Duration::from_millis(100);
+        assert_eq!(attempt_timeout(base, 0), base);
+        assert_eq!(attempt_timeout(base, 1), base * 2);
+        assert_eq!(attempt_timeout(base, 2), base * 4);
+        assert_eq!(attempt_timeout(base, 3), base * 8);
+        assert_eq!(attempt_timeout(base, 4), base * 8);
+        assert_eq!(attempt_timeout(base, 255), base * 8);
+    }
+
+    fn confirmed(invoke_id: u8) -> CorrelationKey {
+        CorrelationKey::Confirmed {
+            peer: "127.0.0.1:47808".parse().unwrap(),
+            invoke_id,
+        }
+    }
+
+    #[test]
+    fn reorder_buffer_matches_out_of_order_replies() {
+        let mut buffer = ReorderBuffer::new(Duration::from_secs(5));
+
+        // The ack for invoke ID 6 arrives before the one for 5.
+        buffer.push(confirmed(6), vec![6]);
+        buffer.push(confirmed(5), vec![5]);
+
+        assert_eq!(buffer.take(confirmed(5)), Some(vec![5]));
+        assert_eq!(buffer.take(confirmed(6)), Some(vec![6]));
+        assert_eq!(buffer.take(confirmed(5)), None);
+        assert_eq!(buffer.frames.len(), 0);
+    }
+
+    #[test]
+    fn reorder_buffer_keys_cov_by_process_id() {
+        let mut buffer = ReorderBuffer::new(Duration::from_secs(5));
+        let cov = |subscriber_process_id| CorrelationKey::Cov {
+            subscriber_process_id,
+        };
+
+        buffer.push(cov(1), vec![1]);
+        buffer.push(confirmed(1), vec![0]);
+        buffer.push(cov(2), vec![2]);
+        buffer.push(cov(1), vec![3]);
+
+        assert_eq!(buffer.take_all(cov(1)), vec![vec![1], vec![3]]);
+        assert_eq!(buffer.take_all(cov(1)), Vec::<Vec<u8>>::new());
+        assert_eq!(buffer.frames.len(), 2);
+    }
+
+    #[test]
+    fn reorder_buffer_expires_and_discards() {
+        let mut buffer = ReorderBuffer::new(Duration::ZERO);
+        buffer.push(confirmed(1), vec![1]);
+        assert_eq!(buffer.take(confirmed(1)), None);
+
+        let mut buffer = ReorderBuffer::new(Duration::from_secs(5));
+        buffer.push(confirmed(2), vec![2]);
+        buffer.discard(confirmed(2));
+        assert_eq!(buffer.take(confirmed(2)), None);
+
+        for i in 0..=MAX_BUFFERED_FRAMES {
+            buffer.push(confirmed(i as u8), vec![i as u8]);
+        }
+        assert_eq!(buffer.frames.len(), MAX_BUFFERED_FRAMES);
+        assert_eq!(buffer.take(confirmed(0)), None);
+    }
 }
@@ -0,0 +1,637 @@
+//! BACnet Broadcast Management Device (ASHRAE 135 Annex J.4).
+//!
+//! IP routers do not forward local broadcasts, so a BACnet/IP internetwork
+//! spanning several subnets needs one BBMD per subnet. Each BBMD keeps a
+//! Broadcast Distribution Table (BDT) listing its peers and forwards every
+//! broadcast it sees to them as a Forwarded-NPDU. Foreign devices that
+//! register with the BBMD are kept in its Foreign Device Table (FDT) and
+//! receive those broadcasts as well.
+//!
+//! [`Bbmd`] implements the BVLC processing without owning a socket: each
+//! received message is passed to [`Bbmd::process_message`], which returns
+//! the NPDU to deliver locally (if any) and the frames to transmit. Devices
+//! that are not a BBMD use [`process_message_without_bbmd`] instead.
+//!
+//! # Examples
+//!
+//! ```
+//! use bacnet_rs::datalink::bbmd::Bbmd;
+//!
+//! let local = "192.168.1.10:47808".parse().unwrap();
+//! let broadcast = "192.168.1.255:47808".parse().unwrap();
+//! let mut bbmd = Bbmd::new(local, broadcast);
+//! bbmd.add_bdt_entry(local, [255, 255, 255, 255]);
+//! bbmd.add_bdt_entry("192.168.2.10:47808".parse().unwrap(), [255, 255, 255, 255]);
+//!
+//! // A Who-Is broadcast on the local subnet
+//! let frame = [0x81, 0x0B, 0x00, 0x0C, 0x01, 0x20, 0xFF, 0xFF, 0x00, 0xFF, 0x10, 0x08];
+//! let source = "192.168.1.50:47808".parse().unwrap();
+//! let output = bbmd.process_message(&frame, source).unwrap();
+//!
+//! assert_eq!(output.npdu.as_deref(), Some(&frame[4..]));
+//! assert_eq!(output.frames.len(), 1);
+//! assert_eq!(output.frames[0].0, "192.168.2.10:47808".parse().unwrap());
+//! ```
+
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    time::Instant,
+};
+
+use crate::datalink::{
+    bip::{BdtEntry, BvlcFunction, BvlcHeader, BvlcResult, FdtEntry},
+    bvlc, DataLinkError, Result,
+};
+
+/// Seconds a foreign device registration outlives its TTL (J.5.2.3).
+const FDT_GRACE_PERIOD: u64 = 30;
+
+/// Size of a Read-FDT-Ack entry: B/IP address, TTL and time remaining.
+const FDT_ENTRY_SIZE: usize = 10;
+
+/// Outcome of processing one BVLC message at a BBMD.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BbmdOutput {
+    /// NPDU carried by the message, to be handed to the local network layer.
+    pub npdu: Option<Vec<u8>>,
+
+    /// BVLC frames to transmit, paired with their destination.
+    pub frames: Vec<(SocketAddr, Vec<u8>)>,
+}
+
+/// BACnet Broadcast Management Device.
+///
+/// Maintains the Broadcast Distribution Table and the Foreign Device Table
+/// and decides where each broadcast has to be forwarded. The BDT should
+/// contain an entry for this BBMD itself, as is required for Read-BDT to
+/// return the complete table; that entry is never forwarded to.
+#[derive(Debug, Clone)]
+pub struct Bbmd {
+    /// B/IP address of this BBMD.
+    local_addr: SocketAddr,
+
+    /// Broadcast address of the local subnet.
+    broadcast_addr: SocketAddr,
+
+    /// Broadcast Distribution Table.
+    bdt: Vec<BdtEntry>,
+
+    /// Foreign Device Table.
+    fdt: Vec<FdtEntry>,
+}
+
+impl Bbmd {
+    /// Create a BBMD with empty distribution and foreign device tables.
+    ///
+    /// # Arguments
+    ///
+    /// * `local_addr` - The B/IP address of this BBMD
+    /// * `broadcast_addr` - The broadcast address of the local subnet
+    pub fn new(local_addr: SocketAddr, broadcast_addr: SocketAddr) -> Self {
+        Self {
+            local_addr,
+            broadcast_addr,
+            bdt: Vec::new(),
+            fdt: Vec::new(),
+        }
+    }
+
+    /// Add an entry to the Broadcast Distribution Table.
+    ///
+    /// An existing entry for the same address is replaced.
+    pub fn add_bdt_entry(&mut self, address: SocketAddr, mask: [u8; 4]) {
+        self.bdt.retain(|entry| entry.address != address);
+        self.bdt.push(BdtEntry { address, mask });
+    }
+
+    /// Get the Broadcast Distribution Table.
+    pub fn bdt(&self) -> &[BdtEntry] {
+        &self.bdt
+    }
+
+    /// Get the Foreign Device Table.
+    pub fn fdt(&self) -> &[FdtEntry] {
+        &self.fdt
+    }
+
+    /// Remove foreign devices whose registration has expired.
+    ///
+    /// Registrations are kept for their TTL plus a 30 second grace period.
+    pub fn cleanup_fdt(&mut self) {
+        let now = Instant::now();
+        self.fdt.retain(|entry| {
+            now.duration_since(entry.registration_time).as_secs()
+                < entry.ttl as u64 + FDT_GRACE_PERIOD
+        });
+    }
+
+    /// Build the frames needed to broadcast an NPDU originating at this BBMD.
+    ///
+    /// The NPDU is broadcast on the local subnet as an
+    /// Original-Broadcast-NPDU and forwarded to every peer BBMD and foreign
+    /// device as a Forwarded-NPDU.
+    pub fn broadcast(&self, npdu: &[u8]) -> Result<Vec<(SocketAddr, Vec<u8>)>> {
+        let mut frames = vec![(
+            self.broadcast_addr,
+            encode_frame(BvlcFunction::OriginalBroadcastNpdu, &[], npdu),
+        )];
+
+        let forwarded = encode_forwarded_npdu(self.local_addr, npdu)?;
+        self.forward(&mut frames, &forwarded, self.local_addr);
+
+        Ok(frames)
+    }
+
+    /// Process a received BVLC message.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The complete BVLC message including header
+    /// * `source` - The address the message was received from
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DataLinkError::InvalidFrame`] if the BVLC header is invalid
+    /// or its length does not match the message, and
+    /// [`DataLinkError::Rejected`] for a received BVLC-Result NAK. Malformed
+    /// control requests are answered with a BVLC-Result NAK instead.
+    pub fn process_message(&mut self, data: &[u8], source: SocketAddr) -> Result<BbmdOutput> {
+        let header = BvlcHeader::decode(data)?;
+        if data.len() != header.length as usize {
+            return Err(DataLinkError::InvalidFrame);
+        }
+
+        let payload = &data[4..];
+        let mut output = BbmdOutput::default();
+
+        match header.function {
+            BvlcFunction::OriginalUnicastNpdu => {
+                output.npdu = Some(payload.to_vec());
+            }
+            BvlcFunction::OriginalBroadcastNpdu => {
+                let forwarded = encode_forwarded_npdu(source, payload)?;
+                self.forward(&mut output.frames, &forwarded, source);
+                output.npdu = Some(payload.to_vec());
+            }
+            BvlcFunction::ForwardedNpdu => {
+                if payload.len() < 6 {
+                    return Err(DataLinkError::InvalidFrame);
+                }
+
+                // A peer whose mask is all ones sent the message to this BBMD
+                // directly, so it still has to be broadcast on the local subnet
+                let unicast = self
+                    .bdt
+                    .iter()
+                    .any(|entry| entry.address == source && entry.mask == [255; 4]);
+                if unicast {
+                    output.frames.push((self.broadcast_addr, data.to_vec()));
+                }
+
+                for entry in &self.fdt {
+                    output.frames.push((entry.address, data.to_vec()));
+                }
+
+                output.npdu = Some(payload[6..].to_vec());
+            }
+            BvlcFunction::DistributeBroadcastToNetwork => {
+                if !self.fdt.iter().any(|entry| entry.address == source) {
+                    output
+                        .frames
+                        .push((source, BvlcResult::DistributeBroadcastToNetworkNak.encode()));
+                    return Ok(output);
+                }
+
+                let forwarded = encode_forwarded_npdu(source, payload)?;
+                output.frames.push((self.broadcast_addr, forwarded.clone()));
+                self.forward(&mut output.frames, &forwarded, source);
+                output.npdu = Some(payload.to_vec());
+            }
+            BvlcFunction::WriteBroadcastDistributionTable => {
+                let result = match decode_bdt(payload) {
+                    Some(bdt) => {
+                        self.bdt = bdt;
+                        BvlcResult::SuccessfulCompletion
+                    }
+                    None => BvlcResult::WriteBdtNak,
+                };
+                output.frames.push((source, result.encode()));
+            }
+            BvlcFunction::ReadBroadcastDistributionTable => {
+                let mut entries = Vec::with_capacity(self.bdt.len() * BdtEntry::ENCODED_SIZE);
+                for entry in &self.bdt {
+                    entry.encode(&mut entries)?;
+                }
+                output.frames.push((
+                    source,
+                    encode_frame(
+                        BvlcFunction::ReadBroadcastDistributionTableAck,
+                        &entries,
+                        &[],
+                    ),
+                ));
+            }
+            BvlcFunction::RegisterForeignDevice => {
+                let result = if payload.len() == 2 {
+                    // A TTL of zero cancels the registration
+                    let ttl = u16::from_be_bytes([payload[0], payload[1]]);
+                    self.fdt.retain(|entry| entry.address != source);
+                    if ttl > 0 {
+                        self.fdt.push(FdtEntry {
+                            address: source,
+                            ttl,
+                            registration_time: Instant::now(),
+                        });
+                    }
+                    BvlcResult::SuccessfulCompletion
+                } else {
+                    BvlcResult::RegisterForeignDeviceNak
+                };
+                output.frames.push((source, result.encode()));
+            }
+            BvlcFunction::ReadForeignDeviceTable => {
+                let now = Instant::now();
+                let mut entries = Vec::with_capacity(self.fdt.len() * FDT_ENTRY_SIZE);
+                for entry in &self.fdt {
+                    let remaining = (entry.ttl as u64 + FDT_GRACE_PERIOD)
+                        .saturating_sub(now.duration_since(entry.registration_time).as_secs());
+                    encode_bip_address(entry.address, &mut entries)?;
+                    entries.extend_from_slice(&entry.ttl.to_be_bytes());
+                    entries
+                        .extend_from_slice(&(remaining.min(u16::MAX as u64) as u16).to_be_bytes());
+                }
+                output.frames.push((
+                    source,
+                    encode_frame(BvlcFunction::ReadForeignDeviceTableAck, &entries, &[]),
+                ));
+            }
+            BvlcFunction::DeleteForeignDeviceTableEntry => {
+                let registered = self.fdt.len();
+                if let Some(address) = decode_bip_address(payload) {
+                    self.fdt.retain(|entry| entry.address != address);
+                }
+                let result = if self.fdt.len() < registered {
+                    BvlcResult::SuccessfulCompletion
+                } else {
+                    BvlcResult::DeleteFdtEntryNak
+                };
+                output.frames.push((source, result.encode()));
+            }
+            BvlcFunction::Result => {
+                let result = BvlcResult::from_bytes(data)?;
+                if result.is_nak() {
+                    return Err(DataLinkError::Rejected(result));
+                }
+            }
+            _ => {}
+        }
+
+        Ok(output)
+    }
+
+    /// Queue a Forwarded-NPDU for every peer BBMD and foreign device except
+    /// this BBMD and the originator.
+    fn forward(
+        &self,
+        frames: &mut Vec<(SocketAddr, Vec<u8>)>,
+        forwarded: &[u8],
+        originator: SocketAddr,
+    ) {
+        for entry in &self.bdt {
+            if entry.address != self.local_addr && entry.address != originator {
+                frames.push((entry.forward_address(), forwarded.to_vec()));
+            }
+        }
+
+        for entry in &self.fdt {
+            if entry.address != originator {
+                frames.push((entry.address, forwarded.to_vec()));
+            }
+        }
+    }
+}
+
+/// Process a received BVLC message at a B/IP device that is not a BBMD.
+///
+/// NPDUs are delivered but never forwarded, and the requests only a BBMD
+/// can serve are answered with a BVLC-Result NAK.
+///
+/// # Errors
+///
+/// As for [`Bbmd::process_message`].
+pub fn process_message_without_bbmd(data: &[u8], source: SocketAddr) -> Result<BbmdOutput> {
+    let (function, payload) = bvlc::decode(data)?;
+    let mut output = BbmdOutput::default();
+
+    let nak = match function {
+        BvlcFunction::OriginalUnicastNpdu | BvlcFunction::OriginalBroadcastNpdu => {
+            output.npdu = Some(payload.to_vec());
+            None
+        }
+        BvlcFunction::ForwardedNpdu => {
+            if payload.len() < 6 {
+                return Err(DataLinkError::InvalidFrame);
+            }
+            output.npdu = Some(payload[6..].to_vec());
+            None
+        }
+        BvlcFunction::WriteBroadcastDistributionTable => Some(BvlcResult::WriteBdtNak),
+        BvlcFunction::ReadBroadcastDistributionTable => Some(BvlcResult::ReadBdtNak),
+        BvlcFunction::RegisterForeignDevice => Some(BvlcResult::RegisterForeignDeviceNak),
+        BvlcFunction::ReadForeignDeviceTable => Some(BvlcResult::ReadFdtNak),
+        BvlcFunction::DeleteForeignDeviceTableEntry => Some(BvlcResult::DeleteFdtEntryNak),
+        BvlcFunction::DistributeBroadcastToNetwork => {
+            Some(BvlcResult::DistributeBroadcastToNetworkNak)
+        }
+        BvlcFunction::Result => {
+            let result = BvlcResult::from_bytes(data)?;
+            if result.is_nak() {
+                return Err(DataLinkError::Rejected(result));
+            }
+            None
+        }
+        _ => None,
+    };
+
+    if let Some(nak) = nak {
+        output.frames.push((source, nak.encode()));
+    }
+
+    Ok(output)
+}
+
+/// Encode a BVLC message from its function, control data and NPDU.
+fn encode_frame(function: BvlcFunction, control: &[u8], npdu: &[u8]) -> Vec<u8> {
+    let length = 4 + control.len() + npdu.len();
+    let mut frame = BvlcHeader::new(function, length as u16).encode();
+    frame.extend_from_slice(control);
+    frame.extend_from_slice(npdu);
+    frame
+}
+
+/// Encode a Forwarded-NPDU carrying the B/IP address of the originator.
+fn encode_forwarded_npdu(originator: SocketAddr, npdu: &[u8]) -> Result<Vec<u8>> {
+    let mut control = Vec::with_capacity(6);
+    encode_bip_address(originator, &mut control)?;
+    Ok(encode_frame(BvlcFunction::ForwardedNpdu, &control, npdu))
+}
+
+/// Encode a B/IP address as four IP octets and the port.
+pub(crate) fn encode_bip_address(address: SocketAddr, buffer: &mut Vec<u8>) -> Result<()> {
+    match address {
+        SocketAddr::V4(addr) => {
+            buffer.extend_from_slice(&addr.ip().octets());
+            buffer.extend_from_slice(&addr.port().to_be_bytes());
+            Ok(())
+        }
+        SocketAddr::V6(_) => Err(DataLinkError::UnsupportedType),
+    }
+}
+
+/// Decode a B/IP address of four IP octets and the port.
+fn decode_bip_address(data: &[u8]) -> Option<SocketAddr> {
+    match *data {
+        [a, b, c, d, port_high, port_low] => Some(SocketAddr::new(
+            Ipv4Addr::new(a, b, c, d).into(),
+            u16::from_be_bytes([port_high, port_low]),
+        )),
+        _ => None,
+    }
+}
+
+/// Decode the entries of a Write-BDT or Read-BDT-Ack message.
+#[allow(clippy::manual_is_multiple_of)]
+fn decode_bdt(data: &[u8]) -> Option<Vec<BdtEntry>> {
+    if data.len() % BdtEntry::ENCODED_SIZE != 0 {
+        return None;
+    }
+
+    data.chunks_exact(BdtEntry::ENCODED_SIZE)
+        .map(|chunk| BdtEntry::decode(chunk).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addr(s: &str) -> SocketAddr {
+        s.parse().unwrap()
+    }
+
+    fn test_bbmd() -> Bbmd {
+        let mut bbmd = Bbmd::new(addr("192.168.1.10:47808"), addr("192.168.1.255:47808"));
+        bbmd.add_bdt_entry(addr("192.168.1.10:47808"), [255, 255, 255, 255]);
+        bbmd.add_bdt_entry(addr("192.168.2.10:47808"), [255, 255, 255, 255]);
+        bbmd.add_bdt_entry(addr("192.168.3.10:47808"), [255, 255, 255, 0]);
+        bbmd
+    }
+
+    fn register(bbmd: &mut Bbmd, source: SocketAddr) {
+        let output = bbmd
+            .process_message(&[0x81, 0x05, 0x00, 0x06, 0x00, 0x3C], source)
+            .unwrap();
+        assert_eq!(
+            output.frames,
+            vec![(source, vec![0x81, 0x00, 0x00, 0x06, 0x00, 0x00])]
+        );
+    }
+
+    #[test]
+    fn test_distribute_broadcast_to_network() {
+        let mut bbmd = test_bbmd();
+        register(&mut bbmd, addr("10.0.0.5:47808"));
+        register(&mut bbmd, addr("10.0.0.6:47809"));
+
+        let npdu = [0x01, 0x20, 0xFF, 0xFF, 0x00, 0xFF, 0x10, 0x08];
+        let mut frame = vec![0x81, 0x09, 0x00, 0x0C];
+        frame.extend_from_slice(&npdu);
+
+        let output = bbmd
+            .process_message(&frame, addr("10.0.0.5:47808"))
+            .unwrap();
+        assert_eq!(output.npdu.as_deref(), Some(&npdu[..]));
+
+        let mut forwarded = vec![0x81, 0x04, 0x00, 0x12, 10, 0, 0, 5, 0xBA, 0xC0];
+        forwarded.extend_from_slice(&npdu);
+
+        let destinations: Vec<SocketAddr> = output.frames.iter().map(|(dest, _)| *dest).collect();
+        assert_eq!(
+            destinations,
+            vec![
+                addr("192.168.1.255:47808"),
+                addr("192.168.2.10:47808"),
+                addr("192.168.3.255:47808"),
+                addr("10.0.0.6:47809"),
+            ]
+        );
+        assert!(output.frames.iter().all(|(_, data)| *data == forwarded));
+    }
+
+    #[test]
+    fn test_distribute_broadcast_from_unregistered_device() {
+        let mut bbmd = test_bbmd();
+        let source = addr("10.0.0.5:47808");
+
+        let output = bbmd
+            .process_message(&[0x81, 0x09, 0x00, 0x06, 0x01, 0x00], source)
+            .unwrap();
+        assert_eq!(output.npdu, None);
+        assert_eq!(
+            output.frames,
+            vec![(source, vec![0x81, 0x00, 0x00, 0x06, 0x00, 0x60])]
+        );
+
+        // The NAK is reported as an error by a BBMD receiving it
+        let err = test_bbmd()
+            .process_message(&output.frames[0].1, addr("192.168.1.10:47808"))
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            DataLinkError::Rejected(BvlcResult::DistributeBroadcastToNetworkNak)
+        ));
+    }
+
+    #[test]
+    fn test_forwarded_npdu_from_peer() {
+        let mut bbmd = test_bbmd();
+        register(&mut bbmd, addr("10.0.0.5:47808"));
+
+        let frame = [
+            0x81, 0x04, 0x00, 0x0C, 192, 168, 2, 50, 0xBA, 0xC0, 0x01, 0x00,
+        ];
+        let output = bbmd
+            .process_message(&frame, addr("192.168.2.10:47808"))
+            .unwrap();
+
+        assert_eq!(output.npdu, Some(vec![0x01, 0x00]));
+        assert_eq!(
+            output.frames,
+            vec![
+                (addr("192.168.1.255:47808"), frame.to_vec()),
+                (addr("10.0.0.5:47808"), frame.to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_write_and_read_bdt() {
+        let mut bbmd = test_bbmd();
+        let source = addr("192.168.1.20:47808");
+
+        let entries = [
+            192, 168, 1, 10, 0xBA, 0xC0, 255, 255, 255, 255, // this BBMD
+            172, 16, 0, 1, 0xBA, 0xC1, 255, 255, 0, 0, // peer
+        ];
+        let mut frame = vec![0x81, 0x01, 0x00, 0x18];
+        frame.extend_from_slice(&entries);
+
+        let output = bbmd.process_message(&frame, source).unwrap();
+        assert_eq!(
+            output.frames,
+            vec![(source, vec![0x81, 0x00, 0x00, 0x06, 0x00, 0x00])]
+        );
+        assert_eq!(bbmd.bdt().len(), 2);
+        assert_eq!(
+            bbmd.bdt()[1].forward_address(),
+            addr("172.16.255.255:47809")
+        );
+
+        let output = bbmd
+            .process_message(&[0x81, 0x02, 0x00, 0x04], source)
+            .unwrap();
+        let mut ack = vec![0x81, 0x03, 0x00, 0x18];
+        ack.extend_from_slice(&entries);
+        assert_eq!(output.frames, vec![(source, ack)]);
+
+        // A frame shorter than its header claims is rejected outright
+        let err = bbmd
+            .process_message(&frame[..frame.len() - 1], source)
+            .unwrap_err();
+        assert!(matches!(err, DataLinkError::InvalidFrame));
+
+        // A partial entry is answered with a NAK and leaves the BDT unchanged
+        let mut frame = vec![0x81, 0x01, 0x00, 0x0D];
+        frame.extend_from_slice(&entries[..9]);
+        let output = bbmd.process_message(&frame, source).unwrap();
+        assert_eq!(
+            output.frames,
+            vec![(source, vec![0x81, 0x00, 0x00, 0x06, 0x00, 0x10])]
+        );
+        assert_eq!(bbmd.bdt().len(), 2);
+    }
+
+    #[test]
+    fn test_foreign_device_table() {
+        let mut bbmd = test_bbmd();
+        let foreign = addr("10.0.0.5:47808");
+        let manager = addr("192.168.1.20:47808");
+        register(&mut bbmd, foreign);
+
+        // Read-FDT lists the TTL and the time remaining, grace period included
+        let output = bbmd
+            .process_message(&[0x81, 0x06, 0x00, 0x04], manager)
+            .unwrap();
+        assert_eq!(
+            output.frames,
+            vec![(
+                manager,
+                vec![0x81, 0x07, 0x00, 0x0E, 10, 0, 0, 5, 0xBA, 0xC0, 0x00, 0x3C, 0x00, 0x5A]
+            )]
+        );
+
+        // Delete-FDT-Entry removes the entry, and a second one fails
+        let delete = [0x81, 0x08, 0x00, 0x0A, 10, 0, 0, 5, 0xBA, 0xC0];
+        let output = bbmd.process_message(&delete, manager).unwrap();
+        assert_eq!(
+            output.frames,
+            vec![(manager, vec![0x81, 0x00, 0x00, 0x06, 0x00, 0x00])]
+        );
+        assert!(bbmd.fdt().is_empty());
+        let output = bbmd.process_message(&delete, manager).unwrap();
+        assert_eq!(
+            output.frames,
+            vec![(manager, vec![0x81, 0x00, 0x00, 0x06, 0x00, 0x50])]
+        );
+
+        // Registering with a TTL of zero leaves no entry behind
+        register(&mut bbmd, foreign);
+        let output = bbmd
+            .process_message(&[0x81, 0x05, 0x00, 0x06, 0x00, 0x00], foreign)
+            .unwrap();
+        assert_eq!(
+            output.frames,
+            vec![(foreign, vec![0x81, 0x00, 0x00, 0x06, 0x00, 0x00])]
+        );
+        assert!(bbmd.fdt().is_empty());
+    }
+
+    #[test]
+    fn test_process_message_without_bbmd() {
+        let source = addr("10.0.0.5:47808");
+
+        // Broadcasts are delivered but not forwarded
+        let frame = [0x81, 0x0B, 0x00, 0x06, 0x01, 0x00];
+        let output = process_message_without_bbmd(&frame, source).unwrap();
+        assert_eq!(output.npdu, Some(vec![0x01, 0x00]));
+        assert!(output.frames.is_empty());
+
+        // BBMD requests are refused
+        let requests: [(&[u8], u8); 6] = [
+            (&[0x81, 0x05, 0x00, 0x06, 0x00, 0x3C], 0x30),
+            (&[0x81, 0x01, 0x00, 0x04], 0x10),
+            (&[0x81, 0x02, 0x00, 0x04], 0x20),
+            (&[0x81, 0x06, 0x00, 0x04], 0x40),
+            (&[0x81, 0x08, 0x00, 0x0A, 10, 0, 0, 5, 0xBA, 0xC0], 0x50),
+            (&[0x81, 0x09, 0x00, 0x06, 0x01, 0x00], 0x60),
+        ];
+        for (frame, code) in requests {
+            let output = process_message_without_bbmd(frame, source).unwrap();
+            assert_eq!(output.npdu, None);
+            assert_eq!(
+                output.frames,
+                vec![(source, vec![0x81, 0x00, 0x00, 0x06, 0x00, code])]
+            );
+        }
+    }
+}
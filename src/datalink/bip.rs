@@ -71,13 +71,18 @@
 #[cfg(feature = "std")]
 use std::{
     io::ErrorKind,
-    net::{SocketAddr, ToSocketAddrs, UdpSocket},
+    net::{Ipv4Addr, SocketAddr, ToSocketAddrs, UdpSocket},
     time::{Duration, Instant},
 };
 
 #[cfg(not(feature = "std"))]
 use alloc::{string::String, vec::Vec};
 
+#[cfg(feature = "std")]
+use crate::datalink::{
+    bbmd::{self, Bbmd},
+    bvlc,
+};
 use crate::datalink::{DataLink, DataLinkAddress, DataLinkError, DataLinkType, Result};
 
 /// BACnet/IP well-known UDP port number.
@@ -125,6 +130,18 @@ pub const BACNET_IP_PORT: u16 = 47808;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
 pub enum BvlcFunction {
+    /// BVLC-Result (0x00).
+    ///
+    /// Reports the outcome of a BVLC control request such as Write-BDT or
+    /// Register-Foreign-Device. Carries a 2-byte result code.
+    Result = 0x00,
+
+    /// Write-Broadcast-Distribution-Table (0x01).
+    ///
+    /// Replaces a BBMD's Broadcast Distribution Table with the entries that
+    /// follow the header.
+    WriteBroadcastDistributionTable = 0x01,
+
     /// Original-Unicast-NPDU (0x0A).
     ///
     /// Encapsulates an NPDU for unicast delivery to a specific BACnet/IP device.
@@ -199,6 +216,8 @@ impl TryFrom<u8> for BvlcFunction {
 
     fn try_from(value: u8) -> core::result::Result<Self, Self::Error> {
         let function = match value {
+            0x00 => BvlcFunction::Result,
+            0x01 => BvlcFunction::WriteBroadcastDistributionTable,
             0x0A => BvlcFunction::OriginalUnicastNpdu,
             0x0B => BvlcFunction::OriginalBroadcastNpdu,
             0x04 => BvlcFunction::ForwardedNpdu,
@@ -218,6 +237,94 @@ impl TryFrom<u8> for BvlcFunction {
     }
 }
 
+/// BVLC-Result codes (ASHRAE 135 Annex J.2.1).
+///
+/// A BBMD answers every BVLC control request with a BVLC-Result message
+/// carrying one of these codes. Each request type has its own NAK code, so
+/// the result also identifies which kind of request was rejected.
+///
+/// # Examples
+///
+/// ```
+/// use bacnet_rs::datalink::bip::BvlcResult;
+///
+/// let result = BvlcResult::from_bytes(&[0x81, 0x00, 0x00, 0x06, 0x00, 0x30]).unwrap();
+/// assert_eq!(result, BvlcResult::RegisterForeignDeviceNak);
+/// assert!(result.is_nak());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u16)]
+pub enum BvlcResult {
+    /// Successful completion (0x0000).
+    SuccessfulCompletion = 0x0000,
+
+    /// Write-Broadcast-Distribution-Table NAK (0x0010).
+    WriteBdtNak = 0x0010,
+
+    /// Read-Broadcast-Distribution-Table NAK (0x0020).
+    ReadBdtNak = 0x0020,
+
+    /// Register-Foreign-Device NAK (0x0030).
+    RegisterForeignDeviceNak = 0x0030,
+
+    /// Read-Foreign-Device-Table NAK (0x0040).
+    ReadFdtNak = 0x0040,
+
+    /// Delete-Foreign-Device-Table-Entry NAK (0x0050).
+    DeleteFdtEntryNak = 0x0050,
+
+    /// Distribute-Broadcast-To-Network NAK (0x0060).
+    DistributeBroadcastToNetworkNak = 0x0060,
+}
+
+impl BvlcResult {
+    /// Parse a complete BVLC-Result message.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DataLinkError::InvalidFrame`] if the message is not a
+    /// 6-byte BVLC-Result or carries an unknown result code.
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let header = BvlcHeader::decode(data)?;
+        if header.function != BvlcFunction::Result || header.length != 6 || data.len() != 6 {
+            return Err(DataLinkError::InvalidFrame);
+        }
+
+        u16::from_be_bytes([data[4], data[5]]).try_into()
+    }
+
+    /// Encode the result as a complete BVLC-Result message.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut frame = BvlcHeader::new(BvlcFunction::Result, 6).encode();
+        frame.extend_from_slice(&(*self as u16).to_be_bytes());
+        frame
+    }
+
+    /// Check if the result reports a rejected request.
+    pub fn is_nak(&self) -> bool {
+        *self != BvlcResult::SuccessfulCompletion
+    }
+}
+
+impl TryFrom<u16> for BvlcResult {
+    type Error = DataLinkError;
+
+    fn try_from(value: u16) -> core::result::Result<Self, Self::Error> {
+        let result = match value {
+            0x0000 => BvlcResult::SuccessfulCompletion,
+            0x0010 => BvlcResult::WriteBdtNak,
+            0x0020 => BvlcResult::ReadBdtNak,
+            0x0030 => BvlcResult::RegisterForeignDeviceNak,
+            0x0040 => BvlcResult::ReadFdtNak,
+            0x0050 => BvlcResult::DeleteFdtEntryNak,
+            0x0060 => BvlcResult::DistributeBroadcastToNetworkNak,
+            _ => return Err(DataLinkError::InvalidFrame),
+        };
+
+        Ok(result)
+    }
+}
+
 /// BVLC header structure for BACnet/IP messages.
 ///
 /// Every BACnet/IP message begins with this 4-byte header that identifies
@@ -387,7 +494,7 @@ impl BvlcHeader {
 /// };
 /// # }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg(feature = "std")]
 pub struct BdtEntry {
     /// IP address and port of the peer BBMD.
@@ -406,6 +513,77 @@ pub struct BdtEntry {
     pub mask: [u8; 4],
 }
 
+#[cfg(feature = "std")]
+impl BdtEntry {
+    /// Size of an encoded BDT entry in bytes.
+    pub const ENCODED_SIZE: usize = 10;
+
+    /// Encode the entry as 4 bytes of IP address, 2 bytes of port and
+    /// 4 bytes of broadcast distribution mask.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DataLinkError::UnsupportedType`] for IPv6 addresses.
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> Result<()> {
+        match self.address {
+            SocketAddr::V4(addr) => {
+                buffer.extend_from_slice(&addr.ip().octets());
+                buffer.extend_from_slice(&addr.port().to_be_bytes());
+                buffer.extend_from_slice(&self.mask);
+                Ok(())
+            }
+            SocketAddr::V6(_) => Err(DataLinkError::UnsupportedType),
+        }
+    }
+
+    /// Decode an entry from its 10-byte wire format.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DataLinkError::InvalidFrame`] if fewer than 10 bytes are
+    /// available.
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        if data.len() < Self::ENCODED_SIZE {
+            return Err(DataLinkError::InvalidFrame);
+        }
+
+        let ip = Ipv4Addr::new(data[0], data[1], data[2], data[3]);
+        let port = u16::from_be_bytes([data[4], data[5]]);
+
+        Ok(Self {
+            address: SocketAddr::new(ip.into(), port),
+            mask: [data[6], data[7], data[8], data[9]],
+        })
+    }
+
+    /// Address that broadcasts for this peer are forwarded to.
+    ///
+    /// A mask of all ones means the peer BBMD is addressed directly and
+    /// re-broadcasts on its own subnet. Any other mask yields the directed
+    /// broadcast address of the peer's subnet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bacnet_rs::datalink::bip::BdtEntry;
+    ///
+    /// let entry = BdtEntry {
+    ///     address: "192.168.2.10:47808".parse().unwrap(),
+    ///     mask: [255, 255, 255, 0],
+    /// };
+    /// assert_eq!(entry.forward_address(), "192.168.2.255:47808".parse().unwrap());
+    /// ```
+    pub fn forward_address(&self) -> SocketAddr {
+        match self.address {
+            SocketAddr::V4(addr) => {
+                let ip = u32::from(*addr.ip()) | !u32::from_be_bytes(self.mask);
+                SocketAddr::new(Ipv4Addr::from(ip).into(), addr.port())
+            }
+            SocketAddr::V6(_) => self.address,
+        }
+    }
+}
+
 /// Foreign Device Table (FDT) entry.
 ///
 /// Represents a foreign device that has registered with this BBMD to receive
@@ -502,6 +680,9 @@ pub struct FdtEntry {
 ///
 /// ## BBMD Configuration
 ///
+/// A data link is not a BBMD unless configured as one. Adding a BDT entry
+/// makes it one.
+///
 /// ```no_run
 /// # #[cfg(feature = "std")] {
 /// use bacnet_rs::datalink::bip::{BacnetIpDataLink, BdtEntry};
@@ -527,23 +708,11 @@ pub struct BacnetIpDataLink {
     /// Local IP address and port.
     local_addr: SocketAddr,
 
-    /// Broadcast Distribution Table.
-    ///
-    /// Contains peer BBMDs for broadcast forwarding. Only used when this
-    /// device is configured as a BBMD.
-    bdt: Vec<BdtEntry>,
-
-    /// Foreign Device Table.
-    ///
-    /// Contains registered foreign devices that should receive broadcasts.
-    /// Only used when this device is configured as a BBMD.
-    fdt: Vec<FdtEntry>,
-
-    /// Local broadcast address for this subnet.
-    ///
-    /// Calculated based on the local IP address and subnet mask.
-    /// Used for Original-Broadcast-NPDU messages.
+    /// Broadcast address of the local subnet.
     broadcast_addr: SocketAddr,
+
+    /// BBMD state, if this device is configured as a BBMD.
+    bbmd: Option<Bbmd>,
 }
 
 #[cfg(feature = "std")]
@@ -606,7 +775,7 @@ impl BacnetIpDataLink {
             SocketAddr::V4(addr) => {
                 let ip = addr.ip().octets();
                 // Simple broadcast calculation - in production, use proper subnet mask
-                let broadcast_ip = std::net::Ipv4Addr::new(ip[0], ip[1], ip[2], 255);
+                let broadcast_ip = Ipv4Addr::new(ip[0], ip[1], ip[2], 255);
                 SocketAddr::new(broadcast_ip.into(), BACNET_IP_PORT)
             }
             SocketAddr::V6(_) => {
@@ -618,9 +787,8 @@ impl BacnetIpDataLink {
         Ok(Self {
             socket,
             local_addr,
-            bdt: Vec::new(),
-            fdt: Vec::new(),
             broadcast_addr,
+            bbmd: None,
         })
     }
 
@@ -653,10 +821,7 @@ impl BacnetIpDataLink {
     /// # }
     /// ```
     pub fn send_unicast_npdu(&mut self, npdu: &[u8], dest: SocketAddr) -> Result<()> {
-        let header = BvlcHeader::new(BvlcFunction::OriginalUnicastNpdu, 4 + npdu.len() as u16);
-
-        let mut frame = header.encode();
-        frame.extend_from_slice(npdu);
+        let frame = bvlc::encode_original_unicast(npdu)?;
 
         self.socket
             .send_to(&frame, dest)
@@ -667,10 +832,11 @@ impl BacnetIpDataLink {
 
     /// Send a broadcast NPDU to all devices.
     ///
-    /// Wraps the NPDU in a BVLC Original-Broadcast-NPDU message and sends it to:
-    /// 1. The local subnet broadcast address
-    /// 2. All peer BBMDs in the BDT (if configured as BBMD)
-    /// 3. All registered foreign devices in the FDT (if configured as BBMD)
+    /// Wraps the NPDU in a BVLC Original-Broadcast-NPDU message and sends it to
+    /// the local subnet broadcast address. If configured as a BBMD, it is also
+    /// sent as a Forwarded-NPDU to:
+    /// 1. All peer BBMDs in the BDT
+    /// 2. All registered foreign devices in the FDT
     ///
     /// # Arguments
     ///
@@ -696,19 +862,21 @@ impl BacnetIpDataLink {
     /// # }
     /// ```
     pub fn send_broadcast_npdu(&mut self, npdu: &[u8]) -> Result<()> {
-        let header = BvlcHeader::new(BvlcFunction::OriginalBroadcastNpdu, 4 + npdu.len() as u16);
-
-        let mut frame = header.encode();
-        frame.extend_from_slice(npdu);
+        let mut frames = match &self.bbmd {
+            Some(bbmd) => bbmd.broadcast(npdu)?,
+            None => vec![(self.broadcast_addr, bvlc::encode_original_broadcast(npdu)?)],
+        }
+        .into_iter();
 
-        // Send to local broadcast address
-        self.socket
-            .send_to(&frame, self.broadcast_addr)
-            .map_err(DataLinkError::IoError)?;
+        // The local broadcast comes first
+        if let Some((dest, frame)) = frames.next() {
+            self.socket
+                .send_to(&frame, dest)
+                .map_err(DataLinkError::IoError)?;
+        }
 
-        // Send to all BDT entries
-        for entry in &self.bdt {
-            let _ = self.socket.send_to(&frame, entry.address);
+        for (dest, frame) in frames {
+            let _ = self.socket.send_to(&frame, dest);
         }
 
         Ok(())
@@ -764,11 +932,22 @@ impl BacnetIpDataLink {
         Ok(())
     }
 
+    /// Configure this device as a BBMD, if it is not one already.
+    ///
+    /// A BBMD accepts foreign device registrations and BDT updates from any
+    /// host that can reach it, so only enable it on devices meant to serve
+    /// as one. Returns the BBMD state for further configuration.
+    pub fn enable_bbmd(&mut self) -> &mut Bbmd {
+        let (local_addr, broadcast_addr) = (self.local_addr, self.broadcast_addr);
+        self.bbmd
+            .get_or_insert_with(|| Bbmd::new(local_addr, broadcast_addr))
+    }
+
     /// Add a peer BBMD to the Broadcast Distribution Table.
     ///
-    /// When configured as a BBMD, this device will forward broadcast messages
-    /// to all peers in the BDT. Each peer BBMD is responsible for distributing
-    /// broadcasts to devices on its local subnet.
+    /// This configures the device as a BBMD (see [`Self::enable_bbmd`]), which
+    /// forwards broadcast messages to all peers in the BDT. Each peer BBMD is
+    /// responsible for distributing broadcasts to devices on its local subnet.
     ///
     /// # Arguments
     ///
@@ -790,14 +969,20 @@ impl BacnetIpDataLink {
     /// # }
     /// ```
     pub fn add_bdt_entry(&mut self, address: SocketAddr, mask: [u8; 4]) {
-        self.bdt.push(BdtEntry { address, mask });
+        self.enable_bbmd().add_bdt_entry(address, mask);
+    }
+
+    /// Get the BBMD state of this device, or None if it is not a BBMD.
+    pub fn bbmd(&self) -> Option<&Bbmd> {
+        self.bbmd.as_ref()
     }
 
     /// Remove expired entries from the Foreign Device Table.
     ///
     /// This method should be called periodically to remove foreign devices
     /// whose registration has expired. Devices that fail to re-register
-    /// within their TTL period will no longer receive broadcasts.
+    /// within their TTL, plus the 30 second grace period of Annex J, will no
+    /// longer receive broadcasts.
     ///
     /// # Examples
     ///
@@ -817,16 +1002,17 @@ impl BacnetIpDataLink {
     /// # }
     /// ```
     pub fn cleanup_fdt(&mut self) {
-        let now = Instant::now();
-        self.fdt.retain(|entry| {
-            now.duration_since(entry.registration_time).as_secs() < entry.ttl as u64
-        });
+        if let Some(bbmd) = &mut self.bbmd {
+            bbmd.cleanup_fdt();
+        }
     }
 
     /// Process a received BVLC message.
     ///
-    /// Handles all BVLC message types according to the BACnet/IP specification.
-    /// Returns the encapsulated NPDU for data messages, or None for control messages.
+    /// The message is processed by the BBMD state of this device, and any
+    /// forwarded broadcasts or BVLC-Results it produces are sent. A device
+    /// that is not a BBMD answers BBMD requests with a NAK. Returns the
+    /// encapsulated NPDU for data messages, or None for control messages.
     ///
     /// # Arguments
     ///
@@ -842,45 +1028,18 @@ impl BacnetIpDataLink {
     ///
     /// Returns an error if the message format is invalid.
     fn process_bvlc_message(&mut self, data: &[u8], source: SocketAddr) -> Result<Option<Vec<u8>>> {
-        let header = BvlcHeader::decode(data)?;
+        let output = match &mut self.bbmd {
+            Some(bbmd) => bbmd.process_message(data, source)?,
+            None => bbmd::process_message_without_bbmd(data, source)?,
+        };
 
-        if data.len() != header.length as usize {
-            return Err(DataLinkError::InvalidFrame);
+        for (dest, frame) in &output.frames {
+            let _ = self.socket.send_to(frame, dest);
         }
 
-        match header.function {
-            BvlcFunction::OriginalUnicastNpdu | BvlcFunction::OriginalBroadcastNpdu => {
-                // Return the NPDU portion (skip 4-byte BVLC header)
-                if data.len() > 4 {
-                    Ok(Some(data[4..].to_vec()))
-                } else {
-                    Err(DataLinkError::InvalidFrame)
-                }
-            }
-            BvlcFunction::ForwardedNpdu => {
-                // Forwarded NPDU has original source address after header
-                if data.len() > 10 {
-                    Ok(Some(data[10..].to_vec()))
-                } else {
-                    Err(DataLinkError::InvalidFrame)
-                }
-            }
-            BvlcFunction::RegisterForeignDevice => {
-                // Handle foreign device registration
-                if data.len() == 6 {
-                    let ttl = u16::from_be_bytes([data[4], data[5]]);
-                    self.fdt.push(FdtEntry {
-                        address: source,
-                        ttl,
-                        registration_time: Instant::now(),
-                    });
-                }
-                Ok(None)
-            }
-            _ => {
-                // Other BVLC functions not yet implemented
-                Ok(None)
-            }
+        match output.npdu {
+            Some(npdu) if npdu.is_empty() => Err(DataLinkError::InvalidFrame),
+            npdu => Ok(npdu),
         }
     }
 }
@@ -955,4 +1114,112 @@ mod tests {
         let datalink = result.unwrap();
         assert_eq!(datalink.link_type(), DataLinkType::BacnetIp);
     }
+
+    #[test]
+    fn test_bvlc_result_codes() {
+        let results = [
+            (0x0000, BvlcResult::SuccessfulCompletion),
+            (0x0010, BvlcResult::WriteBdtNak),
+            (0x0020, BvlcResult::ReadBdtNak),
+            (0x0030, BvlcResult::RegisterForeignDeviceNak),
+            (0x0040, BvlcResult::ReadFdtNak),
+            (0x0050, BvlcResult::DeleteFdtEntryNak),
+            (0x0060, BvlcResult::DistributeBroadcastToNetworkNak),
+        ];
+
+        for (code, expected) in results {
+            let frame = [0x81, 0x00, 0x00, 0x06, (code >> 8) as u8, code as u8];
+            let result = BvlcResult::from_bytes(&frame).unwrap();
+            assert_eq!(result, expected);
+            assert_eq!(result.is_nak(), code != 0);
+            assert_eq!(result.encode(), frame);
+        }
+
+        assert!(BvlcResult::from_bytes(&[0x81, 0x00, 0x00, 0x06, 0x00, 0x70]).is_err());
+        assert!(BvlcResult::from_bytes(&[0x81, 0x0A, 0x00, 0x06, 0x00, 0x00]).is_err());
+        assert!(BvlcResult::from_bytes(&[0x81, 0x00, 0x00, 0x05, 0x00]).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_datalink_is_not_bbmd_by_default() {
+        let mut datalink = BacnetIpDataLink::new("127.0.0.1:0").unwrap();
+        let foreign = UdpSocket::bind("127.0.0.1:0").unwrap();
+        foreign
+            .set_read_timeout(Some(Duration::from_secs(1)))
+            .unwrap();
+        let foreign_addr = foreign.local_addr().unwrap();
+        let mut buffer = [0u8; 64];
+
+        // Register-Foreign-Device is refused
+        let npdu = datalink
+            .process_bvlc_message(&[0x81, 0x05, 0x00, 0x06, 0x00, 0x3C], foreign_addr)
+            .unwrap();
+        assert!(npdu.is_none());
+        let (len, _) = foreign.recv_from(&mut buffer).unwrap();
+        assert_eq!(&buffer[..len], [0x81, 0x00, 0x00, 0x06, 0x00, 0x30]);
+        assert!(datalink.bbmd().is_none());
+
+        // A local broadcast is delivered but not forwarded
+        let frame = [0x81, 0x0B, 0x00, 0x08, 0x01, 0x00, 0x10, 0x08];
+        let source: SocketAddr = "127.0.0.2:47808".parse().unwrap();
+        let npdu = datalink.process_bvlc_message(&frame, source).unwrap();
+        assert_eq!(npdu.as_deref(), Some(&frame[4..]));
+        foreign.set_nonblocking(true).unwrap();
+        assert!(foreign.recv_from(&mut buffer).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_datalink_acts_as_bbmd() {
+        let mut datalink = BacnetIpDataLink::new("127.0.0.1:0").unwrap();
+        datalink.enable_bbmd();
+        let foreign = UdpSocket::bind("127.0.0.1:0").unwrap();
+        foreign
+            .set_read_timeout(Some(Duration::from_secs(1)))
+            .unwrap();
+        let foreign_addr = foreign.local_addr().unwrap();
+        let mut buffer = [0u8; 64];
+
+        // Register-Foreign-Device with a TTL of 60 s is acknowledged
+        let npdu = datalink
+            .process_bvlc_message(&[0x81, 0x05, 0x00, 0x06, 0x00, 0x3C], foreign_addr)
+            .unwrap();
+        assert!(npdu.is_none());
+        let (len, _) = foreign.recv_from(&mut buffer).unwrap();
+        assert_eq!(&buffer[..len], [0x81, 0x00, 0x00, 0x06, 0x00, 0x00]);
+        assert_eq!(datalink.bbmd().unwrap().fdt().len(), 1);
+
+        // A local broadcast is forwarded to the foreign device
+        let frame = [0x81, 0x0B, 0x00, 0x08, 0x01, 0x00, 0x10, 0x08];
+        let source: SocketAddr = "127.0.0.2:47808".parse().unwrap();
+        let npdu = datalink.process_bvlc_message(&frame, source).unwrap();
+        assert_eq!(npdu.as_deref(), Some(&frame[4..]));
+        let (len, _) = foreign.recv_from(&mut buffer).unwrap();
+        assert_eq!(&buffer[..4], [0x81, 0x04, 0x00, 0x0E]);
+        assert_eq!(&buffer[4..10], [127, 0, 0, 2, 0xBA, 0xC0]);
+        assert_eq!(&buffer[10..len], &frame[4..]);
+
+        // The registration is still within its TTL
+        datalink.cleanup_fdt();
+        assert_eq!(datalink.bbmd().unwrap().fdt().len(), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_bvlc_result_nak_is_reported() {
+        let mut datalink = BacnetIpDataLink::new("127.0.0.1:0").unwrap();
+        let source = "192.168.1.10:47808".parse().unwrap();
+
+        let result = datalink.process_bvlc_message(&[0x81, 0x00, 0x00, 0x06, 0x00, 0x00], source);
+        assert!(matches!(result, Ok(None)));
+
+        let result = datalink.process_bvlc_message(&[0x81, 0x00, 0x00, 0x06, 0x00, 0x30], source);
+        assert!(matches!(
+            result,
+            Err(DataLinkError::Rejected(
+                BvlcResult::RegisterForeignDeviceNak
+            ))
+        ));
+    }
 }
@@ -0,0 +1,131 @@
+//! BVLC framing of NPDUs for BACnet/IP (ASHRAE 135 Annex J.2).
+//!
+//! Every BACnet/IP datagram starts with a 4-byte BACnet Virtual Link Control
+//! header: the type `0x81`, the [`BvlcFunction`] and the total length of the
+//! datagram. These helpers wrap an NPDU in an Original-Unicast-NPDU or
+//! Original-Broadcast-NPDU frame and split a received datagram into its
+//! function and payload, using [`BvlcHeader`] for the header itself.
+//!
+//! # Examples
+//!
+//! ```
+//! use bacnet_rs::datalink::bip::BvlcFunction;
+//! use bacnet_rs::datalink::bvlc;
+//!
+//! // Who-Is as NPDU + APDU
+//! let npdu = [0x01, 0x00, 0x10, 0x08];
+//! let frame = bvlc::encode_original_broadcast(&npdu).unwrap();
+//! assert_eq!(frame[..4], [0x81, 0x0B, 0x00, 0x08]);
+//!
+//! let (function, payload) = bvlc::decode(&frame).unwrap();
+//! assert_eq!(function, BvlcFunction::OriginalBroadcastNpdu);
+//! assert_eq!(payload, npdu);
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::datalink::{
+    bip::{BvlcFunction, BvlcHeader},
+    DataLinkError, Result,
+};
+
+/// Length of the BVLC header.
+pub const BVLC_HEADER_LEN: usize = 4;
+
+/// Wrap an NPDU in an Original-Unicast-NPDU frame.
+///
+/// # Errors
+///
+/// Returns [`DataLinkError::InvalidFrame`] if the frame would exceed the
+/// 65535 bytes the length field can express.
+pub fn encode_original_unicast(npdu: &[u8]) -> Result<Vec<u8>> {
+    encode(BvlcFunction::OriginalUnicastNpdu, npdu)
+}
+
+/// Wrap an NPDU in an Original-Broadcast-NPDU frame.
+///
+/// # Errors
+///
+/// Returns [`DataLinkError::InvalidFrame`] if the frame would exceed the
+/// 65535 bytes the length field can express.
+pub fn encode_original_broadcast(npdu: &[u8]) -> Result<Vec<u8>> {
+    encode(BvlcFunction::OriginalBroadcastNpdu, npdu)
+}
+
+/// Wrap a payload in a BVLC frame with the given function.
+pub fn encode(function: BvlcFunction, payload: &[u8]) -> Result<Vec<u8>> {
+    let length =
+        u16::try_from(BVLC_HEADER_LEN + payload.len()).map_err(|_| DataLinkError::InvalidFrame)?;
+
+    let mut frame = BvlcHeader::new(function, length).encode();
+    frame.extend_from_slice(payload);
+    Ok(frame)
+}
+
+/// Split a BACnet/IP datagram into its BVLC function and payload.
+///
+/// The payload is everything after the header. For the NPDU functions this
+/// is the NPDU, except for Forwarded-NPDU where the 6-byte address of the
+/// originating device comes first.
+///
+/// # Errors
+///
+/// Returns [`DataLinkError::InvalidFrame`] if the type is not `0x81`, the
+/// function is unknown or the length field does not match the datagram.
+pub fn decode(data: &[u8]) -> Result<(BvlcFunction, &[u8])> {
+    let header = BvlcHeader::decode(data)?;
+    if header.length as usize != data.len() {
+        return Err(DataLinkError::InvalidFrame);
+    }
+
+    Ok((header.function, &data[BVLC_HEADER_LEN..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let npdu = [0x01, 0x04, 0x00, 0x05, 0x01, 0x0C];
+
+        let frame = encode_original_unicast(&npdu).unwrap();
+        assert_eq!(frame[..4], [0x81, 0x0A, 0x00, 0x0A]);
+        assert_eq!(
+            decode(&frame).unwrap(),
+            (BvlcFunction::OriginalUnicastNpdu, &npdu[..])
+        );
+
+        let frame = encode_original_broadcast(&npdu).unwrap();
+        assert_eq!(frame[..4], [0x81, 0x0B, 0x00, 0x0A]);
+        assert_eq!(
+            decode(&frame).unwrap(),
+            (BvlcFunction::OriginalBroadcastNpdu, &npdu[..])
+        );
+
+        assert!(encode_original_unicast(&[0; 65532]).is_err());
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_frames() {
+        let frame = encode_original_unicast(&[0x01, 0x00]).unwrap();
+
+        // Wrong type byte
+        let mut wrong_type = frame.clone();
+        wrong_type[0] = 0x82;
+        assert!(matches!(
+            decode(&wrong_type),
+            Err(DataLinkError::InvalidFrame)
+        ));
+
+        // Length field does not match the datagram
+        assert!(decode(&frame[..frame.len() - 1]).is_err());
+        let mut padded = frame.clone();
+        padded.push(0);
+        assert!(decode(&padded).is_err());
+
+        // Too short for a header
+        assert!(decode(&[0x81, 0x0A]).is_err());
+    }
+}
@@ -0,0 +1,368 @@
+//! Foreign Device Registration for BACnet/IP (ASHRAE 135 Annex J.5).
+//!
+//! A device that is not on the subnet of any BBMD, for example one behind
+//! NAT, can still receive BACnet broadcasts by registering with a BBMD as a
+//! foreign device. The registration expires after its Time-to-Live, so the
+//! device has to re-register periodically.
+//!
+//! [`ForeignDeviceClient`] sends the Register-Foreign-Device request, waits
+//! for the BVLC-Result from the BBMD and re-registers once half of the TTL
+//! has elapsed.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use bacnet_rs::datalink::foreign_device::ForeignDeviceClient;
+//! use std::net::UdpSocket;
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let socket = UdpSocket::bind("192.168.5.20:47808")?;
+//! let mut client = ForeignDeviceClient::new(socket);
+//!
+//! client.register("192.168.1.10:47808".parse()?, 300)?;
+//!
+//! // Call periodically to keep the registration alive
+//! client.maintain()?;
+//!
+//! client.unregister()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    io::{self, ErrorKind},
+    net::{SocketAddr, UdpSocket},
+    time::{Duration, Instant},
+};
+
+use crate::datalink::{
+    bbmd::encode_bip_address,
+    bip::{BvlcFunction, BvlcHeader, BvlcResult},
+    DataLinkError, Result,
+};
+
+/// Default time to wait for the BVLC-Result from the BBMD.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Source of the current time used to schedule re-registration.
+///
+/// Replacing the clock allows the refresh timing to be tested without
+/// waiting for the TTL to pass.
+pub trait Clock {
+    /// Get the current time.
+    fn now(&self) -> Instant;
+}
+
+/// Clock backed by [`Instant::now`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Active registration with a BBMD.
+#[derive(Debug, Clone, Copy)]
+struct Registration {
+    bbmd_addr: SocketAddr,
+    ttl: u16,
+    registered_at: Instant,
+}
+
+/// Foreign device registration client.
+///
+/// Owns the UDP socket used to talk to the BBMD. While waiting for a
+/// BVLC-Result, messages from other sources are discarded, so the client is
+/// intended to be driven from the same loop that receives BACnet traffic.
+pub struct ForeignDeviceClient<C: Clock = SystemClock> {
+    socket: UdpSocket,
+    clock: C,
+    timeout: Duration,
+    registration: Option<Registration>,
+}
+
+impl ForeignDeviceClient {
+    /// Create a client using the system clock.
+    pub fn new(socket: UdpSocket) -> Self {
+        Self::with_clock(socket, SystemClock)
+    }
+}
+
+impl<C: Clock> ForeignDeviceClient<C> {
+    /// Create a client using the given clock.
+    pub fn with_clock(socket: UdpSocket, clock: C) -> Self {
+        Self {
+            socket,
+            clock,
+            timeout: DEFAULT_TIMEOUT,
+            registration: None,
+        }
+    }
+
+    /// Set how long to wait for the BVLC-Result from the BBMD.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Address of the BBMD this client is registered with.
+    pub fn bbmd_address(&self) -> Option<SocketAddr> {
+        self.registration.map(|registration| registration.bbmd_addr)
+    }
+
+    /// Check if the client holds an active registration.
+    pub fn is_registered(&self) -> bool {
+        self.registration.is_some()
+    }
+
+    /// Time at which the registration should be refreshed.
+    ///
+    /// Re-registration is scheduled at half the TTL so that a lost request
+    /// can be retried before the BBMD drops the entry.
+    pub fn next_refresh(&self) -> Option<Instant> {
+        self.registration.map(|registration| {
+            registration.registered_at + Duration::from_secs(registration.ttl as u64 / 2)
+        })
+    }
+
+    /// Register with a BBMD as a foreign device.
+    ///
+    /// # Arguments
+    ///
+    /// * `bbmd_addr` - The IP address and port of the BBMD
+    /// * `ttl` - Time-to-live in seconds, must be non-zero
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DataLinkError::InvalidFrame`] for a TTL of zero,
+    /// [`DataLinkError::Rejected`] if the BBMD answers with a NAK and
+    /// [`DataLinkError::IoError`] if sending fails or no result arrives in
+    /// time.
+    pub fn register(&mut self, bbmd_addr: SocketAddr, ttl: u16) -> Result<()> {
+        if ttl == 0 {
+            return Err(DataLinkError::InvalidFrame);
+        }
+
+        let mut frame = BvlcHeader::new(BvlcFunction::RegisterForeignDevice, 6).encode();
+        frame.extend_from_slice(&ttl.to_be_bytes());
+        self.request(bbmd_addr, &frame)?;
+        self.registration = Some(Registration {
+            bbmd_addr,
+            ttl,
+            registered_at: self.clock.now(),
+        });
+
+        Ok(())
+    }
+
+    /// Re-register if the refresh time has been reached.
+    ///
+    /// Returns `true` if a registration was refreshed. The registration is
+    /// kept if refreshing fails, so the call can simply be repeated.
+    pub fn maintain(&mut self) -> Result<bool> {
+        let Some(registration) = self.registration else {
+            return Ok(false);
+        };
+
+        if self
+            .next_refresh()
+            .is_some_and(|refresh| self.clock.now() < refresh)
+        {
+            return Ok(false);
+        }
+
+        self.register(registration.bbmd_addr, registration.ttl)?;
+        Ok(true)
+    }
+
+    /// Cancel the registration.
+    ///
+    /// Sends a Delete-Foreign-Device-Table-Entry request for the address the
+    /// socket is bound to. Does nothing if the client is not registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DataLinkError::AddressError`] if the socket is bound to an
+    /// unspecified address, which cannot name the FDT entry, and otherwise
+    /// the errors of [`register`](Self::register). The registration is kept
+    /// if cancelling fails.
+    pub fn unregister(&mut self) -> Result<()> {
+        let Some(registration) = self.registration else {
+            return Ok(());
+        };
+
+        let address = self.socket.local_addr().map_err(DataLinkError::IoError)?;
+        if address.ip().is_unspecified() {
+            return Err(DataLinkError::AddressError(format!(
+                "{address} does not identify an FDT entry"
+            )));
+        }
+        let mut frame = BvlcHeader::new(BvlcFunction::DeleteForeignDeviceTableEntry, 10).encode();
+        encode_bip_address(address, &mut frame)?;
+        self.request(registration.bbmd_addr, &frame)?;
+        self.registration = None;
+
+        Ok(())
+    }
+
+    /// Send a request to the BBMD and wait for the result.
+    fn request(&mut self, bbmd_addr: SocketAddr, frame: &[u8]) -> Result<()> {
+        self.socket
+            .send_to(frame, bbmd_addr)
+            .map_err(DataLinkError::IoError)?;
+
+        let deadline = Instant::now() + self.timeout;
+        let mut buffer = [0u8; 1500];
+
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(DataLinkError::IoError(io::Error::new(
+                    ErrorKind::TimedOut,
+                    "no BVLC-Result from BBMD",
+                )));
+            }
+
+            self.socket
+                .set_read_timeout(Some(remaining))
+                .map_err(DataLinkError::IoError)?;
+
+            let (len, source) = match self.socket.recv_from(&mut buffer) {
+                Ok(received) => received,
+                Err(e) if e.kind() == ErrorKind::WouldBlock || e.kind() == ErrorKind::TimedOut => {
+                    continue
+                }
+                Err(e) => return Err(DataLinkError::IoError(e)),
+            };
+
+            if source != bbmd_addr {
+                continue;
+            }
+
+            if let Ok(result) = BvlcResult::from_bytes(&buffer[..len]) {
+                return match result {
+                    BvlcResult::SuccessfulCompletion => Ok(()),
+                    nak => Err(DataLinkError::Rejected(nak)),
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::Cell, rc::Rc, thread};
+
+    #[derive(Clone)]
+    struct MockClock(Rc<Cell<Instant>>);
+
+    impl MockClock {
+        fn advance(&self, secs: u64) {
+            self.0.set(self.0.get() + Duration::from_secs(secs));
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> Instant {
+            self.0.get()
+        }
+    }
+
+    /// Answer one request per result code and return the requests received.
+    fn spawn_bbmd(results: Vec<u16>) -> (SocketAddr, thread::JoinHandle<Vec<Vec<u8>>>) {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let addr = socket.local_addr().unwrap();
+
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for code in results {
+                let mut buffer = [0u8; 1500];
+                let (len, source) = socket.recv_from(&mut buffer).unwrap();
+                requests.push(buffer[..len].to_vec());
+
+                let mut reply = vec![0x81, 0x00, 0x00, 0x06];
+                reply.extend_from_slice(&code.to_be_bytes());
+                socket.send_to(&reply, source).unwrap();
+            }
+            requests
+        });
+
+        (addr, handle)
+    }
+
+    #[test]
+    fn test_register_and_refresh() {
+        let (bbmd_addr, bbmd) = spawn_bbmd(vec![0x0000, 0x0000, 0x0000]);
+        let clock = MockClock(Rc::new(Cell::new(Instant::now())));
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let mut client = ForeignDeviceClient::with_clock(socket, clock.clone());
+
+        client.register(bbmd_addr, 300).unwrap();
+        assert_eq!(client.bbmd_address(), Some(bbmd_addr));
+        assert_eq!(
+            client.next_refresh(),
+            Some(clock.now() + Duration::from_secs(150))
+        );
+
+        clock.advance(149);
+        assert!(!client.maintain().unwrap());
+
+        clock.advance(1);
+        assert!(client.maintain().unwrap());
+        assert_eq!(
+            client.next_refresh(),
+            Some(clock.now() + Duration::from_secs(150))
+        );
+
+        let port = client.socket.local_addr().unwrap().port().to_be_bytes();
+        client.unregister().unwrap();
+        assert!(!client.is_registered());
+        assert!(!client.maintain().unwrap());
+
+        assert_eq!(
+            bbmd.join().unwrap(),
+            vec![
+                vec![0x81, 0x05, 0x00, 0x06, 0x01, 0x2C],
+                vec![0x81, 0x05, 0x00, 0x06, 0x01, 0x2C],
+                vec![0x81, 0x08, 0x00, 0x0A, 127, 0, 0, 1, port[0], port[1]],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_register_nak() {
+        let (bbmd_addr, bbmd) = spawn_bbmd(vec![0x0030]);
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let mut client = ForeignDeviceClient::new(socket);
+
+        let err = client.register(bbmd_addr, 60).unwrap_err();
+        assert!(matches!(
+            err,
+            DataLinkError::Rejected(BvlcResult::RegisterForeignDeviceNak)
+        ));
+        assert!(!client.is_registered());
+        bbmd.join().unwrap();
+
+        assert!(matches!(
+            client.register(bbmd_addr, 0),
+            Err(DataLinkError::InvalidFrame)
+        ));
+    }
+
+    #[test]
+    fn test_unregister_needs_a_specified_address() {
+        let (bbmd_addr, bbmd) = spawn_bbmd(vec![0x0000]);
+        let socket = UdpSocket::bind("0.0.0.0:0").unwrap();
+        let mut client = ForeignDeviceClient::new(socket);
+        client.register(bbmd_addr, 60).unwrap();
+        bbmd.join().unwrap();
+
+        assert!(matches!(
+            client.unregister(),
+            Err(DataLinkError::AddressError(_))
+        ));
+        assert!(client.is_registered());
+    }
+}
@@ -193,6 +193,13 @@ pub enum DataLinkError {
     /// supported by the current implementation or when mixing incompatible
     /// address types with data link types.
     UnsupportedType,
+
+    /// A BVLC request was rejected by the peer.
+    ///
+    /// This error carries the BVLC-Result NAK returned by a BBMD, e.g.
+    /// [`bip::BvlcResult::RegisterForeignDeviceNak`] for a rejected foreign
+    /// device registration.
+    Rejected(bip::BvlcResult),
 }
 
 impl fmt::Display for DataLinkError {
@@ -204,6 +211,7 @@ impl fmt::Display for DataLinkError {
             DataLinkError::CrcError => write!(f, "CRC check failed"),
             DataLinkError::AddressError(msg) => write!(f, "Address error: {}", msg),
             DataLinkError::UnsupportedType => write!(f, "Unsupported data link type"),
+            DataLinkError::Rejected(result) => write!(f, "BVLC request rejected: {:?}", result),
         }
     }
 }
@@ -479,6 +487,27 @@ pub enum DataLinkAddress {
 /// device registration, and BBMD (BACnet Broadcast Management Device) support.
 pub mod bip;
 
+/// BVLC framing helpers for BACnet/IP (Annex J.2).
+///
+/// This module wraps NPDUs in Original-Unicast-NPDU and
+/// Original-Broadcast-NPDU frames and splits received datagrams into their
+/// BVLC function and payload.
+pub mod bvlc;
+
+/// BACnet Broadcast Management Device (Annex J.4).
+///
+/// This module maintains the Broadcast Distribution Table and Foreign Device
+/// Table of a BBMD and forwards broadcasts between BACnet/IP subnets.
+#[cfg(feature = "std")]
+pub mod bbmd;
+
+/// Foreign Device Registration (Annex J.5).
+///
+/// This module registers a BACnet/IP device with a remote BBMD so that it
+/// receives broadcasts from networks it is not directly attached to.
+#[cfg(feature = "std")]
+pub mod foreign_device;
+
 /// BACnet/Ethernet (ISO 8802-3) implementation.
 ///
 /// This module provides direct Ethernet frame communication for BACnet, using
@@ -500,9 +529,15 @@ pub mod mstp;
 /// pattern detection for troubleshooting.
 pub mod validation;
 
+#[cfg(feature = "std")]
+pub use bbmd::Bbmd;
+
 #[cfg(feature = "std")]
 pub use bip::BacnetIpDataLink;
 
+#[cfg(feature = "std")]
+pub use foreign_device::ForeignDeviceClient;
+
 #[cfg(feature = "std")]
 pub use ethernet::EthernetDataLink;
 
@@ -32,10 +32,10 @@ use std::{
 };
 
 #[cfg(not(feature = "std"))]
-use alloc::{collections::VecDeque, string::String, vec::Vec};
+use alloc::{collections::VecDeque, format, string::String, vec, vec::Vec};
 
 use crate::datalink::{DataLink, DataLinkAddress, DataLinkError, DataLinkType, Result};
-use crate::util::crc16_mstp;
+use crate::util::{crc16_mstp, crc8_mstp, crc8_mstp_verify};
 
 /// MS/TP frame preamble bytes
 pub const MSTP_PREAMBLE_55: u8 = 0x55;
@@ -130,7 +130,7 @@ impl MstpFrame {
             (data_length >> 8) as u8,
             (data_length & 0xFF) as u8,
         ];
-        let header_crc = calculate_header_crc(&header_bytes);
+        let header_crc = crc8_mstp(&header_bytes);
 
         // Calculate data CRC if there's data
         let data_crc = if !data.is_empty() {
@@ -219,9 +219,7 @@ impl MstpFrame {
         let header_crc = data[7];
 
         // Verify header CRC
-        let header_bytes = [data[2], data[3], data[4], data[5], data[6]];
-        let calculated_crc = calculate_header_crc(&header_bytes);
-        if calculated_crc != header_crc {
+        if !crc8_mstp_verify(&data[2..MSTP_HEADER_SIZE]) {
             return Err(DataLinkError::CrcError);
         }
 
@@ -365,6 +363,445 @@ impl Default for MstpConfig {
     }
 }
 
+/// Number of tokens passed between maintenance Poll-For-Master frames (Npoll)
+pub const MSTP_NPOLL: u8 = 50;
+
+/// Number of retries when the successor does not use the token (Nretry_token)
+const MSTP_NRETRY_TOKEN: u8 = 1;
+
+/// Encode an MS/TP frame
+pub fn encode_frame(
+    frame_type: MstpFrameType,
+    destination: u8,
+    source: u8,
+    data: &[u8],
+) -> Result<Vec<u8>> {
+    Ok(MstpFrame::new(frame_type, destination, source, data.to_vec())?.encode())
+}
+
+/// Outcome of decoding the start of an MS/TP byte stream
+#[derive(Debug, Clone)]
+pub enum MstpDecode {
+    /// A valid frame and the number of bytes it occupies
+    Frame(MstpFrame, usize),
+    /// The bytes so far are the start of a frame; wait for more data
+    Incomplete,
+    /// The first bytes are line noise or a corrupt frame and must be dropped
+    Skip(usize),
+}
+
+/// Decode the MS/TP frame at the start of a byte stream
+///
+/// Bytes before the preamble, a header with a bad CRC or a data length
+/// above [`MSTP_MAX_DATA_LENGTH`] are reported as bytes to skip. The
+/// length is only trusted once the header CRC is valid, and a frame whose
+/// data CRC fails is skipped as a whole.
+pub fn decode_frame(data: &[u8]) -> MstpDecode {
+    let Some(start) = data
+        .windows(2)
+        .position(|pair| pair == [MSTP_PREAMBLE_55, MSTP_PREAMBLE_FF])
+    else {
+        // A trailing 0x55 may be the start of the next preamble
+        let noise = match data.last() {
+            Some(&MSTP_PREAMBLE_55) => data.len() - 1,
+            _ => data.len(),
+        };
+        return match noise {
+            0 => MstpDecode::Incomplete,
+            noise => MstpDecode::Skip(noise),
+        };
+    };
+    if start > 0 {
+        return MstpDecode::Skip(start);
+    }
+
+    if data.len() < MSTP_HEADER_SIZE {
+        return MstpDecode::Incomplete;
+    }
+    if !crc8_mstp_verify(&data[2..MSTP_HEADER_SIZE]) {
+        // Resynchronise on the next preamble
+        return MstpDecode::Skip(2);
+    }
+
+    let data_length = ((data[5] as usize) << 8) | (data[6] as usize);
+    if data_length > MSTP_MAX_DATA_LENGTH {
+        return MstpDecode::Skip(MSTP_HEADER_SIZE);
+    }
+    let size = MSTP_HEADER_SIZE + data_length + if data_length > 0 { 2 } else { 0 };
+    if data.len() < size {
+        return MstpDecode::Incomplete;
+    }
+
+    match MstpFrame::decode(&data[..size]) {
+        Ok(frame) => MstpDecode::Frame(frame, size),
+        Err(_) => MstpDecode::Skip(size),
+    }
+}
+
+/// Outcome of driving the master node state machine
+#[derive(Debug, Clone, Default)]
+pub struct MstpOutput {
+    /// Frames to transmit, in order
+    pub transmit: Vec<MstpFrame>,
+    /// NPDU received for this station, with the source address
+    pub received: Option<(Vec<u8>, u8)>,
+}
+
+/// MS/TP master node state machine (Clause 9.5.6)
+///
+/// The node performs no I/O and keeps no timers. Received frames are passed
+/// to [`receive`](Self::receive) and the expiry of the timer belonging to the
+/// current state is signalled with [`timeout`](Self::timeout):
+///
+/// - `Idle`: T_no_token
+/// - `NoToken`: T_slot multiplied by the station address
+/// - `WaitForReply`: T_reply_timeout
+/// - `PassToken` and `PollForMaster`: T_usage_timeout
+/// - `AnswerDataRequest`: T_reply_delay
+///
+/// A station that finds no other master on the network becomes the sole
+/// master: it keeps the token, with [`next_station`](Self::next_station)
+/// pointing at itself, and periodically polls for masters that join later.
+#[derive(Debug, Clone)]
+pub struct MstpMasterNode {
+    /// This station (TS)
+    station: u8,
+    /// Highest master address on the network (Nmax_master)
+    max_master: u8,
+    /// Frames to send per token (Nmax_info_frames)
+    max_info_frames: u8,
+    /// Current state
+    state: MstpState,
+    /// Station the token is passed to (NS)
+    next_station: u8,
+    /// Station last polled for master (PS)
+    poll_station: u8,
+    /// Tokens passed since the last maintenance poll
+    token_count: u8,
+    /// Frames sent while holding the current token
+    frame_count: u8,
+    /// Token pass retries
+    retry_count: u8,
+    /// No other master is known
+    sole_master: bool,
+    /// Source of the data request being answered
+    reply_to: u8,
+    /// Frames waiting for the token
+    send_queue: VecDeque<MstpFrame>,
+}
+
+impl MstpMasterNode {
+    /// Create a master node in the Idle state
+    ///
+    /// The station address must be a master address not above `max_master`.
+    pub fn new(config: &MstpConfig) -> Result<Self> {
+        if config.max_master > 127 || config.station_address > config.max_master {
+            return Err(DataLinkError::AddressError(format!(
+                "Station {} is not a master address up to {}",
+                config.station_address, config.max_master
+            )));
+        }
+
+        Ok(Self {
+            station: config.station_address,
+            max_master: config.max_master,
+            max_info_frames: config.max_info_frames.max(1),
+            state: MstpState::Idle,
+            next_station: config.station_address,
+            poll_station: config.station_address,
+            token_count: MSTP_NPOLL,
+            frame_count: 0,
+            retry_count: 0,
+            sole_master: false,
+            reply_to: 0,
+            send_queue: VecDeque::new(),
+        })
+    }
+
+    /// Get the current state
+    pub fn state(&self) -> MstpState {
+        self.state
+    }
+
+    /// Get the station the token is passed to
+    ///
+    /// This is the node's own address while it is the sole master.
+    pub fn next_station(&self) -> u8 {
+        self.next_station
+    }
+
+    /// Check if this node is the only master on the network
+    pub fn is_sole_master(&self) -> bool {
+        self.sole_master
+    }
+
+    /// Queue an NPDU to be sent when this node holds the token
+    pub fn queue(&mut self, destination: u8, data: Vec<u8>, expecting_reply: bool) -> Result<()> {
+        let frame = MstpFrame::bacnet_data(destination, self.station, data, expecting_reply)?;
+        self.send_queue.push_back(frame);
+        Ok(())
+    }
+
+    /// Answer the data request received in the `AnswerDataRequest` state
+    pub fn reply(&mut self, data: Vec<u8>) -> Result<MstpOutput> {
+        if self.state != MstpState::AnswerDataRequest {
+            return Err(DataLinkError::InvalidFrame);
+        }
+
+        let frame = MstpFrame::bacnet_data(self.reply_to, self.station, data, false)?;
+        self.state = MstpState::Idle;
+
+        Ok(MstpOutput {
+            transmit: vec![frame],
+            received: None,
+        })
+    }
+
+    /// Process a received frame
+    pub fn receive(&mut self, frame: &MstpFrame) -> MstpOutput {
+        let mut output = MstpOutput::default();
+
+        let for_us = frame.destination == self.station;
+        if !for_us && frame.destination != 255 {
+            // Traffic between other stations means the token is in use
+            if self.state != MstpState::AnswerDataRequest {
+                self.state = MstpState::Idle;
+            }
+            return output;
+        }
+
+        match self.state {
+            MstpState::WaitForReply if for_us => match frame.frame_type {
+                MstpFrameType::Token
+                | MstpFrameType::PollForMaster
+                | MstpFrameType::ReplyToPollForMaster
+                | MstpFrameType::TestRequest => self.state = MstpState::Idle,
+                _ => {
+                    if frame.is_data() {
+                        output.received = Some((frame.data.clone(), frame.source));
+                    }
+                    self.use_token(&mut output);
+                }
+            },
+            MstpState::PollForMaster => {
+                if frame.frame_type == MstpFrameType::ReplyToPollForMaster && for_us {
+                    self.sole_master = false;
+                    self.next_station = frame.source;
+                    self.poll_station = self.station;
+                    self.token_count = 0;
+                    self.pass_token(&mut output);
+                } else {
+                    self.state = MstpState::Idle;
+                }
+            }
+            _ => {
+                self.state = MstpState::Idle;
+                self.receive_idle(frame, &mut output);
+            }
+        }
+
+        output
+    }
+
+    /// Process the expiry of the timer for the current state
+    pub fn timeout(&mut self) -> MstpOutput {
+        let mut output = MstpOutput::default();
+
+        match self.state {
+            MstpState::Idle => self.state = MstpState::NoToken,
+            MstpState::NoToken => {
+                // Generate a token by looking for a successor
+                self.poll_station = self.successor(self.station);
+                self.next_station = self.station;
+                self.token_count = 0;
+                self.poll_for_master(&mut output);
+            }
+            MstpState::WaitForReply => {
+                self.frame_count = self.max_info_frames;
+                self.done_with_token(&mut output);
+            }
+            MstpState::PassToken => {
+                if self.retry_count < MSTP_NRETRY_TOKEN {
+                    self.retry_count += 1;
+                    output.transmit.push(control_frame(
+                        MstpFrameType::Token,
+                        self.next_station,
+                        self.station,
+                    ));
+                } else {
+                    // The successor is gone, find a new one
+                    self.poll_station = self.successor(self.next_station);
+                    self.next_station = self.station;
+                    self.token_count = 0;
+                    self.poll_for_master(&mut output);
+                }
+            }
+            MstpState::PollForMaster => self.poll_ended(&mut output),
+            MstpState::AnswerDataRequest => {
+                output.transmit.push(control_frame(
+                    MstpFrameType::ReplyPostponed,
+                    self.reply_to,
+                    self.station,
+                ));
+                self.state = MstpState::Idle;
+            }
+            _ => {}
+        }
+
+        output
+    }
+
+    fn receive_idle(&mut self, frame: &MstpFrame, output: &mut MstpOutput) {
+        let for_us = frame.destination == self.station;
+
+        match frame.frame_type {
+            MstpFrameType::Token if for_us => {
+                self.frame_count = 0;
+                self.use_token(output);
+            }
+            MstpFrameType::PollForMaster if for_us => {
+                output.transmit.push(control_frame(
+                    MstpFrameType::ReplyToPollForMaster,
+                    frame.source,
+                    self.station,
+                ));
+            }
+            MstpFrameType::TestRequest if for_us => {
+                if let Ok(response) = MstpFrame::new(
+                    MstpFrameType::TestResponse,
+                    frame.source,
+                    self.station,
+                    frame.data.clone(),
+                ) {
+                    output.transmit.push(response);
+                }
+            }
+            MstpFrameType::BacnetDataExpectingReply if for_us => {
+                output.received = Some((frame.data.clone(), frame.source));
+                self.reply_to = frame.source;
+                self.state = MstpState::AnswerDataRequest;
+            }
+            MstpFrameType::BacnetDataExpectingReply
+            | MstpFrameType::BacnetDataNotExpectingReply => {
+                output.received = Some((frame.data.clone(), frame.source));
+            }
+            _ => {}
+        }
+    }
+
+    /// Send queued frames while holding the token
+    fn use_token(&mut self, output: &mut MstpOutput) {
+        self.state = MstpState::UseToken;
+
+        while self.frame_count < self.max_info_frames {
+            let Some(frame) = self.send_queue.pop_front() else {
+                break;
+            };
+            self.frame_count += 1;
+
+            let expecting_reply = frame.frame_type == MstpFrameType::BacnetDataExpectingReply
+                && frame.destination != 255;
+            output.transmit.push(frame);
+
+            if expecting_reply {
+                self.state = MstpState::WaitForReply;
+                return;
+            }
+        }
+
+        self.done_with_token(output);
+    }
+
+    fn done_with_token(&mut self, output: &mut MstpOutput) {
+        self.state = MstpState::DoneWithToken;
+
+        // A sole master passes the token to itself until the next poll is due
+        while self.sole_master && self.token_count < MSTP_NPOLL - 1 {
+            self.token_count += 1;
+            self.frame_count = 0;
+
+            if !self.send_queue.is_empty() {
+                self.use_token(output);
+                return;
+            }
+        }
+
+        if self.token_count < MSTP_NPOLL - 1 {
+            self.token_count += 1;
+            self.pass_token(output);
+        } else if self.successor(self.poll_station) != self.next_station {
+            // Maintenance poll of the next address between us and NS
+            self.poll_station = self.successor(self.poll_station);
+            self.poll_for_master(output);
+        } else if !self.sole_master {
+            self.poll_station = self.station;
+            self.token_count = 1;
+            self.pass_token(output);
+        } else {
+            self.poll_station = self.successor(self.next_station);
+            self.next_station = self.station;
+            self.token_count = 1;
+            self.poll_for_master(output);
+        }
+    }
+
+    /// No Reply-To-Poll-For-Master arrived for the polled station
+    fn poll_ended(&mut self, output: &mut MstpOutput) {
+        if self.sole_master {
+            self.frame_count = 0;
+            self.use_token(output);
+        } else if self.next_station != self.station {
+            self.pass_token(output);
+        } else if self.successor(self.poll_station) != self.station {
+            self.poll_station = self.successor(self.poll_station);
+            self.poll_for_master(output);
+        } else {
+            self.sole_master = true;
+            self.frame_count = 0;
+            self.use_token(output);
+        }
+    }
+
+    fn pass_token(&mut self, output: &mut MstpOutput) {
+        output.transmit.push(control_frame(
+            MstpFrameType::Token,
+            self.next_station,
+            self.station,
+        ));
+        self.retry_count = 0;
+        self.state = MstpState::PassToken;
+    }
+
+    fn poll_for_master(&mut self, output: &mut MstpOutput) {
+        output.transmit.push(control_frame(
+            MstpFrameType::PollForMaster,
+            self.poll_station,
+            self.station,
+        ));
+        self.retry_count = 0;
+        self.state = MstpState::PollForMaster;
+    }
+
+    fn successor(&self, station: u8) -> u8 {
+        (station + 1) % (self.max_master + 1)
+    }
+}
+
+/// Build a frame without data
+fn control_frame(frame_type: MstpFrameType, destination: u8, source: u8) -> MstpFrame {
+    let header = [frame_type as u8, destination, source, 0, 0];
+
+    MstpFrame {
+        frame_type,
+        destination,
+        source,
+        data_length: 0,
+        header_crc: crc8_mstp(&header),
+        data: Vec::new(),
+        data_crc: None,
+    }
+}
+
 /// Type alias for receive queue
 #[cfg(feature = "std")]
 type ReceiveQueue = Arc<Mutex<VecDeque<(Vec<u8>, DataLinkAddress)>>>;
@@ -540,24 +977,6 @@ impl DataLink for MstpDataLink {
     }
 }
 
-/// Calculate MS/TP header CRC
-fn calculate_header_crc(header: &[u8; 5]) -> u8 {
-    let mut crc = 0xFFu8;
-
-    for &byte in header {
-        crc ^= byte;
-        for _ in 0..8 {
-            if crc & 0x01 != 0 {
-                crc = (crc >> 1) ^ 0x55;
-            } else {
-                crc >>= 1;
-            }
-        }
-    }
-
-    !crc
-}
-
 /// Validate MS/TP address
 pub fn validate_mstp_address(address: u8) -> Result<()> {
     match address {
@@ -613,7 +1032,7 @@ mod tests {
     #[test]
     fn test_header_crc() {
         let header = [0x00, 0x05, 0x03, 0x00, 0x00]; // Token frame header
-        let crc = calculate_header_crc(&header);
+        let crc = crc8_mstp(&header);
 
         // Create frame and verify CRC matches
         let frame = MstpFrame::token(5, 3).unwrap();
@@ -688,4 +1107,221 @@ mod tests {
         let result = datalink.send_frame(&npdu, &DataLinkAddress::Broadcast);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_canonical_frames() {
+        // Token from station 5 to station 16 (Annex G.1)
+        let token = encode_frame(MstpFrameType::Token, 0x10, 0x05, &[]).unwrap();
+        assert_eq!(token, [0x55, 0xFF, 0x00, 0x10, 0x05, 0x00, 0x00, 0x8C]);
+
+        // Data CRC of 0x01 0x22 0x30 is 0xBD10, sent low octet first (Annex G.2)
+        let data = encode_frame(
+            MstpFrameType::BacnetDataNotExpectingReply,
+            0x10,
+            0x05,
+            &[0x01, 0x22, 0x30],
+        )
+        .unwrap();
+        assert_eq!(
+            data,
+            [0x55, 0xFF, 0x06, 0x10, 0x05, 0x00, 0x03, 0x9C, 0x01, 0x22, 0x30, 0x10, 0xBD]
+        );
+
+        // Leading line noise is skipped
+        let mut stream = vec![0x00, 0x55, 0x12];
+        stream.extend_from_slice(&data);
+        stream.extend_from_slice(&token);
+        assert!(matches!(decode_frame(&stream), MstpDecode::Skip(3)));
+        let MstpDecode::Frame(frame, consumed) = decode_frame(&stream[3..]) else {
+            panic!("expected a frame");
+        };
+        assert_eq!(consumed, data.len());
+        assert_eq!(frame.data, [0x01, 0x22, 0x30]);
+
+        let MstpDecode::Frame(frame, consumed) = decode_frame(&stream[3 + data.len()..]) else {
+            panic!("expected a frame");
+        };
+        assert_eq!(consumed, token.len());
+        assert!(frame.is_token());
+
+        // An incomplete frame needs more data, as may a trailing 0x55
+        assert!(matches!(decode_frame(&data[..10]), MstpDecode::Incomplete));
+        assert!(matches!(decode_frame(&data[..5]), MstpDecode::Incomplete));
+        assert!(matches!(decode_frame(&[0x00, 0x55]), MstpDecode::Skip(1)));
+        assert!(matches!(decode_frame(&[0x55]), MstpDecode::Incomplete));
+    }
+
+    #[test]
+    fn test_decode_frame_resynchronises() {
+        let data = encode_frame(
+            MstpFrameType::BacnetDataNotExpectingReply,
+            0x10,
+            0x05,
+            &[0x01, 0x22, 0x30],
+        )
+        .unwrap();
+
+        // A corrupt header is not trusted for its length
+        let mut corrupt = data.clone();
+        corrupt[6] = 0xFF;
+        assert!(matches!(decode_frame(&corrupt), MstpDecode::Skip(2)));
+
+        // A length above the MS/TP maximum is dropped with the header
+        let mut header = vec![0x55, 0xFF, 0x06, 0x10, 0x05, 0x02, 0x00];
+        header.push(crc8_mstp(&header[2..]));
+        assert!(matches!(
+            decode_frame(&header),
+            MstpDecode::Skip(MSTP_HEADER_SIZE)
+        ));
+
+        // A bad data CRC drops the whole frame
+        let mut corrupt = data.clone();
+        corrupt[9] ^= 0x01;
+        assert!(matches!(decode_frame(&corrupt), MstpDecode::Skip(13)));
+    }
+
+    fn master(station: u8, max_master: u8) -> MstpMasterNode {
+        MstpMasterNode::new(&MstpConfig {
+            station_address: station,
+            max_master,
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    fn summary(output: &MstpOutput) -> Vec<(MstpFrameType, u8)> {
+        output
+            .transmit
+            .iter()
+            .map(|frame| (frame.frame_type, frame.destination))
+            .collect()
+    }
+
+    #[test]
+    fn test_master_token_passing() {
+        let mut node = master(5, 10);
+        node.queue(20, vec![0x01, 0x00], false).unwrap();
+
+        // The first token triggers a maintenance poll for a successor
+        let output = node.receive(&MstpFrame::token(5, 3).unwrap());
+        assert_eq!(
+            summary(&output),
+            [
+                (MstpFrameType::BacnetDataNotExpectingReply, 20),
+                (MstpFrameType::PollForMaster, 6),
+            ]
+        );
+        assert_eq!(node.state(), MstpState::PollForMaster);
+
+        let reply = control_frame(MstpFrameType::ReplyToPollForMaster, 5, 6);
+        let output = node.receive(&reply);
+        assert_eq!(summary(&output), [(MstpFrameType::Token, 6)]);
+        assert_eq!(node.next_station(), 6);
+        assert_eq!(node.state(), MstpState::PassToken);
+
+        // The successor did not use the token: retry once, then look further
+        assert_eq!(summary(&node.timeout()), [(MstpFrameType::Token, 6)]);
+        assert_eq!(
+            summary(&node.timeout()),
+            [(MstpFrameType::PollForMaster, 7)]
+        );
+        assert_eq!(node.next_station(), 5);
+
+        // Traffic from the successor shows the token was taken
+        let mut node = master(5, 10);
+        node.receive(&MstpFrame::token(5, 3).unwrap());
+        node.receive(&reply);
+        node.receive(&MstpFrame::token(7, 6).unwrap());
+        assert_eq!(node.state(), MstpState::Idle);
+    }
+
+    #[test]
+    fn test_sole_master() {
+        let mut node = master(0, 2);
+
+        // Lost token: the node polls every address and finds nobody
+        assert!(node.timeout().transmit.is_empty());
+        assert_eq!(node.state(), MstpState::NoToken);
+        assert_eq!(
+            summary(&node.timeout()),
+            [(MstpFrameType::PollForMaster, 1)]
+        );
+        assert_eq!(
+            summary(&node.timeout()),
+            [(MstpFrameType::PollForMaster, 2)]
+        );
+
+        // After declaring itself sole master it keeps the token and restarts
+        // the maintenance poll
+        assert_eq!(
+            summary(&node.timeout()),
+            [(MstpFrameType::PollForMaster, 1)]
+        );
+        assert!(node.is_sole_master());
+        assert_eq!(node.next_station(), 0);
+
+        node.queue(1, vec![0x01, 0x00], false).unwrap();
+        assert_eq!(
+            summary(&node.timeout()),
+            [
+                (MstpFrameType::BacnetDataNotExpectingReply, 1),
+                (MstpFrameType::PollForMaster, 2),
+            ]
+        );
+
+        // A master joining the network ends sole master operation
+        let output = node.receive(&control_frame(MstpFrameType::ReplyToPollForMaster, 0, 2));
+        assert_eq!(summary(&output), [(MstpFrameType::Token, 2)]);
+        assert!(!node.is_sole_master());
+        assert_eq!(node.next_station(), 2);
+    }
+
+    #[test]
+    fn test_master_data_requests() {
+        let mut node = master(5, 10);
+
+        // Answering a confirmed request
+        let request = MstpFrame::bacnet_data(5, 8, vec![0x01, 0x04], true).unwrap();
+        let output = node.receive(&request);
+        assert_eq!(output.received, Some((vec![0x01, 0x04], 8)));
+        assert_eq!(node.state(), MstpState::AnswerDataRequest);
+
+        let output = node.reply(vec![0x01, 0x00]).unwrap();
+        assert_eq!(
+            summary(&output),
+            [(MstpFrameType::BacnetDataNotExpectingReply, 8)]
+        );
+        assert_eq!(node.state(), MstpState::Idle);
+
+        // No answer in time
+        node.receive(&request);
+        assert_eq!(
+            summary(&node.timeout()),
+            [(MstpFrameType::ReplyPostponed, 8)]
+        );
+
+        // Waiting for the reply to our own request
+        node.queue(8, vec![0x01, 0x04], true).unwrap();
+        let output = node.receive(&MstpFrame::token(5, 3).unwrap());
+        assert_eq!(
+            summary(&output),
+            [(MstpFrameType::BacnetDataExpectingReply, 8)]
+        );
+        assert_eq!(node.state(), MstpState::WaitForReply);
+
+        let reply = MstpFrame::bacnet_data(5, 8, vec![0x01, 0x00], false).unwrap();
+        let output = node.receive(&reply);
+        assert_eq!(output.received, Some((vec![0x01, 0x00], 8)));
+        assert_eq!(summary(&output), [(MstpFrameType::PollForMaster, 6)]);
+    }
+
+    #[test]
+    fn test_master_address_check() {
+        let config = MstpConfig {
+            station_address: 20,
+            max_master: 10,
+            ..Default::default()
+        };
+        assert!(MstpMasterNode::new(&config).is_err());
+    }
 }
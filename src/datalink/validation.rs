@@ -13,7 +13,7 @@
 //! - Common error detection patterns
 
 use crate::datalink::DataLinkType;
-use crate::util::crc16_mstp;
+use crate::util::{crc16_mstp, crc8_mstp};
 
 /// Frame validation result with detailed information
 #[derive(Debug, Clone)]
@@ -300,7 +300,7 @@ pub fn validate_mstp_frame(data: &[u8]) -> ValidationResult {
     // Verify header CRC
     let header_crc = data[7];
     let header_bytes = [data[2], data[3], data[4], data[5], data[6]];
-    let calculated_crc = calculate_mstp_header_crc(&header_bytes);
+    let calculated_crc = crc8_mstp(&header_bytes);
 
     if header_crc != calculated_crc {
         result.is_valid = false;
@@ -391,24 +391,6 @@ pub fn validate_frame(data: &[u8]) -> ValidationResult {
     }
 }
 
-/// Calculate MS/TP header CRC (for validation)
-fn calculate_mstp_header_crc(header: &[u8; 5]) -> u8 {
-    let mut crc = 0xFFu8;
-
-    for &byte in header {
-        crc ^= byte;
-        for _ in 0..8 {
-            if crc & 0x01 != 0 {
-                crc = (crc >> 1) ^ 0x55;
-            } else {
-                crc >>= 1;
-            }
-        }
-    }
-
-    !crc
-}
-
 /// Perform deep frame analysis
 pub fn analyze_frame(data: &[u8]) -> FrameAnalysis {
     let validation = validate_frame(data);
@@ -671,7 +653,7 @@ mod tests {
             0x02, // Destination
             0x01, // Source
             0x00, 0x00, // Data length = 0
-            0xFC, // Header CRC (correct value for this header)
+            0x73, // Header CRC (correct value for this header)
         ];
 
         let result = validate_mstp_frame(&frame);
@@ -685,6 +667,30 @@ mod tests {
         assert!(!result.is_valid);
     }
 
+    #[test]
+    fn test_mstp_validation_annex_g_frame() {
+        // Data frame from station 5 to station 16 with the Annex G CRCs
+        let frame = vec![
+            0x55, 0xFF, 0x06, 0x10, 0x05, 0x00, 0x03, 0x9C, 0x01, 0x22, 0x30, 0x10, 0xBD,
+        ];
+        let result = validate_mstp_frame(&frame);
+        assert!(result.is_valid);
+        assert!(result.errors.is_empty());
+
+        // CRCs computed with the 0x55 and 0xA001 polynomials are rejected
+        let mut bad_header = frame.clone();
+        bad_header[7] = 0x8B;
+        assert!(!validate_mstp_frame(&bad_header).is_valid);
+
+        let mut bad_data = frame.clone();
+        bad_data[11] = 0xC7;
+        bad_data[12] = 0x4B;
+        assert!(matches!(
+            validate_mstp_frame(&bad_data).errors[..],
+            [ValidationError::CrcMismatch { .. }]
+        ));
+    }
+
     #[test]
     fn test_auto_detection() {
         // MS/TP frame
@@ -47,15 +47,15 @@
 //! ## Encoding Basic Types
 //!
 //! ```rust
-//! use bacnet_rs::encoding::{encode_unsigned, encode_real, ApplicationTag};
+//! use bacnet_rs::encoding::{write_unsigned, write_real, ApplicationTag};
 //!
 //! let mut buffer = Vec::new();
 //!
 //! // Encode an unsigned integer with application tag
-//! encode_unsigned(&mut buffer, 42).unwrap();
+//! write_unsigned(&mut buffer, 42).unwrap();
 //!
 //! // Encode a real number with application tag
-//! encode_real(&mut buffer, 23.5).unwrap();
+//! write_real(&mut buffer, 23.5).unwrap();
 //!
 //! println!("Encoded {} bytes", buffer.len());
 //! ```
@@ -134,7 +134,9 @@ use core::fmt;
 use std::fmt;
 
 #[cfg(not(feature = "std"))]
-use alloc::{string::String, vec::Vec};
+use alloc::{borrow::Cow, collections::BTreeMap, string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::{borrow::Cow, collections::BTreeMap};
 
 use crate::object::ObjectIdentifier;
 
@@ -181,6 +183,127 @@ impl fmt::Display for EncodingError {
 #[cfg(feature = "std")]
 impl Error for EncodingError {}
 
+/// Output buffer written to by the primitive encoders
+///
+/// Implemented by `Vec<u8>`, which grows as needed and never fails, and by
+/// [`FixedBuffer`] for targets without a heap, which fails with
+/// [`EncodingError::BufferOverflow`] once it is full.
+pub trait BufWrite {
+    /// Append a single byte
+    fn push(&mut self, byte: u8) -> Result<()>;
+
+    /// Append all bytes of `data`, or none of them if they do not fit
+    fn extend_from_slice(&mut self, data: &[u8]) -> Result<()>;
+}
+
+impl BufWrite for Vec<u8> {
+    fn push(&mut self, byte: u8) -> Result<()> {
+        Vec::push(self, byte);
+        Ok(())
+    }
+
+    fn extend_from_slice(&mut self, data: &[u8]) -> Result<()> {
+        Vec::extend_from_slice(self, data);
+        Ok(())
+    }
+}
+
+/// Encode buffer with a fixed capacity of `N` bytes, stored inline
+///
+/// # Examples
+///
+/// ```
+/// use bacnet_rs::encoding::{write_unsigned, EncodingError, FixedBuffer};
+///
+/// let mut buffer = FixedBuffer::<4>::new();
+/// write_unsigned(&mut buffer, 1000).unwrap();
+/// assert_eq!(buffer.as_slice(), &[0x22, 0x03, 0xE8]);
+///
+/// assert!(matches!(
+///     write_unsigned(&mut buffer, 1000),
+///     Err(EncodingError::BufferOverflow)
+/// ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixedBuffer<const N: usize> {
+    data: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> FixedBuffer<N> {
+    /// Create an empty buffer
+    pub const fn new() -> Self {
+        Self {
+            data: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Number of bytes written
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if nothing has been written
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Total number of bytes the buffer can hold
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Number of bytes that can still be written
+    pub fn remaining(&self) -> usize {
+        N - self.len
+    }
+
+    /// The bytes written so far
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+
+    /// Discard the written bytes
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Default for FixedBuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for FixedBuffer<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize> BufWrite for FixedBuffer<N> {
+    fn push(&mut self, byte: u8) -> Result<()> {
+        if self.len == N {
+            return Err(EncodingError::BufferOverflow);
+        }
+
+        self.data[self.len] = byte;
+        self.len += 1;
+        Ok(())
+    }
+
+    fn extend_from_slice(&mut self, data: &[u8]) -> Result<()> {
+        if data.len() > self.remaining() {
+            return Err(EncodingError::BufferOverflow);
+        }
+
+        self.data[self.len..self.len + data.len()].copy_from_slice(data);
+        self.len += data.len();
+        Ok(())
+    }
+}
+
 /// BACnet application tag numbers
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
@@ -221,16 +344,23 @@ pub fn decode_tag(data: &[u8]) -> Result<(BACnetTag, usize, usize)> {
 
     let tag = if tag_type == 0 {
         BACnetTag::Application(ApplicationTag::try_from(tag_byte >> 4)?)
+    } else if tag_byte >> 4 == 0x0F {
+        // Extended tag number in the following octet
+        if data.len() < 2 {
+            return Err(EncodingError::BufferUnderflow);
+        }
+        consumed += 1;
+        BACnetTag::Context(data[1])
     } else {
         BACnetTag::Context(tag_byte >> 4)
     };
 
     if length == 5 {
-        if data.len() < 2 {
+        if data.len() < consumed + 1 {
             return Err(EncodingError::BufferUnderflow);
         }
 
-        let len_byte = data[1];
+        let len_byte = data[consumed];
         consumed += 1;
 
         match len_byte {
@@ -238,17 +368,22 @@ pub fn decode_tag(data: &[u8]) -> Result<(BACnetTag, usize, usize)> {
                 length = len_byte as usize;
             }
             254 => {
-                if data.len() < 4 {
+                if data.len() < consumed + 2 {
                     return Err(EncodingError::BufferUnderflow);
                 }
-                length = u16::from_be_bytes([data[2], data[3]]) as usize;
+                length = u16::from_be_bytes([data[consumed], data[consumed + 1]]) as usize;
                 consumed += 2;
             }
             255 => {
-                if data.len() < 6 {
+                if data.len() < consumed + 4 {
                     return Err(EncodingError::BufferUnderflow);
                 }
-                length = u32::from_be_bytes([data[2], data[3], data[4], data[5]]) as usize;
+                length = u32::from_be_bytes([
+                    data[consumed],
+                    data[consumed + 1],
+                    data[consumed + 2],
+                    data[consumed + 3],
+                ]) as usize;
                 consumed += 4;
             }
         }
@@ -258,26 +393,32 @@ pub fn decode_tag(data: &[u8]) -> Result<(BACnetTag, usize, usize)> {
 }
 
 /// Encode a BACnet application tag
-pub fn encode_application_tag(buffer: &mut Vec<u8>, tag: ApplicationTag, length: usize) {
+pub fn write_application_tag<B: BufWrite + ?Sized>(
+    buffer: &mut B,
+    tag: ApplicationTag,
+    length: usize,
+) -> Result<()> {
     let tag_byte = if length < 5 {
         (tag as u8) << 4 | (length as u8)
     } else {
         (tag as u8) << 4 | 5
     };
 
-    buffer.push(tag_byte);
+    buffer.push(tag_byte)?;
 
     if length >= 5 {
         if length < 254 {
-            buffer.push(length as u8);
+            buffer.push(length as u8)?;
         } else if length < 65536 {
-            buffer.push(254);
-            buffer.extend_from_slice(&(length as u16).to_be_bytes());
+            buffer.push(254)?;
+            buffer.extend_from_slice(&(length as u16).to_be_bytes())?;
         } else {
-            buffer.push(255);
-            buffer.extend_from_slice(&(length as u32).to_be_bytes());
+            buffer.push(255)?;
+            buffer.extend_from_slice(&(length as u32).to_be_bytes())?;
         }
     }
+
+    Ok(())
 }
 
 /// Decode a BACnet application tag
@@ -324,8 +465,8 @@ pub fn decode_application_tag(data: &[u8]) -> Result<(ApplicationTag, usize, usi
 }
 
 /// Encode a BACnet boolean value
-pub fn encode_boolean(buffer: &mut Vec<u8>, value: bool) -> Result<()> {
-    encode_application_tag(buffer, ApplicationTag::Boolean, if value { 1 } else { 0 });
+pub fn write_boolean<B: BufWrite + ?Sized>(buffer: &mut B, value: bool) -> Result<()> {
+    write_application_tag(buffer, ApplicationTag::Boolean, if value { 1 } else { 0 })?;
     Ok(())
 }
 
@@ -347,46 +488,52 @@ pub fn decode_boolean(data: &[u8]) -> Result<(bool, usize)> {
 }
 
 /// Encode a BACnet unsigned integer
-pub fn encode_unsigned(buffer: &mut Vec<u8>, value: u32) -> Result<()> {
-    let bytes = if value == 0 {
-        vec![0]
-    } else if value <= 0xFF {
-        vec![value as u8]
-    } else if value <= 0xFFFF {
-        (value as u16).to_be_bytes().to_vec()
-    } else if value <= 0xFFFFFF {
-        let bytes = value.to_be_bytes();
-        bytes[1..].to_vec()
-    } else {
-        value.to_be_bytes().to_vec()
-    };
-
-    encode_application_tag(buffer, ApplicationTag::UnsignedInt, bytes.len());
-    buffer.extend_from_slice(&bytes);
+pub fn write_unsigned<B: BufWrite + ?Sized>(buffer: &mut B, value: u32) -> Result<()> {
+    let length = unsigned_length(value);
+    write_application_tag(buffer, ApplicationTag::UnsignedInt, length)?;
+    buffer.extend_from_slice(&value.to_be_bytes()[4 - length..])?;
     Ok(())
 }
 
-pub fn encode_unsigned64(buffer: &mut Vec<u8>, value: u64) {
-    let bytes = if value == 0 {
-        vec![0]
-    } else if value <= 0xFF {
-        vec![value as u8]
-    } else if value <= 0xFFFF {
-        (value as u16).to_be_bytes().to_vec()
-    } else if value <= 0xFFFFFF {
-        let bytes = value.to_be_bytes();
-        bytes[1..].to_vec()
-    } else if value <= 0xFFFFFFFF {
-        (value as u32).to_be_bytes().to_vec()
-    } else {
-        value.to_be_bytes().to_vec()
-    };
+/// Number of octets in the shortest encoding of an unsigned value
+fn unsigned_length(value: u32) -> usize {
+    match value {
+        0..=0xFF => 1,
+        0x100..=0xFFFF => 2,
+        0x1_0000..=0xFF_FFFF => 3,
+        _ => 4,
+    }
+}
 
-    encode_application_tag(buffer, ApplicationTag::UnsignedInt, bytes.len());
-    buffer.extend_from_slice(&bytes);
+/// Number of octets in the shortest two's complement encoding of a signed
+/// value
+fn signed_length(value: i32) -> usize {
+    match value {
+        -0x80..=0x7F => 1,
+        -0x8000..=0x7FFF => 2,
+        -0x80_0000..=0x7F_FFFF => 3,
+        _ => 4,
+    }
+}
+
+/// Encode a BACnet unsigned integer of up to 64 bits
+pub fn write_unsigned64<B: BufWrite + ?Sized>(buffer: &mut B, value: u64) -> Result<()> {
+    // Values that do not fit in 32 bits always take all eight octets
+    let length = match u32::try_from(value) {
+        Ok(value) => unsigned_length(value),
+        Err(_) => 8,
+    };
+    write_application_tag(buffer, ApplicationTag::UnsignedInt, length)?;
+    buffer.extend_from_slice(&value.to_be_bytes()[8 - length..])?;
+    Ok(())
 }
 
 /// Decode a BACnet unsigned integer
+///
+/// The value must be 1-4 octets long; a zero-length value is rejected with
+/// [`EncodingError::InvalidLength`]. This strict form is what the service
+/// decoders use. See [`decode_unsigned_allow_empty`] for peers that send an
+/// empty value for zero.
 pub fn decode_unsigned(data: &[u8]) -> Result<(u32, usize)> {
     let (tag, length, mut consumed) = decode_application_tag(data)?;
 
@@ -418,6 +565,31 @@ pub fn decode_unsigned(data: &[u8]) -> Result<(u32, usize)> {
     Ok((value, consumed))
 }
 
+/// Decode a BACnet unsigned integer, treating a zero-length value as `0`
+///
+/// # Examples
+///
+/// ```
+/// use bacnet_rs::encoding::{decode_unsigned, decode_unsigned_allow_empty};
+///
+/// // Application tag 2 (Unsigned Integer) with a length of zero
+/// assert!(decode_unsigned(&[0x20]).is_err());
+/// assert_eq!(decode_unsigned_allow_empty(&[0x20]).unwrap(), (0, 1));
+/// ```
+pub fn decode_unsigned_allow_empty(data: &[u8]) -> Result<(u32, usize)> {
+    let (tag, length, consumed) = decode_application_tag(data)?;
+
+    if tag != ApplicationTag::UnsignedInt {
+        return Err(EncodingError::InvalidTag);
+    }
+
+    if length == 0 {
+        return Ok((0, consumed));
+    }
+
+    decode_unsigned(data)
+}
+
 /// Decode a BACnet unsigned integer into a u64
 pub fn decode_unsigned64(data: &[u8]) -> Result<(u64, usize)> {
     let (tag, length, mut consumed) = decode_application_tag(data)?;
@@ -441,39 +613,23 @@ pub fn decode_unsigned64(data: &[u8]) -> Result<(u64, usize)> {
 }
 
 /// Encode a BACnet signed integer
-pub fn encode_signed(buffer: &mut Vec<u8>, value: i32) -> Result<()> {
-    let bytes = if (-128..=127).contains(&value) {
-        vec![value as u8]
-    } else if (-32768..=32767).contains(&value) {
-        (value as i16).to_be_bytes().to_vec()
-    } else if (-8388608..=8388607).contains(&value) {
-        let bytes = value.to_be_bytes();
-        bytes[1..].to_vec()
-    } else {
-        value.to_be_bytes().to_vec()
-    };
-
-    encode_application_tag(buffer, ApplicationTag::SignedInt, bytes.len());
-    buffer.extend_from_slice(&bytes);
+pub fn write_signed<B: BufWrite + ?Sized>(buffer: &mut B, value: i32) -> Result<()> {
+    let length = signed_length(value);
+    write_application_tag(buffer, ApplicationTag::SignedInt, length)?;
+    buffer.extend_from_slice(&value.to_be_bytes()[4 - length..])?;
     Ok(())
 }
 
-pub fn encode_signed64(buffer: &mut Vec<u8>, value: i64) {
-    let bytes = if (-128..=127).contains(&value) {
-        vec![value as u8]
-    } else if (-32768..=32767).contains(&value) {
-        (value as i16).to_be_bytes().to_vec()
-    } else if (-8388608..=8388607).contains(&value) {
-        let bytes = value.to_be_bytes();
-        bytes[1..].to_vec()
-    } else if (i32::MIN as i64..=i32::MAX as i64).contains(&value) {
-        (value as i32).to_be_bytes().to_vec()
-    } else {
-        value.to_be_bytes().to_vec()
+/// Encode a BACnet signed integer of up to 64 bits
+pub fn write_signed64<B: BufWrite + ?Sized>(buffer: &mut B, value: i64) -> Result<()> {
+    // Values that do not fit in 32 bits always take all eight octets
+    let length = match i32::try_from(value) {
+        Ok(value) => signed_length(value),
+        Err(_) => 8,
     };
-
-    encode_application_tag(buffer, ApplicationTag::SignedInt, bytes.len());
-    buffer.extend_from_slice(&bytes);
+    write_application_tag(buffer, ApplicationTag::SignedInt, length)?;
+    buffer.extend_from_slice(&value.to_be_bytes()[8 - length..])?;
+    Ok(())
 }
 
 /// Decode a BACnet signed integer
@@ -561,9 +717,9 @@ pub fn decode_signed64(data: &[u8]) -> Result<(i64, usize)> {
 }
 
 /// Encode a BACnet real (float) value
-pub fn encode_real(buffer: &mut Vec<u8>, value: f32) -> Result<()> {
-    encode_application_tag(buffer, ApplicationTag::Real, 4);
-    buffer.extend_from_slice(&value.to_be_bytes());
+pub fn write_real<B: BufWrite + ?Sized>(buffer: &mut B, value: f32) -> Result<()> {
+    write_application_tag(buffer, ApplicationTag::Real, 4)?;
+    buffer.extend_from_slice(&value.to_be_bytes())?;
     Ok(())
 }
 
@@ -595,15 +751,23 @@ pub fn decode_real(data: &[u8]) -> Result<(f32, usize)> {
 }
 
 /// Encode a BACnet octet string
-pub fn encode_octet_string(buffer: &mut Vec<u8>, value: &[u8]) -> Result<()> {
-    encode_application_tag(buffer, ApplicationTag::OctetString, value.len());
-    buffer.extend_from_slice(value);
+pub fn write_octet_string<B: BufWrite + ?Sized>(buffer: &mut B, value: &[u8]) -> Result<()> {
+    write_application_tag(buffer, ApplicationTag::OctetString, value.len())?;
+    buffer.extend_from_slice(value)?;
     Ok(())
 }
 
 /// Decode a BACnet octet string
 pub fn decode_octet_string(data: &[u8]) -> Result<(Vec<u8>, usize)> {
-    let (tag, length, mut consumed) = decode_application_tag(data)?;
+    let (value, consumed) = decode_octet_string_ref(data)?;
+    Ok((value.to_vec(), consumed))
+}
+
+/// Decode a BACnet octet string without copying it
+///
+/// The returned slice borrows from `data`.
+pub fn decode_octet_string_ref(data: &[u8]) -> Result<(&[u8], usize)> {
+    let (tag, length, consumed) = decode_application_tag(data)?;
 
     if tag != ApplicationTag::OctetString {
         return Err(EncodingError::InvalidTag);
@@ -613,44 +777,184 @@ pub fn decode_octet_string(data: &[u8]) -> Result<(Vec<u8>, usize)> {
         return Err(EncodingError::BufferUnderflow);
     }
 
-    let value = data[consumed..consumed + length].to_vec();
-    consumed += length;
+    Ok((&data[consumed..consumed + length], consumed + length))
+}
 
-    Ok((value, consumed))
+/// BACnet character sets (Clause 20.2.9)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum CharacterSet {
+    /// ISO 10646 (UTF-8), formerly ANSI X3.4
+    Utf8 = 0,
+    /// IBM/Microsoft DBCS
+    Dbcs = 1,
+    /// JIS X 0208
+    JisX0208 = 2,
+    /// ISO 10646 (UCS-4)
+    Ucs4 = 3,
+    /// ISO 10646 (UCS-2), big-endian
+    Ucs2 = 4,
+    /// ISO 8859-1
+    Iso8859_1 = 5,
 }
 
-/// Encode a BACnet character string
-pub fn encode_character_string(buffer: &mut Vec<u8>, value: &str) -> Result<()> {
-    let string_bytes = value.as_bytes();
-    encode_application_tag(
+impl CharacterSet {
+    /// Transcode raw string octets in this character set into a `String`
+    ///
+    /// Returns `InvalidFormat` for character sets that are not supported
+    /// (DBCS and JIS X 0208) or for malformed data.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn decode(self, data: &[u8]) -> Result<String> {
+        match self {
+            CharacterSet::Utf8 => String::from_utf8(data.to_vec())
+                .map_err(|_| EncodingError::InvalidFormat("Invalid UTF-8 string".to_string())),
+            CharacterSet::Ucs2 => {
+                if data.len() % 2 != 0 {
+                    return Err(EncodingError::InvalidFormat(
+                        "Invalid UCS-2 string length".to_string(),
+                    ));
+                }
+                let units = data
+                    .chunks_exact(2)
+                    .map(|pair| u16::from_be_bytes([pair[0], pair[1]]));
+                char::decode_utf16(units)
+                    .collect::<core::result::Result<String, _>>()
+                    .map_err(|_| EncodingError::InvalidFormat("Invalid UCS-2 string".to_string()))
+            }
+            CharacterSet::Ucs4 => {
+                if data.len() % 4 != 0 {
+                    return Err(EncodingError::InvalidFormat(
+                        "Invalid UCS-4 string length".to_string(),
+                    ));
+                }
+                data.chunks_exact(4)
+                    .map(|quad| {
+                        char::from_u32(u32::from_be_bytes([quad[0], quad[1], quad[2], quad[3]]))
+                            .ok_or_else(|| {
+                                EncodingError::InvalidFormat("Invalid UCS-4 string".to_string())
+                            })
+                    })
+                    .collect()
+            }
+            CharacterSet::Iso8859_1 => Ok(data.iter().map(|&b| b as char).collect()),
+            CharacterSet::Dbcs | CharacterSet::JisX0208 => Err(EncodingError::InvalidFormat(
+                format!("Unsupported character set {}", self as u8),
+            )),
+        }
+    }
+
+    /// Transcode a string into octets in this character set
+    ///
+    /// Returns `InvalidFormat` for unsupported character sets or characters the
+    /// set cannot represent.
+    pub fn encode(self, value: &str) -> Result<Vec<u8>> {
+        match self {
+            CharacterSet::Utf8 => Ok(value.as_bytes().to_vec()),
+            CharacterSet::Ucs2 => Ok(value
+                .encode_utf16()
+                .flat_map(|unit| unit.to_be_bytes())
+                .collect()),
+            CharacterSet::Ucs4 => Ok(value
+                .chars()
+                .flat_map(|c| (c as u32).to_be_bytes())
+                .collect()),
+            CharacterSet::Iso8859_1 => value
+                .chars()
+                .map(|c| {
+                    u8::try_from(c as u32).map_err(|_| {
+                        EncodingError::InvalidFormat(
+                            "Character not representable in ISO 8859-1".to_string(),
+                        )
+                    })
+                })
+                .collect(),
+            CharacterSet::Dbcs | CharacterSet::JisX0208 => Err(EncodingError::InvalidFormat(
+                format!("Unsupported character set {}", self as u8),
+            )),
+        }
+    }
+}
+
+impl TryFrom<u8> for CharacterSet {
+    type Error = EncodingError;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(CharacterSet::Utf8),
+            1 => Ok(CharacterSet::Dbcs),
+            2 => Ok(CharacterSet::JisX0208),
+            3 => Ok(CharacterSet::Ucs4),
+            4 => Ok(CharacterSet::Ucs2),
+            5 => Ok(CharacterSet::Iso8859_1),
+            _ => Err(EncodingError::InvalidFormat(format!(
+                "Unknown character set {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// Encode a BACnet character string using UTF-8 (character set 0)
+pub fn write_character_string<B: BufWrite + ?Sized>(buffer: &mut B, value: &str) -> Result<()> {
+    write_character_string_with_set(buffer, value, CharacterSet::Utf8)
+}
+
+/// Encode a BACnet character string in the given character set
+pub fn write_character_string_with_set<B: BufWrite + ?Sized>(
+    buffer: &mut B,
+    value: &str,
+    character_set: CharacterSet,
+) -> Result<()> {
+    let string_bytes = character_set.encode(value)?;
+    write_application_tag(
         buffer,
         ApplicationTag::CharacterString,
         string_bytes.len() + 1,
-    );
-    buffer.push(0); // Character set encoding (0 = ANSI X3.4)
-    buffer.extend_from_slice(string_bytes);
+    )?;
+    buffer.push(character_set as u8)?;
+    buffer.extend_from_slice(&string_bytes)?;
     Ok(())
 }
 
 /// Decode a BACnet character string
+///
+/// The string is transcoded from the character set named in its first
+/// content octet.
 pub fn decode_character_string(data: &[u8]) -> Result<(String, usize)> {
+    let (value, consumed) = decode_character_string_cow(data)?;
+    Ok((value.into_owned(), consumed))
+}
+
+/// Decode a BACnet character string, borrowing it when possible
+///
+/// A UTF-8 string is returned as a slice of `data`; strings in other character
+/// sets have to be transcoded into an owned `String`.
+pub fn decode_character_string_cow(data: &[u8]) -> Result<(Cow<'_, str>, usize)> {
     let (tag, length, mut consumed) = decode_application_tag(data)?;
 
     if tag != ApplicationTag::CharacterString {
         return Err(EncodingError::InvalidTag);
     }
 
-    if data.len() < consumed + length || length == 0 {
+    // Even an empty string carries its character set octet
+    if length == 0 {
+        return Err(EncodingError::InvalidLength);
+    }
+    if data.len() < consumed + length {
         return Err(EncodingError::BufferUnderflow);
     }
 
-    // Skip character set encoding byte
-    let _encoding = data[consumed];
+    let character_set = CharacterSet::try_from(data[consumed])?;
     consumed += 1;
 
     let string_data = &data[consumed..consumed + length - 1];
-    let value = String::from_utf8(string_data.to_vec())
-        .map_err(|_| EncodingError::InvalidFormat("Invalid UTF-8 string".to_string()))?;
+    let value = match character_set {
+        CharacterSet::Utf8 => Cow::Borrowed(
+            core::str::from_utf8(string_data)
+                .map_err(|_| EncodingError::InvalidFormat("Invalid UTF-8 string".to_string()))?,
+        ),
+        _ => Cow::Owned(character_set.decode(string_data)?),
+    };
 
     consumed += length - 1;
 
@@ -658,23 +962,17 @@ pub fn decode_character_string(data: &[u8]) -> Result<(String, usize)> {
 }
 
 /// Encode a BACnet enumerated value
-pub fn encode_enumerated(buffer: &mut Vec<u8>, value: u32) {
-    let bytes = if value <= 0xFF {
-        vec![value as u8]
-    } else if value <= 0xFFFF {
-        (value as u16).to_be_bytes().to_vec()
-    } else if value <= 0xFFFFFF {
-        let bytes = value.to_be_bytes();
-        bytes[1..].to_vec()
-    } else {
-        value.to_be_bytes().to_vec()
-    };
-
-    encode_application_tag(buffer, ApplicationTag::Enumerated, bytes.len());
-    buffer.extend_from_slice(&bytes);
+pub fn write_enumerated<B: BufWrite + ?Sized>(buffer: &mut B, value: u32) -> Result<()> {
+    let length = unsigned_length(value);
+    write_application_tag(buffer, ApplicationTag::Enumerated, length)?;
+    buffer.extend_from_slice(&value.to_be_bytes()[4 - length..])?;
+    Ok(())
 }
 
 /// Decode a BACnet enumerated value
+///
+/// Like [`decode_unsigned`], a zero-length value is rejected. See
+/// [`decode_enumerated_allow_empty`] for the lenient form.
 pub fn decode_enumerated(data: &[u8]) -> Result<(u32, usize)> {
     let (tag, length, mut consumed) = decode_application_tag(data)?;
 
@@ -706,13 +1004,39 @@ pub fn decode_enumerated(data: &[u8]) -> Result<(u32, usize)> {
     Ok((value, consumed))
 }
 
+/// Decode a BACnet enumerated value, treating a zero-length value as `0`
+pub fn decode_enumerated_allow_empty(data: &[u8]) -> Result<(u32, usize)> {
+    let (tag, length, consumed) = decode_application_tag(data)?;
+
+    if tag != ApplicationTag::Enumerated {
+        return Err(EncodingError::InvalidTag);
+    }
+
+    if length == 0 {
+        return Ok((0, consumed));
+    }
+
+    decode_enumerated(data)
+}
+
 /// Encode a BACnet date
-pub fn encode_date(buffer: &mut Vec<u8>, year: u16, month: u8, day: u8, weekday: u8) -> Result<()> {
-    encode_application_tag(buffer, ApplicationTag::Date, 4);
-    buffer.push(((year - 1900) % 256) as u8);
-    buffer.push(month);
-    buffer.push(day);
-    buffer.push(weekday);
+pub fn write_date<B: BufWrite + ?Sized>(
+    buffer: &mut B,
+    year: u16,
+    month: u8,
+    day: u8,
+    weekday: u8,
+) -> Result<()> {
+    write_application_tag(buffer, ApplicationTag::Date, 4)?;
+    // 255 is the unspecified year, as returned by decode_date
+    if year == 255 {
+        buffer.push(255)?;
+    } else {
+        buffer.push((year.wrapping_sub(1900) % 256) as u8)?;
+    }
+    buffer.push(month)?;
+    buffer.push(day)?;
+    buffer.push(weekday)?;
     Ok(())
 }
 
@@ -742,18 +1066,18 @@ pub fn decode_date(data: &[u8]) -> Result<((u16, u8, u8, u8), usize)> {
 }
 
 /// Encode a BACnet time
-pub fn encode_time(
-    buffer: &mut Vec<u8>,
+pub fn write_time<B: BufWrite + ?Sized>(
+    buffer: &mut B,
     hour: u8,
     minute: u8,
     second: u8,
     hundredths: u8,
 ) -> Result<()> {
-    encode_application_tag(buffer, ApplicationTag::Time, 4);
-    buffer.push(hour);
-    buffer.push(minute);
-    buffer.push(second);
-    buffer.push(hundredths);
+    write_application_tag(buffer, ApplicationTag::Time, 4)?;
+    buffer.push(hour)?;
+    buffer.push(minute)?;
+    buffer.push(second)?;
+    buffer.push(hundredths)?;
     Ok(())
 }
 
@@ -779,12 +1103,15 @@ pub fn decode_time(data: &[u8]) -> Result<((u8, u8, u8, u8), usize)> {
 }
 
 /// Encode a BACnet object identifier
-pub fn encode_object_identifier(buffer: &mut Vec<u8>, object_id: ObjectIdentifier) -> Result<()> {
+pub fn write_object_identifier<B: BufWrite + ?Sized>(
+    buffer: &mut B,
+    object_id: ObjectIdentifier,
+) -> Result<()> {
     let object_id: u32 = object_id
         .try_into()
         .map_err(|_| EncodingError::ValueOutOfRange)?;
-    encode_application_tag(buffer, ApplicationTag::ObjectIdentifier, 4);
-    buffer.extend_from_slice(&object_id.to_be_bytes());
+    write_application_tag(buffer, ApplicationTag::ObjectIdentifier, 4)?;
+    buffer.extend_from_slice(&object_id.to_be_bytes())?;
     Ok(())
 }
 
@@ -816,9 +1143,9 @@ pub fn decode_object_identifier(data: &[u8]) -> Result<(ObjectIdentifier, usize)
 }
 
 /// Encode a BACnet double (64-bit float)
-pub fn encode_double(buffer: &mut Vec<u8>, value: f64) -> Result<()> {
-    encode_application_tag(buffer, ApplicationTag::Double, 8);
-    buffer.extend_from_slice(&value.to_be_bytes());
+pub fn write_double<B: BufWrite + ?Sized>(buffer: &mut B, value: f64) -> Result<()> {
+    write_application_tag(buffer, ApplicationTag::Double, 8)?;
+    buffer.extend_from_slice(&value.to_be_bytes())?;
     Ok(())
 }
 
@@ -850,62 +1177,164 @@ pub fn decode_double(data: &[u8]) -> Result<(f64, usize)> {
 }
 
 /// Encode a context-specific tag
-pub fn encode_context_tag(buffer: &mut Vec<u8>, tag_number: u8, length: usize) -> Result<()> {
-    if tag_number > 14 {
+///
+/// Tag numbers 0-14 fit in the initial octet. Tag numbers 15-254 use the
+/// extended form, where the tag nibble is 0xF and the tag number follows in
+/// the next octet (Clause 20.2.1.2).
+pub fn write_context_tag<B: BufWrite + ?Sized>(
+    buffer: &mut B,
+    tag_number: u8,
+    length: usize,
+) -> Result<()> {
+    if tag_number == 0xFF {
         return Err(EncodingError::ValueOutOfRange);
     }
 
+    let tag_nibble = if tag_number > 14 {
+        0xF0
+    } else {
+        tag_number << 4
+    };
+
     let tag_byte = if length < 5 {
-        0x08 | (tag_number << 4) | (length as u8)
+        0x08 | tag_nibble | (length as u8)
     } else {
-        0x08 | (tag_number << 4) | 5
+        0x08 | tag_nibble | 5
     };
 
-    buffer.push(tag_byte);
+    buffer.push(tag_byte)?;
+
+    if tag_number > 14 {
+        buffer.push(tag_number)?;
+    }
 
     if length >= 5 {
         if length < 254 {
-            buffer.push(length as u8);
+            buffer.push(length as u8)?;
         } else if length < 65536 {
-            buffer.push(254);
-            buffer.extend_from_slice(&(length as u16).to_be_bytes());
+            buffer.push(254)?;
+            buffer.extend_from_slice(&(length as u16).to_be_bytes())?;
         } else {
-            buffer.push(255);
-            buffer.extend_from_slice(&(length as u32).to_be_bytes());
+            buffer.push(255)?;
+            buffer.extend_from_slice(&(length as u32).to_be_bytes())?;
         }
     }
 
     Ok(())
 }
 
-/// Encode a context-specific unsigned integer
-pub fn encode_context_unsigned(value: u32, tag_number: u8) -> Result<Vec<u8>> {
-    let mut buffer = Vec::new();
+// The `encode_*` names below are the original `Vec`-only encoders, kept so
+// existing callers still build. New code should use the `write_*` encoders.
 
-    // Determine the number of bytes needed for the unsigned value
-    let bytes = if value == 0 {
-        vec![0]
-    } else if value <= 0xFF {
-        vec![value as u8]
-    } else if value <= 0xFFFF {
-        (value as u16).to_be_bytes().to_vec()
-    } else if value <= 0xFFFFFF {
-        let bytes = value.to_be_bytes();
-        bytes[1..].to_vec()
-    } else {
-        value.to_be_bytes().to_vec()
-    };
+/// Encode a BACnet application tag
+#[deprecated(since = "0.3.2", note = "use `write_application_tag`")]
+pub fn encode_application_tag(buffer: &mut Vec<u8>, tag: ApplicationTag, length: usize) {
+    // Writing to a Vec cannot fail
+    let _ = write_application_tag(buffer, tag, length);
+}
+
+/// Encode a BACnet boolean value
+#[deprecated(since = "0.3.2", note = "use `write_boolean`")]
+pub fn encode_boolean(buffer: &mut Vec<u8>, value: bool) -> Result<()> {
+    write_boolean(buffer, value)
+}
+
+/// Encode a BACnet unsigned integer
+#[deprecated(since = "0.3.2", note = "use `write_unsigned`")]
+pub fn encode_unsigned(buffer: &mut Vec<u8>, value: u32) -> Result<()> {
+    write_unsigned(buffer, value)
+}
+
+/// Encode a BACnet unsigned integer of up to 64 bits
+#[deprecated(since = "0.3.2", note = "use `write_unsigned64`")]
+pub fn encode_unsigned64(buffer: &mut Vec<u8>, value: u64) {
+    let _ = write_unsigned64(buffer, value);
+}
+
+/// Encode a BACnet signed integer
+#[deprecated(since = "0.3.2", note = "use `write_signed`")]
+pub fn encode_signed(buffer: &mut Vec<u8>, value: i32) -> Result<()> {
+    write_signed(buffer, value)
+}
+
+/// Encode a BACnet signed integer of up to 64 bits
+#[deprecated(since = "0.3.2", note = "use `write_signed64`")]
+pub fn encode_signed64(buffer: &mut Vec<u8>, value: i64) {
+    let _ = write_signed64(buffer, value);
+}
+
+/// Encode a BACnet real (float) value
+#[deprecated(since = "0.3.2", note = "use `write_real`")]
+pub fn encode_real(buffer: &mut Vec<u8>, value: f32) -> Result<()> {
+    write_real(buffer, value)
+}
+
+/// Encode a BACnet octet string
+#[deprecated(since = "0.3.2", note = "use `write_octet_string`")]
+pub fn encode_octet_string(buffer: &mut Vec<u8>, value: &[u8]) -> Result<()> {
+    write_octet_string(buffer, value)
+}
+
+/// Encode a BACnet character string using UTF-8 (character set 0)
+#[deprecated(since = "0.3.2", note = "use `write_character_string`")]
+pub fn encode_character_string(buffer: &mut Vec<u8>, value: &str) -> Result<()> {
+    write_character_string(buffer, value)
+}
+
+/// Encode a BACnet enumerated value
+#[deprecated(since = "0.3.2", note = "use `write_enumerated`")]
+pub fn encode_enumerated(buffer: &mut Vec<u8>, value: u32) {
+    let _ = write_enumerated(buffer, value);
+}
+
+/// Encode a BACnet date
+#[deprecated(since = "0.3.2", note = "use `write_date`")]
+pub fn encode_date(buffer: &mut Vec<u8>, year: u16, month: u8, day: u8, weekday: u8) -> Result<()> {
+    write_date(buffer, year, month, day, weekday)
+}
+
+/// Encode a BACnet time
+#[deprecated(since = "0.3.2", note = "use `write_time`")]
+pub fn encode_time(
+    buffer: &mut Vec<u8>,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    hundredths: u8,
+) -> Result<()> {
+    write_time(buffer, hour, minute, second, hundredths)
+}
 
-    // Encode the context tag
-    encode_context_tag(&mut buffer, tag_number, bytes.len())?;
+/// Encode a BACnet object identifier
+#[deprecated(since = "0.3.2", note = "use `write_object_identifier`")]
+pub fn encode_object_identifier(buffer: &mut Vec<u8>, object_id: ObjectIdentifier) -> Result<()> {
+    write_object_identifier(buffer, object_id)
+}
 
-    // Add the value bytes
-    buffer.extend_from_slice(&bytes);
+/// Encode a BACnet double (64-bit float)
+#[deprecated(since = "0.3.2", note = "use `write_double`")]
+pub fn encode_double(buffer: &mut Vec<u8>, value: f64) -> Result<()> {
+    write_double(buffer, value)
+}
+
+/// Encode a context-specific tag
+#[deprecated(since = "0.3.2", note = "use `write_context_tag`")]
+pub fn encode_context_tag(buffer: &mut Vec<u8>, tag_number: u8, length: usize) -> Result<()> {
+    write_context_tag(buffer, tag_number, length)
+}
 
+/// Encode a context-specific unsigned integer
+pub fn encode_context_unsigned(value: u32, tag_number: u8) -> Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+    let length = unsigned_length(value);
+    write_context_tag(&mut buffer, tag_number, length)?;
+    buffer.extend_from_slice(&value.to_be_bytes()[4 - length..]);
     Ok(buffer)
 }
 
 /// Decode a context-specific tag
+///
+/// Handles both the extended tag number form and extended lengths.
 pub fn decode_context_tag(data: &[u8]) -> Result<(u8, usize, usize)> {
     if data.is_empty() {
         return Err(EncodingError::InvalidTag);
@@ -916,16 +1345,24 @@ pub fn decode_context_tag(data: &[u8]) -> Result<(u8, usize, usize)> {
         return Err(EncodingError::InvalidTag);
     }
 
-    let tag_number = (tag_byte >> 4) & 0x0F;
+    let mut tag_number = (tag_byte >> 4) & 0x0F;
     let mut length = (tag_byte & 0x07) as usize;
     let mut consumed = 1;
 
-    if length == 5 {
+    if tag_number == 0x0F {
         if data.len() < 2 {
             return Err(EncodingError::BufferUnderflow);
         }
+        tag_number = data[1];
+        consumed += 1;
+    }
 
-        let len_byte = data[1];
+    if length == 5 {
+        if data.len() < consumed + 1 {
+            return Err(EncodingError::BufferUnderflow);
+        }
+
+        let len_byte = data[consumed];
         consumed += 1;
 
         match len_byte {
@@ -933,17 +1370,22 @@ pub fn decode_context_tag(data: &[u8]) -> Result<(u8, usize, usize)> {
                 length = len_byte as usize;
             }
             254 => {
-                if data.len() < 4 {
+                if data.len() < consumed + 2 {
                     return Err(EncodingError::BufferUnderflow);
                 }
-                length = u16::from_be_bytes([data[2], data[3]]) as usize;
+                length = u16::from_be_bytes([data[consumed], data[consumed + 1]]) as usize;
                 consumed += 2;
             }
             255 => {
-                if data.len() < 6 {
+                if data.len() < consumed + 4 {
                     return Err(EncodingError::BufferUnderflow);
                 }
-                length = u32::from_be_bytes([data[2], data[3], data[4], data[5]]) as usize;
+                length = u32::from_be_bytes([
+                    data[consumed],
+                    data[consumed + 1],
+                    data[consumed + 2],
+                    data[consumed + 3],
+                ]) as usize;
                 consumed += 4;
             }
         }
@@ -1001,6 +1443,140 @@ pub fn decode_context_enumerated(data: &[u8], expected_tag: u8) -> Result<(u32,
     decode_context_unsigned(data, expected_tag)
 }
 
+/// Encode a context-specific boolean
+///
+/// Unlike the application-tagged form, a context-tagged boolean carries its
+/// value in a single content octet.
+pub fn encode_context_boolean(value: bool, tag_number: u8) -> Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+    write_context_tag(&mut buffer, tag_number, 1)?;
+    buffer.push(value as u8);
+    Ok(buffer)
+}
+
+/// Decode a context-specific boolean
+pub fn decode_context_boolean(data: &[u8], expected_tag: u8) -> Result<(bool, usize)> {
+    let (tag_number, length, tag_consumed) = decode_context_tag(data)?;
+
+    if tag_number != expected_tag {
+        return Err(EncodingError::InvalidTag);
+    }
+
+    if length != 1 {
+        return Err(EncodingError::InvalidLength);
+    }
+
+    if data.len() < tag_consumed + 1 {
+        return Err(EncodingError::BufferUnderflow);
+    }
+
+    Ok((data[tag_consumed] != 0, tag_consumed + 1))
+}
+
+/// Encode a context-specific real
+pub fn encode_context_real(value: f32, tag_number: u8) -> Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+    write_context_tag(&mut buffer, tag_number, 4)?;
+    buffer.extend_from_slice(&value.to_be_bytes());
+    Ok(buffer)
+}
+
+/// Encode a context-specific signed integer in its shortest two's complement
+/// form
+pub fn encode_context_signed(value: i32, tag_number: u8) -> Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+    let length = signed_length(value);
+    write_context_tag(&mut buffer, tag_number, length)?;
+    buffer.extend_from_slice(&value.to_be_bytes()[4 - length..]);
+    Ok(buffer)
+}
+
+/// Decode a context-specific signed integer, sign-extending 1-3 octet forms
+pub fn decode_context_signed(data: &[u8], expected_tag: u8) -> Result<(i32, usize)> {
+    let (tag_number, length, tag_consumed) = decode_context_tag(data)?;
+
+    // Opening and closing tags are not values
+    if tag_number != expected_tag || data[0] & 0x07 >= 6 {
+        return Err(EncodingError::InvalidTag);
+    }
+
+    if !(1..=4).contains(&length) {
+        return Err(EncodingError::InvalidLength);
+    }
+
+    if data.len() < tag_consumed + length {
+        return Err(EncodingError::UnexpectedEndOfData);
+    }
+
+    let content = &data[tag_consumed..tag_consumed + length];
+    let mut value = if content[0] & 0x80 != 0 { -1i32 } else { 0 };
+    for &byte in content {
+        value = (value << 8) | byte as i32;
+    }
+
+    Ok((value, tag_consumed + length))
+}
+
+/// Decode a context-specific real
+pub fn decode_context_real(data: &[u8], expected_tag: u8) -> Result<(f32, usize)> {
+    let (tag_number, length, tag_consumed) = decode_context_tag(data)?;
+
+    if tag_number != expected_tag {
+        return Err(EncodingError::InvalidTag);
+    }
+
+    if length != 4 {
+        return Err(EncodingError::InvalidLength);
+    }
+
+    if data.len() < tag_consumed + 4 {
+        return Err(EncodingError::BufferUnderflow);
+    }
+
+    let value = f32::from_be_bytes([
+        data[tag_consumed],
+        data[tag_consumed + 1],
+        data[tag_consumed + 2],
+        data[tag_consumed + 3],
+    ]);
+
+    Ok((value, tag_consumed + 4))
+}
+
+/// Encode a context-specific character string using UTF-8 (character set 0)
+pub fn encode_context_character_string(value: &str, tag_number: u8) -> Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+    write_context_tag(&mut buffer, tag_number, value.len() + 1)?;
+    buffer.push(CharacterSet::Utf8 as u8);
+    buffer.extend_from_slice(value.as_bytes());
+    Ok(buffer)
+}
+
+/// Decode a context-specific character string
+///
+/// The string is transcoded from the character set named in its first
+/// content octet.
+pub fn decode_context_character_string(data: &[u8], expected_tag: u8) -> Result<(String, usize)> {
+    let (tag_number, length, tag_consumed) = decode_context_tag(data)?;
+
+    if tag_number != expected_tag {
+        return Err(EncodingError::InvalidTag);
+    }
+
+    // Even an empty string carries its character set octet
+    if length == 0 {
+        return Err(EncodingError::InvalidLength);
+    }
+    if data.len() < tag_consumed + length {
+        return Err(EncodingError::BufferUnderflow);
+    }
+
+    let character_set = CharacterSet::try_from(data[tag_consumed])?;
+    let value = character_set.decode(&data[tag_consumed + 1..tag_consumed + length])?;
+
+    Ok((value, tag_consumed + length))
+}
+
 /// Encode a context-specific object identifier
 pub fn encode_context_object_id(object_id: ObjectIdentifier, tag_number: u8) -> Result<Vec<u8>> {
     let mut buffer = Vec::new();
@@ -1009,7 +1585,7 @@ pub fn encode_context_object_id(object_id: ObjectIdentifier, tag_number: u8) ->
     let object_id: u32 = object_id.try_into()?;
 
     // Encode context tag with length 4
-    encode_context_tag(&mut buffer, tag_number, 4)?;
+    write_context_tag(&mut buffer, tag_number, 4)?;
 
     // Add the object identifier bytes
     buffer.extend_from_slice(&object_id.to_be_bytes());
@@ -1140,100 +1716,38 @@ pub mod advanced {
         use super::*;
 
         /// Encode a context-specific tag
-        pub fn encode_context_tag(
+        pub fn write_context_tag(
             buffer: &mut Vec<u8>,
             tag_number: u8,
             length: usize,
         ) -> Result<()> {
-            if tag_number > 14 {
-                return Err(EncodingError::ValueOutOfRange);
-            }
-
-            let tag_byte = if length < 5 {
-                0x08 | (tag_number << 4) | (length as u8)
-            } else {
-                0x08 | (tag_number << 4) | 5
-            };
-
-            buffer.push(tag_byte);
-
-            if length >= 5 {
-                if length < 254 {
-                    buffer.push(length as u8);
-                } else if length < 65536 {
-                    buffer.push(254);
-                    buffer.extend_from_slice(&(length as u16).to_be_bytes());
-                } else {
-                    buffer.push(255);
-                    buffer.extend_from_slice(&(length as u32).to_be_bytes());
-                }
-            }
-
-            Ok(())
+            crate::encoding::write_context_tag(buffer, tag_number, length)
         }
 
         /// Decode a context-specific tag
         pub fn decode_context_tag(data: &[u8]) -> Result<(u8, usize, usize)> {
-            if data.is_empty() {
-                return Err(EncodingError::InvalidTag);
-            }
-
-            let tag_byte = data[0];
-            if (tag_byte & 0x08) == 0 {
-                return Err(EncodingError::InvalidTag);
-            }
-
-            let tag_number = (tag_byte >> 4) & 0x0F;
-            let mut length = (tag_byte & 0x07) as usize;
-            let mut consumed = 1;
-
-            if length == 5 {
-                if data.len() < 2 {
-                    return Err(EncodingError::BufferUnderflow);
-                }
-
-                let len_byte = data[1];
-                consumed += 1;
-
-                match len_byte {
-                    0..=253 => {
-                        length = len_byte as usize;
-                    }
-                    254 => {
-                        if data.len() < 4 {
-                            return Err(EncodingError::BufferUnderflow);
-                        }
-                        length = u16::from_be_bytes([data[2], data[3]]) as usize;
-                        consumed += 2;
-                    }
-                    255 => {
-                        if data.len() < 6 {
-                            return Err(EncodingError::BufferUnderflow);
-                        }
-                        length = u32::from_be_bytes([data[2], data[3], data[4], data[5]]) as usize;
-                        consumed += 4;
-                    }
-                }
-            }
-
-            Ok((tag_number, length, consumed))
+            crate::encoding::decode_context_tag(data)
         }
 
         /// Encode opening tag for constructed data
         pub fn encode_opening_tag(buffer: &mut Vec<u8>, tag_number: u8) -> Result<()> {
-            if tag_number > 14 {
-                return Err(EncodingError::ValueOutOfRange);
-            }
-            buffer.push(0x0E | (tag_number << 4));
-            Ok(())
+            encode_constructed_tag(buffer, tag_number, 0x0E)
         }
 
         /// Encode closing tag for constructed data
         pub fn encode_closing_tag(buffer: &mut Vec<u8>, tag_number: u8) -> Result<()> {
-            if tag_number > 14 {
-                return Err(EncodingError::ValueOutOfRange);
+            encode_constructed_tag(buffer, tag_number, 0x0F)
+        }
+
+        fn encode_constructed_tag(buffer: &mut Vec<u8>, tag_number: u8, marker: u8) -> Result<()> {
+            match tag_number {
+                0..=14 => buffer.push(marker | (tag_number << 4)),
+                15..=254 => {
+                    buffer.push(marker | 0xF0);
+                    buffer.push(tag_number);
+                }
+                _ => return Err(EncodingError::ValueOutOfRange),
             }
-            buffer.push(0x0F | (tag_number << 4));
             Ok(())
         }
     }
@@ -1243,16 +1757,33 @@ pub mod advanced {
         use super::*;
 
         /// Encode a bit string
-        #[allow(clippy::manual_is_multiple_of)]
         pub fn encode_bit_string(buffer: &mut Vec<u8>, bits: &[bool]) -> Result<()> {
-            let byte_count = bits.len().div_ceil(8);
+            write_application_tag(
+                buffer,
+                ApplicationTag::BitString,
+                bits.len().div_ceil(8) + 1,
+            )?;
+            encode_bit_string_contents(buffer, bits);
+            Ok(())
+        }
+
+        /// Encode a context-specific bit string
+        pub fn encode_context_bit_string(bits: &[bool], tag_number: u8) -> Result<Vec<u8>> {
+            let mut buffer = Vec::new();
+            write_context_tag(&mut buffer, tag_number, bits.len().div_ceil(8) + 1)?;
+            encode_bit_string_contents(&mut buffer, bits);
+            Ok(buffer)
+        }
+
+        /// Encode the unused-bits octet and the packed bits
+        #[allow(clippy::manual_is_multiple_of)]
+        fn encode_bit_string_contents(buffer: &mut Vec<u8>, bits: &[bool]) {
             let unused_bits = if bits.len() % 8 == 0 {
                 0
             } else {
                 8 - (bits.len() % 8)
             };
 
-            encode_application_tag(buffer, ApplicationTag::BitString, byte_count + 1);
             buffer.push(unused_bits as u8);
 
             let mut current_byte = 0u8;
@@ -1274,8 +1805,6 @@ pub mod advanced {
             if bit_pos > 0 {
                 buffer.push(current_byte);
             }
-
-            Ok(())
         }
 
         /// Decode a bit string
@@ -1290,8 +1819,33 @@ pub mod advanced {
                 return Err(EncodingError::BufferUnderflow);
             }
 
-            let unused_bits = data[consumed] as usize;
-            consumed += 1;
+            let bits = decode_bit_string_contents(&data[consumed..consumed + length])?;
+            consumed += length;
+            Ok((bits, consumed))
+        }
+
+        /// Decode a context-specific bit string
+        pub fn decode_context_bit_string(
+            data: &[u8],
+            expected_tag: u8,
+        ) -> Result<(Vec<bool>, usize)> {
+            let (tag_number, length, consumed) = decode_context_tag(data)?;
+
+            if tag_number != expected_tag {
+                return Err(EncodingError::InvalidTag);
+            }
+
+            if length == 0 || data.len() < consumed + length {
+                return Err(EncodingError::BufferUnderflow);
+            }
+
+            let bits = decode_bit_string_contents(&data[consumed..consumed + length])?;
+            Ok((bits, consumed + length))
+        }
+
+        /// Decode the unused-bits octet and the packed bits that follow it
+        fn decode_bit_string_contents(contents: &[u8]) -> Result<Vec<bool>> {
+            let unused_bits = contents[0] as usize;
 
             if unused_bits > 7 {
                 return Err(EncodingError::InvalidFormat(
@@ -1299,24 +1853,192 @@ pub mod advanced {
                 ));
             }
 
-            let mut bits = Vec::new();
-            let byte_count = length - 1;
+            let mut bits = Vec::new();
+            let byte_count = contents.len() - 1;
+
+            for (i, &byte_val) in contents[1..].iter().enumerate() {
+                let bits_in_byte = if i == byte_count - 1 {
+                    8 - unused_bits
+                } else {
+                    8
+                };
+
+                for bit_pos in 0..bits_in_byte {
+                    bits.push((byte_val & (1 << (7 - bit_pos))) != 0);
+                }
+            }
+
+            Ok(bits)
+        }
+
+        /// BACnet bit string that keeps its octets as received
+        ///
+        /// Bit 0 is the most significant bit of the first octet. Unlike a
+        /// `Vec<bool>`, the unused bits of the last octet are carried through
+        /// unchanged, so decoding and encoding again reproduces the original
+        /// octets exactly.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use bacnet_rs::encoding::advanced::bitstring::BacnetBitString;
+        ///
+        /// let mut bits = BacnetBitString::with_len(7);
+        /// bits.set(1, true);
+        /// assert!(bits.get(1));
+        /// assert_eq!(bits.len_bits(), 7);
+        ///
+        /// let mut buffer = Vec::new();
+        /// bits.encode(&mut buffer).unwrap();
+        /// assert_eq!(buffer, [0x82, 0x01, 0x40]);
+        /// ```
+        #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+        pub struct BacnetBitString {
+            bytes: Vec<u8>,
+            unused_bits: u8,
+        }
+
+        impl BacnetBitString {
+            /// Create an empty bit string
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            /// Create a bit string of `len` cleared bits
+            pub fn with_len(len: usize) -> Self {
+                Self {
+                    bytes: vec![0; len.div_ceil(8)],
+                    unused_bits: ((8 - len % 8) % 8) as u8,
+                }
+            }
+
+            /// Create a bit string from its packed octets and the number of
+            /// unused bits in the last octet
+            pub fn from_bytes(bytes: Vec<u8>, unused_bits: u8) -> Result<Self> {
+                if unused_bits > 7 || (bytes.is_empty() && unused_bits != 0) {
+                    return Err(EncodingError::InvalidFormat(
+                        "Invalid unused bits count".to_string(),
+                    ));
+                }
+                Ok(Self { bytes, unused_bits })
+            }
+
+            /// Number of bits in the string
+            pub fn len_bits(&self) -> usize {
+                self.bytes.len() * 8 - self.unused_bits as usize
+            }
+
+            /// Check if the string has no bits
+            pub fn is_empty(&self) -> bool {
+                self.len_bits() == 0
+            }
+
+            /// Number of unused bits in the last octet
+            pub fn unused_bits(&self) -> u8 {
+                self.unused_bits
+            }
+
+            /// The packed octets, bit 0 first
+            pub fn as_bytes(&self) -> &[u8] {
+                &self.bytes
+            }
+
+            /// Get a bit, `false` for positions past the end of the string
+            pub fn get(&self, index: usize) -> bool {
+                index < self.len_bits() && self.bytes[index / 8] & (0x80 >> (index % 8)) != 0
+            }
+
+            /// Set a bit, extending the string with cleared bits if `index` is
+            /// past its end
+            pub fn set(&mut self, index: usize, value: bool) {
+                let len = self.len_bits();
+                if index >= len {
+                    // Bits that become used must not keep stale unused values
+                    for position in len..self.bytes.len() * 8 {
+                        self.bytes[position / 8] &= !(0x80 >> (position % 8));
+                    }
+                    self.bytes.resize((index + 1).div_ceil(8), 0);
+                    self.unused_bits = ((8 - (index + 1) % 8) % 8) as u8;
+                }
+
+                let mask = 0x80 >> (index % 8);
+                if value {
+                    self.bytes[index / 8] |= mask;
+                } else {
+                    self.bytes[index / 8] &= !mask;
+                }
+            }
+
+            /// Get the bits as a `Vec<bool>`, bit 0 first
+            pub fn to_bits(&self) -> Vec<bool> {
+                (0..self.len_bits()).map(|index| self.get(index)).collect()
+            }
+
+            /// Encode as an application-tagged bit string
+            pub fn encode(&self, buffer: &mut Vec<u8>) -> Result<()> {
+                write_application_tag(buffer, ApplicationTag::BitString, self.bytes.len() + 1)?;
+                buffer.push(self.unused_bits);
+                buffer.extend_from_slice(&self.bytes);
+                Ok(())
+            }
+
+            /// Encode as a context-tagged bit string
+            pub fn encode_context(&self, tag_number: u8) -> Result<Vec<u8>> {
+                let mut buffer = Vec::new();
+                write_context_tag(&mut buffer, tag_number, self.bytes.len() + 1)?;
+                buffer.push(self.unused_bits);
+                buffer.extend_from_slice(&self.bytes);
+                Ok(buffer)
+            }
+
+            /// Decode an application-tagged bit string, returning it with the
+            /// bytes consumed
+            pub fn decode(data: &[u8]) -> Result<(Self, usize)> {
+                let (tag, length, consumed) = decode_application_tag(data)?;
+
+                if tag != ApplicationTag::BitString {
+                    return Err(EncodingError::InvalidTag);
+                }
+
+                Self::decode_contents(data, consumed, length)
+            }
+
+            /// Decode a context-tagged bit string, returning it with the bytes
+            /// consumed
+            pub fn decode_context(data: &[u8], expected_tag: u8) -> Result<(Self, usize)> {
+                let (tag_number, length, consumed) = decode_context_tag(data)?;
+
+                if tag_number != expected_tag {
+                    return Err(EncodingError::InvalidTag);
+                }
+
+                Self::decode_contents(data, consumed, length)
+            }
+
+            fn decode_contents(data: &[u8], start: usize, length: usize) -> Result<(Self, usize)> {
+                if length == 0 || data.len() < start + length {
+                    return Err(EncodingError::BufferUnderflow);
+                }
 
-            for i in 0..byte_count {
-                let byte_val = data[consumed + i];
-                let bits_in_byte = if i == byte_count - 1 {
-                    8 - unused_bits
-                } else {
-                    8
-                };
+                let bits = Self::from_bytes(data[start + 1..start + length].to_vec(), data[start])?;
+                Ok((bits, start + length))
+            }
+        }
 
-                for bit_pos in 0..bits_in_byte {
-                    bits.push((byte_val & (1 << (7 - bit_pos))) != 0);
+        impl From<&[bool]> for BacnetBitString {
+            fn from(bits: &[bool]) -> Self {
+                let mut bit_string = Self::with_len(bits.len());
+                for (index, &bit) in bits.iter().enumerate() {
+                    bit_string.set(index, bit);
                 }
+                bit_string
             }
+        }
 
-            consumed += byte_count;
-            Ok((bits, consumed))
+        impl From<&BacnetBitString> for Vec<bool> {
+            fn from(bits: &BacnetBitString) -> Self {
+                bits.to_bits()
+            }
         }
     }
 
@@ -1516,24 +2238,24 @@ pub trait EncodableValue {
 
 impl EncodableValue for bool {
     fn encode_to(&self, _tag: ApplicationTag, buffer: &mut Vec<u8>) -> Result<()> {
-        encode_boolean(buffer, *self)
+        write_boolean(buffer, *self)
     }
 
     fn encode_context_to(&self, tag_number: u8, buffer: &mut Vec<u8>) -> Result<()> {
-        advanced::context::encode_context_tag(buffer, tag_number, if *self { 1 } else { 0 })
+        advanced::context::write_context_tag(buffer, tag_number, if *self { 1 } else { 0 })
     }
 }
 
 impl EncodableValue for u32 {
     fn encode_to(&self, _tag: ApplicationTag, buffer: &mut Vec<u8>) -> Result<()> {
-        encode_unsigned(buffer, *self)
+        write_unsigned(buffer, *self)
     }
 
     fn encode_context_to(&self, tag_number: u8, buffer: &mut Vec<u8>) -> Result<()> {
         let temp_buffer = Vec::new();
         let mut temp = temp_buffer;
-        encode_unsigned(&mut temp, *self)?;
-        advanced::context::encode_context_tag(buffer, tag_number, temp.len() - 1)?;
+        write_unsigned(&mut temp, *self)?;
+        advanced::context::write_context_tag(buffer, tag_number, temp.len() - 1)?;
         buffer.extend_from_slice(&temp[1..]);
         Ok(())
     }
@@ -1541,14 +2263,14 @@ impl EncodableValue for u32 {
 
 impl EncodableValue for i32 {
     fn encode_to(&self, _tag: ApplicationTag, buffer: &mut Vec<u8>) -> Result<()> {
-        encode_signed(buffer, *self)
+        write_signed(buffer, *self)
     }
 
     fn encode_context_to(&self, tag_number: u8, buffer: &mut Vec<u8>) -> Result<()> {
         let temp_buffer = Vec::new();
         let mut temp = temp_buffer;
-        encode_signed(&mut temp, *self)?;
-        advanced::context::encode_context_tag(buffer, tag_number, temp.len() - 1)?;
+        write_signed(&mut temp, *self)?;
+        advanced::context::write_context_tag(buffer, tag_number, temp.len() - 1)?;
         buffer.extend_from_slice(&temp[1..]);
         Ok(())
     }
@@ -1556,11 +2278,11 @@ impl EncodableValue for i32 {
 
 impl EncodableValue for f32 {
     fn encode_to(&self, _tag: ApplicationTag, buffer: &mut Vec<u8>) -> Result<()> {
-        encode_real(buffer, *self)
+        write_real(buffer, *self)
     }
 
     fn encode_context_to(&self, tag_number: u8, buffer: &mut Vec<u8>) -> Result<()> {
-        advanced::context::encode_context_tag(buffer, tag_number, 4)?;
+        advanced::context::write_context_tag(buffer, tag_number, 4)?;
         buffer.extend_from_slice(&self.to_be_bytes());
         Ok(())
     }
@@ -1568,11 +2290,11 @@ impl EncodableValue for f32 {
 
 impl EncodableValue for f64 {
     fn encode_to(&self, _tag: ApplicationTag, buffer: &mut Vec<u8>) -> Result<()> {
-        encode_double(buffer, *self)
+        write_double(buffer, *self)
     }
 
     fn encode_context_to(&self, tag_number: u8, buffer: &mut Vec<u8>) -> Result<()> {
-        advanced::context::encode_context_tag(buffer, tag_number, 8)?;
+        advanced::context::write_context_tag(buffer, tag_number, 8)?;
         buffer.extend_from_slice(&self.to_be_bytes());
         Ok(())
     }
@@ -1580,17 +2302,258 @@ impl EncodableValue for f64 {
 
 impl EncodableValue for &str {
     fn encode_to(&self, _tag: ApplicationTag, buffer: &mut Vec<u8>) -> Result<()> {
-        encode_character_string(buffer, self)
+        write_character_string(buffer, self)
     }
 
     fn encode_context_to(&self, tag_number: u8, buffer: &mut Vec<u8>) -> Result<()> {
-        advanced::context::encode_context_tag(buffer, tag_number, self.len() + 1)?;
+        advanced::context::write_context_tag(buffer, tag_number, self.len() + 1)?;
         buffer.push(0); // Character set
         buffer.extend_from_slice(self.as_bytes());
         Ok(())
     }
 }
 
+/// Length of the contents of a constructed value, i.e. the number of bytes
+/// before the closing tag matching `tag_number`
+///
+/// `data` starts just after the opening tag. Nested constructed values are
+/// skipped as a whole, so a closing tag inside them is not mistaken for the
+/// end of the value.
+pub(crate) fn constructed_value_length(data: &[u8], tag_number: u8) -> Result<usize> {
+    let mut pos = 0;
+    let mut depth = 0usize;
+
+    while pos < data.len() {
+        let (tag, length, consumed) = decode_tag(&data[pos..])?;
+        // Opening and closing tags are marked in the tag octet itself; a
+        // decoded length of 6 or 7 may also be an extended primitive length.
+        let marker = data[pos] & 0x07;
+        match tag {
+            // Opening tag
+            BACnetTag::Context(_) if marker == 6 => depth += 1,
+            // Closing tag
+            BACnetTag::Context(number) if marker == 7 => {
+                if depth == 0 {
+                    if number != tag_number {
+                        return Err(EncodingError::InvalidTag);
+                    }
+                    return Ok(pos);
+                }
+                depth -= 1;
+            }
+            // Application booleans carry their value in the tag itself
+            BACnetTag::Application(ApplicationTag::Boolean) => {}
+            _ => pos += length,
+        }
+        pos += consumed;
+    }
+
+    Err(EncodingError::UnexpectedEndOfData)
+}
+
+/// Length of the whole value starting at `data`, tag included
+///
+/// A constructed value is measured up to and including its matching closing
+/// tag, however deeply it nests. A closing tag with no opening tag before it
+/// is not a value.
+pub(crate) fn value_length(data: &[u8]) -> Result<usize> {
+    let (tag, length, consumed) = decode_tag(data)?;
+    let marker = data[0] & 0x07;
+    let total = match tag {
+        BACnetTag::Context(number) if marker == 6 => {
+            let contents = constructed_value_length(&data[consumed..], number)?;
+            let (_, _, closing) = decode_tag(&data[consumed + contents..])?;
+            consumed + contents + closing
+        }
+        BACnetTag::Context(_) if marker == 7 => return Err(EncodingError::InvalidTag),
+        BACnetTag::Application(ApplicationTag::Boolean) => consumed,
+        _ => consumed + length,
+    };
+
+    if total > data.len() {
+        return Err(EncodingError::UnexpectedEndOfData);
+    }
+    Ok(total)
+}
+
+/// Cursor over context-tagged service data
+///
+/// Like [`DecodingStream`], but aware of context tags and constructed
+/// values. Every read advances past what it returns, so decoders do not have
+/// to track offsets themselves.
+///
+/// ```
+/// use bacnet_rs::encoding::TagReader;
+///
+/// // [0] unsigned 5, [1] { unsigned 7 }
+/// let data = [0x09, 0x05, 0x1E, 0x21, 0x07, 0x1F];
+/// let mut reader = TagReader::new(&data);
+/// assert_eq!(reader.read_context_unsigned(0).unwrap(), 5);
+/// assert_eq!(reader.peek_context_tag(), Some(1));
+/// reader.expect_opening(1).unwrap();
+/// assert_eq!(reader.read_unsigned().unwrap(), 7);
+/// reader.expect_closing(1).unwrap();
+/// assert!(reader.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct TagReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> TagReader<'a> {
+    /// Create a reader positioned at the start of `data`
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    /// Current position in the data
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The data not read yet
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.data[self.position..]
+    }
+
+    /// Check if all data has been read
+    pub fn is_empty(&self) -> bool {
+        self.position >= self.data.len()
+    }
+
+    /// Tag number of the next tag, if it is a context tag
+    ///
+    /// Opening and closing tags are included.
+    pub fn peek_context_tag(&self) -> Option<u8> {
+        decode_context_tag(self.remaining())
+            .ok()
+            .map(|(tag_number, _, _)| tag_number)
+    }
+
+    /// Check if the next tag is the opening tag `tag_number`
+    pub fn is_opening(&self, tag_number: u8) -> bool {
+        self.is_constructed_tag(tag_number, 6)
+    }
+
+    /// Check if the next tag is the closing tag `tag_number`
+    pub fn is_closing(&self, tag_number: u8) -> bool {
+        self.is_constructed_tag(tag_number, 7)
+    }
+
+    /// Read the opening tag `tag_number`
+    pub fn expect_opening(&mut self, tag_number: u8) -> Result<()> {
+        self.expect_constructed_tag(tag_number, 6)
+    }
+
+    /// Read the closing tag `tag_number`
+    pub fn expect_closing(&mut self, tag_number: u8) -> Result<()> {
+        self.expect_constructed_tag(tag_number, 7)
+    }
+
+    /// Read a constructed value, returning the data between its opening and
+    /// closing tags
+    ///
+    /// Nested constructed values are part of the returned data.
+    pub fn read_constructed(&mut self, tag_number: u8) -> Result<&'a [u8]> {
+        self.expect_opening(tag_number)?;
+        let start = self.position;
+        let length = constructed_value_length(self.remaining(), tag_number)?;
+        self.position += length;
+        self.expect_closing(tag_number)?;
+        Ok(&self.data[start..start + length])
+    }
+
+    /// Read an application-tagged unsigned integer
+    pub fn read_unsigned(&mut self) -> Result<u32> {
+        self.read(decode_unsigned)
+    }
+
+    /// Read an application-tagged enumerated value
+    pub fn read_enumerated(&mut self) -> Result<u32> {
+        self.read(decode_enumerated)
+    }
+
+    /// Read an application-tagged object identifier
+    pub fn read_object_id(&mut self) -> Result<ObjectIdentifier> {
+        self.read(decode_object_identifier)
+    }
+
+    /// Read a context-tagged unsigned integer
+    pub fn read_context_unsigned(&mut self, tag_number: u8) -> Result<u32> {
+        self.read(|data| decode_context_unsigned(data, tag_number))
+    }
+
+    /// Read a context-tagged enumerated value
+    pub fn read_context_enumerated(&mut self, tag_number: u8) -> Result<u32> {
+        self.read(|data| decode_context_enumerated(data, tag_number))
+    }
+
+    /// Read a context-tagged boolean
+    pub fn read_context_boolean(&mut self, tag_number: u8) -> Result<bool> {
+        self.read(|data| decode_context_boolean(data, tag_number))
+    }
+
+    /// Read a context-tagged object identifier
+    pub fn read_context_object_id(&mut self, tag_number: u8) -> Result<ObjectIdentifier> {
+        self.read(|data| decode_context_object_id(data, tag_number))
+    }
+
+    /// Read a context-tagged character string
+    pub fn read_context_character_string(&mut self, tag_number: u8) -> Result<String> {
+        self.read(|data| decode_context_character_string(data, tag_number))
+    }
+
+    /// Read an optional context-tagged unsigned integer
+    ///
+    /// Returns `None`, without advancing, if the next tag is not the
+    /// primitive context tag `tag_number`.
+    pub fn read_optional_context_unsigned(&mut self, tag_number: u8) -> Result<Option<u32>> {
+        if self.peek_context_tag() != Some(tag_number)
+            || self.is_opening(tag_number)
+            || self.is_closing(tag_number)
+        {
+            return Ok(None);
+        }
+        self.read_context_unsigned(tag_number).map(Some)
+    }
+
+    /// Skip the next value, primitive or constructed
+    ///
+    /// Used to step over optional fields a decoder does not understand.
+    pub fn skip_value(&mut self) -> Result<()> {
+        self.position += value_length(self.remaining())?;
+        Ok(())
+    }
+
+    fn read<T>(&mut self, decode: impl FnOnce(&'a [u8]) -> Result<(T, usize)>) -> Result<T> {
+        let (value, consumed) = decode(self.remaining())?;
+        self.position += consumed;
+        Ok(value)
+    }
+
+    fn is_constructed_tag(&self, tag_number: u8, marker: u8) -> bool {
+        self.peek_context_tag() == Some(tag_number)
+            && self
+                .remaining()
+                .first()
+                .is_some_and(|tag| tag & 0x07 == marker)
+    }
+
+    fn expect_constructed_tag(&mut self, tag_number: u8, marker: u8) -> Result<()> {
+        if !self.is_constructed_tag(tag_number, marker) {
+            return Err(if self.is_empty() {
+                EncodingError::UnexpectedEndOfData
+            } else {
+                EncodingError::InvalidTag
+            });
+        }
+        let (_, _, consumed) = decode_context_tag(self.remaining())?;
+        self.position += consumed;
+        Ok(())
+    }
+}
+
 /// Decoding stream for efficient multi-value decoding
 pub struct DecodingStream<'a> {
     data: &'a [u8],
@@ -1708,6 +2671,16 @@ impl<'a> DecodingStream<'a> {
         Ok(())
     }
 
+    /// Skip a value that may be context tagged and constructed
+    ///
+    /// Unlike [`skip_value`](Self::skip_value), an opening tag skips
+    /// everything up to its matching closing tag, including nested
+    /// constructed values.
+    pub fn skip_constructed_value(&mut self) -> Result<()> {
+        self.position += value_length(&self.data[self.position..])?;
+        Ok(())
+    }
+
     /// Get current position
     pub fn position(&self) -> usize {
         self.position
@@ -1739,7 +2712,7 @@ impl PropertyArrayEncoder {
     /// Add a property value
     pub fn add_property<T: EncodableValue>(&mut self, property_id: u32, value: T) -> Result<()> {
         // Encode property identifier with context tag 0
-        advanced::context::encode_context_tag(&mut self.buffer, 0, 4)?;
+        advanced::context::write_context_tag(&mut self.buffer, 0, 4)?;
         self.buffer.extend_from_slice(&property_id.to_be_bytes());
 
         // Open context tag 1 for value
@@ -1787,7 +2760,7 @@ impl ErrorEncoder {
     /// Encode an error class and code
     pub fn encode_error(&mut self, error_class: u32, error_code: u32) -> Result<()> {
         // Error class with context tag 0
-        advanced::context::encode_context_tag(
+        advanced::context::write_context_tag(
             &mut self.buffer,
             0,
             if error_class <= 0xFF {
@@ -1798,10 +2771,10 @@ impl ErrorEncoder {
                 4
             },
         )?;
-        encode_enumerated(&mut self.buffer, error_class);
+        write_enumerated(&mut self.buffer, error_class)?;
 
         // Error code with context tag 1
-        advanced::context::encode_context_tag(
+        advanced::context::write_context_tag(
             &mut self.buffer,
             1,
             if error_code <= 0xFF {
@@ -1812,7 +2785,7 @@ impl ErrorEncoder {
                 4
             },
         )?;
-        encode_enumerated(&mut self.buffer, error_code);
+        write_enumerated(&mut self.buffer, error_code)?;
 
         Ok(())
     }
@@ -1997,10 +2970,17 @@ impl EncodingAnalyzer {
 }
 
 /// Encoding cache for frequently used values
+///
+/// Holds up to `max_size` encodings keyed by the hash of the original value.
+/// When full, storing a new encoding evicts the least recently used one.
 #[derive(Debug)]
 pub struct EncodingCache {
-    /// Cached encoded values
-    cache: Vec<CacheEntry>,
+    /// Cached encoded values by hash
+    cache: BTreeMap<u64, CacheEntry>,
+    /// Hashes by the tick of their last use, oldest first
+    recency: BTreeMap<u64, u64>,
+    /// Incremented on every use of an entry
+    tick: u64,
     /// Maximum cache size
     max_size: usize,
     /// Cache hit statistics
@@ -2012,22 +2992,19 @@ pub struct EncodingCache {
 /// Cache entry
 #[derive(Debug, Clone)]
 struct CacheEntry {
-    /// Hash of the original value
-    hash: u64,
     /// Encoded data
     encoded: Vec<u8>,
-    /// Access count
-    access_count: u32,
-    /// Last access time
-    #[cfg(feature = "std")]
-    last_access: std::time::Instant,
+    /// Tick of the last use
+    last_used: u64,
 }
 
 impl EncodingCache {
     /// Create a new encoding cache
     pub fn new(max_size: usize) -> Self {
         Self {
-            cache: Vec::with_capacity(max_size),
+            cache: BTreeMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
             max_size,
             hits: 0,
             misses: 0,
@@ -2036,45 +3013,56 @@ impl EncodingCache {
 
     /// Get cached encoding if available
     pub fn get(&mut self, hash: u64) -> Option<Vec<u8>> {
-        if let Some(entry) = self.cache.iter_mut().find(|e| e.hash == hash) {
-            entry.access_count += 1;
-            #[cfg(feature = "std")]
-            {
-                entry.last_access = std::time::Instant::now();
-            }
-            self.hits += 1;
-            Some(entry.encoded.clone())
-        } else {
+        let Some(entry) = self.cache.get_mut(&hash) else {
             self.misses += 1;
-            None
-        }
+            return None;
+        };
+
+        self.tick += 1;
+        self.recency.remove(&entry.last_used);
+        self.recency.insert(self.tick, hash);
+        entry.last_used = self.tick;
+        self.hits += 1;
+        Some(entry.encoded.clone())
     }
 
     /// Store encoded value in cache
     pub fn put(&mut self, hash: u64, encoded: Vec<u8>) {
-        // Check if already exists
-        if self.cache.iter().any(|e| e.hash == hash) {
+        if self.max_size == 0 || self.cache.contains_key(&hash) {
             return;
         }
 
-        // Remove least recently used if cache is full
         if self.cache.len() >= self.max_size {
-            self.cache.sort_by_key(|e| e.access_count);
-            self.cache.remove(0);
+            if let Some((_, oldest)) = self.recency.pop_first() {
+                self.cache.remove(&oldest);
+            }
         }
 
-        self.cache.push(CacheEntry {
+        self.tick += 1;
+        self.recency.insert(self.tick, hash);
+        self.cache.insert(
             hash,
-            encoded,
-            access_count: 1,
-            #[cfg(feature = "std")]
-            last_access: std::time::Instant::now(),
-        });
+            CacheEntry {
+                encoded,
+                last_used: self.tick,
+            },
+        );
+    }
+
+    /// Number of cached encodings
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Check if the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
     }
 
     /// Clear the cache
     pub fn clear(&mut self) {
         self.cache.clear();
+        self.recency.clear();
         self.hits = 0;
         self.misses = 0;
     }
@@ -2277,19 +3265,312 @@ mod tests {
 
     use super::*;
 
+    /// Octets written by the allocating encoders that write_unsigned and
+    /// friends replaced, to check the output did not change
+    fn reference_unsigned(value: u64) -> Vec<u8> {
+        let bytes = value.to_be_bytes();
+        let length = match value {
+            0..=0xFF => 1,
+            0x100..=0xFFFF => 2,
+            0x1_0000..=0xFF_FFFF => 3,
+            0x100_0000..=0xFFFF_FFFF => 4,
+            _ => 8,
+        };
+        bytes[8 - length..].to_vec()
+    }
+
+    fn reference_signed(value: i64) -> Vec<u8> {
+        let bytes = value.to_be_bytes();
+        let length = if (-128..=127).contains(&value) {
+            1
+        } else if (-32768..=32767).contains(&value) {
+            2
+        } else if (-8388608..=8388607).contains(&value) {
+            3
+        } else if (i32::MIN as i64..=i32::MAX as i64).contains(&value) {
+            4
+        } else {
+            8
+        };
+        bytes[8 - length..].to_vec()
+    }
+
+    #[test]
+    fn test_integer_encoding_matches_reference() {
+        let unsigned = [
+            0,
+            1,
+            0x7F,
+            0xFF,
+            0x100,
+            0xFFFF,
+            0x1_0000,
+            0xFF_FFFF,
+            0x100_0000,
+            u32::MAX as u64,
+            u32::MAX as u64 + 1,
+            u64::MAX,
+        ];
+        for value in unsigned {
+            let mut expected = Vec::new();
+            let bytes = reference_unsigned(value);
+            write_application_tag(&mut expected, ApplicationTag::UnsignedInt, bytes.len()).unwrap();
+            expected.extend_from_slice(&bytes);
+
+            let mut buffer = Vec::new();
+            write_unsigned64(&mut buffer, value).unwrap();
+            assert_eq!(buffer, expected, "unsigned {value:#X}");
+
+            if let Ok(value) = u32::try_from(value) {
+                let mut buffer = Vec::new();
+                write_unsigned(&mut buffer, value).unwrap();
+                assert_eq!(buffer, expected, "unsigned {value:#X}");
+
+                let mut buffer = Vec::new();
+                write_enumerated(&mut buffer, value).unwrap();
+                assert_eq!(buffer[1..], expected[1..], "enumerated {value:#X}");
+
+                let context = encode_context_unsigned(value, 2).unwrap();
+                assert_eq!(context[1..], bytes, "context unsigned {value:#X}");
+            }
+        }
+
+        let signed = [
+            0,
+            1,
+            -1,
+            127,
+            128,
+            -128,
+            -129,
+            32767,
+            32768,
+            -32768,
+            -32769,
+            8388607,
+            8388608,
+            -8388608,
+            -8388609,
+            i32::MAX as i64,
+            i32::MIN as i64,
+            i32::MAX as i64 + 1,
+            i32::MIN as i64 - 1,
+            i64::MAX,
+            i64::MIN,
+        ];
+        for value in signed {
+            let mut expected = Vec::new();
+            let bytes = reference_signed(value);
+            write_application_tag(&mut expected, ApplicationTag::SignedInt, bytes.len()).unwrap();
+            expected.extend_from_slice(&bytes);
+
+            let mut buffer = Vec::new();
+            write_signed64(&mut buffer, value).unwrap();
+            assert_eq!(buffer, expected, "signed {value}");
+
+            if let Ok(value) = i32::try_from(value) {
+                let mut buffer = Vec::new();
+                write_signed(&mut buffer, value).unwrap();
+                assert_eq!(buffer, expected, "signed {value}");
+
+                let context = encode_context_signed(value, 2).unwrap();
+                assert_eq!(context[1..], bytes, "context signed {value}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_encoding_cache_evicts_least_recently_used() {
+        let mut cache = EncodingCache::new(3);
+        cache.put(1, vec![0x21, 0x01]);
+        cache.put(2, vec![0x21, 0x02]);
+        cache.put(3, vec![0x21, 0x03]);
+
+        // Using 1 leaves 2 as the least recently used
+        assert_eq!(cache.get(1), Some(vec![0x21, 0x01]));
+        cache.put(4, vec![0x21, 0x04]);
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.get(2), None);
+        assert!(cache.get(1).is_some());
+        assert!(cache.get(3).is_some());
+        assert!(cache.get(4).is_some());
+
+        // 1 is now the oldest
+        cache.put(5, vec![0x21, 0x05]);
+        assert_eq!(cache.get(1), None);
+
+        assert_eq!(cache.hits, 4);
+        assert_eq!(cache.misses, 2);
+        assert!((cache.hit_ratio() - 4.0 / 6.0).abs() < f64::EPSILON);
+
+        // Storing a hash that is already cached keeps the first encoding
+        cache.put(5, vec![0x00]);
+        assert_eq!(cache.get(5), Some(vec![0x21, 0x05]));
+
+        let mut cache = EncodingCache::new(0);
+        cache.put(1, vec![0x00]);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_zero_length_unsigned_and_enumerated() {
+        // Tag headers with a length of zero
+        assert!(matches!(
+            decode_unsigned(&[0x20]),
+            Err(EncodingError::InvalidLength)
+        ));
+        assert!(matches!(
+            decode_enumerated(&[0x90]),
+            Err(EncodingError::InvalidLength)
+        ));
+        assert_eq!(decode_unsigned_allow_empty(&[0x20, 0xFF]).unwrap(), (0, 1));
+        assert_eq!(decode_enumerated_allow_empty(&[0x90]).unwrap(), (0, 1));
+
+        // Non-empty values decode as before
+        let mut buffer = Vec::new();
+        write_unsigned(&mut buffer, 0).unwrap();
+        assert_eq!(buffer, [0x21, 0x00]);
+        assert_eq!(decode_unsigned_allow_empty(&buffer).unwrap(), (0, 2));
+        assert_eq!(
+            decode_enumerated_allow_empty(&[0x91, 0x05]).unwrap(),
+            (5, 2)
+        );
+
+        // The tag is still checked
+        assert!(matches!(
+            decode_unsigned_allow_empty(&[0x90]),
+            Err(EncodingError::InvalidTag)
+        ));
+        assert!(matches!(
+            decode_enumerated_allow_empty(&[0x20]),
+            Err(EncodingError::InvalidTag)
+        ));
+    }
+
+    #[test]
+    fn test_context_signed() {
+        let cases: [(i32, &[u8]); 9] = [
+            (0, &[0x29, 0x00]),
+            (-1, &[0x29, 0xFF]),
+            (127, &[0x29, 0x7F]),
+            (-128, &[0x29, 0x80]),
+            (128, &[0x2A, 0x00, 0x80]),
+            (-129, &[0x2A, 0xFF, 0x7F]),
+            (-32769, &[0x2B, 0xFF, 0x7F, 0xFF]),
+            (0x7FFFFF, &[0x2B, 0x7F, 0xFF, 0xFF]),
+            (i32::MIN, &[0x2C, 0x80, 0x00, 0x00, 0x00]),
+        ];
+
+        for (value, encoded) in cases {
+            assert_eq!(encode_context_signed(value, 2).unwrap(), encoded);
+            assert_eq!(
+                decode_context_signed(encoded, 2).unwrap(),
+                (value, encoded.len())
+            );
+        }
+
+        assert!(decode_context_signed(&[0x19, 0xFF], 2).is_err());
+        assert!(decode_context_signed(&[0x2A, 0xFF], 2).is_err());
+        assert!(decode_context_signed(&[0x2E], 2).is_err());
+    }
+
+    #[test]
+    fn test_skip_constructed_value() {
+        // [3] { [0] unsigned 1, [1] { real, [2] { } }, true }, unsigned 9
+        let data = [
+            0x3E, 0x09, 0x01, 0x1E, 0x44, 0x42, 0x48, 0x00, 0x00, 0x2E, 0x2F, 0x1F, 0x11, 0x3F,
+            0x21, 0x09,
+        ];
+        let mut stream = DecodingStream::new(&data);
+        stream.skip_constructed_value().unwrap();
+        assert_eq!(stream.position(), 14);
+        assert_eq!(stream.decode_unsigned().unwrap(), 9);
+        assert!(!stream.has_data());
+
+        let mut reader = TagReader::new(&data);
+        reader.skip_value().unwrap();
+        reader.skip_value().unwrap();
+        assert!(reader.is_empty());
+
+        // Primitive context values are skipped by their length
+        assert_eq!(value_length(&[0x1A, 0x12, 0x34, 0x00]).unwrap(), 3);
+
+        // Missing closing tag, stray closing tag and short primitive value
+        assert!(matches!(
+            DecodingStream::new(&data[..13]).skip_constructed_value(),
+            Err(EncodingError::UnexpectedEndOfData)
+        ));
+        assert!(matches!(
+            DecodingStream::new(&data[13..]).skip_constructed_value(),
+            Err(EncodingError::InvalidTag)
+        ));
+        assert!(matches!(
+            value_length(&[0x1A, 0x12]),
+            Err(EncodingError::UnexpectedEndOfData)
+        ));
+    }
+
+    #[test]
+    fn test_tag_reader() {
+        // [0] object, [1] enumerated, [3] { real, [0] { unsigned } }, [4] true
+        let data = [
+            0x0C, 0x00, 0x00, 0x00, 0x01, 0x19, 0x55, 0x3E, 0x44, 0x42, 0x48, 0x00, 0x00, 0x0E,
+            0x21, 0x02, 0x0F, 0x3F, 0x49, 0x01,
+        ];
+        let mut reader = TagReader::new(&data);
+
+        assert_eq!(
+            reader.read_context_object_id(0).unwrap(),
+            ObjectIdentifier::new(ObjectType::AnalogInput, 1)
+        );
+        assert_eq!(reader.read_context_enumerated(1).unwrap(), 85);
+
+        // Optional tag 2 is absent, so nothing is read
+        assert_eq!(reader.read_optional_context_unsigned(2).unwrap(), None);
+        assert_eq!(reader.peek_context_tag(), Some(3));
+        assert!(reader.is_opening(3));
+        assert!(!reader.is_closing(3));
+        assert_eq!(reader.read_optional_context_unsigned(3).unwrap(), None);
+
+        // The closing tag of the nested value does not end tag 3
+        assert_eq!(reader.read_constructed(3).unwrap(), &data[8..17]);
+        assert_eq!(reader.position(), 18);
+        assert!(reader.read_context_boolean(4).unwrap());
+        assert!(reader.is_empty());
+        assert!(reader.peek_context_tag().is_none());
+
+        // Failed reads report an error
+        let mut reader = TagReader::new(&data);
+        assert!(reader.read_context_unsigned(1).is_err());
+        assert!(reader.expect_closing(0).is_err());
+        assert!(matches!(
+            TagReader::new(&data[..16]).read_constructed(0),
+            Err(EncodingError::InvalidTag)
+        ));
+        let mut reader = TagReader::new(&data[7..16]);
+        assert!(matches!(
+            reader.read_constructed(3),
+            Err(EncodingError::UnexpectedEndOfData)
+        ));
+        assert!(matches!(
+            TagReader::new(&[]).expect_opening(0),
+            Err(EncodingError::UnexpectedEndOfData)
+        ));
+    }
+
     #[test]
     fn test_encode_decode_boolean() {
         let mut buffer = Vec::new();
 
         // Test true
-        encode_boolean(&mut buffer, true).unwrap();
+        write_boolean(&mut buffer, true).unwrap();
         let (value, consumed) = decode_boolean(&buffer).unwrap();
         assert!(value);
         assert_eq!(consumed, 1);
 
         // Test false
         buffer.clear();
-        encode_boolean(&mut buffer, false).unwrap();
+        write_boolean(&mut buffer, false).unwrap();
         let (value, consumed) = decode_boolean(&buffer).unwrap();
         assert!(!value);
         assert_eq!(consumed, 1);
@@ -2302,7 +3583,7 @@ mod tests {
 
         for &test_value in &test_values {
             buffer.clear();
-            encode_unsigned(&mut buffer, test_value).unwrap();
+            write_unsigned(&mut buffer, test_value).unwrap();
             let (value, _) = decode_unsigned(&buffer).unwrap();
             assert_eq!(value, test_value);
         }
@@ -2315,7 +3596,7 @@ mod tests {
 
         for &test_value in &test_values {
             buffer.clear();
-            encode_signed(&mut buffer, test_value).unwrap();
+            write_signed(&mut buffer, test_value).unwrap();
             let (value, _) = decode_signed(&buffer).unwrap();
             assert_eq!(value, test_value);
         }
@@ -2336,7 +3617,7 @@ mod tests {
 
         for &test_value in &test_values {
             buffer.clear();
-            encode_real(&mut buffer, test_value).unwrap();
+            write_real(&mut buffer, test_value).unwrap();
             let (value, _) = decode_real(&buffer).unwrap();
             assert_eq!(value, test_value);
         }
@@ -2349,18 +3630,159 @@ mod tests {
 
         for &test_string in &test_strings {
             buffer.clear();
-            encode_character_string(&mut buffer, test_string).unwrap();
+            write_character_string(&mut buffer, test_string).unwrap();
             let (value, _) = decode_character_string(&buffer).unwrap();
             assert_eq!(value, test_string);
         }
     }
 
+    #[test]
+    fn test_short_character_strings() {
+        // The empty string is just the character set octet
+        let mut buffer = Vec::new();
+        write_character_string(&mut buffer, "").unwrap();
+        assert_eq!(buffer, vec![0x71, 0x00]);
+        assert_eq!(
+            decode_character_string(&buffer).unwrap(),
+            (String::new(), 2)
+        );
+
+        let mut buffer = Vec::new();
+        write_character_string(&mut buffer, "A").unwrap();
+        assert_eq!(buffer, vec![0x72, 0x00, b'A']);
+        assert_eq!(
+            decode_character_string(&buffer).unwrap(),
+            ("A".to_string(), 3)
+        );
+
+        // Without the character set octet the string is malformed
+        assert!(matches!(
+            decode_character_string(&[0x70]),
+            Err(EncodingError::InvalidLength)
+        ));
+        assert!(matches!(
+            decode_character_string(&[0x72, 0x00]),
+            Err(EncodingError::BufferUnderflow)
+        ));
+
+        // Context tagged
+        let buffer = encode_context_character_string("", 4).unwrap();
+        assert_eq!(buffer, vec![0x49, 0x00]);
+        assert_eq!(
+            decode_context_character_string(&buffer, 4).unwrap(),
+            (String::new(), 2)
+        );
+        assert!(matches!(
+            decode_context_character_string(&[0x48], 4),
+            Err(EncodingError::InvalidLength)
+        ));
+    }
+
+    #[test]
+    fn test_borrowed_string_decoding() {
+        let mut buffer = Vec::new();
+        write_octet_string(&mut buffer, &[0xDE, 0xAD, 0xBE, 0xEF]).unwrap();
+        let (octets, consumed) = decode_octet_string_ref(&buffer).unwrap();
+        assert_eq!(octets, [0xDE, 0xAD, 0xBE, 0xEF]);
+        assert_eq!(consumed, buffer.len());
+        assert!(core::ptr::eq(octets.as_ptr(), buffer[1..].as_ptr()));
+
+        let mut buffer = Vec::new();
+        write_character_string(&mut buffer, "Zone Temp").unwrap();
+        let (value, consumed) = decode_character_string_cow(&buffer).unwrap();
+        assert_eq!(consumed, buffer.len());
+        match value {
+            Cow::Borrowed(value) => {
+                assert_eq!(value, "Zone Temp");
+                assert!(core::ptr::eq(value.as_ptr(), buffer[3..].as_ptr()));
+            }
+            Cow::Owned(_) => panic!("Expected a borrowed UTF-8 string"),
+        }
+
+        // Other character sets are transcoded
+        let mut buffer = Vec::new();
+        write_character_string_with_set(&mut buffer, "Zone Temp", CharacterSet::Ucs2).unwrap();
+        let (value, _) = decode_character_string_cow(&buffer).unwrap();
+        assert!(matches!(value, Cow::Owned(ref value) if value == "Zone Temp"));
+
+        assert!(decode_character_string_cow(&[0x73, 0x00, 0xC3, 0x28]).is_err());
+        assert!(decode_octet_string_ref(&[0x62, 0x01]).is_err());
+    }
+
+    #[test]
+    fn test_long_character_strings() {
+        // The character set octet counts towards the length, so 253 string
+        // octets are the first to need the two-octet extended length
+        for (string_length, header) in [
+            (0, vec![0x71]),
+            (252, vec![0x75, 253]),
+            (253, vec![0x75, 254, 0x00, 0xFE]),
+            (65535, vec![0x75, 255, 0x00, 0x01, 0x00, 0x00]),
+        ] {
+            let value = "x".repeat(string_length);
+            let mut buffer = Vec::new();
+            write_character_string(&mut buffer, &value).unwrap();
+            assert_eq!(&buffer[..header.len()], header.as_slice());
+            assert_eq!(buffer.len(), header.len() + 1 + string_length);
+            assert_eq!(
+                decode_character_string(&buffer).unwrap(),
+                (value.clone(), buffer.len())
+            );
+
+            let buffer = encode_context_character_string(&value, 3).unwrap();
+            assert_eq!(
+                decode_context_character_string(&buffer, 3).unwrap(),
+                (value, buffer.len())
+            );
+        }
+    }
+
+    #[test]
+    fn test_character_sets() {
+        // "Überwachung" in UCS-2, as sent by many European devices
+        let mut data = vec![0x75, 0x17, 0x04];
+        for c in "Überwachung".encode_utf16() {
+            data.extend_from_slice(&c.to_be_bytes());
+        }
+        assert_eq!(&data[3..5], &[0x00, 0xDC]);
+        let (value, consumed) = decode_character_string(&data).unwrap();
+        assert_eq!(value, "Überwachung");
+        assert_eq!(consumed, data.len());
+
+        // ISO 8859-1 uses one octet per character
+        let data = [0x75, 0x05, 0x05, 0xDC, b'b', b'e', b'r'];
+        assert_eq!(decode_character_string(&data).unwrap().0, "Über");
+
+        let mut buffer = Vec::new();
+        for set in [
+            CharacterSet::Utf8,
+            CharacterSet::Ucs2,
+            CharacterSet::Ucs4,
+            CharacterSet::Iso8859_1,
+        ] {
+            buffer.clear();
+            write_character_string_with_set(&mut buffer, "Überwachung", set).unwrap();
+            assert_eq!(buffer[2], set as u8);
+            assert_eq!(decode_character_string(&buffer).unwrap().0, "Überwachung");
+        }
+
+        // Unsupported sets and unrepresentable characters are errors
+        let data = [0x73, 0x02, 0x82, 0xA0];
+        assert!(matches!(
+            decode_character_string(&data),
+            Err(EncodingError::InvalidFormat(_))
+        ));
+        assert!(
+            write_character_string_with_set(&mut buffer, "€", CharacterSet::Iso8859_1).is_err()
+        );
+    }
+
     #[test]
     fn test_encode_decode_octet_string() {
         let mut buffer = Vec::new();
         let test_data = vec![0x01, 0x02, 0x03, 0xFF, 0x00];
 
-        encode_octet_string(&mut buffer, &test_data).unwrap();
+        write_octet_string(&mut buffer, &test_data).unwrap();
         let (decoded, _) = decode_octet_string(&buffer).unwrap();
         assert_eq!(decoded, test_data);
     }
@@ -2372,7 +3794,7 @@ mod tests {
 
         for &test_value in &test_values {
             buffer.clear();
-            encode_enumerated(&mut buffer, test_value);
+            write_enumerated(&mut buffer, test_value).unwrap();
             let (value, _) = decode_enumerated(&buffer).unwrap();
             assert_eq!(value, test_value);
         }
@@ -2382,7 +3804,7 @@ mod tests {
     fn test_encode_decode_date() {
         let mut buffer = Vec::new();
 
-        encode_date(&mut buffer, 2024, 3, 15, 5).unwrap(); // Friday, March 15, 2024
+        write_date(&mut buffer, 2024, 3, 15, 5).unwrap(); // Friday, March 15, 2024
         let ((year, month, day, weekday), _) = decode_date(&buffer).unwrap();
         assert_eq!(year, 2024);
         assert_eq!(month, 3);
@@ -2394,7 +3816,7 @@ mod tests {
     fn test_encode_decode_time() {
         let mut buffer = Vec::new();
 
-        encode_time(&mut buffer, 14, 30, 45, 50).unwrap(); // 14:30:45.50
+        write_time(&mut buffer, 14, 30, 45, 50).unwrap(); // 14:30:45.50
         let ((hour, minute, second, hundredths), _) = decode_time(&buffer).unwrap();
         assert_eq!(hour, 14);
         assert_eq!(minute, 30);
@@ -2407,7 +3829,7 @@ mod tests {
         let mut buffer = Vec::new();
 
         let object_id = ObjectIdentifier::new(ObjectType::AnalogValue, 12345);
-        encode_object_identifier(&mut buffer, object_id).unwrap(); // Analog Value 12345
+        write_object_identifier(&mut buffer, object_id).unwrap(); // Analog Value 12345
         let (object_id, _) = decode_object_identifier(&buffer).unwrap();
         assert_eq!(object_id.object_type, ObjectType::AnalogValue);
         assert_eq!(object_id.instance, 12345);
@@ -2428,7 +3850,7 @@ mod tests {
 
         for &test_value in &test_values {
             buffer.clear();
-            encode_double(&mut buffer, test_value).unwrap();
+            write_double(&mut buffer, test_value).unwrap();
             let (value, _) = decode_double(&buffer).unwrap();
             assert_eq!(value, test_value);
         }
@@ -2464,7 +3886,7 @@ mod tests {
         let mut buffer = Vec::new();
 
         // Test context-specific tag encoding
-        encode_context_tag(&mut buffer, 5, 10).unwrap();
+        write_context_tag(&mut buffer, 5, 10).unwrap();
         let (tag_number, length, consumed) = decode_context_tag(&buffer).unwrap();
 
         assert_eq!(tag_number, 5);
@@ -2485,6 +3907,82 @@ mod tests {
         assert_eq!(buffer, vec![0x3E, 0x3F]);
     }
 
+    #[test]
+    fn test_extended_context_tag_number() {
+        use advanced::context::*;
+
+        let mut buffer = Vec::new();
+
+        // Tag number 20 with an extended (u16) length
+        write_context_tag(&mut buffer, 20, 300).unwrap();
+        assert_eq!(buffer, vec![0xFD, 20, 254, 0x01, 0x2C]);
+
+        let (tag_number, length, consumed) = decode_context_tag(&buffer).unwrap();
+        assert_eq!(tag_number, 20);
+        assert_eq!(length, 300);
+        assert_eq!(consumed, 5);
+
+        let (tag, length, consumed) = decode_tag(&buffer).unwrap();
+        assert_eq!(tag, BACnetTag::Context(20));
+        assert_eq!(length, 300);
+        assert_eq!(consumed, 5);
+
+        buffer.clear();
+        encode_opening_tag(&mut buffer, 20).unwrap();
+        encode_closing_tag(&mut buffer, 20).unwrap();
+        assert_eq!(buffer, vec![0xFE, 20, 0xFF, 20]);
+
+        // 255 is reserved and cannot be encoded
+        assert!(write_context_tag(&mut buffer, 255, 1).is_err());
+    }
+
+    #[test]
+    fn test_context_real() {
+        let encoded = encode_context_real(0.5, 4).unwrap();
+        assert_eq!(encoded, vec![0x4C, 0x3F, 0x00, 0x00, 0x00]);
+        assert_eq!(decode_context_real(&encoded, 4).unwrap(), (0.5, 5));
+        assert!(decode_context_real(&encoded, 3).is_err());
+        assert!(decode_context_real(&encoded[..4], 4).is_err());
+    }
+
+    #[test]
+    fn test_context_character_string() {
+        let encoded = encode_context_character_string("abc", 1).unwrap();
+        assert_eq!(encoded, vec![0x1C, 0x00, b'a', b'b', b'c']);
+        assert_eq!(
+            decode_context_character_string(&encoded, 1).unwrap(),
+            ("abc".to_string(), 5)
+        );
+        assert!(decode_context_character_string(&encoded, 2).is_err());
+        assert!(decode_context_character_string(&encoded[..4], 1).is_err());
+
+        // Extended length and UCS-2 content
+        let long = "x".repeat(20);
+        let encoded = encode_context_character_string(&long, 4).unwrap();
+        assert_eq!(&encoded[..3], &[0x4D, 21, 0x00]);
+        assert_eq!(
+            decode_context_character_string(&encoded, 4).unwrap(),
+            (long, 23)
+        );
+        let ucs2 = [0x2D, 0x05, 0x04, 0x00, 0xDC, 0x00, 0x62];
+        assert_eq!(
+            decode_context_character_string(&ucs2, 2).unwrap(),
+            ("\u{dc}b".to_string(), 7)
+        );
+    }
+
+    #[test]
+    fn test_context_boolean() {
+        let encoded = encode_context_boolean(true, 2).unwrap();
+        assert_eq!(encoded, vec![0x29, 0x01]);
+
+        let (value, consumed) = decode_context_boolean(&encoded, 2).unwrap();
+        assert!(value);
+        assert_eq!(consumed, 2);
+
+        assert!(decode_context_boolean(&encoded, 3).is_err());
+    }
+
     #[test]
     fn test_bit_string_encoding() {
         use advanced::bitstring::*;
@@ -2498,6 +3996,62 @@ mod tests {
         assert_eq!(decoded_bits, bits);
     }
 
+    #[test]
+    fn test_context_bit_string() {
+        use advanced::bitstring::*;
+
+        let bits = vec![false, true, true];
+        let encoded = encode_context_bit_string(&bits, 3).unwrap();
+        assert_eq!(encoded, vec![0x3A, 0x05, 0x60]);
+        assert_eq!(decode_context_bit_string(&encoded, 3).unwrap(), (bits, 3));
+        assert!(decode_context_bit_string(&encoded, 2).is_err());
+        assert!(decode_context_bit_string(&encoded[..2], 3).is_err());
+    }
+
+    #[test]
+    fn test_bacnet_bit_string_preserves_octets() {
+        use advanced::bitstring::*;
+
+        // Six bits, with the two unused bits left set by the sender
+        let encoded = [0x82, 0x02, 0xA7];
+        let (bits, consumed) = BacnetBitString::decode(&encoded).unwrap();
+        assert_eq!(consumed, 3);
+        assert_eq!(bits.len_bits(), 6);
+        assert_eq!(bits.unused_bits(), 2);
+        assert_eq!(bits.to_bits(), [true, false, true, false, false, true]);
+        assert!(!bits.get(6));
+
+        let mut buffer = Vec::new();
+        bits.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, encoded);
+
+        // Seven bits, one unused
+        let mut bits = BacnetBitString::from_bytes(vec![0x00], 1).unwrap();
+        for index in [0, 3, 6] {
+            bits.set(index, true);
+        }
+        assert_eq!(bits.len_bits(), 7);
+        assert_eq!(bits.as_bytes(), [0x92]);
+        let encoded = bits.encode_context(2).unwrap();
+        assert_eq!(encoded, [0x2A, 0x01, 0x92]);
+        assert_eq!(
+            BacnetBitString::decode_context(&encoded, 2).unwrap(),
+            (bits.clone(), 3)
+        );
+        assert_eq!(BacnetBitString::from(bits.to_bits().as_slice()), bits);
+
+        // Growing the string clears the formerly unused bits
+        let (mut bits, _) = BacnetBitString::decode(&[0x82, 0x02, 0xFF]).unwrap();
+        bits.set(9, false);
+        assert_eq!(bits.len_bits(), 10);
+        assert_eq!(bits.as_bytes(), [0xFC, 0x00]);
+        assert_eq!(bits.unused_bits(), 6);
+
+        assert!(BacnetBitString::from_bytes(vec![0x00], 8).is_err());
+        assert!(BacnetBitString::from_bytes(Vec::new(), 1).is_err());
+        assert!(BacnetBitString::decode(&[0x80]).is_err());
+    }
+
     #[test]
     fn test_fast_encoder() {
         use advanced::perf::FastEncoder;
@@ -2524,8 +4078,8 @@ mod tests {
 
         // Test with valid data
         let mut buffer = Vec::new();
-        encode_unsigned(&mut buffer, 42).unwrap();
-        encode_character_string(&mut buffer, "Hello").unwrap();
+        write_unsigned(&mut buffer, 42).unwrap();
+        write_character_string(&mut buffer, "Hello").unwrap();
 
         assert!(validator.validate(&buffer).is_ok());
     }
@@ -2538,7 +4092,7 @@ mod tests {
         // Performance test for encoding/decoding
         for i in 0..iterations {
             buffer.clear();
-            encode_unsigned(&mut buffer, i).unwrap();
+            write_unsigned(&mut buffer, i).unwrap();
             let (value, _) = decode_unsigned(&buffer).unwrap();
             assert_eq!(value, i);
         }
@@ -2564,10 +4118,15 @@ mod tests {
 
         for &test_value in &test_values {
             buffer.clear();
-            encode_signed64(&mut buffer, test_value);
+            write_signed64(&mut buffer, test_value).unwrap();
             let (value, _) = decode_signed64(&buffer).unwrap();
             assert_eq!(value, test_value);
         }
+
+        // Three-octet values use exactly three octets
+        buffer.clear();
+        write_signed64(&mut buffer, -100000).unwrap();
+        assert_eq!(buffer, vec![0x33, 0xFE, 0x79, 0x60]);
     }
 
     #[test]
@@ -2589,10 +4148,77 @@ mod tests {
 
         for &test_value in &test_values {
             buffer.clear();
-            encode_unsigned64(&mut buffer, test_value);
+            write_unsigned64(&mut buffer, test_value).unwrap();
             let (value, _) = decode_unsigned64(&buffer).unwrap();
             assert_eq!(value, test_value);
         }
+
+        // Three-octet values use exactly three octets
+        buffer.clear();
+        write_unsigned64(&mut buffer, 90000).unwrap();
+        assert_eq!(buffer, vec![0x23, 0x01, 0x5F, 0x90]);
+    }
+
+    #[test]
+    fn test_encode_64_three_octet_range() {
+        // Values in 0x10000..=0xFFFFFF must carry the low three octets only
+        let mut buffer = Vec::new();
+        for value in [0x10000u64, 0x123456, 0xFFFFFF] {
+            buffer.clear();
+            write_unsigned64(&mut buffer, value).unwrap();
+            let bytes = value.to_be_bytes();
+            assert_eq!(buffer, vec![0x23, bytes[5], bytes[6], bytes[7]]);
+            assert_eq!(decode_unsigned64(&buffer).unwrap(), (value, 4));
+        }
+
+        for value in [0x10000i64, 0x7FFFFF, -0x8001, -0x800000] {
+            buffer.clear();
+            write_signed64(&mut buffer, value).unwrap();
+            let bytes = value.to_be_bytes();
+            assert_eq!(buffer, vec![0x33, bytes[5], bytes[6], bytes[7]]);
+            assert_eq!(decode_signed64(&buffer).unwrap(), (value, 4));
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_vec_encoders() {
+        let mut old = Vec::new();
+        let mut new = Vec::new();
+
+        encode_application_tag(&mut old, ApplicationTag::Null, 0);
+        encode_boolean(&mut old, true).unwrap();
+        encode_unsigned(&mut old, 300).unwrap();
+        encode_unsigned64(&mut old, 0x1_0000_0000);
+        encode_signed(&mut old, -2).unwrap();
+        encode_signed64(&mut old, -0x1_0000_0000);
+        encode_real(&mut old, 1.5).unwrap();
+        encode_octet_string(&mut old, &[1, 2]).unwrap();
+        encode_character_string(&mut old, "ok").unwrap();
+        encode_enumerated(&mut old, 7);
+        encode_date(&mut old, 2024, 3, 15, 5).unwrap();
+        encode_time(&mut old, 12, 30, 0, 0).unwrap();
+        encode_object_identifier(&mut old, ObjectIdentifier::new(ObjectType::Device, 1)).unwrap();
+        encode_double(&mut old, 2.5).unwrap();
+        encode_context_tag(&mut old, 3, 1).unwrap();
+
+        write_application_tag(&mut new, ApplicationTag::Null, 0).unwrap();
+        write_boolean(&mut new, true).unwrap();
+        write_unsigned(&mut new, 300).unwrap();
+        write_unsigned64(&mut new, 0x1_0000_0000).unwrap();
+        write_signed(&mut new, -2).unwrap();
+        write_signed64(&mut new, -0x1_0000_0000).unwrap();
+        write_real(&mut new, 1.5).unwrap();
+        write_octet_string(&mut new, &[1, 2]).unwrap();
+        write_character_string(&mut new, "ok").unwrap();
+        write_enumerated(&mut new, 7).unwrap();
+        write_date(&mut new, 2024, 3, 15, 5).unwrap();
+        write_time(&mut new, 12, 30, 0, 0).unwrap();
+        write_object_identifier(&mut new, ObjectIdentifier::new(ObjectType::Device, 1)).unwrap();
+        write_double(&mut new, 2.5).unwrap();
+        write_context_tag(&mut new, 3, 1).unwrap();
+
+        assert_eq!(old, new);
     }
 
     #[test]
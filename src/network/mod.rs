@@ -157,9 +157,17 @@ pub struct NpduControl {
     pub expecting_reply: bool,
     /// Network priority (0-3)
     pub priority: u8,
+    /// Reserved bits (0x40 and 0x10) as received
+    ///
+    /// Kept so that a router re-emits a forwarded NPDU unchanged. Only the
+    /// bits in [`NpduControl::RESERVED_MASK`] are encoded.
+    pub reserved: u8,
 }
 
 impl NpduControl {
+    /// Mask of the reserved bits of the control octet
+    pub const RESERVED_MASK: u8 = 0x50;
+
     /// Create control byte from flags
     pub fn to_byte(&self) -> u8 {
         let mut byte = 0u8;
@@ -176,6 +184,7 @@ impl NpduControl {
             byte |= 0x04;
         }
         byte |= self.priority & 0x03;
+        byte |= self.reserved & Self::RESERVED_MASK;
         byte
     }
 
@@ -187,10 +196,54 @@ impl NpduControl {
             source_present: (byte & 0x08) != 0,
             expecting_reply: (byte & 0x04) != 0,
             priority: byte & 0x03,
+            reserved: byte & Self::RESERVED_MASK,
         }
     }
 }
 
+/// BACnet network number
+///
+/// Network numbers are 16 bits on the wire. 0 stands for the local network and
+/// 65535 for a global broadcast; neither names a remote network that can be
+/// looked up in a routing table.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NetworkNumber(pub u16);
+
+impl NetworkNumber {
+    /// The local network
+    pub const LOCAL: NetworkNumber = NetworkNumber(0);
+    /// Global broadcast to all networks
+    pub const BROADCAST: NetworkNumber = NetworkNumber(0xFFFF);
+
+    /// Check if this is the local network
+    pub fn is_local(self) -> bool {
+        self == Self::LOCAL
+    }
+
+    /// Check if this is the global broadcast network
+    pub fn is_broadcast(self) -> bool {
+        self == Self::BROADCAST
+    }
+
+    /// Check if this names a remote network (1-65534)
+    pub fn is_valid_remote(self) -> bool {
+        !self.is_local() && !self.is_broadcast()
+    }
+}
+
+impl From<u16> for NetworkNumber {
+    fn from(value: u16) -> Self {
+        NetworkNumber(value)
+    }
+}
+
+impl From<NetworkNumber> for u16 {
+    fn from(value: NetworkNumber) -> Self {
+        value.0
+    }
+}
+
 /// Network address (network number + MAC address)
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -207,14 +260,62 @@ impl NetworkAddress {
         Self { network, address }
     }
 
+    /// Address of every device on every network
+    pub fn global_broadcast() -> Self {
+        Self::new(NetworkNumber::BROADCAST.0, Vec::new())
+    }
+
+    /// Address of every device on a remote network
+    pub fn remote_broadcast(network: u16) -> Self {
+        Self::new(network, Vec::new())
+    }
+
+    /// Encode as the network number, MAC length and MAC of an NPDU
+    /// destination or source specifier
+    ///
+    /// MAC addresses are at most 255 octets on the wire; a longer one is
+    /// rejected and nothing is written.
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> Result<()> {
+        let length = u8::try_from(self.address.len()).map_err(|_| {
+            NetworkError::InvalidNpdu("MAC address longer than 255 octets".to_string())
+        })?;
+        buffer.extend_from_slice(&self.network.to_be_bytes());
+        buffer.push(length);
+        buffer.extend_from_slice(&self.address);
+        Ok(())
+    }
+
+    /// Decode an NPDU destination or source specifier, returning the address
+    /// with the bytes consumed
+    pub fn decode(data: &[u8]) -> Result<(Self, usize)> {
+        if data.len() < 3 {
+            return Err(NetworkError::InvalidNpdu(
+                "Network address too short".to_string(),
+            ));
+        }
+
+        let network = u16::from_be_bytes([data[0], data[1]]);
+        let length = data[2] as usize;
+        let address = data
+            .get(3..3 + length)
+            .ok_or_else(|| NetworkError::InvalidNpdu("Invalid MAC address length".to_string()))?;
+
+        Ok((Self::new(network, address.to_vec()), 3 + length))
+    }
+
+    /// Network number of this address
+    pub fn network_number(&self) -> NetworkNumber {
+        NetworkNumber(self.network)
+    }
+
     /// Check if this is a broadcast address
     pub fn is_broadcast(&self) -> bool {
-        self.network == 0xFFFF
+        self.network_number().is_broadcast()
     }
 
     /// Check if this is a local network address
     pub fn is_local(&self) -> bool {
-        self.network == 0
+        self.network_number().is_local()
     }
 }
 
@@ -255,11 +356,9 @@ impl Npdu {
                 source_present: false,
                 expecting_reply: false, // YABE uses 0x20 (no expecting_reply bit)
                 priority: 0,
+                reserved: 0,
             },
-            destination: Some(NetworkAddress {
-                network: 0xFFFF,
-                address: vec![],
-            }),
+            destination: Some(NetworkAddress::global_broadcast()),
             source: None,
             hop_count: Some(255),
         }
@@ -296,7 +395,18 @@ pub struct RouterInfo {
 
 impl Npdu {
     /// Encode NPDU to bytes
+    ///
+    /// # Panics
+    ///
+    /// Panics if a destination or source MAC address is longer than 255
+    /// octets. Use [`Npdu::try_encode`] to get an error instead.
     pub fn encode(&self) -> Vec<u8> {
+        self.try_encode()
+            .expect("NPDU MAC address longer than 255 octets")
+    }
+
+    /// Encode NPDU to bytes, rejecting MAC addresses longer than 255 octets
+    pub fn try_encode(&self) -> Result<Vec<u8>> {
         let mut buffer = Vec::new();
 
         // Version
@@ -307,16 +417,12 @@ impl Npdu {
 
         // Destination network address
         if let Some(ref dest) = self.destination {
-            buffer.extend_from_slice(&dest.network.to_be_bytes());
-            buffer.push(dest.address.len() as u8);
-            buffer.extend_from_slice(&dest.address);
+            dest.encode(&mut buffer)?;
         }
 
         // Source network address
         if let Some(ref src) = self.source {
-            buffer.extend_from_slice(&src.network.to_be_bytes());
-            buffer.push(src.address.len() as u8);
-            buffer.extend_from_slice(&src.address);
+            src.encode(&mut buffer)?;
         }
 
         // Hop count (only if destination is present)
@@ -324,7 +430,7 @@ impl Npdu {
             buffer.push(self.hop_count.unwrap_or(255));
         }
 
-        buffer
+        Ok(buffer)
     }
 
     /// Decode NPDU from bytes
@@ -352,56 +458,21 @@ impl Npdu {
 
         // Destination network address
         let destination = if control.destination_present {
-            if pos + 3 > data.len() {
-                return Err(NetworkError::InvalidNpdu(
-                    "Invalid destination address".to_string(),
-                ));
-            }
-
-            let network = u16::from_be_bytes([data[pos], data[pos + 1]]);
-            pos += 2;
-
-            let addr_len = data[pos] as usize;
-            pos += 1;
-
-            if pos + addr_len > data.len() {
-                return Err(NetworkError::InvalidNpdu(
-                    "Invalid destination address length".to_string(),
-                ));
-            }
-
-            let address = data[pos..pos + addr_len].to_vec();
-            pos += addr_len;
-
-            Some(NetworkAddress::new(network, address))
+            let (address, consumed) = NetworkAddress::decode(&data[pos..]).map_err(|_| {
+                NetworkError::InvalidNpdu("Invalid destination address".to_string())
+            })?;
+            pos += consumed;
+            Some(address)
         } else {
             None
         };
 
         // Source network address
         let source = if control.source_present {
-            if pos + 3 > data.len() {
-                return Err(NetworkError::InvalidNpdu(
-                    "Invalid source address".to_string(),
-                ));
-            }
-
-            let network = u16::from_be_bytes([data[pos], data[pos + 1]]);
-            pos += 2;
-
-            let addr_len = data[pos] as usize;
-            pos += 1;
-
-            if pos + addr_len > data.len() {
-                return Err(NetworkError::InvalidNpdu(
-                    "Invalid source address length".to_string(),
-                ));
-            }
-
-            let address = data[pos..pos + addr_len].to_vec();
-            pos += addr_len;
-
-            Some(NetworkAddress::new(network, address))
+            let (address, consumed) = NetworkAddress::decode(&data[pos..])
+                .map_err(|_| NetworkError::InvalidNpdu("Invalid source address".to_string()))?;
+            pos += consumed;
+            Some(address)
         } else {
             None
         };
@@ -437,6 +508,7 @@ impl Default for Npdu {
 }
 
 /// Network layer message handling
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NetworkLayerMessage {
     /// Message type
     pub message_type: NetworkMessageType,
@@ -483,6 +555,232 @@ impl NetworkLayerMessage {
     pub fn data(&self) -> Option<&[u8]> {
         self.data.as_deref()
     }
+
+    /// Create a Who-Is-Router-To-Network message
+    ///
+    /// Without a network number the message asks every router for all the
+    /// networks it can reach.
+    pub fn who_is_router_to_network(network: Option<u16>) -> Self {
+        Self::new(
+            NetworkMessageType::WhoIsRouterToNetwork,
+            network.map(|network| network.to_be_bytes().to_vec()),
+        )
+    }
+
+    /// Create an I-Am-Router-To-Network message
+    pub fn i_am_router_to_network(networks: &[u16]) -> Self {
+        Self::with_networks(NetworkMessageType::IAmRouterToNetwork, networks)
+    }
+
+    /// Create an I-Could-Be-Router-To-Network message
+    pub fn i_could_be_router_to_network(network: u16, performance_index: u8) -> Self {
+        let mut data = network.to_be_bytes().to_vec();
+        data.push(performance_index);
+        Self::new(NetworkMessageType::ICouldBeRouterToNetwork, Some(data))
+    }
+
+    /// Create a Reject-Message-To-Network message
+    pub fn reject_message_to_network(reason: u8, network: u16) -> Self {
+        let mut data = vec![reason];
+        data.extend_from_slice(&network.to_be_bytes());
+        Self::new(NetworkMessageType::RejectMessageToNetwork, Some(data))
+    }
+
+    /// Create a Router-Busy-To-Network message
+    pub fn router_busy_to_network(networks: &[u16]) -> Self {
+        Self::with_networks(NetworkMessageType::RouterBusyToNetwork, networks)
+    }
+
+    /// Create a Router-Available-To-Network message
+    pub fn router_available_to_network(networks: &[u16]) -> Self {
+        Self::with_networks(NetworkMessageType::RouterAvailableToNetwork, networks)
+    }
+
+    /// Create an Initialize-Routing-Table message
+    ///
+    /// An empty list of ports is a query for the complete routing table.
+    pub fn initialize_routing_table(ports: &[RoutingTablePort]) -> Result<Self> {
+        Ok(Self::new(
+            NetworkMessageType::InitializeRoutingTable,
+            Some(RoutingTablePort::encode_list(ports)?),
+        ))
+    }
+
+    /// Create an Initialize-Routing-Table-Ack message
+    pub fn initialize_routing_table_ack(ports: &[RoutingTablePort]) -> Result<Self> {
+        Ok(Self::new(
+            NetworkMessageType::InitializeRoutingTableAck,
+            Some(RoutingTablePort::encode_list(ports)?),
+        ))
+    }
+
+    /// Create a What-Is-Network-Number message
+    pub fn what_is_network_number() -> Self {
+        Self::new(NetworkMessageType::WhatIsNetworkNumber, None)
+    }
+
+    /// Create a Network-Number-Is message
+    ///
+    /// `configured` tells whether the network number was configured rather
+    /// than learned from another device.
+    pub fn network_number_is(network: u16, configured: bool) -> Self {
+        let mut data = network.to_be_bytes().to_vec();
+        data.push(configured as u8);
+        Self::new(NetworkMessageType::NetworkNumberIs, Some(data))
+    }
+
+    /// Get the network numbers of a Who-Is-Router-To-Network,
+    /// I-Am-Router-To-Network, Router-Busy-To-Network or
+    /// Router-Available-To-Network message
+    pub fn networks(&self) -> Result<Vec<u16>> {
+        self.expect_type(&[
+            NetworkMessageType::WhoIsRouterToNetwork,
+            NetworkMessageType::IAmRouterToNetwork,
+            NetworkMessageType::RouterBusyToNetwork,
+            NetworkMessageType::RouterAvailableToNetwork,
+        ])?;
+
+        let pairs = self.data().unwrap_or_default().chunks_exact(2);
+        if !pairs.remainder().is_empty() {
+            return Err(NetworkError::InvalidNpdu(
+                "Truncated network number".to_string(),
+            ));
+        }
+
+        Ok(pairs
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+            .collect())
+    }
+
+    /// Get the network number and performance index of an
+    /// I-Could-Be-Router-To-Network message
+    pub fn could_be_router(&self) -> Result<(u16, u8)> {
+        self.expect_type(&[NetworkMessageType::ICouldBeRouterToNetwork])?;
+
+        match self.data() {
+            Some(&[high, low, performance_index]) => {
+                Ok((u16::from_be_bytes([high, low]), performance_index))
+            }
+            _ => Err(NetworkError::InvalidNpdu(
+                "Invalid I-Could-Be-Router-To-Network".to_string(),
+            )),
+        }
+    }
+
+    /// Get the reject reason and network number of a
+    /// Reject-Message-To-Network message
+    pub fn reject_reason(&self) -> Result<(u8, u16)> {
+        self.expect_type(&[NetworkMessageType::RejectMessageToNetwork])?;
+
+        match self.data() {
+            Some(&[reason, high, low]) => Ok((reason, u16::from_be_bytes([high, low]))),
+            _ => Err(NetworkError::InvalidNpdu(
+                "Invalid Reject-Message-To-Network".to_string(),
+            )),
+        }
+    }
+
+    /// Get the ports of an Initialize-Routing-Table or
+    /// Initialize-Routing-Table-Ack message
+    pub fn routing_table_ports(&self) -> Result<Vec<RoutingTablePort>> {
+        self.expect_type(&[
+            NetworkMessageType::InitializeRoutingTable,
+            NetworkMessageType::InitializeRoutingTableAck,
+        ])?;
+
+        RoutingTablePort::decode_list(self.data().unwrap_or_default())
+    }
+
+    /// Get the network number and configured flag of a Network-Number-Is
+    /// message
+    pub fn network_number(&self) -> Result<(u16, bool)> {
+        self.expect_type(&[NetworkMessageType::NetworkNumberIs])?;
+
+        match self.data() {
+            Some(&[high, low, configured]) => {
+                Ok((u16::from_be_bytes([high, low]), configured != 0))
+            }
+            _ => Err(NetworkError::InvalidNpdu(
+                "Invalid Network-Number-Is".to_string(),
+            )),
+        }
+    }
+
+    fn with_networks(message_type: NetworkMessageType, networks: &[u16]) -> Self {
+        let data = networks
+            .iter()
+            .flat_map(|network| network.to_be_bytes())
+            .collect();
+        Self::new(message_type, Some(data))
+    }
+
+    fn expect_type(&self, types: &[NetworkMessageType]) -> Result<()> {
+        if types.contains(&self.message_type) {
+            Ok(())
+        } else {
+            Err(NetworkError::UnsupportedNetworkMessageType(
+                self.message_type as u8,
+            ))
+        }
+    }
+}
+
+/// Routing table entry of an Initialize-Routing-Table message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoutingTablePort {
+    /// Network reachable through the port (DNET)
+    pub network: u16,
+    /// Port identifier, 0 removes the entry
+    pub port_id: u8,
+    /// Port information, e.g. a PTP dial string
+    pub port_info: Vec<u8>,
+}
+
+impl RoutingTablePort {
+    /// Encode a list of ports preceded by the number of ports
+    pub fn encode_list(ports: &[RoutingTablePort]) -> Result<Vec<u8>> {
+        let count = u8::try_from(ports.len())
+            .map_err(|_| NetworkError::InvalidNpdu("Too many ports".to_string()))?;
+
+        let mut data = vec![count];
+        for port in ports {
+            let info_length = u8::try_from(port.port_info.len())
+                .map_err(|_| NetworkError::InvalidNpdu("Port info too long".to_string()))?;
+
+            data.extend_from_slice(&port.network.to_be_bytes());
+            data.push(port.port_id);
+            data.push(info_length);
+            data.extend_from_slice(&port.port_info);
+        }
+
+        Ok(data)
+    }
+
+    /// Decode a list of ports preceded by the number of ports
+    pub fn decode_list(data: &[u8]) -> Result<Vec<RoutingTablePort>> {
+        let truncated = || NetworkError::InvalidNpdu("Truncated routing table".to_string());
+
+        let (&count, mut data) = data.split_first().ok_or_else(truncated)?;
+        let mut ports = Vec::with_capacity(count as usize);
+
+        for _ in 0..count {
+            if data.len() < 4 {
+                return Err(truncated());
+            }
+
+            let info_length = data[3] as usize;
+            let port_info = data.get(4..4 + info_length).ok_or_else(truncated)?;
+
+            ports.push(RoutingTablePort {
+                network: u16::from_be_bytes([data[0], data[1]]),
+                port_id: data[2],
+                port_info: port_info.to_vec(),
+            });
+            data = &data[4 + info_length..];
+        }
+
+        Ok(ports)
+    }
 }
 
 /// Basic routing table implementation
@@ -508,7 +806,12 @@ impl RoutingTable {
     }
 
     /// Find route to network
+    ///
+    /// The reserved local and broadcast network numbers never have a route.
     pub fn find_route(&self, network: u16) -> Option<&RouterInfo> {
+        if !NetworkNumber(network).is_valid_remote() {
+            return None;
+        }
         self.entries.iter().find(|r| r.networks.contains(&network))
     }
 
@@ -524,6 +827,23 @@ impl Default for RoutingTable {
     }
 }
 
+/// Reject-Message-To-Network reason 0, "other error" (Clause 6.4.4)
+///
+/// There is no dedicated reason for an exhausted hop count, so the router
+/// reports it with this one.
+pub const REJECT_REASON_OTHER: u8 = 0;
+
+/// Outcome of routing an NPDU
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RouteResult {
+    /// Deliver the message on the local network
+    Local,
+    /// Forward the message to the given next hop
+    Forward(NetworkAddress),
+    /// Drop the message and send this reject back toward the source
+    Reject(NetworkLayerMessage),
+}
+
 /// Network layer router manager for handling routing operations
 #[derive(Debug)]
 pub struct RouterManager {
@@ -565,18 +885,43 @@ impl RouterManager {
     }
 
     /// Process a routing request
+    ///
+    /// Returns the next hop, or `None` for local delivery. Global broadcasts
+    /// are delivered locally too; they are never looked up in the routing
+    /// table. Use [`RouterManager::route`] to get the Reject-Message-To-Network
+    /// for an exhausted hop count instead of an error.
     pub fn route_message(&mut self, npdu: &mut Npdu) -> Result<Option<NetworkAddress>> {
+        match self.route(npdu)? {
+            RouteResult::Local => Ok(None),
+            RouteResult::Forward(next_hop) => Ok(Some(next_hop)),
+            RouteResult::Reject(_) => Err(NetworkError::HopCountExceeded),
+        }
+    }
+
+    /// Route an NPDU
+    ///
+    /// Like [`RouterManager::route_message`], but a message whose hop count
+    /// has reached zero produces a Reject-Message-To-Network with reason
+    /// [`REJECT_REASON_OTHER`] for the destination network. The
+    /// caller should send it to the NPDU source.
+    pub fn route(&mut self, npdu: &mut Npdu) -> Result<RouteResult> {
         // Check if this is a local message
         if let Some(ref dest) = npdu.destination {
-            if dest.network == self.local_network || dest.network == 0 {
-                return Ok(None); // Local delivery
+            let network = dest.network_number();
+            if network.0 == self.local_network || !network.is_valid_remote() {
+                return Ok(RouteResult::Local);
             }
 
             // Check hop count
             if let Some(hops) = npdu.hop_count {
                 if hops == 0 {
                     self.performance_metrics.hop_count_exceeded += 1;
-                    return Err(NetworkError::HopCountExceeded);
+                    return Ok(RouteResult::Reject(
+                        NetworkLayerMessage::reject_message_to_network(
+                            REJECT_REASON_OTHER,
+                            dest.network,
+                        ),
+                    ));
                 }
                 npdu.hop_count = Some(hops - 1);
             }
@@ -589,27 +934,33 @@ impl RouterManager {
             // Find route
             if let Some(router) = self.routing_table.find_route(dest.network) {
                 self.performance_metrics.messages_routed += 1;
-                Ok(Some(router.address.clone()))
+                Ok(RouteResult::Forward(router.address.clone()))
             } else {
                 self.performance_metrics.network_unreachable_count += 1;
                 Err(NetworkError::NetworkUnreachable(dest.network))
             }
         } else {
-            Ok(None) // No destination specified
+            Ok(RouteResult::Local) // No destination specified
         }
     }
 
     /// Process network layer messages
+    ///
+    /// `source` is the address the message came from: the NPDU source
+    /// (SNET/SADR) when present, otherwise the data link address of the
+    /// sender on the local network. Routers announced with
+    /// I-Am-Router-To-Network are recorded under this address.
     pub fn process_network_message(
         &mut self,
         message: &NetworkLayerMessage,
+        source: &NetworkAddress,
     ) -> Result<Option<NetworkLayerMessage>> {
         match message.message_type {
             NetworkMessageType::WhoIsRouterToNetwork => {
                 self.handle_who_is_router_to_network(message.data())
             }
             NetworkMessageType::IAmRouterToNetwork => {
-                self.handle_i_am_router_to_network(message.data())
+                self.handle_i_am_router_to_network(message, source)
             }
             NetworkMessageType::RouterBusyToNetwork => {
                 self.handle_router_busy_to_network(message.data())
@@ -646,23 +997,15 @@ impl RouterManager {
     /// Handle I-Am-Router-To-Network message
     fn handle_i_am_router_to_network(
         &mut self,
-        data: Option<&[u8]>,
+        message: &NetworkLayerMessage,
+        source: &NetworkAddress,
     ) -> Result<Option<NetworkLayerMessage>> {
-        // Parse networks this router can reach
-        let mut pos = 0;
-        let mut networks = Vec::new();
-
-        if let Some(data) = data {
-            while pos + 1 < data.len() {
-                let network = u16::from_be_bytes([data[pos], data[pos + 1]]);
-                networks.push(network);
-                pos += 2;
-            }
+        // Networks this router can reach
+        let networks = message.networks()?;
+        if !networks.is_empty() {
+            self.add_discovered_router(networks, source.clone(), None);
         }
 
-        // Add router to routing table (would need router address from NPDU source)
-        // This is a simplified implementation
-
         Ok(None)
     }
 
@@ -712,6 +1055,11 @@ impl RouterManager {
         address: NetworkAddress,
         performance_index: Option<u8>,
     ) {
+        // Reserved network numbers cannot be reached through a router
+        let networks = networks
+            .into_iter()
+            .filter(|&network| NetworkNumber(network).is_valid_remote())
+            .collect();
         let router = RouterInfo {
             networks,
             address,
@@ -786,12 +1134,16 @@ impl PathDiscovery {
     }
 
     /// Find optimal path to destination network using Dijkstra's algorithm
+    ///
+    /// The path lists every network from `source` to `destination`, both
+    /// included.
     pub fn find_path(&mut self, source: u16, destination: u16) -> Option<Vec<u16>> {
-        // Check cache first
+        // Check cache first; cached paths start at the source they were
+        // found from
         if let Some((_, path)) = self
             .path_cache
             .iter()
-            .find(|(dest, _)| *dest == destination)
+            .find(|(dest, path)| *dest == destination && path.first() == Some(&source))
         {
             return Some(path.clone());
         }
@@ -807,7 +1159,10 @@ impl PathDiscovery {
         path
     }
 
-    /// Dijkstra's shortest path algorithm (simplified)
+    /// Dijkstra's shortest path algorithm
+    ///
+    /// Links are used in both directions, since a router connects its
+    /// networks both ways.
     fn dijkstra_shortest_path(&self, source: u16, destination: u16) -> Option<Vec<u16>> {
         if source == destination {
             return Some(vec![source]);
@@ -819,12 +1174,14 @@ impl PathDiscovery {
 
         // Initialize distances
         for link in &self.network_topology {
-            distances.insert(link.source_network, u16::MAX);
-            distances.insert(link.destination_network, u16::MAX);
             unvisited.insert(link.source_network);
             unvisited.insert(link.destination_network);
         }
 
+        if !unvisited.contains(&source) || !unvisited.contains(&destination) {
+            return None;
+        }
+
         distances.insert(source, 0);
 
         while !unvisited.is_empty() {
@@ -834,35 +1191,42 @@ impl PathDiscovery {
                 .min_by_key(|&&node| distances.get(&node).unwrap_or(&u16::MAX))
                 .unwrap();
 
-            if *distances.get(&current).unwrap_or(&u16::MAX) == u16::MAX {
+            let Some(&distance) = distances.get(&current) else {
                 break; // No more reachable nodes
-            }
+            };
 
             unvisited.remove(&current);
 
             if current == destination {
-                // Reconstruct path
-                let mut path = Vec::new();
+                // Reconstruct path by walking back to the source
+                let mut path = vec![destination];
                 let mut current_node = destination;
                 while let Some(&prev) = previous.get(&current_node) {
-                    path.push(current_node);
+                    path.push(prev);
                     current_node = prev;
                 }
-                path.push(source);
                 path.reverse();
                 return Some(path);
             }
 
-            // Update distances to neighbors
+            // Update distances to neighbors, following links either way
             for link in &self.network_topology {
-                if link.source_network == current {
-                    let neighbor = link.destination_network;
-                    if unvisited.contains(&neighbor) {
-                        let new_distance = distances[&current].saturating_add(link.cost);
-                        if new_distance < *distances.get(&neighbor).unwrap_or(&u16::MAX) {
-                            distances.insert(neighbor, new_distance);
-                            previous.insert(neighbor, current);
-                        }
+                let neighbor = if link.source_network == current {
+                    link.destination_network
+                } else if link.destination_network == current {
+                    link.source_network
+                } else {
+                    continue;
+                };
+
+                if unvisited.contains(&neighbor) {
+                    let new_distance = distance.saturating_add(link.cost);
+                    if distances
+                        .get(&neighbor)
+                        .is_none_or(|&known| new_distance < known)
+                    {
+                        distances.insert(neighbor, new_distance);
+                        previous.insert(neighbor, current);
                     }
                 }
             }
@@ -1166,6 +1530,9 @@ impl NetworkLayerHandler {
 
     /// Find best router for a network
     pub fn find_router(&self, network: u16) -> Option<&RouterInfo> {
+        if !NetworkNumber(network).is_valid_remote() {
+            return None;
+        }
         self.routers
             .iter()
             .filter(|r| r.networks.contains(&network))
@@ -1527,6 +1894,7 @@ mod tests {
             source_present: true,
             expecting_reply: false,
             priority: 2,
+            ..Default::default()
         };
 
         let byte = control.to_byte();
@@ -1539,6 +1907,62 @@ mod tests {
         assert_eq!(control.priority, decoded.priority);
     }
 
+    #[test]
+    fn test_network_address_encode_decode() {
+        let address = NetworkAddress::new(1001, vec![0xC0, 0xA8, 0x01, 0x0A, 0xBA, 0xC0]);
+
+        let mut buffer = Vec::new();
+        address.encode(&mut buffer).unwrap();
+        assert_eq!(
+            buffer,
+            [0x03, 0xE9, 0x06, 0xC0, 0xA8, 0x01, 0x0A, 0xBA, 0xC0]
+        );
+        assert_eq!(NetworkAddress::decode(&buffer).unwrap(), (address, 9));
+
+        // MAC shorter than its length octet
+        assert!(NetworkAddress::decode(&buffer[..8]).is_err());
+        assert!(NetworkAddress::decode(&buffer[..2]).is_err());
+
+        let broadcast = NetworkAddress::global_broadcast();
+        assert!(broadcast.is_broadcast());
+        assert!(broadcast.address.is_empty());
+
+        let remote = NetworkAddress::remote_broadcast(5);
+        let mut buffer = Vec::new();
+        remote.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, [0x00, 0x05, 0x00]);
+        assert!(!remote.is_broadcast());
+
+        // The MAC length has to fit in one octet
+        let mut buffer = Vec::new();
+        assert!(NetworkAddress::new(5, vec![0; 256])
+            .encode(&mut buffer)
+            .is_err());
+        assert!(buffer.is_empty());
+
+        let mut npdu = Npdu::new();
+        npdu.destination = Some(NetworkAddress::new(5, vec![0; 256]));
+        npdu.control.destination_present = true;
+        assert!(npdu.try_encode().is_err());
+    }
+
+    #[test]
+    fn test_npdu_control_preserves_reserved_bits() {
+        for byte in [0x40, 0x10, 0x7C, 0xFF] {
+            assert_eq!(NpduControl::from_byte(byte).to_byte(), byte);
+        }
+
+        // Reserved bit 0x40 set on an NPDU with a destination
+        let data = [0x01, 0x60, 0x00, 0x05, 0x01, 0x0A, 0xFE, 0x10, 0x08];
+        let (npdu, consumed) = Npdu::decode(&data).unwrap();
+        assert_eq!(npdu.control.reserved, 0x40);
+        assert_eq!(consumed, 7);
+
+        let mut encoded = npdu.encode();
+        encoded.extend_from_slice(&data[consumed..]);
+        assert_eq!(encoded, data);
+    }
+
     #[test]
     fn test_npdu_encode_decode_basic() {
         let npdu = Npdu::new();
@@ -1586,6 +2010,92 @@ mod tests {
         assert_eq!(decoded.data, vec![0x00, 0x64].into());
     }
 
+    #[test]
+    fn test_network_message_constructors() {
+        let message = NetworkLayerMessage::who_is_router_to_network(Some(100));
+        assert_eq!(message.encode(), [0x00, 0x00, 0x64]);
+        assert_eq!(message.networks().unwrap(), [100]);
+        assert_eq!(
+            NetworkLayerMessage::who_is_router_to_network(None).encode(),
+            [0x00]
+        );
+
+        let message = NetworkLayerMessage::i_am_router_to_network(&[100, 0x1234]);
+        assert_eq!(message.encode(), [0x01, 0x00, 0x64, 0x12, 0x34]);
+        let decoded = NetworkLayerMessage::decode(&message.encode()).unwrap();
+        assert_eq!(decoded.networks().unwrap(), [100, 0x1234]);
+
+        let message = NetworkLayerMessage::i_could_be_router_to_network(5, 10);
+        assert_eq!(message.encode(), [0x02, 0x00, 0x05, 0x0A]);
+        assert_eq!(message.could_be_router().unwrap(), (5, 10));
+
+        let message = NetworkLayerMessage::reject_message_to_network(3, 0x0A0B);
+        assert_eq!(message.encode(), [0x03, 0x03, 0x0A, 0x0B]);
+        assert_eq!(message.reject_reason().unwrap(), (3, 0x0A0B));
+
+        let message = NetworkLayerMessage::router_busy_to_network(&[7]);
+        assert_eq!(message.encode(), [0x04, 0x00, 0x07]);
+        let message = NetworkLayerMessage::router_available_to_network(&[]);
+        assert_eq!(message.encode(), [0x05]);
+        assert!(message.networks().unwrap().is_empty());
+
+        let ports = [
+            RoutingTablePort {
+                network: 10,
+                port_id: 1,
+                port_info: Vec::new(),
+            },
+            RoutingTablePort {
+                network: 20,
+                port_id: 2,
+                port_info: b"555".to_vec(),
+            },
+        ];
+        let message = NetworkLayerMessage::initialize_routing_table(&ports).unwrap();
+        assert_eq!(
+            message.encode(),
+            [0x06, 0x02, 0x00, 0x0A, 0x01, 0x00, 0x00, 0x14, 0x02, 0x03, b'5', b'5', b'5']
+        );
+        let decoded = NetworkLayerMessage::decode(&message.encode()).unwrap();
+        assert_eq!(decoded.routing_table_ports().unwrap(), ports);
+
+        let message = NetworkLayerMessage::initialize_routing_table_ack(&[]).unwrap();
+        assert_eq!(message.encode(), [0x07, 0x00]);
+        assert!(message.routing_table_ports().unwrap().is_empty());
+
+        assert_eq!(
+            NetworkLayerMessage::what_is_network_number().encode(),
+            [0x12]
+        );
+        let message = NetworkLayerMessage::network_number_is(100, true);
+        assert_eq!(message.encode(), [0x13, 0x00, 0x64, 0x01]);
+        assert_eq!(message.network_number().unwrap(), (100, true));
+    }
+
+    #[test]
+    fn test_network_message_accessor_errors() {
+        // Accessors only apply to their own message types
+        let message = NetworkLayerMessage::network_number_is(100, false);
+        assert!(matches!(
+            message.networks(),
+            Err(NetworkError::UnsupportedNetworkMessageType(0x13))
+        ));
+
+        let message =
+            NetworkLayerMessage::new(NetworkMessageType::IAmRouterToNetwork, Some(vec![0x00]));
+        assert!(message.networks().is_err());
+
+        let message =
+            NetworkLayerMessage::new(NetworkMessageType::RejectMessageToNetwork, Some(vec![3]));
+        assert!(message.reject_reason().is_err());
+
+        let message = NetworkLayerMessage::new(
+            NetworkMessageType::InitializeRoutingTable,
+            Some(vec![0x01, 0x00, 0x0A, 0x01, 0x02, b'5']),
+        );
+        assert!(message.routing_table_ports().is_err());
+    }
+
     #[test]
     fn test_routing_table() {
         let mut table = RoutingTable::new();
@@ -1633,7 +2143,10 @@ mod tests {
         let mut hopless_npdu = Npdu::new();
         hopless_npdu.destination = Some(NetworkAddress::new(100, vec![10, 0, 0, 1]));
         hopless_npdu.hop_count = Some(0);
-        assert!(manager.route_message(&mut hopless_npdu).is_err());
+        assert!(matches!(
+            manager.route_message(&mut hopless_npdu),
+            Err(NetworkError::HopCountExceeded)
+        ));
 
         // Test network unreachable
         let mut unreachable_npdu = Npdu::new();
@@ -1641,9 +2154,41 @@ mod tests {
         assert!(manager.route_message(&mut unreachable_npdu).is_err());
     }
 
+    #[test]
+    fn test_route_hop_count_exceeded_rejects() {
+        let mut manager = RouterManager::new(1);
+        manager.add_discovered_router(
+            vec![100],
+            NetworkAddress::new(0, vec![192, 168, 1, 1]),
+            None,
+        );
+
+        let mut npdu = Npdu::new();
+        npdu.destination = Some(NetworkAddress::new(100, vec![10, 0, 0, 1]));
+        npdu.hop_count = Some(1);
+        assert_eq!(
+            manager.route(&mut npdu).unwrap(),
+            RouteResult::Forward(NetworkAddress::new(0, vec![192, 168, 1, 1]))
+        );
+        assert_eq!(npdu.hop_count, Some(0));
+
+        // The next router sees a hop count of zero
+        let RouteResult::Reject(reject) = manager.route(&mut npdu).unwrap() else {
+            panic!("expected a reject");
+        };
+        assert_eq!(
+            reject.message_type,
+            NetworkMessageType::RejectMessageToNetwork
+        );
+        assert_eq!(reject.encode(), vec![0x03, 0x00, 0x00, 0x64]);
+        assert_eq!(reject.reject_reason().unwrap(), (REJECT_REASON_OTHER, 100));
+        assert_eq!(manager.performance_metrics.hop_count_exceeded, 1);
+    }
+
     #[test]
     fn test_router_manager_network_messages() {
         let mut manager = RouterManager::new(1);
+        let source = NetworkAddress::new(0, vec![192, 168, 1, 2]);
 
         // Add router for network 100
         manager.add_discovered_router(
@@ -1657,7 +2202,9 @@ mod tests {
             NetworkMessageType::WhoIsRouterToNetwork,
             vec![0x00, 0x64].into(), // Network 100
         );
-        let response = manager.process_network_message(&who_is_msg).unwrap();
+        let response = manager
+            .process_network_message(&who_is_msg, &source)
+            .unwrap();
         assert!(response.is_some());
         if let Some(resp) = response {
             assert_eq!(resp.message_type, NetworkMessageType::IAmRouterToNetwork);
@@ -1666,7 +2213,9 @@ mod tests {
 
         // Test What-Is-Network-Number
         let what_is_msg = NetworkLayerMessage::new(NetworkMessageType::WhatIsNetworkNumber, None);
-        let response = manager.process_network_message(&what_is_msg).unwrap();
+        let response = manager
+            .process_network_message(&what_is_msg, &source)
+            .unwrap();
         assert!(response.is_some());
         if let Some(resp) = response {
             assert_eq!(resp.message_type, NetworkMessageType::NetworkNumberIs);
@@ -1678,7 +2227,7 @@ mod tests {
             NetworkMessageType::RouterBusyToNetwork,
             vec![0x00, 0x64].into(), // Network 100
         );
-        manager.process_network_message(&busy_msg).unwrap();
+        manager.process_network_message(&busy_msg, &source).unwrap();
         assert!(manager.busy_networks.contains(&100));
 
         // Test Router-Available-To-Network
@@ -1686,10 +2235,44 @@ mod tests {
             NetworkMessageType::RouterAvailableToNetwork,
             vec![0x00, 0x64].into(), // Network 100
         );
-        manager.process_network_message(&available_msg).unwrap();
+        manager
+            .process_network_message(&available_msg, &source)
+            .unwrap();
         assert!(!manager.busy_networks.contains(&100));
     }
 
+    #[test]
+    fn test_router_manager_learns_i_am_router() {
+        let mut manager = RouterManager::new(1);
+        let router = NetworkAddress::new(5, vec![0x0A]);
+
+        // I-Am-Router-To-Network for networks 200 and 300 relayed from network 5
+        let mut npdu = Npdu::new();
+        npdu.control.network_message = true;
+        npdu.control.source_present = true;
+        npdu.source = Some(router.clone());
+        let mut frame = npdu.encode();
+        frame.extend(NetworkLayerMessage::i_am_router_to_network(&[200, 300]).encode());
+
+        let (npdu, consumed) = Npdu::decode(&frame).unwrap();
+        let message = NetworkLayerMessage::decode(&frame[consumed..]).unwrap();
+        let source = npdu.source.unwrap();
+        assert_eq!(
+            manager.process_network_message(&message, &source).unwrap(),
+            None
+        );
+
+        assert_eq!(
+            manager.routing_table.find_route(200).unwrap().address,
+            router
+        );
+        assert_eq!(
+            manager.routing_table.find_route(300).unwrap().address,
+            router
+        );
+        assert!(manager.routing_table.find_route(400).is_none());
+    }
+
     #[test]
     fn test_path_discovery() {
         let mut discovery = PathDiscovery::new();
@@ -1727,6 +2310,35 @@ mod tests {
         assert!(cached_path.is_some());
     }
 
+    #[test]
+    fn test_path_discovery_multi_hop_and_reverse() {
+        let mut discovery = PathDiscovery::new();
+        let link = |source_network, destination_network, cost| NetworkLink {
+            source_network,
+            destination_network,
+            cost,
+            router_address: NetworkAddress::new(0, vec![source_network as u8]),
+        };
+
+        // Chain 10 - 20 - 30, plus an expensive direct link 10 - 30
+        discovery.add_link(link(10, 20, 5));
+        discovery.add_link(link(20, 30, 5));
+        discovery.add_link(link(10, 30, 50));
+
+        assert_eq!(discovery.find_path(10, 30), Some(vec![10, 20, 30]));
+
+        // Links are followed in reverse, and the cached 10 -> 30 path is not
+        // handed out for another source
+        assert_eq!(discovery.find_path(30, 10), Some(vec![30, 20, 10]));
+        assert_eq!(discovery.find_path(20, 30), Some(vec![20, 30]));
+
+        // A cheaper direct link replaces the chain
+        discovery.add_link(link(10, 30, 1));
+        assert_eq!(discovery.find_path(10, 30), Some(vec![10, 30]));
+
+        assert_eq!(discovery.find_path(99, 30), None);
+    }
+
     #[test]
     fn test_network_diagnostics() {
         let mut diagnostics = NetworkDiagnostics::new();
@@ -1808,6 +2420,53 @@ mod tests {
         assert!(!remote_addr.is_broadcast());
     }
 
+    #[test]
+    fn test_network_number() {
+        assert!(NetworkNumber(0).is_local());
+        assert!(!NetworkNumber(0).is_valid_remote());
+        assert!(NetworkNumber(65535).is_broadcast());
+        assert!(!NetworkNumber(65535).is_valid_remote());
+        assert!(NetworkNumber(1).is_valid_remote());
+        assert!(NetworkNumber(65534).is_valid_remote());
+        assert_eq!(u16::from(NetworkNumber::from(100)), 100);
+    }
+
+    #[test]
+    fn test_routing_to_reserved_networks() {
+        let mut manager = RouterManager::new(1);
+
+        // Even a router claiming the reserved numbers is not used for them
+        manager.routing_table.add_router(RouterInfo {
+            networks: vec![0, 100, 65535],
+            address: NetworkAddress::new(0, vec![192, 168, 1, 1]),
+            performance_index: None,
+        });
+        assert!(manager.routing_table.find_route(0).is_none());
+        assert!(manager.routing_table.find_route(65535).is_none());
+
+        // Network 0 is local delivery
+        let mut npdu = Npdu::new();
+        npdu.destination = Some(NetworkAddress::new(0, vec![10, 0, 0, 1]));
+        npdu.hop_count = Some(5);
+        assert!(manager.route_message(&mut npdu).unwrap().is_none());
+        assert_eq!(npdu.hop_count, Some(5));
+
+        // A global broadcast is not looked up either
+        let mut npdu = Npdu::global_broadcast();
+        assert!(manager.route_message(&mut npdu).unwrap().is_none());
+        assert_eq!(manager.performance_metrics.network_unreachable_count, 0);
+        assert_eq!(manager.performance_metrics.messages_routed, 0);
+
+        // Discovered routers only keep the remote networks
+        manager.add_discovered_router(
+            vec![0, 200, 65535],
+            NetworkAddress::new(0, vec![192, 168, 1, 2]),
+            None,
+        );
+        let router = manager.routing_table.find_route(200).unwrap();
+        assert_eq!(router.networks, vec![200]);
+    }
+
     #[test]
     fn test_performance_metrics() {
         let mut manager = RouterManager::new(1);
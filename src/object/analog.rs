@@ -5,8 +5,8 @@
 
 use crate::object::{
     engineering_units::EngineeringUnits, event_state::EventState, reliability::Reliability,
-    BacnetObject, ObjectError, ObjectIdentifier, ObjectType, PropertyIdentifier, PropertyValue,
-    Result,
+    BacnetObject, ObjectError, ObjectIdentifier, ObjectType, PriorityArray, PropertyIdentifier,
+    PropertyValue, Result, StatusFlags,
 };
 
 #[cfg(not(feature = "std"))]
@@ -75,7 +75,7 @@ pub struct AnalogOutput {
     /// Resolution
     pub resolution: Option<f32>,
     /// Priority array (16 levels)
-    pub priority_array: [Option<f32>; 16],
+    pub priority_array: PriorityArray<f32>,
     /// Relinquish default
     pub relinquish_default: f32,
     /// COV increment
@@ -104,7 +104,7 @@ pub struct AnalogValue {
     /// Units
     pub units: EngineeringUnits,
     /// Priority array (16 levels)
-    pub priority_array: [Option<f32>; 16],
+    pub priority_array: PriorityArray<f32>,
     /// Relinquish default
     pub relinquish_default: f32,
     /// COV increment
@@ -190,7 +190,7 @@ impl AnalogOutput {
             min_pres_value: None,
             max_pres_value: None,
             resolution: None,
-            priority_array: [None; 16],
+            priority_array: PriorityArray::new(),
             relinquish_default: 0.0,
             cov_increment: None,
         }
@@ -198,35 +198,19 @@ impl AnalogOutput {
 
     /// Write to priority array at specified priority level (1-16)
     pub fn write_priority(&mut self, priority: u8, value: Option<f32>) -> Result<()> {
-        if !(1..=16).contains(&priority) {
-            return Err(ObjectError::InvalidValue(
-                "Priority must be 1-16".to_string(),
-            ));
-        }
-        self.priority_array[(priority - 1) as usize] = value;
+        self.priority_array.set(priority, value)?;
         self.update_present_value();
         Ok(())
     }
 
     /// Update present value based on priority array
     fn update_present_value(&mut self) {
-        // Find highest priority non-null value
-        if let Some(value) = self.priority_array.iter().flatten().next() {
-            self.present_value = *value;
-            return;
-        }
-        // If all priorities are null, use relinquish default
-        self.present_value = self.relinquish_default;
+        self.present_value = self.priority_array.effective_value(self.relinquish_default);
     }
 
     /// Get the effective priority level for current present value
     pub fn get_effective_priority(&self) -> Option<u8> {
-        for (i, priority_value) in self.priority_array.iter().enumerate() {
-            if priority_value.is_some() {
-                return Some((i + 1) as u8);
-            }
-        }
-        None
+        self.priority_array.active_priority()
     }
 }
 
@@ -243,7 +227,7 @@ impl AnalogValue {
             reliability: Reliability::NoFaultDetected,
             out_of_service: false,
             units: EngineeringUnits::NoUnits,
-            priority_array: [None; 16],
+            priority_array: PriorityArray::new(),
             relinquish_default: 0.0,
             cov_increment: None,
         }
@@ -251,25 +235,14 @@ impl AnalogValue {
 
     /// Write to priority array at specified priority level (1-16)
     pub fn write_priority(&mut self, priority: u8, value: Option<f32>) -> Result<()> {
-        if !(1..=16).contains(&priority) {
-            return Err(ObjectError::InvalidValue(
-                "Priority must be 1-16".to_string(),
-            ));
-        }
-        self.priority_array[(priority - 1) as usize] = value;
+        self.priority_array.set(priority, value)?;
         self.update_present_value();
         Ok(())
     }
 
     /// Update present value based on priority array
     fn update_present_value(&mut self) {
-        // Find highest priority non-null value
-        if let Some(value) = self.priority_array.iter().flatten().next() {
-            self.present_value = *value;
-            return;
-        }
-        // If all priorities are null, use relinquish default
-        self.present_value = self.relinquish_default;
+        self.present_value = self.priority_array.effective_value(self.relinquish_default);
     }
 }
 
@@ -290,7 +263,21 @@ impl BacnetObject for AnalogInput {
                 ObjectType::AnalogInput,
             ))),
             PropertyIdentifier::PresentValue => Ok(PropertyValue::Real(self.present_value)),
+            PropertyIdentifier::StatusFlags => {
+                let (in_alarm, fault, overridden, out_of_service) = self.get_status_flags();
+                Ok(StatusFlags {
+                    in_alarm,
+                    fault,
+                    overridden,
+                    out_of_service,
+                }
+                .into())
+            }
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(u16::from(self.event_state) as u32))
+            }
             PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
+            PropertyIdentifier::Units => Ok(PropertyValue::Enumerated(u32::from(self.units))),
             _ => Err(ObjectError::UnknownProperty),
         }
     }
@@ -330,7 +317,10 @@ impl BacnetObject for AnalogInput {
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
             PropertyIdentifier::PresentValue,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
             PropertyIdentifier::OutOfService,
+            PropertyIdentifier::Units,
         ]
     }
 }
@@ -353,6 +343,13 @@ impl BacnetObject for AnalogOutput {
             ))),
             PropertyIdentifier::PresentValue => Ok(PropertyValue::Real(self.present_value)),
             PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
+            PropertyIdentifier::StatusFlags => {
+                Ok(StatusFlags::from_packed(self.status_flags).into())
+            }
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(u16::from(self.event_state) as u32))
+            }
+            PropertyIdentifier::Units => Ok(PropertyValue::Enumerated(u32::from(self.units))),
             PropertyIdentifier::PriorityArray => {
                 let array: Vec<PropertyValue> = self
                     .priority_array
@@ -364,6 +361,15 @@ impl BacnetObject for AnalogOutput {
                     .collect();
                 Ok(PropertyValue::Array(array))
             }
+            PropertyIdentifier::RelinquishDefault => {
+                Ok(PropertyValue::Real(self.relinquish_default))
+            }
+            PropertyIdentifier::CurrentCommandPriority => {
+                Ok(match self.priority_array.active_priority() {
+                    Some(priority) => PropertyValue::UnsignedInteger(priority.into()),
+                    None => PropertyValue::Null,
+                })
+            }
             _ => Err(ObjectError::UnknownProperty),
         }
     }
@@ -413,8 +419,13 @@ impl BacnetObject for AnalogOutput {
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
             PropertyIdentifier::PresentValue,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
             PropertyIdentifier::OutOfService,
+            PropertyIdentifier::Units,
             PropertyIdentifier::PriorityArray,
+            PropertyIdentifier::RelinquishDefault,
+            PropertyIdentifier::CurrentCommandPriority,
         ]
     }
 }
@@ -437,6 +448,13 @@ impl BacnetObject for AnalogValue {
             ))),
             PropertyIdentifier::PresentValue => Ok(PropertyValue::Real(self.present_value)),
             PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
+            PropertyIdentifier::StatusFlags => {
+                Ok(StatusFlags::from_packed(self.status_flags).into())
+            }
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(u16::from(self.event_state) as u32))
+            }
+            PropertyIdentifier::Units => Ok(PropertyValue::Enumerated(u32::from(self.units))),
             PropertyIdentifier::PriorityArray => {
                 let array: Vec<PropertyValue> = self
                     .priority_array
@@ -448,6 +466,15 @@ impl BacnetObject for AnalogValue {
                     .collect();
                 Ok(PropertyValue::Array(array))
             }
+            PropertyIdentifier::RelinquishDefault => {
+                Ok(PropertyValue::Real(self.relinquish_default))
+            }
+            PropertyIdentifier::CurrentCommandPriority => {
+                Ok(match self.priority_array.active_priority() {
+                    Some(priority) => PropertyValue::UnsignedInteger(priority.into()),
+                    None => PropertyValue::Null,
+                })
+            }
             _ => Err(ObjectError::UnknownProperty),
         }
     }
@@ -497,8 +524,13 @@ impl BacnetObject for AnalogValue {
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
             PropertyIdentifier::PresentValue,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
             PropertyIdentifier::OutOfService,
+            PropertyIdentifier::Units,
             PropertyIdentifier::PriorityArray,
+            PropertyIdentifier::RelinquishDefault,
+            PropertyIdentifier::CurrentCommandPriority,
         ]
     }
 }
@@ -574,5 +606,10 @@ mod tests {
         assert!(overridden);
         assert!(!out_of_service);
         assert_eq!(ai.status_flags, 0x0A); // 1010 in binary
+
+        assert_eq!(
+            ai.get_property(PropertyIdentifier::StatusFlags).unwrap(),
+            PropertyValue::BitString(vec![true, false, true, false])
+        );
     }
 }
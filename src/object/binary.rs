@@ -5,7 +5,7 @@
 
 use crate::object::{
     event_state::EventState, reliability::Reliability, BacnetObject, ObjectError, ObjectIdentifier,
-    ObjectType, PropertyIdentifier, PropertyValue, Result,
+    ObjectType, PriorityArray, PropertyIdentifier, PropertyValue, Result, StatusFlags,
 };
 
 #[cfg(not(feature = "std"))]
@@ -124,7 +124,7 @@ pub struct BinaryOutput {
     /// Active text
     pub active_text: String,
     /// Priority array (16 levels)
-    pub priority_array: [Option<BinaryPV>; 16],
+    pub priority_array: PriorityArray<BinaryPV>,
     /// Relinquish default
     pub relinquish_default: BinaryPV,
     /// Minimum off time
@@ -157,7 +157,7 @@ pub struct BinaryValue {
     /// Active text
     pub active_text: String,
     /// Priority array (16 levels)
-    pub priority_array: [Option<BinaryPV>; 16],
+    pub priority_array: PriorityArray<BinaryPV>,
     /// Relinquish default
     pub relinquish_default: BinaryPV,
 }
@@ -243,7 +243,7 @@ impl BinaryOutput {
             polarity: Polarity::Normal,
             inactive_text: "INACTIVE".to_string(),
             active_text: "ACTIVE".to_string(),
-            priority_array: [None; 16],
+            priority_array: PriorityArray::new(),
             relinquish_default: BinaryPV::Inactive,
             minimum_off_time: 0,
             minimum_on_time: 0,
@@ -252,35 +252,19 @@ impl BinaryOutput {
 
     /// Write to priority array at specified priority level (1-16)
     pub fn write_priority(&mut self, priority: u8, value: Option<BinaryPV>) -> Result<()> {
-        if !(1..=16).contains(&priority) {
-            return Err(ObjectError::InvalidValue(
-                "Priority must be 1-16".to_string(),
-            ));
-        }
-        self.priority_array[(priority - 1) as usize] = value;
+        self.priority_array.set(priority, value)?;
         self.update_present_value();
         Ok(())
     }
 
     /// Update present value based on priority array
     fn update_present_value(&mut self) {
-        // Find highest priority non-null value
-        if let Some(value) = self.priority_array.iter().flatten().next() {
-            self.present_value = *value;
-            return;
-        }
-        // If all priorities are null, use relinquish default
-        self.present_value = self.relinquish_default;
+        self.present_value = self.priority_array.effective_value(self.relinquish_default);
     }
 
     /// Get the effective priority level for current present value
     pub fn get_effective_priority(&self) -> Option<u8> {
-        for (i, priority_value) in self.priority_array.iter().enumerate() {
-            if priority_value.is_some() {
-                return Some((i + 1) as u8);
-            }
-        }
-        None
+        self.priority_array.active_priority()
     }
 }
 
@@ -298,32 +282,21 @@ impl BinaryValue {
             out_of_service: false,
             inactive_text: "INACTIVE".to_string(),
             active_text: "ACTIVE".to_string(),
-            priority_array: [None; 16],
+            priority_array: PriorityArray::new(),
             relinquish_default: BinaryPV::Inactive,
         }
     }
 
     /// Write to priority array at specified priority level (1-16)
     pub fn write_priority(&mut self, priority: u8, value: Option<BinaryPV>) -> Result<()> {
-        if !(1..=16).contains(&priority) {
-            return Err(ObjectError::InvalidValue(
-                "Priority must be 1-16".to_string(),
-            ));
-        }
-        self.priority_array[(priority - 1) as usize] = value;
+        self.priority_array.set(priority, value)?;
         self.update_present_value();
         Ok(())
     }
 
     /// Update present value based on priority array
     fn update_present_value(&mut self) {
-        // Find highest priority non-null value
-        if let Some(value) = self.priority_array.iter().flatten().next() {
-            self.present_value = *value;
-            return;
-        }
-        // If all priorities are null, use relinquish default
-        self.present_value = self.relinquish_default;
+        self.present_value = self.priority_array.effective_value(self.relinquish_default);
     }
 }
 
@@ -347,6 +320,13 @@ impl BacnetObject for BinaryInput {
                 Ok(PropertyValue::Enumerated(self.present_value as u32))
             }
             PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
+            PropertyIdentifier::StatusFlags => {
+                Ok(StatusFlags::from_packed(self.status_flags).into())
+            }
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(u16::from(self.event_state) as u32))
+            }
+            PropertyIdentifier::Polarity => Ok(PropertyValue::Enumerated(self.polarity as u32)),
             _ => Err(ObjectError::UnknownProperty),
         }
     }
@@ -386,7 +366,10 @@ impl BacnetObject for BinaryInput {
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
             PropertyIdentifier::PresentValue,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
             PropertyIdentifier::OutOfService,
+            PropertyIdentifier::Polarity,
         ]
     }
 }
@@ -411,6 +394,13 @@ impl BacnetObject for BinaryOutput {
                 Ok(PropertyValue::Enumerated(self.present_value as u32))
             }
             PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
+            PropertyIdentifier::StatusFlags => {
+                Ok(StatusFlags::from_packed(self.status_flags).into())
+            }
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(u16::from(self.event_state) as u32))
+            }
+            PropertyIdentifier::Polarity => Ok(PropertyValue::Enumerated(self.polarity as u32)),
             PropertyIdentifier::PriorityArray => {
                 let array: Vec<PropertyValue> = self
                     .priority_array
@@ -422,6 +412,15 @@ impl BacnetObject for BinaryOutput {
                     .collect();
                 Ok(PropertyValue::Array(array))
             }
+            PropertyIdentifier::RelinquishDefault => {
+                Ok(PropertyValue::Enumerated(self.relinquish_default as u32))
+            }
+            PropertyIdentifier::CurrentCommandPriority => {
+                Ok(match self.priority_array.active_priority() {
+                    Some(priority) => PropertyValue::UnsignedInteger(priority.into()),
+                    None => PropertyValue::Null,
+                })
+            }
             _ => Err(ObjectError::UnknownProperty),
         }
     }
@@ -480,8 +479,13 @@ impl BacnetObject for BinaryOutput {
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
             PropertyIdentifier::PresentValue,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
             PropertyIdentifier::OutOfService,
+            PropertyIdentifier::Polarity,
             PropertyIdentifier::PriorityArray,
+            PropertyIdentifier::RelinquishDefault,
+            PropertyIdentifier::CurrentCommandPriority,
         ]
     }
 }
@@ -506,6 +510,12 @@ impl BacnetObject for BinaryValue {
                 Ok(PropertyValue::Enumerated(self.present_value as u32))
             }
             PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
+            PropertyIdentifier::StatusFlags => {
+                Ok(StatusFlags::from_packed(self.status_flags).into())
+            }
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(u16::from(self.event_state) as u32))
+            }
             PropertyIdentifier::PriorityArray => {
                 let array: Vec<PropertyValue> = self
                     .priority_array
@@ -517,6 +527,15 @@ impl BacnetObject for BinaryValue {
                     .collect();
                 Ok(PropertyValue::Array(array))
             }
+            PropertyIdentifier::RelinquishDefault => {
+                Ok(PropertyValue::Enumerated(self.relinquish_default as u32))
+            }
+            PropertyIdentifier::CurrentCommandPriority => {
+                Ok(match self.priority_array.active_priority() {
+                    Some(priority) => PropertyValue::UnsignedInteger(priority.into()),
+                    None => PropertyValue::Null,
+                })
+            }
             _ => Err(ObjectError::UnknownProperty),
         }
     }
@@ -575,8 +594,12 @@ impl BacnetObject for BinaryValue {
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
             PropertyIdentifier::PresentValue,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
             PropertyIdentifier::OutOfService,
             PropertyIdentifier::PriorityArray,
+            PropertyIdentifier::RelinquishDefault,
+            PropertyIdentifier::CurrentCommandPriority,
         ]
     }
 }
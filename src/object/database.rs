@@ -160,6 +160,16 @@ impl ObjectDatabase {
         property: PropertyIdentifier,
     ) -> Result<PropertyValue> {
         let objects = self.objects.read().unwrap();
+        if identifier == self.device_id && property == PropertyIdentifier::ObjectList {
+            let mut list: Vec<_> = objects.keys().copied().collect();
+            // The device object comes first
+            list.sort_by_key(|&id| (id != self.device_id, u32::from(id.object_type), id.instance));
+            return Ok(PropertyValue::Array(
+                list.into_iter()
+                    .map(PropertyValue::ObjectIdentifier)
+                    .collect(),
+            ));
+        }
         match objects.get(&identifier) {
             Some(obj) => obj.get_property(property),
             None => Err(ObjectError::NotFound),
@@ -424,6 +434,27 @@ mod tests {
         assert_eq!(db.object_count_by_type(ObjectType::AnalogInput), 0);
     }
 
+    #[test]
+    fn test_device_object_list() {
+        let device = Device::new(1234, "Test Device".to_string());
+        let device_id = device.identifier();
+        let db = ObjectDatabase::new(device);
+        db.add_object(Box::new(BinaryInput::new(1, "Door Sensor".to_string())))
+            .unwrap();
+        db.add_object(Box::new(AnalogInput::new(2, "Temperature".to_string())))
+            .unwrap();
+
+        assert_eq!(
+            db.get_property(device_id, PropertyIdentifier::ObjectList)
+                .unwrap(),
+            PropertyValue::Array(vec![
+                PropertyValue::ObjectIdentifier(device_id),
+                PropertyValue::ObjectIdentifier(ObjectIdentifier::new(ObjectType::AnalogInput, 2)),
+                PropertyValue::ObjectIdentifier(ObjectIdentifier::new(ObjectType::BinaryInput, 1)),
+            ])
+        );
+    }
+
     #[test]
     fn test_object_lookup() {
         let device = Device::new(1234, "Test Device".to_string());
@@ -62,7 +62,33 @@ macro_rules! generate_engineering_units {
                 }
             }
 
+            impl core::fmt::Display for $name {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    match self.as_str() {
+                        Some(name) => f.write_str(name),
+                        None => f.write_str(&self.bacnet_name()),
+                    }
+                }
+            }
+
             impl $name {
+                /// Get the unit for the value of a Units property
+                ///
+                /// Codes without a standard unit map to `Reserved` or
+                /// `Custom`, so this never fails.
+                pub fn from_enumeration(value: u32) -> Self {
+                    Self::from(value)
+                }
+
+                /// The BACnet name of a standard unit, `None` for reserved
+                /// and custom codes
+                pub fn as_str(&self) -> Option<&'static str> {
+                    match self {
+                        $($name::$variant => Some($bacnet_name),)*
+                        _ => None,
+                    }
+                }
+
                 pub fn bacnet_name(&self) -> String {
                     match self {
                         $($name::$variant => $bacnet_name.to_string(),)*
@@ -655,6 +681,32 @@ mod tests {
         assert_eq!(EngineeringUnits::from(1000).bacnet_name(), "Custom(1000)");
     }
 
+    #[test]
+    fn test_from_enumeration_and_display() {
+        let celsius = EngineeringUnits::from_enumeration(62);
+        assert_eq!(celsius, EngineeringUnits::DegreesCelsius);
+        assert_eq!(celsius.as_str(), Some("degrees-celsius"));
+        assert_eq!(celsius.to_string(), "degrees-celsius");
+
+        let fahrenheit = EngineeringUnits::from_enumeration(64);
+        assert_eq!(fahrenheit.as_str(), Some("degrees-fahrenheit"));
+
+        let kilowatts = EngineeringUnits::from_enumeration(48);
+        assert_eq!(kilowatts, EngineeringUnits::Kilowatts);
+        assert_eq!(kilowatts.to_string(), "kilowatts");
+        assert_eq!(kilowatts.unit_symbol(), "kW");
+
+        // Codes without a standard unit
+        let custom = EngineeringUnits::from_enumeration(1000);
+        assert!(matches!(custom, EngineeringUnits::Custom(v) if v.value() == 1000));
+        assert_eq!(custom.as_str(), None);
+        assert_eq!(custom.to_string(), "Custom(1000)");
+
+        let reserved = EngineeringUnits::from_enumeration(255);
+        assert!(matches!(reserved, EngineeringUnits::Reserved(_)));
+        assert_eq!(reserved.to_string(), "Reserved(255)");
+    }
+
     #[test]
     fn test_unit_symbol() {
         assert_eq!(EngineeringUnits::DegreesCelsius.unit_symbol(), "°C");
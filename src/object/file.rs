@@ -200,7 +200,32 @@ impl BacnetObject for File {
             PropertyIdentifier::ObjectType => {
                 Ok(PropertyValue::Enumerated(u32::from(ObjectType::File)))
             }
+            PropertyIdentifier::FileType => {
+                Ok(PropertyValue::CharacterString(self.file_type.clone()))
+            }
+            PropertyIdentifier::FileSize => Ok(PropertyValue::UnsignedInteger(self.file_size)),
+            PropertyIdentifier::ModificationDate => {
+                // A BACnetDateTime; only the date is kept, so the time is
+                // unspecified
+                Ok(PropertyValue::List(vec![
+                    PropertyValue::Date(self.modification_date),
+                    PropertyValue::Time(crate::object::Time {
+                        hour: 255,
+                        minute: 255,
+                        second: 255,
+                        hundredths: 255,
+                    }),
+                ]))
+            }
             PropertyIdentifier::Archive => Ok(PropertyValue::Boolean(self.archive)),
+            PropertyIdentifier::ReadOnly => Ok(PropertyValue::Boolean(self.read_only)),
+            PropertyIdentifier::FileAccessMethod => {
+                Ok(PropertyValue::Enumerated(self.file_access_method as u32))
+            }
+            PropertyIdentifier::RecordCount => match self.record_count {
+                Some(count) => Ok(PropertyValue::UnsignedInteger(count)),
+                None => Err(ObjectError::UnknownProperty),
+            },
             _ => Err(ObjectError::UnknownProperty),
         }
     }
@@ -235,12 +260,21 @@ impl BacnetObject for File {
     }
 
     fn property_list(&self) -> Vec<PropertyIdentifier> {
-        vec![
+        let mut properties = vec![
             PropertyIdentifier::ObjectIdentifier,
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
+            PropertyIdentifier::FileType,
+            PropertyIdentifier::FileSize,
+            PropertyIdentifier::ModificationDate,
             PropertyIdentifier::Archive,
-        ]
+            PropertyIdentifier::ReadOnly,
+            PropertyIdentifier::FileAccessMethod,
+        ];
+        if self.record_count.is_some() {
+            properties.push(PropertyIdentifier::RecordCount);
+        }
+        properties
     }
 }
 
@@ -117,6 +117,7 @@
 //! - Proper object identifier encoding/decoding
 //! - Thread-safe object database implementation
 
+use crate::encoding::{advanced::bitstring::BacnetBitString, Result as EncodingResult};
 use bitflags::bitflags;
 use core::fmt::Display;
 #[cfg(feature = "std")]
@@ -250,10 +251,38 @@ pub trait BacnetObject: Send + Sync {
 
     /// Get list of all properties
     fn property_list(&self) -> Vec<PropertyIdentifier>;
+
+    /// Check that the object has every property its type requires
+    ///
+    /// Returns the required properties, per [`ObjectPropertyTable`], that
+    /// [`get_property`](Self::get_property) cannot read. Property_List is
+    /// always available through [`property_list`](Self::property_list).
+    /// Object types without a table are not checked.
+    fn validate_required_properties(&self) -> core::result::Result<(), Vec<PropertyIdentifier>> {
+        let Some(table) = ObjectPropertyTable::for_object_type(self.identifier().object_type)
+        else {
+            return Ok(());
+        };
+
+        let missing: Vec<_> = table
+            .required
+            .iter()
+            .copied()
+            .filter(|&property| {
+                property != PropertyIdentifier::PropertyList && self.get_property(property).is_err()
+            })
+            .collect();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
+        }
+    }
 }
 
 /// Property values can be of various types
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum PropertyValue {
     Null,
     Boolean(bool),
@@ -290,6 +319,115 @@ pub struct Time {
     pub hundredths: u8, // 0-99, 255 = unspecified
 }
 
+/// Date and time field value meaning "any"
+const UNSPECIFIED: u8 = 255;
+
+impl Date {
+    /// Create a date, filling in the weekday
+    ///
+    /// The weekday is unspecified when any other field is unspecified or a
+    /// pattern.
+    pub fn new(year: u16, month: u8, day: u8) -> Self {
+        Self {
+            year,
+            month,
+            day,
+            weekday: Self::compute_weekday(year, month, day),
+        }
+    }
+
+    /// The BACnet weekday (1 = Monday to 7 = Sunday) of a Gregorian date
+    ///
+    /// Returns 255 (unspecified) if the date is not a valid date in the years
+    /// BACnet can encode, 1900-2154.
+    pub fn compute_weekday(year: u16, month: u8, day: u8) -> u8 {
+        // Offsets of each month from January in Sakamoto's method
+        const MONTH_OFFSETS: [u32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+
+        if !(1900..=2154).contains(&year) || day == 0 || day > days_in_month(year, month) {
+            return UNSPECIFIED;
+        }
+
+        // January and February count as months of the previous year
+        let year = year as u32 - u32::from(month < 3);
+        let sunday_based = (year + year / 4 - year / 100
+            + year / 400
+            + MONTH_OFFSETS[month as usize - 1]
+            + day as u32)
+            % 7;
+        if sunday_based == 0 {
+            7
+        } else {
+            sunday_based as u8
+        }
+    }
+
+    /// Check if any field is unspecified or a pattern (odd/even months, last
+    /// day of month), so the date stands for more than one day
+    pub fn is_wildcard(&self) -> bool {
+        self.year == UNSPECIFIED as u16
+            || matches!(self.month, 13 | 14 | UNSPECIFIED)
+            || matches!(self.day, 32 | UNSPECIFIED)
+            || self.weekday == UNSPECIFIED
+    }
+
+    /// Check if a concrete date falls on this date pattern
+    ///
+    /// Unspecified fields match anything; month 13 and 14 match odd and even
+    /// months and day 32 the last day of the month.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn matches(&self, concrete: &Date) -> bool {
+        let year = self.year == UNSPECIFIED as u16 || self.year == concrete.year;
+        let month = match self.month {
+            UNSPECIFIED => true,
+            13 => concrete.month % 2 == 1,
+            14 => concrete.month % 2 == 0,
+            month => month == concrete.month,
+        };
+        let day = match self.day {
+            UNSPECIFIED => true,
+            32 => concrete.day == days_in_month(concrete.year, concrete.month),
+            day => day == concrete.day,
+        };
+        let weekday = self.weekday == UNSPECIFIED || self.weekday == concrete.weekday;
+
+        year && month && day && weekday
+    }
+}
+
+impl Time {
+    /// Check if any field is unspecified
+    pub fn is_wildcard(&self) -> bool {
+        [self.hour, self.minute, self.second, self.hundredths].contains(&UNSPECIFIED)
+    }
+
+    /// Check if a concrete time matches this time, unspecified fields
+    /// matching anything
+    pub fn matches(&self, concrete: &Time) -> bool {
+        let field = |pattern: u8, value: u8| pattern == UNSPECIFIED || pattern == value;
+        field(self.hour, concrete.hour)
+            && field(self.minute, concrete.minute)
+            && field(self.second, concrete.second)
+            && field(self.hundredths, concrete.hundredths)
+    }
+}
+
+/// Number of days in a month of the Gregorian calendar
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+#[allow(clippy::manual_is_multiple_of)]
+fn is_leap_year(year: u16) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
 /// Device object implementation
 #[derive(Debug, Clone)]
 pub struct Device {
@@ -323,6 +461,10 @@ pub struct Device {
     pub max_apdu_length_accepted: u16,
     /// Segmentation support
     pub segmentation_supported: Segmentation,
+    /// APDU timeout (milliseconds)
+    pub apdu_timeout: u16,
+    /// Number of APDU retries
+    pub number_of_apdu_retries: u8,
     /// Device address binding (for routing)
     pub device_address_binding: Vec<AddressBinding>,
     /// Database revision
@@ -348,6 +490,8 @@ impl Device {
             object_types_supported: vec![ObjectType::Device],
             max_apdu_length_accepted: 1476,
             segmentation_supported: Segmentation::Both,
+            apdu_timeout: 6000,
+            number_of_apdu_retries: 3,
             device_address_binding: Vec::new(),
             database_revision: 1,
         }
@@ -443,12 +587,52 @@ impl BacnetObject for Device {
             PropertyIdentifier::ProtocolRevision => Ok(PropertyValue::UnsignedInteger(
                 self.protocol_revision as u32,
             )),
+            PropertyIdentifier::ProtocolServicesSupported => {
+                let mut bits = self.protocol_services_supported.to_bool_vec();
+                bits.truncate(SERVICE_NAMES.len());
+                Ok(PropertyValue::BitString(bits))
+            }
+            PropertyIdentifier::ProtocolObjectTypesSupported => {
+                let types: Vec<usize> = self
+                    .object_types_supported
+                    .iter()
+                    .map(|&object_type| u32::from(object_type) as usize)
+                    .collect();
+                let mut bits = vec![false; types.iter().max().map_or(0, |&max| max + 1)];
+                for object_type in types {
+                    bits[object_type] = true;
+                }
+                Ok(PropertyValue::BitString(bits))
+            }
+            // A standalone device only knows itself; ObjectDatabase answers
+            // with every object it holds
+            PropertyIdentifier::ObjectList => {
+                Ok(PropertyValue::Array(vec![PropertyValue::ObjectIdentifier(
+                    self.identifier,
+                )]))
+            }
             PropertyIdentifier::MaxApduLengthAccepted => Ok(PropertyValue::UnsignedInteger(
                 self.max_apdu_length_accepted as u32,
             )),
             PropertyIdentifier::SegmentationSupported => Ok(PropertyValue::Enumerated(
                 self.segmentation_supported as u32,
             )),
+            PropertyIdentifier::ApduTimeout => {
+                Ok(PropertyValue::UnsignedInteger(self.apdu_timeout as u32))
+            }
+            PropertyIdentifier::NumberOfApduRetries => Ok(PropertyValue::UnsignedInteger(
+                self.number_of_apdu_retries as u32,
+            )),
+            PropertyIdentifier::DeviceAddressBinding => {
+                // Each binding is a device identifier and a local BACnetAddress
+                let mut bindings = Vec::new();
+                for binding in &self.device_address_binding {
+                    bindings.push(PropertyValue::ObjectIdentifier(binding.device_identifier));
+                    bindings.push(PropertyValue::UnsignedInteger(0));
+                    bindings.push(PropertyValue::OctetString(binding.network_address.clone()));
+                }
+                Ok(PropertyValue::List(bindings))
+            }
             PropertyIdentifier::DatabaseRevision => {
                 Ok(PropertyValue::UnsignedInteger(self.database_revision))
             }
@@ -535,8 +719,14 @@ impl BacnetObject for Device {
             PropertyIdentifier::ApplicationSoftwareVersion,
             PropertyIdentifier::ProtocolVersion,
             PropertyIdentifier::ProtocolRevision,
+            PropertyIdentifier::ProtocolServicesSupported,
+            PropertyIdentifier::ProtocolObjectTypesSupported,
+            PropertyIdentifier::ObjectList,
             PropertyIdentifier::MaxApduLengthAccepted,
             PropertyIdentifier::SegmentationSupported,
+            PropertyIdentifier::ApduTimeout,
+            PropertyIdentifier::NumberOfApduRetries,
+            PropertyIdentifier::DeviceAddressBinding,
             PropertyIdentifier::DatabaseRevision,
         ]
     }
@@ -651,6 +841,60 @@ bitflags! {
     }
 }
 
+/// Service names in Protocol_Services_Supported bit order
+const SERVICE_NAMES: [&str; 50] = [
+    "AcknowledgeAlarm",
+    "ConfirmedCOVNotification",
+    "ConfirmedEventNotification",
+    "GetAlarmSummary",
+    "GetEnrollmentSummary",
+    "SubscribeCOV",
+    "AtomicReadFile",
+    "AtomicWriteFile",
+    "AddListElement",
+    "RemoveListElement",
+    "CreateObject",
+    "DeleteObject",
+    "ReadProperty",
+    "ReadPropertyConditional",
+    "ReadPropertyMultiple",
+    "WriteProperty",
+    "WritePropertyMultiple",
+    "DeviceCommunicationControl",
+    "ConfirmedPrivateTransfer",
+    "ConfirmedTextMessage",
+    "ReinitializeDevice",
+    "VtOpen",
+    "VtClose",
+    "VtData",
+    "Authenticate",
+    "RequestKey",
+    "IAm",
+    "IHave",
+    "UnconfirmedCOVNotification",
+    "UnconfirmedEventNotification",
+    "UnconfirmedPrivateTransfer",
+    "UnconfirmedTextMessage",
+    "TimeSynchronization",
+    "WhoHas",
+    "WhoIs",
+    "ReadRange",
+    "UtcTimeSynchronization",
+    "LifeSafetyOperation",
+    "SubscribeCOVProperty",
+    "GetEventInformation",
+    "WriteGroup",
+    "SubscribeCOVPropertyMultiple",
+    "ConfirmedCOVNotificationMultiple",
+    "UnconfirmedCOVNotificationMultiple",
+    "ConfirmedAuditNotification",
+    "AuditLogQuery",
+    "UnconfirmedAuditNotification",
+    "WhoAmI",
+    "YouAre",
+    "AuthRequest",
+];
+
 impl ProtocolServicesSupported {
     pub fn to_bool_vec(&self) -> Vec<bool> {
         let mut vec = Vec::new();
@@ -659,6 +903,73 @@ impl ProtocolServicesSupported {
         }
         vec
     }
+
+    /// Decode the application-tagged bit string of a Protocol_Services_Supported
+    /// read, returning the services with the bytes consumed
+    ///
+    /// Bits for services this crate does not know are ignored.
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        let (bits, consumed) = BacnetBitString::decode(data)?;
+        Ok((Self::from(&bits), consumed))
+    }
+
+    /// Check if the device executes ReadProperty
+    pub fn supports_read_property(&self) -> bool {
+        self.contains(Self::READ_PROPERTY)
+    }
+
+    /// Check if the device executes ReadPropertyMultiple
+    pub fn supports_read_property_multiple(&self) -> bool {
+        self.contains(Self::READ_PROPERTY_MULTIPLE)
+    }
+
+    /// Check if the device executes WriteProperty
+    pub fn supports_write_property(&self) -> bool {
+        self.contains(Self::WRITE_PROPERTY)
+    }
+
+    /// Check if the device executes WritePropertyMultiple
+    pub fn supports_write_property_multiple(&self) -> bool {
+        self.contains(Self::WRITE_PROPERTY_MULTIPLE)
+    }
+
+    /// Check if the device accepts COV subscriptions with SubscribeCOV
+    pub fn supports_cov(&self) -> bool {
+        self.contains(Self::SUBSCRIBE_COV)
+    }
+
+    /// Check if the device executes ReadRange
+    pub fn supports_read_range(&self) -> bool {
+        self.contains(Self::READ_RANGE)
+    }
+
+    /// Check if the device answers Who-Is
+    pub fn supports_who_is(&self) -> bool {
+        self.contains(Self::WHO_IS)
+    }
+
+    /// Check if the device accepts TimeSynchronization
+    pub fn supports_time_synchronization(&self) -> bool {
+        self.contains(Self::TIME_SYNCHRONIZATION)
+    }
+
+    /// Iterate over the names of the supported services in bit order
+    pub fn service_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        SERVICE_NAMES
+            .iter()
+            .enumerate()
+            .filter(|(bit, _)| self.bits() & (1 << bit) != 0)
+            .map(|(_, name)| *name)
+    }
+}
+
+impl From<&BacnetBitString> for ProtocolServicesSupported {
+    fn from(bits: &BacnetBitString) -> Self {
+        let bits = (0..bits.len_bits().min(64))
+            .filter(|&index| bits.get(index))
+            .fold(0u64, |acc, index| acc | 1 << index);
+        Self::from_bits_truncate(bits)
+    }
 }
 
 impl From<Vec<bool>> for ProtocolServicesSupported {
@@ -699,9 +1010,16 @@ pub mod multistate;
 pub mod event_state;
 pub mod object_type;
 pub mod reliability;
+pub mod status_flags;
 pub use object_type::ObjectType;
 pub mod property_identifier;
 pub use property_identifier::PropertyIdentifier;
+/// Priority array of commandable objects
+pub mod priority_array;
+pub use priority_array::{PriorityArray, PriorityValue};
+/// Required and optional properties per object type
+pub mod property_table;
+pub use property_table::ObjectPropertyTable;
 
 pub use analog::{AnalogInput, AnalogOutput, AnalogValue};
 pub use binary::{BinaryInput, BinaryOutput, BinaryPV, BinaryValue, Polarity};
@@ -711,6 +1029,7 @@ pub use event_state::EventState;
 pub use file::{File, FileAccessMethod};
 pub use multistate::{MultiStateInput, MultiStateOutput, MultiStateValue};
 pub use reliability::Reliability;
+pub use status_flags::StatusFlags;
 
 #[cfg(feature = "std")]
 pub use database::{DatabaseBuilder, DatabaseStatistics, ObjectDatabase};
@@ -767,4 +1086,103 @@ mod tests {
         let services_new = ProtocolServicesSupported::from(bools);
         assert_eq!(services, services_new);
     }
+
+    #[test]
+    fn test_decode_protocol_services_supported() {
+        // 50 bits with ReadProperty (12) and WhoIs (34) set
+        let encoded = [0x85, 0x08, 0x06, 0x00, 0x08, 0x00, 0x00, 0x20, 0x00, 0x00];
+        let (services, consumed) = ProtocolServicesSupported::decode(&encoded).unwrap();
+        assert_eq!(consumed, encoded.len());
+        assert_eq!(
+            services,
+            ProtocolServicesSupported::READ_PROPERTY | ProtocolServicesSupported::WHO_IS
+        );
+
+        assert!(services.supports_read_property());
+        assert!(services.supports_who_is());
+        assert!(!services.supports_write_property());
+        assert!(!services.supports_cov());
+        assert_eq!(
+            services.service_names().collect::<Vec<_>>(),
+            ["ReadProperty", "WhoIs"]
+        );
+    }
+
+    #[test]
+    fn test_compute_weekday() {
+        assert_eq!(Date::compute_weekday(2024, 3, 15), 5); // Friday
+        assert_eq!(Date::compute_weekday(2024, 1, 1), 1); // Monday
+        assert_eq!(Date::compute_weekday(2000, 2, 29), 2); // Tuesday
+        assert_eq!(Date::compute_weekday(1900, 1, 1), 1); // Monday
+        assert_eq!(Date::compute_weekday(2023, 12, 31), 7); // Sunday
+
+        assert_eq!(Date::compute_weekday(2023, 2, 29), 255);
+        assert_eq!(Date::compute_weekday(2024, 13, 1), 255);
+        assert_eq!(Date::compute_weekday(255, 3, 15), 255);
+        assert_eq!(Date::compute_weekday(2024, 3, 32), 255);
+
+        let date = Date::new(2024, 3, 15);
+        assert_eq!(
+            date,
+            Date {
+                year: 2024,
+                month: 3,
+                day: 15,
+                weekday: 5
+            }
+        );
+        assert_eq!(Date::new(2024, 255, 15).weekday, 255);
+    }
+
+    #[test]
+    fn test_date_and_time_patterns() {
+        let date = |year, month, day, weekday| Date {
+            year,
+            month,
+            day,
+            weekday,
+        };
+
+        // Every 15th of 2024, whatever the weekday
+        let pattern = date(2024, 255, 15, 255);
+        assert!(pattern.is_wildcard());
+        assert!(pattern.matches(&date(2024, 3, 15, 5)));
+        assert!(pattern.matches(&date(2024, 7, 15, 1)));
+        assert!(!pattern.matches(&date(2024, 3, 16, 6)));
+        assert!(!pattern.matches(&date(2025, 3, 15, 6)));
+
+        // Any Monday
+        let mondays = date(255, 255, 255, 1);
+        assert!(mondays.matches(&date(2024, 7, 15, 1)));
+        assert!(!mondays.matches(&date(2024, 3, 15, 5)));
+
+        // Last day of even months
+        let pattern = date(255, 14, 32, 255);
+        assert!(pattern.matches(&date(2024, 2, 29, 4)));
+        assert!(!pattern.matches(&date(2023, 2, 29, 255)));
+        assert!(pattern.matches(&date(2023, 2, 28, 2)));
+        assert!(!pattern.matches(&date(2024, 3, 31, 7)));
+
+        let concrete = date(2024, 3, 15, 5);
+        assert!(!concrete.is_wildcard());
+        assert!(concrete.matches(&concrete));
+
+        let on_the_hour = Time {
+            hour: 255,
+            minute: 0,
+            second: 0,
+            hundredths: 0,
+        };
+        let time = |hour, minute| Time {
+            hour,
+            minute,
+            second: 0,
+            hundredths: 0,
+        };
+        assert!(on_the_hour.is_wildcard());
+        assert!(on_the_hour.matches(&time(8, 0)));
+        assert!(on_the_hour.matches(&time(17, 0)));
+        assert!(!on_the_hour.matches(&time(8, 30)));
+        assert!(!time(8, 0).is_wildcard());
+    }
 }
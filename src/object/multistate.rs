@@ -5,7 +5,7 @@
 
 use crate::object::{
     event_state::EventState, reliability::Reliability, BacnetObject, ObjectError, ObjectIdentifier,
-    ObjectType, PropertyIdentifier, PropertyValue, Result,
+    ObjectType, PriorityArray, PropertyIdentifier, PropertyValue, Result, StatusFlags,
 };
 
 #[cfg(not(feature = "std"))]
@@ -64,7 +64,7 @@ pub struct MultiStateOutput {
     /// State text array
     pub state_text: Vec<String>,
     /// Priority array (16 levels)
-    pub priority_array: [Option<u32>; 16],
+    pub priority_array: PriorityArray<u32>,
     /// Relinquish default
     pub relinquish_default: u32,
 }
@@ -93,7 +93,7 @@ pub struct MultiStateValue {
     /// State text array
     pub state_text: Vec<String>,
     /// Priority array (16 levels)
-    pub priority_array: [Option<u32>; 16],
+    pub priority_array: PriorityArray<u32>,
     /// Relinquish default
     pub relinquish_default: u32,
 }
@@ -175,19 +175,13 @@ impl MultiStateOutput {
             out_of_service: false,
             number_of_states,
             state_text,
-            priority_array: [None; 16],
+            priority_array: PriorityArray::new(),
             relinquish_default: 1,
         }
     }
 
     /// Write to priority array at specified priority level (1-16)
     pub fn write_priority(&mut self, priority: u8, value: Option<u32>) -> Result<()> {
-        if !(1..=16).contains(&priority) {
-            return Err(ObjectError::InvalidValue(
-                "Priority must be 1-16".to_string(),
-            ));
-        }
-
         if let Some(val) = value {
             if val < 1 || val > self.number_of_states {
                 return Err(ObjectError::InvalidValue(format!(
@@ -197,30 +191,19 @@ impl MultiStateOutput {
             }
         }
 
-        self.priority_array[(priority - 1) as usize] = value;
+        self.priority_array.set(priority, value)?;
         self.update_present_value();
         Ok(())
     }
 
     /// Update present value based on priority array
     fn update_present_value(&mut self) {
-        // Find highest priority non-null value
-        if let Some(value) = self.priority_array.iter().flatten().next() {
-            self.present_value = *value;
-            return;
-        }
-        // If all priorities are null, use relinquish default
-        self.present_value = self.relinquish_default;
+        self.present_value = self.priority_array.effective_value(self.relinquish_default);
     }
 
     /// Get the effective priority level for current present value
     pub fn get_effective_priority(&self) -> Option<u8> {
-        for (i, priority_value) in self.priority_array.iter().enumerate() {
-            if priority_value.is_some() {
-                return Some((i + 1) as u8);
-            }
-        }
-        None
+        self.priority_array.active_priority()
     }
 }
 
@@ -243,19 +226,13 @@ impl MultiStateValue {
             out_of_service: false,
             number_of_states,
             state_text,
-            priority_array: [None; 16],
+            priority_array: PriorityArray::new(),
             relinquish_default: 1,
         }
     }
 
     /// Write to priority array at specified priority level (1-16)
     pub fn write_priority(&mut self, priority: u8, value: Option<u32>) -> Result<()> {
-        if !(1..=16).contains(&priority) {
-            return Err(ObjectError::InvalidValue(
-                "Priority must be 1-16".to_string(),
-            ));
-        }
-
         if let Some(val) = value {
             if val < 1 || val > self.number_of_states {
                 return Err(ObjectError::InvalidValue(format!(
@@ -265,20 +242,14 @@ impl MultiStateValue {
             }
         }
 
-        self.priority_array[(priority - 1) as usize] = value;
+        self.priority_array.set(priority, value)?;
         self.update_present_value();
         Ok(())
     }
 
     /// Update present value based on priority array
     fn update_present_value(&mut self) {
-        // Find highest priority non-null value
-        if let Some(value) = self.priority_array.iter().flatten().next() {
-            self.present_value = *value;
-            return;
-        }
-        // If all priorities are null, use relinquish default
-        self.present_value = self.relinquish_default;
+        self.present_value = self.priority_array.effective_value(self.relinquish_default);
     }
 }
 
@@ -302,6 +273,15 @@ impl BacnetObject for MultiStateInput {
                 Ok(PropertyValue::UnsignedInteger(self.present_value))
             }
             PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
+            PropertyIdentifier::StatusFlags => {
+                Ok(StatusFlags::from_packed(self.status_flags).into())
+            }
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(u16::from(self.event_state) as u32))
+            }
+            PropertyIdentifier::NumberOfStates => {
+                Ok(PropertyValue::UnsignedInteger(self.number_of_states))
+            }
             _ => Err(ObjectError::UnknownProperty),
         }
     }
@@ -341,7 +321,10 @@ impl BacnetObject for MultiStateInput {
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
             PropertyIdentifier::PresentValue,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
             PropertyIdentifier::OutOfService,
+            PropertyIdentifier::NumberOfStates,
         ]
     }
 }
@@ -366,6 +349,15 @@ impl BacnetObject for MultiStateOutput {
                 Ok(PropertyValue::UnsignedInteger(self.present_value))
             }
             PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
+            PropertyIdentifier::StatusFlags => {
+                Ok(StatusFlags::from_packed(self.status_flags).into())
+            }
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(u16::from(self.event_state) as u32))
+            }
+            PropertyIdentifier::NumberOfStates => {
+                Ok(PropertyValue::UnsignedInteger(self.number_of_states))
+            }
             PropertyIdentifier::PriorityArray => {
                 let array: Vec<PropertyValue> = self
                     .priority_array
@@ -377,6 +369,15 @@ impl BacnetObject for MultiStateOutput {
                     .collect();
                 Ok(PropertyValue::Array(array))
             }
+            PropertyIdentifier::RelinquishDefault => {
+                Ok(PropertyValue::UnsignedInteger(self.relinquish_default))
+            }
+            PropertyIdentifier::CurrentCommandPriority => {
+                Ok(match self.priority_array.active_priority() {
+                    Some(priority) => PropertyValue::UnsignedInteger(priority.into()),
+                    None => PropertyValue::Null,
+                })
+            }
             _ => Err(ObjectError::UnknownProperty),
         }
     }
@@ -426,8 +427,13 @@ impl BacnetObject for MultiStateOutput {
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
             PropertyIdentifier::PresentValue,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
             PropertyIdentifier::OutOfService,
+            PropertyIdentifier::NumberOfStates,
             PropertyIdentifier::PriorityArray,
+            PropertyIdentifier::RelinquishDefault,
+            PropertyIdentifier::CurrentCommandPriority,
         ]
     }
 }
@@ -452,6 +458,15 @@ impl BacnetObject for MultiStateValue {
                 Ok(PropertyValue::UnsignedInteger(self.present_value))
             }
             PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
+            PropertyIdentifier::StatusFlags => {
+                Ok(StatusFlags::from_packed(self.status_flags).into())
+            }
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(u16::from(self.event_state) as u32))
+            }
+            PropertyIdentifier::NumberOfStates => {
+                Ok(PropertyValue::UnsignedInteger(self.number_of_states))
+            }
             PropertyIdentifier::PriorityArray => {
                 let array: Vec<PropertyValue> = self
                     .priority_array
@@ -463,6 +478,15 @@ impl BacnetObject for MultiStateValue {
                     .collect();
                 Ok(PropertyValue::Array(array))
             }
+            PropertyIdentifier::RelinquishDefault => {
+                Ok(PropertyValue::UnsignedInteger(self.relinquish_default))
+            }
+            PropertyIdentifier::CurrentCommandPriority => {
+                Ok(match self.priority_array.active_priority() {
+                    Some(priority) => PropertyValue::UnsignedInteger(priority.into()),
+                    None => PropertyValue::Null,
+                })
+            }
             _ => Err(ObjectError::UnknownProperty),
         }
     }
@@ -512,8 +536,13 @@ impl BacnetObject for MultiStateValue {
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
             PropertyIdentifier::PresentValue,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
             PropertyIdentifier::OutOfService,
+            PropertyIdentifier::NumberOfStates,
             PropertyIdentifier::PriorityArray,
+            PropertyIdentifier::RelinquishDefault,
+            PropertyIdentifier::CurrentCommandPriority,
         ]
     }
 }
@@ -72,3 +72,29 @@ generate_custom_enum!(
     u32,
     128..=1023
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::{decode_object_identifier, write_object_identifier};
+    use crate::object::ObjectIdentifier;
+
+    #[test]
+    fn test_object_type_from_value() {
+        assert_eq!(ObjectType::from(17u32), ObjectType::Schedule);
+        assert_eq!(ObjectType::from(15u32), ObjectType::NotificationClass);
+        assert_eq!(ObjectType::from(12u32), ObjectType::Loop);
+
+        // Vendor types keep their number instead of becoming a standard type
+        let proprietary = ObjectType::from(200u32);
+        assert!(matches!(proprietary, ObjectType::Custom(value) if value.value() == 200));
+        assert_eq!(u32::from(proprietary), 200);
+        assert!(matches!(ObjectType::from(100u32), ObjectType::Reserved(_)));
+
+        let mut buffer = Vec::new();
+        write_object_identifier(&mut buffer, ObjectIdentifier::new(proprietary, 7)).unwrap();
+        let (decoded, _) = decode_object_identifier(&buffer).unwrap();
+        assert_eq!(decoded.object_type, proprietary);
+        assert_eq!(decoded.instance, 7);
+    }
+}
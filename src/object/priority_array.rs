@@ -0,0 +1,262 @@
+//! Priority array of commandable objects
+//!
+//! Commandable objects such as Analog Output and Binary Output take their
+//! present value from a 16-slot priority array (ASHRAE 135 clause 19.2).
+//! Priority 1 is the highest; a slot that has been relinquished is NULL. When
+//! every slot is NULL the object falls back to its Relinquish_Default.
+
+use crate::encoding::{
+    decode_application_tag, decode_enumerated, decode_real, decode_unsigned, write_application_tag,
+    write_enumerated, write_real, write_unsigned, ApplicationTag, BufWrite, EncodingError,
+    Result as EncodingResult,
+};
+use crate::object::{BinaryPV, ObjectError, Result};
+
+#[cfg(not(feature = "std"))]
+use alloc::string::ToString;
+
+/// Number of slots in a priority array
+pub const PRIORITY_LEVELS: usize = 16;
+
+/// Value type that can be commanded through a priority array
+pub trait PriorityValue: Copy {
+    /// Encode the value application-tagged
+    fn encode<B: BufWrite + ?Sized>(&self, buffer: &mut B) -> EncodingResult<()>;
+
+    /// Decode an application-tagged value, returning it with the bytes consumed
+    fn decode(data: &[u8]) -> EncodingResult<(Self, usize)>;
+}
+
+impl PriorityValue for f32 {
+    fn encode<B: BufWrite + ?Sized>(&self, buffer: &mut B) -> EncodingResult<()> {
+        write_real(buffer, *self)
+    }
+
+    fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        decode_real(data)
+    }
+}
+
+impl PriorityValue for u32 {
+    fn encode<B: BufWrite + ?Sized>(&self, buffer: &mut B) -> EncodingResult<()> {
+        write_unsigned(buffer, *self)
+    }
+
+    fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        decode_unsigned(data)
+    }
+}
+
+impl PriorityValue for BinaryPV {
+    fn encode<B: BufWrite + ?Sized>(&self, buffer: &mut B) -> EncodingResult<()> {
+        write_enumerated(buffer, *self as u32)
+    }
+
+    fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        let (value, consumed) = decode_enumerated(data)?;
+        let value = match value {
+            0 => BinaryPV::Inactive,
+            1 => BinaryPV::Active,
+            _ => return Err(EncodingError::ValueOutOfRange),
+        };
+        Ok((value, consumed))
+    }
+}
+
+/// BACnetPriorityArray of a commandable object
+///
+/// # Examples
+///
+/// ```
+/// use bacnet_rs::object::PriorityArray;
+///
+/// let mut array = PriorityArray::new();
+/// array.set(8, Some(72.0)).unwrap();
+/// array.set(1, Some(50.0)).unwrap();
+/// assert_eq!(array.effective_value(68.0), 50.0);
+///
+/// array.relinquish(1).unwrap();
+/// array.relinquish(8).unwrap();
+/// assert_eq!(array.effective_value(68.0), 68.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriorityArray<T> {
+    slots: [Option<T>; PRIORITY_LEVELS],
+}
+
+impl<T: Copy> PriorityArray<T> {
+    /// Create a priority array with every slot relinquished
+    pub fn new() -> Self {
+        Self {
+            slots: [None; PRIORITY_LEVELS],
+        }
+    }
+
+    /// Command a value at a priority (1-16), or relinquish it with `None`
+    pub fn set(&mut self, priority: u8, value: Option<T>) -> Result<()> {
+        let index = Self::index(priority)?;
+        self.slots[index] = value;
+        Ok(())
+    }
+
+    /// Relinquish the command at a priority (1-16)
+    pub fn relinquish(&mut self, priority: u8) -> Result<()> {
+        self.set(priority, None)
+    }
+
+    /// Get the value commanded at a priority, `None` if the slot is
+    /// relinquished or the priority is not 1-16
+    pub fn get(&self, priority: u8) -> Option<T> {
+        Self::index(priority)
+            .ok()
+            .and_then(|index| self.slots[index])
+    }
+
+    /// The highest priority with a commanded value
+    pub fn active_priority(&self) -> Option<u8> {
+        self.slots
+            .iter()
+            .position(Option::is_some)
+            .map(|index| index as u8 + 1)
+    }
+
+    /// The value of the highest priority non-NULL slot, or
+    /// `relinquish_default` if every slot is relinquished
+    pub fn effective_value(&self, relinquish_default: T) -> T {
+        self.slots
+            .iter()
+            .flatten()
+            .next()
+            .copied()
+            .unwrap_or(relinquish_default)
+    }
+
+    /// Iterate over the slots from priority 1 to 16
+    pub fn iter(&self) -> impl Iterator<Item = &Option<T>> {
+        self.slots.iter()
+    }
+
+    fn index(priority: u8) -> Result<usize> {
+        if !(1..=PRIORITY_LEVELS as u8).contains(&priority) {
+            return Err(ObjectError::InvalidValue(
+                "Priority must be 1-16".to_string(),
+            ));
+        }
+        Ok(priority as usize - 1)
+    }
+}
+
+impl<T: PriorityValue> PriorityArray<T> {
+    /// Encode as a BACnetPriorityArray, with NULL for relinquished slots
+    pub fn encode<B: BufWrite + ?Sized>(&self, buffer: &mut B) -> EncodingResult<()> {
+        for slot in &self.slots {
+            match slot {
+                Some(value) => value.encode(buffer)?,
+                None => write_application_tag(buffer, ApplicationTag::Null, 0)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Decode a BACnetPriorityArray of exactly 16 elements, returning it with
+    /// the bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        let mut array = Self::new();
+        let mut pos = 0;
+
+        for slot in array.slots.iter_mut() {
+            if pos >= data.len() {
+                return Err(EncodingError::UnexpectedEndOfData);
+            }
+
+            let (tag, length, consumed) = decode_application_tag(&data[pos..])?;
+            if tag == ApplicationTag::Null && length == 0 {
+                pos += consumed;
+            } else {
+                let (value, consumed) = T::decode(&data[pos..])?;
+                *slot = Some(value);
+                pos += consumed;
+            }
+        }
+
+        Ok((array, pos))
+    }
+}
+
+impl<T: Copy> Default for PriorityArray<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_override_and_relinquish() {
+        let mut array = PriorityArray::new();
+        assert_eq!(array.effective_value(20.0), 20.0);
+        assert_eq!(array.active_priority(), None);
+
+        // Operator command at priority 8
+        array.set(8, Some(72.5)).unwrap();
+        assert_eq!(array.effective_value(20.0), 72.5);
+        assert_eq!(array.active_priority(), Some(8));
+
+        // Manual life safety override at priority 1
+        array.set(1, Some(0.0)).unwrap();
+        assert_eq!(array.effective_value(20.0), 0.0);
+        assert_eq!(array.active_priority(), Some(1));
+        assert_eq!(array.get(8), Some(72.5));
+
+        array.relinquish(1).unwrap();
+        assert_eq!(array.effective_value(20.0), 72.5);
+
+        array.relinquish(8).unwrap();
+        assert_eq!(array.effective_value(20.0), 20.0);
+        assert_eq!(array.active_priority(), None);
+
+        assert!(array.set(0, Some(1.0)).is_err());
+        assert!(array.relinquish(17).is_err());
+        assert_eq!(array.get(0), None);
+    }
+
+    #[test]
+    fn test_priority_array_round_trip() {
+        let mut array = PriorityArray::new();
+        array.set(1, Some(BinaryPV::Inactive)).unwrap();
+        array.set(16, Some(BinaryPV::Active)).unwrap();
+
+        let mut buffer = Vec::new();
+        array.encode(&mut buffer).unwrap();
+
+        let mut expected = vec![0x91, 0x00];
+        expected.extend_from_slice(&[0x00; 14]);
+        expected.extend_from_slice(&[0x91, 0x01]);
+        assert_eq!(buffer, expected);
+
+        assert_eq!(
+            PriorityArray::<BinaryPV>::decode(&buffer).unwrap(),
+            (array, buffer.len())
+        );
+
+        // Fewer than 16 elements
+        assert!(matches!(
+            PriorityArray::<BinaryPV>::decode(&buffer[..buffer.len() - 2]),
+            Err(EncodingError::UnexpectedEndOfData)
+        ));
+    }
+
+    #[test]
+    fn test_real_priority_array_decode() {
+        let mut buffer = Vec::new();
+        write_real(&mut buffer, 42.0).unwrap();
+        buffer.extend_from_slice(&[0x00; 15]);
+
+        let (array, consumed) = PriorityArray::<f32>::decode(&buffer).unwrap();
+        assert_eq!(consumed, buffer.len());
+        assert_eq!(array.get(1), Some(42.0));
+        assert_eq!(array.active_priority(), Some(1));
+    }
+}
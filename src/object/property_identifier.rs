@@ -517,3 +517,557 @@ generate_custom_enum!(
     ZoneMembers = 165,
     ZoneTo = 321,
 }, u32, 512..=4194303);
+
+impl PropertyIdentifier {
+    /// Name of the property as written in the standard, e.g. `present-value`
+    ///
+    /// Proprietary identifiers are named `proprietary` and unassigned ones
+    /// `reserved`; their numbers are available through `u32::from`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::AbsenteeLimit => "absentee-limit",
+            Self::AcceptedModes => "accepted-modes",
+            Self::AccessAlarmEvents => "access-alarm-events",
+            Self::AccessDoors => "access-doors",
+            Self::AccessEvent => "access-event",
+            Self::AccessEventAuthenticationFactor => "access-event-authentication-factor",
+            Self::AccessEventCredential => "access-event-credential",
+            Self::AccessEventTag => "access-event-tag",
+            Self::AccessEventTime => "access-event-time",
+            Self::AccessTransactionEvents => "access-transaction-events",
+            Self::Accompaniment => "accompaniment",
+            Self::AccompanimentTime => "accompaniment-time",
+            Self::AckRequired => "ack-required",
+            Self::AckedTransitions => "acked-transitions",
+            Self::Action => "action",
+            Self::ActionText => "action-text",
+            Self::ActivationTime => "activation-time",
+            Self::ActiveAuthenticationPolicy => "active-authentication-policy",
+            Self::ActiveCovMultipleSubscriptions => "active-cov-multiple-subscriptions",
+            Self::ActiveCovSubscriptions => "active-cov-subscriptions",
+            Self::ActiveText => "active-text",
+            Self::ActiveVtSessions => "active-vt-sessions",
+            Self::ActualShedLevel => "actual-shed-level",
+            Self::AdditionalReferencePorts => "additional-reference-ports",
+            Self::AdjustValue => "adjust-value",
+            Self::AlarmValue => "alarm-value",
+            Self::AlarmValues => "alarm-values",
+            Self::AlignIntervals => "align-intervals",
+            Self::All => "all",
+            Self::AllWritesSuccessful => "all-writes-successful",
+            Self::AllowGroupDelayInhibit => "allow-group-delay-inhibit",
+            Self::ApduLength => "apdu-length",
+            Self::ApduSegmentTimeout => "apdu-segment-timeout",
+            Self::ApduTimeout => "apdu-timeout",
+            Self::ApplicationSoftwareVersion => "application-software-version",
+            Self::Archive => "archive",
+            Self::AssignedAccessRights => "assigned-access-rights",
+            Self::AssignedLandingCalls => "assigned-landing-calls",
+            Self::AttemptedSamples => "attempted-samples",
+            Self::AuditSourceReporter => "audit-source-reporter",
+            Self::AuditLevel => "audit-level",
+            Self::AuditNotificationRecipient => "audit-notification-recipient",
+            Self::AuditPriorityFilter => "audit-priority-filter",
+            Self::AuditableOperations => "auditable-operations",
+            Self::AuthenticationFactors => "authentication-factors",
+            Self::AuthenticationPolicyList => "authentication-policy-list",
+            Self::AuthenticationPolicyNames => "authentication-policy-names",
+            Self::AuthenticationStatus => "authentication-status",
+            Self::AuthorizationCache => "authorization-cache",
+            Self::AuthorizationExemptions => "authorization-exemptions",
+            Self::AuthorizationGroups => "authorization-groups",
+            Self::AuthorizationMode => "authorization-mode",
+            Self::AuthorizationPolicy => "authorization-policy",
+            Self::AuthorizationScope => "authorization-scope",
+            Self::AuthorizationServer => "authorization-server",
+            Self::AuthorizationStatus => "authorization-status",
+            Self::AutoSubordinateDiscovery => "auto-subordinate-discovery",
+            Self::AverageValue => "average-value",
+            Self::BackupAndRestoreState => "backup-and-restore-state",
+            Self::BackupFailureTimeout => "backup-failure-timeout",
+            Self::BackupPreparationTime => "backup-preparation-time",
+            Self::BacnetIpGlobalAddress => "bacnet-ip-global-address",
+            Self::BacnetIpMode => "bacnet-ip-mode",
+            Self::BacnetIpMulticastAddress => "bacnet-ip-multicast-address",
+            Self::BacnetIpNatTraversal => "bacnet-ip-nat-traversal",
+            Self::BacnetIpUdpPort => "bacnet-ip-udp-port",
+            Self::BacnetIpv6Mode => "bacnet-ipv6-mode",
+            Self::BacnetIpv6UdpPort => "bacnet-ipv6-udp-port",
+            Self::BacnetIpv6MulticastAddress => "bacnet-ipv6-multicast-address",
+            Self::BbmdAcceptFdRegistrations => "bbmd-accept-fd-registrations",
+            Self::BbmdBroadcastDistributionTable => "bbmd-broadcast-distribution-table",
+            Self::BbmdForeignDeviceTable => "bbmd-foreign-device-table",
+            Self::BelongsTo => "belongs-to",
+            Self::Bias => "bias",
+            Self::BitMask => "bit-mask",
+            Self::BitText => "bit-text",
+            Self::BlinkWarnEnable => "blink-warn-enable",
+            Self::BufferSize => "buffer-size",
+            Self::CarAssignedDirection => "car-assigned-direction",
+            Self::CarDoorCommand => "car-door-command",
+            Self::CarDoorStatus => "car-door-status",
+            Self::CarDoorText => "car-door-text",
+            Self::CarDoorZone => "car-door-zone",
+            Self::CarDriveStatus => "car-drive-status",
+            Self::CarLoad => "car-load",
+            Self::CarLoadUnits => "car-load-units",
+            Self::CarMode => "car-mode",
+            Self::CarMovingDirection => "car-moving-direction",
+            Self::CarPosition => "car-position",
+            Self::CertificateSigningRequestFile => "certificate-signing-request-file",
+            Self::ChangeOfStateCount => "change-of-state-count",
+            Self::ChangeOfStateTime => "change-of-state-time",
+            Self::ChangesPending => "changes-pending",
+            Self::ChannelNumber => "channel-number",
+            Self::ClientCovIncrement => "client-cov-increment",
+            Self::ColorCommand => "color-command",
+            Self::ColorOverride => "color-override",
+            Self::ColorReference => "color-reference",
+            Self::Command => "command",
+            Self::CommandTimeArray => "command-time-array",
+            Self::CommandValidationResult => "command-validation-result",
+            Self::ConfigurationFiles => "configuration-files",
+            Self::ControlGroups => "control-groups",
+            Self::ControlledVariableReference => "controlled-variable-reference",
+            Self::ControlledVariableUnits => "controlled-variable-units",
+            Self::ControlledVariableValue => "controlled-variable-value",
+            Self::Count => "count",
+            Self::CountBeforeChange => "count-before-change",
+            Self::CountChangeTime => "count-change-time",
+            Self::CovIncrement => "cov-increment",
+            Self::CovPeriod => "cov-period",
+            Self::CovResubscriptionInterval => "cov-resubscription-interval",
+            Self::CovuPeriod => "covu-period",
+            Self::CovuRecipients => "covu-recipients",
+            Self::CredentialDisable => "credential-disable",
+            Self::CredentialStatus => "credential-status",
+            Self::Credentials => "credentials",
+            Self::CredentialsInZone => "credentials-in-zone",
+            Self::CurrentCommandPriority => "current-command-priority",
+            Self::CurrentHealth => "current-health",
+            Self::DatabaseRevision => "database-revision",
+            Self::DateList => "date-list",
+            Self::DaylightSavingsStatus => "daylight-savings-status",
+            Self::DaysRemaining => "days-remaining",
+            Self::Deadband => "deadband",
+            Self::DefaultColor => "default-color",
+            Self::DefaultColorTemperature => "default-color-temperature",
+            Self::DefaultFadeTime => "default-fade-time",
+            Self::DefaultPresentValue => "default-present-value",
+            Self::DefaultRampRate => "default-ramp-rate",
+            Self::DefaultStepIncrement => "default-step-increment",
+            Self::DefaultSubordinateRelationship => "default-subordinate-relationship",
+            Self::DefaultTimeout => "default-timeout",
+            Self::DeleteOnForward => "delete-on-forward",
+            Self::DefaultOnValue => "default-on-value",
+            Self::DeployedProfileLocation => "deployed-profile-location",
+            Self::DerivativeConstant => "derivative-constant",
+            Self::DerivativeConstantUnits => "derivative-constant-units",
+            Self::Description => "description",
+            Self::DescriptionOfHalt => "description-of-halt",
+            Self::DeviceAddressBinding => "device-address-binding",
+            Self::DeviceAddressProxyEnable => "device-address-proxy-enable",
+            Self::DeviceAddressProxyTable => "device-address-proxy-table",
+            Self::DeviceAddressProxyTimeout => "device-address-proxy-timeout",
+            Self::DeviceType => "device-type",
+            Self::DeviceUuid => "device-uuid",
+            Self::DirectReading => "direct-reading",
+            Self::DoorAlarmState => "door-alarm-state",
+            Self::DoorExtendedPulseTime => "door-extended-pulse-time",
+            Self::DoorMembers => "door-members",
+            Self::DoorOpenTooLongTime => "door-open-too-long-time",
+            Self::DoorPulseTime => "door-pulse-time",
+            Self::DoorStatus => "door-status",
+            Self::DoorUnlockDelayTime => "door-unlock-delay-time",
+            Self::DutyWindow => "duty-window",
+            Self::EffectivePeriod => "effective-period",
+            Self::EgressActive => "egress-active",
+            Self::EgressTime => "egress-time",
+            Self::ElapsedActiveTime => "elapsed-active-time",
+            Self::ElevatorGroup => "elevator-group",
+            Self::Enable => "enable",
+            Self::EnergyMeter => "energy-meter",
+            Self::EnergyMeterRef => "energy-meter-ref",
+            Self::EntryPoints => "entry-points",
+            Self::ErrorLimit => "error-limit",
+            Self::EscalatorMode => "escalator-mode",
+            Self::EventAlgorithmInhibit => "event-algorithm-inhibit",
+            Self::EventAlgorithmInhibitRef => "event-algorithm-inhibit-ref",
+            Self::EventDetectionEnable => "event-detection-enable",
+            Self::EventEnable => "event-enable",
+            Self::EventMessageTexts => "event-message-texts",
+            Self::EventMessageTextsConfig => "event-message-texts-config",
+            Self::EventParameters => "event-parameters",
+            Self::EventState => "event-state",
+            Self::EventTimeStamps => "event-time-stamps",
+            Self::EventType => "event-type",
+            Self::ExceptionSchedule => "exception-schedule",
+            Self::ExecutionDelay => "execution-delay",
+            Self::ExitPoints => "exit-points",
+            Self::ExpectedShedLevel => "expected-shed-level",
+            Self::ExpirationTime => "expiration-time",
+            Self::ExtendedTimeEnable => "extended-time-enable",
+            Self::FailedAttemptEvents => "failed-attempt-events",
+            Self::FailedAttempts => "failed-attempts",
+            Self::FailedAttemptsTime => "failed-attempts-time",
+            Self::FaultHighLimit => "fault-high-limit",
+            Self::FaultLowLimit => "fault-low-limit",
+            Self::FaultParameters => "fault-parameters",
+            Self::FaultSignals => "fault-signals",
+            Self::FaultType => "fault-type",
+            Self::FaultValues => "fault-values",
+            Self::FdBbmdAddress => "fd-bbmd-address",
+            Self::FdSubscriptionLifetime => "fd-subscription-lifetime",
+            Self::FeedbackValue => "feedback-value",
+            Self::FileAccessMethod => "file-access-method",
+            Self::FileSize => "file-size",
+            Self::FileType => "file-type",
+            Self::FirmwareRevision => "firmware-revision",
+            Self::FloorNumber => "floor-number",
+            Self::FloorText => "floor-text",
+            Self::FullDutyBaseline => "full-duty-baseline",
+            Self::GlobalIdentifier => "global-identifier",
+            Self::GroupId => "group-id",
+            Self::GroupMemberNames => "group-member-names",
+            Self::GroupMembers => "group-members",
+            Self::GroupMode => "group-mode",
+            Self::HighEndTrim => "high-end-trim",
+            Self::HighLimit => "high-limit",
+            Self::HigherDeck => "higher-deck",
+            Self::InProcess => "in-process",
+            Self::InProgress => "in-progress",
+            Self::InactiveText => "inactive-text",
+            Self::InitialTimeout => "initial-timeout",
+            Self::InputReference => "input-reference",
+            Self::InstallationId => "installation-id",
+            Self::InstanceOf => "instance-of",
+            Self::InstantaneousPower => "instantaneous-power",
+            Self::IntegralConstant => "integral-constant",
+            Self::IntegralConstantUnits => "integral-constant-units",
+            Self::InterfaceValue => "interface-value",
+            Self::IntervalOffset => "interval-offset",
+            Self::IpAddress => "ip-address",
+            Self::IpDefaultGateway => "ip-default-gateway",
+            Self::IpDhcpEnable => "ip-dhcp-enable",
+            Self::IpDhcpLeaseTime => "ip-dhcp-lease-time",
+            Self::IpDhcpLeaseTimeRemaining => "ip-dhcp-lease-time-remaining",
+            Self::IpDhcpServer => "ip-dhcp-server",
+            Self::IpDnsServer => "ip-dns-server",
+            Self::IpSubnetMask => "ip-subnet-mask",
+            Self::Ipv6Address => "ipv6-address",
+            Self::Ipv6AutoAddressingEnable => "ipv6-auto-addressing-enable",
+            Self::Ipv6DefaultGateway => "ipv6-default-gateway",
+            Self::Ipv6DhcpLeaseTime => "ipv6-dhcp-lease-time",
+            Self::Ipv6DhcpLeaseTimeRemaining => "ipv6-dhcp-lease-time-remaining",
+            Self::Ipv6DhcpServer => "ipv6-dhcp-server",
+            Self::Ipv6DnsServer => "ipv6-dns-server",
+            Self::Ipv6PrefixLength => "ipv6-prefix-length",
+            Self::Ipv6ZoneIndex => "ipv6-zone-index",
+            Self::IssueConfirmedNotifications => "issue-confirmed-notifications",
+            Self::IssuerCertificateFiles => "issuer-certificate-files",
+            Self::IsUtc => "is-utc",
+            Self::LandingCallControl => "landing-call-control",
+            Self::LandingCalls => "landing-calls",
+            Self::LandingDoorStatus => "landing-door-status",
+            Self::LastAccessEvent => "last-access-event",
+            Self::LastAccessPoint => "last-access-point",
+            Self::LastCommandTime => "last-command-time",
+            Self::LastCredentialAdded => "last-credential-added",
+            Self::LastCredentialAddedTime => "last-credential-added-time",
+            Self::LastCredentialRemoved => "last-credential-removed",
+            Self::LastCredentialRemovedTime => "last-credential-removed-time",
+            Self::LastNotifyRecord => "last-notify-record",
+            Self::LastOnValue => "last-on-value",
+            Self::LastPriority => "last-priority",
+            Self::LastRestartReason => "last-restart-reason",
+            Self::LastRestoreTime => "last-restore-time",
+            Self::LastStateChange => "last-state-change",
+            Self::LastUseTime => "last-use-time",
+            Self::LifeSafetyAlarmValues => "life-safety-alarm-values",
+            Self::LightingCommand => "lighting-command",
+            Self::LightingCommandDefaultPriority => "lighting-command-default-priority",
+            Self::LimitEnable => "limit-enable",
+            Self::LimitMonitoringInterval => "limit-monitoring-interval",
+            Self::LinkSpeed => "link-speed",
+            Self::LinkSpeedAutonegotiate => "link-speed-autonegotiate",
+            Self::LinkSpeeds => "link-speeds",
+            Self::ListOfGroupMembers => "list-of-group-members",
+            Self::ListOfObjectPropertyReferences => "list-of-object-property-references",
+            Self::LocalDate => "local-date",
+            Self::LocalForwardingOnly => "local-forwarding-only",
+            Self::LocalTime => "local-time",
+            Self::Location => "location",
+            Self::LockStatus => "lock-status",
+            Self::Lockout => "lockout",
+            Self::LockoutRelinquishTime => "lockout-relinquish-time",
+            Self::LogBuffer => "log-buffer",
+            Self::LogDeviceObjectProperty => "log-device-object-property",
+            Self::LogInterval => "log-interval",
+            Self::LoggingObject => "logging-object",
+            Self::LoggingRecord => "logging-record",
+            Self::LoggingType => "logging-type",
+            Self::LowDiffLimit => "low-diff-limit",
+            Self::LowEndTrim => "low-end-trim",
+            Self::LowLimit => "low-limit",
+            Self::LowerDeck => "lower-deck",
+            Self::MacAddress => "mac-address",
+            Self::MachineRoomId => "machine-room-id",
+            Self::MaintenanceRequired => "maintenance-required",
+            Self::MakingCarCall => "making-car-call",
+            Self::ManipulatedVariableReference => "manipulated-variable-reference",
+            Self::ManualSubordinateAddressBinding => "manual-subordinate-address-binding",
+            Self::MaskedAlarmValues => "masked-alarm-values",
+            Self::MaxActualValue => "max-actual-value",
+            Self::MaxApduLengthAccepted => "max-apdu-length-accepted",
+            Self::MaxBvlcLengthAccepted => "max-bvlc-length-accepted",
+            Self::MaxFailedAttempts => "max-failed-attempts",
+            Self::MaxProxiedIAmsPerSecond => "max-proxied-i-ams-per-second",
+            Self::MaxInfoFrames => "max-info-frames",
+            Self::MaxManager => "max-manager",
+            Self::MaxNpduLengthAccepted => "max-npdu-length-accepted",
+            Self::MaxPresValue => "max-pres-value",
+            Self::MaxSegmentsAccepted => "max-segments-accepted",
+            Self::MaximumOutput => "maximum-output",
+            Self::MaximumSendDelay => "maximum-send-delay",
+            Self::MaximumValue => "maximum-value",
+            Self::MaximumValueTimestamp => "maximum-value-timestamp",
+            Self::MemberOf => "member-of",
+            Self::MemberStatusFlags => "member-status-flags",
+            Self::Members => "members",
+            Self::MinActualValue => "min-actual-value",
+            Self::MinPresValue => "min-pres-value",
+            Self::MinimumOffTime => "minimum-off-time",
+            Self::MinimumOnTime => "minimum-on-time",
+            Self::MinimumOutput => "minimum-output",
+            Self::MinimumValue => "minimum-value",
+            Self::MinimumValueTimestamp => "minimum-value-timestamp",
+            Self::Mode => "mode",
+            Self::ModelName => "model-name",
+            Self::ModificationDate => "modification-date",
+            Self::MonitoredObjects => "monitored-objects",
+            Self::MusterPoint => "muster-point",
+            Self::NegativeAccessRules => "negative-access-rules",
+            Self::NetworkInterfaceName => "network-interface-name",
+            Self::NetworkNumber => "network-number",
+            Self::NetworkNumberQuality => "network-number-quality",
+            Self::NetworkType => "network-type",
+            Self::NextStoppingFloor => "next-stopping-floor",
+            Self::NodeSubtype => "node-subtype",
+            Self::NodeType => "node-type",
+            Self::NotificationClass => "notification-class",
+            Self::NotificationThreshold => "notification-threshold",
+            Self::NotifyType => "notify-type",
+            Self::NumberOfApduRetries => "number-of-apdu-retries",
+            Self::NumberOfAuthenticationPolicies => "number-of-authentication-policies",
+            Self::NumberOfStates => "number-of-states",
+            Self::ObjectIdentifier => "object-identifier",
+            Self::ObjectList => "object-list",
+            Self::ObjectName => "object-name",
+            Self::ObjectPropertyReference => "object-property-reference",
+            Self::ObjectType => "object-type",
+            Self::OccupancyCount => "occupancy-count",
+            Self::OccupancyCountAdjust => "occupancy-count-adjust",
+            Self::OccupancyCountEnable => "occupancy-count-enable",
+            Self::OccupancyLowerLimit => "occupancy-lower-limit",
+            Self::OccupancyLowerLimitEnforced => "occupancy-lower-limit-enforced",
+            Self::OccupancyState => "occupancy-state",
+            Self::OccupancyUpperLimit => "occupancy-upper-limit",
+            Self::OccupancyUpperLimitEnforced => "occupancy-upper-limit-enforced",
+            Self::OperationDirection => "operation-direction",
+            Self::OperationExpected => "operation-expected",
+            Self::OperationalCertificateFile => "operational-certificate-file",
+            Self::Optional => "optional",
+            Self::OutOfService => "out-of-service",
+            Self::OutputUnits => "output-units",
+            Self::OverrideColorReference => "override-color-reference",
+            Self::PassbackMode => "passback-mode",
+            Self::PassbackTimeout => "passback-timeout",
+            Self::PassengerAlarm => "passenger-alarm",
+            Self::Polarity => "polarity",
+            Self::PortFilter => "port-filter",
+            Self::PositiveAccessRules => "positive-access-rules",
+            Self::Power => "power",
+            Self::PowerMode => "power-mode",
+            Self::Prescale => "prescale",
+            Self::PresentStage => "present-stage",
+            Self::PresentValue => "present-value",
+            Self::Priority => "priority",
+            Self::PriorityArray => "priority-array",
+            Self::PriorityForWriting => "priority-for-writing",
+            Self::ProcessIdentifier => "process-identifier",
+            Self::ProcessIdentifierFilter => "process-identifier-filter",
+            Self::ProfileLocation => "profile-location",
+            Self::ProfileName => "profile-name",
+            Self::ProgramChange => "program-change",
+            Self::ProgramLocation => "program-location",
+            Self::ProgramState => "program-state",
+            Self::PropertyList => "property-list",
+            Self::ProportionalConstant => "proportional-constant",
+            Self::ProportionalConstantUnits => "proportional-constant-units",
+            Self::ProtocolLevel => "protocol-level",
+            Self::ProtocolObjectTypesSupported => "protocol-object-types-supported",
+            Self::ProtocolRevision => "protocol-revision",
+            Self::ProtocolServicesSupported => "protocol-services-supported",
+            Self::ProtocolVersion => "protocol-version",
+            Self::PulseRate => "pulse-rate",
+            Self::ReadOnly => "read-only",
+            Self::ReasonForDisable => "reason-for-disable",
+            Self::ReasonForHalt => "reason-for-halt",
+            Self::RecipientList => "recipient-list",
+            Self::RecordCount => "record-count",
+            Self::RecordsSinceNotification => "records-since-notification",
+            Self::ReferencePort => "reference-port",
+            Self::RegisteredCarCall => "registered-car-call",
+            Self::Reliability => "reliability",
+            Self::ReliabilityEvaluationInhibit => "reliability-evaluation-inhibit",
+            Self::RelinquishDefault => "relinquish-default",
+            Self::Represents => "represents",
+            Self::RequestedShedLevel => "requested-shed-level",
+            Self::RequestedUpdateInterval => "requested-update-interval",
+            Self::Required => "required",
+            Self::Resolution => "resolution",
+            Self::RestartNotificationRecipients => "restart-notification-recipients",
+            Self::RestoreCompletionTime => "restore-completion-time",
+            Self::RestorePreparationTime => "restore-preparation-time",
+            Self::RoutingTable => "routing-table",
+            Self::ScConnectWaitTimeout => "sc-connect-wait-timeout",
+            Self::ScDirectConnectAcceptEnable => "sc-direct-connect-accept-enable",
+            Self::ScDirectConnectAcceptUris => "sc-direct-connect-accept-uris",
+            Self::ScDirectConnectBinding => "sc-direct-connect-binding",
+            Self::ScDirectConnectConnectionStatus => "sc-direct-connect-connection-status",
+            Self::ScDirectConnectInitiateEnable => "sc-direct-connect-initiate-enable",
+            Self::ScDisconnectWaitTimeout => "sc-disconnect-wait-timeout",
+            Self::ScFailedConnectionRequests => "sc-failed-connection-requests",
+            Self::ScFailoverHubConnectionStatus => "sc-failover-hub-connection-status",
+            Self::ScFailoverHubUri => "sc-failover-hub-uri",
+            Self::ScHubConnectorState => "sc-hub-connector-state",
+            Self::ScHubFunctionAcceptUris => "sc-hub-function-accept-uris",
+            Self::ScHubFunctionBinding => "sc-hub-function-binding",
+            Self::ScHubFunctionConnectionStatus => "sc-hub-function-connection-status",
+            Self::ScHubFunctionEnable => "sc-hub-function-enable",
+            Self::ScHeartbeatTimeout => "sc-heartbeat-timeout",
+            Self::ScPrimaryHubConnectionStatus => "sc-primary-hub-connection-status",
+            Self::ScPrimaryHubUri => "sc-primary-hub-uri",
+            Self::ScMaximumReconnectTime => "sc-maximum-reconnect-time",
+            Self::ScMinimumReconnectTime => "sc-minimum-reconnect-time",
+            Self::Scale => "scale",
+            Self::ScaleFactor => "scale-factor",
+            Self::ScheduleDefault => "schedule-default",
+            Self::SecuredStatus => "secured-status",
+            Self::SegmentationSupported => "segmentation-supported",
+            Self::SendNow => "send-now",
+            Self::SerialNumber => "serial-number",
+            Self::Setpoint => "setpoint",
+            Self::SetpointReference => "setpoint-reference",
+            Self::Setting => "setting",
+            Self::ShedDuration => "shed-duration",
+            Self::ShedLevelDescriptions => "shed-level-descriptions",
+            Self::ShedLevels => "shed-levels",
+            Self::Silenced => "silenced",
+            Self::Stages => "stages",
+            Self::StageNames => "stage-names",
+            Self::StartTime => "start-time",
+            Self::StateChangeValues => "state-change-values",
+            Self::StateDescription => "state-description",
+            Self::StateText => "state-text",
+            Self::StatusFlags => "status-flags",
+            Self::StopTime => "stop-time",
+            Self::StopWhenFull => "stop-when-full",
+            Self::StrikeCount => "strike-count",
+            Self::StructuredObjectList => "structured-object-list",
+            Self::SubordinateAddressBinding => "subordinate-address-binding",
+            Self::SubordinateAnnotations => "subordinate-annotations",
+            Self::SubordinateList => "subordinate-list",
+            Self::SubordinateNodeTypes => "subordinate-node-types",
+            Self::SubordinateProxyEnable => "subordinate-proxy-enable",
+            Self::SubordinateRelationships => "subordinate-relationships",
+            Self::SubordinateTags => "subordinate-tags",
+            Self::SubscribedRecipients => "subscribed-recipients",
+            Self::SupportedFormatClasses => "supported-format-classes",
+            Self::SupportedFormats => "supported-formats",
+            Self::SystemStatus => "system-status",
+            Self::Tags => "tags",
+            Self::TargetReferences => "target-references",
+            Self::ThreatAuthority => "threat-authority",
+            Self::ThreatLevel => "threat-level",
+            Self::TimeDelay => "time-delay",
+            Self::TimeDelayNormal => "time-delay-normal",
+            Self::TimeOfActiveTimeReset => "time-of-active-time-reset",
+            Self::TimeOfDeviceRestart => "time-of-device-restart",
+            Self::TimeOfStateCountReset => "time-of-state-count-reset",
+            Self::TimeOfStrikeCountReset => "time-of-strike-count-reset",
+            Self::TimeSynchronizationInterval => "time-synchronization-interval",
+            Self::TimeSynchronizationRecipients => "time-synchronization-recipients",
+            Self::TimerRunning => "timer-running",
+            Self::TimerState => "timer-state",
+            Self::TotalRecordCount => "total-record-count",
+            Self::TraceFlag => "trace-flag",
+            Self::TrackingValue => "tracking-value",
+            Self::TransactionNotificationClass => "transaction-notification-class",
+            Self::Transition => "transition",
+            Self::Trigger => "trigger",
+            Self::TrimFadeTime => "trim-fade-time",
+            Self::Units => "units",
+            Self::UpdateInterval => "update-interval",
+            Self::UpdateTime => "update-time",
+            Self::UserExternalIdentifier => "user-external-identifier",
+            Self::UserInformationReference => "user-information-reference",
+            Self::UserName => "user-name",
+            Self::UserType => "user-type",
+            Self::UsesRemaining => "uses-remaining",
+            Self::UtcOffset => "utc-offset",
+            Self::UtcTimeSynchronizationRecipients => "utc-time-synchronization-recipients",
+            Self::ValidSamples => "valid-samples",
+            Self::ValueBeforeChange => "value-before-change",
+            Self::ValueChangeTime => "value-change-time",
+            Self::ValueSet => "value-set",
+            Self::ValueSource => "value-source",
+            Self::ValueSourceArray => "value-source-array",
+            Self::VarianceValue => "variance-value",
+            Self::VendorIdentifier => "vendor-identifier",
+            Self::VendorName => "vendor-name",
+            Self::VerificationTime => "verification-time",
+            Self::VirtualMacAddressTable => "virtual-mac-address-table",
+            Self::VtClassesSupported => "vt-classes-supported",
+            Self::WeeklySchedule => "weekly-schedule",
+            Self::WindowInterval => "window-interval",
+            Self::WindowSamples => "window-samples",
+            Self::WriteEveryScheduledAction => "write-every-scheduled-action",
+            Self::WriteStatus => "write-status",
+            Self::ZoneFrom => "zone-from",
+            Self::ZoneMembers => "zone-members",
+            Self::ZoneTo => "zone-to",
+            Self::Custom(_) => "proprietary",
+            Self::Reserved(_) => "reserved",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_property_identifier_names() {
+        assert_eq!(
+            PropertyIdentifier::from(85u32),
+            PropertyIdentifier::PresentValue
+        );
+        assert_eq!(PropertyIdentifier::PresentValue.name(), "present-value");
+        assert_eq!(
+            PropertyIdentifier::from(77u32),
+            PropertyIdentifier::ObjectName
+        );
+        assert_eq!(PropertyIdentifier::ObjectName.name(), "object-name");
+        assert_eq!(
+            PropertyIdentifier::Ipv6DhcpServer.name(),
+            "ipv6-dhcp-server"
+        );
+
+        let proprietary = PropertyIdentifier::from(600u32);
+        assert!(matches!(proprietary, PropertyIdentifier::Custom(value) if value.value() == 600));
+        assert_eq!(proprietary.name(), "proprietary");
+        assert_eq!(u32::from(proprietary), 600);
+        assert_eq!(PropertyIdentifier::from(466u32).name(), "reserved");
+    }
+}
@@ -0,0 +1,443 @@
+//! Required and optional properties per object type
+//!
+//! Lists the properties ASHRAE 135 clause 12 defines for each object type
+//! implemented by this crate, so that an object instance can be checked for
+//! conformance with [`BacnetObject::validate_required_properties`].
+//!
+//! [`BacnetObject::validate_required_properties`]: crate::object::BacnetObject::validate_required_properties
+
+use crate::object::{ObjectType, PropertyIdentifier as P};
+
+/// Properties defined for one object type
+#[derive(Debug)]
+pub struct ObjectPropertyTable {
+    /// The object type the lists apply to
+    pub object_type: ObjectType,
+    /// Properties every instance must have
+    pub required: &'static [P],
+    /// Properties an instance may have
+    pub optional: &'static [P],
+}
+
+impl ObjectPropertyTable {
+    /// Get the table for an object type, if the crate knows it
+    pub fn for_object_type(object_type: ObjectType) -> Option<&'static Self> {
+        TABLES.iter().find(|table| table.object_type == object_type)
+    }
+
+    /// Check if a property is required for the object type
+    pub fn is_required(&self, property: P) -> bool {
+        self.required.contains(&property)
+    }
+
+    /// Check if a property is defined, required or optional, for the object
+    /// type
+    pub fn is_defined(&self, property: P) -> bool {
+        self.is_required(property) || self.optional.contains(&property)
+    }
+}
+
+/// Optional properties shared by the intrinsic reporting objects
+macro_rules! reporting_properties {
+    ($($property:ident),*) => {
+        &[
+            $(P::$property,)*
+            P::Description,
+            P::Reliability,
+            P::TimeDelay,
+            P::NotificationClass,
+            P::EventEnable,
+            P::AckedTransitions,
+            P::NotifyType,
+            P::EventTimeStamps,
+            P::EventMessageTexts,
+            P::EventDetectionEnable,
+            P::ReliabilityEvaluationInhibit,
+        ]
+    };
+}
+
+static TABLES: &[ObjectPropertyTable] = &[
+    ObjectPropertyTable {
+        object_type: ObjectType::AnalogInput,
+        required: &[
+            P::ObjectIdentifier,
+            P::ObjectName,
+            P::ObjectType,
+            P::PresentValue,
+            P::StatusFlags,
+            P::EventState,
+            P::OutOfService,
+            P::Units,
+            P::PropertyList,
+        ],
+        optional: reporting_properties!(
+            DeviceType,
+            UpdateInterval,
+            MinPresValue,
+            MaxPresValue,
+            Resolution,
+            CovIncrement,
+            HighLimit,
+            LowLimit,
+            Deadband,
+            LimitEnable
+        ),
+    },
+    ObjectPropertyTable {
+        object_type: ObjectType::AnalogOutput,
+        required: &[
+            P::ObjectIdentifier,
+            P::ObjectName,
+            P::ObjectType,
+            P::PresentValue,
+            P::StatusFlags,
+            P::EventState,
+            P::OutOfService,
+            P::Units,
+            P::PriorityArray,
+            P::RelinquishDefault,
+            P::CurrentCommandPriority,
+            P::PropertyList,
+        ],
+        optional: reporting_properties!(
+            DeviceType,
+            MinPresValue,
+            MaxPresValue,
+            Resolution,
+            CovIncrement,
+            HighLimit,
+            LowLimit,
+            Deadband,
+            LimitEnable
+        ),
+    },
+    ObjectPropertyTable {
+        object_type: ObjectType::AnalogValue,
+        required: &[
+            P::ObjectIdentifier,
+            P::ObjectName,
+            P::ObjectType,
+            P::PresentValue,
+            P::StatusFlags,
+            P::EventState,
+            P::OutOfService,
+            P::Units,
+            P::PropertyList,
+        ],
+        optional: reporting_properties!(
+            PriorityArray,
+            RelinquishDefault,
+            CurrentCommandPriority,
+            MinPresValue,
+            MaxPresValue,
+            Resolution,
+            CovIncrement,
+            HighLimit,
+            LowLimit,
+            Deadband,
+            LimitEnable
+        ),
+    },
+    ObjectPropertyTable {
+        object_type: ObjectType::BinaryInput,
+        required: &[
+            P::ObjectIdentifier,
+            P::ObjectName,
+            P::ObjectType,
+            P::PresentValue,
+            P::StatusFlags,
+            P::EventState,
+            P::OutOfService,
+            P::Polarity,
+            P::PropertyList,
+        ],
+        optional: reporting_properties!(
+            DeviceType,
+            InactiveText,
+            ActiveText,
+            ChangeOfStateTime,
+            ChangeOfStateCount,
+            ElapsedActiveTime,
+            AlarmValue
+        ),
+    },
+    ObjectPropertyTable {
+        object_type: ObjectType::BinaryOutput,
+        required: &[
+            P::ObjectIdentifier,
+            P::ObjectName,
+            P::ObjectType,
+            P::PresentValue,
+            P::StatusFlags,
+            P::EventState,
+            P::OutOfService,
+            P::Polarity,
+            P::PriorityArray,
+            P::RelinquishDefault,
+            P::CurrentCommandPriority,
+            P::PropertyList,
+        ],
+        optional: reporting_properties!(
+            DeviceType,
+            InactiveText,
+            ActiveText,
+            ChangeOfStateTime,
+            ChangeOfStateCount,
+            ElapsedActiveTime,
+            MinimumOffTime,
+            MinimumOnTime,
+            FeedbackValue
+        ),
+    },
+    ObjectPropertyTable {
+        object_type: ObjectType::BinaryValue,
+        required: &[
+            P::ObjectIdentifier,
+            P::ObjectName,
+            P::ObjectType,
+            P::PresentValue,
+            P::StatusFlags,
+            P::EventState,
+            P::OutOfService,
+            P::PropertyList,
+        ],
+        optional: reporting_properties!(
+            InactiveText,
+            ActiveText,
+            ChangeOfStateTime,
+            ChangeOfStateCount,
+            ElapsedActiveTime,
+            MinimumOffTime,
+            MinimumOnTime,
+            PriorityArray,
+            RelinquishDefault,
+            CurrentCommandPriority,
+            AlarmValue
+        ),
+    },
+    ObjectPropertyTable {
+        object_type: ObjectType::MultiStateInput,
+        required: &[
+            P::ObjectIdentifier,
+            P::ObjectName,
+            P::ObjectType,
+            P::PresentValue,
+            P::StatusFlags,
+            P::EventState,
+            P::OutOfService,
+            P::NumberOfStates,
+            P::PropertyList,
+        ],
+        optional: reporting_properties!(DeviceType, StateText, AlarmValues, FaultValues),
+    },
+    ObjectPropertyTable {
+        object_type: ObjectType::MultiStateOutput,
+        required: &[
+            P::ObjectIdentifier,
+            P::ObjectName,
+            P::ObjectType,
+            P::PresentValue,
+            P::StatusFlags,
+            P::EventState,
+            P::OutOfService,
+            P::NumberOfStates,
+            P::PriorityArray,
+            P::RelinquishDefault,
+            P::CurrentCommandPriority,
+            P::PropertyList,
+        ],
+        optional: reporting_properties!(DeviceType, StateText, FeedbackValue),
+    },
+    ObjectPropertyTable {
+        object_type: ObjectType::MultiStateValue,
+        required: &[
+            P::ObjectIdentifier,
+            P::ObjectName,
+            P::ObjectType,
+            P::PresentValue,
+            P::StatusFlags,
+            P::EventState,
+            P::OutOfService,
+            P::NumberOfStates,
+            P::PropertyList,
+        ],
+        optional: reporting_properties!(
+            StateText,
+            PriorityArray,
+            RelinquishDefault,
+            CurrentCommandPriority,
+            AlarmValues,
+            FaultValues
+        ),
+    },
+    ObjectPropertyTable {
+        object_type: ObjectType::File,
+        required: &[
+            P::ObjectIdentifier,
+            P::ObjectName,
+            P::ObjectType,
+            P::FileType,
+            P::FileSize,
+            P::ModificationDate,
+            P::Archive,
+            P::ReadOnly,
+            P::FileAccessMethod,
+            P::PropertyList,
+        ],
+        optional: &[P::Description, P::RecordCount],
+    },
+    ObjectPropertyTable {
+        object_type: ObjectType::Device,
+        required: &[
+            P::ObjectIdentifier,
+            P::ObjectName,
+            P::ObjectType,
+            P::SystemStatus,
+            P::VendorName,
+            P::VendorIdentifier,
+            P::ModelName,
+            P::FirmwareRevision,
+            P::ApplicationSoftwareVersion,
+            P::ProtocolVersion,
+            P::ProtocolRevision,
+            P::ProtocolServicesSupported,
+            P::ProtocolObjectTypesSupported,
+            P::ObjectList,
+            P::MaxApduLengthAccepted,
+            P::SegmentationSupported,
+            P::ApduTimeout,
+            P::NumberOfApduRetries,
+            P::DeviceAddressBinding,
+            P::DatabaseRevision,
+            P::PropertyList,
+        ],
+        optional: &[
+            P::Location,
+            P::Description,
+            P::MaxSegmentsAccepted,
+            P::ApduSegmentTimeout,
+            P::LocalTime,
+            P::LocalDate,
+            P::UtcOffset,
+            P::DaylightSavingsStatus,
+            P::ActiveCovSubscriptions,
+            P::LastRestartReason,
+            P::TimeOfDeviceRestart,
+        ],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::{
+        AnalogInput, AnalogOutput, AnalogValue, BacnetObject, BinaryInput, BinaryOutput,
+        BinaryValue, Device, File, MultiStateInput, MultiStateOutput, MultiStateValue, ObjectError,
+        ObjectIdentifier, PropertyValue,
+    };
+
+    /// Object that only has the properties it was created with
+    struct PartialObject {
+        identifier: ObjectIdentifier,
+        properties: Vec<P>,
+    }
+
+    impl BacnetObject for PartialObject {
+        fn identifier(&self) -> ObjectIdentifier {
+            self.identifier
+        }
+
+        fn get_property(&self, property: P) -> crate::object::Result<PropertyValue> {
+            if self.properties.contains(&property) {
+                Ok(PropertyValue::Null)
+            } else {
+                Err(ObjectError::UnknownProperty)
+            }
+        }
+
+        fn set_property(&mut self, _: P, _: PropertyValue) -> crate::object::Result<()> {
+            Err(ObjectError::PropertyNotWritable)
+        }
+
+        fn is_property_writable(&self, _: P) -> bool {
+            false
+        }
+
+        fn property_list(&self) -> Vec<P> {
+            self.properties.clone()
+        }
+    }
+
+    #[test]
+    fn test_analog_input_required_properties() {
+        let table = ObjectPropertyTable::for_object_type(ObjectType::AnalogInput).unwrap();
+        for property in [
+            P::PresentValue,
+            P::StatusFlags,
+            P::EventState,
+            P::OutOfService,
+            P::Units,
+        ] {
+            assert!(table.is_required(property), "{property} not required");
+        }
+        assert!(!table.is_required(P::Description));
+        assert!(table.is_defined(P::Description));
+        assert!(!table.is_defined(P::PriorityArray));
+
+        let ai = AnalogInput::new(1, "Zone Temp".to_string());
+        assert_eq!(ai.validate_required_properties(), Ok(()));
+
+        let partial = PartialObject {
+            identifier: ObjectIdentifier::new(ObjectType::AnalogInput, 2),
+            properties: vec![
+                P::ObjectIdentifier,
+                P::ObjectName,
+                P::ObjectType,
+                P::PresentValue,
+                P::OutOfService,
+            ],
+        };
+        assert_eq!(
+            partial.validate_required_properties(),
+            Err(vec![P::StatusFlags, P::EventState, P::Units])
+        );
+    }
+
+    #[test]
+    fn test_objects_have_required_properties() {
+        let objects: Vec<Box<dyn BacnetObject>> = vec![
+            Box::new(AnalogInput::new(1, "AI".to_string())),
+            Box::new(AnalogOutput::new(1, "AO".to_string())),
+            Box::new(AnalogValue::new(1, "AV".to_string())),
+            Box::new(BinaryInput::new(1, "BI".to_string())),
+            Box::new(BinaryOutput::new(1, "BO".to_string())),
+            Box::new(BinaryValue::new(1, "BV".to_string())),
+            Box::new(MultiStateInput::new(1, "MSI".to_string(), 3)),
+            Box::new(MultiStateOutput::new(1, "MSO".to_string(), 3)),
+            Box::new(MultiStateValue::new(1, "MSV".to_string(), 3)),
+            Box::new(File::new(1, "File".to_string(), "text/plain".to_string())),
+            Box::new(Device::new(1, "Device".to_string())),
+        ];
+
+        for object in &objects {
+            assert_eq!(
+                object.validate_required_properties(),
+                Ok(()),
+                "{:?}",
+                object.identifier()
+            );
+        }
+    }
+
+    #[test]
+    fn test_unknown_object_type() {
+        assert!(ObjectPropertyTable::for_object_type(ObjectType::Calendar).is_none());
+
+        let object = PartialObject {
+            identifier: ObjectIdentifier::new(ObjectType::Calendar, 1),
+            properties: Vec::new(),
+        };
+        assert_eq!(object.validate_required_properties(), Ok(()));
+    }
+}
@@ -0,0 +1,196 @@
+//! BACnetStatusFlags
+//!
+//! The four general health indicators of an object (ASHRAE 135 clause 12),
+//! carried on the wire as a 4-bit bit string. They are reported by
+//! Status_Flags reads and by every COV and event notification.
+
+use core::fmt;
+
+use crate::encoding::{
+    advanced::bitstring::{
+        decode_bit_string, decode_context_bit_string, encode_bit_string, encode_context_bit_string,
+    },
+    EncodingError, Result as EncodingResult,
+};
+use crate::property::PropertyValue;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Status flags of an object
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct StatusFlags {
+    /// The object's Event_State is not NORMAL
+    pub in_alarm: bool,
+    /// The object's Reliability is not NO_FAULT_DETECTED
+    pub fault: bool,
+    /// The present value is overridden by a local mechanism
+    pub overridden: bool,
+    /// The object's Out_Of_Service is TRUE
+    pub out_of_service: bool,
+}
+
+impl StatusFlags {
+    /// Create status flags with every flag cleared
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the flags as a bit string, bit 0 (in-alarm) first
+    pub fn to_bits(self) -> Vec<bool> {
+        vec![
+            self.in_alarm,
+            self.fault,
+            self.overridden,
+            self.out_of_service,
+        ]
+    }
+
+    /// Create status flags from a bit string, bit 0 (in-alarm) first
+    ///
+    /// Missing bits are taken as cleared and bits beyond the fourth are
+    /// ignored.
+    pub fn from_bits(bits: &[bool]) -> Self {
+        let bit = |index: usize| bits.get(index).copied().unwrap_or(false);
+        Self {
+            in_alarm: bit(0),
+            fault: bit(1),
+            overridden: bit(2),
+            out_of_service: bit(3),
+        }
+    }
+
+    /// Create status flags from the packed form the object types store, with
+    /// in-alarm in bit 3 down to out-of-service in bit 0
+    pub fn from_packed(bits: u8) -> Self {
+        Self {
+            in_alarm: bits & 0x08 != 0,
+            fault: bits & 0x04 != 0,
+            overridden: bits & 0x02 != 0,
+            out_of_service: bits & 0x01 != 0,
+        }
+    }
+
+    /// Check if no flag is set
+    pub fn is_normal(self) -> bool {
+        self == Self::default()
+    }
+
+    /// Encode as an application-tagged bit string
+    pub fn encode(self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        encode_bit_string(buffer, &self.to_bits())
+    }
+
+    /// Decode an application-tagged bit string, returning the flags with the
+    /// bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        let (bits, consumed) = decode_bit_string(data)?;
+        Ok((Self::from_bits(&bits), consumed))
+    }
+
+    /// Encode as a context-tagged bit string
+    pub fn encode_context(self, tag_number: u8) -> EncodingResult<Vec<u8>> {
+        encode_context_bit_string(&self.to_bits(), tag_number)
+    }
+
+    /// Decode a context-tagged bit string, returning the flags with the bytes
+    /// consumed
+    pub fn decode_context(data: &[u8], tag_number: u8) -> EncodingResult<(Self, usize)> {
+        let (bits, consumed) = decode_context_bit_string(data, tag_number)?;
+        Ok((Self::from_bits(&bits), consumed))
+    }
+}
+
+impl From<StatusFlags> for PropertyValue {
+    fn from(flags: StatusFlags) -> Self {
+        PropertyValue::BitString(flags.to_bits())
+    }
+}
+
+impl From<StatusFlags> for crate::object::PropertyValue {
+    fn from(flags: StatusFlags) -> Self {
+        crate::object::PropertyValue::BitString(flags.to_bits())
+    }
+}
+
+impl TryFrom<&PropertyValue> for StatusFlags {
+    type Error = EncodingError;
+
+    /// Convert the bit string returned by a Status_Flags read
+    fn try_from(value: &PropertyValue) -> Result<Self, Self::Error> {
+        match value {
+            PropertyValue::BitString(bits) => Ok(Self::from_bits(bits)),
+            _ => Err(EncodingError::InvalidTag),
+        }
+    }
+}
+
+impl fmt::Display for StatusFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let flags = [
+            (self.in_alarm, "in-alarm"),
+            (self.fault, "fault"),
+            (self.overridden, "overridden"),
+            (self.out_of_service, "out-of-service"),
+        ];
+
+        let mut first = true;
+        for (_, name) in flags.iter().filter(|(set, _)| *set) {
+            if !first {
+                write!(f, ", ")?;
+            }
+            write!(f, "{name}")?;
+            first = false;
+        }
+
+        if first {
+            write!(f, "normal")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_flags_round_trip() {
+        let flags = StatusFlags {
+            in_alarm: true,
+            fault: true,
+            ..Default::default()
+        };
+        assert_eq!(flags.to_bits(), vec![true, true, false, false]);
+
+        let mut buffer = Vec::new();
+        flags.encode(&mut buffer).unwrap();
+        // Bit string, 4 unused bits, 1100
+        assert_eq!(buffer, [0x82, 0x04, 0xC0]);
+        assert_eq!(StatusFlags::decode(&buffer).unwrap(), (flags, 3));
+
+        let encoded = flags.encode_context(1).unwrap();
+        assert_eq!(encoded, [0x1A, 0x04, 0xC0]);
+        assert_eq!(
+            StatusFlags::decode_context(&encoded, 1).unwrap(),
+            (flags, 3)
+        );
+
+        assert_eq!(
+            StatusFlags::try_from(&PropertyValue::from(flags)).unwrap(),
+            flags
+        );
+        assert_eq!(flags.to_string(), "in-alarm, fault");
+        assert_eq!(StatusFlags::new().to_string(), "normal");
+        assert!(!flags.is_normal());
+        assert_eq!(StatusFlags::from_packed(0x0C), flags);
+    }
+
+    #[test]
+    fn test_status_flags_from_short_bit_string() {
+        let flags = StatusFlags::from_bits(&[false, true]);
+        assert!(flags.fault);
+        assert!(!flags.out_of_service);
+        assert!(StatusFlags::try_from(&PropertyValue::Boolean(true)).is_err());
+    }
+}
@@ -16,12 +16,12 @@ use crate::{
         advanced::bitstring::{decode_bit_string, encode_bit_string},
         decode_application_tag, decode_boolean, decode_character_string, decode_date,
         decode_double, decode_enumerated, decode_object_identifier, decode_octet_string,
-        decode_real, decode_signed64, decode_time, decode_unsigned64, encode_application_tag,
-        encode_boolean, encode_character_string, encode_date, encode_double, encode_enumerated,
-        encode_object_identifier, encode_octet_string, encode_real, encode_signed64, encode_time,
-        encode_unsigned64, EncodingError,
+        decode_real, decode_signed64, decode_tag, decode_time, decode_unsigned64, value_length,
+        write_application_tag, write_boolean, write_character_string, write_date, write_double,
+        write_enumerated, write_object_identifier, write_octet_string, write_real, write_signed64,
+        write_time, write_unsigned64, BACnetTag, EncodingError,
     },
-    object::{EngineeringUnits, ObjectIdentifier},
+    object::{EngineeringUnits, ObjectIdentifier, ObjectType, PropertyIdentifier},
     ApplicationTag,
 };
 
@@ -55,6 +55,10 @@ pub enum PropertyValue {
     ObjectIdentifier(ObjectIdentifier), // (object_type, instance)
     /// Null value
     Null,
+    /// Array of values (a BACnetARRAY property read in full)
+    Array(Vec<PropertyValue>),
+    /// List of values (a BACnetLIST property read in full)
+    List(Vec<PropertyValue>),
     /// Unknown/unsupported value type
     Unknown(Vec<u8>),
 }
@@ -87,6 +91,10 @@ impl PropertyValue {
                 format!("Object({}, {})", id.object_type, id.instance)
             }
             PropertyValue::Null => "Null".to_string(),
+            PropertyValue::Array(values) | PropertyValue::List(values) => {
+                let items: Vec<String> = values.iter().map(|v| v.as_display_string()).collect();
+                format!("[{}]", items.join(", "))
+            }
             PropertyValue::Unknown(_) => "Unknown".to_string(),
         }
     }
@@ -205,34 +213,194 @@ pub fn decode_property_value(data: &[u8]) -> Result<(PropertyValue, usize), Enco
     }
 }
 
+/// Encode a property value as application-tagged data
+///
+/// This is the inverse of [`decode_property_value`]. Arrays and lists are
+/// encoded element by element, recursing into nested collections. Object
+/// identifiers whose type or instance exceed their bit widths are rejected
+/// with [`EncodingError::ValueOutOfRange`].
 pub fn encode_property_value(
     value: &PropertyValue,
     buffer: &mut Vec<u8>,
 ) -> Result<(), EncodingError> {
     match value {
-        PropertyValue::Real(f) => encode_real(buffer, *f)?,
-        PropertyValue::Double(f) => encode_double(buffer, *f)?,
-        PropertyValue::Boolean(b) => encode_boolean(buffer, *b)?,
-        PropertyValue::Unsigned(u) => encode_unsigned64(buffer, *u),
-        PropertyValue::Signed(i) => encode_signed64(buffer, *i),
-        PropertyValue::OctetString(s) => encode_octet_string(buffer, s)?,
-        PropertyValue::CharacterString(s) => encode_character_string(buffer, s)?,
-        PropertyValue::Enumerated(e) => encode_enumerated(buffer, *e),
+        PropertyValue::Real(f) => write_real(buffer, *f)?,
+        PropertyValue::Double(f) => write_double(buffer, *f)?,
+        PropertyValue::Boolean(b) => write_boolean(buffer, *b)?,
+        PropertyValue::Unsigned(u) => write_unsigned64(buffer, *u)?,
+        PropertyValue::Signed(i) => write_signed64(buffer, *i)?,
+        PropertyValue::OctetString(s) => write_octet_string(buffer, s)?,
+        PropertyValue::CharacterString(s) => write_character_string(buffer, s)?,
+        PropertyValue::Enumerated(e) => write_enumerated(buffer, *e)?,
         PropertyValue::BitString(bits) => encode_bit_string(buffer, bits)?,
-        PropertyValue::Date(y, m, d, w) => encode_date(buffer, *y, *m, *d, *w)?,
-        PropertyValue::Time(h, m, s, hs) => encode_time(buffer, *h, *m, *s, *hs)?,
-        PropertyValue::ObjectIdentifier(id) => encode_object_identifier(buffer, *id)?,
-        PropertyValue::Null => encode_application_tag(buffer, ApplicationTag::Null, 0),
+        PropertyValue::Date(y, m, d, w) => write_date(buffer, *y, *m, *d, *w)?,
+        PropertyValue::Time(h, m, s, hs) => write_time(buffer, *h, *m, *s, *hs)?,
+        PropertyValue::ObjectIdentifier(id) => write_object_identifier(buffer, *id)?,
+        PropertyValue::Null => write_application_tag(buffer, ApplicationTag::Null, 0)?,
+        PropertyValue::Array(values) | PropertyValue::List(values) => {
+            for value in values {
+                encode_property_value(value, buffer)?;
+            }
+        }
         PropertyValue::Unknown(data) => buffer.extend_from_slice(data),
     }
 
     Ok(())
 }
 
+/// How the value of a known property is decoded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeStrategy {
+    /// Any application-tagged value; more than one value decodes as an array
+    Any,
+    /// A single value that must carry the given application tag
+    Tagged(ApplicationTag),
+    /// A BACnetARRAY read in full, one application-tagged value per element
+    Array,
+}
+
+/// Look up the decode strategy for a property
+///
+/// Some properties depend on the object type, e.g. present-value is a Real
+/// for analog objects but an Enumerated for binary objects. Properties that
+/// are not in the table use [`DecodeStrategy::Any`].
+pub fn decode_strategy(property_id: PropertyIdentifier, object_type: ObjectType) -> DecodeStrategy {
+    use PropertyIdentifier as P;
+
+    match property_id {
+        P::PresentValue | P::RelinquishDefault => present_value_strategy(object_type),
+        P::CovIncrement
+        | P::MinPresValue
+        | P::MaxPresValue
+        | P::Resolution
+        | P::Deadband
+        | P::HighLimit
+        | P::LowLimit => DecodeStrategy::Tagged(ApplicationTag::Real),
+        P::ObjectIdentifier => DecodeStrategy::Tagged(ApplicationTag::ObjectIdentifier),
+        P::ObjectName
+        | P::Description
+        | P::VendorName
+        | P::ModelName
+        | P::FirmwareRevision
+        | P::ApplicationSoftwareVersion
+        | P::Location
+        | P::ActiveText
+        | P::InactiveText
+        | P::DeviceType => DecodeStrategy::Tagged(ApplicationTag::CharacterString),
+        P::ObjectType
+        | P::Units
+        | P::EventState
+        | P::Reliability
+        | P::Polarity
+        | P::SystemStatus
+        | P::SegmentationSupported => DecodeStrategy::Tagged(ApplicationTag::Enumerated),
+        P::StatusFlags
+        | P::EventEnable
+        | P::AckedTransitions
+        | P::ProtocolServicesSupported
+        | P::ProtocolObjectTypesSupported => DecodeStrategy::Tagged(ApplicationTag::BitString),
+        P::OutOfService => DecodeStrategy::Tagged(ApplicationTag::Boolean),
+        P::VendorIdentifier
+        | P::MaxApduLengthAccepted
+        | P::NumberOfStates
+        | P::ProtocolVersion
+        | P::ProtocolRevision
+        | P::DatabaseRevision
+        | P::ApduTimeout
+        | P::NumberOfApduRetries => DecodeStrategy::Tagged(ApplicationTag::UnsignedInt),
+        P::LocalDate => DecodeStrategy::Tagged(ApplicationTag::Date),
+        P::LocalTime => DecodeStrategy::Tagged(ApplicationTag::Time),
+        P::ObjectList | P::StructuredObjectList | P::PriorityArray | P::StateText => {
+            DecodeStrategy::Array
+        }
+        _ => DecodeStrategy::Any,
+    }
+}
+
+fn present_value_strategy(object_type: ObjectType) -> DecodeStrategy {
+    match object_type {
+        ObjectType::AnalogInput | ObjectType::AnalogOutput | ObjectType::AnalogValue => {
+            DecodeStrategy::Tagged(ApplicationTag::Real)
+        }
+        ObjectType::BinaryInput
+        | ObjectType::BinaryOutput
+        | ObjectType::BinaryValue
+        | ObjectType::BinaryLightingOutput => DecodeStrategy::Tagged(ApplicationTag::Enumerated),
+        ObjectType::MultiStateInput
+        | ObjectType::MultiStateOutput
+        | ObjectType::MultiStateValue
+        | ObjectType::PositiveIntegerValue => DecodeStrategy::Tagged(ApplicationTag::UnsignedInt),
+        ObjectType::IntegerValue => DecodeStrategy::Tagged(ApplicationTag::SignedInt),
+        ObjectType::LargeAnalogValue => DecodeStrategy::Tagged(ApplicationTag::Double),
+        ObjectType::CharacterstringValue => DecodeStrategy::Tagged(ApplicationTag::CharacterString),
+        _ => DecodeStrategy::Any,
+    }
+}
+
+/// Decode the value of a known property using its [`DecodeStrategy`]
+///
+/// `raw` holds the complete encoded value, e.g. the contents of the
+/// property-value tag of a ReadProperty acknowledgement.
+pub fn decode_known(
+    property_id: PropertyIdentifier,
+    object_type: ObjectType,
+    raw: &[u8],
+) -> Result<PropertyValue, EncodingError> {
+    match decode_strategy(property_id, object_type) {
+        DecodeStrategy::Tagged(expected) => {
+            let (tag, _, _) = decode_application_tag(raw)?;
+            if tag != expected {
+                return Err(EncodingError::InvalidTag);
+            }
+
+            let (value, consumed) = decode_property_value(raw)?;
+            if consumed != raw.len() {
+                return Err(EncodingError::InvalidFormat(
+                    "Unexpected data after property value".to_string(),
+                ));
+            }
+
+            Ok(value)
+        }
+        DecodeStrategy::Array => Ok(PropertyValue::Array(decode_property_values(raw)?)),
+        DecodeStrategy::Any => {
+            let mut values = decode_property_values(raw)?;
+            match values.len() {
+                1 => Ok(values.remove(0)),
+                _ => Ok(PropertyValue::Array(values)),
+            }
+        }
+    }
+}
+
+/// Decode every element of a property value
+///
+/// Application-tagged elements are decoded; context-tagged elements (parts of
+/// a constructed value this decoder does not know) are kept as raw
+/// [`PropertyValue::Unknown`] bytes.
+pub(crate) fn decode_property_values(mut data: &[u8]) -> Result<Vec<PropertyValue>, EncodingError> {
+    let mut values = Vec::new();
+    while !data.is_empty() {
+        let consumed = match decode_tag(data)?.0 {
+            BACnetTag::Application(_) => {
+                let (value, consumed) = decode_property_value(data)?;
+                values.push(value);
+                consumed
+            }
+            BACnetTag::Context(_) => {
+                let length = value_length(data)?;
+                values.push(PropertyValue::Unknown(data[..length].to_vec()));
+                length
+            }
+        };
+        data = &data[consumed..];
+    }
+
+    Ok(values)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::object::ObjectType;
-
     use super::*;
 
     #[test]
@@ -351,4 +519,161 @@ mod tests {
         assert_eq!(consumed, 2);
         assert_eq!(units.bacnet_name(), "cubic-feet-per-minute");
     }
+
+    #[test]
+    fn test_decode_known_present_value() {
+        // Real 21.5 for an analog input
+        let value = decode_known(
+            PropertyIdentifier::PresentValue,
+            ObjectType::AnalogInput,
+            &[0x44, 0x41, 0xAC, 0x00, 0x00],
+        )
+        .unwrap();
+        assert_eq!(value, PropertyValue::Real(21.5));
+
+        // Enumerated active for a binary input
+        let value = decode_known(
+            PropertyIdentifier::PresentValue,
+            ObjectType::BinaryInput,
+            &[0x91, 0x01],
+        )
+        .unwrap();
+        assert_eq!(value, PropertyValue::Enumerated(1));
+
+        // A Real is not a valid binary present-value
+        assert!(decode_known(
+            PropertyIdentifier::PresentValue,
+            ObjectType::BinaryInput,
+            &[0x44, 0x41, 0xAC, 0x00, 0x00],
+        )
+        .is_err());
+
+        // Out-of-service is a Boolean regardless of object type
+        let value = decode_known(
+            PropertyIdentifier::OutOfService,
+            ObjectType::BinaryInput,
+            &[0x11],
+        )
+        .unwrap();
+        assert_eq!(value, PropertyValue::Boolean(true));
+    }
+
+    #[test]
+    fn test_decode_known_array() {
+        // Object list with a single entry still decodes as an array
+        let value = decode_known(
+            PropertyIdentifier::ObjectList,
+            ObjectType::Device,
+            &[0xC4, 0x02, 0x00, 0x00, 0x7B],
+        )
+        .unwrap();
+        assert_eq!(
+            value,
+            PropertyValue::Array(vec![PropertyValue::ObjectIdentifier(
+                ObjectIdentifier::new(ObjectType::Device, 123)
+            )])
+        );
+
+        // Priority array mixing relinquished and commanded slots
+        let value = decode_known(
+            PropertyIdentifier::PriorityArray,
+            ObjectType::AnalogOutput,
+            &[0x00, 0x44, 0x42, 0x28, 0x00, 0x00, 0x00],
+        )
+        .unwrap();
+        assert_eq!(
+            value,
+            PropertyValue::Array(vec![
+                PropertyValue::Null,
+                PropertyValue::Real(42.0),
+                PropertyValue::Null,
+            ])
+        );
+
+        let mut buffer = Vec::new();
+        encode_property_value(&value, &mut buffer).unwrap();
+        assert_eq!(buffer, [0x00, 0x44, 0x42, 0x28, 0x00, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn test_encode_property_value_round_trip() {
+        let values = [
+            PropertyValue::Null,
+            PropertyValue::Boolean(true),
+            PropertyValue::Boolean(false),
+            PropertyValue::Unsigned(0),
+            PropertyValue::Unsigned(255),
+            PropertyValue::Unsigned(0x1_0000),
+            PropertyValue::Unsigned(u32::MAX as u64),
+            PropertyValue::Unsigned(u64::MAX),
+            PropertyValue::Signed(-1),
+            PropertyValue::Signed(-200),
+            PropertyValue::Signed(0x7F_FFFF),
+            PropertyValue::Signed(i64::MIN),
+            PropertyValue::Real(-12.25),
+            PropertyValue::Double(1.0e100),
+            PropertyValue::OctetString(vec![]),
+            PropertyValue::OctetString(vec![0xDE, 0xAD, 0xBE, 0xEF]),
+            PropertyValue::CharacterString("Zone 4 supply fan".to_string()),
+            PropertyValue::BitString(vec![true, false, false, true, true]),
+            PropertyValue::Enumerated(95),
+            PropertyValue::Date(2024, 3, 15, 5),
+            PropertyValue::Time(23, 59, 30, 99),
+            PropertyValue::ObjectIdentifier(ObjectIdentifier::new(ObjectType::Device, 0x3FFFFF)),
+        ];
+
+        for value in values {
+            let mut buffer = Vec::new();
+            encode_property_value(&value, &mut buffer).unwrap();
+            let (decoded, consumed) = decode_property_value(&buffer).unwrap();
+            assert_eq!(consumed, buffer.len(), "{:?}", value);
+            assert_eq!(decoded, value);
+        }
+    }
+
+    #[test]
+    fn test_encode_property_value_nested() {
+        let value = PropertyValue::List(vec![
+            PropertyValue::Array(vec![PropertyValue::Unsigned(1), PropertyValue::Null]),
+            PropertyValue::Enumerated(2),
+        ]);
+
+        let mut buffer = Vec::new();
+        encode_property_value(&value, &mut buffer).unwrap();
+        assert_eq!(buffer, [0x21, 0x01, 0x00, 0x91, 0x02]);
+
+        // Nesting is not visible on the wire, so the elements come back flat
+        assert_eq!(
+            decode_known(
+                PropertyIdentifier::PriorityArray,
+                ObjectType::AnalogOutput,
+                &buffer
+            )
+            .unwrap(),
+            PropertyValue::Array(vec![
+                PropertyValue::Unsigned(1),
+                PropertyValue::Null,
+                PropertyValue::Enumerated(2),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_encode_property_value_object_identifier_out_of_range() {
+        let mut buffer = Vec::new();
+        let value =
+            PropertyValue::ObjectIdentifier(ObjectIdentifier::new(ObjectType::Device, 0x400000));
+        assert!(matches!(
+            encode_property_value(&value, &mut buffer),
+            Err(EncodingError::ValueOutOfRange)
+        ));
+
+        let value = PropertyValue::Array(vec![PropertyValue::ObjectIdentifier(
+            ObjectIdentifier::new(ObjectType::from(2000u32), 1),
+        )]);
+        assert!(matches!(
+            encode_property_value(&value, &mut buffer),
+            Err(EncodingError::ValueOutOfRange)
+        ));
+    }
 }
@@ -382,17 +382,257 @@ generate_custom_enum!(
     ApduTooLong = 11,
 }, u8, 64..=255);
 
+generate_custom_enum!(
+    /// Error classes of an Error PDU (ASHRAE 135 `BACnetErrorClass`).
+    ///
+    /// Classes 64-65535 are vendor-proprietary and surface as `Custom`.
+    ErrorClass{
+    Device = 0,
+    Object = 1,
+    Property = 2,
+    Resources = 3,
+    Security = 4,
+    Services = 5,
+    Vt = 6,
+    Communication = 7,
+}, u16, 64..=65535);
+
+generate_custom_enum!(
+    /// Error codes of an Error PDU (ASHRAE 135 `BACnetErrorCode`).
+    ///
+    /// Codes 256-65535 are vendor-proprietary and surface as `Custom`; codes
+    /// this crate does not name surface as `Reserved`.
+    ErrorCode{
+    Other = 0,
+    AuthenticationFailed = 1,
+    ConfigurationInProgress = 2,
+    DeviceBusy = 3,
+    DynamicCreationNotSupported = 4,
+    FileAccessDenied = 5,
+    IncompatibleSecurityLevels = 6,
+    InconsistentParameters = 7,
+    InconsistentSelectionCriterion = 8,
+    InvalidDataType = 9,
+    InvalidFileAccessMethod = 10,
+    InvalidFileStartPosition = 11,
+    InvalidOperatorName = 12,
+    InvalidParameterDataType = 13,
+    InvalidTimeStamp = 14,
+    KeyGenerationError = 15,
+    MissingRequiredParameter = 16,
+    NoObjectsOfSpecifiedType = 17,
+    NoSpaceForObject = 18,
+    NoSpaceToAddListElement = 19,
+    NoSpaceToWriteProperty = 20,
+    NoVtSessionsAvailable = 21,
+    PropertyIsNotAList = 22,
+    ObjectDeletionNotPermitted = 23,
+    ObjectIdentifierAlreadyExists = 24,
+    OperationalProblem = 25,
+    PasswordFailure = 26,
+    ReadAccessDenied = 27,
+    SecurityNotSupported = 28,
+    ServiceRequestDenied = 29,
+    Timeout = 30,
+    UnknownObject = 31,
+    UnknownProperty = 32,
+    UnknownVtClass = 34,
+    UnknownVtSession = 35,
+    UnsupportedObjectType = 36,
+    ValueOutOfRange = 37,
+    VtSessionAlreadyClosed = 38,
+    VtSessionTerminationFailure = 39,
+    WriteAccessDenied = 40,
+    CharacterSetNotSupported = 41,
+    InvalidArrayIndex = 42,
+    CovSubscriptionFailed = 43,
+    NotCovProperty = 44,
+    OptionalFunctionalityNotSupported = 45,
+    InvalidConfigurationData = 46,
+    DatatypeNotSupported = 47,
+    DuplicateName = 48,
+    DuplicateObjectId = 49,
+    PropertyIsNotAnArray = 50,
+    AbortBufferOverflow = 51,
+    AbortInvalidApduInThisState = 52,
+    AbortPreemptedByHigherPriorityTask = 53,
+    AbortSegmentationNotSupported = 54,
+    AbortProprietary = 55,
+    AbortOther = 56,
+    InvalidTag = 57,
+    NetworkDown = 58,
+    RejectBufferOverflow = 59,
+    RejectInconsistentParameters = 60,
+    RejectInvalidParameterDataType = 61,
+    RejectInvalidTag = 62,
+    RejectMissingRequiredParameter = 63,
+    RejectParameterOutOfRange = 64,
+    RejectTooManyArguments = 65,
+    RejectUndefinedEnumeration = 66,
+    RejectUnrecognizedService = 67,
+    RejectProprietary = 68,
+    RejectOther = 69,
+    UnknownDevice = 70,
+    UnknownRoute = 71,
+    ValueNotInitialized = 72,
+    InvalidEventState = 73,
+    NoAlarmConfigured = 74,
+    LogBufferFull = 75,
+    LoggedValuePurged = 76,
+    NoPropertySpecified = 77,
+    NotConfiguredForTriggeredLogging = 78,
+    UnknownSubscription = 79,
+    ParameterOutOfRange = 80,
+    ListElementNotFound = 81,
+    Busy = 82,
+    CommunicationDisabled = 83,
+}, u16, 256..=65535);
+
+impl ErrorClass {
+    /// Name of the error class as written in the standard, e.g. `property`
+    ///
+    /// Proprietary classes are named `proprietary` and unassigned ones
+    /// `reserved`; their numbers are available through `u16::from`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Device => "device",
+            Self::Object => "object",
+            Self::Property => "property",
+            Self::Resources => "resources",
+            Self::Security => "security",
+            Self::Services => "services",
+            Self::Vt => "vt",
+            Self::Communication => "communication",
+            Self::Custom(_) => "proprietary",
+            Self::Reserved(_) => "reserved",
+        }
+    }
+}
+
+impl ErrorCode {
+    /// Name of the error code as written in the standard, e.g. `unknown-object`
+    ///
+    /// Proprietary codes are named `proprietary` and codes this crate does
+    /// not name `reserved`; their numbers are available through `u16::from`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Other => "other",
+            Self::AuthenticationFailed => "authentication-failed",
+            Self::ConfigurationInProgress => "configuration-in-progress",
+            Self::DeviceBusy => "device-busy",
+            Self::DynamicCreationNotSupported => "dynamic-creation-not-supported",
+            Self::FileAccessDenied => "file-access-denied",
+            Self::IncompatibleSecurityLevels => "incompatible-security-levels",
+            Self::InconsistentParameters => "inconsistent-parameters",
+            Self::InconsistentSelectionCriterion => "inconsistent-selection-criterion",
+            Self::InvalidDataType => "invalid-data-type",
+            Self::InvalidFileAccessMethod => "invalid-file-access-method",
+            Self::InvalidFileStartPosition => "invalid-file-start-position",
+            Self::InvalidOperatorName => "invalid-operator-name",
+            Self::InvalidParameterDataType => "invalid-parameter-data-type",
+            Self::InvalidTimeStamp => "invalid-time-stamp",
+            Self::KeyGenerationError => "key-generation-error",
+            Self::MissingRequiredParameter => "missing-required-parameter",
+            Self::NoObjectsOfSpecifiedType => "no-objects-of-specified-type",
+            Self::NoSpaceForObject => "no-space-for-object",
+            Self::NoSpaceToAddListElement => "no-space-to-add-list-element",
+            Self::NoSpaceToWriteProperty => "no-space-to-write-property",
+            Self::NoVtSessionsAvailable => "no-vt-sessions-available",
+            Self::PropertyIsNotAList => "property-is-not-a-list",
+            Self::ObjectDeletionNotPermitted => "object-deletion-not-permitted",
+            Self::ObjectIdentifierAlreadyExists => "object-identifier-already-exists",
+            Self::OperationalProblem => "operational-problem",
+            Self::PasswordFailure => "password-failure",
+            Self::ReadAccessDenied => "read-access-denied",
+            Self::SecurityNotSupported => "security-not-supported",
+            Self::ServiceRequestDenied => "service-request-denied",
+            Self::Timeout => "timeout",
+            Self::UnknownObject => "unknown-object",
+            Self::UnknownProperty => "unknown-property",
+            Self::UnknownVtClass => "unknown-vt-class",
+            Self::UnknownVtSession => "unknown-vt-session",
+            Self::UnsupportedObjectType => "unsupported-object-type",
+            Self::ValueOutOfRange => "value-out-of-range",
+            Self::VtSessionAlreadyClosed => "vt-session-already-closed",
+            Self::VtSessionTerminationFailure => "vt-session-termination-failure",
+            Self::WriteAccessDenied => "write-access-denied",
+            Self::CharacterSetNotSupported => "character-set-not-supported",
+            Self::InvalidArrayIndex => "invalid-array-index",
+            Self::CovSubscriptionFailed => "cov-subscription-failed",
+            Self::NotCovProperty => "not-cov-property",
+            Self::OptionalFunctionalityNotSupported => "optional-functionality-not-supported",
+            Self::InvalidConfigurationData => "invalid-configuration-data",
+            Self::DatatypeNotSupported => "datatype-not-supported",
+            Self::DuplicateName => "duplicate-name",
+            Self::DuplicateObjectId => "duplicate-object-id",
+            Self::PropertyIsNotAnArray => "property-is-not-an-array",
+            Self::AbortBufferOverflow => "abort-buffer-overflow",
+            Self::AbortInvalidApduInThisState => "abort-invalid-apdu-in-this-state",
+            Self::AbortPreemptedByHigherPriorityTask => "abort-preempted-by-higher-priority-task",
+            Self::AbortSegmentationNotSupported => "abort-segmentation-not-supported",
+            Self::AbortProprietary => "abort-proprietary",
+            Self::AbortOther => "abort-other",
+            Self::InvalidTag => "invalid-tag",
+            Self::NetworkDown => "network-down",
+            Self::RejectBufferOverflow => "reject-buffer-overflow",
+            Self::RejectInconsistentParameters => "reject-inconsistent-parameters",
+            Self::RejectInvalidParameterDataType => "reject-invalid-parameter-data-type",
+            Self::RejectInvalidTag => "reject-invalid-tag",
+            Self::RejectMissingRequiredParameter => "reject-missing-required-parameter",
+            Self::RejectParameterOutOfRange => "reject-parameter-out-of-range",
+            Self::RejectTooManyArguments => "reject-too-many-arguments",
+            Self::RejectUndefinedEnumeration => "reject-undefined-enumeration",
+            Self::RejectUnrecognizedService => "reject-unrecognized-service",
+            Self::RejectProprietary => "reject-proprietary",
+            Self::RejectOther => "reject-other",
+            Self::UnknownDevice => "unknown-device",
+            Self::UnknownRoute => "unknown-route",
+            Self::ValueNotInitialized => "value-not-initialized",
+            Self::InvalidEventState => "invalid-event-state",
+            Self::NoAlarmConfigured => "no-alarm-configured",
+            Self::LogBufferFull => "log-buffer-full",
+            Self::LoggedValuePurged => "logged-value-purged",
+            Self::NoPropertySpecified => "no-property-specified",
+            Self::NotConfiguredForTriggeredLogging => "not-configured-for-triggered-logging",
+            Self::UnknownSubscription => "unknown-subscription",
+            Self::ParameterOutOfRange => "parameter-out-of-range",
+            Self::ListElementNotFound => "list-element-not-found",
+            Self::Busy => "busy",
+            Self::CommunicationDisabled => "communication-disabled",
+            Self::Custom(_) => "proprietary",
+            Self::Reserved(_) => "reserved",
+        }
+    }
+}
+
+/// Decode the raw error class and code of an Error PDU.
+///
+/// Never fails: values without a named variant map to `Custom` or `Reserved`.
+pub fn decode_error(error_class: u16, error_code: u16) -> (ErrorClass, ErrorCode) {
+    (ErrorClass::from(error_class), ErrorCode::from(error_code))
+}
+
 use crate::encoding::{
-    decode_context_enumerated, decode_context_object_id, decode_context_tag,
-    decode_context_unsigned, decode_enumerated, decode_object_identifier, decode_tag,
-    decode_unsigned, encode_context_enumerated, encode_context_object_id, encode_context_unsigned,
-    encode_enumerated, encode_object_identifier, encode_unsigned, BACnetTag,
-    Result as EncodingResult,
+    advanced::bitstring::{
+        decode_bit_string, decode_context_bit_string, encode_bit_string, encode_context_bit_string,
+    },
+    advanced::context::{encode_closing_tag, encode_opening_tag},
+    constructed_value_length, decode_boolean, decode_character_string, decode_context_boolean,
+    decode_context_character_string, decode_context_enumerated, decode_context_object_id,
+    decode_context_real, decode_context_signed, decode_context_tag, decode_context_unsigned,
+    decode_enumerated, decode_object_identifier, decode_octet_string, decode_signed, decode_tag,
+    decode_unsigned, encode_context_boolean, encode_context_character_string,
+    encode_context_enumerated, encode_context_object_id, encode_context_real,
+    encode_context_signed, encode_context_unsigned, write_boolean, write_character_string,
+    write_enumerated, write_object_identifier, write_octet_string, write_signed, write_unsigned,
+    BACnetTag, BufWrite, Result as EncodingResult, TagReader,
 };
+use crate::network::NetworkAddress;
 use crate::object::{
-    ObjectError, ObjectIdentifier, PropertyIdentifier, PropertyValue, Segmentation,
+    EventState, ObjectError, ObjectIdentifier, ObjectType, PropertyIdentifier, PropertyValue,
+    Segmentation, StatusFlags,
 };
-use crate::property::{self, decode_property_value, encode_property_value};
+use crate::property::{self, decode_property_value, decode_property_values, encode_property_value};
 use crate::{generate_custom_enum, EncodingError};
 
 /// Special array index value indicating all elements
@@ -454,7 +694,25 @@ impl WhoIsRequest {
     }
 
     /// Decode a Who-Is request
+    ///
+    /// A low limit that ends the request is kept with no high limit. A low
+    /// limit followed by anything other than context tag 1 is rejected.
     pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        Self::decode_range(data, true)
+    }
+
+    /// Decode a Who-Is request, ignoring trailing data after a low limit
+    ///
+    /// Some devices follow context tag 0 with something other than context
+    /// tag 1. The low limit is kept with no high limit, so
+    /// [`matches`](Self::matches) answers every instance at or above it, where
+    /// [`decode`](Self::decode) would reject the request. A low limit that
+    /// ends the request decodes the same way with either function.
+    pub fn decode_lenient(data: &[u8]) -> EncodingResult<Self> {
+        Self::decode_range(data, false)
+    }
+
+    fn decode_range(data: &[u8], strict: bool) -> EncodingResult<Self> {
         let mut request = WhoIsRequest::new();
         let mut pos = 0;
 
@@ -471,12 +729,13 @@ impl WhoIsRequest {
                             Ok((high, _consumed)) => {
                                 request.device_instance_range_high_limit = Some(high);
                             }
-                            Err(_) => {
+                            Err(_) if strict => {
                                 // Invalid format - low without high
                                 return Err(crate::encoding::EncodingError::InvalidFormat(
                                     "Who-Is request has low limit without high limit".to_string(),
                                 ));
                             }
+                            Err(_) => {}
                         }
                     }
                 }
@@ -501,6 +760,33 @@ impl WhoIsRequest {
             (None, Some(high)) => device_instance <= high,
         }
     }
+
+    /// Check whether a device with this instance should answer with an I-Am
+    ///
+    /// Same as [`matches`](Self::matches), named for the responder side.
+    pub fn should_respond(&self, device_instance: u32) -> bool {
+        self.matches(device_instance)
+    }
+}
+
+/// Build the I-Am a device sends in reply to a Who-Is
+///
+/// Returns the encoded unconfirmed-request APDU carrying `device`, or `None`
+/// when the device instance is outside the Who-Is range and the device must
+/// stay silent.
+pub fn respond_to_whois(whois: &WhoIsRequest, device: &IAmRequest) -> Option<Vec<u8>> {
+    if !whois.should_respond(device.device_identifier.instance) {
+        return None;
+    }
+
+    let mut service_data = Vec::new();
+    device.encode(&mut service_data).ok()?;
+
+    let apdu = crate::app::Apdu::UnconfirmedRequest {
+        service_choice: UnconfirmedServiceChoice::IAm,
+        service_data,
+    };
+    Some(apdu.encode())
 }
 
 /// I-Am response (unconfirmed service)
@@ -532,19 +818,37 @@ impl IAmRequest {
         }
     }
 
+    /// Create an I-Am for the device object with instance `device_id`
+    pub fn for_device(
+        device_id: u32,
+        max_apdu_length_accepted: u32,
+        segmentation_supported: Segmentation,
+        vendor_identifier: u16,
+    ) -> Self {
+        Self::new(
+            ObjectIdentifier::new(ObjectType::Device, device_id),
+            max_apdu_length_accepted,
+            segmentation_supported,
+            vendor_identifier,
+        )
+    }
+
     /// Encode the I-Am request
-    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+    ///
+    /// Accepts any [`BufWrite`], so a [`FixedBuffer`](crate::encoding::FixedBuffer)
+    /// can be used where no heap is available.
+    pub fn encode<B: BufWrite + ?Sized>(&self, buffer: &mut B) -> EncodingResult<()> {
         // Device identifier (object identifier) - application tag
-        encode_object_identifier(buffer, self.device_identifier)?;
+        write_object_identifier(buffer, self.device_identifier)?;
 
         // Maximum APDU length accepted - application tag
-        encode_unsigned(buffer, self.max_apdu_length_accepted)?;
+        write_unsigned(buffer, self.max_apdu_length_accepted)?;
 
         // Segmentation supported - application tag (enumerated)
-        encode_enumerated(buffer, self.segmentation_supported as u32);
+        write_enumerated(buffer, self.segmentation_supported as u32)?;
 
         // Vendor identifier - application tag
-        encode_unsigned(buffer, self.vendor_identifier as u32)?;
+        write_unsigned(buffer, self.vendor_identifier as u32)?;
 
         Ok(())
     }
@@ -579,6 +883,390 @@ impl IAmRequest {
     }
 }
 
+/// Object a Who-Has request is looking for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WhoHasObject {
+    /// Object identifier (context tag 2)
+    Identifier(ObjectIdentifier),
+    /// Object name (context tag 3)
+    Name(String),
+}
+
+/// Who-Has request (unconfirmed service)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WhoHasRequest {
+    /// Low limit of device instance range (optional)
+    pub device_instance_range_low_limit: Option<u32>,
+    /// High limit of device instance range (optional)
+    pub device_instance_range_high_limit: Option<u32>,
+    /// Object being searched for
+    pub object: WhoHasObject,
+}
+
+impl WhoHasRequest {
+    /// Create a new Who-Has request sent to all devices
+    pub fn new(object: WhoHasObject) -> Self {
+        Self {
+            device_instance_range_low_limit: None,
+            device_instance_range_high_limit: None,
+            object,
+        }
+    }
+
+    /// Create a new Who-Has request for an object identifier
+    pub fn for_identifier(object_identifier: ObjectIdentifier) -> Self {
+        Self::new(WhoHasObject::Identifier(object_identifier))
+    }
+
+    /// Create a new Who-Has request for an object name
+    pub fn for_name(object_name: impl Into<String>) -> Self {
+        Self::new(WhoHasObject::Name(object_name.into()))
+    }
+
+    /// Limit the request to devices within a range of instances
+    pub fn with_range(mut self, low: u32, high: u32) -> Self {
+        self.device_instance_range_low_limit = Some(low);
+        self.device_instance_range_high_limit = Some(high);
+        self
+    }
+
+    /// Encode the Who-Has request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Both limits must be present together, as for Who-Is
+        if let (Some(low), Some(high)) = (
+            self.device_instance_range_low_limit,
+            self.device_instance_range_high_limit,
+        ) {
+            buffer.extend_from_slice(&encode_context_unsigned(low, 0)?);
+            buffer.extend_from_slice(&encode_context_unsigned(high, 1)?);
+        }
+
+        match &self.object {
+            WhoHasObject::Identifier(object_identifier) => {
+                buffer.extend_from_slice(&encode_context_object_id(*object_identifier, 2)?);
+            }
+            WhoHasObject::Name(object_name) => {
+                buffer.extend_from_slice(&encode_context_character_string(object_name, 3)?);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decode a Who-Has request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let mut pos = 0;
+        let mut low_limit = None;
+        let mut high_limit = None;
+
+        // Device instance range - context tags 0 and 1 (optional)
+        if let Ok((low, consumed)) = decode_context_unsigned(data, 0) {
+            pos += consumed;
+            let (high, consumed) = decode_context_unsigned(&data[pos..], 1).map_err(|_| {
+                EncodingError::InvalidFormat(
+                    "Who-Has request has low limit without high limit".to_string(),
+                )
+            })?;
+            pos += consumed;
+            low_limit = Some(low);
+            high_limit = Some(high);
+        }
+
+        // Object identifier (context tag 2) or object name (context tag 3)
+        let (tag_number, _, _) = decode_context_tag(&data[pos..])?;
+        let (object, consumed) = match tag_number {
+            2 => {
+                let (object_identifier, consumed) = decode_context_object_id(&data[pos..], 2)?;
+                (WhoHasObject::Identifier(object_identifier), consumed)
+            }
+            3 => {
+                let (object_name, consumed) = decode_context_character_string(&data[pos..], 3)?;
+                (WhoHasObject::Name(object_name), consumed)
+            }
+            _ => return Err(EncodingError::InvalidTag),
+        };
+        pos += consumed;
+
+        if pos != data.len() {
+            return Err(EncodingError::InvalidFormat(
+                "Trailing data after Who-Has request".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            device_instance_range_low_limit: low_limit,
+            device_instance_range_high_limit: high_limit,
+            object,
+        })
+    }
+
+    /// Check whether a device with this instance is addressed by the request
+    pub fn matches_device(&self, device_instance: u32) -> bool {
+        match (
+            self.device_instance_range_low_limit,
+            self.device_instance_range_high_limit,
+        ) {
+            (Some(low), Some(high)) => device_instance >= low && device_instance <= high,
+            _ => true,
+        }
+    }
+
+    /// Check whether an object is the one being searched for
+    pub fn matches_object(&self, object_identifier: ObjectIdentifier, object_name: &str) -> bool {
+        match &self.object {
+            WhoHasObject::Identifier(identifier) => *identifier == object_identifier,
+            WhoHasObject::Name(name) => name == object_name,
+        }
+    }
+}
+
+/// Build the I-Have a device sends in reply to a Who-Has
+///
+/// `objects` lists the identifier and name of each object in the device.
+/// Returns the encoded unconfirmed-request APDU for the first object the
+/// request matches, by identifier or by name, or `None` when the device is
+/// outside the request's range or has no such object.
+pub fn respond_to_who_has<'a>(
+    who_has: &WhoHasRequest,
+    device_identifier: ObjectIdentifier,
+    objects: impl IntoIterator<Item = (ObjectIdentifier, &'a str)>,
+) -> Option<Vec<u8>> {
+    if !who_has.matches_device(device_identifier.instance) {
+        return None;
+    }
+
+    let (object_identifier, object_name) = objects
+        .into_iter()
+        .find(|(identifier, name)| who_has.matches_object(*identifier, name))?;
+
+    let mut service_data = Vec::new();
+    IHaveRequest::new(device_identifier, object_identifier, object_name)
+        .encode(&mut service_data)
+        .ok()?;
+
+    let apdu = crate::app::Apdu::UnconfirmedRequest {
+        service_choice: UnconfirmedServiceChoice::IHave,
+        service_data,
+    };
+    Some(apdu.encode())
+}
+
+/// I-Have response (unconfirmed service)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IHaveRequest {
+    /// Device object identifier
+    pub device_identifier: ObjectIdentifier,
+    /// Identifier of the object found
+    pub object_identifier: ObjectIdentifier,
+    /// Name of the object found
+    pub object_name: String,
+}
+
+impl IHaveRequest {
+    /// Create a new I-Have request
+    pub fn new(
+        device_identifier: ObjectIdentifier,
+        object_identifier: ObjectIdentifier,
+        object_name: impl Into<String>,
+    ) -> Self {
+        Self {
+            device_identifier,
+            object_identifier,
+            object_name: object_name.into(),
+        }
+    }
+
+    /// Encode the I-Have request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // All parameters are application tagged
+        write_object_identifier(buffer, self.device_identifier)?;
+        write_object_identifier(buffer, self.object_identifier)?;
+        write_character_string(buffer, &self.object_name)?;
+
+        Ok(())
+    }
+
+    /// Decode an I-Have request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let (device_identifier, mut pos) = decode_object_identifier(data)?;
+
+        let (object_identifier, consumed) = decode_object_identifier(&data[pos..])?;
+        pos += consumed;
+
+        let (object_name, _consumed) = decode_character_string(&data[pos..])?;
+
+        Ok(Self::new(device_identifier, object_identifier, object_name))
+    }
+}
+
+/// Write-Group request (unconfirmed service)
+#[derive(Debug, Clone, PartialEq)]
+pub struct WriteGroupRequest {
+    /// Control group number
+    pub group_number: u32,
+    /// Priority of the writes (1-16)
+    pub write_priority: u8,
+    /// Values to write, per channel
+    pub change_list: Vec<GroupChannelValue>,
+    /// Whether channels should honour their inhibit delay (optional)
+    pub inhibit_delay: Option<bool>,
+}
+
+/// A value written to one channel by Write-Group (BACnetGroupChannelValue)
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupChannelValue {
+    /// Channel number
+    pub channel: u16,
+    /// Priority overriding the request's write priority (1-16, optional)
+    pub overriding_priority: Option<u8>,
+    /// Channel value, application tagged
+    pub value: property::PropertyValue,
+}
+
+impl WriteGroupRequest {
+    /// Create a new Write-Group request
+    pub fn new(group_number: u32, write_priority: u8, change_list: Vec<GroupChannelValue>) -> Self {
+        Self {
+            group_number,
+            write_priority,
+            change_list,
+            inhibit_delay: None,
+        }
+    }
+
+    /// Encode the Write-Group request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Group number - context tag 0
+        buffer.extend_from_slice(&encode_context_unsigned(self.group_number, 0)?);
+
+        // Write priority - context tag 1
+        if !(1..=16).contains(&self.write_priority) {
+            return Err(EncodingError::ValueOutOfRange);
+        }
+        buffer.extend_from_slice(&encode_context_unsigned(self.write_priority as u32, 1)?);
+
+        // Change list - context tag 2 (constructed)
+        encode_opening_tag(buffer, 2)?;
+        for value in &self.change_list {
+            value.encode(buffer)?;
+        }
+        encode_closing_tag(buffer, 2)?;
+
+        // Inhibit delay - context tag 3 (optional)
+        if let Some(inhibit_delay) = self.inhibit_delay {
+            buffer.extend_from_slice(&encode_context_boolean(inhibit_delay, 3)?);
+        }
+
+        Ok(())
+    }
+
+    /// Decode a Write-Group request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        // Group number - context tag 0
+        let (group_number, mut pos) = decode_context_unsigned(data, 0)?;
+
+        // Write priority - context tag 1
+        let (write_priority, consumed) = decode_context_unsigned(&data[pos..], 1)?;
+        if !(1..=16).contains(&write_priority) {
+            return Err(EncodingError::ValueOutOfRange);
+        }
+        pos += consumed;
+
+        // Change list - context tag 2 (constructed)
+        pos += expect_constructed_tag(&data[pos..], 2, 6)?;
+        let end = pos + constructed_value_length(&data[pos..], 2)?;
+        let mut change_list = Vec::new();
+        while pos < end {
+            let (value, consumed) = GroupChannelValue::decode(&data[pos..end])?;
+            change_list.push(value);
+            pos += consumed;
+        }
+        pos = end + expect_constructed_tag(&data[end..], 2, 7)?;
+
+        // Inhibit delay - context tag 3 (optional)
+        let inhibit_delay = if pos < data.len() {
+            let (inhibit_delay, consumed) = decode_context_boolean(&data[pos..], 3)?;
+            pos += consumed;
+            Some(inhibit_delay)
+        } else {
+            None
+        };
+
+        if pos != data.len() {
+            return Err(EncodingError::InvalidFormat(
+                "Trailing data after Write-Group request".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            group_number,
+            write_priority: write_priority as u8,
+            change_list,
+            inhibit_delay,
+        })
+    }
+}
+
+impl GroupChannelValue {
+    /// Create a channel value written at the request's priority
+    pub fn new(channel: u16, value: property::PropertyValue) -> Self {
+        Self {
+            channel,
+            overriding_priority: None,
+            value,
+        }
+    }
+
+    /// Encode the channel value
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Channel - context tag 0
+        buffer.extend_from_slice(&encode_context_unsigned(self.channel as u32, 0)?);
+
+        // Overriding priority - context tag 1 (optional)
+        if let Some(priority) = self.overriding_priority {
+            if !(1..=16).contains(&priority) {
+                return Err(EncodingError::ValueOutOfRange);
+            }
+            buffer.extend_from_slice(&encode_context_unsigned(priority as u32, 1)?);
+        }
+
+        // Value - application tagged
+        encode_property_value(&self.value, buffer)
+    }
+
+    /// Decode a channel value, returning it with the number of bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        // Channel - context tag 0
+        let (channel, mut pos) = decode_context_unsigned(data, 0)?;
+        let channel = u16::try_from(channel).map_err(|_| EncodingError::ValueOutOfRange)?;
+
+        // Overriding priority - context tag 1 (optional)
+        let overriding_priority = match decode_context_unsigned(&data[pos..], 1) {
+            Ok((priority, consumed)) => {
+                if !(1..=16).contains(&priority) {
+                    return Err(EncodingError::ValueOutOfRange);
+                }
+                pos += consumed;
+                Some(priority as u8)
+            }
+            Err(_) => None,
+        };
+
+        // Value - application tagged
+        let (value, consumed) = decode_property_value(&data[pos..])?;
+        pos += consumed;
+
+        Ok((
+            Self {
+                channel,
+                overriding_priority,
+                value,
+            },
+            pos,
+        ))
+    }
+}
+
 /// Read Property request (confirmed service)
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ReadPropertyRequest {
@@ -687,63 +1375,50 @@ impl ReadPropertyResponse {
 
     /// Decode a Read Property response
     pub fn decode(data: &[u8]) -> EncodingResult<Self> {
-        let mut pos = 0;
-
-        // Decode object identifier - context tag 0
-        let (object_identifier, consumed) = decode_context_object_id(&data[pos..], 0)?;
-        pos += consumed;
+        let (mut response, value_data) = Self::decode_header(data)?;
+        response.property_values = decode_property_values(value_data)?;
 
-        // Decode property identifier - context tag 1
-        let (property_identifier, consumed) = decode_context_enumerated(&data[pos..], 1)?;
-        pos += consumed;
+        Ok(response)
+    }
 
-        // Property array index - context tag 2 (optional)
-        let property_array_index = match decode_context_unsigned(&data[pos..], 2) {
-            Ok((array_index, consumed)) => {
-                pos += consumed;
-                if array_index == BACNET_ARRAY_ALL {
-                    None
-                } else {
-                    Some(array_index)
-                }
-            }
-            Err(_) => None,
-        };
+    /// Get the encoded property value of a Read Property response
+    ///
+    /// Returns the bytes between the opening and closing property-value tags,
+    /// for decoding with a property-specific decoder such as
+    /// [`property::decode_known`].
+    pub fn value_data(data: &[u8]) -> EncodingResult<&[u8]> {
+        Ok(Self::decode_header(data)?.1)
+    }
 
-        let (tag, _, consumed) = decode_tag(&data[pos..])?;
-        pos += consumed;
+    /// Decode everything but the property values, returning the encoded
+    /// values alongside
+    fn decode_header(data: &[u8]) -> EncodingResult<(Self, &[u8])> {
+        let mut reader = TagReader::new(data);
 
-        let property_values = if let BACnetTag::Context(3) = tag {
-            let (tag, _, _) = decode_tag(&data[pos..])?;
-            let mut current_tag = tag;
-            let mut values = Vec::new();
+        // Object identifier - context tag 0
+        let object_identifier = reader.read_context_object_id(0)?;
 
-            while let BACnetTag::Application(_) = current_tag {
-                let (value, consumed) = decode_property_value(&data[pos..])?;
-                values.push(value);
-                pos += consumed;
-                let (tag, _, _) = decode_tag(&data[pos..])?;
-                current_tag = tag;
-            }
-            values
-        } else {
-            return Err(EncodingError::InvalidTag);
-        };
+        // Property identifier - context tag 1
+        let property_identifier = reader.read_context_enumerated(1)?;
 
-        let (tag, _, _) = decode_tag(&data[pos..])?;
+        // Property array index - context tag 2 (optional)
+        let property_array_index = reader
+            .read_optional_context_unsigned(2)?
+            .filter(|&array_index| array_index != BACNET_ARRAY_ALL);
 
-        if let BACnetTag::Context(tag) = tag {
-            if tag != 3 {
-                return Err(EncodingError::InvalidTag);
-            }
-        }
+        // Property value - context tag 3 (constructed). The value may itself
+        // be constructed; the reader skips nested values whole.
+        let value_data = reader.read_constructed(3)?;
 
-        Ok(ReadPropertyResponse {
-            object_identifier,
-            property_identifier: property_identifier.into(),
-            property_array_index,
-            property_values,
-        })
+        Ok((
+            ReadPropertyResponse {
+                object_identifier,
+                property_identifier: property_identifier.into(),
+                property_array_index,
+                property_values: Vec::new(),
+            },
+            value_data,
+        ))
     }
 
     pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
@@ -759,19 +1434,19 @@ impl ReadPropertyResponse {
             buffer.extend_from_slice(&array_bytes);
         }
 
-        // Property value - context tag 3 (opening tag)
-        buffer.push(0x3E); // Context tag 3, opening tag
+        // Property value - context tag 3 (constructed)
+        encode_opening_tag(buffer, 3)?;
         for property_value in &self.property_values {
             encode_property_value(property_value, buffer)?;
         }
-        buffer.push(0x3F); // Context tag 3, closing tag
+        encode_closing_tag(buffer, 3)?;
 
         Ok(())
     }
 }
 
 /// Write Property request (confirmed service)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WritePropertyRequest {
     /// Object identifier to write to
     pub object_identifier: ObjectIdentifier,
@@ -836,29 +1511,24 @@ impl WritePropertyRequest {
     /// Encode the Write Property request
     pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
         // Object identifier - context tag 0
-        let object_id: u32 = self.object_identifier.try_into()?;
-        buffer.push(0x0C); // Context tag 0, length 4
-        buffer.extend_from_slice(&object_id.to_be_bytes());
+        buffer.extend_from_slice(&encode_context_object_id(self.object_identifier, 0)?);
 
         // Property identifier - context tag 1
-        buffer.push(0x19); // Context tag 1, length 1
-        buffer.push(self.property_identifier as u8);
+        buffer.extend_from_slice(&encode_context_enumerated(self.property_identifier, 1)?);
 
         // Property array index - context tag 2 (optional)
         if let Some(array_index) = self.property_array_index {
-            buffer.push(0x29); // Context tag 2, length 1
-            buffer.push(array_index as u8);
+            buffer.extend_from_slice(&encode_context_unsigned(array_index, 2)?);
         }
 
-        // Property value - context tag 3 (opening tag)
-        buffer.push(0x3E); // Context tag 3, opening tag
+        // Property value - context tag 3 (constructed)
+        encode_opening_tag(buffer, 3)?;
         buffer.extend_from_slice(&self.property_value);
-        buffer.push(0x3F); // Context tag 3, closing tag
+        encode_closing_tag(buffer, 3)?;
 
         // Priority - context tag 4 (optional)
         if let Some(priority) = self.priority {
-            buffer.push(0x49); // Context tag 4, length 1
-            buffer.push(priority);
+            buffer.extend_from_slice(&encode_context_unsigned(priority as u32, 4)?);
         }
 
         Ok(())
@@ -869,65 +1539,44 @@ impl WritePropertyRequest {
         let mut pos = 0;
 
         // Decode object identifier - context tag 0
-        if pos + 5 > data.len() || data[pos] != 0x0C {
-            return Err(crate::encoding::EncodingError::InvalidTag);
-        }
-        pos += 1;
-
-        let object_id_bytes = [data[pos], data[pos + 1], data[pos + 2], data[pos + 3]];
-        let object_id = u32::from_be_bytes(object_id_bytes);
-        let object_identifier = object_id.into();
-        pos += 4;
+        let (object_identifier, consumed) = decode_context_object_id(data, 0)?;
+        pos += consumed;
 
         // Decode property identifier - context tag 1
-        if pos + 2 > data.len() || data[pos] != 0x19 {
-            return Err(crate::encoding::EncodingError::InvalidTag);
-        }
-        pos += 1;
-        let property_identifier = data[pos] as u32;
-        pos += 1;
+        let (property_identifier, consumed) = decode_context_enumerated(&data[pos..], 1)?;
+        pos += consumed;
 
         // Property array index - context tag 2 (optional)
-        let property_array_index = if pos < data.len() && data[pos] == 0x29 {
-            pos += 1;
-            let array_index = data[pos] as u32;
-            pos += 1;
-            Some(array_index)
-        } else {
-            None
+        let property_array_index = match decode_context_unsigned(&data[pos..], 2) {
+            Ok((array_index, consumed)) => {
+                pos += consumed;
+                Some(array_index)
+            }
+            Err(_) => None,
         };
 
         // Property value - context tag 3 (opening tag)
-        if pos >= data.len() || data[pos] != 0x3E {
-            return Err(crate::encoding::EncodingError::InvalidTag);
-        }
-        pos += 1;
-
-        // Find closing tag
-        let value_start = pos;
-        let mut value_end = pos;
-        while value_end < data.len() {
-            if data[value_end] == 0x3F {
-                break;
-            }
-            value_end += 1;
+        let (tag_number, length, consumed) = decode_context_tag(&data[pos..])?;
+        if tag_number != 3 || length != 6 {
+            return Err(EncodingError::InvalidTag);
         }
+        pos += consumed;
 
-        if value_end >= data.len() {
-            return Err(crate::encoding::EncodingError::InvalidTag);
-        }
+        let value_length = constructed_value_length(&data[pos..], 3)?;
+        let property_value = data[pos..pos + value_length].to_vec();
+        pos += value_length;
 
-        let property_value = data[value_start..value_end].to_vec();
-        pos = value_end + 1;
+        // Closing tag 3, already checked by constructed_value_length
+        let (_, _, consumed) = decode_context_tag(&data[pos..])?;
+        pos += consumed;
 
         // Priority - context tag 4 (optional)
-        let priority = if pos < data.len() && data[pos] == 0x49 {
-            pos += 1;
-            if pos < data.len() {
-                Some(data[pos])
-            } else {
-                None
+        let priority = if pos < data.len() {
+            let (priority, _) = decode_context_unsigned(&data[pos..], 4)?;
+            if !(1..=16).contains(&priority) {
+                return Err(EncodingError::ValueOutOfRange);
             }
+            Some(priority as u8)
         } else {
             None
         };
@@ -943,13 +1592,13 @@ impl WritePropertyRequest {
 }
 
 /// Read Property Multiple request (confirmed service)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ReadPropertyMultipleRequest {
     /// List of objects and properties to read
     pub read_access_specifications: Vec<ReadAccessSpecification>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ReadAccessSpecification {
     /// Object identifier
     pub object_identifier: ObjectIdentifier,
@@ -957,7 +1606,7 @@ pub struct ReadAccessSpecification {
     pub property_references: Vec<PropertyReference>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PropertyReference {
     /// Property identifier
     pub property_identifier: PropertyIdentifier,
@@ -978,6 +1627,7 @@ impl ReadPropertyMultipleRequest {
         self.read_access_specifications.push(spec);
     }
 
+    /// Encode the Read Property Multiple request
     pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
         for spec in &self.read_access_specifications {
             spec.encode(buffer)?;
@@ -985,6 +1635,22 @@ impl ReadPropertyMultipleRequest {
 
         Ok(())
     }
+
+    /// Decode a Read Property Multiple request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let mut read_access_specifications = Vec::new();
+        let mut pos = 0;
+
+        while pos < data.len() {
+            let (spec, consumed) = ReadAccessSpecification::decode(&data[pos..])?;
+            read_access_specifications.push(spec);
+            pos += consumed;
+        }
+
+        Ok(Self {
+            read_access_specifications,
+        })
+    }
 }
 
 impl ReadAccessSpecification {
@@ -1004,22 +1670,65 @@ impl ReadAccessSpecification {
         self.property_references.push(property_reference);
     }
 
+    /// Encode the read access specification
+    ///
+    /// The list of property references must not be empty; ask for
+    /// [`PropertyIdentifier::All`] to read every property of the object.
     pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        if self.property_references.is_empty() {
+            return Err(EncodingError::InvalidFormat(
+                "Read access specification has no property references".into(),
+            ));
+        }
+
         // Object identifier - context tag 0
         let object_id_bytes = encode_context_object_id(self.object_identifier, 0)?;
         buffer.extend_from_slice(&object_id_bytes);
 
-        // Property references - context tag 1 (opening tag)
-        buffer.push(0x1E); // Context tag 1, opening tag
+        // Property references - context tag 1 (constructed)
+        encode_opening_tag(buffer, 1)?;
 
         for property_ref in &self.property_references {
             property_ref.encode(buffer)?;
         }
 
-        buffer.push(0x1F); // Context tag 1, closing tag
+        encode_closing_tag(buffer, 1)?;
 
         Ok(())
     }
+
+    /// Decode a read access specification, returning it with the bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        let (object_identifier, mut pos) = decode_context_object_id(data, 0)?;
+
+        // Property references - context tag 1 (opening tag)
+        let (tag_number, length, consumed) = decode_context_tag(&data[pos..])?;
+        if tag_number != 1 || length != 6 {
+            return Err(EncodingError::InvalidTag);
+        }
+        pos += consumed;
+
+        let mut property_references = Vec::new();
+        loop {
+            let (tag_number, length, consumed) = decode_context_tag(&data[pos..])?;
+            if tag_number == 1 && length == 7 {
+                pos += consumed;
+                break;
+            }
+
+            let (property_ref, consumed) = PropertyReference::decode(&data[pos..])?;
+            property_references.push(property_ref);
+            pos += consumed;
+        }
+
+        Ok((
+            Self {
+                object_identifier,
+                property_references,
+            },
+            pos,
+        ))
+    }
 }
 
 impl PropertyReference {
@@ -1039,25 +1748,74 @@ impl PropertyReference {
         }
     }
 
+    /// Encode the property reference
+    ///
+    /// An array index of [`BACNET_ARRAY_ALL`] is the same as no index and is
+    /// omitted from the encoding.
     pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
         let prop_id_bytes = encode_context_enumerated(self.property_identifier.into(), 0)?;
         buffer.extend_from_slice(&prop_id_bytes);
 
         if let Some(array_index) = self.property_array_index {
-            let array_bytes = encode_context_unsigned(array_index, 1)?;
-            buffer.extend_from_slice(&array_bytes);
+            if array_index != BACNET_ARRAY_ALL {
+                let array_bytes = encode_context_unsigned(array_index, 1)?;
+                buffer.extend_from_slice(&array_bytes);
+            }
         }
 
         Ok(())
     }
+
+    /// Decode a property reference, returning it with the bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        let (property_identifier, mut pos) = decode_context_enumerated(data, 0)?;
+
+        let property_array_index = match decode_context_unsigned(&data[pos..], 1) {
+            Ok((array_index, consumed)) => {
+                pos += consumed;
+                Some(array_index).filter(|&index| index != BACNET_ARRAY_ALL)
+            }
+            Err(_) => None,
+        };
+
+        Ok((
+            Self {
+                property_identifier: property_identifier.into(),
+                property_array_index,
+            },
+            pos,
+        ))
+    }
 }
 
-#[derive(Debug, Clone)]
+/// Read Property Multiple response (complex ACK)
+#[derive(Debug, Clone, PartialEq)]
 pub struct ReadPropertyMultipleResponse {
+    /// One result per object in the request
     pub read_access_results: Vec<ReadAccessResult>,
 }
 
 impl ReadPropertyMultipleResponse {
+    /// Create a new Read Property Multiple response
+    pub fn new(read_access_results: Vec<ReadAccessResult>) -> Self {
+        Self {
+            read_access_results,
+        }
+    }
+
+    /// Encode the Read Property Multiple response
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        for result in &self.read_access_results {
+            result.encode(buffer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Decode a Read Property Multiple response
+    ///
+    /// Per-property failures are returned as [`PropertyResultValue::Error`]
+    /// rather than failing the whole decode.
     pub fn decode(data: &[u8]) -> EncodingResult<Self> {
         let mut read_access_results = Vec::new();
 
@@ -1075,13 +1833,40 @@ impl ReadPropertyMultipleResponse {
     }
 }
 
-#[derive(Debug, Clone)]
+/// Results for a single object in a Read Property Multiple response
+#[derive(Debug, Clone, PartialEq)]
 pub struct ReadAccessResult {
+    /// Object the results belong to
     pub object_identifier: ObjectIdentifier,
+    /// One entry per requested property
     pub results: Vec<PropertyResult>,
 }
 
 impl ReadAccessResult {
+    /// Create a new read access result
+    pub fn new(object_identifier: ObjectIdentifier, results: Vec<PropertyResult>) -> Self {
+        Self {
+            object_identifier,
+            results,
+        }
+    }
+
+    /// Encode the read access result
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Object identifier - context tag 0
+        buffer.extend_from_slice(&encode_context_object_id(self.object_identifier, 0)?);
+
+        // List of results - context tag 1 (constructed)
+        encode_opening_tag(buffer, 1)?;
+        for result in &self.results {
+            result.encode(buffer)?;
+        }
+        encode_closing_tag(buffer, 1)?;
+
+        Ok(())
+    }
+
+    /// Decode a read access result, returning it with the bytes consumed
     pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
         let mut total_consumed = 0;
         let mut results = Vec::new();
@@ -1123,14 +1908,53 @@ impl ReadAccessResult {
     }
 }
 
-#[derive(Debug, Clone)]
+/// Outcome of reading one property in a Read Property Multiple response
+#[derive(Debug, Clone, PartialEq)]
 pub struct PropertyResult {
+    /// Property that was read
     pub property_identifier: PropertyIdentifier,
+    /// Array index (optional)
     pub array_index: Option<u32>,
+    /// The property value, or the error the device returned for it
     pub value: PropertyResultValue,
 }
 
 impl PropertyResult {
+    /// Encode the property result
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Property identifier - context tag 2
+        buffer.extend_from_slice(&encode_context_enumerated(
+            self.property_identifier.into(),
+            2,
+        )?);
+
+        // Property array index - context tag 3 (optional)
+        if let Some(array_index) = self.array_index {
+            buffer.extend_from_slice(&encode_context_unsigned(array_index, 3)?);
+        }
+
+        match &self.value {
+            // Property value - context tag 4 (constructed)
+            PropertyResultValue::Value(values) => {
+                encode_opening_tag(buffer, 4)?;
+                for value in values {
+                    encode_property_value(value, buffer)?;
+                }
+                encode_closing_tag(buffer, 4)?;
+            }
+            // Property access error - context tag 5 (constructed)
+            PropertyResultValue::Error(error_class, error_code) => {
+                encode_opening_tag(buffer, 5)?;
+                write_enumerated(buffer, *error_class)?;
+                write_enumerated(buffer, *error_code)?;
+                encode_closing_tag(buffer, 5)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decode a property result, returning it with the bytes consumed
     pub fn decode(bytes: &[u8]) -> EncodingResult<(Self, usize)> {
         let (property_identifier, consumed) = decode_context_enumerated(bytes, 2)?;
         let mut total_consumed = consumed;
@@ -1149,17 +1973,12 @@ impl PropertyResult {
         total_consumed += consumed;
 
         let value = if let BACnetTag::Context(4) = tag {
-            let (tag, _, _) = decode_tag(&bytes[total_consumed..])?;
-            let mut current_tag = tag;
-            let mut values = Vec::new();
-
-            while let BACnetTag::Application(_) = current_tag {
-                let (value, consumed) = decode_property_value(&bytes[total_consumed..])?;
-                values.push(value);
-                total_consumed += consumed;
-                let (tag, _, _) = decode_tag(&bytes[total_consumed..])?;
-                current_tag = tag;
-            }
+            // The value may be a list (e.g. object-list), so collect every
+            // element up to the matching closing tag.
+            let value_length = constructed_value_length(&bytes[total_consumed..], 4)?;
+            let values =
+                decode_property_values(&bytes[total_consumed..total_consumed + value_length])?;
+            total_consumed += value_length;
             PropertyResultValue::Value(values)
         } else if let BACnetTag::Context(5) = tag {
             let (error_class, consumed) = decode_enumerated(&bytes[total_consumed..])?;
@@ -1193,814 +2012,3315 @@ impl PropertyResult {
     }
 }
 
+/// Either the value(s) read or a property access error
 #[derive(Debug, Clone, PartialEq)]
 pub enum PropertyResultValue {
+    /// Decoded property value(s)
     Value(Vec<property::PropertyValue>),
+    /// Error class and error code returned for this property
     Error(u32, u32),
 }
 
-/// Subscribe COV request (confirmed service)
-#[derive(Debug, Clone)]
-pub struct SubscribeCovRequest {
-    /// Subscriber process identifier
-    pub subscriber_process_identifier: u32,
-    /// Monitored object identifier
-    pub monitored_object_identifier: ObjectIdentifier,
-    /// Issue confirmed notifications
-    pub issue_confirmed_notifications: Option<bool>,
-    /// Lifetime (seconds, 0 = permanent)
-    pub lifetime: Option<u32>,
+/// Write Property Multiple request (confirmed service)
+#[derive(Debug, Clone, PartialEq)]
+pub struct WritePropertyMultipleRequest {
+    /// List of objects and the property values to write to them
+    pub write_access_specifications: Vec<WriteAccessSpecification>,
 }
 
-impl SubscribeCovRequest {
-    /// Create a new Subscribe COV request
-    pub fn new(
-        subscriber_process_identifier: u32,
-        monitored_object_identifier: ObjectIdentifier,
-    ) -> Self {
+/// The properties to write to a single object
+#[derive(Debug, Clone, PartialEq)]
+pub struct WriteAccessSpecification {
+    /// Object identifier
+    pub object_identifier: ObjectIdentifier,
+    /// Property values to write
+    pub list_of_properties: Vec<BacnetPropertyValue>,
+}
+
+/// A property value as written by WritePropertyMultiple and CreateObject or
+/// reported in a COV notification (BACnetPropertyValue)
+#[derive(Debug, Clone, PartialEq)]
+pub struct BacnetPropertyValue {
+    /// Property identifier
+    pub property_identifier: PropertyIdentifier,
+    /// Property array index (optional)
+    pub property_array_index: Option<u32>,
+    /// Value to write
+    pub value: property::PropertyValue,
+    /// Priority (1-16, optional)
+    pub priority: Option<u8>,
+}
+
+/// Fluent builder for a [`WritePropertyMultipleRequest`]
+#[derive(Debug, Clone, Default)]
+pub struct WritePropertyMultipleRequestBuilder {
+    write_access_specifications: Vec<WriteAccessSpecification>,
+}
+
+impl WritePropertyMultipleRequest {
+    /// Create a new Write Property Multiple request
+    pub fn new(write_access_specifications: Vec<WriteAccessSpecification>) -> Self {
         Self {
-            subscriber_process_identifier,
-            monitored_object_identifier,
-            issue_confirmed_notifications: None,
-            lifetime: None,
+            write_access_specifications,
         }
     }
 
-    /// Create a new Subscribe COV request with confirmation preference
-    pub fn with_confirmation(
-        subscriber_process_identifier: u32,
-        monitored_object_identifier: ObjectIdentifier,
-        issue_confirmed_notifications: bool,
-    ) -> Self {
-        Self {
-            subscriber_process_identifier,
-            monitored_object_identifier,
-            issue_confirmed_notifications: Some(issue_confirmed_notifications),
-            lifetime: None,
-        }
+    /// Begin building a request one object at a time
+    pub fn builder() -> WritePropertyMultipleRequestBuilder {
+        WritePropertyMultipleRequestBuilder::default()
     }
 
-    /// Create a new Subscribe COV request with lifetime
-    pub fn with_lifetime(
-        subscriber_process_identifier: u32,
-        monitored_object_identifier: ObjectIdentifier,
-        lifetime: u32,
-    ) -> Self {
-        Self {
-            subscriber_process_identifier,
-            monitored_object_identifier,
-            issue_confirmed_notifications: None,
-            lifetime: Some(lifetime),
-        }
+    /// Add a write access specification
+    pub fn add_specification(&mut self, spec: WriteAccessSpecification) {
+        self.write_access_specifications.push(spec);
     }
 
-    /// Encode the Subscribe COV request
+    /// Encode the Write Property Multiple request
     pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
-        // Subscriber process identifier - context tag 0
-        buffer.push(0x09); // Context tag 0, length 1
-        buffer.push(self.subscriber_process_identifier as u8);
-
-        // Monitored object identifier - context tag 1
-        let object_id: u32 = self.monitored_object_identifier.try_into()?;
-        buffer.push(0x1C); // Context tag 1, length 4
-        buffer.extend_from_slice(&object_id.to_be_bytes());
-
-        // Issue confirmed notifications - context tag 2 (optional)
-        if let Some(confirmed) = self.issue_confirmed_notifications {
-            buffer.push(0x22); // Context tag 2, length 2
-            buffer.push(if confirmed { 1 } else { 0 });
+        if self.write_access_specifications.is_empty() {
+            return Err(EncodingError::InvalidFormat(
+                "Write Property Multiple request has no write access specifications".to_string(),
+            ));
         }
 
-        // Lifetime - context tag 3 (optional)
-        if let Some(lifetime) = self.lifetime {
-            buffer.push(0x39); // Context tag 3, length 1
-            buffer.push(lifetime as u8);
+        for spec in &self.write_access_specifications {
+            spec.encode(buffer)?;
         }
 
         Ok(())
     }
 }
 
-/// Subscribe COV Property request (confirmed service)
-#[derive(Debug, Clone)]
-pub struct SubscribeCovPropertyRequest {
-    /// Subscriber process identifier
-    pub subscriber_process_identifier: u32,
-    /// Monitored object identifier
-    pub monitored_object_identifier: ObjectIdentifier,
-    /// Issue confirmed notifications
-    pub issue_confirmed_notifications: Option<bool>,
-    /// Lifetime (seconds, 0 = permanent)
-    pub lifetime: Option<u32>,
-    /// Monitored property reference
-    pub monitored_property: PropertyReference,
-    /// COV increment (optional)
-    pub cov_increment: Option<f32>,
+impl WritePropertyMultipleRequestBuilder {
+    /// Add the writes for one object
+    pub fn object(mut self, spec: WriteAccessSpecification) -> Self {
+        self.write_access_specifications.push(spec);
+        self
+    }
+
+    /// Finish building the request
+    pub fn build(self) -> WritePropertyMultipleRequest {
+        WritePropertyMultipleRequest::new(self.write_access_specifications)
+    }
 }
 
-impl SubscribeCovPropertyRequest {
-    /// Create a new Subscribe COV Property request
-    pub fn new(
-        subscriber_process_identifier: u32,
-        monitored_object_identifier: ObjectIdentifier,
-        monitored_property: PropertyReference,
-    ) -> Self {
+impl WriteAccessSpecification {
+    /// Create a write access specification with no properties yet
+    pub fn new(object_identifier: ObjectIdentifier) -> Self {
         Self {
-            subscriber_process_identifier,
-            monitored_object_identifier,
-            issue_confirmed_notifications: None,
-            lifetime: None,
-            monitored_property,
-            cov_increment: None,
+            object_identifier,
+            list_of_properties: Vec::new(),
         }
     }
 
-    /// Set COV increment
-    pub fn with_cov_increment(mut self, increment: f32) -> Self {
-        self.cov_increment = Some(increment);
+    /// Write `value` to a property without a priority
+    pub fn add(
+        self,
+        property_identifier: PropertyIdentifier,
+        value: property::PropertyValue,
+    ) -> Self {
+        self.add_value(BacnetPropertyValue::new(property_identifier, value))
+    }
+
+    /// Write `value` to a property at the given priority
+    pub fn add_with_priority(
+        self,
+        property_identifier: PropertyIdentifier,
+        value: property::PropertyValue,
+        priority: u8,
+    ) -> Self {
+        let mut write_value = BacnetPropertyValue::new(property_identifier, value);
+        write_value.priority = Some(priority);
+        self.add_value(write_value)
+    }
+
+    /// Add a fully specified property value
+    pub fn add_value(mut self, write_value: BacnetPropertyValue) -> Self {
+        self.list_of_properties.push(write_value);
         self
     }
+
+    /// Encode the write access specification
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        if self.list_of_properties.is_empty() {
+            return Err(EncodingError::InvalidFormat(
+                "Write access specification has no property values".to_string(),
+            ));
+        }
+
+        // Object identifier - context tag 0
+        buffer.extend_from_slice(&encode_context_object_id(self.object_identifier, 0)?);
+
+        // List of properties - context tag 1 (constructed)
+        encode_opening_tag(buffer, 1)?;
+        for write_value in &self.list_of_properties {
+            write_value.encode(buffer)?;
+        }
+        encode_closing_tag(buffer, 1)?;
+
+        Ok(())
+    }
 }
 
-/// COV Notification request (unconfirmed service)
-#[derive(Debug, Clone)]
-pub struct CovNotificationRequest {
-    /// Subscriber process identifier
-    pub subscriber_process_identifier: u32,
-    /// Initiating device identifier
-    pub initiating_device_identifier: ObjectIdentifier,
-    /// Monitored object identifier
-    pub monitored_object_identifier: ObjectIdentifier,
-    /// Time remaining (seconds)
-    pub time_remaining: u32,
-    /// List of values (property-value pairs)
-    pub list_of_values: Vec<PropertyValue>,
-}
-
-impl CovNotificationRequest {
-    /// Create a new COV Notification request
-    pub fn new(
-        subscriber_process_identifier: u32,
-        initiating_device_identifier: ObjectIdentifier,
-        monitored_object_identifier: ObjectIdentifier,
-        time_remaining: u32,
-        list_of_values: Vec<PropertyValue>,
-    ) -> Self {
+impl BacnetPropertyValue {
+    /// Create a property value to write, with no array index or priority
+    pub fn new(property_identifier: PropertyIdentifier, value: property::PropertyValue) -> Self {
         Self {
-            subscriber_process_identifier,
-            initiating_device_identifier,
-            monitored_object_identifier,
-            time_remaining,
-            list_of_values,
+            property_identifier,
+            property_array_index: None,
+            value,
+            priority: None,
         }
     }
 
-    /// Encode the COV Notification request
+    /// Encode the property value
     pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
-        // Subscriber process identifier - context tag 0
-        buffer.push(0x09); // Context tag 0, length 1
-        buffer.push(self.subscriber_process_identifier as u8);
-
-        // Initiating device identifier - context tag 1
-        let device_id: u32 = self.initiating_device_identifier.try_into()?;
-        buffer.push(0x1C); // Context tag 1, length 4
-        buffer.extend_from_slice(&device_id.to_be_bytes());
+        // Property identifier - context tag 0
+        buffer.extend_from_slice(&encode_context_enumerated(
+            self.property_identifier.into(),
+            0,
+        )?);
 
-        // Monitored object identifier - context tag 2
-        let object_id: u32 = self.monitored_object_identifier.try_into()?;
-        buffer.push(0x2C); // Context tag 2, length 4
-        buffer.extend_from_slice(&object_id.to_be_bytes());
+        // Property array index - context tag 1 (optional)
+        if let Some(array_index) = self.property_array_index {
+            buffer.extend_from_slice(&encode_context_unsigned(array_index, 1)?);
+        }
 
-        // Time remaining - context tag 3
-        buffer.push(0x39); // Context tag 3, length 1
-        buffer.push(self.time_remaining as u8);
+        // Property value - context tag 2 (constructed)
+        encode_opening_tag(buffer, 2)?;
+        encode_property_value(&self.value, buffer)?;
+        encode_closing_tag(buffer, 2)?;
 
-        // List of values would be encoded here in a real implementation
-        // This is complex as it involves encoding property-value pairs
+        // Priority - context tag 3 (optional)
+        if let Some(priority) = self.priority {
+            if !(1..=16).contains(&priority) {
+                return Err(EncodingError::ValueOutOfRange);
+            }
+            buffer.extend_from_slice(&encode_context_unsigned(priority as u32, 3)?);
+        }
 
         Ok(())
     }
+
+    /// Decode a property value, returning it with the number of bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        // Property identifier - context tag 0
+        let (property_identifier, mut pos) = decode_context_enumerated(data, 0)?;
+        let property_identifier = PropertyIdentifier::from(property_identifier);
+
+        // Property array index - context tag 1 (optional)
+        let property_array_index = match decode_context_unsigned(&data[pos..], 1) {
+            Ok((array_index, consumed)) => {
+                pos += consumed;
+                Some(array_index)
+            }
+            Err(_) => None,
+        };
+
+        // Property value - context tag 2 (constructed)
+        pos += expect_constructed_tag(&data[pos..], 2, 6)?;
+        let length = constructed_value_length(&data[pos..], 2)?;
+        let mut values = decode_property_values(&data[pos..pos + length])?;
+        let value = match values.len() {
+            0 => {
+                return Err(EncodingError::InvalidFormat(
+                    "Empty property value".to_string(),
+                ))
+            }
+            1 => values.remove(0),
+            _ => property::PropertyValue::Array(values),
+        };
+        pos += length;
+        pos += expect_constructed_tag(&data[pos..], 2, 7)?;
+
+        // Priority - context tag 3 (optional)
+        let priority = match decode_context_unsigned(&data[pos..], 3) {
+            Ok((priority, consumed)) => {
+                if !(1..=16).contains(&priority) {
+                    return Err(EncodingError::ValueOutOfRange);
+                }
+                pos += consumed;
+                Some(priority as u8)
+            }
+            Err(_) => None,
+        };
+
+        Ok((
+            Self {
+                property_identifier,
+                property_array_index,
+                value,
+                priority,
+            },
+            pos,
+        ))
+    }
 }
 
-/// COV Subscription information
-#[derive(Debug, Clone)]
-pub struct CovSubscription {
-    /// Subscriber process identifier
-    pub subscriber_process_identifier: u32,
-    /// Subscriber device identifier
-    pub subscriber_device_identifier: ObjectIdentifier,
-    /// Monitored object identifier
-    pub monitored_object_identifier: ObjectIdentifier,
-    /// Monitored property (for COV Property subscriptions)
-    pub monitored_property: Option<PropertyReference>,
-    /// Issue confirmed notifications
-    pub issue_confirmed_notifications: bool,
-    /// Lifetime (seconds, 0 = permanent)
-    pub lifetime: u32,
-    /// Remaining time (seconds)
-    pub time_remaining: u32,
-    /// COV increment (for analog properties)
-    pub cov_increment: Option<f32>,
+/// Which object a Create Object request should create
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectSpecifier {
+    /// Any free instance of this type, chosen by the device - context tag 0
+    ObjectType(ObjectType),
+    /// This exact object - context tag 1
+    ObjectIdentifier(ObjectIdentifier),
 }
 
-impl CovSubscription {
-    /// Create a new COV subscription
-    pub fn new(
-        subscriber_process_identifier: u32,
-        subscriber_device_identifier: ObjectIdentifier,
-        monitored_object_identifier: ObjectIdentifier,
-        lifetime: u32,
-    ) -> Self {
+/// Create Object request (confirmed service)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateObjectRequest {
+    /// Object to create
+    pub object_specifier: ObjectSpecifier,
+    /// Initial property values (optional)
+    pub list_of_initial_values: Vec<BacnetPropertyValue>,
+}
+
+impl CreateObjectRequest {
+    /// Create a new Create Object request with no initial values
+    pub fn new(object_specifier: ObjectSpecifier) -> Self {
         Self {
-            subscriber_process_identifier,
-            subscriber_device_identifier,
-            monitored_object_identifier,
-            monitored_property: None,
-            issue_confirmed_notifications: false,
-            lifetime,
-            time_remaining: lifetime,
-            cov_increment: None,
+            object_specifier,
+            list_of_initial_values: Vec::new(),
         }
     }
 
-    /// Check if subscription has expired
-    pub fn is_expired(&self) -> bool {
-        self.lifetime > 0 && self.time_remaining == 0
+    /// Add an initial property value
+    pub fn with_initial_value(mut self, value: BacnetPropertyValue) -> Self {
+        self.list_of_initial_values.push(value);
+        self
     }
 
-    /// Update time remaining (should be called periodically)
-    pub fn update_time(&mut self, elapsed_seconds: u32) {
-        if self.lifetime > 0 {
-            self.time_remaining = self.time_remaining.saturating_sub(elapsed_seconds);
+    /// Encode the Create Object request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Object specifier - context tag 0 (constructed)
+        encode_opening_tag(buffer, 0)?;
+        match self.object_specifier {
+            ObjectSpecifier::ObjectType(object_type) => {
+                buffer.extend_from_slice(&encode_context_enumerated(u32::from(object_type), 0)?);
+            }
+            ObjectSpecifier::ObjectIdentifier(object_identifier) => {
+                buffer.extend_from_slice(&encode_context_object_id(object_identifier, 1)?);
+            }
+        }
+        encode_closing_tag(buffer, 0)?;
+
+        // List of initial values - context tag 1 (constructed, optional)
+        if !self.list_of_initial_values.is_empty() {
+            encode_opening_tag(buffer, 1)?;
+            for value in &self.list_of_initial_values {
+                value.encode(buffer)?;
+            }
+            encode_closing_tag(buffer, 1)?;
         }
+
+        Ok(())
     }
-}
 
-/// COV Subscription manager
-#[derive(Debug, Default)]
-pub struct CovSubscriptionManager {
-    /// List of active subscriptions
-    subscriptions: Vec<CovSubscription>,
-}
+    /// Decode a Create Object request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        // Object specifier - context tag 0 (constructed)
+        let mut pos = expect_constructed_tag(data, 0, 6)?;
+        let object_specifier = match decode_context_enumerated(&data[pos..], 0) {
+            Ok((object_type, consumed)) => {
+                pos += consumed;
+                if object_type > 0x3FF {
+                    return Err(EncodingError::ValueOutOfRange);
+                }
+                ObjectSpecifier::ObjectType(ObjectType::from(object_type))
+            }
+            Err(_) => {
+                let (object_identifier, consumed) = decode_context_object_id(&data[pos..], 1)?;
+                pos += consumed;
+                ObjectSpecifier::ObjectIdentifier(object_identifier)
+            }
+        };
+        pos += expect_constructed_tag(&data[pos..], 0, 7)?;
 
-impl CovSubscriptionManager {
-    /// Create a new COV subscription manager
-    pub fn new() -> Self {
-        Self {
-            subscriptions: Vec::new(),
+        // List of initial values - context tag 1 (constructed, optional)
+        let mut list_of_initial_values = Vec::new();
+        if pos < data.len() {
+            pos += expect_constructed_tag(&data[pos..], 1, 6)?;
+            let end = pos + constructed_value_length(&data[pos..], 1)?;
+            while pos < end {
+                let (value, consumed) = BacnetPropertyValue::decode(&data[pos..end])?;
+                list_of_initial_values.push(value);
+                pos += consumed;
+            }
+            pos += expect_constructed_tag(&data[pos..], 1, 7)?;
         }
-    }
 
-    /// Add a new subscription
-    pub fn add_subscription(&mut self, subscription: CovSubscription) {
-        // Remove any existing subscription for the same object and subscriber
-        self.subscriptions.retain(|s| {
-            !(s.subscriber_device_identifier == subscription.subscriber_device_identifier
-                && s.subscriber_process_identifier == subscription.subscriber_process_identifier
-                && s.monitored_object_identifier == subscription.monitored_object_identifier)
-        });
+        if pos != data.len() {
+            return Err(EncodingError::InvalidFormat(
+                "Trailing data after Create Object request".to_string(),
+            ));
+        }
 
-        self.subscriptions.push(subscription);
+        Ok(Self {
+            object_specifier,
+            list_of_initial_values,
+        })
     }
+}
 
-    /// Remove a subscription
-    pub fn remove_subscription(
-        &mut self,
-        subscriber_device: ObjectIdentifier,
-        subscriber_process: u32,
-        monitored_object: ObjectIdentifier,
-    ) {
-        self.subscriptions.retain(|s| {
-            !(s.subscriber_device_identifier == subscriber_device
-                && s.subscriber_process_identifier == subscriber_process
-                && s.monitored_object_identifier == monitored_object)
-        });
+/// Create Object response, the identifier of the created object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreateObjectResponse {
+    /// Object identifier
+    pub object_identifier: ObjectIdentifier,
+}
+
+impl CreateObjectResponse {
+    /// Create a new Create Object response
+    pub fn new(object_identifier: ObjectIdentifier) -> Self {
+        Self { object_identifier }
     }
 
-    /// Get all subscriptions for a monitored object
-    pub fn get_subscriptions_for_object(
-        &self,
-        object_id: ObjectIdentifier,
-    ) -> Vec<&CovSubscription> {
-        self.subscriptions
-            .iter()
-            .filter(|s| s.monitored_object_identifier == object_id && !s.is_expired())
-            .collect()
+    /// Encode the Create Object response
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        write_object_identifier(buffer, self.object_identifier)
     }
 
-    /// Remove expired subscriptions
-    pub fn cleanup_expired(&mut self) {
-        self.subscriptions.retain(|s| !s.is_expired());
+    /// Decode a Create Object response
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let (object_identifier, _) = decode_object_identifier(data)?;
+        Ok(Self { object_identifier })
     }
+}
 
-    /// Update all subscription timers
-    pub fn update_timers(&mut self, elapsed_seconds: u32) {
-        for subscription in &mut self.subscriptions {
-            subscription.update_time(elapsed_seconds);
-        }
+/// Error returned for a failed Create Object request
+///
+/// Besides the error class and code, a device reports which entry of the
+/// list of initial values it could not apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreateObjectError {
+    /// Error class
+    pub error_class: u32,
+    /// Error code
+    pub error_code: u32,
+    /// 1-based index of the initial value that failed, 0 if the failure was
+    /// not caused by an initial value
+    pub first_failed_element_number: u32,
+}
+
+impl CreateObjectError {
+    /// Encode the Create Object error
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        encode_error_with_element_number(
+            buffer,
+            self.error_class,
+            self.error_code,
+            self.first_failed_element_number,
+        )
     }
 
-    /// Get total number of active subscriptions
-    pub fn active_count(&self) -> usize {
-        self.subscriptions
-            .iter()
-            .filter(|s| !s.is_expired())
-            .count()
+    /// Decode a Create Object error
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let (error_class, error_code, first_failed_element_number) =
+            decode_error_with_element_number(data)?;
+        Ok(Self {
+            error_class,
+            error_code,
+            first_failed_element_number,
+        })
     }
 }
 
-/// Atomic Read File request (confirmed service)
-#[derive(Debug, Clone)]
-pub struct AtomicReadFileRequest {
-    /// File object identifier
-    pub file_identifier: ObjectIdentifier,
-    /// Access method specification
-    pub access_method: FileAccessMethod,
+/// Encode an error type followed by a first failed element number, the
+/// layout shared by the Create Object and Change List errors
+fn encode_error_with_element_number(
+    buffer: &mut Vec<u8>,
+    error_class: u32,
+    error_code: u32,
+    first_failed_element_number: u32,
+) -> EncodingResult<()> {
+    // Error type - context tag 0 (constructed)
+    encode_opening_tag(buffer, 0)?;
+    write_enumerated(buffer, error_class)?;
+    write_enumerated(buffer, error_code)?;
+    encode_closing_tag(buffer, 0)?;
+
+    // First failed element number - context tag 1
+    buffer.extend_from_slice(&encode_context_unsigned(first_failed_element_number, 1)?);
+
+    Ok(())
 }
 
-/// File access method for atomic read/write
-#[derive(Debug, Clone)]
-pub enum FileAccessMethod {
-    /// Stream access - read/write bytes at position
-    StreamAccess {
-        /// File position to start reading/writing
-        file_start_position: i32,
-        /// Number of octets to read (for read operations)
-        requested_octet_count: u32,
-    },
-    /// Record access - read/write records
-    RecordAccess {
-        /// Starting record number
-        file_start_record: i32,
-        /// Number of records to read (for read operations)
-        requested_record_count: u32,
-    },
+/// Decode an error type followed by a first failed element number
+fn decode_error_with_element_number(data: &[u8]) -> EncodingResult<(u32, u32, u32)> {
+    // Error type - context tag 0 (constructed)
+    let mut pos = expect_constructed_tag(data, 0, 6)?;
+    let (error_class, consumed) = decode_enumerated(&data[pos..])?;
+    pos += consumed;
+    let (error_code, consumed) = decode_enumerated(&data[pos..])?;
+    pos += consumed;
+    pos += expect_constructed_tag(&data[pos..], 0, 7)?;
+
+    // First failed element number - context tag 1
+    let (first_failed_element_number, _) = decode_context_unsigned(&data[pos..], 1)?;
+
+    Ok((error_class, error_code, first_failed_element_number))
 }
 
-impl AtomicReadFileRequest {
-    /// Create a new Atomic Read File request with stream access
-    pub fn new_stream_access(
-        file_identifier: ObjectIdentifier,
-        start_position: i32,
-        octet_count: u32,
-    ) -> Self {
-        Self {
-            file_identifier,
-            access_method: FileAccessMethod::StreamAccess {
-                file_start_position: start_position,
-                requested_octet_count: octet_count,
-            },
-        }
+/// Delete Object request (confirmed service)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeleteObjectRequest {
+    /// Object to delete
+    pub object_identifier: ObjectIdentifier,
+}
+
+impl DeleteObjectRequest {
+    /// Create a new Delete Object request
+    pub fn new(object_identifier: ObjectIdentifier) -> Self {
+        Self { object_identifier }
     }
 
-    /// Create a new Atomic Read File request with record access
-    pub fn new_record_access(
-        file_identifier: ObjectIdentifier,
-        start_record: i32,
-        record_count: u32,
-    ) -> Self {
-        Self {
-            file_identifier,
-            access_method: FileAccessMethod::RecordAccess {
-                file_start_record: start_record,
-                requested_record_count: record_count,
-            },
+    /// Encode the Delete Object request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        write_object_identifier(buffer, self.object_identifier)
+    }
+
+    /// Decode a Delete Object request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let (object_identifier, _) = decode_object_identifier(data)?;
+        Ok(Self { object_identifier })
+    }
+}
+
+/// Add List Element request (confirmed service)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddListElementRequest {
+    /// Object identifier
+    pub object_identifier: ObjectIdentifier,
+    /// Property identifier
+    pub property_identifier: PropertyIdentifier,
+    /// Property array index (optional)
+    pub property_array_index: Option<u32>,
+    /// Encoded elements to add, without the enclosing tags
+    pub list_of_elements: Vec<u8>,
+}
+
+/// Remove List Element request (confirmed service)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoveListElementRequest {
+    /// Object identifier
+    pub object_identifier: ObjectIdentifier,
+    /// Property identifier
+    pub property_identifier: PropertyIdentifier,
+    /// Property array index (optional)
+    pub property_array_index: Option<u32>,
+    /// Encoded elements to remove, without the enclosing tags
+    pub list_of_elements: Vec<u8>,
+}
+
+impl AddListElementRequest {
+    /// Create a new Add List Element request
+    pub fn new(
+        object_identifier: ObjectIdentifier,
+        property_identifier: PropertyIdentifier,
+        list_of_elements: Vec<u8>,
+    ) -> Self {
+        Self {
+            object_identifier,
+            property_identifier,
+            property_array_index: None,
+            list_of_elements,
         }
     }
 
-    /// Encode the Atomic Read File request
+    /// Encode the Add List Element request
     pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
-        // File identifier - context tag 0
-        let file_id: u32 = self.file_identifier.try_into()?;
-        buffer.push(0x0C); // Context tag 0, length 4
-        buffer.extend_from_slice(&file_id.to_be_bytes());
+        encode_list_element_request(
+            buffer,
+            self.object_identifier,
+            self.property_identifier,
+            self.property_array_index,
+            &self.list_of_elements,
+        )
+    }
 
-        // Access method - context tag 1 (opening tag)
-        buffer.push(0x1E); // Context tag 1, opening tag
+    /// Decode an Add List Element request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let (object_identifier, property_identifier, property_array_index, list_of_elements) =
+            decode_list_element_request(data)?;
+        Ok(Self {
+            object_identifier,
+            property_identifier,
+            property_array_index,
+            list_of_elements,
+        })
+    }
+}
 
-        match &self.access_method {
-            FileAccessMethod::StreamAccess {
-                file_start_position,
-                requested_octet_count,
-            } => {
-                // Stream access - context tag 0 (opening tag)
-                buffer.push(0x0E); // Context tag 0, opening tag
+impl RemoveListElementRequest {
+    /// Create a new Remove List Element request
+    pub fn new(
+        object_identifier: ObjectIdentifier,
+        property_identifier: PropertyIdentifier,
+        list_of_elements: Vec<u8>,
+    ) -> Self {
+        Self {
+            object_identifier,
+            property_identifier,
+            property_array_index: None,
+            list_of_elements,
+        }
+    }
 
-                // File start position - context tag 0
-                buffer.push(0x09); // Context tag 0, length 1
-                buffer.extend_from_slice(&file_start_position.to_be_bytes());
+    /// Encode the Remove List Element request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        encode_list_element_request(
+            buffer,
+            self.object_identifier,
+            self.property_identifier,
+            self.property_array_index,
+            &self.list_of_elements,
+        )
+    }
 
-                // Requested octet count - context tag 1
-                buffer.push(0x19); // Context tag 1, length 1
-                buffer.extend_from_slice(&requested_octet_count.to_be_bytes());
+    /// Decode a Remove List Element request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let (object_identifier, property_identifier, property_array_index, list_of_elements) =
+            decode_list_element_request(data)?;
+        Ok(Self {
+            object_identifier,
+            property_identifier,
+            property_array_index,
+            list_of_elements,
+        })
+    }
+}
 
-                buffer.push(0x0F); // Context tag 0, closing tag
-            }
-            FileAccessMethod::RecordAccess {
-                file_start_record,
-                requested_record_count,
-            } => {
-                // Record access - context tag 1 (opening tag)
-                buffer.push(0x1E); // Context tag 1, opening tag
+/// Encode the parameters shared by Add List Element and Remove List Element
+fn encode_list_element_request(
+    buffer: &mut Vec<u8>,
+    object_identifier: ObjectIdentifier,
+    property_identifier: PropertyIdentifier,
+    property_array_index: Option<u32>,
+    list_of_elements: &[u8],
+) -> EncodingResult<()> {
+    // Object identifier - context tag 0
+    buffer.extend_from_slice(&encode_context_object_id(object_identifier, 0)?);
+
+    // Property identifier - context tag 1
+    buffer.extend_from_slice(&encode_context_enumerated(property_identifier.into(), 1)?);
+
+    // Property array index - context tag 2 (optional)
+    if let Some(array_index) = property_array_index {
+        buffer.extend_from_slice(&encode_context_unsigned(array_index, 2)?);
+    }
 
-                // File start record - context tag 0
-                buffer.push(0x09); // Context tag 0, length 1
-                buffer.extend_from_slice(&file_start_record.to_be_bytes());
+    // List of elements - context tag 3 (constructed)
+    encode_opening_tag(buffer, 3)?;
+    buffer.extend_from_slice(list_of_elements);
+    encode_closing_tag(buffer, 3)?;
 
-                // Requested record count - context tag 1
-                buffer.push(0x19); // Context tag 1, length 1
-                buffer.extend_from_slice(&requested_record_count.to_be_bytes());
+    Ok(())
+}
 
-                buffer.push(0x1F); // Context tag 1, closing tag
-            }
+/// Decode the parameters shared by Add List Element and Remove List Element
+fn decode_list_element_request(
+    data: &[u8],
+) -> EncodingResult<(ObjectIdentifier, PropertyIdentifier, Option<u32>, Vec<u8>)> {
+    // Object identifier - context tag 0
+    let (object_identifier, mut pos) = decode_context_object_id(data, 0)?;
+
+    // Property identifier - context tag 1
+    let (property_identifier, consumed) = decode_context_enumerated(&data[pos..], 1)?;
+    pos += consumed;
+
+    // Property array index - context tag 2 (optional)
+    let property_array_index = match decode_context_unsigned(&data[pos..], 2) {
+        Ok((array_index, consumed)) => {
+            pos += consumed;
+            Some(array_index)
         }
+        Err(_) => None,
+    };
+
+    // List of elements - context tag 3 (constructed)
+    pos += expect_constructed_tag(&data[pos..], 3, 6)?;
+    let length = constructed_value_length(&data[pos..], 3)?;
+    let list_of_elements = data[pos..pos + length].to_vec();
+    pos += length;
+    expect_constructed_tag(&data[pos..], 3, 7)?;
+
+    Ok((
+        object_identifier,
+        PropertyIdentifier::from(property_identifier),
+        property_array_index,
+        list_of_elements,
+    ))
+}
 
-        buffer.push(0x1F); // Context tag 1, closing tag
+/// Error returned for a failed Add List Element or Remove List Element
+/// request (BACnet-Change-List-Error)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChangeListError {
+    /// Error class
+    pub error_class: u32,
+    /// Error code
+    pub error_code: u32,
+    /// 1-based index of the list element that failed, 0 if the failure was
+    /// not caused by an element
+    pub first_failed_element_number: u32,
+}
 
-        Ok(())
+impl ChangeListError {
+    /// Encode the Change List error
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        encode_error_with_element_number(
+            buffer,
+            self.error_class,
+            self.error_code,
+            self.first_failed_element_number,
+        )
     }
-}
 
-/// Atomic Read File response (confirmed service)
-#[derive(Debug, Clone)]
-pub struct AtomicReadFileResponse {
-    /// End of file flag
-    pub end_of_file: bool,
-    /// Access method and data
-    pub access_method_result: FileAccessMethodResult,
+    /// Decode a Change List error
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let (error_class, error_code, first_failed_element_number) =
+            decode_error_with_element_number(data)?;
+        Ok(Self {
+            error_class,
+            error_code,
+            first_failed_element_number,
+        })
+    }
 }
 
-/// File access method result for atomic read response
-#[derive(Debug, Clone)]
-pub enum FileAccessMethodResult {
-    /// Stream access result
-    StreamAccess {
-        /// File position after read
-        file_start_position: i32,
-        /// File data read
-        file_data: Vec<u8>,
+/// Range of items requested by Read Range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeSpec {
+    /// `count` items starting at the 1-based `reference_index`; a negative
+    /// count reads backwards
+    ByPosition { reference_index: u32, count: i16 },
+    /// `count` log records starting at `reference_sequence_number`
+    BySequenceNumber {
+        reference_sequence_number: u32,
+        count: i16,
     },
-    /// Record access result
-    RecordAccess {
-        /// Starting record number
-        file_start_record: i32,
-        /// Number of records returned
-        record_count: u32,
-        /// Record data
-        file_record_data: Vec<Vec<u8>>,
+    /// `count` log records logged after (or, for a negative count, before)
+    /// `reference_time`
+    ByTime {
+        reference_time: BacnetDateTime,
+        count: i16,
     },
 }
 
-impl AtomicReadFileResponse {
-    /// Create a new stream access response
-    pub fn new_stream_access(end_of_file: bool, start_position: i32, data: Vec<u8>) -> Self {
+/// Read Range request (confirmed service)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadRangeRequest {
+    /// Object identifier
+    pub object_identifier: ObjectIdentifier,
+    /// Property identifier
+    pub property_identifier: PropertyIdentifier,
+    /// Property array index (optional)
+    pub property_array_index: Option<u32>,
+    /// Items to read (optional, the whole list if absent)
+    pub range: Option<RangeSpec>,
+}
+
+impl ReadRangeRequest {
+    /// Create a new Read Range request for a whole list
+    pub fn new(
+        object_identifier: ObjectIdentifier,
+        property_identifier: PropertyIdentifier,
+    ) -> Self {
         Self {
-            end_of_file,
-            access_method_result: FileAccessMethodResult::StreamAccess {
-                file_start_position: start_position,
-                file_data: data,
-            },
+            object_identifier,
+            property_identifier,
+            property_array_index: None,
+            range: None,
         }
     }
 
-    /// Create a new record access response
-    pub fn new_record_access(end_of_file: bool, start_record: i32, records: Vec<Vec<u8>>) -> Self {
-        let record_count = records.len() as u32;
+    /// Create a new Read Range request for `count` items from a position
+    pub fn by_position(
+        object_identifier: ObjectIdentifier,
+        property_identifier: PropertyIdentifier,
+        reference_index: u32,
+        count: i16,
+    ) -> Self {
         Self {
-            end_of_file,
-            access_method_result: FileAccessMethodResult::RecordAccess {
-                file_start_record: start_record,
-                record_count,
-                file_record_data: records,
-            },
+            range: Some(RangeSpec::ByPosition {
+                reference_index,
+                count,
+            }),
+            ..Self::new(object_identifier, property_identifier)
         }
     }
-}
-
-/// Atomic Write File request (confirmed service)
-#[derive(Debug, Clone)]
-pub struct AtomicWriteFileRequest {
-    /// File object identifier
-    pub file_identifier: ObjectIdentifier,
-    /// Access method and data
-    pub access_method: FileWriteAccessMethod,
-}
-
-/// File write access method for atomic write
-#[derive(Debug, Clone)]
-pub enum FileWriteAccessMethod {
-    /// Stream access - write bytes at position
-    StreamAccess {
-        /// File position to start writing
-        file_start_position: i32,
-        /// Data to write
-        file_data: Vec<u8>,
-    },
-    /// Record access - write records
-    RecordAccess {
-        /// Starting record number
-        file_start_record: i32,
-        /// Number of records to write
-        record_count: u32,
-        /// Record data to write
-        file_record_data: Vec<Vec<u8>>,
-    },
-}
 
-impl AtomicWriteFileRequest {
-    /// Create a new Atomic Write File request with stream access
-    pub fn new_stream_access(
-        file_identifier: ObjectIdentifier,
-        start_position: i32,
-        data: Vec<u8>,
+    /// Create a new Read Range request for `count` log records from a
+    /// sequence number
+    pub fn by_sequence_number(
+        object_identifier: ObjectIdentifier,
+        property_identifier: PropertyIdentifier,
+        reference_sequence_number: u32,
+        count: i16,
     ) -> Self {
         Self {
-            file_identifier,
-            access_method: FileWriteAccessMethod::StreamAccess {
-                file_start_position: start_position,
-                file_data: data,
-            },
+            range: Some(RangeSpec::BySequenceNumber {
+                reference_sequence_number,
+                count,
+            }),
+            ..Self::new(object_identifier, property_identifier)
         }
     }
 
-    /// Create a new Atomic Write File request with record access
-    pub fn new_record_access(
-        file_identifier: ObjectIdentifier,
-        start_record: i32,
-        records: Vec<Vec<u8>>,
+    /// Create a new Read Range request for `count` log records around a time
+    pub fn by_time(
+        object_identifier: ObjectIdentifier,
+        property_identifier: PropertyIdentifier,
+        reference_time: BacnetDateTime,
+        count: i16,
     ) -> Self {
-        let record_count = records.len() as u32;
         Self {
-            file_identifier,
-            access_method: FileWriteAccessMethod::RecordAccess {
-                file_start_record: start_record,
-                record_count,
-                file_record_data: records,
-            },
+            range: Some(RangeSpec::ByTime {
+                reference_time,
+                count,
+            }),
+            ..Self::new(object_identifier, property_identifier)
         }
     }
 
-    /// Encode the Atomic Write File request
+    /// Encode the Read Range request
     pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
-        // File identifier - context tag 0
-        let file_id: u32 = self.file_identifier.try_into()?;
-        buffer.push(0x0C); // Context tag 0, length 4
-        buffer.extend_from_slice(&file_id.to_be_bytes());
+        // Object identifier - context tag 0
+        buffer.extend_from_slice(&encode_context_object_id(self.object_identifier, 0)?);
 
-        // Access method - context tag 1 (opening tag)
-        buffer.push(0x1E); // Context tag 1, opening tag
+        // Property identifier - context tag 1
+        buffer.extend_from_slice(&encode_context_enumerated(
+            self.property_identifier.into(),
+            1,
+        )?);
 
-        match &self.access_method {
-            FileWriteAccessMethod::StreamAccess {
-                file_start_position,
-                file_data,
-            } => {
-                // Stream access - context tag 0 (opening tag)
-                buffer.push(0x0E); // Context tag 0, opening tag
+        // Property array index - context tag 2 (optional)
+        if let Some(array_index) = self.property_array_index {
+            buffer.extend_from_slice(&encode_context_unsigned(array_index, 2)?);
+        }
 
-                // File start position - context tag 0
-                buffer.push(0x09); // Context tag 0, length 1
-                buffer.extend_from_slice(&file_start_position.to_be_bytes());
-
-                // File data - context tag 1 (opening tag)
-                buffer.push(0x1E); // Context tag 1, opening tag
-                buffer.extend_from_slice(file_data);
-                buffer.push(0x1F); // Context tag 1, closing tag
-
-                buffer.push(0x0F); // Context tag 0, closing tag
+        match self.range {
+            // By position - context tag 3 (constructed)
+            Some(RangeSpec::ByPosition {
+                reference_index,
+                count,
+            }) => {
+                encode_opening_tag(buffer, 3)?;
+                write_unsigned(buffer, reference_index)?;
+                write_signed(buffer, count as i32)?;
+                encode_closing_tag(buffer, 3)?;
             }
-            FileWriteAccessMethod::RecordAccess {
-                file_start_record,
-                record_count: _,
-                file_record_data,
-            } => {
-                // Record access - context tag 1 (opening tag)
-                buffer.push(0x1E); // Context tag 1, opening tag
-
-                // File start record - context tag 0
-                buffer.push(0x09); // Context tag 0, length 1
-                buffer.extend_from_slice(&file_start_record.to_be_bytes());
-
-                // Record count - context tag 1
-                let record_count = file_record_data.len() as u32;
-                buffer.push(0x19); // Context tag 1, length 1
-                buffer.extend_from_slice(&record_count.to_be_bytes());
-
-                // File record data - context tag 2 (opening tag)
-                buffer.push(0x2E); // Context tag 2, opening tag
-                for record in file_record_data {
-                    // Each record as octet string
-                    buffer.push(0x65); // Application tag 6 (OctetString), length depends on record size
-                    buffer.push(record.len() as u8);
-                    buffer.extend_from_slice(record);
-                }
-                buffer.push(0x2F); // Context tag 2, closing tag
-
-                buffer.push(0x1F); // Context tag 1, closing tag
+            // By sequence number - context tag 6 (constructed)
+            Some(RangeSpec::BySequenceNumber {
+                reference_sequence_number,
+                count,
+            }) => {
+                encode_opening_tag(buffer, 6)?;
+                write_unsigned(buffer, reference_sequence_number)?;
+                write_signed(buffer, count as i32)?;
+                encode_closing_tag(buffer, 6)?;
             }
+            // By time - context tag 7 (constructed)
+            Some(RangeSpec::ByTime {
+                reference_time,
+                count,
+            }) => {
+                encode_opening_tag(buffer, 7)?;
+                reference_time.encode(buffer)?;
+                write_signed(buffer, count as i32)?;
+                encode_closing_tag(buffer, 7)?;
+            }
+            None => {}
         }
 
-        buffer.push(0x1F); // Context tag 1, closing tag
-
         Ok(())
     }
 }
 
-/// Atomic Write File response (confirmed service)
-#[derive(Debug, Clone)]
-pub struct AtomicWriteFileResponse {
-    /// File start position (for stream access) or start record (for record access)
-    pub file_start_position: i32,
-}
-
-/// Time Synchronization request (unconfirmed service)
-#[derive(Debug, Clone)]
-pub struct TimeSynchronizationRequest {
-    /// Date and time to synchronize to
-    pub date_time: BacnetDateTime,
+/// A trend log record (BACnetLogRecord)
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogRecord {
+    /// When the value was logged
+    pub timestamp: BacnetDateTime,
+    /// Logged value or log event
+    pub log_datum: LogDatum,
+    /// Status flags of the logged object (optional)
+    pub status_flags: Option<Vec<bool>>,
 }
 
-/// UTC Time Synchronization request (unconfirmed service)
-#[derive(Debug, Clone)]
-pub struct UtcTimeSynchronizationRequest {
-    /// UTC date and time to synchronize to
-    pub utc_date_time: BacnetDateTime,
+/// Value of a trend log record
+#[derive(Debug, Clone, PartialEq)]
+pub enum LogDatum {
+    /// Change of the log's own status - context tag 0
+    LogStatus(Vec<bool>),
+    /// Context tag 1
+    Boolean(bool),
+    /// Context tag 2
+    Real(f32),
+    /// Context tag 3
+    Enumerated(u32),
+    /// Context tag 4
+    Unsigned(u32),
+    /// Context tag 5
+    Signed(i32),
+    /// Context tag 6
+    BitString(Vec<bool>),
+    /// Context tag 7
+    Null,
+    /// The value could not be read - context tag 8 (constructed)
+    Failure { error_class: u32, error_code: u32 },
+    /// Clock change, in seconds - context tag 9
+    TimeChange(f32),
+    /// Any other value, kept encoded - context tag 10 (constructed)
+    AnyValue(Vec<u8>),
 }
 
-/// BACnet Date and Time structure
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct BacnetDateTime {
-    /// Date component
-    pub date: crate::object::Date,
-    /// Time component
-    pub time: crate::object::Time,
-}
+impl LogRecord {
+    /// Encode the log record
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Timestamp - context tag 0 (constructed)
+        encode_opening_tag(buffer, 0)?;
+        self.timestamp.encode(buffer)?;
+        encode_closing_tag(buffer, 0)?;
+
+        // Log datum - context tag 1 (constructed)
+        encode_opening_tag(buffer, 1)?;
+        self.log_datum.encode(buffer)?;
+        encode_closing_tag(buffer, 1)?;
+
+        // Status flags - context tag 2 (optional)
+        if let Some(status_flags) = &self.status_flags {
+            buffer.extend_from_slice(&encode_context_bit_string(status_flags, 2)?);
+        }
 
-impl BacnetDateTime {
-    /// Create a new BACnet DateTime from Date and Time components
-    pub fn new(date: crate::object::Date, time: crate::object::Time) -> Self {
-        Self { date, time }
+        Ok(())
     }
 
-    /// Create from current system time (requires std feature)
-    #[cfg(feature = "std")]
-    pub fn now() -> Self {
-        use chrono::{Datelike, Local, Timelike};
+    /// Decode a log record, returning it with the number of bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        // Timestamp - context tag 0 (constructed)
+        let mut pos = expect_constructed_tag(data, 0, 6)?;
+        let (timestamp, consumed) = BacnetDateTime::decode(&data[pos..])?;
+        pos += consumed;
+        pos += expect_constructed_tag(&data[pos..], 0, 7)?;
 
-        let now = Local::now();
-        let year = now.year() as u16;
-        let month = now.month() as u8;
-        let day = now.day() as u8;
-        let weekday = now.weekday().number_from_monday() as u8; // BACnet uses 1=Monday
-        let hour = now.hour() as u8;
-        let minute = now.minute() as u8;
-        let second = now.second() as u8;
-        let hundredths = (now.nanosecond() / 10_000_000) as u8;
+        // Log datum - context tag 1 (constructed)
+        pos += expect_constructed_tag(&data[pos..], 1, 6)?;
+        let (log_datum, consumed) = LogDatum::decode(&data[pos..])?;
+        pos += consumed;
+        pos += expect_constructed_tag(&data[pos..], 1, 7)?;
 
-        let date = crate::object::Date {
-            year,
-            month,
-            day,
-            weekday,
-        };
-        let time = crate::object::Time {
-            hour,
-            minute,
-            second,
-            hundredths,
+        // Status flags - context tag 2 (optional)
+        let status_flags = match decode_context_bit_string(&data[pos..], 2) {
+            Ok((bits, consumed)) => {
+                pos += consumed;
+                Some(bits)
+            }
+            Err(_) => None,
         };
-        Self::new(date, time)
-    }
 
-    /// Create unspecified time (all 255 values)
-    pub fn unspecified() -> Self {
-        Self {
-            date: crate::object::Date {
-                year: 255,
-                month: 255,
-                day: 255,
-                weekday: 255,
-            },
-            time: crate::object::Time {
-                hour: 255,
-                minute: 255,
-                second: 255,
-                hundredths: 255,
+        Ok((
+            Self {
+                timestamp,
+                log_datum,
+                status_flags,
             },
-        }
+            pos,
+        ))
     }
 
-    /// Check if this datetime is unspecified
-    pub fn is_unspecified(&self) -> bool {
-        self.date.year == 255
-            && self.date.month == 255
-            && self.date.day == 255
-            && self.date.weekday == 255
-            && self.time.hour == 255
-            && self.time.minute == 255
-            && self.time.second == 255
-            && self.time.hundredths == 255
-    }
+    /// Decode the item data of a Read Range response on a log buffer
+    pub fn decode_list(data: &[u8]) -> EncodingResult<Vec<Self>> {
+        let mut records = Vec::new();
+        let mut pos = 0;
 
-    /// Encode BACnet DateTime
-    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
-        use crate::encoding::{encode_date, encode_time};
+        while pos < data.len() {
+            let (record, consumed) = Self::decode(&data[pos..])?;
+            records.push(record);
+            pos += consumed;
+        }
 
-        // Encode date
-        encode_date(
-            buffer,
-            self.date.year,
-            self.date.month,
-            self.date.day,
-            self.date.weekday,
-        )?;
+        Ok(records)
+    }
+}
 
-        // Encode time
-        encode_time(
-            buffer,
-            self.time.hour,
-            self.time.minute,
-            self.time.second,
-            self.time.hundredths,
-        )?;
+impl LogDatum {
+    /// Encode the log datum
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        match self {
+            LogDatum::LogStatus(bits) => {
+                buffer.extend_from_slice(&encode_context_bit_string(bits, 0)?)
+            }
+            LogDatum::Boolean(value) => {
+                buffer.extend_from_slice(&encode_context_boolean(*value, 1)?)
+            }
+            LogDatum::Real(value) => buffer.extend_from_slice(&encode_context_real(*value, 2)?),
+            LogDatum::Enumerated(value) => {
+                buffer.extend_from_slice(&encode_context_enumerated(*value, 3)?)
+            }
+            LogDatum::Unsigned(value) => {
+                buffer.extend_from_slice(&encode_context_unsigned(*value, 4)?)
+            }
+            LogDatum::Signed(value) => buffer.extend_from_slice(&encode_context_signed(*value, 5)?),
+            LogDatum::BitString(bits) => {
+                buffer.extend_from_slice(&encode_context_bit_string(bits, 6)?)
+            }
+            LogDatum::Null => crate::encoding::write_context_tag(buffer, 7, 0)?,
+            LogDatum::Failure {
+                error_class,
+                error_code,
+            } => {
+                encode_opening_tag(buffer, 8)?;
+                write_enumerated(buffer, *error_class)?;
+                write_enumerated(buffer, *error_code)?;
+                encode_closing_tag(buffer, 8)?;
+            }
+            LogDatum::TimeChange(value) => {
+                buffer.extend_from_slice(&encode_context_real(*value, 9)?)
+            }
+            LogDatum::AnyValue(data) => {
+                encode_opening_tag(buffer, 10)?;
+                buffer.extend_from_slice(data);
+                encode_closing_tag(buffer, 10)?;
+            }
+        }
 
         Ok(())
     }
 
-    /// Decode BACnet DateTime
+    /// Decode a log datum, returning it with the number of bytes consumed
     pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
-        use crate::encoding::{decode_date, decode_time};
-
-        // Decode date (4 bytes)
-        let ((year, month, day, weekday), consumed_date) = decode_date(data)?;
-
-        // Decode time (4 bytes)
-        let ((hour, minute, second, hundredths), consumed_time) =
-            decode_time(&data[consumed_date..])?;
-
-        let datetime = BacnetDateTime {
-            date: crate::object::Date {
-                year,
-                month,
-                day,
-                weekday,
-            },
-            time: crate::object::Time {
-                hour,
-                minute,
-                second,
-                hundredths,
-            },
-        };
+        let (tag_number, _, consumed) = decode_context_tag(data)?;
+        let opening = data[0] & 0x07 == 6;
 
-        Ok((datetime, consumed_date + consumed_time))
+        match (tag_number, opening) {
+            (0, false) => {
+                let (bits, consumed) = decode_context_bit_string(data, 0)?;
+                Ok((LogDatum::LogStatus(bits), consumed))
+            }
+            (1, false) => {
+                let (value, consumed) = decode_context_boolean(data, 1)?;
+                Ok((LogDatum::Boolean(value), consumed))
+            }
+            (2, false) => {
+                let (value, consumed) = decode_context_real(data, 2)?;
+                Ok((LogDatum::Real(value), consumed))
+            }
+            (3, false) => {
+                let (value, consumed) = decode_context_enumerated(data, 3)?;
+                Ok((LogDatum::Enumerated(value), consumed))
+            }
+            (4, false) => {
+                let (value, consumed) = decode_context_unsigned(data, 4)?;
+                Ok((LogDatum::Unsigned(value), consumed))
+            }
+            (5, false) => {
+                let (value, consumed) = decode_context_signed(data, 5)?;
+                Ok((LogDatum::Signed(value), consumed))
+            }
+            (6, false) => {
+                let (bits, consumed) = decode_context_bit_string(data, 6)?;
+                Ok((LogDatum::BitString(bits), consumed))
+            }
+            (7, false) => Ok((LogDatum::Null, consumed)),
+            (8, true) => {
+                let mut pos = consumed;
+                let (error_class, consumed) = decode_enumerated(&data[pos..])?;
+                pos += consumed;
+                let (error_code, consumed) = decode_enumerated(&data[pos..])?;
+                pos += consumed;
+                pos += expect_constructed_tag(&data[pos..], 8, 7)?;
+                Ok((
+                    LogDatum::Failure {
+                        error_class,
+                        error_code,
+                    },
+                    pos,
+                ))
+            }
+            (9, false) => {
+                let (value, consumed) = decode_context_real(data, 9)?;
+                Ok((LogDatum::TimeChange(value), consumed))
+            }
+            (10, true) => {
+                let length = constructed_value_length(&data[consumed..], 10)?;
+                let value = data[consumed..consumed + length].to_vec();
+                let closing = expect_constructed_tag(&data[consumed + length..], 10, 7)?;
+                Ok((LogDatum::AnyValue(value), consumed + length + closing))
+            }
+            _ => Err(EncodingError::InvalidTag),
+        }
     }
 }
 
-impl TimeSynchronizationRequest {
-    /// Create a new Time Synchronization request
-    pub fn new(date_time: BacnetDateTime) -> Self {
-        Self { date_time }
-    }
-
-    /// Create Time Synchronization request with current time
-    #[cfg(feature = "std")]
-    pub fn now() -> Self {
-        Self::new(BacnetDateTime::now())
-    }
+/// Result flags of a Read Range response (BACnetResultFlags)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResultFlags {
+    /// The first returned item is the first item in the list
+    pub first_item: bool,
+    /// The last returned item is the last item in the list
+    pub last_item: bool,
+    /// More items matched the request than were returned
+    pub more_items: bool,
+}
 
-    /// Encode the Time Synchronization request
-    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
-        self.date_time.encode(buffer)
+impl ResultFlags {
+    /// Build the flags from their bit string, ignoring missing or extra bits
+    pub fn from_bits(bits: &[bool]) -> Self {
+        let bit = |index: usize| bits.get(index).copied().unwrap_or(false);
+        Self {
+            first_item: bit(0),
+            last_item: bit(1),
+            more_items: bit(2),
+        }
     }
 
-    /// Decode a Time Synchronization request
-    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
-        let (date_time, _consumed) = BacnetDateTime::decode(data)?;
-        Ok(Self::new(date_time))
+    /// The flags as a bit string
+    pub fn to_bits(self) -> Vec<bool> {
+        vec![self.first_item, self.last_item, self.more_items]
     }
 }
 
-impl UtcTimeSynchronizationRequest {
-    /// Create a new UTC Time Synchronization request
-    pub fn new(utc_date_time: BacnetDateTime) -> Self {
-        Self { utc_date_time }
-    }
+/// Read Range response (complex ACK)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadRangeResponse {
+    /// Object identifier
+    pub object_identifier: ObjectIdentifier,
+    /// Property identifier
+    pub property_identifier: PropertyIdentifier,
+    /// Property array index (optional)
+    pub property_array_index: Option<u32>,
+    /// Result flags
+    pub result_flags: ResultFlags,
+    /// Number of items returned
+    pub item_count: u32,
+    /// Encoded items, without the enclosing tags
+    pub item_data: Vec<u8>,
+    /// Sequence number of the first item (optional, for by-sequence and
+    /// by-time requests)
+    pub first_sequence_number: Option<u32>,
+}
 
-    /// Create UTC Time Synchronization request with current UTC time
-    #[cfg(feature = "std")]
-    pub fn now() -> Self {
-        use chrono::{Datelike, Timelike, Utc};
+impl ReadRangeResponse {
+    /// Encode the Read Range response
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Object identifier - context tag 0
+        buffer.extend_from_slice(&encode_context_object_id(self.object_identifier, 0)?);
 
-        let now = Utc::now();
-        let year = now.year() as u16;
-        let month = now.month() as u8;
-        let day = now.day() as u8;
-        let weekday = now.weekday().number_from_monday() as u8;
-        let hour = now.hour() as u8;
-        let minute = now.minute() as u8;
-        let second = now.second() as u8;
-        let hundredths = (now.nanosecond() / 10_000_000) as u8;
+        // Property identifier - context tag 1
+        buffer.extend_from_slice(&encode_context_enumerated(
+            self.property_identifier.into(),
+            1,
+        )?);
+
+        // Property array index - context tag 2 (optional)
+        if let Some(array_index) = self.property_array_index {
+            buffer.extend_from_slice(&encode_context_unsigned(array_index, 2)?);
+        }
+
+        // Result flags - context tag 3
+        buffer.extend_from_slice(&encode_context_bit_string(&self.result_flags.to_bits(), 3)?);
+
+        // Item count - context tag 4
+        buffer.extend_from_slice(&encode_context_unsigned(self.item_count, 4)?);
+
+        // Item data - context tag 5 (constructed)
+        encode_opening_tag(buffer, 5)?;
+        buffer.extend_from_slice(&self.item_data);
+        encode_closing_tag(buffer, 5)?;
+
+        // First sequence number - context tag 6 (optional)
+        if let Some(sequence_number) = self.first_sequence_number {
+            buffer.extend_from_slice(&encode_context_unsigned(sequence_number, 6)?);
+        }
+
+        Ok(())
+    }
+
+    /// Decode a Read Range response
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        // Object identifier - context tag 0
+        let (object_identifier, mut pos) = decode_context_object_id(data, 0)?;
+
+        // Property identifier - context tag 1
+        let (property_identifier, consumed) = decode_context_enumerated(&data[pos..], 1)?;
+        pos += consumed;
+
+        // Property array index - context tag 2 (optional)
+        let property_array_index = match decode_context_unsigned(&data[pos..], 2) {
+            Ok((array_index, consumed)) => {
+                pos += consumed;
+                Some(array_index)
+            }
+            Err(_) => None,
+        };
+
+        // Result flags - context tag 3
+        let (bits, consumed) = decode_context_bit_string(&data[pos..], 3)?;
+        let result_flags = ResultFlags::from_bits(&bits);
+        pos += consumed;
+
+        // Item count - context tag 4
+        let (item_count, consumed) = decode_context_unsigned(&data[pos..], 4)?;
+        pos += consumed;
+
+        // Item data - context tag 5 (constructed)
+        pos += expect_constructed_tag(&data[pos..], 5, 6)?;
+        let length = constructed_value_length(&data[pos..], 5)?;
+        let item_data = data[pos..pos + length].to_vec();
+        pos += length;
+        pos += expect_constructed_tag(&data[pos..], 5, 7)?;
+
+        // First sequence number - context tag 6 (optional)
+        let first_sequence_number = if pos < data.len() {
+            let (sequence_number, _) = decode_context_unsigned(&data[pos..], 6)?;
+            Some(sequence_number)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            object_identifier,
+            property_identifier: PropertyIdentifier::from(property_identifier),
+            property_array_index,
+            result_flags,
+            item_count,
+            item_data,
+            first_sequence_number,
+        })
+    }
+}
+
+/// Subscribe COV request (confirmed service)
+#[derive(Debug, Clone)]
+pub struct SubscribeCovRequest {
+    /// Subscriber process identifier
+    pub subscriber_process_identifier: u32,
+    /// Monitored object identifier
+    pub monitored_object_identifier: ObjectIdentifier,
+    /// Issue confirmed notifications
+    pub issue_confirmed_notifications: Option<bool>,
+    /// Lifetime (seconds, 0 = permanent)
+    pub lifetime: Option<u32>,
+}
+
+impl SubscribeCovRequest {
+    /// Create a new Subscribe COV request
+    pub fn new(
+        subscriber_process_identifier: u32,
+        monitored_object_identifier: ObjectIdentifier,
+    ) -> Self {
+        Self {
+            subscriber_process_identifier,
+            monitored_object_identifier,
+            issue_confirmed_notifications: None,
+            lifetime: None,
+        }
+    }
+
+    /// Create a new Subscribe COV request with confirmation preference
+    pub fn with_confirmation(
+        subscriber_process_identifier: u32,
+        monitored_object_identifier: ObjectIdentifier,
+        issue_confirmed_notifications: bool,
+    ) -> Self {
+        Self {
+            subscriber_process_identifier,
+            monitored_object_identifier,
+            issue_confirmed_notifications: Some(issue_confirmed_notifications),
+            lifetime: None,
+        }
+    }
+
+    /// Create a new Subscribe COV request with lifetime
+    pub fn with_lifetime(
+        subscriber_process_identifier: u32,
+        monitored_object_identifier: ObjectIdentifier,
+        lifetime: u32,
+    ) -> Self {
+        Self {
+            subscriber_process_identifier,
+            monitored_object_identifier,
+            issue_confirmed_notifications: None,
+            lifetime: Some(lifetime),
+        }
+    }
+
+    /// Encode the Subscribe COV request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Subscriber process identifier - context tag 0
+        buffer.extend_from_slice(&encode_context_unsigned(
+            self.subscriber_process_identifier,
+            0,
+        )?);
+
+        // Monitored object identifier - context tag 1
+        buffer.extend_from_slice(&encode_context_object_id(
+            self.monitored_object_identifier,
+            1,
+        )?);
+
+        // Issue confirmed notifications - context tag 2 (optional)
+        if let Some(confirmed) = self.issue_confirmed_notifications {
+            buffer.extend_from_slice(&encode_context_boolean(confirmed, 2)?);
+        }
+
+        // Lifetime - context tag 3 (optional)
+        if let Some(lifetime) = self.lifetime {
+            buffer.extend_from_slice(&encode_context_unsigned(lifetime, 3)?);
+        }
+
+        Ok(())
+    }
+}
+
+/// Subscribe COV Property request (confirmed service)
+#[derive(Debug, Clone)]
+pub struct SubscribeCovPropertyRequest {
+    /// Subscriber process identifier
+    pub subscriber_process_identifier: u32,
+    /// Monitored object identifier
+    pub monitored_object_identifier: ObjectIdentifier,
+    /// Issue confirmed notifications
+    pub issue_confirmed_notifications: Option<bool>,
+    /// Lifetime (seconds, 0 = permanent)
+    pub lifetime: Option<u32>,
+    /// Monitored property reference
+    pub monitored_property: PropertyReference,
+    /// COV increment (optional)
+    pub cov_increment: Option<f32>,
+}
+
+impl SubscribeCovPropertyRequest {
+    /// Create a new Subscribe COV Property request
+    pub fn new(
+        subscriber_process_identifier: u32,
+        monitored_object_identifier: ObjectIdentifier,
+        monitored_property: PropertyReference,
+    ) -> Self {
+        Self {
+            subscriber_process_identifier,
+            monitored_object_identifier,
+            issue_confirmed_notifications: None,
+            lifetime: None,
+            monitored_property,
+            cov_increment: None,
+        }
+    }
+
+    /// Set COV increment
+    pub fn with_cov_increment(mut self, increment: f32) -> Self {
+        self.cov_increment = Some(increment);
+        self
+    }
+
+    /// Encode the Subscribe COV Property request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Subscriber process identifier - context tag 0
+        buffer.extend_from_slice(&encode_context_unsigned(
+            self.subscriber_process_identifier,
+            0,
+        )?);
+
+        // Monitored object identifier - context tag 1
+        buffer.extend_from_slice(&encode_context_object_id(
+            self.monitored_object_identifier,
+            1,
+        )?);
+
+        // Issue confirmed notifications - context tag 2 (optional)
+        if let Some(confirmed) = self.issue_confirmed_notifications {
+            buffer.extend_from_slice(&encode_context_boolean(confirmed, 2)?);
+        }
+
+        // Lifetime - context tag 3 (optional)
+        if let Some(lifetime) = self.lifetime {
+            buffer.extend_from_slice(&encode_context_unsigned(lifetime, 3)?);
+        }
+
+        // Monitored property reference - context tag 4 (constructed)
+        encode_opening_tag(buffer, 4)?;
+        self.monitored_property.encode(buffer)?;
+        encode_closing_tag(buffer, 4)?;
+
+        // COV increment - context tag 5 (optional)
+        if let Some(increment) = self.cov_increment {
+            buffer.extend_from_slice(&encode_context_real(increment, 5)?);
+        }
+
+        Ok(())
+    }
+}
+
+/// COV Notification request (unconfirmed service)
+#[derive(Debug, Clone)]
+pub struct CovNotificationRequest {
+    /// Subscriber process identifier
+    pub subscriber_process_identifier: u32,
+    /// Initiating device identifier
+    pub initiating_device_identifier: ObjectIdentifier,
+    /// Monitored object identifier
+    pub monitored_object_identifier: ObjectIdentifier,
+    /// Time remaining (seconds)
+    pub time_remaining: u32,
+    /// List of values (property-value pairs)
+    pub list_of_values: Vec<BacnetPropertyValue>,
+}
+
+impl CovNotificationRequest {
+    /// Create a new COV Notification request
+    pub fn new(
+        subscriber_process_identifier: u32,
+        initiating_device_identifier: ObjectIdentifier,
+        monitored_object_identifier: ObjectIdentifier,
+        time_remaining: u32,
+        list_of_values: Vec<BacnetPropertyValue>,
+    ) -> Self {
+        Self {
+            subscriber_process_identifier,
+            initiating_device_identifier,
+            monitored_object_identifier,
+            time_remaining,
+            list_of_values,
+        }
+    }
+
+    /// Encode the COV Notification request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Subscriber process identifier - context tag 0
+        buffer.extend_from_slice(&encode_context_unsigned(
+            self.subscriber_process_identifier,
+            0,
+        )?);
+
+        // Initiating device identifier - context tag 1
+        buffer.extend_from_slice(&encode_context_object_id(
+            self.initiating_device_identifier,
+            1,
+        )?);
+
+        // Monitored object identifier - context tag 2
+        buffer.extend_from_slice(&encode_context_object_id(
+            self.monitored_object_identifier,
+            2,
+        )?);
+
+        // Time remaining - context tag 3
+        buffer.extend_from_slice(&encode_context_unsigned(self.time_remaining, 3)?);
+
+        // List of values - context tag 4 (constructed)
+        encode_opening_tag(buffer, 4)?;
+        for value in &self.list_of_values {
+            value.encode(buffer)?;
+        }
+        encode_closing_tag(buffer, 4)?;
+
+        Ok(())
+    }
+
+    /// Decode a COV Notification request from its service data
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        // Subscriber process identifier - context tag 0
+        let (subscriber_process_identifier, mut pos) = decode_context_unsigned(data, 0)?;
+
+        // Initiating device identifier - context tag 1
+        let (initiating_device_identifier, consumed) = decode_context_object_id(&data[pos..], 1)?;
+        pos += consumed;
+
+        // Monitored object identifier - context tag 2
+        let (monitored_object_identifier, consumed) = decode_context_object_id(&data[pos..], 2)?;
+        pos += consumed;
+
+        // Time remaining - context tag 3
+        let (time_remaining, consumed) = decode_context_unsigned(&data[pos..], 3)?;
+        pos += consumed;
+
+        // List of values - context tag 4 (constructed)
+        pos += expect_constructed_tag(&data[pos..], 4, 6)?;
+        let end = pos + constructed_value_length(&data[pos..], 4)?;
+        let mut list_of_values = Vec::new();
+        while pos < end {
+            let (value, consumed) = BacnetPropertyValue::decode(&data[pos..end])?;
+            list_of_values.push(value);
+            pos += consumed;
+        }
+        expect_constructed_tag(&data[end..], 4, 7)?;
+
+        Ok(Self {
+            subscriber_process_identifier,
+            initiating_device_identifier,
+            monitored_object_identifier,
+            time_remaining,
+            list_of_values,
+        })
+    }
+}
+
+/// Map an object property value onto the application-tagged value it is
+/// encoded as
+fn to_application_value(value: &PropertyValue) -> property::PropertyValue {
+    match value {
+        PropertyValue::Null => property::PropertyValue::Null,
+        PropertyValue::Boolean(b) => property::PropertyValue::Boolean(*b),
+        PropertyValue::UnsignedInteger(u) => property::PropertyValue::Unsigned(*u as u64),
+        PropertyValue::SignedInt(i) => property::PropertyValue::Signed(*i as i64),
+        PropertyValue::Real(f) => property::PropertyValue::Real(*f),
+        PropertyValue::Double(f) => property::PropertyValue::Double(*f),
+        PropertyValue::OctetString(s) => property::PropertyValue::OctetString(s.clone()),
+        PropertyValue::CharacterString(s) => property::PropertyValue::CharacterString(s.clone()),
+        PropertyValue::BitString(bits) => property::PropertyValue::BitString(bits.clone()),
+        PropertyValue::Enumerated(e) => property::PropertyValue::Enumerated(*e),
+        PropertyValue::Date(d) => property::PropertyValue::Date(d.year, d.month, d.day, d.weekday),
+        PropertyValue::Time(t) => {
+            property::PropertyValue::Time(t.hour, t.minute, t.second, t.hundredths)
+        }
+        PropertyValue::ObjectIdentifier(id) => property::PropertyValue::ObjectIdentifier(*id),
+        PropertyValue::Array(values) => {
+            property::PropertyValue::Array(values.iter().map(to_application_value).collect())
+        }
+        PropertyValue::List(values) => {
+            property::PropertyValue::List(values.iter().map(to_application_value).collect())
+        }
+    }
+}
+
+/// Notification recipient (BACnetRecipient)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Recipient {
+    /// Recipient identified by its device object
+    Device(ObjectIdentifier),
+    /// Recipient identified by its network address
+    Address(NetworkAddress),
+}
+
+impl Recipient {
+    /// Encode the recipient
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        match self {
+            // Device - context tag 0
+            Recipient::Device(device) => {
+                buffer.extend_from_slice(&encode_context_object_id(*device, 0)?);
+            }
+            // Address - context tag 1 (constructed)
+            Recipient::Address(address) => {
+                encode_opening_tag(buffer, 1)?;
+                write_unsigned(buffer, address.network as u32)?;
+                write_octet_string(buffer, &address.address)?;
+                encode_closing_tag(buffer, 1)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decode a recipient, returning it with the bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        let (tag_number, length, consumed) = decode_context_tag(data)?;
+        match (tag_number, length) {
+            (0, 4) => {
+                let (device, consumed) = decode_context_object_id(data, 0)?;
+                Ok((Recipient::Device(device), consumed))
+            }
+            (1, 6) => {
+                let mut pos = consumed;
+                let (network, consumed) = decode_unsigned(&data[pos..])?;
+                pos += consumed;
+                let network = u16::try_from(network).map_err(|_| EncodingError::ValueOutOfRange)?;
+                let (address, consumed) = decode_octet_string(&data[pos..])?;
+                pos += consumed;
+
+                let (tag_number, length, consumed) = decode_context_tag(&data[pos..])?;
+                if tag_number != 1 || length != 7 {
+                    return Err(EncodingError::InvalidTag);
+                }
+                pos += consumed;
+
+                Ok((
+                    Recipient::Address(NetworkAddress::new(network, address)),
+                    pos,
+                ))
+            }
+            _ => Err(EncodingError::InvalidTag),
+        }
+    }
+}
+
+/// Reference to a property of an object (BACnetObjectPropertyReference)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectPropertyReference {
+    /// Object identifier
+    pub object_identifier: ObjectIdentifier,
+    /// Property identifier
+    pub property_identifier: PropertyIdentifier,
+    /// Property array index (optional)
+    pub property_array_index: Option<u32>,
+}
+
+impl ObjectPropertyReference {
+    /// Create a new object property reference
+    pub fn new(
+        object_identifier: ObjectIdentifier,
+        property_identifier: PropertyIdentifier,
+    ) -> Self {
+        Self {
+            object_identifier,
+            property_identifier,
+            property_array_index: None,
+        }
+    }
+
+    /// Encode the object property reference
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Object identifier - context tag 0
+        buffer.extend_from_slice(&encode_context_object_id(self.object_identifier, 0)?);
+
+        // Property identifier - context tag 1
+        buffer.extend_from_slice(&encode_context_enumerated(
+            self.property_identifier.into(),
+            1,
+        )?);
+
+        // Property array index - context tag 2 (optional)
+        if let Some(array_index) = self.property_array_index {
+            buffer.extend_from_slice(&encode_context_unsigned(array_index, 2)?);
+        }
+
+        Ok(())
+    }
+
+    /// Decode an object property reference, returning it with the bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        let (object_identifier, mut pos) = decode_context_object_id(data, 0)?;
+
+        let (property_identifier, consumed) = decode_context_enumerated(&data[pos..], 1)?;
+        pos += consumed;
+
+        let property_array_index = match decode_context_unsigned(&data[pos..], 2) {
+            Ok((array_index, consumed)) => {
+                pos += consumed;
+                Some(array_index)
+            }
+            Err(_) => None,
+        };
+
+        Ok((
+            Self {
+                object_identifier,
+                property_identifier: property_identifier.into(),
+                property_array_index,
+            },
+            pos,
+        ))
+    }
+}
+
+/// Reference to an object, optionally in another device
+/// (BACnetDeviceObjectReference)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceObjectReference {
+    /// Device holding the object, absent for an object in the local device
+    pub device_identifier: Option<ObjectIdentifier>,
+    /// Object identifier
+    pub object_identifier: ObjectIdentifier,
+}
+
+impl DeviceObjectReference {
+    /// Create a reference to an object in the local device
+    pub fn new(object_identifier: ObjectIdentifier) -> Self {
+        Self {
+            device_identifier: None,
+            object_identifier,
+        }
+    }
+
+    /// Create a reference to an object in another device
+    pub fn with_device(
+        device_identifier: ObjectIdentifier,
+        object_identifier: ObjectIdentifier,
+    ) -> Self {
+        Self {
+            device_identifier: Some(device_identifier),
+            object_identifier,
+        }
+    }
+
+    /// Encode the device object reference
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Device identifier - context tag 0 (optional)
+        if let Some(device_identifier) = self.device_identifier {
+            buffer.extend_from_slice(&encode_context_object_id(device_identifier, 0)?);
+        }
+
+        // Object identifier - context tag 1
+        buffer.extend_from_slice(&encode_context_object_id(self.object_identifier, 1)?);
+
+        Ok(())
+    }
+
+    /// Decode a device object reference, returning it with the bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        // Device identifier - context tag 0 (optional)
+        let (device_identifier, mut pos) = match decode_context_object_id(data, 0) {
+            Ok((device_identifier, consumed)) => (Some(device_identifier), consumed),
+            Err(_) => (None, 0),
+        };
+
+        // Object identifier - context tag 1
+        let (object_identifier, consumed) = decode_context_object_id(&data[pos..], 1)?;
+        pos += consumed;
+
+        Ok((
+            Self {
+                device_identifier,
+                object_identifier,
+            },
+            pos,
+        ))
+    }
+
+    /// Encode a list of references, e.g. the value of a Subordinate_List
+    /// property
+    pub fn encode_list(references: &[Self], buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        for reference in references {
+            reference.encode(buffer)?;
+        }
+        Ok(())
+    }
+
+    /// Decode a list of references, e.g. the value of a Subordinate_List
+    /// property
+    pub fn decode_list(data: &[u8]) -> EncodingResult<Vec<Self>> {
+        let mut references = Vec::new();
+        let mut pos = 0;
+
+        while pos < data.len() {
+            let (reference, consumed) = Self::decode(&data[pos..])?;
+            references.push(reference);
+            pos += consumed;
+        }
+
+        Ok(references)
+    }
+}
+
+generate_custom_enum!(
+    /// Lighting operations (BACnetLightingOperation)
+    LightingOperation{
+    None = 0,
+    FadeTo = 1,
+    RampTo = 2,
+    StepUp = 3,
+    StepDown = 4,
+    StepOn = 5,
+    StepOff = 6,
+    Warn = 7,
+    WarnOff = 8,
+    WarnRelinquish = 9,
+    Stop = 10,
+}, u32, 256..=65535);
+
+/// Command written to a Lighting Output's Lighting_Command property
+/// (BACnetLightingCommand)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LightingCommand {
+    /// Operation to perform
+    pub operation: LightingOperation,
+    /// Target level in percent, 0.0 to 100.0 (optional)
+    pub target_level: Option<f32>,
+    /// Ramp rate in percent per second, 0.1 to 100.0 (optional)
+    pub ramp_rate: Option<f32>,
+    /// Step increment in percent, 0.1 to 100.0 (optional)
+    pub step_increment: Option<f32>,
+    /// Fade time in milliseconds, 100 to 86400000 (optional)
+    pub fade_time: Option<u32>,
+    /// Priority (1-16, optional)
+    pub priority: Option<u8>,
+}
+
+impl LightingCommand {
+    /// Create a new lighting command with no optional parameters
+    pub fn new(operation: LightingOperation) -> Self {
+        Self {
+            operation,
+            target_level: None,
+            ramp_rate: None,
+            step_increment: None,
+            fade_time: None,
+            priority: None,
+        }
+    }
+
+    /// Create a command fading to `target_level` over `fade_time` milliseconds
+    pub fn fade_to(target_level: f32, fade_time: u32) -> Self {
+        Self {
+            target_level: Some(target_level),
+            fade_time: Some(fade_time),
+            ..Self::new(LightingOperation::FadeTo)
+        }
+    }
+
+    /// Encode the lighting command
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Operation - context tag 0
+        buffer.extend_from_slice(&encode_context_enumerated(self.operation.into(), 0)?);
+
+        // Target level - context tag 1 (optional)
+        if let Some(target_level) = self.target_level {
+            if !(0.0..=100.0).contains(&target_level) {
+                return Err(EncodingError::ValueOutOfRange);
+            }
+            buffer.extend_from_slice(&encode_context_real(target_level, 1)?);
+        }
+
+        // Ramp rate - context tag 2 (optional)
+        if let Some(ramp_rate) = self.ramp_rate {
+            if !(0.1..=100.0).contains(&ramp_rate) {
+                return Err(EncodingError::ValueOutOfRange);
+            }
+            buffer.extend_from_slice(&encode_context_real(ramp_rate, 2)?);
+        }
+
+        // Step increment - context tag 3 (optional)
+        if let Some(step_increment) = self.step_increment {
+            if !(0.1..=100.0).contains(&step_increment) {
+                return Err(EncodingError::ValueOutOfRange);
+            }
+            buffer.extend_from_slice(&encode_context_real(step_increment, 3)?);
+        }
+
+        // Fade time - context tag 4 (optional)
+        if let Some(fade_time) = self.fade_time {
+            if !(100..=86_400_000).contains(&fade_time) {
+                return Err(EncodingError::ValueOutOfRange);
+            }
+            buffer.extend_from_slice(&encode_context_unsigned(fade_time, 4)?);
+        }
+
+        // Priority - context tag 5 (optional)
+        if let Some(priority) = self.priority {
+            if !(1..=16).contains(&priority) {
+                return Err(EncodingError::ValueOutOfRange);
+            }
+            buffer.extend_from_slice(&encode_context_unsigned(priority as u32, 5)?);
+        }
+
+        Ok(())
+    }
+
+    /// Decode a lighting command, returning it with the number of bytes
+    /// consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        // Operation - context tag 0
+        let (operation, mut pos) = decode_context_enumerated(data, 0)?;
+        let mut command = Self::new(LightingOperation::from(operation));
+
+        // Target level - context tag 1 (optional)
+        if let Ok((target_level, consumed)) = decode_context_real(&data[pos..], 1) {
+            command.target_level = Some(target_level);
+            pos += consumed;
+        }
+
+        // Ramp rate - context tag 2 (optional)
+        if let Ok((ramp_rate, consumed)) = decode_context_real(&data[pos..], 2) {
+            command.ramp_rate = Some(ramp_rate);
+            pos += consumed;
+        }
+
+        // Step increment - context tag 3 (optional)
+        if let Ok((step_increment, consumed)) = decode_context_real(&data[pos..], 3) {
+            command.step_increment = Some(step_increment);
+            pos += consumed;
+        }
+
+        // Fade time - context tag 4 (optional)
+        if let Ok((fade_time, consumed)) = decode_context_unsigned(&data[pos..], 4) {
+            command.fade_time = Some(fade_time);
+            pos += consumed;
+        }
+
+        // Priority - context tag 5 (optional)
+        if let Ok((priority, consumed)) = decode_context_unsigned(&data[pos..], 5) {
+            if !(1..=16).contains(&priority) {
+                return Err(EncodingError::ValueOutOfRange);
+            }
+            command.priority = Some(priority as u8);
+            pos += consumed;
+        }
+
+        Ok((command, pos))
+    }
+}
+
+/// Check for the opening or closing (`length` 6 or 7) tag `tag_number` and
+/// return its size
+fn expect_constructed_tag(data: &[u8], tag_number: u8, length: usize) -> EncodingResult<usize> {
+    let (found_number, found_length, consumed) = decode_context_tag(data)?;
+    if found_number != tag_number || found_length != length {
+        return Err(EncodingError::InvalidTag);
+    }
+    Ok(consumed)
+}
+
+/// COV Subscription information
+#[derive(Debug, Clone)]
+pub struct CovSubscription {
+    /// Subscriber process identifier
+    pub subscriber_process_identifier: u32,
+    /// Subscriber device identifier
+    pub subscriber_device_identifier: ObjectIdentifier,
+    /// Monitored object identifier
+    pub monitored_object_identifier: ObjectIdentifier,
+    /// Monitored property (for COV Property subscriptions)
+    pub monitored_property: Option<PropertyReference>,
+    /// Issue confirmed notifications
+    pub issue_confirmed_notifications: bool,
+    /// Lifetime (seconds, 0 = permanent)
+    pub lifetime: u32,
+    /// Remaining time (seconds)
+    pub time_remaining: u32,
+    /// COV increment (for analog properties)
+    pub cov_increment: Option<f32>,
+    /// Subscriber network address, when the subscriber is identified by
+    /// address rather than by device
+    pub subscriber_address: Option<NetworkAddress>,
+    /// Analog value sent in the last notification, compared against the COV
+    /// increment
+    last_reported_value: Option<f32>,
+    /// Enumerated or unsigned state sent in the last notification, for
+    /// binary and multi-state objects
+    last_reported_state: Option<u32>,
+    /// Value of any other type sent in the last notification
+    last_reported_other: Option<PropertyValue>,
+    /// Status_Flags sent in the last notification
+    last_reported_status_flags: Option<PropertyValue>,
+}
+
+impl CovSubscription {
+    /// Create a new COV subscription
+    pub fn new(
+        subscriber_process_identifier: u32,
+        subscriber_device_identifier: ObjectIdentifier,
+        monitored_object_identifier: ObjectIdentifier,
+        lifetime: u32,
+    ) -> Self {
+        Self {
+            subscriber_process_identifier,
+            subscriber_device_identifier,
+            monitored_object_identifier,
+            monitored_property: None,
+            issue_confirmed_notifications: false,
+            lifetime,
+            time_remaining: lifetime,
+            cov_increment: None,
+            subscriber_address: None,
+            last_reported_value: None,
+            last_reported_state: None,
+            last_reported_other: None,
+            last_reported_status_flags: None,
+        }
+    }
+
+    /// Analog value sent in the last notification, if any
+    pub fn last_reported_value(&self) -> Option<f32> {
+        self.last_reported_value
+    }
+
+    /// Enumerated or unsigned state sent in the last notification, if any
+    pub fn last_reported_state(&self) -> Option<u32> {
+        self.last_reported_state
+    }
+
+    /// Status_Flags sent in the last notification, if any
+    pub fn last_reported_status_flags(&self) -> Option<&PropertyValue> {
+        self.last_reported_status_flags.as_ref()
+    }
+
+    /// The recipient notifications are sent to
+    pub fn recipient(&self) -> Recipient {
+        match &self.subscriber_address {
+            Some(address) => Recipient::Address(address.clone()),
+            None => Recipient::Device(self.subscriber_device_identifier),
+        }
+    }
+
+    /// Encode as a BACnetCOVSubscription, as listed in the
+    /// active-cov-subscriptions property
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Recipient process - context tag 0 (constructed)
+        encode_opening_tag(buffer, 0)?;
+        encode_opening_tag(buffer, 0)?;
+        self.recipient().encode(buffer)?;
+        encode_closing_tag(buffer, 0)?;
+        buffer.extend_from_slice(&encode_context_unsigned(
+            self.subscriber_process_identifier,
+            1,
+        )?);
+        encode_closing_tag(buffer, 0)?;
+
+        // Monitored property reference - context tag 1 (constructed)
+        let property = self
+            .monitored_property
+            .clone()
+            .unwrap_or_else(|| PropertyReference::new(PropertyIdentifier::PresentValue));
+        let reference = ObjectPropertyReference {
+            object_identifier: self.monitored_object_identifier,
+            property_identifier: property.property_identifier,
+            property_array_index: property.property_array_index,
+        };
+        encode_opening_tag(buffer, 1)?;
+        reference.encode(buffer)?;
+        encode_closing_tag(buffer, 1)?;
+
+        // Issue confirmed notifications - context tag 2
+        buffer.extend_from_slice(&encode_context_boolean(
+            self.issue_confirmed_notifications,
+            2,
+        )?);
+
+        // Time remaining - context tag 3
+        buffer.extend_from_slice(&encode_context_unsigned(self.time_remaining, 3)?);
+
+        // COV increment - context tag 4 (optional)
+        if let Some(increment) = self.cov_increment {
+            buffer.extend_from_slice(&encode_context_real(increment, 4)?);
+        }
+
+        Ok(())
+    }
+
+    /// Decode a BACnetCOVSubscription, returning it with the bytes consumed
+    ///
+    /// The lifetime is not part of the encoding, so it is set to the time
+    /// remaining. Subscribers identified by address get the wildcard device
+    /// instance 4194303 and their address in `subscriber_address`.
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        // Recipient process - context tag 0 (constructed)
+        let mut pos = expect_constructed_tag(data, 0, 6)?;
+        pos += expect_constructed_tag(&data[pos..], 0, 6)?;
+        let (recipient, consumed) = Recipient::decode(&data[pos..])?;
+        pos += consumed;
+        pos += expect_constructed_tag(&data[pos..], 0, 7)?;
+        let (subscriber_process_identifier, consumed) = decode_context_unsigned(&data[pos..], 1)?;
+        pos += consumed;
+        pos += expect_constructed_tag(&data[pos..], 0, 7)?;
+
+        // Monitored property reference - context tag 1 (constructed)
+        pos += expect_constructed_tag(&data[pos..], 1, 6)?;
+        let (reference, consumed) = ObjectPropertyReference::decode(&data[pos..])?;
+        pos += consumed;
+        pos += expect_constructed_tag(&data[pos..], 1, 7)?;
+
+        // Issue confirmed notifications - context tag 2
+        let (issue_confirmed_notifications, consumed) = decode_context_boolean(&data[pos..], 2)?;
+        pos += consumed;
+
+        // Time remaining - context tag 3
+        let (time_remaining, consumed) = decode_context_unsigned(&data[pos..], 3)?;
+        pos += consumed;
+
+        // COV increment - context tag 4 (optional)
+        let cov_increment = match decode_context_real(&data[pos..], 4) {
+            Ok((increment, consumed)) => {
+                pos += consumed;
+                Some(increment)
+            }
+            Err(_) => None,
+        };
+
+        let (subscriber_device_identifier, subscriber_address) = match recipient {
+            Recipient::Device(device) => (device, None),
+            Recipient::Address(address) => (
+                ObjectIdentifier::new(ObjectType::Device, 0x3FFFFF),
+                Some(address),
+            ),
+        };
+
+        Ok((
+            Self {
+                subscriber_process_identifier,
+                subscriber_device_identifier,
+                monitored_object_identifier: reference.object_identifier,
+                monitored_property: Some(PropertyReference {
+                    property_identifier: reference.property_identifier,
+                    property_array_index: reference.property_array_index,
+                }),
+                issue_confirmed_notifications,
+                lifetime: time_remaining,
+                time_remaining,
+                cov_increment,
+                subscriber_address,
+                last_reported_value: None,
+                last_reported_state: None,
+                last_reported_other: None,
+                last_reported_status_flags: None,
+            },
+            pos,
+        ))
+    }
+
+    /// Decode the value of an active-cov-subscriptions property
+    pub fn decode_list(data: &[u8]) -> EncodingResult<Vec<Self>> {
+        let mut subscriptions = Vec::new();
+        let mut pos = 0;
+
+        while pos < data.len() {
+            let (subscription, consumed) = Self::decode(&data[pos..])?;
+            subscriptions.push(subscription);
+            pos += consumed;
+        }
+
+        Ok(subscriptions)
+    }
+
+    /// Check if subscription has expired
+    pub fn is_expired(&self) -> bool {
+        self.lifetime > 0 && self.time_remaining == 0
+    }
+
+    /// Update time remaining (should be called periodically)
+    pub fn update_time(&mut self, elapsed_seconds: u32) {
+        if self.lifetime > 0 {
+            self.time_remaining = self.time_remaining.saturating_sub(elapsed_seconds);
+        }
+    }
+}
+
+/// COV Subscription manager
+#[derive(Debug, Default)]
+pub struct CovSubscriptionManager {
+    /// List of active subscriptions
+    subscriptions: Vec<CovSubscription>,
+}
+
+impl CovSubscriptionManager {
+    /// Create a new COV subscription manager
+    pub fn new() -> Self {
+        Self {
+            subscriptions: Vec::new(),
+        }
+    }
+
+    /// Add a new subscription
+    pub fn add_subscription(&mut self, subscription: CovSubscription) {
+        // Remove any existing subscription for the same object and subscriber
+        self.subscriptions.retain(|s| {
+            !(s.subscriber_device_identifier == subscription.subscriber_device_identifier
+                && s.subscriber_process_identifier == subscription.subscriber_process_identifier
+                && s.monitored_object_identifier == subscription.monitored_object_identifier)
+        });
+
+        self.subscriptions.push(subscription);
+    }
+
+    /// Remove a subscription
+    pub fn remove_subscription(
+        &mut self,
+        subscriber_device: ObjectIdentifier,
+        subscriber_process: u32,
+        monitored_object: ObjectIdentifier,
+    ) {
+        self.subscriptions.retain(|s| {
+            !(s.subscriber_device_identifier == subscriber_device
+                && s.subscriber_process_identifier == subscriber_process
+                && s.monitored_object_identifier == monitored_object)
+        });
+    }
+
+    /// Get all subscriptions for a monitored object
+    pub fn get_subscriptions_for_object(
+        &self,
+        object_id: ObjectIdentifier,
+    ) -> Vec<&CovSubscription> {
+        self.subscriptions
+            .iter()
+            .filter(|s| s.monitored_object_identifier == object_id && !s.is_expired())
+            .collect()
+    }
+
+    /// Remove expired subscriptions
+    pub fn cleanup_expired(&mut self) {
+        self.subscriptions.retain(|s| !s.is_expired());
+    }
+
+    /// Update all subscription timers
+    pub fn update_timers(&mut self, elapsed_seconds: u32) {
+        for subscription in &mut self.subscriptions {
+            subscription.update_time(elapsed_seconds);
+        }
+    }
+
+    /// Get total number of active subscriptions
+    pub fn active_count(&self) -> usize {
+        self.subscriptions
+            .iter()
+            .filter(|s| !s.is_expired())
+            .count()
+    }
+
+    /// Build the COV notifications for a new value of a monitored object
+    ///
+    /// `new_values` holds the monitored property's value, Present_Value
+    /// unless the subscription names another, optionally followed by the
+    /// object's Status_Flags. A Real value is only reported to a subscriber
+    /// once it has moved by at least the subscription's COV increment since
+    /// the value last reported to it, and other values once they differ from
+    /// the last reported value. A change of Status_Flags is always reported,
+    /// as is the first value after subscribing.
+    pub fn generate_notifications(
+        &mut self,
+        object_id: ObjectIdentifier,
+        new_values: &[PropertyValue],
+        device_id: u32,
+    ) -> Vec<CovNotificationRequest> {
+        let Some(value) = new_values.first() else {
+            return Vec::new();
+        };
+        let initiating_device = ObjectIdentifier::new(ObjectType::Device, device_id);
+
+        let mut notifications = Vec::new();
+        for subscription in &mut self.subscriptions {
+            if subscription.monitored_object_identifier != object_id || subscription.is_expired() {
+                continue;
+            }
+
+            let value_changed = match value {
+                PropertyValue::Real(new_value) => {
+                    match (subscription.last_reported_value, subscription.cov_increment) {
+                        (None, _) => true,
+                        (Some(last), Some(increment)) => (new_value - last).abs() >= increment,
+                        (Some(last), None) => *new_value != last,
+                    }
+                }
+                PropertyValue::Enumerated(state) | PropertyValue::UnsignedInteger(state) => {
+                    subscription.last_reported_state != Some(*state)
+                }
+                other => subscription.last_reported_other.as_ref() != Some(other),
+            };
+            let status_flags = new_values.get(1);
+            let flags_changed = status_flags.is_some_and(|flags| {
+                subscription.last_reported_status_flags.as_ref() != Some(flags)
+            });
+            if !value_changed && !flags_changed {
+                continue;
+            }
+
+            match value {
+                PropertyValue::Real(new_value) => {
+                    subscription.last_reported_value = Some(*new_value)
+                }
+                PropertyValue::Enumerated(state) | PropertyValue::UnsignedInteger(state) => {
+                    subscription.last_reported_state = Some(*state)
+                }
+                other => subscription.last_reported_other = Some(other.clone()),
+            }
+            if let Some(flags) = status_flags {
+                subscription.last_reported_status_flags = Some(flags.clone());
+            }
+
+            let property = subscription
+                .monitored_property
+                .clone()
+                .unwrap_or_else(|| PropertyReference::new(PropertyIdentifier::PresentValue));
+            let mut list_of_values = vec![BacnetPropertyValue {
+                property_array_index: property.property_array_index,
+                ..BacnetPropertyValue::new(
+                    property.property_identifier,
+                    to_application_value(value),
+                )
+            }];
+            if let Some(status_flags) = new_values.get(1) {
+                list_of_values.push(BacnetPropertyValue::new(
+                    PropertyIdentifier::StatusFlags,
+                    to_application_value(status_flags),
+                ));
+            }
+
+            notifications.push(CovNotificationRequest::new(
+                subscription.subscriber_process_identifier,
+                initiating_device,
+                object_id,
+                subscription.time_remaining,
+                list_of_values,
+            ));
+        }
+
+        notifications
+    }
+}
+
+/// Atomic Read File request (confirmed service)
+#[derive(Debug, Clone)]
+pub struct AtomicReadFileRequest {
+    /// File object identifier
+    pub file_identifier: ObjectIdentifier,
+    /// Access method specification
+    pub access_method: FileAccessMethod,
+}
+
+/// File access method for atomic read/write
+#[derive(Debug, Clone)]
+pub enum FileAccessMethod {
+    /// Stream access - read/write bytes at position
+    StreamAccess {
+        /// File position to start reading/writing
+        file_start_position: i32,
+        /// Number of octets to read (for read operations)
+        requested_octet_count: u32,
+    },
+    /// Record access - read/write records
+    RecordAccess {
+        /// Starting record number
+        file_start_record: i32,
+        /// Number of records to read (for read operations)
+        requested_record_count: u32,
+    },
+}
+
+impl AtomicReadFileRequest {
+    /// Create a new Atomic Read File request with stream access
+    pub fn new_stream_access(
+        file_identifier: ObjectIdentifier,
+        start_position: i32,
+        octet_count: u32,
+    ) -> Self {
+        Self {
+            file_identifier,
+            access_method: FileAccessMethod::StreamAccess {
+                file_start_position: start_position,
+                requested_octet_count: octet_count,
+            },
+        }
+    }
+
+    /// Create a new Atomic Read File request with record access
+    pub fn new_record_access(
+        file_identifier: ObjectIdentifier,
+        start_record: i32,
+        record_count: u32,
+    ) -> Self {
+        Self {
+            file_identifier,
+            access_method: FileAccessMethod::RecordAccess {
+                file_start_record: start_record,
+                requested_record_count: record_count,
+            },
+        }
+    }
+
+    /// Encode the Atomic Read File request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // File identifier - context tag 0
+        let file_id: u32 = self.file_identifier.try_into()?;
+        buffer.push(0x0C); // Context tag 0, length 4
+        buffer.extend_from_slice(&file_id.to_be_bytes());
+
+        // Access method - context tag 1 (opening tag)
+        buffer.push(0x1E); // Context tag 1, opening tag
+
+        match &self.access_method {
+            FileAccessMethod::StreamAccess {
+                file_start_position,
+                requested_octet_count,
+            } => {
+                // Stream access - context tag 0 (opening tag)
+                buffer.push(0x0E); // Context tag 0, opening tag
+
+                // File start position - context tag 0
+                buffer.push(0x09); // Context tag 0, length 1
+                buffer.extend_from_slice(&file_start_position.to_be_bytes());
+
+                // Requested octet count - context tag 1
+                buffer.push(0x19); // Context tag 1, length 1
+                buffer.extend_from_slice(&requested_octet_count.to_be_bytes());
+
+                buffer.push(0x0F); // Context tag 0, closing tag
+            }
+            FileAccessMethod::RecordAccess {
+                file_start_record,
+                requested_record_count,
+            } => {
+                // Record access - context tag 1 (opening tag)
+                buffer.push(0x1E); // Context tag 1, opening tag
+
+                // File start record - context tag 0
+                buffer.push(0x09); // Context tag 0, length 1
+                buffer.extend_from_slice(&file_start_record.to_be_bytes());
+
+                // Requested record count - context tag 1
+                buffer.push(0x19); // Context tag 1, length 1
+                buffer.extend_from_slice(&requested_record_count.to_be_bytes());
+
+                buffer.push(0x1F); // Context tag 1, closing tag
+            }
+        }
+
+        buffer.push(0x1F); // Context tag 1, closing tag
+
+        Ok(())
+    }
+}
+
+/// Atomic Read File response (confirmed service)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtomicReadFileResponse {
+    /// End of file flag
+    pub end_of_file: bool,
+    /// Access method and data
+    pub access_method_result: FileAccessMethodResult,
+}
+
+/// File access method result for atomic read response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileAccessMethodResult {
+    /// Stream access result
+    StreamAccess {
+        /// File position after read
+        file_start_position: i32,
+        /// File data read
+        file_data: Vec<u8>,
+    },
+    /// Record access result
+    RecordAccess {
+        /// Starting record number
+        file_start_record: i32,
+        /// Number of records returned
+        record_count: u32,
+        /// Record data
+        file_record_data: Vec<Vec<u8>>,
+    },
+}
+
+impl AtomicReadFileResponse {
+    /// Create a new stream access response
+    pub fn new_stream_access(end_of_file: bool, start_position: i32, data: Vec<u8>) -> Self {
+        Self {
+            end_of_file,
+            access_method_result: FileAccessMethodResult::StreamAccess {
+                file_start_position: start_position,
+                file_data: data,
+            },
+        }
+    }
+
+    /// Create a new record access response
+    pub fn new_record_access(end_of_file: bool, start_record: i32, records: Vec<Vec<u8>>) -> Self {
+        let record_count = records.len() as u32;
+        Self {
+            end_of_file,
+            access_method_result: FileAccessMethodResult::RecordAccess {
+                file_start_record: start_record,
+                record_count,
+                file_record_data: records,
+            },
+        }
+    }
+
+    /// Encode the Atomic Read File response
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // End of file
+        write_boolean(buffer, self.end_of_file)?;
+
+        match &self.access_method_result {
+            // Stream access - context tag 0 (constructed)
+            FileAccessMethodResult::StreamAccess {
+                file_start_position,
+                file_data,
+            } => {
+                encode_opening_tag(buffer, 0)?;
+                write_signed(buffer, *file_start_position)?;
+                write_octet_string(buffer, file_data)?;
+                encode_closing_tag(buffer, 0)?;
+            }
+            // Record access - context tag 1 (constructed)
+            FileAccessMethodResult::RecordAccess {
+                file_start_record,
+                file_record_data,
+                ..
+            } => {
+                encode_opening_tag(buffer, 1)?;
+                write_signed(buffer, *file_start_record)?;
+                write_unsigned(buffer, file_record_data.len() as u32)?;
+                for record in file_record_data {
+                    write_octet_string(buffer, record)?;
+                }
+                encode_closing_tag(buffer, 1)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decode an Atomic Read File response
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        // End of file
+        let (end_of_file, mut pos) = decode_boolean(data)?;
+
+        let (tag_number, _, consumed) = decode_context_tag(&data[pos..])?;
+        if data[pos] & 0x07 != 6 {
+            return Err(EncodingError::InvalidTag);
+        }
+        pos += consumed;
+
+        let access_method_result = match tag_number {
+            // Stream access - context tag 0 (constructed)
+            0 => {
+                let (file_start_position, consumed) = decode_signed(&data[pos..])?;
+                pos += consumed;
+                let (file_data, consumed) = decode_octet_string(&data[pos..])?;
+                pos += consumed;
+                FileAccessMethodResult::StreamAccess {
+                    file_start_position,
+                    file_data,
+                }
+            }
+            // Record access - context tag 1 (constructed)
+            1 => {
+                let (file_start_record, consumed) = decode_signed(&data[pos..])?;
+                pos += consumed;
+                let (record_count, consumed) = decode_unsigned(&data[pos..])?;
+                pos += consumed;
+                let mut file_record_data = Vec::new();
+                for _ in 0..record_count {
+                    let (record, consumed) = decode_octet_string(&data[pos..])?;
+                    file_record_data.push(record);
+                    pos += consumed;
+                }
+                FileAccessMethodResult::RecordAccess {
+                    file_start_record,
+                    record_count,
+                    file_record_data,
+                }
+            }
+            _ => return Err(EncodingError::InvalidTag),
+        };
+        expect_constructed_tag(&data[pos..], tag_number, 7)?;
+
+        Ok(Self {
+            end_of_file,
+            access_method_result,
+        })
+    }
+}
+
+/// Atomic Write File request (confirmed service)
+#[derive(Debug, Clone)]
+pub struct AtomicWriteFileRequest {
+    /// File object identifier
+    pub file_identifier: ObjectIdentifier,
+    /// Access method and data
+    pub access_method: FileWriteAccessMethod,
+}
+
+/// File write access method for atomic write
+#[derive(Debug, Clone)]
+pub enum FileWriteAccessMethod {
+    /// Stream access - write bytes at position
+    StreamAccess {
+        /// File position to start writing
+        file_start_position: i32,
+        /// Data to write
+        file_data: Vec<u8>,
+    },
+    /// Record access - write records
+    RecordAccess {
+        /// Starting record number
+        file_start_record: i32,
+        /// Number of records to write
+        record_count: u32,
+        /// Record data to write
+        file_record_data: Vec<Vec<u8>>,
+    },
+}
+
+impl AtomicWriteFileRequest {
+    /// Create a new Atomic Write File request with stream access
+    pub fn new_stream_access(
+        file_identifier: ObjectIdentifier,
+        start_position: i32,
+        data: Vec<u8>,
+    ) -> Self {
+        Self {
+            file_identifier,
+            access_method: FileWriteAccessMethod::StreamAccess {
+                file_start_position: start_position,
+                file_data: data,
+            },
+        }
+    }
+
+    /// Create a new Atomic Write File request with record access
+    pub fn new_record_access(
+        file_identifier: ObjectIdentifier,
+        start_record: i32,
+        records: Vec<Vec<u8>>,
+    ) -> Self {
+        let record_count = records.len() as u32;
+        Self {
+            file_identifier,
+            access_method: FileWriteAccessMethod::RecordAccess {
+                file_start_record: start_record,
+                record_count,
+                file_record_data: records,
+            },
+        }
+    }
+
+    /// Encode the Atomic Write File request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // File identifier - context tag 0
+        let file_id: u32 = self.file_identifier.try_into()?;
+        buffer.push(0x0C); // Context tag 0, length 4
+        buffer.extend_from_slice(&file_id.to_be_bytes());
+
+        // Access method - context tag 1 (opening tag)
+        buffer.push(0x1E); // Context tag 1, opening tag
+
+        match &self.access_method {
+            FileWriteAccessMethod::StreamAccess {
+                file_start_position,
+                file_data,
+            } => {
+                // Stream access - context tag 0 (opening tag)
+                buffer.push(0x0E); // Context tag 0, opening tag
+
+                // File start position - context tag 0
+                buffer.push(0x09); // Context tag 0, length 1
+                buffer.extend_from_slice(&file_start_position.to_be_bytes());
+
+                // File data - context tag 1 (opening tag)
+                buffer.push(0x1E); // Context tag 1, opening tag
+                buffer.extend_from_slice(file_data);
+                buffer.push(0x1F); // Context tag 1, closing tag
+
+                buffer.push(0x0F); // Context tag 0, closing tag
+            }
+            FileWriteAccessMethod::RecordAccess {
+                file_start_record,
+                record_count: _,
+                file_record_data,
+            } => {
+                // Record access - context tag 1 (opening tag)
+                buffer.push(0x1E); // Context tag 1, opening tag
+
+                // File start record - context tag 0
+                buffer.push(0x09); // Context tag 0, length 1
+                buffer.extend_from_slice(&file_start_record.to_be_bytes());
+
+                // Record count - context tag 1
+                let record_count = file_record_data.len() as u32;
+                buffer.push(0x19); // Context tag 1, length 1
+                buffer.extend_from_slice(&record_count.to_be_bytes());
+
+                // File record data - context tag 2 (opening tag)
+                buffer.push(0x2E); // Context tag 2, opening tag
+                for record in file_record_data {
+                    // Each record as octet string
+                    buffer.push(0x65); // Application tag 6 (OctetString), length depends on record size
+                    buffer.push(record.len() as u8);
+                    buffer.extend_from_slice(record);
+                }
+                buffer.push(0x2F); // Context tag 2, closing tag
+
+                buffer.push(0x1F); // Context tag 1, closing tag
+            }
+        }
+
+        buffer.push(0x1F); // Context tag 1, closing tag
+
+        Ok(())
+    }
+}
+
+/// Atomic Write File response (confirmed service)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtomicWriteFileResponse {
+    /// File start position (for stream access) or start record (for record access)
+    pub file_start_position: i32,
+    /// Whether `file_start_position` is a record number
+    record_access: bool,
+}
+
+impl AtomicWriteFileResponse {
+    /// Create a new stream access response
+    pub fn new_stream_access(file_start_position: i32) -> Self {
+        Self {
+            file_start_position,
+            record_access: false,
+        }
+    }
+
+    /// Create a new record access response
+    pub fn new_record_access(file_start_record: i32) -> Self {
+        Self {
+            file_start_position: file_start_record,
+            record_access: true,
+        }
+    }
+
+    /// Check if `file_start_position` is a record number rather than an
+    /// octet position
+    pub fn is_record_access(&self) -> bool {
+        self.record_access
+    }
+
+    /// Encode the Atomic Write File response
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // File start position - context tag 0, or file start record - context tag 1
+        let tag_number = if self.record_access { 1 } else { 0 };
+        buffer.extend_from_slice(&encode_context_signed(
+            self.file_start_position,
+            tag_number,
+        )?);
+        Ok(())
+    }
+
+    /// Decode an Atomic Write File response
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        if let Ok((file_start_position, _)) = decode_context_signed(data, 0) {
+            return Ok(Self::new_stream_access(file_start_position));
+        }
+        let (file_start_record, _) = decode_context_signed(data, 1)?;
+        Ok(Self::new_record_access(file_start_record))
+    }
+}
+
+/// Time Synchronization request (unconfirmed service)
+#[derive(Debug, Clone)]
+pub struct TimeSynchronizationRequest {
+    /// Date and time to synchronize to
+    pub date_time: BacnetDateTime,
+}
+
+/// UTC Time Synchronization request (unconfirmed service)
+#[derive(Debug, Clone)]
+pub struct UtcTimeSynchronizationRequest {
+    /// UTC date and time to synchronize to
+    pub utc_date_time: BacnetDateTime,
+}
+
+/// BACnet Date and Time structure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BacnetDateTime {
+    /// Date component
+    pub date: crate::object::Date,
+    /// Time component
+    pub time: crate::object::Time,
+}
+
+impl BacnetDateTime {
+    /// Create a new BACnet DateTime from Date and Time components
+    pub fn new(date: crate::object::Date, time: crate::object::Time) -> Self {
+        Self { date, time }
+    }
+
+    /// Create from current system time (requires std feature)
+    #[cfg(feature = "std")]
+    pub fn now() -> Self {
+        use chrono::{Datelike, Local, Timelike};
+
+        let now = Local::now();
+        let year = now.year() as u16;
+        let month = now.month() as u8;
+        let day = now.day() as u8;
+        let weekday = now.weekday().number_from_monday() as u8; // BACnet uses 1=Monday
+        let hour = now.hour() as u8;
+        let minute = now.minute() as u8;
+        let second = now.second() as u8;
+        let hundredths = (now.nanosecond() / 10_000_000) as u8;
+
+        let date = crate::object::Date {
+            year,
+            month,
+            day,
+            weekday,
+        };
+        let time = crate::object::Time {
+            hour,
+            minute,
+            second,
+            hundredths,
+        };
+        Self::new(date, time)
+    }
+
+    /// Create unspecified time (all 255 values)
+    pub fn unspecified() -> Self {
+        Self {
+            date: crate::object::Date {
+                year: 255,
+                month: 255,
+                day: 255,
+                weekday: 255,
+            },
+            time: crate::object::Time {
+                hour: 255,
+                minute: 255,
+                second: 255,
+                hundredths: 255,
+            },
+        }
+    }
+
+    /// Check if this datetime is unspecified
+    pub fn is_unspecified(&self) -> bool {
+        self.date.year == 255
+            && self.date.month == 255
+            && self.date.day == 255
+            && self.date.weekday == 255
+            && self.time.hour == 255
+            && self.time.minute == 255
+            && self.time.second == 255
+            && self.time.hundredths == 255
+    }
+
+    /// Encode BACnet DateTime
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        use crate::encoding::{write_date, write_time};
+
+        // Encode date
+        write_date(
+            buffer,
+            self.date.year,
+            self.date.month,
+            self.date.day,
+            self.date.weekday,
+        )?;
+
+        // Encode time
+        write_time(
+            buffer,
+            self.time.hour,
+            self.time.minute,
+            self.time.second,
+            self.time.hundredths,
+        )?;
+
+        Ok(())
+    }
+
+    /// Decode BACnet DateTime
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        use crate::encoding::{decode_date, decode_time};
+
+        // Decode date (4 bytes)
+        let ((year, month, day, weekday), consumed_date) = decode_date(data)?;
+
+        // Decode time (4 bytes)
+        let ((hour, minute, second, hundredths), consumed_time) =
+            decode_time(&data[consumed_date..])?;
+
+        let datetime = BacnetDateTime {
+            date: crate::object::Date {
+                year,
+                month,
+                day,
+                weekday,
+            },
+            time: crate::object::Time {
+                hour,
+                minute,
+                second,
+                hundredths,
+            },
+        };
+
+        Ok((datetime, consumed_date + consumed_time))
+    }
+}
+
+/// BACnet time stamp (BACnetTimeStamp)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BacnetTimeStamp {
+    /// Time of day - context tag 0
+    Time(crate::object::Time),
+    /// Sequence number - context tag 1
+    SequenceNumber(u32),
+    /// Date and time - context tag 2 (constructed)
+    DateTime(BacnetDateTime),
+}
+
+impl BacnetTimeStamp {
+    /// Check if the time stamp is unspecified, e.g. for a transition that has
+    /// not occurred
+    pub fn is_unspecified(&self) -> bool {
+        match self {
+            BacnetTimeStamp::Time(time) => {
+                time.hour == 255
+                    && time.minute == 255
+                    && time.second == 255
+                    && time.hundredths == 255
+            }
+            BacnetTimeStamp::SequenceNumber(_) => false,
+            BacnetTimeStamp::DateTime(date_time) => date_time.is_unspecified(),
+        }
+    }
+
+    /// Encode the time stamp
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        match self {
+            BacnetTimeStamp::Time(time) => {
+                crate::encoding::write_context_tag(buffer, 0, 4)?;
+                buffer.extend_from_slice(&[time.hour, time.minute, time.second, time.hundredths]);
+            }
+            BacnetTimeStamp::SequenceNumber(sequence_number) => {
+                buffer.extend_from_slice(&encode_context_unsigned(*sequence_number, 1)?);
+            }
+            BacnetTimeStamp::DateTime(date_time) => {
+                encode_opening_tag(buffer, 2)?;
+                date_time.encode(buffer)?;
+                encode_closing_tag(buffer, 2)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decode a time stamp, returning it with the number of bytes consumed
+    ///
+    /// The choice is read from the context tag of the time stamp itself.
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        if data.is_empty() {
+            return Err(EncodingError::UnexpectedEndOfData);
+        }
+
+        let (tag_number, length, consumed) = decode_context_tag(data)?;
+        match (tag_number, data[0] & 0x07) {
+            (0, _) => {
+                if length != 4 {
+                    return Err(EncodingError::InvalidLength);
+                }
+                if data.len() < consumed + 4 {
+                    return Err(EncodingError::UnexpectedEndOfData);
+                }
+                let value = &data[consumed..consumed + 4];
+                let time = crate::object::Time {
+                    hour: value[0],
+                    minute: value[1],
+                    second: value[2],
+                    hundredths: value[3],
+                };
+                Ok((BacnetTimeStamp::Time(time), consumed + 4))
+            }
+            (1, _) => {
+                let (sequence_number, consumed) = decode_context_unsigned(data, 1)?;
+                Ok((BacnetTimeStamp::SequenceNumber(sequence_number), consumed))
+            }
+            // Opening tag 2
+            (2, 6) => {
+                let (date_time, date_time_len) = BacnetDateTime::decode(&data[consumed..])?;
+                let pos = consumed + date_time_len;
+                let closing = expect_constructed_tag(&data[pos..], 2, 7)?;
+                Ok((BacnetTimeStamp::DateTime(date_time), pos + closing))
+            }
+            _ => Err(EncodingError::InvalidTag),
+        }
+    }
+
+    /// Encode the time stamps of the to-offnormal, to-fault and to-normal
+    /// transitions, enclosed in the given context tag
+    pub fn encode_array(
+        time_stamps: &[BacnetTimeStamp; 3],
+        tag_number: u8,
+        buffer: &mut Vec<u8>,
+    ) -> EncodingResult<()> {
+        encode_opening_tag(buffer, tag_number)?;
+        for time_stamp in time_stamps {
+            time_stamp.encode(buffer)?;
+        }
+        encode_closing_tag(buffer, tag_number)
+    }
+
+    /// Decode the time stamps of the to-offnormal, to-fault and to-normal
+    /// transitions, enclosed in the given context tag
+    ///
+    /// Each element may use a different choice.
+    pub fn decode_array(
+        data: &[u8],
+        tag_number: u8,
+    ) -> EncodingResult<([BacnetTimeStamp; 3], usize)> {
+        let mut pos = expect_constructed_tag(data, tag_number, 6)?;
+
+        let mut time_stamps = [BacnetTimeStamp::SequenceNumber(0); 3];
+        for time_stamp in &mut time_stamps {
+            let (decoded, consumed) = BacnetTimeStamp::decode(&data[pos..])?;
+            *time_stamp = decoded;
+            pos += consumed;
+        }
+
+        pos += expect_constructed_tag(&data[pos..], tag_number, 7)?;
+        Ok((time_stamps, pos))
+    }
+}
+
+/// A date or set of dates in a calendar or exception schedule
+/// (BACnetCalendarEntry)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarEntry {
+    /// A single date, fields may be unspecified (255) - context tag 0
+    Date(crate::object::Date),
+    /// An inclusive range of dates - context tag 1 (constructed)
+    DateRange {
+        start_date: crate::object::Date,
+        end_date: crate::object::Date,
+    },
+    /// Month, week of month and day of week, 255 for any - context tag 2
+    WeekNDay {
+        month: u8,
+        week_of_month: u8,
+        day_of_week: u8,
+    },
+}
+
+impl CalendarEntry {
+    /// Encode the calendar entry
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        use crate::encoding::write_date;
+
+        match self {
+            CalendarEntry::Date(date) => {
+                crate::encoding::write_context_tag(buffer, 0, 4)?;
+                buffer.extend_from_slice(&date_octets(date)?);
+            }
+            CalendarEntry::DateRange {
+                start_date,
+                end_date,
+            } => {
+                encode_opening_tag(buffer, 1)?;
+                for date in [start_date, end_date] {
+                    write_date(buffer, date.year, date.month, date.day, date.weekday)?;
+                }
+                encode_closing_tag(buffer, 1)?;
+            }
+            CalendarEntry::WeekNDay {
+                month,
+                week_of_month,
+                day_of_week,
+            } => {
+                crate::encoding::write_context_tag(buffer, 2, 3)?;
+                buffer.extend_from_slice(&[*month, *week_of_month, *day_of_week]);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decode a calendar entry, returning it with the number of bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        use crate::encoding::decode_date;
+
+        if data.is_empty() {
+            return Err(EncodingError::UnexpectedEndOfData);
+        }
+
+        let (tag_number, length, consumed) = decode_context_tag(data)?;
+        match (tag_number, data[0] & 0x07) {
+            (0, 0..=5) => {
+                if length != 4 {
+                    return Err(EncodingError::InvalidLength);
+                }
+                if data.len() < consumed + 4 {
+                    return Err(EncodingError::UnexpectedEndOfData);
+                }
+                let date = date_from_octets(&data[consumed..consumed + 4]);
+                Ok((CalendarEntry::Date(date), consumed + 4))
+            }
+            // Opening tag 1
+            (1, 6) => {
+                let mut pos = consumed;
+                let mut dates = [crate::object::Date {
+                    year: 255,
+                    month: 255,
+                    day: 255,
+                    weekday: 255,
+                }; 2];
+                for date in &mut dates {
+                    let ((year, month, day, weekday), consumed) = decode_date(&data[pos..])?;
+                    *date = crate::object::Date {
+                        year,
+                        month,
+                        day,
+                        weekday,
+                    };
+                    pos += consumed;
+                }
+                pos += expect_constructed_tag(&data[pos..], 1, 7)?;
+                Ok((
+                    CalendarEntry::DateRange {
+                        start_date: dates[0],
+                        end_date: dates[1],
+                    },
+                    pos,
+                ))
+            }
+            (2, 0..=5) => {
+                if length != 3 {
+                    return Err(EncodingError::InvalidLength);
+                }
+                if data.len() < consumed + 3 {
+                    return Err(EncodingError::UnexpectedEndOfData);
+                }
+                let value = &data[consumed..consumed + 3];
+                Ok((
+                    CalendarEntry::WeekNDay {
+                        month: value[0],
+                        week_of_month: value[1],
+                        day_of_week: value[2],
+                    },
+                    consumed + 3,
+                ))
+            }
+            _ => Err(EncodingError::InvalidTag),
+        }
+    }
+}
+
+/// The raw octets of a date, as carried by a context-tagged date
+fn date_octets(date: &crate::object::Date) -> EncodingResult<[u8; 4]> {
+    let year = match date.year {
+        255 => 255,
+        1900..=2154 => (date.year - 1900) as u8,
+        _ => return Err(EncodingError::ValueOutOfRange),
+    };
+    Ok([year, date.month, date.day, date.weekday])
+}
+
+/// A date from the raw octets of a context-tagged date
+fn date_from_octets(value: &[u8]) -> crate::object::Date {
+    crate::object::Date {
+        year: if value[0] == 255 {
+            255
+        } else {
+            value[0] as u16 + 1900
+        },
+        month: value[1],
+        day: value[2],
+        weekday: value[3],
+    }
+}
+
+/// A scheduled value and the time it takes effect (BACnetTimeValue)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeValue {
+    /// Time of day the value takes effect
+    pub time: crate::object::Time,
+    /// Value to write, Null to relinquish
+    pub value: property::PropertyValue,
+}
+
+impl TimeValue {
+    /// Create a new time value
+    pub fn new(time: crate::object::Time, value: property::PropertyValue) -> Self {
+        Self { time, value }
+    }
+
+    /// Encode the time value
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        crate::encoding::write_time(
+            buffer,
+            self.time.hour,
+            self.time.minute,
+            self.time.second,
+            self.time.hundredths,
+        )?;
+        encode_property_value(&self.value, buffer)
+    }
+
+    /// Decode a time value, returning it with the number of bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        let ((hour, minute, second, hundredths), mut pos) = crate::encoding::decode_time(data)?;
+        let (value, consumed) = decode_property_value(&data[pos..])?;
+        pos += consumed;
+
+        Ok((
+            Self {
+                time: crate::object::Time {
+                    hour,
+                    minute,
+                    second,
+                    hundredths,
+                },
+                value,
+            },
+            pos,
+        ))
+    }
+}
+
+/// When a special event applies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecialEventPeriod {
+    /// The dates of a calendar entry - context tag 0 (constructed)
+    CalendarEntry(CalendarEntry),
+    /// The dates of a Calendar object - context tag 1
+    CalendarReference(ObjectIdentifier),
+}
+
+/// An entry of a Schedule's Exception_Schedule (BACnetSpecialEvent)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpecialEvent {
+    /// When the event applies
+    pub period: SpecialEventPeriod,
+    /// Values scheduled on those days
+    pub list_of_time_values: Vec<TimeValue>,
+    /// Priority of the event (1-16), lower numbers win
+    pub event_priority: u8,
+}
+
+impl SpecialEvent {
+    /// Create a new special event
+    pub fn new(
+        period: SpecialEventPeriod,
+        list_of_time_values: Vec<TimeValue>,
+        event_priority: u8,
+    ) -> Self {
+        Self {
+            period,
+            list_of_time_values,
+            event_priority,
+        }
+    }
+
+    /// Encode the special event
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        match &self.period {
+            // Calendar entry - context tag 0 (constructed)
+            SpecialEventPeriod::CalendarEntry(entry) => {
+                encode_opening_tag(buffer, 0)?;
+                entry.encode(buffer)?;
+                encode_closing_tag(buffer, 0)?;
+            }
+            // Calendar reference - context tag 1
+            SpecialEventPeriod::CalendarReference(calendar) => {
+                buffer.extend_from_slice(&encode_context_object_id(*calendar, 1)?);
+            }
+        }
+
+        // List of time values - context tag 2 (constructed)
+        encode_opening_tag(buffer, 2)?;
+        for time_value in &self.list_of_time_values {
+            time_value.encode(buffer)?;
+        }
+        encode_closing_tag(buffer, 2)?;
+
+        // Event priority - context tag 3
+        if !(1..=16).contains(&self.event_priority) {
+            return Err(EncodingError::ValueOutOfRange);
+        }
+        buffer.extend_from_slice(&encode_context_unsigned(self.event_priority as u32, 3)?);
+
+        Ok(())
+    }
+
+    /// Decode a special event, returning it with the number of bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        let (period, mut pos) = match decode_context_object_id(data, 1) {
+            Ok((calendar, consumed)) => (SpecialEventPeriod::CalendarReference(calendar), consumed),
+            Err(_) => {
+                let mut pos = expect_constructed_tag(data, 0, 6)?;
+                let (entry, consumed) = CalendarEntry::decode(&data[pos..])?;
+                pos += consumed;
+                pos += expect_constructed_tag(&data[pos..], 0, 7)?;
+                (SpecialEventPeriod::CalendarEntry(entry), pos)
+            }
+        };
+
+        // List of time values - context tag 2 (constructed)
+        pos += expect_constructed_tag(&data[pos..], 2, 6)?;
+        let end = pos + constructed_value_length(&data[pos..], 2)?;
+        let mut list_of_time_values = Vec::new();
+        while pos < end {
+            let (time_value, consumed) = TimeValue::decode(&data[pos..end])?;
+            list_of_time_values.push(time_value);
+            pos += consumed;
+        }
+        pos += expect_constructed_tag(&data[pos..], 2, 7)?;
+
+        // Event priority - context tag 3
+        let (event_priority, consumed) = decode_context_unsigned(&data[pos..], 3)?;
+        if !(1..=16).contains(&event_priority) {
+            return Err(EncodingError::ValueOutOfRange);
+        }
+        pos += consumed;
+
+        Ok((
+            Self {
+                period,
+                list_of_time_values,
+                event_priority: event_priority as u8,
+            },
+            pos,
+        ))
+    }
+
+    /// Encode a list of special events, e.g. the value of an
+    /// Exception_Schedule property
+    pub fn encode_list(events: &[Self], buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        for event in events {
+            event.encode(buffer)?;
+        }
+        Ok(())
+    }
+
+    /// Decode a list of special events, e.g. the value of an
+    /// Exception_Schedule property
+    pub fn decode_list(data: &[u8]) -> EncodingResult<Vec<Self>> {
+        let mut events = Vec::new();
+        let mut pos = 0;
+
+        while pos < data.len() {
+            let (event, consumed) = Self::decode(&data[pos..])?;
+            events.push(event);
+            pos += consumed;
+        }
+
+        Ok(events)
+    }
+}
+
+impl TimeSynchronizationRequest {
+    /// Create a new Time Synchronization request
+    pub fn new(date_time: BacnetDateTime) -> Self {
+        Self { date_time }
+    }
+
+    /// Create Time Synchronization request with current time
+    #[cfg(feature = "std")]
+    pub fn now() -> Self {
+        Self::new(BacnetDateTime::now())
+    }
+
+    /// Encode the Time Synchronization request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        self.date_time.encode(buffer)
+    }
+
+    /// Decode a Time Synchronization request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let (date_time, _consumed) = BacnetDateTime::decode(data)?;
+        Ok(Self::new(date_time))
+    }
+}
+
+impl UtcTimeSynchronizationRequest {
+    /// Create a new UTC Time Synchronization request
+    pub fn new(utc_date_time: BacnetDateTime) -> Self {
+        Self { utc_date_time }
+    }
+
+    /// Create UTC Time Synchronization request with current UTC time
+    #[cfg(feature = "std")]
+    pub fn now() -> Self {
+        use chrono::{Datelike, Timelike, Utc};
+
+        let now = Utc::now();
+        let year = now.year() as u16;
+        let month = now.month() as u8;
+        let day = now.day() as u8;
+        let weekday = now.weekday().number_from_monday() as u8;
+        let hour = now.hour() as u8;
+        let minute = now.minute() as u8;
+        let second = now.second() as u8;
+        let hundredths = (now.nanosecond() / 10_000_000) as u8;
 
         let date = crate::object::Date {
             year,
@@ -2008,25 +5328,1136 @@ impl UtcTimeSynchronizationRequest {
             day,
             weekday,
         };
-        let time = crate::object::Time {
-            hour,
-            minute,
-            second,
-            hundredths,
+        let time = crate::object::Time {
+            hour,
+            minute,
+            second,
+            hundredths,
+        };
+        let utc_date_time = BacnetDateTime::new(date, time);
+        Self::new(utc_date_time)
+    }
+
+    /// Encode the UTC Time Synchronization request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        self.utc_date_time.encode(buffer)
+    }
+
+    /// Decode a UTC Time Synchronization request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let (utc_date_time, _consumed) = BacnetDateTime::decode(data)?;
+        Ok(Self::new(utc_date_time))
+    }
+}
+
+/// Reinitialized state of device (BACnetReinitializedStateOfDevice)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum ReinitializedStateOfDevice {
+    ColdStart = 0,
+    WarmStart = 1,
+    StartBackup = 2,
+    EndBackup = 3,
+    StartRestore = 4,
+    EndRestore = 5,
+    AbortRestore = 6,
+}
+
+impl TryFrom<u32> for ReinitializedStateOfDevice {
+    type Error = EncodingError;
+
+    fn try_from(value: u32) -> EncodingResult<Self> {
+        match value {
+            0 => Ok(Self::ColdStart),
+            1 => Ok(Self::WarmStart),
+            2 => Ok(Self::StartBackup),
+            3 => Ok(Self::EndBackup),
+            4 => Ok(Self::StartRestore),
+            5 => Ok(Self::EndRestore),
+            6 => Ok(Self::AbortRestore),
+            _ => Err(EncodingError::ValueOutOfRange),
+        }
+    }
+}
+
+/// Reinitialize Device request (confirmed service)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReinitializeDeviceRequest {
+    /// State the device should reinitialize to
+    pub reinitialized_state: ReinitializedStateOfDevice,
+    /// Password (optional, 1-20 characters)
+    pub password: Option<String>,
+}
+
+impl ReinitializeDeviceRequest {
+    /// Create a new Reinitialize Device request without a password
+    pub fn new(reinitialized_state: ReinitializedStateOfDevice) -> Self {
+        Self {
+            reinitialized_state,
+            password: None,
+        }
+    }
+
+    /// Create a new Reinitialize Device request with a password
+    pub fn with_password(
+        reinitialized_state: ReinitializedStateOfDevice,
+        password: impl Into<String>,
+    ) -> Self {
+        Self {
+            reinitialized_state,
+            password: Some(password.into()),
+        }
+    }
+
+    /// Check the request parameters
+    ///
+    /// The password, if present, must be 1-20 characters.
+    pub fn validate(&self) -> Result<()> {
+        validate_password(self.password.as_deref())
+    }
+
+    /// Encode the Reinitialize Device request
+    ///
+    /// Fails with [`EncodingError::ValueOutOfRange`] before writing anything
+    /// if [`validate`](Self::validate) does.
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        self.validate()
+            .map_err(|_| EncodingError::ValueOutOfRange)?;
+
+        // Reinitialized state of device - context tag 0
+        buffer.extend_from_slice(&encode_context_enumerated(
+            self.reinitialized_state as u32,
+            0,
+        )?);
+
+        // Password - context tag 1 (optional)
+        if let Some(password) = &self.password {
+            buffer.extend_from_slice(&encode_context_character_string(password, 1)?);
+        }
+
+        Ok(())
+    }
+
+    /// Decode a Reinitialize Device request
+    ///
+    /// A password outside 1-20 characters fails with
+    /// [`EncodingError::ValueOutOfRange`].
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        // Reinitialized state of device - context tag 0
+        let (state, mut pos) = decode_context_enumerated(data, 0)?;
+        let reinitialized_state = ReinitializedStateOfDevice::try_from(state)?;
+
+        // Password - context tag 1 (optional)
+        let password = if pos < data.len() {
+            let (password, consumed) = decode_context_character_string(&data[pos..], 1)?;
+            pos += consumed;
+            Some(password)
+        } else {
+            None
+        };
+
+        if pos != data.len() {
+            return Err(EncodingError::InvalidFormat(
+                "Trailing data after Reinitialize Device request".to_string(),
+            ));
+        }
+        validate_password(password.as_deref()).map_err(|_| EncodingError::ValueOutOfRange)?;
+
+        Ok(Self {
+            reinitialized_state,
+            password,
+        })
+    }
+}
+
+/// Check the password of a ReinitializeDevice or DeviceCommunicationControl
+/// request, which must be 1-20 characters when present
+fn validate_password(password: Option<&str>) -> Result<()> {
+    if let Some(password) = password {
+        let length = password.chars().count();
+        if !(1..=20).contains(&length) {
+            return Err(ServiceError::InvalidParameters(format!(
+                "Password must be 1-20 characters, got {}",
+                length
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Communication state requested by DeviceCommunicationControl
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum EnableDisable {
+    Enable = 0,
+    Disable = 1,
+    DisableInitiation = 2,
+}
+
+impl TryFrom<u32> for EnableDisable {
+    type Error = EncodingError;
+
+    fn try_from(value: u32) -> EncodingResult<Self> {
+        match value {
+            0 => Ok(Self::Enable),
+            1 => Ok(Self::Disable),
+            2 => Ok(Self::DisableInitiation),
+            _ => Err(EncodingError::ValueOutOfRange),
+        }
+    }
+}
+
+/// Device Communication Control request (confirmed service)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceCommunicationControlRequest {
+    /// How long the state applies, in minutes (optional, indefinite if absent)
+    pub time_duration: Option<u16>,
+    /// Requested communication state
+    pub enable_disable: EnableDisable,
+    /// Password (optional, 1-20 characters)
+    pub password: Option<String>,
+}
+
+impl DeviceCommunicationControlRequest {
+    /// Create a new Device Communication Control request without a duration
+    /// or password
+    pub fn new(enable_disable: EnableDisable) -> Self {
+        Self {
+            time_duration: None,
+            enable_disable,
+            password: None,
+        }
+    }
+
+    /// Set the duration in minutes
+    pub fn with_duration(mut self, minutes: u16) -> Self {
+        self.time_duration = Some(minutes);
+        self
+    }
+
+    /// Set the password
+    pub fn with_password(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    /// Check the request parameters
+    ///
+    /// The password, if present, must be 1-20 characters.
+    pub fn validate(&self) -> Result<()> {
+        validate_password(self.password.as_deref())
+    }
+
+    /// Encode the Device Communication Control request
+    ///
+    /// Fails with [`EncodingError::ValueOutOfRange`] before writing anything
+    /// if [`validate`](Self::validate) does.
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        self.validate()
+            .map_err(|_| EncodingError::ValueOutOfRange)?;
+
+        // Time duration - context tag 0 (optional)
+        if let Some(minutes) = self.time_duration {
+            buffer.extend_from_slice(&encode_context_unsigned(minutes as u32, 0)?);
+        }
+
+        // Enable/disable - context tag 1
+        buffer.extend_from_slice(&encode_context_enumerated(self.enable_disable as u32, 1)?);
+
+        // Password - context tag 2 (optional)
+        if let Some(password) = &self.password {
+            buffer.extend_from_slice(&encode_context_character_string(password, 2)?);
+        }
+
+        Ok(())
+    }
+
+    /// Decode a Device Communication Control request
+    ///
+    /// A password outside 1-20 characters fails with
+    /// [`EncodingError::ValueOutOfRange`].
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let mut pos = 0;
+
+        // Time duration - context tag 0 (optional)
+        let time_duration = match decode_context_unsigned(data, 0) {
+            Ok((minutes, consumed)) => {
+                pos += consumed;
+                Some(u16::try_from(minutes).map_err(|_| EncodingError::ValueOutOfRange)?)
+            }
+            Err(_) => None,
+        };
+
+        // Enable/disable - context tag 1
+        let (enable_disable, consumed) = decode_context_enumerated(&data[pos..], 1)?;
+        let enable_disable = EnableDisable::try_from(enable_disable)?;
+        pos += consumed;
+
+        // Password - context tag 2 (optional)
+        let password = if pos < data.len() {
+            let (password, consumed) = decode_context_character_string(&data[pos..], 2)?;
+            pos += consumed;
+            Some(password)
+        } else {
+            None
+        };
+
+        if pos != data.len() {
+            return Err(EncodingError::InvalidFormat(
+                "Trailing data after Device Communication Control request".to_string(),
+            ));
+        }
+        validate_password(password.as_deref()).map_err(|_| EncodingError::ValueOutOfRange)?;
+
+        Ok(Self {
+            time_duration,
+            enable_disable,
+            password,
+        })
+    }
+}
+
+/// Get Alarm Summary request (confirmed service)
+///
+/// The request has no parameters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GetAlarmSummaryRequest;
+
+impl GetAlarmSummaryRequest {
+    /// Create a new Get Alarm Summary request
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Encode the Get Alarm Summary request
+    pub fn encode(&self, _buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        Ok(())
+    }
+
+    /// Decode a Get Alarm Summary request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        if !data.is_empty() {
+            return Err(EncodingError::InvalidFormat(
+                "Get Alarm Summary request takes no parameters".to_string(),
+            ));
+        }
+        Ok(Self)
+    }
+}
+
+/// An object in alarm, as reported by Get Alarm Summary
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlarmSummaryEntry {
+    /// Object identifier
+    pub object_identifier: ObjectIdentifier,
+    /// Event state of the object
+    pub alarm_state: EventState,
+    /// Acknowledged transitions (to-offnormal, to-fault, to-normal)
+    pub acknowledged_transitions: Vec<bool>,
+}
+
+impl AlarmSummaryEntry {
+    /// Encode the alarm summary entry
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        write_object_identifier(buffer, self.object_identifier)?;
+        write_enumerated(buffer, u16::from(self.alarm_state) as u32)?;
+        encode_bit_string(buffer, &self.acknowledged_transitions)?;
+        Ok(())
+    }
+
+    /// Decode an alarm summary entry, returning it with the bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        let (object_identifier, mut pos) = decode_object_identifier(data)?;
+
+        let (alarm_state, consumed) = decode_enumerated(&data[pos..])?;
+        pos += consumed;
+        let alarm_state = u16::try_from(alarm_state).map_err(|_| EncodingError::ValueOutOfRange)?;
+
+        let (acknowledged_transitions, consumed) = decode_bit_string(&data[pos..])?;
+        pos += consumed;
+
+        Ok((
+            Self {
+                object_identifier,
+                alarm_state: EventState::from(alarm_state),
+                acknowledged_transitions,
+            },
+            pos,
+        ))
+    }
+}
+
+/// Get Alarm Summary response
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GetAlarmSummaryResponse {
+    /// Objects in alarm
+    pub alarm_summaries: Vec<AlarmSummaryEntry>,
+}
+
+impl GetAlarmSummaryResponse {
+    /// Create a new Get Alarm Summary response
+    pub fn new(alarm_summaries: Vec<AlarmSummaryEntry>) -> Self {
+        Self { alarm_summaries }
+    }
+
+    /// Encode the Get Alarm Summary response
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        for entry in &self.alarm_summaries {
+            entry.encode(buffer)?;
+        }
+        Ok(())
+    }
+
+    /// Decode a Get Alarm Summary response
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let mut alarm_summaries = Vec::new();
+        let mut pos = 0;
+
+        while pos < data.len() {
+            let (entry, consumed) = AlarmSummaryEntry::decode(&data[pos..])?;
+            alarm_summaries.push(entry);
+            pos += consumed;
+        }
+
+        Ok(Self { alarm_summaries })
+    }
+}
+
+/// Kind of notification an event generates (BACnetNotifyType)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum NotifyType {
+    Alarm = 0,
+    Event = 1,
+    AckNotification = 2,
+}
+
+impl TryFrom<u32> for NotifyType {
+    type Error = EncodingError;
+
+    fn try_from(value: u32) -> EncodingResult<Self> {
+        match value {
+            0 => Ok(Self::Alarm),
+            1 => Ok(Self::Event),
+            2 => Ok(Self::AckNotification),
+            _ => Err(EncodingError::ValueOutOfRange),
+        }
+    }
+}
+
+generate_custom_enum!(
+    /// Event type of an event notification (BACnetEventType)
+    EventType {
+        ChangeOfBitstring = 0,
+        ChangeOfState = 1,
+        ChangeOfValue = 2,
+        CommandFailure = 3,
+        FloatingLimit = 4,
+        OutOfRange = 5,
+        ComplexEventType = 6,
+        ChangeOfLifeSafety = 8,
+        Extended = 9,
+        BufferReady = 10,
+        UnsignedRange = 11,
+        AccessEvent = 13,
+        DoubleOutOfRange = 14,
+        SignedOutOfRange = 15,
+        UnsignedOutOfRange = 16,
+        ChangeOfCharacterstring = 17,
+        ChangeOfStatusFlags = 18,
+        ChangeOfReliability = 19,
+        None = 20,
+        ChangeOfDiscreteValue = 21,
+        ChangeOfTimer = 22,
+    },
+    u16,
+    64..=65535
+);
+
+/// Confirmed and Unconfirmed Event Notification request
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventNotificationRequest {
+    /// Process identifier of the recipient
+    pub process_identifier: u32,
+    /// Device that generated the notification
+    pub initiating_device_identifier: ObjectIdentifier,
+    /// Object that generated the event
+    pub event_object_identifier: ObjectIdentifier,
+    /// Time of the transition
+    pub time_stamp: BacnetTimeStamp,
+    /// Notification class of the event
+    pub notification_class: u32,
+    /// Priority of the notification
+    pub priority: u8,
+    /// Event algorithm that detected the event
+    pub event_type: EventType,
+    /// Message text (optional)
+    pub message_text: Option<String>,
+    /// Kind of notification
+    pub notify_type: NotifyType,
+    /// Whether the notification must be acknowledged (absent for
+    /// ack-notifications)
+    pub ack_required: Option<bool>,
+    /// Event state before the transition (absent for ack-notifications)
+    pub from_state: Option<EventState>,
+    /// Event state after the transition
+    pub to_state: EventState,
+    /// Values that caused the event (absent for ack-notifications)
+    pub event_values: Option<EventValues>,
+}
+
+/// Values of an event notification (BACnetNotificationParameters)
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventValues {
+    /// Change of value - context tag 2 (constructed)
+    ChangeOfValue {
+        /// The changed bits or value of the monitored property
+        new_value: ChangeOfValueNewValue,
+        /// Status flags of the object
+        status_flags: StatusFlags,
+    },
+    /// Out of range - context tag 5 (constructed)
+    OutOfRange {
+        /// Value that is out of range
+        exceeding_value: f32,
+        /// Status flags of the object
+        status_flags: StatusFlags,
+        /// Deadband of the object
+        deadband: f32,
+        /// Limit that was exceeded
+        exceeded_limit: f32,
+    },
+    /// Any other choice, kept encoded
+    Other {
+        /// Context tag of the choice
+        tag_number: u8,
+        /// Encoded content between the opening and closing tags
+        data: Vec<u8>,
+    },
+}
+
+/// New value reported by a change-of-value event
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangeOfValueNewValue {
+    /// Changed bits - context tag 0
+    ChangedBits(Vec<bool>),
+    /// Changed value - context tag 1
+    ChangedValue(f32),
+}
+
+impl EventNotificationRequest {
+    /// Encode the Event Notification request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Process identifier - context tag 0
+        buffer.extend_from_slice(&encode_context_unsigned(self.process_identifier, 0)?);
+
+        // Initiating device identifier - context tag 1
+        buffer.extend_from_slice(&encode_context_object_id(
+            self.initiating_device_identifier,
+            1,
+        )?);
+
+        // Event object identifier - context tag 2
+        buffer.extend_from_slice(&encode_context_object_id(self.event_object_identifier, 2)?);
+
+        // Time stamp - context tag 3 (constructed)
+        encode_opening_tag(buffer, 3)?;
+        self.time_stamp.encode(buffer)?;
+        encode_closing_tag(buffer, 3)?;
+
+        // Notification class - context tag 4
+        buffer.extend_from_slice(&encode_context_unsigned(self.notification_class, 4)?);
+
+        // Priority - context tag 5
+        buffer.extend_from_slice(&encode_context_unsigned(self.priority as u32, 5)?);
+
+        // Event type - context tag 6
+        buffer.extend_from_slice(&encode_context_enumerated(
+            u16::from(self.event_type) as u32,
+            6,
+        )?);
+
+        // Message text - context tag 7 (optional)
+        if let Some(message_text) = &self.message_text {
+            buffer.extend_from_slice(&encode_context_character_string(message_text, 7)?);
+        }
+
+        // Notify type - context tag 8
+        buffer.extend_from_slice(&encode_context_enumerated(self.notify_type as u32, 8)?);
+
+        // Ack required - context tag 9 (optional)
+        if let Some(ack_required) = self.ack_required {
+            buffer.extend_from_slice(&encode_context_boolean(ack_required, 9)?);
+        }
+
+        // From state - context tag 10 (optional)
+        if let Some(from_state) = self.from_state {
+            buffer.extend_from_slice(&encode_context_enumerated(
+                u16::from(from_state) as u32,
+                10,
+            )?);
+        }
+
+        // To state - context tag 11
+        buffer.extend_from_slice(&encode_context_enumerated(
+            u16::from(self.to_state) as u32,
+            11,
+        )?);
+
+        // Event values - context tag 12 (constructed, optional)
+        if let Some(event_values) = &self.event_values {
+            encode_opening_tag(buffer, 12)?;
+            event_values.encode(buffer)?;
+            encode_closing_tag(buffer, 12)?;
+        }
+
+        Ok(())
+    }
+
+    /// Decode an Event Notification request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        // Process identifier - context tag 0
+        let (process_identifier, mut pos) = decode_context_unsigned(data, 0)?;
+
+        // Initiating device identifier - context tag 1
+        let (initiating_device_identifier, consumed) = decode_context_object_id(&data[pos..], 1)?;
+        pos += consumed;
+
+        // Event object identifier - context tag 2
+        let (event_object_identifier, consumed) = decode_context_object_id(&data[pos..], 2)?;
+        pos += consumed;
+
+        // Time stamp - context tag 3 (constructed)
+        pos += expect_constructed_tag(&data[pos..], 3, 6)?;
+        let (time_stamp, consumed) = BacnetTimeStamp::decode(&data[pos..])?;
+        pos += consumed;
+        pos += expect_constructed_tag(&data[pos..], 3, 7)?;
+
+        // Notification class - context tag 4
+        let (notification_class, consumed) = decode_context_unsigned(&data[pos..], 4)?;
+        pos += consumed;
+
+        // Priority - context tag 5
+        let (priority, consumed) = decode_context_unsigned(&data[pos..], 5)?;
+        let priority = u8::try_from(priority).map_err(|_| EncodingError::ValueOutOfRange)?;
+        pos += consumed;
+
+        // Event type - context tag 6
+        let (event_type, consumed) = decode_context_enumerated(&data[pos..], 6)?;
+        let event_type = u16::try_from(event_type).map_err(|_| EncodingError::ValueOutOfRange)?;
+        pos += consumed;
+
+        // Message text - context tag 7 (optional)
+        let message_text = match decode_context_character_string(&data[pos..], 7) {
+            Ok((message_text, consumed)) => {
+                pos += consumed;
+                Some(message_text)
+            }
+            Err(_) => None,
+        };
+
+        // Notify type - context tag 8
+        let (notify_type, consumed) = decode_context_enumerated(&data[pos..], 8)?;
+        pos += consumed;
+
+        // Ack required - context tag 9 (optional)
+        let ack_required = match decode_context_boolean(&data[pos..], 9) {
+            Ok((ack_required, consumed)) => {
+                pos += consumed;
+                Some(ack_required)
+            }
+            Err(_) => None,
         };
-        let utc_date_time = BacnetDateTime::new(date, time);
-        Self::new(utc_date_time)
+
+        // From state - context tag 10 (optional)
+        let from_state = match decode_context_enumerated(&data[pos..], 10) {
+            Ok((from_state, consumed)) => {
+                pos += consumed;
+                Some(decode_event_state(from_state)?)
+            }
+            Err(_) => None,
+        };
+
+        // To state - context tag 11
+        let (to_state, consumed) = decode_context_enumerated(&data[pos..], 11)?;
+        let to_state = decode_event_state(to_state)?;
+        pos += consumed;
+
+        // Event values - context tag 12 (constructed, optional)
+        let event_values = if pos < data.len() {
+            pos += expect_constructed_tag(&data[pos..], 12, 6)?;
+            let (event_values, consumed) = EventValues::decode(&data[pos..])?;
+            pos += consumed;
+            pos += expect_constructed_tag(&data[pos..], 12, 7)?;
+            Some(event_values)
+        } else {
+            None
+        };
+
+        if pos != data.len() {
+            return Err(EncodingError::InvalidFormat(
+                "Trailing data after Event Notification request".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            process_identifier,
+            initiating_device_identifier,
+            event_object_identifier,
+            time_stamp,
+            notification_class,
+            priority,
+            event_type: EventType::from(event_type),
+            message_text,
+            notify_type: NotifyType::try_from(notify_type)?,
+            ack_required,
+            from_state,
+            to_state,
+            event_values,
+        })
     }
+}
 
-    /// Encode the UTC Time Synchronization request
+fn decode_event_state(value: u32) -> EncodingResult<EventState> {
+    u16::try_from(value)
+        .map(EventState::from)
+        .map_err(|_| EncodingError::ValueOutOfRange)
+}
+
+impl EventValues {
+    /// Encode the event values
     pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
-        self.utc_date_time.encode(buffer)
+        match self {
+            EventValues::ChangeOfValue {
+                new_value,
+                status_flags,
+            } => {
+                encode_opening_tag(buffer, 2)?;
+
+                // New value - context tag 0 (constructed)
+                encode_opening_tag(buffer, 0)?;
+                match new_value {
+                    ChangeOfValueNewValue::ChangedBits(bits) => {
+                        buffer.extend_from_slice(&encode_context_bit_string(bits, 0)?);
+                    }
+                    ChangeOfValueNewValue::ChangedValue(value) => {
+                        buffer.extend_from_slice(&encode_context_real(*value, 1)?);
+                    }
+                }
+                encode_closing_tag(buffer, 0)?;
+
+                // Status flags - context tag 1
+                buffer.extend_from_slice(&status_flags.encode_context(1)?);
+
+                encode_closing_tag(buffer, 2)?;
+            }
+            EventValues::OutOfRange {
+                exceeding_value,
+                status_flags,
+                deadband,
+                exceeded_limit,
+            } => {
+                encode_opening_tag(buffer, 5)?;
+                buffer.extend_from_slice(&encode_context_real(*exceeding_value, 0)?);
+                buffer.extend_from_slice(&status_flags.encode_context(1)?);
+                buffer.extend_from_slice(&encode_context_real(*deadband, 2)?);
+                buffer.extend_from_slice(&encode_context_real(*exceeded_limit, 3)?);
+                encode_closing_tag(buffer, 5)?;
+            }
+            EventValues::Other { tag_number, data } => {
+                encode_opening_tag(buffer, *tag_number)?;
+                buffer.extend_from_slice(data);
+                encode_closing_tag(buffer, *tag_number)?;
+            }
+        }
+
+        Ok(())
     }
 
-    /// Decode a UTC Time Synchronization request
+    /// Decode event values, returning them with the number of bytes consumed
+    ///
+    /// The choice is read from its opening tag.
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        let (tag_number, _, _) = decode_context_tag(data)?;
+        let mut pos = expect_constructed_tag(data, tag_number, 6)?;
+        let end = pos + constructed_value_length(&data[pos..], tag_number)?;
+        let content = &data[pos..end];
+
+        let event_values = match tag_number {
+            2 => {
+                // New value - context tag 0 (constructed)
+                let mut offset = expect_constructed_tag(content, 0, 6)?;
+                let new_value = match decode_context_bit_string(&content[offset..], 0) {
+                    Ok((bits, consumed)) => {
+                        offset += consumed;
+                        ChangeOfValueNewValue::ChangedBits(bits)
+                    }
+                    Err(_) => {
+                        let (value, consumed) = decode_context_real(&content[offset..], 1)?;
+                        offset += consumed;
+                        ChangeOfValueNewValue::ChangedValue(value)
+                    }
+                };
+                offset += expect_constructed_tag(&content[offset..], 0, 7)?;
+
+                // Status flags - context tag 1
+                let (status_flags, _) = StatusFlags::decode_context(&content[offset..], 1)?;
+
+                EventValues::ChangeOfValue {
+                    new_value,
+                    status_flags,
+                }
+            }
+            5 => {
+                let (exceeding_value, mut offset) = decode_context_real(content, 0)?;
+                let (status_flags, consumed) = StatusFlags::decode_context(&content[offset..], 1)?;
+                offset += consumed;
+                let (deadband, consumed) = decode_context_real(&content[offset..], 2)?;
+                offset += consumed;
+                let (exceeded_limit, _) = decode_context_real(&content[offset..], 3)?;
+
+                EventValues::OutOfRange {
+                    exceeding_value,
+                    status_flags,
+                    deadband,
+                    exceeded_limit,
+                }
+            }
+            _ => EventValues::Other {
+                tag_number,
+                data: content.to_vec(),
+            },
+        };
+
+        pos = end + expect_constructed_tag(&data[end..], tag_number, 7)?;
+        Ok((event_values, pos))
+    }
+}
+
+/// Get Event Information request (confirmed service)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GetEventInformationRequest {
+    /// Last object of the previous page, to continue after it (optional)
+    pub last_received_object_identifier: Option<ObjectIdentifier>,
+}
+
+impl GetEventInformationRequest {
+    /// Create a request for the first page
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a request for the page after `last_received_object_identifier`
+    pub fn after(last_received_object_identifier: ObjectIdentifier) -> Self {
+        Self {
+            last_received_object_identifier: Some(last_received_object_identifier),
+        }
+    }
+
+    /// Encode the Get Event Information request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Last received object identifier - context tag 0 (optional)
+        if let Some(object_identifier) = self.last_received_object_identifier {
+            buffer.extend_from_slice(&encode_context_object_id(object_identifier, 0)?);
+        }
+        Ok(())
+    }
+
+    /// Decode a Get Event Information request
     pub fn decode(data: &[u8]) -> EncodingResult<Self> {
-        let (utc_date_time, _consumed) = BacnetDateTime::decode(data)?;
-        Ok(Self::new(utc_date_time))
+        if data.is_empty() {
+            return Ok(Self::new());
+        }
+        let (object_identifier, _) = decode_context_object_id(data, 0)?;
+        Ok(Self::after(object_identifier))
+    }
+}
+
+/// Event state of an object, as reported by Get Event Information
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventSummary {
+    /// Object identifier
+    pub object_identifier: ObjectIdentifier,
+    /// Event state of the object
+    pub event_state: EventState,
+    /// Acknowledged transitions (to-offnormal, to-fault, to-normal)
+    pub acknowledged_transitions: Vec<bool>,
+    /// Time stamps of the to-offnormal, to-fault and to-normal transitions
+    pub event_time_stamps: [BacnetTimeStamp; 3],
+    /// Kind of notification generated
+    pub notify_type: NotifyType,
+    /// Enabled transitions (to-offnormal, to-fault, to-normal)
+    pub event_enable: Vec<bool>,
+    /// Priorities of the to-offnormal, to-fault and to-normal notifications
+    pub event_priorities: [u32; 3],
+}
+
+impl EventSummary {
+    /// Encode the event summary
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Object identifier - context tag 0
+        buffer.extend_from_slice(&encode_context_object_id(self.object_identifier, 0)?);
+
+        // Event state - context tag 1
+        buffer.extend_from_slice(&encode_context_enumerated(
+            u16::from(self.event_state) as u32,
+            1,
+        )?);
+
+        // Acknowledged transitions - context tag 2
+        buffer.extend_from_slice(&encode_context_bit_string(
+            &self.acknowledged_transitions,
+            2,
+        )?);
+
+        // Event time stamps - context tag 3 (constructed)
+        BacnetTimeStamp::encode_array(&self.event_time_stamps, 3, buffer)?;
+
+        // Notify type - context tag 4
+        buffer.extend_from_slice(&encode_context_enumerated(self.notify_type as u32, 4)?);
+
+        // Event enable - context tag 5
+        buffer.extend_from_slice(&encode_context_bit_string(&self.event_enable, 5)?);
+
+        // Event priorities - context tag 6 (constructed)
+        encode_opening_tag(buffer, 6)?;
+        for priority in self.event_priorities {
+            write_unsigned(buffer, priority)?;
+        }
+        encode_closing_tag(buffer, 6)?;
+
+        Ok(())
+    }
+
+    /// Decode an event summary, returning it with the number of bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        // Object identifier - context tag 0
+        let (object_identifier, mut pos) = decode_context_object_id(data, 0)?;
+
+        // Event state - context tag 1
+        let (event_state, consumed) = decode_context_enumerated(&data[pos..], 1)?;
+        pos += consumed;
+        let event_state = u16::try_from(event_state).map_err(|_| EncodingError::ValueOutOfRange)?;
+
+        // Acknowledged transitions - context tag 2
+        let (acknowledged_transitions, consumed) = decode_context_bit_string(&data[pos..], 2)?;
+        pos += consumed;
+
+        // Event time stamps - context tag 3 (constructed)
+        let (event_time_stamps, consumed) = BacnetTimeStamp::decode_array(&data[pos..], 3)?;
+        pos += consumed;
+
+        // Notify type - context tag 4
+        let (notify_type, consumed) = decode_context_enumerated(&data[pos..], 4)?;
+        pos += consumed;
+
+        // Event enable - context tag 5
+        let (event_enable, consumed) = decode_context_bit_string(&data[pos..], 5)?;
+        pos += consumed;
+
+        // Event priorities - context tag 6 (constructed)
+        pos += expect_constructed_tag(&data[pos..], 6, 6)?;
+        let mut event_priorities = [0; 3];
+        for priority in &mut event_priorities {
+            let (value, consumed) = decode_unsigned(&data[pos..])?;
+            *priority = value;
+            pos += consumed;
+        }
+        pos += expect_constructed_tag(&data[pos..], 6, 7)?;
+
+        Ok((
+            Self {
+                object_identifier,
+                event_state: EventState::from(event_state),
+                acknowledged_transitions,
+                event_time_stamps,
+                notify_type: NotifyType::try_from(notify_type)?,
+                event_enable,
+                event_priorities,
+            },
+            pos,
+        ))
+    }
+}
+
+/// Get Event Information response
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GetEventInformationResponse {
+    /// Objects with active events
+    pub list_of_event_summaries: Vec<EventSummary>,
+    /// More objects follow the last one returned
+    pub more_events: bool,
+}
+
+impl GetEventInformationResponse {
+    /// Create a new Get Event Information response
+    pub fn new(list_of_event_summaries: Vec<EventSummary>, more_events: bool) -> Self {
+        Self {
+            list_of_event_summaries,
+            more_events,
+        }
+    }
+
+    /// The request for the next page, if there are more events
+    pub fn next_request(&self) -> Option<GetEventInformationRequest> {
+        if !self.more_events {
+            return None;
+        }
+        self.list_of_event_summaries
+            .last()
+            .map(|summary| GetEventInformationRequest::after(summary.object_identifier))
+    }
+
+    /// Encode the Get Event Information response
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // List of event summaries - context tag 0 (constructed)
+        encode_opening_tag(buffer, 0)?;
+        for summary in &self.list_of_event_summaries {
+            summary.encode(buffer)?;
+        }
+        encode_closing_tag(buffer, 0)?;
+
+        // More events - context tag 1
+        buffer.extend_from_slice(&encode_context_boolean(self.more_events, 1)?);
+
+        Ok(())
+    }
+
+    /// Decode a Get Event Information response
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        // List of event summaries - context tag 0 (constructed)
+        let mut pos = expect_constructed_tag(data, 0, 6)?;
+        let end = pos + constructed_value_length(&data[pos..], 0)?;
+        let mut list_of_event_summaries = Vec::new();
+        while pos < end {
+            let (summary, consumed) = EventSummary::decode(&data[pos..end])?;
+            list_of_event_summaries.push(summary);
+            pos += consumed;
+        }
+        pos += expect_constructed_tag(&data[pos..], 0, 7)?;
+
+        // More events - context tag 1
+        let (more_events, _) = decode_context_boolean(&data[pos..], 1)?;
+
+        Ok(Self {
+            list_of_event_summaries,
+            more_events,
+        })
+    }
+}
+
+/// Acknowledge Alarm request (confirmed service)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AcknowledgeAlarmRequest {
+    /// Process identifier of the acknowledging process
+    pub acknowledging_process_identifier: u32,
+    /// Object that generated the event
+    pub event_object_identifier: ObjectIdentifier,
+    /// Event state being acknowledged
+    pub event_state_acknowledged: EventState,
+    /// Time stamp of the transition being acknowledged
+    pub time_stamp: BacnetTimeStamp,
+    /// Who or what acknowledged the alarm, e.g. an operator name
+    pub acknowledgment_source: String,
+    /// When the alarm was acknowledged
+    pub time_of_acknowledgment: BacnetTimeStamp,
+}
+
+impl AcknowledgeAlarmRequest {
+    /// Create a new Acknowledge Alarm request
+    pub fn new(
+        acknowledging_process_identifier: u32,
+        event_object_identifier: ObjectIdentifier,
+        event_state_acknowledged: EventState,
+        time_stamp: BacnetTimeStamp,
+        acknowledgment_source: impl Into<String>,
+        time_of_acknowledgment: BacnetTimeStamp,
+    ) -> Self {
+        Self {
+            acknowledging_process_identifier,
+            event_object_identifier,
+            event_state_acknowledged,
+            time_stamp,
+            acknowledgment_source: acknowledgment_source.into(),
+            time_of_acknowledgment,
+        }
+    }
+
+    /// Encode the Acknowledge Alarm request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Acknowledging process identifier - context tag 0
+        buffer.extend_from_slice(&encode_context_unsigned(
+            self.acknowledging_process_identifier,
+            0,
+        )?);
+
+        // Event object identifier - context tag 1
+        buffer.extend_from_slice(&encode_context_object_id(self.event_object_identifier, 1)?);
+
+        // Event state acknowledged - context tag 2
+        buffer.extend_from_slice(&encode_context_enumerated(
+            u16::from(self.event_state_acknowledged) as u32,
+            2,
+        )?);
+
+        // Time stamp - context tag 3 (constructed)
+        encode_opening_tag(buffer, 3)?;
+        self.time_stamp.encode(buffer)?;
+        encode_closing_tag(buffer, 3)?;
+
+        // Acknowledgment source - context tag 4
+        buffer.extend_from_slice(&encode_context_character_string(
+            &self.acknowledgment_source,
+            4,
+        )?);
+
+        // Time of acknowledgment - context tag 5 (constructed)
+        encode_opening_tag(buffer, 5)?;
+        self.time_of_acknowledgment.encode(buffer)?;
+        encode_closing_tag(buffer, 5)?;
+
+        Ok(())
+    }
+
+    /// Decode an Acknowledge Alarm request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        // Acknowledging process identifier - context tag 0
+        let (acknowledging_process_identifier, mut pos) = decode_context_unsigned(data, 0)?;
+
+        // Event object identifier - context tag 1
+        let (event_object_identifier, consumed) = decode_context_object_id(&data[pos..], 1)?;
+        pos += consumed;
+
+        // Event state acknowledged - context tag 2
+        let (event_state_acknowledged, consumed) = decode_context_enumerated(&data[pos..], 2)?;
+        pos += consumed;
+        let event_state_acknowledged =
+            u16::try_from(event_state_acknowledged).map_err(|_| EncodingError::ValueOutOfRange)?;
+
+        // Time stamp - context tag 3 (constructed)
+        pos += expect_constructed_tag(&data[pos..], 3, 6)?;
+        let (time_stamp, consumed) = BacnetTimeStamp::decode(&data[pos..])?;
+        pos += consumed;
+        pos += expect_constructed_tag(&data[pos..], 3, 7)?;
+
+        // Acknowledgment source - context tag 4
+        let (acknowledgment_source, consumed) = decode_context_character_string(&data[pos..], 4)?;
+        pos += consumed;
+
+        // Time of acknowledgment - context tag 5 (constructed)
+        pos += expect_constructed_tag(&data[pos..], 5, 6)?;
+        let (time_of_acknowledgment, consumed) = BacnetTimeStamp::decode(&data[pos..])?;
+        pos += consumed;
+        expect_constructed_tag(&data[pos..], 5, 7)?;
+
+        Ok(Self {
+            acknowledging_process_identifier,
+            event_object_identifier,
+            event_state_acknowledged: EventState::from(event_state_acknowledged),
+            time_stamp,
+            acknowledgment_source,
+            time_of_acknowledgment,
+        })
     }
 }
 
@@ -2042,147 +6473,914 @@ mod tests {
         assert!(whois_all.matches(123));
         assert!(whois_all.matches(456));
 
-        // Test Who-Is for specific device
-        let whois_specific = WhoIsRequest::for_device(123);
-        assert!(whois_specific.matches(123));
-        assert!(!whois_specific.matches(124));
+        // Test Who-Is for specific device
+        let whois_specific = WhoIsRequest::for_device(123);
+        assert!(whois_specific.matches(123));
+        assert!(!whois_specific.matches(124));
+
+        // Test Who-Is for range
+        let whois_range = WhoIsRequest::for_range(100, 200);
+        assert!(whois_range.matches(150));
+        assert!(!whois_range.matches(50));
+        assert!(!whois_range.matches(250));
+    }
+
+    #[test]
+    fn test_respond_to_whois() {
+        let device = IAmRequest::for_device(1234, 1476, Segmentation::Both, 260);
+        assert_eq!(
+            device.device_identifier,
+            ObjectIdentifier::new(ObjectType::Device, 1234)
+        );
+
+        // In range
+        let apdu = respond_to_whois(&WhoIsRequest::for_range(1000, 2000), &device).unwrap();
+        assert_eq!(&apdu[..2], &[0x10, UnconfirmedServiceChoice::IAm as u8]);
+        assert_eq!(IAmRequest::decode(&apdu[2..]).unwrap(), device);
+
+        // Out of range
+        assert!(!WhoIsRequest::for_device(1235).should_respond(1234));
+        assert!(respond_to_whois(&WhoIsRequest::for_range(0, 1233), &device).is_none());
+
+        // Unbounded
+        assert!(WhoIsRequest::new().should_respond(4194303));
+        assert!(respond_to_whois(&WhoIsRequest::new(), &device).is_some());
+    }
+
+    #[test]
+    fn test_whois_encoding() {
+        let mut buffer = Vec::new();
+
+        // Test encoding Who-Is for all devices
+        let whois_all = WhoIsRequest::new();
+        whois_all.encode(&mut buffer).unwrap();
+        assert_eq!(buffer.len(), 0); // No parameters for all devices
+
+        // Test encoding Who-Is for specific device
+        buffer.clear();
+        let whois_specific = WhoIsRequest::for_device(123);
+        whois_specific.encode(&mut buffer).unwrap();
+        assert!(!buffer.is_empty());
+
+        // Test decoding
+        let decoded = WhoIsRequest::decode(&buffer).unwrap();
+        assert_eq!(decoded, whois_specific);
+    }
+
+    #[test]
+    fn test_whois_lone_low_limit() {
+        // Low limit 100 followed by an application-tagged unsigned instead of
+        // context tag 1
+        let data = [0x09, 100, 0x21, 0x05];
+        assert!(WhoIsRequest::decode(&data).is_err());
+
+        let lenient = WhoIsRequest::decode_lenient(&data).unwrap();
+        assert_eq!(lenient.device_instance_range_low_limit, Some(100));
+        assert_eq!(lenient.device_instance_range_high_limit, None);
+        assert!(lenient.should_respond(100));
+        assert!(lenient.should_respond(4_000_000));
+        assert!(!lenient.should_respond(99));
+
+        // A bare low limit is accepted by both
+        let bare = [0x09, 100];
+        let expected = WhoIsRequest {
+            device_instance_range_low_limit: Some(100),
+            device_instance_range_high_limit: None,
+        };
+        assert_eq!(WhoIsRequest::decode(&bare).unwrap(), expected);
+        assert_eq!(WhoIsRequest::decode_lenient(&bare).unwrap(), expected);
+
+        // Well-formed requests decode the same either way
+        let mut buffer = Vec::new();
+        WhoIsRequest::for_range(10, 20).encode(&mut buffer).unwrap();
+        assert_eq!(
+            WhoIsRequest::decode_lenient(&buffer).unwrap(),
+            WhoIsRequest::decode(&buffer).unwrap()
+        );
+        assert_eq!(
+            WhoIsRequest::decode_lenient(&[]).unwrap(),
+            WhoIsRequest::new()
+        );
+    }
+
+    #[test]
+    fn test_iam_request() {
+        let device_id = ObjectIdentifier::new(ObjectType::Device, 123);
+        let iam = IAmRequest::new(device_id, 1476, Segmentation::Both, 999);
+
+        assert_eq!(iam.device_identifier.instance, 123);
+        assert_eq!(iam.max_apdu_length_accepted, 1476);
+        assert_eq!(iam.vendor_identifier, 999);
+    }
+
+    #[test]
+    fn test_read_property_request() {
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+        let read_prop = ReadPropertyRequest::new(object_id, PropertyIdentifier::PresentValue); // Present Value
+
+        assert_eq!(read_prop.object_identifier.instance, 1);
+        assert_eq!(
+            read_prop.property_identifier,
+            PropertyIdentifier::PresentValue
+        );
+        assert_eq!(read_prop.property_array_index, None);
+
+        let read_prop_array =
+            ReadPropertyRequest::with_array_index(object_id, PropertyIdentifier::PresentValue, 0);
+        assert_eq!(read_prop_array.property_array_index, Some(0));
+    }
+
+    #[test]
+    fn test_write_property_request() {
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogOutput, 1);
+        let property_value = vec![0x44, 0x42, 0x20, 0x00, 0x00]; // Real 40.0
+        let write_prop = WritePropertyRequest::new(object_id, 85, property_value.clone());
+
+        assert_eq!(write_prop.object_identifier.instance, 1);
+        assert_eq!(write_prop.property_identifier, 85);
+        assert_eq!(write_prop.property_value, property_value);
+        assert_eq!(write_prop.priority, None);
+
+        // Test with priority
+        let write_prop_priority =
+            WritePropertyRequest::with_priority(object_id, 85, property_value.clone(), 8);
+        assert_eq!(write_prop_priority.priority, Some(8));
+
+        // Test encoding/decoding
+        let mut buffer = Vec::new();
+        write_prop.encode(&mut buffer).unwrap();
+        assert!(!buffer.is_empty());
+
+        let decoded = WritePropertyRequest::decode(&buffer).unwrap();
+        assert_eq!(decoded.object_identifier.instance, 1);
+        assert_eq!(decoded.property_identifier, 85);
+        assert_eq!(decoded.property_value, property_value);
+    }
+
+    #[test]
+    fn test_read_property_multiple_request() {
+        let object_id1 = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+        let object_id2 = ObjectIdentifier::new(ObjectType::BinaryInput, 2);
+
+        let prop_ref1 = PropertyReference::new(PropertyIdentifier::PresentValue); // Present Value
+        let prop_ref2 = PropertyReference::new(PropertyIdentifier::ObjectName); // Object Name
+        let prop_ref3 = PropertyReference::with_array_index(PropertyIdentifier::PriorityArray, 8); // Priority Array[8]
+
+        let spec1 = ReadAccessSpecification::new(object_id1, vec![prop_ref1, prop_ref2]);
+        let spec2 = ReadAccessSpecification::new(object_id2, vec![prop_ref3]);
+
+        let rpm_request = ReadPropertyMultipleRequest::new(vec![spec1, spec2]);
+
+        assert_eq!(rpm_request.read_access_specifications.len(), 2);
+        assert_eq!(
+            rpm_request.read_access_specifications[0]
+                .property_references
+                .len(),
+            2
+        );
+        assert_eq!(
+            rpm_request.read_access_specifications[1]
+                .property_references
+                .len(),
+            1
+        );
+        assert_eq!(
+            rpm_request.read_access_specifications[1].property_references[0].property_array_index,
+            Some(8)
+        );
+    }
+
+    #[test]
+    fn test_read_property_multiple_request_encoding() {
+        // RPM service data as sent by YABE: an analog input's present-value,
+        // status-flags and priority-array[8], followed by ALL for the device.
+        let captured = vec![
+            0x0C, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x09, 0x55, 0x09, 0x6F, 0x09, 0x57, 0x19, 0x08,
+            0x1F, 0x0C, 0x02, 0x00, 0x04, 0xD2, 0x1E, 0x09, 0x08, 0x1F,
+        ];
+
+        let request = ReadPropertyMultipleRequest::new(vec![
+            ReadAccessSpecification::new(
+                ObjectIdentifier::new(ObjectType::AnalogInput, 0),
+                vec![
+                    PropertyReference::new(PropertyIdentifier::PresentValue),
+                    PropertyReference::new(PropertyIdentifier::StatusFlags),
+                    PropertyReference::with_array_index(PropertyIdentifier::PriorityArray, 8),
+                ],
+            ),
+            ReadAccessSpecification::new(
+                ObjectIdentifier::new(ObjectType::Device, 1234),
+                vec![PropertyReference::new(PropertyIdentifier::All)],
+            ),
+        ]);
+
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, captured);
+
+        let decoded = ReadPropertyMultipleRequest::decode(&captured).unwrap();
+        assert_eq!(decoded, request);
+
+        // BACNET_ARRAY_ALL is the same as no array index
+        let all_elements = ReadAccessSpecification::new(
+            ObjectIdentifier::new(ObjectType::AnalogInput, 0),
+            vec![PropertyReference::with_array_index(
+                PropertyIdentifier::PriorityArray,
+                BACNET_ARRAY_ALL,
+            )],
+        );
+        buffer.clear();
+        all_elements.encode(&mut buffer).unwrap();
+        assert_eq!(&buffer[5..], &[0x1E, 0x09, 0x57, 0x1F]);
+
+        // An empty property list cannot be encoded
+        let empty =
+            ReadAccessSpecification::new(ObjectIdentifier::new(ObjectType::AnalogInput, 0), vec![]);
+        assert!(empty.encode(&mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_subscribe_cov_request() {
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+        let cov_req = SubscribeCovRequest::new(123, object_id);
+
+        assert_eq!(cov_req.subscriber_process_identifier, 123);
+        assert_eq!(cov_req.monitored_object_identifier.instance, 1);
+        assert_eq!(cov_req.issue_confirmed_notifications, None);
+        assert_eq!(cov_req.lifetime, None);
+
+        // Test with confirmation
+        let cov_confirmed = SubscribeCovRequest::with_confirmation(123, object_id, true);
+        assert_eq!(cov_confirmed.issue_confirmed_notifications, Some(true));
+
+        // Test with lifetime
+        let cov_lifetime = SubscribeCovRequest::with_lifetime(123, object_id, 3600);
+        assert_eq!(cov_lifetime.lifetime, Some(3600));
+
+        // Test encoding
+        let mut buffer = Vec::new();
+        cov_req.encode(&mut buffer).unwrap();
+        assert!(!buffer.is_empty());
+    }
+
+    #[test]
+    fn test_object_identifier_out_of_range() {
+        // One past the largest 22-bit instance number
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogValue, 0x400000);
+
+        let write = WritePropertyRequest::new(object_id, 85, vec![0x44, 0, 0, 0, 0]);
+        assert!(matches!(
+            write.encode(&mut Vec::new()),
+            Err(EncodingError::ValueOutOfRange)
+        ));
+        assert!(matches!(
+            SubscribeCovRequest::new(1, object_id).encode(&mut Vec::new()),
+            Err(EncodingError::ValueOutOfRange)
+        ));
+        assert!(matches!(
+            crate::encoding::write_object_identifier(&mut Vec::new(), object_id),
+            Err(EncodingError::ValueOutOfRange)
+        ));
+    }
+
+    #[test]
+    fn test_create_object_trend_log() {
+        let request = CreateObjectRequest::new(ObjectSpecifier::ObjectType(ObjectType::TrendLog))
+            .with_initial_value(BacnetPropertyValue::new(
+                PropertyIdentifier::LogInterval,
+                property::PropertyValue::Unsigned(90000),
+            ));
+
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(
+            buffer,
+            vec![
+                0x0E, 0x09, 0x14, 0x0F, // any trend log
+                0x1E, 0x09, 0x86, // log interval
+                0x2E, 0x23, 0x01, 0x5F, 0x90, 0x2F, // 90000 hundredths
+                0x1F,
+            ]
+        );
+        assert_eq!(CreateObjectRequest::decode(&buffer).unwrap(), request);
+
+        // A specific instance, no initial values
+        let log = ObjectIdentifier::new(ObjectType::TrendLog, 7);
+        let request = CreateObjectRequest::new(ObjectSpecifier::ObjectIdentifier(log));
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![0x0E, 0x1C, 0x05, 0x00, 0x00, 0x07, 0x0F]);
+        assert_eq!(CreateObjectRequest::decode(&buffer).unwrap(), request);
+
+        let response = CreateObjectResponse::new(log);
+        let mut buffer = Vec::new();
+        response.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![0xC4, 0x05, 0x00, 0x00, 0x07]);
+        assert_eq!(CreateObjectResponse::decode(&buffer).unwrap(), response);
+
+        let request = DeleteObjectRequest::new(log);
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(DeleteObjectRequest::decode(&buffer).unwrap(), request);
+    }
+
+    #[test]
+    fn test_create_object_error() {
+        // property / value-out-of-range on the first initial value
+        let data = [0x0E, 0x91, 0x02, 0x91, 0x25, 0x0F, 0x19, 0x01];
+        let error = CreateObjectError::decode(&data).unwrap();
+        assert_eq!(
+            error,
+            CreateObjectError {
+                error_class: 2,
+                error_code: 37,
+                first_failed_element_number: 1,
+            }
+        );
+
+        let mut buffer = Vec::new();
+        error.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, data);
+    }
+
+    #[test]
+    fn test_lighting_command_fade_to() {
+        let command = LightingCommand::fade_to(50.0, 2000);
+
+        let mut buffer = Vec::new();
+        command.encode(&mut buffer).unwrap();
+        assert_eq!(
+            buffer,
+            vec![
+                0x09, 0x01, // fade-to
+                0x1C, 0x42, 0x48, 0x00, 0x00, // 50.0 %
+                0x4A, 0x07, 0xD0, // 2000 ms
+            ]
+        );
+        assert_eq!(LightingCommand::decode(&buffer).unwrap(), (command, 10));
+
+        // Priority is carried through, proprietary operations are kept
+        let command = LightingCommand {
+            priority: Some(8),
+            ..LightingCommand::new(LightingOperation::from(300))
+        };
+        let mut buffer = Vec::new();
+        command.encode(&mut buffer).unwrap();
+        let (decoded, _) = LightingCommand::decode(&buffer).unwrap();
+        assert_eq!(decoded, command);
+        assert_eq!(u32::from(decoded.operation), 300);
+
+        // Fade times below 100ms are out of range
+        let mut buffer = Vec::new();
+        assert!(LightingCommand::fade_to(50.0, 50)
+            .encode(&mut buffer)
+            .is_err());
+    }
+
+    #[test]
+    fn test_add_list_element_recipient() {
+        let notification_class = ObjectIdentifier::new(ObjectType::NotificationClass, 1);
+
+        // A BACnetDestination for device 1234, every day, all day
+        let mut destination = Vec::new();
+        crate::encoding::advanced::bitstring::encode_bit_string(&mut destination, &[true; 7])
+            .unwrap();
+        crate::encoding::write_time(&mut destination, 0, 0, 0, 0).unwrap();
+        crate::encoding::write_time(&mut destination, 23, 59, 59, 99).unwrap();
+        Recipient::Device(ObjectIdentifier::new(ObjectType::Device, 1234))
+            .encode(&mut destination)
+            .unwrap();
+        write_unsigned(&mut destination, 1).unwrap();
+        crate::encoding::write_boolean(&mut destination, true).unwrap();
+        crate::encoding::advanced::bitstring::encode_bit_string(
+            &mut destination,
+            &[true, true, true],
+        )
+        .unwrap();
+
+        let request = AddListElementRequest::new(
+            notification_class,
+            PropertyIdentifier::RecipientList,
+            destination.clone(),
+        );
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(&buffer[..7], &[0x0C, 0x03, 0xC0, 0x00, 0x01, 0x19, 0x66]);
+        assert_eq!(buffer[7], 0x3E);
+        assert_eq!(&buffer[8..8 + destination.len()], &destination[..]);
+        assert_eq!(buffer[buffer.len() - 1], 0x3F);
+        assert_eq!(AddListElementRequest::decode(&buffer).unwrap(), request);
+
+        // The recipient is read back out of the raw element
+        let (recipient, _) = Recipient::decode(&destination[13..]).unwrap();
+        assert_eq!(
+            recipient,
+            Recipient::Device(ObjectIdentifier::new(ObjectType::Device, 1234))
+        );
+
+        let request = RemoveListElementRequest {
+            property_array_index: Some(1),
+            ..RemoveListElementRequest::new(
+                notification_class,
+                PropertyIdentifier::RecipientList,
+                destination,
+            )
+        };
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(RemoveListElementRequest::decode(&buffer).unwrap(), request);
+
+        // object / unknown-object on the first element
+        let data = [0x0E, 0x91, 0x01, 0x91, 0x1F, 0x0F, 0x19, 0x01];
+        let error = ChangeListError::decode(&data).unwrap();
+        assert_eq!(error.first_failed_element_number, 1);
+        assert_eq!(error.error_code, 31);
+        let mut buffer = Vec::new();
+        error.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, data);
+    }
+
+    #[test]
+    fn test_device_object_reference_list() {
+        let references = vec![
+            DeviceObjectReference::new(ObjectIdentifier::new(ObjectType::AnalogInput, 1)),
+            DeviceObjectReference::with_device(
+                ObjectIdentifier::new(ObjectType::Device, 260001),
+                ObjectIdentifier::new(ObjectType::BinaryOutput, 3),
+            ),
+        ];
+
+        let mut buffer = Vec::new();
+        DeviceObjectReference::encode_list(&references, &mut buffer).unwrap();
+        assert_eq!(
+            buffer,
+            vec![
+                0x1C, 0x00, 0x00, 0x00, 0x01, // analog input 1
+                0x0C, 0x02, 0x03, 0xF7, 0xA1, // device 260001
+                0x1C, 0x01, 0x00, 0x00, 0x03, // binary output 3
+            ]
+        );
+        assert_eq!(
+            DeviceObjectReference::decode_list(&buffer).unwrap(),
+            references
+        );
+
+        // A device identifier alone is not a reference
+        assert!(DeviceObjectReference::decode_list(&buffer[5..10]).is_err());
+    }
+
+    #[test]
+    fn test_read_range_request_by_position() {
+        let log = ObjectIdentifier::new(ObjectType::TrendLog, 1);
+
+        let mut buffer = Vec::new();
+        ReadRangeRequest::by_position(log, PropertyIdentifier::LogBuffer, 1, 2)
+            .encode(&mut buffer)
+            .unwrap();
+        assert_eq!(
+            buffer,
+            vec![
+                0x0C, 0x05, 0x00, 0x00, 0x01, // trend log 1
+                0x19, 0x83, // log buffer
+                0x3E, 0x21, 0x01, 0x31, 0x02, 0x3F, // from index 1, 2 items
+            ]
+        );
+
+        // Reading backwards from a large index
+        buffer.clear();
+        ReadRangeRequest::by_position(log, PropertyIdentifier::LogBuffer, 1000, -10)
+            .encode(&mut buffer)
+            .unwrap();
+        assert_eq!(&buffer[7..], &[0x3E, 0x22, 0x03, 0xE8, 0x31, 0xF6, 0x3F]);
+
+        // The whole list
+        buffer.clear();
+        ReadRangeRequest::new(log, PropertyIdentifier::LogBuffer)
+            .encode(&mut buffer)
+            .unwrap();
+        assert_eq!(buffer.len(), 7);
+    }
+
+    #[test]
+    fn test_log_record_decoding() {
+        let timestamp = BacnetDateTime::new(
+            crate::object::Date {
+                year: 2024,
+                month: 3,
+                day: 15,
+                weekday: 5,
+            },
+            crate::object::Time {
+                hour: 14,
+                minute: 0,
+                second: 0,
+                hundredths: 0,
+            },
+        );
+        let records = vec![
+            LogRecord {
+                timestamp,
+                log_datum: LogDatum::Real(20.5),
+                status_flags: None,
+            },
+            LogRecord {
+                timestamp,
+                log_datum: LogDatum::Signed(-200),
+                status_flags: Some(vec![false, true, false, false]),
+            },
+            LogRecord {
+                timestamp,
+                log_datum: LogDatum::Failure {
+                    error_class: 2,
+                    error_code: 32,
+                },
+                status_flags: None,
+            },
+            LogRecord {
+                timestamp,
+                log_datum: LogDatum::LogStatus(vec![false, true]),
+                status_flags: None,
+            },
+        ];
+
+        let mut buffer = Vec::new();
+        for record in &records {
+            record.encode(&mut buffer).unwrap();
+        }
+        assert_eq!(LogRecord::decode_list(&buffer).unwrap(), records);
 
-        // Test Who-Is for range
-        let whois_range = WhoIsRequest::for_range(100, 200);
-        assert!(whois_range.matches(150));
-        assert!(!whois_range.matches(50));
-        assert!(!whois_range.matches(250));
+        // Negative values use the shortest form
+        let mut buffer = Vec::new();
+        LogDatum::Signed(-200).encode(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![0x5A, 0xFF, 0x38]);
+        assert_eq!(
+            LogDatum::decode(&buffer).unwrap(),
+            (LogDatum::Signed(-200), 3)
+        );
     }
 
     #[test]
-    fn test_whois_encoding() {
+    fn test_read_range_request_by_time() {
+        let log = ObjectIdentifier::new(ObjectType::TrendLog, 1);
+        let since = BacnetDateTime::new(
+            crate::object::Date {
+                year: 2024,
+                month: 3,
+                day: 15,
+                weekday: 5,
+            },
+            crate::object::Time {
+                hour: 14,
+                minute: 0,
+                second: 0,
+                hundredths: 0,
+            },
+        );
+
         let mut buffer = Vec::new();
+        ReadRangeRequest::by_time(log, PropertyIdentifier::LogBuffer, since, 100)
+            .encode(&mut buffer)
+            .unwrap();
+        assert_eq!(
+            &buffer[7..],
+            &[
+                0x7E, // by time
+                0xA4, 0x7C, 0x03, 0x0F, 0x05, // 2024-03-15
+                0xB4, 0x0E, 0x00, 0x00, 0x00, // 14:00
+                0x31, 0x64, // 100 records
+                0x7F,
+            ]
+        );
 
-        // Test encoding Who-Is for all devices
-        let whois_all = WhoIsRequest::new();
-        whois_all.encode(&mut buffer).unwrap();
-        assert_eq!(buffer.len(), 0); // No parameters for all devices
+        // The 100 records before that time
+        buffer.clear();
+        ReadRangeRequest::by_time(log, PropertyIdentifier::LogBuffer, since, -100)
+            .encode(&mut buffer)
+            .unwrap();
+        assert_eq!(&buffer[18..], &[0x31, 0x9C, 0x7F]);
 
-        // Test encoding Who-Is for specific device
         buffer.clear();
-        let whois_specific = WhoIsRequest::for_device(123);
-        whois_specific.encode(&mut buffer).unwrap();
-        assert!(!buffer.is_empty());
+        ReadRangeRequest::by_sequence_number(log, PropertyIdentifier::LogBuffer, 300, 50)
+            .encode(&mut buffer)
+            .unwrap();
+        assert_eq!(&buffer[7..], &[0x6E, 0x22, 0x01, 0x2C, 0x31, 0x32, 0x6F]);
 
-        // Test decoding
-        let decoded = WhoIsRequest::decode(&buffer).unwrap();
-        assert_eq!(decoded, whois_specific);
+        // A by-time response reports the sequence number of its first record
+        let data = [
+            0x0C, 0x05, 0x00, 0x00, 0x01, 0x19, 0x83, 0x3A, 0x05, 0x40, 0x49, 0x00, 0x5E, 0x5F,
+            0x6A, 0x01, 0x2C,
+        ];
+        let response = ReadRangeResponse::decode(&data).unwrap();
+        assert!(response.result_flags.last_item);
+        assert_eq!(response.item_count, 0);
+        assert!(response.item_data.is_empty());
+        assert_eq!(response.first_sequence_number, Some(300));
     }
 
     #[test]
-    fn test_iam_request() {
-        let device_id = ObjectIdentifier::new(ObjectType::Device, 123);
-        let iam = IAmRequest::new(device_id, 1476, Segmentation::Both, 999);
+    fn test_read_range_response_result_flags() {
+        // A trend log page with more records to come
+        let data = [
+            0x0C, 0x05, 0x00, 0x00, 0x01, // trend log 1
+            0x19, 0x83, // log buffer
+            0x3A, 0x05, 0xA0, // result flags: first-item, more-items
+            0x49, 0x02, // item count
+            0x5E, // item data
+            0x0E, 0xA4, 0x7C, 0x03, 0x0F, 0x05, 0xB4, 0x0E, 0x00, 0x00, 0x00, 0x0F, // 14:00
+            0x1E, 0x2C, 0x41, 0xA4, 0x00, 0x00, 0x1F, // 20.5
+            0x0E, 0xA4, 0x7C, 0x03, 0x0F, 0x05, 0xB4, 0x0E, 0x0F, 0x00, 0x00, 0x0F, // 14:15
+            0x1E, 0x2C, 0x41, 0xA8, 0x00, 0x00, 0x1F, // 21.0
+            0x5F, 0x69, 0x01, // first sequence number 1
+        ];
 
-        assert_eq!(iam.device_identifier.instance, 123);
-        assert_eq!(iam.max_apdu_length_accepted, 1476);
-        assert_eq!(iam.vendor_identifier, 999);
+        let response = ReadRangeResponse::decode(&data).unwrap();
+        assert_eq!(
+            response.object_identifier,
+            ObjectIdentifier::new(ObjectType::TrendLog, 1)
+        );
+        assert_eq!(response.property_identifier, PropertyIdentifier::LogBuffer);
+        assert_eq!(response.property_array_index, None);
+        assert_eq!(
+            response.result_flags,
+            ResultFlags {
+                first_item: true,
+                last_item: false,
+                more_items: true,
+            }
+        );
+        assert_eq!(response.item_count, 2);
+        assert_eq!(response.item_data, data[13..51]);
+        assert_eq!(response.first_sequence_number, Some(1));
+
+        // Encodes back to the same bytes
+        let mut buffer = Vec::new();
+        response.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, data);
+
+        // The last page clears more-items
+        let last_page = ReadRangeResponse {
+            result_flags: ResultFlags {
+                first_item: false,
+                last_item: true,
+                more_items: false,
+            },
+            first_sequence_number: None,
+            ..response
+        };
+        buffer.clear();
+        last_page.encode(&mut buffer).unwrap();
+        let decoded = ReadRangeResponse::decode(&buffer).unwrap();
+        assert!(!decoded.result_flags.more_items);
+        assert!(decoded.result_flags.last_item);
+        assert_eq!(decoded, last_page);
     }
 
     #[test]
-    fn test_read_property_request() {
+    fn test_subscribe_cov_property_request_encoding() {
         let object_id = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
-        let read_prop = ReadPropertyRequest::new(object_id, PropertyIdentifier::PresentValue); // Present Value
 
-        assert_eq!(read_prop.object_identifier.instance, 1);
+        // Minimal form
+        let request = SubscribeCovPropertyRequest::new(
+            18,
+            object_id,
+            PropertyReference::new(PropertyIdentifier::PresentValue),
+        );
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
         assert_eq!(
-            read_prop.property_identifier,
-            PropertyIdentifier::PresentValue
+            buffer,
+            vec![
+                0x09, 0x12, // process id 18
+                0x1C, 0x00, 0x00, 0x00, 0x01, // monitored object
+                0x4E, 0x09, 0x55, 0x4F, // monitored property
+            ]
         );
-        assert_eq!(read_prop.property_array_index, None);
 
-        let read_prop_array =
-            ReadPropertyRequest::with_array_index(object_id, PropertyIdentifier::PresentValue, 0);
-        assert_eq!(read_prop_array.property_array_index, Some(0));
+        // Full form, with an array index and COV increment
+        let mut request = SubscribeCovPropertyRequest::new(
+            18,
+            object_id,
+            PropertyReference::with_array_index(PropertyIdentifier::PriorityArray, 8),
+        )
+        .with_cov_increment(0.5);
+        request.issue_confirmed_notifications = Some(false);
+        request.lifetime = Some(300);
+        buffer.clear();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(
+            buffer,
+            vec![
+                0x09, 0x12, // process id 18
+                0x1C, 0x00, 0x00, 0x00, 0x01, // monitored object
+                0x29, 0x00, // unconfirmed notifications
+                0x3A, 0x01, 0x2C, // lifetime 300
+                0x4E, 0x09, 0x57, 0x19, 0x08, 0x4F, // priority array [8]
+                0x5C, 0x3F, 0x00, 0x00, 0x00, // COV increment 0.5
+            ]
+        );
     }
 
     #[test]
-    fn test_write_property_request() {
-        let object_id = ObjectIdentifier::new(ObjectType::AnalogOutput, 1);
-        let property_value = vec![0x44, 0x42, 0x20, 0x00, 0x00]; // Real 40.0
-        let write_prop = WritePropertyRequest::new(object_id, 85, property_value.clone());
+    fn test_subscribe_cov_request_encoding() {
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
 
-        assert_eq!(write_prop.object_identifier.instance, 1);
-        assert_eq!(write_prop.property_identifier, 85);
-        assert_eq!(write_prop.property_value, property_value);
-        assert_eq!(write_prop.priority, None);
+        // Small values produce the same bytes as the original fixed tags
+        let mut buffer = Vec::new();
+        SubscribeCovRequest::new(18, object_id)
+            .encode(&mut buffer)
+            .unwrap();
+        assert_eq!(buffer, vec![0x09, 0x12, 0x1C, 0x00, 0x00, 0x00, 0x01]);
+
+        // Values above 255 are no longer truncated
+        let mut request = SubscribeCovRequest::with_confirmation(70000, object_id, true);
+        request.lifetime = Some(3600);
+        buffer.clear();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(
+            buffer,
+            vec![
+                0x0B, 0x01, 0x11, 0x70, // process id 70000
+                0x1C, 0x00, 0x00, 0x00, 0x01, // monitored object
+                0x29, 0x01, // confirmed notifications
+                0x3A, 0x0E, 0x10, // lifetime 3600
+            ]
+        );
+    }
 
-        // Test with priority
-        let write_prop_priority =
-            WritePropertyRequest::with_priority(object_id, 85, property_value.clone(), 8);
-        assert_eq!(write_prop_priority.priority, Some(8));
+    #[test]
+    fn test_write_property_request_encoding() {
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogOutput, 1);
+        let property_value = vec![0x44, 0x42, 0x20, 0x00, 0x00];
 
-        // Test encoding/decoding
         let mut buffer = Vec::new();
-        write_prop.encode(&mut buffer).unwrap();
-        assert!(!buffer.is_empty());
+        WritePropertyRequest::with_priority(object_id, 85, property_value.clone(), 8)
+            .encode(&mut buffer)
+            .unwrap();
+        assert_eq!(
+            buffer,
+            vec![
+                0x0C, 0x00, 0x40, 0x00, 0x01, 0x19, 0x55, 0x3E, 0x44, 0x42, 0x20, 0x00, 0x00, 0x3F,
+                0x49, 0x08,
+            ]
+        );
 
-        let decoded = WritePropertyRequest::decode(&buffer).unwrap();
-        assert_eq!(decoded.object_identifier.instance, 1);
-        assert_eq!(decoded.property_identifier, 85);
-        assert_eq!(decoded.property_value, property_value);
+        // Proprietary property ids need a two-byte enumeration
+        buffer.clear();
+        WritePropertyRequest::new(object_id, 0x8000, property_value)
+            .encode(&mut buffer)
+            .unwrap();
+        assert_eq!(&buffer[5..8], &[0x1A, 0x80, 0x00]);
     }
 
     #[test]
-    fn test_read_property_multiple_request() {
-        let object_id1 = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
-        let object_id2 = ObjectIdentifier::new(ObjectType::BinaryInput, 2);
-
-        let prop_ref1 = PropertyReference::new(PropertyIdentifier::PresentValue); // Present Value
-        let prop_ref2 = PropertyReference::new(PropertyIdentifier::ObjectName); // Object Name
-        let prop_ref3 = PropertyReference::with_array_index(PropertyIdentifier::PriorityArray, 8); // Priority Array[8]
+    fn test_write_property_request_decoding() {
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogValue, 7);
+
+        // Proprietary property, two-byte array index, and a Real (0.75) whose
+        // encoding contains the closing-tag octet 0x3F.
+        let request = WritePropertyRequest {
+            object_identifier: object_id,
+            property_identifier: 0x8000,
+            property_array_index: Some(300),
+            property_value: vec![0x44, 0x3F, 0x40, 0x00, 0x00],
+            priority: Some(16),
+        };
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(
+            &buffer[5..],
+            &[
+                0x1A, 0x80, 0x00, 0x2A, 0x01, 0x2C, 0x3E, 0x44, 0x3F, 0x40, 0x00, 0x00, 0x3F, 0x49,
+                0x10,
+            ]
+        );
+        assert_eq!(WritePropertyRequest::decode(&buffer).unwrap(), request);
 
-        let spec1 = ReadAccessSpecification::new(object_id1, vec![prop_ref1, prop_ref2]);
-        let spec2 = ReadAccessSpecification::new(object_id2, vec![prop_ref3]);
+        // A constructed value with its own nested closing tags
+        let request = WritePropertyRequest::new(
+            object_id,
+            123,
+            vec![0x0E, 0x1E, 0x21, 0x01, 0x1F, 0x0F, 0x91, 0x02],
+        );
+        buffer.clear();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(WritePropertyRequest::decode(&buffer).unwrap(), request);
 
-        let rpm_request = ReadPropertyMultipleRequest::new(vec![spec1, spec2]);
+        // Missing closing tag
+        assert!(WritePropertyRequest::decode(&buffer[..buffer.len() - 1]).is_err());
+    }
 
-        assert_eq!(rpm_request.read_access_specifications.len(), 2);
-        assert_eq!(
-            rpm_request.read_access_specifications[0]
-                .property_references
-                .len(),
-            2
-        );
+    #[test]
+    fn test_write_property_multiple_request_builder() {
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogValue, 1);
+        let request = WritePropertyMultipleRequest::builder()
+            .object(
+                WriteAccessSpecification::new(object_id)
+                    .add_with_priority(
+                        PropertyIdentifier::PresentValue,
+                        property::PropertyValue::Real(21.5),
+                        8,
+                    )
+                    .add(
+                        PropertyIdentifier::Description,
+                        property::PropertyValue::CharacterString("Zone".to_string()),
+                    ),
+            )
+            .build();
+
+        assert_eq!(request.write_access_specifications.len(), 1);
         assert_eq!(
-            rpm_request.read_access_specifications[1]
-                .property_references
-                .len(),
-            1
+            request.write_access_specifications[0].list_of_properties[0].priority,
+            Some(8)
         );
+
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
         assert_eq!(
-            rpm_request.read_access_specifications[1].property_references[0].property_array_index,
-            Some(8)
+            buffer,
+            vec![
+                0x0C, 0x00, 0x80, 0x00, 0x01, // object identifier
+                0x1E, // list of properties
+                0x09, 0x55, 0x2E, 0x44, 0x41, 0xAC, 0x00, 0x00, 0x2F, 0x39,
+                0x08, // present-value
+                0x09, 0x1C, 0x2E, 0x75, 0x05, 0x00, b'Z', b'o', b'n', b'e',
+                0x2F, // description
+                0x1F,
+            ]
         );
+
+        // Priorities outside 1-16 and empty requests are rejected
+        let request = WritePropertyMultipleRequest::builder()
+            .object(WriteAccessSpecification::new(object_id).add_with_priority(
+                PropertyIdentifier::PresentValue,
+                property::PropertyValue::Real(0.0),
+                17,
+            ))
+            .build();
+        assert!(request.encode(&mut Vec::new()).is_err());
+        assert!(WritePropertyMultipleRequest::builder()
+            .build()
+            .encode(&mut Vec::new())
+            .is_err());
     }
 
     #[test]
-    fn test_subscribe_cov_request() {
-        let object_id = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
-        let cov_req = SubscribeCovRequest::new(123, object_id);
+    fn test_cov_subscription_list_decoding() {
+        // Device 100 watching AI 1 present-value with an increment, then a
+        // subscriber on network 5 identified only by its MAC, watching BV 3.
+        let data = [
+            0x0E, 0x0E, 0x0C, 0x02, 0x00, 0x00, 0x64, 0x0F, 0x19, 0x07, 0x0F, // recipient
+            0x1E, 0x0C, 0x00, 0x00, 0x00, 0x01, 0x19, 0x55, 0x1F, // monitored property
+            0x29, 0x01, 0x3A, 0x01, 0x2C, 0x4C, 0x3F, 0x00, 0x00, 0x00, // flags
+            0x0E, 0x0E, 0x1E, 0x21, 0x05, 0x62, 0x0A, 0x01, 0x1F, 0x0F, 0x19, 0x2A,
+            0x0F, // recipient
+            0x1E, 0x0C, 0x01, 0x40, 0x00, 0x03, 0x19, 0x55, 0x1F, // monitored property
+            0x29, 0x00, 0x39, 0x00, // flags
+        ];
 
-        assert_eq!(cov_req.subscriber_process_identifier, 123);
-        assert_eq!(cov_req.monitored_object_identifier.instance, 1);
-        assert_eq!(cov_req.issue_confirmed_notifications, None);
-        assert_eq!(cov_req.lifetime, None);
+        let subscriptions = CovSubscription::decode_list(&data).unwrap();
+        assert_eq!(subscriptions.len(), 2);
 
-        // Test with confirmation
-        let cov_confirmed = SubscribeCovRequest::with_confirmation(123, object_id, true);
-        assert_eq!(cov_confirmed.issue_confirmed_notifications, Some(true));
+        let first = &subscriptions[0];
+        assert_eq!(first.subscriber_process_identifier, 7);
+        assert_eq!(
+            first.recipient(),
+            Recipient::Device(ObjectIdentifier::new(ObjectType::Device, 100))
+        );
+        assert_eq!(
+            first.monitored_object_identifier,
+            ObjectIdentifier::new(ObjectType::AnalogInput, 1)
+        );
+        assert_eq!(
+            first.monitored_property,
+            Some(PropertyReference::new(PropertyIdentifier::PresentValue))
+        );
+        assert!(first.issue_confirmed_notifications);
+        assert_eq!(first.time_remaining, 300);
+        assert_eq!(first.cov_increment, Some(0.5));
 
-        // Test with lifetime
-        let cov_lifetime = SubscribeCovRequest::with_lifetime(123, object_id, 3600);
-        assert_eq!(cov_lifetime.lifetime, Some(3600));
+        let second = &subscriptions[1];
+        assert_eq!(second.subscriber_process_identifier, 42);
+        assert_eq!(
+            second.recipient(),
+            Recipient::Address(NetworkAddress::new(5, vec![0x0A, 0x01]))
+        );
+        assert_eq!(
+            second.monitored_object_identifier,
+            ObjectIdentifier::new(ObjectType::BinaryValue, 3)
+        );
+        assert!(!second.issue_confirmed_notifications);
+        assert_eq!(second.time_remaining, 0);
+        assert_eq!(second.cov_increment, None);
 
-        // Test encoding
         let mut buffer = Vec::new();
-        cov_req.encode(&mut buffer).unwrap();
-        assert!(!buffer.is_empty());
+        for subscription in &subscriptions {
+            subscription.encode(&mut buffer).unwrap();
+        }
+        assert_eq!(buffer, data);
+
+        // Truncated list
+        assert!(CovSubscription::decode_list(&data[..data.len() - 1]).is_err());
     }
 
     #[test]
@@ -2214,13 +7412,170 @@ mod tests {
         assert_eq!(manager.subscriptions.len(), 0);
     }
 
+    #[test]
+    fn test_cov_last_reported_value() {
+        let mut manager = CovSubscriptionManager::new();
+        let device_id = ObjectIdentifier::new(ObjectType::Device, 1);
+        let analog = ObjectIdentifier::new(ObjectType::AnalogValue, 1);
+        let binary = ObjectIdentifier::new(ObjectType::BinaryValue, 1);
+
+        let mut subscription = CovSubscription::new(5, device_id, analog, 0);
+        subscription.cov_increment = Some(0.5);
+        manager.add_subscription(subscription);
+        manager.add_subscription(CovSubscription::new(6, device_id, binary, 0));
+
+        let mut notify = |object, value| manager.generate_notifications(object, &[value], 1).len();
+
+        // The first value after subscribing always fires
+        assert_eq!(notify(analog, PropertyValue::Real(20.0)), 1);
+        assert_eq!(notify(analog, PropertyValue::Real(20.4)), 0);
+        assert_eq!(notify(analog, PropertyValue::Real(20.6)), 1);
+        // Measured from 20.6, the last value reported
+        assert_eq!(notify(analog, PropertyValue::Real(21.0)), 0);
+
+        assert_eq!(notify(binary, PropertyValue::Enumerated(0)), 1);
+        assert_eq!(notify(binary, PropertyValue::Enumerated(0)), 0);
+        assert_eq!(notify(binary, PropertyValue::Enumerated(1)), 1);
+
+        let subscriptions = manager.get_subscriptions_for_object(analog);
+        assert_eq!(subscriptions[0].last_reported_value(), Some(20.6));
+        let subscriptions = manager.get_subscriptions_for_object(binary);
+        assert_eq!(subscriptions[0].last_reported_state(), Some(1));
+    }
+
+    #[test]
+    fn test_cov_status_flags_and_other_values() {
+        let mut manager = CovSubscriptionManager::new();
+        let device_id = ObjectIdentifier::new(ObjectType::Device, 1);
+        let analog = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+        let value = ObjectIdentifier::new(ObjectType::CharacterstringValue, 1);
+
+        let mut subscription = CovSubscription::new(5, device_id, analog, 0);
+        subscription.cov_increment = Some(1.0);
+        manager.add_subscription(subscription);
+        manager.add_subscription(CovSubscription::new(6, device_id, value, 0));
+
+        let normal = PropertyValue::BitString(vec![false; 4]);
+        let fault = PropertyValue::BitString(vec![false, true, false, false]);
+        let mut notify = |object, values: &[PropertyValue]| {
+            manager.generate_notifications(object, values, 1).len()
+        };
+
+        // A change of Status_Flags fires even within the COV increment
+        assert_eq!(
+            notify(analog, &[PropertyValue::Real(20.0), normal.clone()]),
+            1
+        );
+        assert_eq!(
+            notify(analog, &[PropertyValue::Real(20.1), normal.clone()]),
+            0
+        );
+        assert_eq!(
+            notify(analog, &[PropertyValue::Real(20.2), fault.clone()]),
+            1
+        );
+        assert_eq!(
+            notify(analog, &[PropertyValue::Real(20.3), fault.clone()]),
+            0
+        );
+        // The increment is measured from the value sent with the flags
+        assert_eq!(
+            notify(analog, &[PropertyValue::Real(21.1), fault.clone()]),
+            0
+        );
+        assert_eq!(
+            notify(analog, &[PropertyValue::Real(21.2), fault.clone()]),
+            1
+        );
+
+        // Other values fire only when they change
+        let text = |s: &str| PropertyValue::CharacterString(s.to_string());
+        assert_eq!(notify(value, &[text("on")]), 1);
+        assert_eq!(notify(value, &[text("on")]), 0);
+        assert_eq!(notify(value, &[text("off")]), 1);
+
+        let subscriptions = manager.get_subscriptions_for_object(analog);
+        assert_eq!(subscriptions[0].last_reported_status_flags(), Some(&fault));
+    }
+
+    #[test]
+    fn test_cov_generate_notifications() {
+        let mut manager = CovSubscriptionManager::new();
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+        let other_id = ObjectIdentifier::new(ObjectType::AnalogInput, 2);
+
+        for (process, device, lifetime) in [(1, 10, 600), (2, 11, 0)] {
+            let mut subscription = CovSubscription::new(
+                process,
+                ObjectIdentifier::new(ObjectType::Device, device),
+                object_id,
+                lifetime,
+            );
+            subscription.cov_increment = Some(1.0);
+            manager.add_subscription(subscription);
+        }
+        manager.add_subscription(CovSubscription::new(
+            3,
+            ObjectIdentifier::new(ObjectType::Device, 12),
+            other_id,
+            600,
+        ));
+        manager.update_timers(100);
+
+        let flags = PropertyValue::BitString(vec![false; 4]);
+        let notifications = manager.generate_notifications(
+            object_id,
+            &[PropertyValue::Real(20.0), flags.clone()],
+            99,
+        );
+        assert_eq!(notifications.len(), 2);
+        assert_eq!(notifications[0].subscriber_process_identifier, 1);
+        assert_eq!(notifications[0].time_remaining, 500);
+        assert_eq!(notifications[1].subscriber_process_identifier, 2);
+        assert_eq!(notifications[1].time_remaining, 0);
+        assert_eq!(
+            notifications[0].initiating_device_identifier,
+            ObjectIdentifier::new(ObjectType::Device, 99)
+        );
+        assert_eq!(
+            notifications[0].list_of_values,
+            vec![
+                BacnetPropertyValue::new(
+                    PropertyIdentifier::PresentValue,
+                    property::PropertyValue::Real(20.0)
+                ),
+                BacnetPropertyValue::new(
+                    PropertyIdentifier::StatusFlags,
+                    property::PropertyValue::BitString(vec![false; 4]),
+                ),
+            ]
+        );
+
+        // Below the increment
+        assert!(manager
+            .generate_notifications(object_id, &[PropertyValue::Real(20.5)], 99)
+            .is_empty());
+
+        // Above the increment, one notification per subscriber
+        let notifications =
+            manager.generate_notifications(object_id, &[PropertyValue::Real(21.5)], 99);
+        assert_eq!(notifications.len(), 2);
+        assert_eq!(notifications[0].list_of_values.len(), 1);
+    }
+
     #[test]
     fn test_cov_notification_request() {
         let device_id = ObjectIdentifier::new(ObjectType::Device, 1);
         let object_id = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
         let values = vec![
-            crate::object::PropertyValue::Real(25.5), // Present Value
-            crate::object::PropertyValue::Boolean(false), // Status Flags
+            BacnetPropertyValue::new(
+                PropertyIdentifier::PresentValue,
+                property::PropertyValue::Real(25.5),
+            ),
+            BacnetPropertyValue::new(
+                PropertyIdentifier::StatusFlags,
+                property::PropertyValue::BitString(vec![false; 4]),
+            ),
         ];
 
         let notification = CovNotificationRequest::new(123, device_id, object_id, 3600, values);
@@ -2231,10 +7586,124 @@ mod tests {
         assert_eq!(notification.time_remaining, 3600);
         assert_eq!(notification.list_of_values.len(), 2);
 
-        // Test encoding
+        // Encoded as an analog input controller reports it
         let mut buffer = Vec::new();
         notification.encode(&mut buffer).unwrap();
-        assert!(!buffer.is_empty());
+        assert_eq!(
+            buffer,
+            vec![
+                0x09, 0x7B, // subscriber process id 123
+                0x1C, 0x02, 0x00, 0x00, 0x01, // device 1
+                0x2C, 0x00, 0x00, 0x00, 0x01, // analog input 1
+                0x3A, 0x0E, 0x10, // time remaining 3600
+                0x4E, // list of values
+                0x09, 0x55, 0x2E, 0x44, 0x41, 0xCC, 0x00, 0x00, 0x2F, // present value 25.5
+                0x09, 0x6F, 0x2E, 0x82, 0x04, 0x00, 0x2F, // status flags
+                0x4F,
+            ]
+        );
+
+        // List elements decode back
+        let (present_value, consumed) = BacnetPropertyValue::decode(&buffer[16..]).unwrap();
+        assert_eq!(consumed, 9);
+        assert_eq!(present_value, notification.list_of_values[0]);
+        let (status_flags, consumed) = BacnetPropertyValue::decode(&buffer[25..]).unwrap();
+        assert_eq!(consumed, 7);
+        assert_eq!(status_flags, notification.list_of_values[1]);
+
+        // Array index and priority are optional
+        let mut value = BacnetPropertyValue::new(
+            PropertyIdentifier::PriorityArray,
+            property::PropertyValue::Unsigned(5),
+        );
+        value.property_array_index = Some(8);
+        value.priority = Some(16);
+        let mut buffer = Vec::new();
+        value.encode(&mut buffer).unwrap();
+        assert_eq!(
+            BacnetPropertyValue::decode(&buffer).unwrap(),
+            (value, buffer.len())
+        );
+
+        // Several elements decode as an array, as in WritePropertyMultiple
+        let value = BacnetPropertyValue::new(
+            PropertyIdentifier::WeeklySchedule,
+            property::PropertyValue::Array(vec![
+                property::PropertyValue::Real(1.0),
+                property::PropertyValue::Real(2.0),
+            ]),
+        );
+        let mut buffer = Vec::new();
+        value.encode(&mut buffer).unwrap();
+        assert_eq!(
+            BacnetPropertyValue::decode(&buffer).unwrap(),
+            (value, buffer.len())
+        );
+    }
+
+    #[test]
+    fn test_cov_notification_decoding() {
+        let device_id = ObjectIdentifier::new(ObjectType::Device, 1);
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+        let notification = CovNotificationRequest::new(
+            123,
+            device_id,
+            object_id,
+            3600,
+            vec![
+                BacnetPropertyValue::new(
+                    PropertyIdentifier::PresentValue,
+                    property::PropertyValue::Real(25.5),
+                ),
+                BacnetPropertyValue::new(
+                    PropertyIdentifier::StatusFlags,
+                    property::PropertyValue::BitString(vec![true, false, false, true]),
+                ),
+            ],
+        );
+        let mut buffer = Vec::new();
+        notification.encode(&mut buffer).unwrap();
+        let decoded = CovNotificationRequest::decode(&buffer).unwrap();
+        assert_eq!(decoded.subscriber_process_identifier, 123);
+        assert_eq!(decoded.initiating_device_identifier, device_id);
+        assert_eq!(decoded.monitored_object_identifier, object_id);
+        assert_eq!(decoded.time_remaining, 3600);
+        assert_eq!(decoded.list_of_values, notification.list_of_values);
+
+        // Captured from a controller: device 260001, analog input 0 at 72.3,
+        // subscription without lifetime
+        let capture = [
+            0x09, 0x01, 0x1C, 0x02, 0x03, 0xF7, 0xA1, 0x2C, 0x00, 0x00, 0x00, 0x00, 0x39, 0x00,
+            0x4E, 0x09, 0x55, 0x2E, 0x44, 0x42, 0x90, 0x99, 0x9A, 0x2F, 0x09, 0x6F, 0x2E, 0x82,
+            0x04, 0x00, 0x2F, 0x4F,
+        ];
+        let decoded = CovNotificationRequest::decode(&capture).unwrap();
+        assert_eq!(decoded.subscriber_process_identifier, 1);
+        assert_eq!(
+            decoded.initiating_device_identifier,
+            ObjectIdentifier::new(ObjectType::Device, 260001)
+        );
+        assert_eq!(
+            decoded.monitored_object_identifier,
+            ObjectIdentifier::new(ObjectType::AnalogInput, 0)
+        );
+        assert_eq!(decoded.time_remaining, 0);
+        assert_eq!(decoded.list_of_values.len(), 2);
+        assert_eq!(
+            decoded.list_of_values[0].property_identifier,
+            PropertyIdentifier::PresentValue
+        );
+        assert_eq!(
+            decoded.list_of_values[0].value,
+            property::PropertyValue::Real(72.3)
+        );
+        assert_eq!(
+            decoded.list_of_values[1].value,
+            property::PropertyValue::BitString(vec![false; 4])
+        );
+
+        // A truncated list is rejected
+        assert!(CovNotificationRequest::decode(&capture[..capture.len() - 1]).is_err());
     }
 
     #[test]
@@ -2356,10 +7825,61 @@ mod tests {
 
     #[test]
     fn test_atomic_write_file_response() {
-        let response = AtomicWriteFileResponse {
-            file_start_position: 150,
-        };
+        let response = AtomicWriteFileResponse::new_stream_access(150);
         assert_eq!(response.file_start_position, 150);
+        assert!(!response.is_record_access());
+        assert!(AtomicWriteFileResponse::new_record_access(3).is_record_access());
+    }
+
+    #[test]
+    fn test_atomic_read_file_response_decoding() {
+        // Stream access, last block of the file
+        let response = AtomicReadFileResponse::new_stream_access(true, 1024, b"tail".to_vec());
+        let mut buffer = Vec::new();
+        response.encode(&mut buffer).unwrap();
+        assert_eq!(
+            buffer,
+            vec![
+                0x11, // end of file
+                0x0E, 0x32, 0x04, 0x00, // from position 1024
+                0x64, b't', b'a', b'i', b'l', 0x0F,
+            ]
+        );
+        assert_eq!(AtomicReadFileResponse::decode(&buffer).unwrap(), response);
+
+        // Record access, more records follow
+        let records = vec![b"Record 1".to_vec(), Vec::new(), b"Record 3".to_vec()];
+        let response = AtomicReadFileResponse::new_record_access(false, 5, records.clone());
+        let mut buffer = Vec::new();
+        response.encode(&mut buffer).unwrap();
+        assert_eq!(&buffer[..5], &[0x10, 0x1E, 0x31, 0x05, 0x21]);
+        let decoded = AtomicReadFileResponse::decode(&buffer).unwrap();
+        assert!(!decoded.end_of_file);
+        match decoded.access_method_result {
+            FileAccessMethodResult::RecordAccess {
+                file_start_record,
+                record_count,
+                file_record_data,
+            } => {
+                assert_eq!(file_start_record, 5);
+                assert_eq!(record_count, 3);
+                assert_eq!(file_record_data, records);
+            }
+            _ => panic!("Expected RecordAccess result"),
+        }
+    }
+
+    #[test]
+    fn test_atomic_write_file_response_decoding() {
+        let buffer = [0x0A, 0x04, 0x00];
+        let response = AtomicWriteFileResponse::decode(&buffer).unwrap();
+        assert_eq!(response, AtomicWriteFileResponse::new_stream_access(1024));
+
+        let response = AtomicWriteFileResponse::new_record_access(-1);
+        let mut buffer = Vec::new();
+        response.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![0x19, 0xFF]);
+        assert_eq!(AtomicWriteFileResponse::decode(&buffer).unwrap(), response);
     }
 
     #[test]
@@ -2403,6 +7923,501 @@ mod tests {
         assert_eq!(decoded, datetime);
     }
 
+    #[test]
+    fn test_time_stamp_round_trip() {
+        use crate::object::{Date, Time};
+
+        let time = Time {
+            hour: 14,
+            minute: 30,
+            second: 45,
+            hundredths: 50,
+        };
+        let date = Date {
+            year: 2024,
+            month: 3,
+            day: 15,
+            weekday: 5,
+        };
+        let cases = [
+            (
+                BacnetTimeStamp::Time(time),
+                vec![0x0C, 0x0E, 0x1E, 0x2D, 0x32],
+            ),
+            (BacnetTimeStamp::SequenceNumber(300), vec![0x1A, 0x01, 0x2C]),
+            (
+                BacnetTimeStamp::DateTime(BacnetDateTime::new(date, time)),
+                vec![
+                    0x2E, 0xA4, 0x7C, 0x03, 0x0F, 0x05, 0xB4, 0x0E, 0x1E, 0x2D, 0x32, 0x2F,
+                ],
+            ),
+        ];
+
+        for (time_stamp, encoded) in cases {
+            let mut buffer = Vec::new();
+            time_stamp.encode(&mut buffer).unwrap();
+            assert_eq!(buffer, encoded);
+            assert_eq!(
+                BacnetTimeStamp::decode(&buffer).unwrap(),
+                (time_stamp, encoded.len())
+            );
+
+            // Truncated time stamps are rejected
+            assert!(BacnetTimeStamp::decode(&buffer[..buffer.len() - 1]).is_err());
+        }
+
+        // Context tag 3 is not a time stamp choice
+        assert!(BacnetTimeStamp::decode(&[0x39, 0x01]).is_err());
+    }
+
+    #[test]
+    fn test_event_time_stamps_mixed_choices() {
+        // To-offnormal at 2024-03-15 14:30:45.50, to-fault and to-normal
+        // unspecified, as a device reports them in an event summary
+        let data = [
+            0x3E, // event time stamps
+            0x2E, 0xA4, 0x7C, 0x03, 0x0F, 0x05, 0xB4, 0x0E, 0x1E, 0x2D, 0x32,
+            0x2F, // dateTime
+            0x0C, 0xFF, 0xFF, 0xFF, 0xFF, // time, unspecified
+            0x2E, 0xA4, 0xFF, 0xFF, 0xFF, 0xFF, 0xB4, 0xFF, 0xFF, 0xFF, 0xFF,
+            0x2F, // dateTime
+            0x3F,
+        ];
+
+        let (time_stamps, consumed) = BacnetTimeStamp::decode_array(&data, 3).unwrap();
+        assert_eq!(consumed, data.len());
+        match time_stamps[0] {
+            BacnetTimeStamp::DateTime(date_time) => {
+                assert_eq!(date_time.date.year, 2024);
+                assert_eq!(date_time.date.month, 3);
+                assert_eq!(date_time.date.day, 15);
+                assert_eq!(date_time.time.hour, 14);
+                assert_eq!(date_time.time.hundredths, 50);
+            }
+            other => panic!("expected a dateTime, got {:?}", other),
+        }
+        assert!(!time_stamps[0].is_unspecified());
+        assert!(matches!(time_stamps[1], BacnetTimeStamp::Time(_)));
+        assert!(time_stamps[1].is_unspecified());
+        assert_eq!(
+            time_stamps[2],
+            BacnetTimeStamp::DateTime(BacnetDateTime::unspecified())
+        );
+        assert!(time_stamps[2].is_unspecified());
+
+        // Encodes back to the same bytes
+        let mut buffer = Vec::new();
+        BacnetTimeStamp::encode_array(&time_stamps, 3, &mut buffer).unwrap();
+        assert_eq!(buffer, data);
+
+        // A sequence number in the middle of the array
+        let mixed = [
+            time_stamps[0],
+            BacnetTimeStamp::SequenceNumber(300),
+            time_stamps[1],
+        ];
+        let mut buffer = Vec::new();
+        BacnetTimeStamp::encode_array(&mixed, 3, &mut buffer).unwrap();
+        assert_eq!(
+            BacnetTimeStamp::decode_array(&buffer, 3).unwrap(),
+            (mixed, buffer.len())
+        );
+
+        // Only two elements
+        let short = [&data[..12], &data[29..]].concat();
+        assert!(BacnetTimeStamp::decode_array(&short, 3).is_err());
+    }
+
+    #[test]
+    fn test_reinitialize_device_request() {
+        // Warm start without a password
+        let request = ReinitializeDeviceRequest::new(ReinitializedStateOfDevice::WarmStart);
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![0x09, 0x01]);
+        assert_eq!(ReinitializeDeviceRequest::decode(&buffer).unwrap(), request);
+
+        // Warm start with a password
+        let request =
+            ReinitializeDeviceRequest::with_password(ReinitializedStateOfDevice::WarmStart, "abc");
+        buffer.clear();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![0x09, 0x01, 0x1C, 0x00, b'a', b'b', b'c']);
+        let decoded = ReinitializeDeviceRequest::decode(&buffer).unwrap();
+        assert_eq!(decoded.password.as_deref(), Some("abc"));
+        assert_eq!(decoded, request);
+
+        // Passwords are limited to 20 characters
+        let request = ReinitializeDeviceRequest::with_password(
+            ReinitializedStateOfDevice::ColdStart,
+            "x".repeat(21),
+        );
+        assert!(matches!(
+            request.validate(),
+            Err(ServiceError::InvalidParameters(_))
+        ));
+        let mut buffer = Vec::new();
+        assert!(matches!(
+            request.encode(&mut buffer),
+            Err(EncodingError::ValueOutOfRange)
+        ));
+        assert!(buffer.is_empty());
+
+        // Passwords from the wire get the same check
+        assert!(matches!(
+            ReinitializeDeviceRequest::decode(&[0x09, 0x00, 0x19, 0x00]),
+            Err(EncodingError::ValueOutOfRange)
+        ));
+        let mut data = vec![0x09, 0x00, 0x1D, 22, 0x00];
+        data.extend_from_slice(&[b'x'; 21]);
+        assert!(matches!(
+            ReinitializeDeviceRequest::decode(&data),
+            Err(EncodingError::ValueOutOfRange)
+        ));
+
+        // Unknown states are rejected
+        assert!(ReinitializeDeviceRequest::decode(&[0x09, 0x07]).is_err());
+    }
+
+    #[test]
+    fn test_get_alarm_summary_response() {
+        let mut buffer = Vec::new();
+        GetAlarmSummaryRequest::new().encode(&mut buffer).unwrap();
+        assert!(buffer.is_empty());
+        assert!(GetAlarmSummaryRequest::decode(&[0x09]).is_err());
+
+        let data = [
+            0xC4, 0x00, 0x00, 0x00, 0x01, // analog input 1
+            0x91, 0x03, // high-limit
+            0x82, 0x05, 0x60, // to-offnormal unacknowledged
+            0xC4, 0x00, 0xC0, 0x00, 0x03, // binary input 3
+            0x91, 0x02, // offnormal
+            0x82, 0x05, 0xE0, // all acknowledged
+        ];
+        let response = GetAlarmSummaryResponse::decode(&data).unwrap();
+        assert_eq!(
+            response.alarm_summaries,
+            vec![
+                AlarmSummaryEntry {
+                    object_identifier: ObjectIdentifier::new(ObjectType::AnalogInput, 1),
+                    alarm_state: EventState::HighLimit,
+                    acknowledged_transitions: vec![false, true, true],
+                },
+                AlarmSummaryEntry {
+                    object_identifier: ObjectIdentifier::new(ObjectType::BinaryInput, 3),
+                    alarm_state: EventState::Offnormal,
+                    acknowledged_transitions: vec![true, true, true],
+                },
+            ]
+        );
+
+        let mut buffer = Vec::new();
+        response.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, data);
+    }
+
+    #[test]
+    fn test_special_event_round_trip() {
+        let christmas = crate::object::Date {
+            year: 2024,
+            month: 12,
+            day: 25,
+            weekday: 3,
+        };
+        let midnight = crate::object::Time {
+            hour: 0,
+            minute: 0,
+            second: 0,
+            hundredths: 0,
+        };
+        let event = SpecialEvent::new(
+            SpecialEventPeriod::CalendarEntry(CalendarEntry::Date(christmas)),
+            vec![TimeValue::new(
+                midnight,
+                property::PropertyValue::Real(65.0),
+            )],
+            10,
+        );
+
+        let mut buffer = Vec::new();
+        event.encode(&mut buffer).unwrap();
+        assert_eq!(
+            buffer,
+            vec![
+                0x0E, 0x0C, 0x7C, 0x0C, 0x19, 0x03, 0x0F, // on 2024-12-25
+                0x2E, 0xB4, 0x00, 0x00, 0x00, 0x00, // from 00:00
+                0x44, 0x42, 0x82, 0x00, 0x00, 0x2F, // 65.0
+                0x39, 0x0A, // priority 10
+            ]
+        );
+        assert_eq!(SpecialEvent::decode(&buffer).unwrap(), (event.clone(), 21));
+
+        // The other period choices
+        let events = vec![
+            event,
+            SpecialEvent::new(
+                SpecialEventPeriod::CalendarReference(ObjectIdentifier::new(
+                    ObjectType::Calendar,
+                    1,
+                )),
+                vec![
+                    TimeValue::new(midnight, property::PropertyValue::Real(60.0)),
+                    TimeValue::new(
+                        crate::object::Time {
+                            hour: 12,
+                            ..midnight
+                        },
+                        property::PropertyValue::Null,
+                    ),
+                ],
+                16,
+            ),
+            SpecialEvent::new(
+                SpecialEventPeriod::CalendarEntry(CalendarEntry::DateRange {
+                    start_date: christmas,
+                    end_date: crate::object::Date {
+                        year: 2025,
+                        month: 1,
+                        day: 1,
+                        weekday: 3,
+                    },
+                }),
+                vec![],
+                1,
+            ),
+            SpecialEvent::new(
+                // Every Monday in any month
+                SpecialEventPeriod::CalendarEntry(CalendarEntry::WeekNDay {
+                    month: 255,
+                    week_of_month: 255,
+                    day_of_week: 1,
+                }),
+                vec![TimeValue::new(
+                    midnight,
+                    property::PropertyValue::Real(55.0),
+                )],
+                8,
+            ),
+        ];
+        let mut buffer = Vec::new();
+        SpecialEvent::encode_list(&events, &mut buffer).unwrap();
+        assert_eq!(SpecialEvent::decode_list(&buffer).unwrap(), events);
+    }
+
+    #[test]
+    fn test_get_event_information_pages() {
+        let summary = |instance, event_state, time_stamp| EventSummary {
+            object_identifier: ObjectIdentifier::new(ObjectType::AnalogInput, instance),
+            event_state,
+            acknowledged_transitions: vec![false, true, true],
+            event_time_stamps: [
+                time_stamp,
+                BacnetTimeStamp::SequenceNumber(0),
+                BacnetTimeStamp::SequenceNumber(0),
+            ],
+            notify_type: NotifyType::Alarm,
+            event_enable: vec![true, true, true],
+            event_priorities: [100, 100, 200],
+        };
+        let device = [
+            GetEventInformationResponse::new(
+                vec![
+                    summary(
+                        1,
+                        EventState::HighLimit,
+                        BacnetTimeStamp::SequenceNumber(17),
+                    ),
+                    summary(
+                        2,
+                        EventState::LowLimit,
+                        BacnetTimeStamp::Time(crate::object::Time {
+                            hour: 8,
+                            minute: 30,
+                            second: 0,
+                            hundredths: 0,
+                        }),
+                    ),
+                ],
+                true,
+            ),
+            GetEventInformationResponse::new(
+                vec![summary(
+                    5,
+                    EventState::Fault,
+                    BacnetTimeStamp::SequenceNumber(3),
+                )],
+                false,
+            ),
+        ];
+
+        let first = GetEventInformationRequest::new();
+        let mut buffer = Vec::new();
+        first.encode(&mut buffer).unwrap();
+        assert!(buffer.is_empty());
+        assert_eq!(GetEventInformationRequest::decode(&buffer).unwrap(), first);
+
+        let mut request = Some(first);
+        let mut summaries = Vec::new();
+        let mut pages = 0;
+        while let Some(current) = request {
+            let page = match current.last_received_object_identifier {
+                None => &device[0],
+                Some(last) if last.instance == 2 => &device[1],
+                Some(last) => panic!("unexpected continuation after {last:?}"),
+            };
+            let mut buffer = Vec::new();
+            page.encode(&mut buffer).unwrap();
+
+            let response = GetEventInformationResponse::decode(&buffer).unwrap();
+            assert_eq!(&response, page);
+            request = response.next_request();
+            summaries.extend(response.list_of_event_summaries);
+            pages += 1;
+        }
+
+        assert_eq!(pages, 2);
+        let instances: Vec<_> = summaries
+            .iter()
+            .map(|summary| summary.object_identifier.instance)
+            .collect();
+        assert_eq!(instances, vec![1, 2, 5]);
+
+        // The continuation request carries the last object of the first page
+        let mut buffer = Vec::new();
+        device[0]
+            .next_request()
+            .unwrap()
+            .encode(&mut buffer)
+            .unwrap();
+        assert_eq!(buffer, vec![0x0C, 0x00, 0x00, 0x00, 0x02]);
+    }
+
+    #[test]
+    fn test_acknowledge_alarm_off_normal() {
+        let transition = BacnetDateTime::new(
+            crate::object::Date {
+                year: 2024,
+                month: 3,
+                day: 15,
+                weekday: 5,
+            },
+            crate::object::Time {
+                hour: 14,
+                minute: 30,
+                second: 0,
+                hundredths: 0,
+            },
+        );
+        let acknowledged = BacnetDateTime::new(
+            transition.date,
+            crate::object::Time {
+                hour: 14,
+                minute: 35,
+                ..transition.time
+            },
+        );
+        let request = AcknowledgeAlarmRequest::new(
+            1,
+            ObjectIdentifier::new(ObjectType::AnalogInput, 2),
+            EventState::Offnormal,
+            BacnetTimeStamp::DateTime(transition),
+            "op",
+            BacnetTimeStamp::DateTime(acknowledged),
+        );
+
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(
+            buffer,
+            vec![
+                0x09, 0x01, // process 1
+                0x1C, 0x00, 0x00, 0x00, 0x02, // analog input 2
+                0x29, 0x02, // offnormal
+                0x3E, 0x2E, 0xA4, 0x7C, 0x03, 0x0F, 0x05, 0xB4, 0x0E, 0x1E, 0x00, 0x00, 0x2F,
+                0x3F, // transition time stamp
+                0x4B, 0x00, b'o', b'p', // acknowledged by "op"
+                0x5E, 0x2E, 0xA4, 0x7C, 0x03, 0x0F, 0x05, 0xB4, 0x0E, 0x23, 0x00, 0x00, 0x2F,
+                0x5F, // acknowledgment time stamp
+            ]
+        );
+        assert_eq!(AcknowledgeAlarmRequest::decode(&buffer).unwrap(), request);
+
+        // A sequence number time stamp on the transition
+        let request = AcknowledgeAlarmRequest {
+            time_stamp: BacnetTimeStamp::SequenceNumber(42),
+            ..request
+        };
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(&buffer[9..13], &[0x3E, 0x19, 0x2A, 0x3F]);
+        assert_eq!(AcknowledgeAlarmRequest::decode(&buffer).unwrap(), request);
+    }
+
+    #[test]
+    fn test_device_communication_control_request() {
+        for enable_disable in [
+            EnableDisable::Enable,
+            EnableDisable::Disable,
+            EnableDisable::DisableInitiation,
+        ] {
+            let request = DeviceCommunicationControlRequest::new(enable_disable);
+            let mut buffer = Vec::new();
+            request.encode(&mut buffer).unwrap();
+            assert_eq!(buffer, vec![0x19, enable_disable as u8]);
+            assert_eq!(
+                DeviceCommunicationControlRequest::decode(&buffer).unwrap(),
+                request
+            );
+
+            let request = request.with_duration(60).with_password("secret");
+            buffer.clear();
+            request.encode(&mut buffer).unwrap();
+            assert_eq!(&buffer[..4], &[0x09, 0x3C, 0x19, enable_disable as u8]);
+            assert_eq!(
+                DeviceCommunicationControlRequest::decode(&buffer).unwrap(),
+                request
+            );
+        }
+
+        // Password length limits
+        let request =
+            DeviceCommunicationControlRequest::new(EnableDisable::Disable).with_password("");
+        assert!(matches!(
+            request.validate(),
+            Err(ServiceError::InvalidParameters(_))
+        ));
+        let mut buffer = Vec::new();
+        assert!(matches!(
+            request.encode(&mut buffer),
+            Err(EncodingError::ValueOutOfRange)
+        ));
+        assert!(buffer.is_empty());
+        let request = DeviceCommunicationControlRequest::new(EnableDisable::Disable)
+            .with_password("x".repeat(21));
+        assert!(matches!(
+            request.validate(),
+            Err(ServiceError::InvalidParameters(_))
+        ));
+        let request = DeviceCommunicationControlRequest::new(EnableDisable::Disable)
+            .with_password("x".repeat(20));
+        assert!(request.validate().is_ok());
+
+        // Passwords from the wire get the same check
+        assert!(matches!(
+            DeviceCommunicationControlRequest::decode(&[0x19, 0x01, 0x29, 0x00]),
+            Err(EncodingError::ValueOutOfRange)
+        ));
+        let mut data = vec![0x19, 0x01, 0x2D, 22, 0x00];
+        data.extend_from_slice(&[b'x'; 21]);
+        assert!(matches!(
+            DeviceCommunicationControlRequest::decode(&data),
+            Err(EncodingError::ValueOutOfRange)
+        ));
+
+        // Unknown state
+        assert!(DeviceCommunicationControlRequest::decode(&[0x19, 0x03]).is_err());
+    }
+
     #[test]
     fn test_time_synchronization_request() {
         let date = crate::object::Date {
@@ -2596,6 +8611,86 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_read_property_multiple_response_object_list() {
+        // Device 1234 object-list: device, analog-input 1, binary-value 2
+        let data = [
+            0x0C, 0x02, 0x00, 0x04, 0xD2, 0x1E, 0x29, 0x4C, 0x4E, 0xC4, 0x02, 0x00, 0x04, 0xD2,
+            0xC4, 0x00, 0x00, 0x00, 0x01, 0xC4, 0x01, 0x40, 0x00, 0x02, 0x4F, 0x1F,
+        ];
+
+        let response = ReadPropertyMultipleResponse::decode(&data).unwrap();
+        let results = &response.read_access_results[0].results;
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].property_identifier,
+            PropertyIdentifier::ObjectList
+        );
+        assert_eq!(
+            results[0].value,
+            PropertyResultValue::Value(vec![
+                property::PropertyValue::ObjectIdentifier(ObjectIdentifier::new(
+                    ObjectType::Device,
+                    1234
+                )),
+                property::PropertyValue::ObjectIdentifier(ObjectIdentifier::new(
+                    ObjectType::AnalogInput,
+                    1
+                )),
+                property::PropertyValue::ObjectIdentifier(ObjectIdentifier::new(
+                    ObjectType::BinaryValue,
+                    2
+                )),
+            ])
+        );
+
+        // Constructed elements are kept whole rather than cutting the list short
+        let data = [
+            0x0C, 0x02, 0x00, 0x04, 0xD2, 0x1E, 0x29, 0x7B, 0x4E, 0x0E, 0x0C, 0x00, 0x00, 0x00,
+            0x01, 0x0F, 0x21, 0x05, 0x4F, 0x1F,
+        ];
+        let response = ReadPropertyMultipleResponse::decode(&data).unwrap();
+        assert_eq!(
+            response.read_access_results[0].results[0].value,
+            PropertyResultValue::Value(vec![
+                property::PropertyValue::Unknown(vec![0x0E, 0x0C, 0x00, 0x00, 0x00, 0x01, 0x0F]),
+                property::PropertyValue::Unsigned(5),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_read_property_multiple_response_mixed() {
+        // Present-value succeeds, a proprietary property fails with
+        // property/unknown-property.
+        let data = [
+            0x0C, 0x00, 0x00, 0x00, 0x01, 0x1E, 0x29, 0x55, 0x4E, 0x44, 0x42, 0x28, 0x00, 0x00,
+            0x4F, 0x2A, 0x02, 0x00, 0x5E, 0x91, 0x02, 0x91, 0x20, 0x5F, 0x1F,
+        ];
+
+        let response = ReadPropertyMultipleResponse::decode(&data).unwrap();
+        let expected = ReadPropertyMultipleResponse::new(vec![ReadAccessResult::new(
+            ObjectIdentifier::new(ObjectType::AnalogInput, 1),
+            vec![
+                PropertyResult {
+                    property_identifier: PropertyIdentifier::PresentValue,
+                    array_index: None,
+                    value: PropertyResultValue::Value(vec![property::PropertyValue::Real(42.0)]),
+                },
+                PropertyResult {
+                    property_identifier: PropertyIdentifier::from(512u32),
+                    array_index: None,
+                    value: PropertyResultValue::Error(2, 32),
+                },
+            ],
+        )]);
+        assert_eq!(response, expected);
+
+        let mut buffer = Vec::new();
+        expected.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, data);
+    }
+
     #[test]
     fn test_read_property_response() {
         let data = [
@@ -2622,4 +8717,324 @@ mod tests {
         assert_eq!(encoded.len(), data.len());
         assert_eq!(encoded, data);
     }
+
+    #[test]
+    fn test_read_property_response_nested_values() {
+        // Priority array of analog-output 1: slot 1 holds 0.75 (0x3F400000),
+        // slot 2 a vendor-specific constructed value with its own context 3
+        // pair, slot 3 relinquished.
+        let data = [
+            0x0C, 0x00, 0x40, 0x00, 0x01, 0x19, 0x57, 0x3E, 0x44, 0x3F, 0x40, 0x00, 0x00, 0x3E,
+            0x21, 0x3F, 0x3F, 0x00, 0x3F,
+        ];
+
+        let response = ReadPropertyResponse::decode(&data).unwrap();
+        assert_eq!(
+            response.property_identifier,
+            PropertyIdentifier::PriorityArray
+        );
+        assert_eq!(
+            response.property_values,
+            vec![
+                property::PropertyValue::Real(0.75),
+                property::PropertyValue::Unknown(vec![0x3E, 0x21, 0x3F, 0x3F]),
+                property::PropertyValue::Null,
+            ]
+        );
+
+        let mut buffer = Vec::new();
+        response.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, data);
+
+        // Truncated before the outer closing tag
+        assert!(ReadPropertyResponse::decode(&data[..data.len() - 1]).is_err());
+
+        assert_eq!(
+            ReadPropertyResponse::value_data(&data).unwrap(),
+            &data[8..data.len() - 1]
+        );
+        assert!(ReadPropertyResponse::value_data(&data[..data.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_i_am_into_fixed_buffer() {
+        use crate::encoding::{EncodingError, FixedBuffer};
+
+        let iam = IAmRequest::new(
+            ObjectIdentifier::new(ObjectType::Device, 4194302),
+            1476,
+            Segmentation::Both,
+            260,
+        );
+
+        let mut expected = Vec::new();
+        iam.encode(&mut expected).unwrap();
+
+        let mut buffer = FixedBuffer::<64>::new();
+        iam.encode(&mut buffer).unwrap();
+        assert_eq!(buffer.as_slice(), expected.as_slice());
+        assert_eq!(IAmRequest::decode(buffer.as_slice()).unwrap(), iam);
+
+        // Too small for the vendor identifier
+        let mut small = FixedBuffer::<10>::new();
+        assert!(matches!(
+            iam.encode(&mut small),
+            Err(EncodingError::BufferOverflow)
+        ));
+    }
+
+    #[test]
+    fn test_who_has_by_name_and_i_have() {
+        // Who-Has for devices 1-100 looking for "Zone1"
+        let data = [
+            0x09, 0x01, 0x19, 0x64, 0x3D, 0x06, 0x00, b'Z', b'o', b'n', b'e', b'1',
+        ];
+        let who_has = WhoHasRequest::decode(&data).unwrap();
+        assert_eq!(who_has, WhoHasRequest::for_name("Zone1").with_range(1, 100));
+
+        let mut buffer = Vec::new();
+        who_has.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, data);
+
+        let device = ObjectIdentifier::new(ObjectType::Device, 5);
+        let zone = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+        let objects = [
+            (ObjectIdentifier::new(ObjectType::AnalogInput, 0), "Outside"),
+            (zone, "Zone1"),
+        ];
+
+        let response = respond_to_who_has(&who_has, device, objects).unwrap();
+        let apdu = crate::app::Apdu::decode(&response).unwrap();
+        let crate::app::Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::IHave,
+            service_data,
+        } = apdu
+        else {
+            panic!("expected I-Have, got {apdu:?}");
+        };
+        assert_eq!(
+            service_data,
+            [
+                0xC4, 0x02, 0x00, 0x00, 0x05, 0xC4, 0x00, 0x00, 0x00, 0x01, 0x75, 0x06, 0x00, b'Z',
+                b'o', b'n', b'e', b'1',
+            ]
+        );
+        assert_eq!(
+            IHaveRequest::decode(&service_data).unwrap(),
+            IHaveRequest::new(device, zone, "Zone1")
+        );
+
+        // No such object, or a device outside the range, stays silent
+        let missing = WhoHasRequest::for_name("Zone2");
+        assert!(respond_to_who_has(&missing, device, objects).is_none());
+        let elsewhere = ObjectIdentifier::new(ObjectType::Device, 500);
+        assert!(respond_to_who_has(&who_has, elsewhere, objects).is_none());
+
+        // By identifier, sent to all devices
+        let by_id = WhoHasRequest::for_identifier(zone);
+        let mut buffer = Vec::new();
+        by_id.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, [0x2C, 0x00, 0x00, 0x00, 0x01]);
+        assert_eq!(WhoHasRequest::decode(&buffer).unwrap(), by_id);
+        assert!(respond_to_who_has(&by_id, elsewhere, objects).is_some());
+
+        // Low limit without high limit
+        assert!(WhoHasRequest::decode(&[0x09, 0x01, 0x2C, 0x00, 0x00, 0x00, 0x01]).is_err());
+    }
+
+    #[test]
+    fn test_write_group_round_trip() {
+        let request = WriteGroupRequest::new(
+            1,
+            8,
+            vec![
+                GroupChannelValue::new(1, property::PropertyValue::Real(50.0)),
+                GroupChannelValue {
+                    overriding_priority: Some(4),
+                    ..GroupChannelValue::new(2, property::PropertyValue::Unsigned(1))
+                },
+            ],
+        );
+
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(
+            buffer,
+            [
+                0x09, 0x01, 0x19, 0x08, 0x2E, 0x09, 0x01, 0x44, 0x42, 0x48, 0x00, 0x00, 0x09, 0x02,
+                0x19, 0x04, 0x21, 0x01, 0x2F,
+            ]
+        );
+        assert_eq!(WriteGroupRequest::decode(&buffer).unwrap(), request);
+
+        let request = WriteGroupRequest {
+            inhibit_delay: Some(true),
+            ..request
+        };
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(buffer[buffer.len() - 2..], [0x39, 0x01]);
+        assert_eq!(WriteGroupRequest::decode(&buffer).unwrap(), request);
+
+        // Truncated before the closing tag of the change list
+        assert!(WriteGroupRequest::decode(&buffer[..buffer.len() - 3]).is_err());
+
+        let invalid = WriteGroupRequest::new(1, 0, Vec::new());
+        assert!(invalid.encode(&mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_decode_out_of_range_event_notification() {
+        let data = [
+            0x09, 0x01, // process identifier 1
+            0x1C, 0x02, 0x00, 0x00, 0x05, // device 5
+            0x2C, 0x00, 0x00, 0x00, 0x01, // analog-input 1
+            0x3E, 0x19, 0x2A, 0x3F, // time stamp, sequence number 42
+            0x49, 0x14, // notification class 20
+            0x59, 0x64, // priority 100
+            0x69, 0x05, // out-of-range
+            0x7D, 0x05, 0x00, b'H', b'i', b'g', b'h', // message text
+            0x89, 0x00, // alarm
+            0x99, 0x01, // ack required
+            0xA9, 0x00, // from normal
+            0xB9, 0x03, // to high-limit
+            0xCE, 0x5E, // event values, out-of-range
+            0x0C, 0x42, 0xC8, 0x00, 0x00, // exceeding value 100.0
+            0x1A, 0x04, 0x80, // status flags, in-alarm
+            0x2C, 0x3F, 0x80, 0x00, 0x00, // deadband 1.0
+            0x3C, 0x42, 0xB4, 0x00, 0x00, // exceeded limit 90.0
+            0x5F, 0xCF,
+        ];
+
+        let request = EventNotificationRequest::decode(&data).unwrap();
+        assert_eq!(request.process_identifier, 1);
+        assert_eq!(
+            request.initiating_device_identifier,
+            ObjectIdentifier::new(ObjectType::Device, 5)
+        );
+        assert_eq!(
+            request.event_object_identifier,
+            ObjectIdentifier::new(ObjectType::AnalogInput, 1)
+        );
+        assert_eq!(request.time_stamp, BacnetTimeStamp::SequenceNumber(42));
+        assert_eq!(request.notification_class, 20);
+        assert_eq!(request.priority, 100);
+        assert_eq!(request.event_type, EventType::OutOfRange);
+        assert_eq!(request.message_text.as_deref(), Some("High"));
+        assert_eq!(request.notify_type, NotifyType::Alarm);
+        assert_eq!(request.ack_required, Some(true));
+        assert_eq!(request.from_state, Some(EventState::Normal));
+        assert_eq!(request.to_state, EventState::HighLimit);
+        assert_eq!(
+            request.event_values,
+            Some(EventValues::OutOfRange {
+                exceeding_value: 100.0,
+                status_flags: StatusFlags {
+                    in_alarm: true,
+                    ..StatusFlags::default()
+                },
+                deadband: 1.0,
+                exceeded_limit: 90.0,
+            })
+        );
+
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, data);
+
+        // An ack-notification carries no ack-required, from-state or values,
+        // and other event types are kept encoded
+        let ack = EventNotificationRequest {
+            message_text: None,
+            notify_type: NotifyType::AckNotification,
+            ack_required: None,
+            from_state: None,
+            event_values: None,
+            ..request.clone()
+        };
+        let mut buffer = Vec::new();
+        ack.encode(&mut buffer).unwrap();
+        assert_eq!(EventNotificationRequest::decode(&buffer).unwrap(), ack);
+
+        let change_of_value = EventNotificationRequest {
+            event_type: EventType::ChangeOfValue,
+            event_values: Some(EventValues::ChangeOfValue {
+                new_value: ChangeOfValueNewValue::ChangedValue(21.5),
+                status_flags: StatusFlags::default(),
+            }),
+            ..request.clone()
+        };
+        let mut buffer = Vec::new();
+        change_of_value.encode(&mut buffer).unwrap();
+        assert_eq!(
+            EventNotificationRequest::decode(&buffer).unwrap(),
+            change_of_value
+        );
+
+        let other = EventNotificationRequest {
+            event_type: EventType::ChangeOfState,
+            event_values: Some(EventValues::Other {
+                tag_number: 1,
+                data: vec![0x0E, 0x09, 0x01, 0x0F, 0x1A, 0x04, 0x00],
+            }),
+            ..request
+        };
+        let mut buffer = Vec::new();
+        other.encode(&mut buffer).unwrap();
+        assert_eq!(EventNotificationRequest::decode(&buffer).unwrap(), other);
+
+        assert!(EventNotificationRequest::decode(&data[..data.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_decode_error() {
+        assert_eq!(
+            decode_error(1, 31),
+            (ErrorClass::Object, ErrorCode::UnknownObject)
+        );
+        assert_eq!(
+            decode_error(2, 32),
+            (ErrorClass::Property, ErrorCode::UnknownProperty)
+        );
+        assert_eq!(
+            decode_error(2, 40),
+            (ErrorClass::Property, ErrorCode::WriteAccessDenied)
+        );
+        assert_eq!(
+            decode_error(2, 37),
+            (ErrorClass::Property, ErrorCode::ValueOutOfRange)
+        );
+        assert_eq!(
+            decode_error(5, 29),
+            (ErrorClass::Services, ErrorCode::ServiceRequestDenied)
+        );
+        assert_eq!(u16::from(ErrorCode::WriteAccessDenied), 40);
+
+        // Vendor-proprietary and unassigned values are preserved
+        let (class, code) = decode_error(300, 512);
+        assert!(matches!(class, ErrorClass::Custom(_)));
+        assert!(matches!(code, ErrorCode::Custom(_)));
+        assert_eq!((u16::from(class), u16::from(code)), (300, 512));
+        let (class, code) = decode_error(8, 33);
+        assert!(matches!(class, ErrorClass::Reserved(_)));
+        assert!(matches!(code, ErrorCode::Reserved(_)));
+
+        assert_eq!(
+            format!("{} / {}", ErrorClass::Object, ErrorCode::UnknownObject),
+            "Object / UnknownObject"
+        );
+    }
+
+    #[test]
+    fn test_error_names() {
+        assert_eq!(ErrorClass::Vt.name(), "vt");
+        assert_eq!(ErrorClass::from(300).name(), "proprietary");
+        assert_eq!(ErrorCode::WriteAccessDenied.name(), "write-access-denied");
+        assert_eq!(
+            ErrorCode::OptionalFunctionalityNotSupported.name(),
+            "optional-functionality-not-supported"
+        );
+        assert_eq!(ErrorCode::from(512).name(), "proprietary");
+        assert_eq!(ErrorCode::from(33).name(), "reserved");
+    }
 }
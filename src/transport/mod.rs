@@ -35,9 +35,12 @@
 #[cfg(feature = "std")]
 use std::error::Error;
 
+#[cfg(feature = "std")]
+use crate::app::{Apdu, PeerInvokeIdManager};
+
 #[cfg(feature = "std")]
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     fmt,
     net::{IpAddr, SocketAddr},
     time::Instant,
@@ -489,6 +492,167 @@ impl Default for TimeoutManager {
     }
 }
 
+/// How long a [`TransactionTable`] keeps the invoke ID state of an idle peer
+#[cfg(feature = "std")]
+pub const PEER_IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// An outstanding confirmed request
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PendingTransaction {
+    /// When the request was first sent
+    pub started: Instant,
+    /// When the response is due
+    pub deadline: Instant,
+    /// Number of times the request has been resent
+    pub retries: u8,
+}
+
+/// Outstanding confirmed requests keyed by peer and invoke ID
+///
+/// Invoke IDs are allocated per peer from a [`PeerInvokeIdManager`] and
+/// released when the transaction is completed or abandoned, so they can be
+/// reused. A peer keeps its place in the invoke ID sequence until it has had
+/// no outstanding transaction for [`PEER_IDLE_TIMEOUT`], so a late reply is
+/// not mistaken for the answer to the next request.
+/// [`TransactionTable::poll_timeouts`] reports the transactions whose
+/// deadline has passed; the caller either resends the request and calls
+/// [`TransactionTable::retry`], or gives up with
+/// [`TransactionTable::complete`].
+///
+/// [`PeerInvokeIdManager`]: crate::app::PeerInvokeIdManager
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct TransactionTable<A> {
+    invoke_ids: PeerInvokeIdManager<A>,
+    transactions: BTreeMap<(A, u8), PendingTransaction>,
+    /// When each peer without outstanding transactions became idle
+    idle_since: BTreeMap<A, Instant>,
+}
+
+#[cfg(feature = "std")]
+impl<A: Ord + Clone> TransactionTable<A> {
+    /// Create an empty transaction table
+    pub fn new() -> Self {
+        Self {
+            invoke_ids: PeerInvokeIdManager::new(),
+            transactions: BTreeMap::new(),
+            idle_since: BTreeMap::new(),
+        }
+    }
+
+    /// Start a transaction with `peer`, returning its invoke ID
+    ///
+    /// Returns `None` when all 256 invoke IDs of the peer are in use.
+    pub fn begin(&mut self, peer: A, deadline: Instant) -> Option<u8> {
+        self.prune_idle_peers(Instant::now());
+        self.idle_since.remove(&peer);
+        let invoke_id = self.invoke_ids.next_id(&peer)?;
+        self.transactions.insert(
+            (peer, invoke_id),
+            PendingTransaction {
+                started: Instant::now(),
+                deadline,
+                retries: 0,
+            },
+        );
+        Some(invoke_id)
+    }
+
+    /// Get an outstanding transaction
+    pub fn get(&self, peer: &A, invoke_id: u8) -> Option<&PendingTransaction> {
+        self.transactions.get(&(peer.clone(), invoke_id))
+    }
+
+    /// Record a resend of a transaction with a new deadline
+    ///
+    /// Returns `false` if the transaction is not outstanding.
+    pub fn retry(&mut self, peer: &A, invoke_id: u8, deadline: Instant) -> bool {
+        match self.transactions.get_mut(&(peer.clone(), invoke_id)) {
+            Some(transaction) => {
+                transaction.deadline = deadline;
+                transaction.retries = transaction.retries.saturating_add(1);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// End a transaction and release its invoke ID
+    pub fn complete(&mut self, peer: &A, invoke_id: u8) -> Option<PendingTransaction> {
+        let transaction = self.transactions.remove(&(peer.clone(), invoke_id))?;
+        self.invoke_ids.release_id(peer, invoke_id);
+        let idle = self
+            .transactions
+            .range((peer.clone(), 0)..=(peer.clone(), u8::MAX))
+            .next()
+            .is_none();
+        if idle {
+            self.idle_since.insert(peer.clone(), Instant::now());
+        }
+        Some(transaction)
+    }
+
+    /// Forget the invoke ID state of peers idle for [`PEER_IDLE_TIMEOUT`]
+    ///
+    /// This is done whenever a transaction begins.
+    pub fn prune_idle_peers(&mut self, now: Instant) {
+        let invoke_ids = &mut self.invoke_ids;
+        self.idle_since.retain(|peer, since| {
+            let keep = now.saturating_duration_since(*since) < PEER_IDLE_TIMEOUT;
+            if !keep {
+                invoke_ids.remove_peer(peer);
+            }
+            keep
+        });
+    }
+
+    /// End the transaction a response APDU from `peer` answers
+    ///
+    /// Returns the invoke ID of the completed transaction, or `None` if the
+    /// APDU is not a response or answers no outstanding request.
+    pub fn complete_response(&mut self, peer: &A, apdu: &Apdu) -> Option<u8> {
+        if !apdu.is_response() {
+            return None;
+        }
+        let invoke_id = apdu.invoke_id()?;
+        self.complete(peer, invoke_id).map(|_| invoke_id)
+    }
+
+    /// Transactions whose deadline is before `now`
+    pub fn poll_timeouts(&self, now: Instant) -> Vec<(A, u8)> {
+        self.transactions
+            .iter()
+            .filter(|(_, transaction)| transaction.deadline < now)
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    /// Iterate over the outstanding transactions
+    pub fn iter(&self) -> impl Iterator<Item = (&A, u8, &PendingTransaction)> {
+        self.transactions
+            .iter()
+            .map(|((peer, invoke_id), transaction)| (peer, *invoke_id, transaction))
+    }
+
+    /// Number of outstanding transactions
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Check if no transaction is outstanding
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A: Ord + Clone> Default for TransactionTable<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// BACnet/IP transport implementation
 #[cfg(feature = "std")]
 pub struct BacnetIpTransport {
@@ -1217,6 +1381,87 @@ mod tests {
         assert!(elapsed.is_none());
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_transaction_table() {
+        let peer: SocketAddr = "192.168.1.10:47808".parse().unwrap();
+        let start = Instant::now();
+        let mut table = TransactionTable::new();
+
+        let invoke_id = table.begin(peer, start + Duration::from_secs(1)).unwrap();
+        let other = table.begin(peer, start + Duration::from_secs(5)).unwrap();
+        assert_ne!(invoke_id, other);
+        assert_eq!(table.len(), 2);
+
+        assert!(table
+            .poll_timeouts(start + Duration::from_millis(500))
+            .is_empty());
+        assert_eq!(
+            table.poll_timeouts(start + Duration::from_millis(1001)),
+            vec![(peer, invoke_id)]
+        );
+
+        // Resending moves the deadline
+        assert!(table.retry(&peer, invoke_id, start + Duration::from_secs(3)));
+        assert_eq!(table.get(&peer, invoke_id).unwrap().retries, 1);
+        assert!(table
+            .poll_timeouts(start + Duration::from_secs(2))
+            .is_empty());
+
+        // A response completes the transaction and frees the invoke ID
+        let ack = Apdu::SimpleAck {
+            invoke_id,
+            service_choice: 15,
+        };
+        assert_eq!(table.complete_response(&peer, &ack), Some(invoke_id));
+        assert!(!table.invoke_ids.is_active(&peer, invoke_id));
+        assert_eq!(table.complete_response(&peer, &ack), None);
+
+        // Responses from another peer do not match
+        let stranger: SocketAddr = "192.168.1.11:47808".parse().unwrap();
+        let ack = Apdu::SimpleAck {
+            invoke_id: other,
+            service_choice: 15,
+        };
+        assert_eq!(table.complete_response(&stranger, &ack), None);
+
+        assert!(table.complete(&peer, other).is_some());
+        assert!(table.is_empty());
+        assert!(!table.retry(&peer, other, start));
+
+        // A peer that has just become idle continues its invoke ID sequence
+        let next = table.begin(peer, start).unwrap();
+        assert_ne!(next, invoke_id);
+        assert_ne!(next, other);
+        assert!(table.complete(&peer, next).is_some());
+
+        // Once idle for long enough it is forgotten, and its IDs start over
+        table.prune_idle_peers(Instant::now() + PEER_IDLE_TIMEOUT - Duration::from_secs(1));
+        assert!(table.idle_since.contains_key(&peer));
+        table.prune_idle_peers(Instant::now() + PEER_IDLE_TIMEOUT);
+        assert!(table.idle_since.is_empty());
+        assert_eq!(table.begin(peer, start), Some(invoke_id));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_timeout_manager() {
+        let mut manager = TimeoutManager::new();
+        let expired = manager.start_request(Duration::ZERO);
+        let pending = manager.start_request(Duration::from_secs(60));
+        assert_eq!((expired, pending), (1, 2));
+        assert_eq!(manager.active_count(), 2);
+        assert!(manager.remaining_time(pending).unwrap() > Duration::from_secs(59));
+
+        std::thread::sleep(Duration::from_millis(2));
+        assert_eq!(manager.check_timeouts(), vec![expired]);
+        assert_eq!(manager.active_invoke_ids(), vec![pending]);
+
+        assert!(manager.complete_request(pending).is_some());
+        assert!(manager.complete_request(pending).is_none());
+        assert_eq!(manager.active_count(), 0);
+    }
+
     #[test]
     fn test_timeout_constants() {
         assert_eq!(constants::DEFAULT_REQUEST_TIMEOUT.as_secs(), 10);
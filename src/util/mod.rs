@@ -208,9 +208,46 @@ use std::{
 #[cfg(not(feature = "std"))]
 use alloc::collections::BTreeMap as HashMap;
 
+/// Calculate the 8-bit header CRC for MS/TP frames
+///
+/// Covers the frame type, destination, source and data length octets. Uses
+/// the polynomial x^8 + x^7 + 1 with the register seeded to 0xFF and returns
+/// the ones complement of the register, which is the value transmitted as
+/// the header CRC (Clause 9 and Annex G.1).
+pub fn crc8_mstp(data: &[u8]) -> u8 {
+    !crc8_mstp_register(data)
+}
+
+/// Verify an MS/TP header against its CRC
+///
+/// `data` holds the header octets followed by the received header CRC. Running
+/// the CRC over all of them leaves the register at 0x55 if the header is
+/// intact.
+pub fn crc8_mstp_verify(data: &[u8]) -> bool {
+    crc8_mstp_register(data) == 0x55
+}
+
+fn crc8_mstp_register(data: &[u8]) -> u8 {
+    let mut crc = 0xFFu8;
+
+    for byte in data {
+        crc ^= *byte;
+        for _ in 0..8 {
+            if crc & 0x01 != 0 {
+                crc = (crc >> 1) ^ 0x81;
+            } else {
+                crc >>= 1;
+            }
+        }
+    }
+
+    crc
+}
+
 /// Calculate CRC-16 for MS/TP frames
 ///
-/// Uses the polynomial x^16 + x^15 + x^2 + 1 (0xA001)
+/// Uses the CRC-CCITT polynomial x^16 + x^12 + x^5 + 1, processed least
+/// significant bit first (0x8408), as defined in Clause 9 and Annex G.2
 pub fn crc16_mstp(data: &[u8]) -> u16 {
     let mut crc = 0xFFFF;
 
@@ -218,7 +255,7 @@ pub fn crc16_mstp(data: &[u8]) -> u16 {
         crc ^= *byte as u16;
         for _ in 0..8 {
             if crc & 0x0001 != 0 {
-                crc = (crc >> 1) ^ 0xA001;
+                crc = (crc >> 1) ^ 0x8408;
             } else {
                 crc >>= 1;
             }
@@ -953,7 +990,12 @@ impl<T: Clone> CircularBuffer<T> {
 
 /// Debug formatting utilities for BACnet data structures and protocol analysis
 pub mod debug {
+    use crate::app::Apdu;
+    use crate::encoding::{decode_tag, ApplicationTag, BACnetTag};
+    use crate::network::{NetworkAddress, Npdu};
     use crate::object::ObjectIdentifier;
+    use crate::property::decode_property_value;
+    use crate::service::{ConfirmedServiceChoice, UnconfirmedServiceChoice};
 
     use super::*;
 
@@ -1387,6 +1429,203 @@ pub mod debug {
         result
     }
 
+    /// Describe a decoded APDU, one field per line
+    ///
+    /// Service choices are shown by name, followed by their number.
+    pub fn format_apdu(apdu: &Apdu) -> String {
+        let mut result = String::new();
+
+        match apdu {
+            Apdu::ConfirmedRequest {
+                segmented,
+                more_follows,
+                segmented_response_accepted,
+                max_segments,
+                max_response_size,
+                invoke_id,
+                sequence_number,
+                proposed_window_size,
+                service_choice,
+                service_data,
+            } => {
+                result.push_str("APDU: Confirmed-Request\n");
+                result.push_str(&format!("  Invoke ID: {}\n", invoke_id));
+                result.push_str(&format!(
+                    "  Service: {:?} ({})\n",
+                    service_choice, *service_choice as u8
+                ));
+                result.push_str(&format!(
+                    "  Segmented: {}, More Follows: {}, Segmented Response Accepted: {}\n",
+                    segmented, more_follows, segmented_response_accepted
+                ));
+                result.push_str(&format!(
+                    "  Max Segments: {:?}, Max APDU: {} bytes\n",
+                    max_segments,
+                    max_response_size.size()
+                ));
+                push_segment_fields(&mut result, *sequence_number, *proposed_window_size);
+                result.push_str(&format!("  Service Data: {} bytes\n", service_data.len()));
+            }
+            Apdu::UnconfirmedRequest {
+                service_choice,
+                service_data,
+            } => {
+                result.push_str("APDU: Unconfirmed-Request\n");
+                result.push_str(&format!(
+                    "  Service: {:?} ({})\n",
+                    service_choice, *service_choice as u8
+                ));
+                result.push_str(&format!("  Service Data: {} bytes\n", service_data.len()));
+            }
+            Apdu::SimpleAck {
+                invoke_id,
+                service_choice,
+            } => {
+                result.push_str("APDU: Simple-ACK\n");
+                result.push_str(&format!("  Invoke ID: {}\n", invoke_id));
+                match ConfirmedServiceChoice::try_from(*service_choice) {
+                    Ok(choice) => {
+                        result.push_str(&format!("  Service: {:?} ({})\n", choice, service_choice))
+                    }
+                    Err(_) => {
+                        result.push_str(&format!("  Service: Unknown ({})\n", service_choice))
+                    }
+                }
+            }
+            Apdu::ComplexAck {
+                segmented,
+                more_follows,
+                invoke_id,
+                sequence_number,
+                proposed_window_size,
+                service_choice,
+                service_data,
+            } => {
+                result.push_str("APDU: Complex-ACK\n");
+                result.push_str(&format!("  Invoke ID: {}\n", invoke_id));
+                result.push_str(&format!(
+                    "  Service: {:?} ({})\n",
+                    service_choice, *service_choice as u8
+                ));
+                result.push_str(&format!(
+                    "  Segmented: {}, More Follows: {}\n",
+                    segmented, more_follows
+                ));
+                push_segment_fields(&mut result, *sequence_number, *proposed_window_size);
+                result.push_str(&format!("  Service Data: {} bytes\n", service_data.len()));
+            }
+            Apdu::SegmentAck {
+                negative,
+                server,
+                invoke_id,
+                sequence_number,
+                window_size,
+            } => {
+                result.push_str("APDU: Segment-ACK\n");
+                result.push_str(&format!("  Invoke ID: {}\n", invoke_id));
+                result.push_str(&format!("  Negative: {}, Server: {}\n", negative, server));
+                push_segment_fields(&mut result, Some(*sequence_number), Some(*window_size));
+            }
+            Apdu::Error {
+                invoke_id,
+                service_choice,
+                error_class,
+                error_code,
+            } => {
+                result.push_str("APDU: Error\n");
+                result.push_str(&format!("  Invoke ID: {}\n", invoke_id));
+                result.push_str(&format!(
+                    "  Service: {:?} ({})\n",
+                    service_choice, *service_choice as u8
+                ));
+                result.push_str(&format!(
+                    "  Error: class {}, code {}\n",
+                    error_class, error_code
+                ));
+            }
+            Apdu::Reject {
+                invoke_id,
+                reject_reason,
+            } => {
+                result.push_str("APDU: Reject\n");
+                result.push_str(&format!("  Invoke ID: {}\n", invoke_id));
+                result.push_str(&format!("  Reason: {:?}\n", reject_reason));
+            }
+            Apdu::Abort {
+                server,
+                invoke_id,
+                abort_reason,
+            } => {
+                result.push_str("APDU: Abort\n");
+                result.push_str(&format!("  Invoke ID: {}\n", invoke_id));
+                result.push_str(&format!("  Server: {}\n", server));
+                result.push_str(&format!("  Reason: {}\n", abort_reason));
+            }
+        }
+
+        result
+    }
+
+    fn push_segment_fields(
+        result: &mut String,
+        sequence_number: Option<u8>,
+        window_size: Option<u8>,
+    ) {
+        if let Some(sequence_number) = sequence_number {
+            result.push_str(&format!("  Sequence Number: {}\n", sequence_number));
+        }
+        if let Some(window_size) = window_size {
+            result.push_str(&format!("  Window Size: {}\n", window_size));
+        }
+    }
+
+    /// Describe a decoded NPDU, one field per line
+    pub fn format_npdu(npdu: &Npdu) -> String {
+        let mut result = String::new();
+        let control = &npdu.control;
+
+        result.push_str("NPDU:\n");
+        result.push_str(&format!("  Version: {}\n", npdu.version));
+        result.push_str(&format!(
+            "  Network Message: {}, Expecting Reply: {}\n",
+            control.network_message, control.expecting_reply
+        ));
+        result.push_str(&format!(
+            "  Priority: {} ({})\n",
+            control.priority,
+            priority_name(control.priority)
+        ));
+
+        if let Some(destination) = &npdu.destination {
+            result.push_str(&format!(
+                "  Destination: {}\n",
+                format_network_address(destination)
+            ));
+        }
+        if let Some(source) = &npdu.source {
+            result.push_str(&format!("  Source: {}\n", format_network_address(source)));
+        }
+        if let Some(hop_count) = npdu.hop_count {
+            result.push_str(&format!("  Hop Count: {}\n", hop_count));
+        }
+
+        result
+    }
+
+    fn format_network_address(address: &NetworkAddress) -> String {
+        let network = if address.network == 0xFFFF {
+            "global broadcast".to_string()
+        } else {
+            format!("network {}", address.network)
+        };
+
+        if address.address.is_empty() {
+            format!("{}, broadcast", network)
+        } else {
+            format!("{}, MAC {:02X?}", network, address.address)
+        }
+    }
+
     /// Debug formatter for network layer (NPDU)
     pub fn format_npdu_structure(data: &[u8]) -> String {
         if data.is_empty() {
@@ -1417,13 +1656,7 @@ pub mod debug {
         result.push_str(&format!(
             "    Priority: {} ({})\n",
             priority,
-            match priority {
-                0 => "Normal",
-                1 => "Urgent",
-                2 => "Critical",
-                3 => "Life Safety",
-                _ => "Unknown",
-            }
+            priority_name(priority)
         ));
 
         let mut pos = 2;
@@ -1497,22 +1730,7 @@ pub mod debug {
         result.push_str(&format!(
             "  Function: 0x{:02X} ({})\n",
             function,
-            match function {
-                0x00 => "Result",
-                0x01 => "Write-BDT",
-                0x02 => "Read-BDT",
-                0x03 => "Read-BDT-Ack",
-                0x04 => "Forwarded-NPDU",
-                0x05 => "Register-Foreign-Device",
-                0x06 => "Read-FDT",
-                0x07 => "Read-FDT-Ack",
-                0x08 => "Delete-FDT-Entry",
-                0x09 => "Distribute-Broadcast-To-Network",
-                0x0A => "Original-Unicast-NPDU",
-                0x0B => "Original-Broadcast-NPDU",
-                0x0C => "Secure-BVLL",
-                _ => "Unknown",
-            }
+            bvll_function_name(function)
         ));
 
         result.push_str(&format!("  Length: {} bytes\n", length));
@@ -1527,12 +1745,454 @@ pub mod debug {
 
         result
     }
+
+    fn bvll_function_name(function: u8) -> &'static str {
+        match function {
+            0x00 => "Result",
+            0x01 => "Write-BDT",
+            0x02 => "Read-BDT",
+            0x03 => "Read-BDT-Ack",
+            0x04 => "Forwarded-NPDU",
+            0x05 => "Register-Foreign-Device",
+            0x06 => "Read-FDT",
+            0x07 => "Read-FDT-Ack",
+            0x08 => "Delete-FDT-Entry",
+            0x09 => "Distribute-Broadcast-To-Network",
+            0x0A => "Original-Unicast-NPDU",
+            0x0B => "Original-Broadcast-NPDU",
+            0x0C => "Secure-BVLL",
+            _ => "Unknown",
+        }
+    }
+
+    fn priority_name(priority: u8) -> &'static str {
+        match priority {
+            0 => "Normal",
+            1 => "Urgent",
+            2 => "Critical",
+            3 => "Life Safety",
+            _ => "Unknown",
+        }
+    }
+
+    /// Break a frame down field by field, like a protocol analyzer
+    ///
+    /// Each line shows the offset of a field, its octets and what they mean,
+    /// from the BVLC header (if the frame starts with one) through the NPDU
+    /// and APDU headers to the individual tags of the service data. Anything
+    /// that cannot be decoded is marked as such and ends the breakdown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bacnet_rs::util::debug::annotate_packet;
+    ///
+    /// let who_is = [0x81, 0x0B, 0x00, 0x0C, 0x01, 0x20, 0xFF, 0xFF, 0x00, 0xFF, 0x10, 0x08];
+    /// let breakdown = annotate_packet(&who_is);
+    /// assert!(breakdown.contains("Service Choice: WhoIs (8)"));
+    /// ```
+    pub fn annotate_packet(data: &[u8]) -> String {
+        let mut annotator = PacketAnnotator {
+            data,
+            pos: 0,
+            lines: String::new(),
+        };
+        annotator.annotate();
+        annotator.lines
+    }
+
+    /// State of [`annotate_packet`] while it walks a frame
+    struct PacketAnnotator<'a> {
+        data: &'a [u8],
+        pos: usize,
+        lines: String,
+    }
+
+    impl<'a> PacketAnnotator<'a> {
+        fn annotate(&mut self) {
+            if self.data.first() == Some(&0x81) && !self.bvlc() {
+                return;
+            }
+            if self.npdu() {
+                self.apdu();
+            }
+        }
+
+        /// Annotate the next `len` octets, or mark the rest of the frame
+        /// undecodable if fewer remain
+        fn field(
+            &mut self,
+            len: usize,
+            name: &str,
+            describe: impl FnOnce(&[u8]) -> String,
+        ) -> Option<&'a [u8]> {
+            let data = self.data;
+            if data.len() - self.pos < len {
+                self.undecodable(&format!("{} truncated", name));
+                return None;
+            }
+
+            let bytes = &data[self.pos..self.pos + len];
+            self.line(len, &format!("{}: {}", name, describe(bytes)));
+            Some(bytes)
+        }
+
+        fn line(&mut self, len: usize, meaning: &str) {
+            let bytes = &self.data[self.pos..self.pos + len];
+            let mut hex = bytes
+                .iter()
+                .take(8)
+                .map(|byte| format!("{:02X}", byte))
+                .collect::<Vec<_>>()
+                .join(" ");
+            if bytes.len() > 8 {
+                hex.push_str(" ..");
+            }
+
+            self.lines
+                .push_str(&format!("{:04X}  {:<26} {}\n", self.pos, hex, meaning));
+            self.pos += len;
+        }
+
+        fn undecodable(&mut self, reason: &str) {
+            self.line(
+                self.data.len() - self.pos,
+                &format!("Undecodable: {}", reason),
+            );
+        }
+
+        fn rest(&mut self, name: &str) {
+            if self.pos < self.data.len() {
+                self.line(self.data.len() - self.pos, name);
+            }
+        }
+
+        /// Returns whether an NPDU follows
+        fn bvlc(&mut self) -> bool {
+            self.field(1, "BVLC Type", |_| "BACnet/IP".to_string());
+            let Some(function) = self.field(1, "BVLC Function", |bytes| {
+                bvll_function_name(bytes[0]).to_string()
+            }) else {
+                return false;
+            };
+            let function = function[0];
+
+            let frame_len = self.data.len();
+            if self
+                .field(2, "BVLC Length", |bytes| {
+                    let length = u16::from_be_bytes([bytes[0], bytes[1]]) as usize;
+                    if length == frame_len {
+                        format!("{} bytes", length)
+                    } else {
+                        format!("{} bytes (frame is {} bytes)", length, frame_len)
+                    }
+                })
+                .is_none()
+            {
+                return false;
+            }
+
+            if function == 0x04
+                && self
+                    .field(6, "Original Source", |bytes| {
+                        format!(
+                            "{}.{}.{}.{}:{}",
+                            bytes[0],
+                            bytes[1],
+                            bytes[2],
+                            bytes[3],
+                            u16::from_be_bytes([bytes[4], bytes[5]])
+                        )
+                    })
+                    .is_none()
+            {
+                return false;
+            }
+
+            if matches!(function, 0x04 | 0x09 | 0x0A | 0x0B) {
+                true
+            } else {
+                self.rest("BVLC Payload");
+                false
+            }
+        }
+
+        /// Returns whether an APDU follows
+        fn npdu(&mut self) -> bool {
+            if self
+                .field(1, "NPDU Version", |bytes| bytes[0].to_string())
+                .is_none()
+            {
+                return false;
+            }
+            let Some(control) = self.field(1, "NPDU Control", |bytes| {
+                let control = bytes[0];
+                let mut flags = Vec::new();
+                if control & 0x80 != 0 {
+                    flags.push("network message");
+                }
+                if control & 0x20 != 0 {
+                    flags.push("destination");
+                }
+                if control & 0x08 != 0 {
+                    flags.push("source");
+                }
+                if control & 0x04 != 0 {
+                    flags.push("expecting reply");
+                }
+                flags.push(priority_name(control & 0x03));
+                format!("0x{:02X} ({})", control, flags.join(", "))
+            }) else {
+                return false;
+            };
+            let control = control[0];
+
+            for (present, side) in [
+                (control & 0x20 != 0, "Destination"),
+                (control & 0x08 != 0, "Source"),
+            ] {
+                if present && !self.network_address(side) {
+                    return false;
+                }
+            }
+            if control & 0x20 != 0
+                && self
+                    .field(1, "Hop Count", |bytes| bytes[0].to_string())
+                    .is_none()
+            {
+                return false;
+            }
+
+            if control & 0x80 != 0 {
+                self.field(1, "Network Message Type", |bytes| {
+                    format!("0x{:02X}", bytes[0])
+                });
+                self.rest("Network Message Data");
+                return false;
+            }
+            true
+        }
+
+        fn network_address(&mut self, side: &str) -> bool {
+            if self
+                .field(
+                    2,
+                    &format!("{} Network", side),
+                    |bytes| match u16::from_be_bytes([bytes[0], bytes[1]]) {
+                        0xFFFF => "65535 (global broadcast)".to_string(),
+                        network => network.to_string(),
+                    },
+                )
+                .is_none()
+            {
+                return false;
+            }
+            let Some(length) = self.field(1, &format!("{} MAC Length", side), |bytes| {
+                if bytes[0] == 0 {
+                    "0 (broadcast)".to_string()
+                } else {
+                    bytes[0].to_string()
+                }
+            }) else {
+                return false;
+            };
+
+            let length = length[0] as usize;
+            length == 0
+                || self
+                    .field(length, &format!("{} MAC", side), |bytes| {
+                        format!("{:02X?}", bytes)
+                    })
+                    .is_some()
+        }
+
+        fn apdu(&mut self) {
+            let Some(header) = self.field(1, "APDU Type", |bytes| {
+                let mut description = match bytes[0] >> 4 {
+                    0 => "Confirmed-Request",
+                    1 => "Unconfirmed-Request",
+                    2 => "Simple-ACK",
+                    3 => "Complex-ACK",
+                    4 => "Segment-ACK",
+                    5 => "Error",
+                    6 => "Reject",
+                    7 => "Abort",
+                    _ => "Reserved",
+                }
+                .to_string();
+                if bytes[0] & 0x0F != 0 {
+                    description.push_str(&format!(" (flags 0x{:X})", bytes[0] & 0x0F));
+                }
+                description
+            }) else {
+                return;
+            };
+            let pdu_type = header[0] >> 4;
+            let segmented = header[0] & 0x08 != 0;
+            let byte = |bytes: &[u8]| bytes[0].to_string();
+
+            let complete = match pdu_type {
+                0 => {
+                    self.field(1, "Max Segments / Max APDU", |bytes| {
+                        format!("0x{:02X}", bytes[0])
+                    })
+                    .is_some()
+                        && self.field(1, "Invoke ID", byte).is_some()
+                        && (!segmented || self.segment_fields())
+                        && self.confirmed_service_choice()
+                }
+                1 => self
+                    .field(
+                        1,
+                        "Service Choice",
+                        |bytes| match UnconfirmedServiceChoice::try_from(bytes[0]) {
+                            Ok(choice) => format!("{:?} ({})", choice, bytes[0]),
+                            Err(_) => format!("Unknown ({})", bytes[0]),
+                        },
+                    )
+                    .is_some(),
+                2 => self.field(1, "Invoke ID", byte).is_some() && self.confirmed_service_choice(),
+                3 => {
+                    self.field(1, "Invoke ID", byte).is_some()
+                        && (!segmented || self.segment_fields())
+                        && self.confirmed_service_choice()
+                }
+                4 => self.field(1, "Invoke ID", byte).is_some() && self.segment_fields(),
+                5 => self.field(1, "Invoke ID", byte).is_some() && self.confirmed_service_choice(),
+                6 | 7 => {
+                    self.field(1, "Invoke ID", byte).is_some()
+                        && self.field(1, "Reason", byte).is_some()
+                }
+                _ => {
+                    self.undecodable("reserved APDU type");
+                    false
+                }
+            };
+
+            if complete {
+                self.tags();
+            }
+        }
+
+        fn segment_fields(&mut self) -> bool {
+            self.field(1, "Sequence Number", |bytes| bytes[0].to_string())
+                .is_some()
+                && self
+                    .field(1, "Window Size", |bytes| bytes[0].to_string())
+                    .is_some()
+        }
+
+        fn confirmed_service_choice(&mut self) -> bool {
+            self.field(
+                1,
+                "Service Choice",
+                |bytes| match ConfirmedServiceChoice::try_from(bytes[0]) {
+                    Ok(choice) => format!("{:?} ({})", choice, bytes[0]),
+                    Err(_) => format!("Unknown ({})", bytes[0]),
+                },
+            )
+            .is_some()
+        }
+
+        /// Annotate the service data tag by tag
+        fn tags(&mut self) {
+            let mut depth = 0;
+
+            while self.pos < self.data.len() {
+                let rest = &self.data[self.pos..];
+                let Ok((tag, length, header)) = decode_tag(rest) else {
+                    self.undecodable("invalid tag");
+                    return;
+                };
+
+                if let BACnetTag::Context(number) = tag {
+                    match rest[0] & 0x07 {
+                        6 => {
+                            self.line(
+                                header,
+                                &format!("{}Opening Tag {}", "  ".repeat(depth), number),
+                            );
+                            depth += 1;
+                            continue;
+                        }
+                        7 => {
+                            depth = depth.saturating_sub(1);
+                            self.line(
+                                header,
+                                &format!("{}Closing Tag {}", "  ".repeat(depth), number),
+                            );
+                            continue;
+                        }
+                        _ => {}
+                    }
+                }
+
+                // A boolean carries its value in the length field
+                let content = match tag {
+                    BACnetTag::Application(ApplicationTag::Boolean) => 0,
+                    _ => length,
+                };
+                if rest.len() < header + content {
+                    self.undecodable("tag content truncated");
+                    return;
+                }
+
+                let meaning = match tag {
+                    BACnetTag::Application(application_tag) => {
+                        match decode_property_value(&rest[..header + content]) {
+                            Ok((value, _)) => format!("{:?}", value),
+                            Err(_) => format!("{:?} (invalid)", application_tag),
+                        }
+                    }
+                    BACnetTag::Context(number) if (1..=4).contains(&content) => {
+                        let value = rest[header..header + content]
+                            .iter()
+                            .fold(0u32, |value, &byte| (value << 8) | byte as u32);
+                        format!("Context Tag {}, {} bytes = {}", number, content, value)
+                    }
+                    BACnetTag::Context(number) => {
+                        format!("Context Tag {}, {} bytes", number, content)
+                    }
+                };
+                self.line(
+                    header + content,
+                    &format!("{}{}", "  ".repeat(depth), meaning),
+                );
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_crc8_mstp() {
+        // Token from station 5 to station 16 (Annex G.1)
+        let header = [0x00, 0x10, 0x05, 0x00, 0x00];
+        assert_eq!(crc8_mstp(&header), 0x8C);
+        assert!(crc8_mstp_verify(&[0x00, 0x10, 0x05, 0x00, 0x00, 0x8C]));
+
+        // Poll-For-Master and a data frame header
+        assert_eq!(crc8_mstp(&[0x01, 0x06, 0x05, 0x00, 0x00]), 0xB1);
+        assert_eq!(crc8_mstp(&[0x06, 0x10, 0x05, 0x00, 0x03]), 0x9C);
+
+        // A corrupted octet or CRC is detected
+        assert!(!crc8_mstp_verify(&[0x00, 0x11, 0x05, 0x00, 0x00, 0x8C]));
+        assert!(!crc8_mstp_verify(&[0x00, 0x10, 0x05, 0x00, 0x00, 0x73]));
+    }
+
+    #[test]
+    fn test_crc16_mstp() {
+        // Data CRC example from Annex G.2
+        assert_eq!(crc16_mstp(&[0x01, 0x22, 0x30]), 0xBD10);
+
+        // Running the CRC over the data and its CRC, low octet first, leaves
+        // the register at 0xF0B8
+        assert_eq!(crc16_mstp(&[0x01, 0x22, 0x30, 0x10, 0xBD]), !0xF0B8);
+    }
+
     #[test]
     fn test_format_bytes() {
         assert_eq!(format_bytes(0), "0 B");
@@ -1645,6 +2305,105 @@ mod tests {
         assert!(formatted.contains("object"));
     }
 
+    #[test]
+    fn test_format_apdu_and_npdu() {
+        use crate::app::{Apdu, MaxApduSize, MaxSegments};
+        use crate::network::{NetworkAddress, Npdu};
+        use crate::service::ConfirmedServiceChoice;
+
+        let apdu = Apdu::ConfirmedRequest {
+            segmented: false,
+            more_follows: false,
+            segmented_response_accepted: true,
+            max_segments: MaxSegments::Unspecified,
+            max_response_size: MaxApduSize::Up1476,
+            invoke_id: 42,
+            sequence_number: None,
+            proposed_window_size: None,
+            service_choice: ConfirmedServiceChoice::ReadProperty,
+            service_data: vec![0x0C, 0x00, 0x00, 0x00, 0x01, 0x19, 0x55],
+        };
+        let formatted = debug::format_apdu(&apdu);
+        assert!(formatted.starts_with("APDU: Confirmed-Request\n"));
+        assert!(formatted.contains("Invoke ID: 42\n"));
+        assert!(formatted.contains("Service: ReadProperty (12)\n"));
+        assert!(formatted.contains("Max APDU: 1476 bytes"));
+        assert!(formatted.contains("Service Data: 7 bytes"));
+        assert!(!formatted.contains("Sequence Number"));
+
+        let formatted = debug::format_apdu(&Apdu::SimpleAck {
+            invoke_id: 7,
+            service_choice: 15,
+        });
+        assert!(formatted.contains("Service: WriteProperty (15)"));
+
+        let mut npdu = Npdu::new();
+        npdu.control.expecting_reply = true;
+        npdu.destination = Some(NetworkAddress::new(5, vec![0x0A]));
+        npdu.hop_count = Some(255);
+        let formatted = debug::format_npdu(&npdu);
+        assert!(formatted.contains("Expecting Reply: true"));
+        assert!(formatted.contains("Destination: network 5, MAC [0A]"));
+        assert!(formatted.contains("Hop Count: 255"));
+        assert!(!formatted.contains("Source"));
+    }
+
+    #[test]
+    fn test_annotate_who_is_broadcast() {
+        // Who-Is for devices 0-123, as sent by a workstation
+        let frame = [
+            0x81, 0x0B, 0x00, 0x10, 0x01, 0x20, 0xFF, 0xFF, 0x00, 0xFF, 0x10, 0x08, 0x09, 0x00,
+            0x19, 0x7B,
+        ];
+        let breakdown = debug::annotate_packet(&frame);
+        let lines: Vec<&str> = breakdown.lines().collect();
+
+        assert!(lines[0].starts_with("0000  81 "));
+        assert!(lines[0].ends_with("BVLC Type: BACnet/IP"));
+        assert!(lines[1].ends_with("BVLC Function: Original-Broadcast-NPDU"));
+        assert!(lines[2].starts_with("0002  00 10 "));
+        assert!(lines[2].ends_with("BVLC Length: 16 bytes"));
+        assert!(lines[5].ends_with("Destination Network: 65535 (global broadcast)"));
+        assert!(lines[7].ends_with("Hop Count: 255"));
+        assert!(lines[8].starts_with("000A  10 "));
+        assert!(lines[8].ends_with("APDU Type: Unconfirmed-Request"));
+        assert!(lines[9].ends_with("Service Choice: WhoIs (8)"));
+        assert!(lines[10].starts_with("000C  09 00 "));
+        assert!(lines[10].ends_with("Context Tag 0, 1 bytes = 0"));
+        assert!(lines[11].ends_with("Context Tag 1, 1 bytes = 123"));
+        assert_eq!(lines.len(), 12);
+        assert!(!breakdown.contains("Undecodable"));
+    }
+
+    #[test]
+    fn test_annotate_malformed_frames() {
+        let frame = [
+            0x81, 0x0A, 0x00, 0x11, 0x01, 0x04, 0x00, 0x05, 0x01, 0x0C, 0x0C, 0x00, 0x00, 0x00,
+            0x01, 0x19, 0x55,
+        ];
+        let breakdown = debug::annotate_packet(&frame);
+        assert!(breakdown.contains("Service Choice: ReadProperty (12)"));
+        assert!(breakdown.contains("Context Tag 1, 1 bytes = 85"));
+        assert!(!breakdown.contains("Undecodable"));
+
+        // No truncation panics
+        for len in 0..frame.len() {
+            debug::annotate_packet(&frame[..len]);
+        }
+
+        let breakdown = debug::annotate_packet(&frame[..3]);
+        assert!(breakdown.ends_with("Undecodable: BVLC Length truncated\n"));
+        let breakdown = debug::annotate_packet(&frame[..12]);
+        assert!(breakdown.contains("(frame is 12 bytes)"));
+        assert!(breakdown
+            .contains("000A  0C 00                      Undecodable: tag content truncated"));
+
+        // Without a BVLC header the frame starts at the NPDU
+        let breakdown = debug::annotate_packet(&[0x01, 0x00, 0x10, 0x08, 0x0D, 0x20, 0x01]);
+        assert!(breakdown.starts_with("0000  01 "));
+        assert!(breakdown.contains("Undecodable: tag content truncated"));
+    }
+
     #[test]
     fn test_annotated_hex_dump() {
         let data = &[0x01, 0x02, 0x03, 0x04];
@@ -35,6 +35,12 @@ pub struct VendorInfo {
     pub name: &'static str,
 }
 
+impl VendorInfo {
+    const fn new(id: u16, name: &'static str) -> Self {
+        Self { id, name }
+    }
+}
+
 impl fmt::Display for VendorInfo {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{} (ID: {})", self.name, self.id)
@@ -44,1595 +50,1614 @@ impl fmt::Display for VendorInfo {
 /// Official BACnet vendor IDs as assigned by ASHRAE
 /// Source: https://bacnet.org/assigned-vendor-ids/
 /// Last updated: 2024
-const BACNET_VENDORS: &[(u16, &str)] = &[
+static BACNET_VENDORS: &[VendorInfo] = &[
     // Core ASHRAE vendors
-    (0, "ASHRAE"),
-    (1, "NIST"),
-    (2, "The Trane Company"),
-    (3, "Daikin Applied Americas"),
-    (4, "PolarSoft"),
-    (5, "Johnson Controls, Inc."),
-    (6, "ABB (Formerly American Auto-Matrix)"),
-    (7, "Siemens Schweiz AG"),
-    (8, "Delta Controls"),
-    (9, "Siemens Schweiz AG"),
-    (10, "Schneider Electric"),
-    (11, "TAC"),
-    (12, "Orion Analysis Corporation"),
-    (13, "Teletrol Systems Inc."),
-    (14, "Cimetrics Technology"),
-    (15, "Cornell University"),
-    (16, "United Technologies Carrier"),
-    (17, "Honeywell Inc."),
-    (18, "Alerton / Honeywell"),
-    (19, "TAC AB"),
-    (20, "Hewlett-Packard Company"),
-    (21, "Dorsette's Inc."),
-    (22, "Siemens Schweiz AG"),
-    (23, "York Controls Group"),
-    (24, "Automated Logic Corporation"),
-    (25, "CSI Control Systems International"),
-    (26, "Phoenix Controls Corporation"),
-    (27, "Innovex Technologies, Inc."),
-    (28, "KMC Controls, Inc."),
-    (29, "Xn Technologies, Inc."),
-    (30, "Hyundai Information Technology Co., Ltd."),
-    (31, "Tokimec Inc."),
-    (32, "Simplex"),
-    (33, "North Building Technologies Limited"),
-    (34, "Notifier"),
-    (35, "Reliable Controls Corporation"),
-    (36, "Tridium Inc."),
-    (37, "MSA Safety"),
-    (38, "Silicon Energy"),
-    (39, "Kieback & Peter GmbH & Co KG"),
-    (40, "Anacon Systems, Inc."),
-    (41, "Systems Controls & Instruments, LLC"),
-    (42, "Acuity Brands Lighting, Inc."),
-    (43, "Micropower Manufacturing"),
-    (44, "Matrix Controls"),
-    (45, "METALAIRE"),
-    (46, "ESS Engineering"),
-    (47, "Sphere Systems Pty Ltd."),
-    (48, "Walker Technologies Corporation"),
-    (49, "H I Solutions, Inc."),
-    (50, "MBS GmbH"),
-    (51, "SAMSON AG"),
-    (52, "Badger Meter Inc."),
-    (53, "DAIKIN Industries Ltd."),
-    (54, "NARA Controls Inc."),
-    (55, "Mammoth Inc."),
-    (56, "Liebert Corporation"),
-    (57, "SEMCO Incorporated"),
-    (58, "Air Monitor Corporation"),
-    (59, "TRIATEK, LLC"),
-    (60, "NexLight"),
-    (61, "Multistack"),
-    (62, "TSI Incorporated"),
-    (63, "Weather-Rite, Inc."),
-    (64, "Dunham-Bush"),
-    (65, "Reliance Electric"),
-    (66, "LCS Inc."),
-    (67, "Regulator Australia PTY Ltd."),
-    (68, "Touch-Plate Lighting Controls"),
-    (69, "Amann GmbH"),
-    (70, "RLE Technologies"),
-    (71, "Cardkey Systems"),
-    (72, "SECOM Co., Ltd."),
-    (73, "ABB Gebäudetechnik AG Bereich NetServ"),
-    (74, "KNX Association cvba"),
-    (
+    VendorInfo::new(0, "ASHRAE"),
+    VendorInfo::new(1, "NIST"),
+    VendorInfo::new(2, "The Trane Company"),
+    VendorInfo::new(3, "Daikin Applied Americas"),
+    VendorInfo::new(4, "PolarSoft"),
+    VendorInfo::new(5, "Johnson Controls, Inc."),
+    VendorInfo::new(6, "ABB (Formerly American Auto-Matrix)"),
+    VendorInfo::new(7, "Siemens Schweiz AG"),
+    VendorInfo::new(8, "Delta Controls"),
+    VendorInfo::new(9, "Siemens Schweiz AG"),
+    VendorInfo::new(10, "Schneider Electric"),
+    VendorInfo::new(11, "TAC"),
+    VendorInfo::new(12, "Orion Analysis Corporation"),
+    VendorInfo::new(13, "Teletrol Systems Inc."),
+    VendorInfo::new(14, "Cimetrics Technology"),
+    VendorInfo::new(15, "Cornell University"),
+    VendorInfo::new(16, "United Technologies Carrier"),
+    VendorInfo::new(17, "Honeywell Inc."),
+    VendorInfo::new(18, "Alerton / Honeywell"),
+    VendorInfo::new(19, "TAC AB"),
+    VendorInfo::new(20, "Hewlett-Packard Company"),
+    VendorInfo::new(21, "Dorsette's Inc."),
+    VendorInfo::new(22, "Siemens Schweiz AG"),
+    VendorInfo::new(23, "York Controls Group"),
+    VendorInfo::new(24, "Automated Logic Corporation"),
+    VendorInfo::new(25, "CSI Control Systems International"),
+    VendorInfo::new(26, "Phoenix Controls Corporation"),
+    VendorInfo::new(27, "Innovex Technologies, Inc."),
+    VendorInfo::new(28, "KMC Controls, Inc."),
+    VendorInfo::new(29, "Xn Technologies, Inc."),
+    VendorInfo::new(30, "Hyundai Information Technology Co., Ltd."),
+    VendorInfo::new(31, "Tokimec Inc."),
+    VendorInfo::new(32, "Simplex"),
+    VendorInfo::new(33, "North Building Technologies Limited"),
+    VendorInfo::new(34, "Notifier"),
+    VendorInfo::new(35, "Reliable Controls Corporation"),
+    VendorInfo::new(36, "Tridium Inc."),
+    VendorInfo::new(37, "MSA Safety"),
+    VendorInfo::new(38, "Silicon Energy"),
+    VendorInfo::new(39, "Kieback & Peter GmbH & Co KG"),
+    VendorInfo::new(40, "Anacon Systems, Inc."),
+    VendorInfo::new(41, "Systems Controls & Instruments, LLC"),
+    VendorInfo::new(42, "Acuity Brands Lighting, Inc."),
+    VendorInfo::new(43, "Micropower Manufacturing"),
+    VendorInfo::new(44, "Matrix Controls"),
+    VendorInfo::new(45, "METALAIRE"),
+    VendorInfo::new(46, "ESS Engineering"),
+    VendorInfo::new(47, "Sphere Systems Pty Ltd."),
+    VendorInfo::new(48, "Walker Technologies Corporation"),
+    VendorInfo::new(49, "H I Solutions, Inc."),
+    VendorInfo::new(50, "MBS GmbH"),
+    VendorInfo::new(51, "SAMSON AG"),
+    VendorInfo::new(52, "Badger Meter Inc."),
+    VendorInfo::new(53, "DAIKIN Industries Ltd."),
+    VendorInfo::new(54, "NARA Controls Inc."),
+    VendorInfo::new(55, "Mammoth Inc."),
+    VendorInfo::new(56, "Liebert Corporation"),
+    VendorInfo::new(57, "SEMCO Incorporated"),
+    VendorInfo::new(58, "Air Monitor Corporation"),
+    VendorInfo::new(59, "TRIATEK, LLC"),
+    VendorInfo::new(60, "NexLight"),
+    VendorInfo::new(61, "Multistack"),
+    VendorInfo::new(62, "TSI Incorporated"),
+    VendorInfo::new(63, "Weather-Rite, Inc."),
+    VendorInfo::new(64, "Dunham-Bush"),
+    VendorInfo::new(65, "Reliance Electric"),
+    VendorInfo::new(66, "LCS Inc."),
+    VendorInfo::new(67, "Regulator Australia PTY Ltd."),
+    VendorInfo::new(68, "Touch-Plate Lighting Controls"),
+    VendorInfo::new(69, "Amann GmbH"),
+    VendorInfo::new(70, "RLE Technologies"),
+    VendorInfo::new(71, "Cardkey Systems"),
+    VendorInfo::new(72, "SECOM Co., Ltd."),
+    VendorInfo::new(73, "ABB Gebäudetechnik AG Bereich NetServ"),
+    VendorInfo::new(74, "KNX Association cvba"),
+    VendorInfo::new(
         75,
         "Institute of Electrical Installation Engineers of Japan (IEIEJ)",
     ),
-    (76, "Nohmi Bosai, Ltd."),
-    (77, "Carel Industries S.p.A."),
-    (78, "UTC Fire & Security España, S.L."),
-    (79, "Hochiki Corporation"),
-    (80, "Fr. Sauter AG"),
-    (81, "Matsushita Electric Works, Ltd."),
-    (82, "Mitsubishi Electric Corporation, Inazawa Works"),
-    (83, "Mitsubishi Heavy Industries, Ltd."),
-    (84, "Xylem, Inc."),
-    (85, "Yamatake Building Systems Co., Ltd."),
-    (86, "The Watt Stopper, Inc."),
-    (87, "Aichi Tokei Denki Co., Ltd."),
-    (88, "Activation Technologies, LLC"),
-    (89, "Saia-Burgess Controls, Ltd."),
-    (90, "Hitachi, Ltd."),
-    (91, "Novar Corp./Trend Control Systems Ltd."),
-    (92, "Mitsubishi Electric Lighting Corporation"),
-    (93, "Argus Control Systems, Ltd."),
-    (94, "Kyuki Corporation"),
-    (95, "Richards-Zeta Building Intelligence, Inc."),
-    (96, "Scientech R&D, Inc."),
-    (97, "VCI Controls, Inc."),
-    (98, "Toshiba Corporation"),
-    (
+    VendorInfo::new(76, "Nohmi Bosai, Ltd."),
+    VendorInfo::new(77, "Carel Industries S.p.A."),
+    VendorInfo::new(78, "UTC Fire & Security España, S.L."),
+    VendorInfo::new(79, "Hochiki Corporation"),
+    VendorInfo::new(80, "Fr. Sauter AG"),
+    VendorInfo::new(81, "Matsushita Electric Works, Ltd."),
+    VendorInfo::new(82, "Mitsubishi Electric Corporation, Inazawa Works"),
+    VendorInfo::new(83, "Mitsubishi Heavy Industries, Ltd."),
+    VendorInfo::new(84, "Xylem, Inc."),
+    VendorInfo::new(85, "Yamatake Building Systems Co., Ltd."),
+    VendorInfo::new(86, "The Watt Stopper, Inc."),
+    VendorInfo::new(87, "Aichi Tokei Denki Co., Ltd."),
+    VendorInfo::new(88, "Activation Technologies, LLC"),
+    VendorInfo::new(89, "Saia-Burgess Controls, Ltd."),
+    VendorInfo::new(90, "Hitachi, Ltd."),
+    VendorInfo::new(91, "Novar Corp./Trend Control Systems Ltd."),
+    VendorInfo::new(92, "Mitsubishi Electric Lighting Corporation"),
+    VendorInfo::new(93, "Argus Control Systems, Ltd."),
+    VendorInfo::new(94, "Kyuki Corporation"),
+    VendorInfo::new(95, "Richards-Zeta Building Intelligence, Inc."),
+    VendorInfo::new(96, "Scientech R&D, Inc."),
+    VendorInfo::new(97, "VCI Controls, Inc."),
+    VendorInfo::new(98, "Toshiba Corporation"),
+    VendorInfo::new(
         99,
         "Mitsubishi Electric Corporation Air Conditioning & Refrigeration Systems Works",
     ),
-    (100, "Custom Mechanical Equipment, LLC"),
-    (101, "ClimateMaster"),
-    (102, "ICP Panel-Tec, Inc."),
-    (103, "D-Tek Controls"),
-    (104, "NEC Engineering, Ltd."),
-    (105, "PRIVA BV"),
-    (106, "Meidensha Corporation"),
-    (107, "JCI Systems Integration Services"),
-    (108, "Freedom Corporation"),
-    (109, "Neuberger Gebäudeautomation GmbH"),
-    (110, "eZi Controls"),
-    (111, "Leviton Manufacturing"),
-    (112, "Fujitsu Limited"),
-    (113, "Vertiv (Formerly Emerson Network Power)"),
-    (114, "S. A. Armstrong, Ltd."),
-    (115, "Visonet AG"),
-    (116, "M&M Systems, Inc."),
-    (117, "Custom Software Engineering"),
-    (118, "Nittan Company, Limited"),
-    (119, "Elutions Inc. (Wizcon Systems SAS)"),
-    (120, "Pacom Systems Pty., Ltd."),
-    (121, "Unico, Inc."),
-    (122, "Ebtron, Inc."),
-    (123, "Scada Engine"),
-    (124, "Lenze Americas (Formerly: AC Technology Corporation)"),
-    (125, "Eagle Technology"),
-    (126, "Data Aire, Inc."),
-    (127, "ABB, Inc."),
-    (128, "Transbit Sp. z o. o."),
-    (129, "Carrier Japan Corporation"),
-    (130, "Shenzhen Junzhi Hi-Tech Co., Ltd."),
-    (131, "Tokai Soft"),
-    (132, "Blue Ridge Technologies"),
-    (133, "Veris Industries"),
-    (134, "Centaurus Prime"),
-    (135, "Sand Network Systems"),
-    (136, "Regulvar, Inc."),
-    (137, "AFDtek Division of Fastek International Inc."),
-    (138, "PowerCold Comfort Air Solutions, Inc."),
-    (139, "I Controls"),
-    (140, "Viconics Electronics, Inc."),
-    (141, "Yaskawa America, Inc."),
-    (142, "DEOS control systems GmbH"),
-    (143, "Digitale Mess- und Steuersysteme AG"),
-    (144, "Fujitsu General Limited"),
-    (145, "Project Engineering S.r.l."),
-    (146, "Sanyo Electric Co., Ltd."),
-    (147, "Integrated Information Systems, Inc."),
-    (148, "Temco Controls, Ltd."),
-    (149, "Airtek International Inc."),
-    (150, "Advantech Corporation"),
-    (151, "Titan Products, Ltd."),
-    (152, "Regel Partners"),
-    (153, "National Environmental Product"),
-    (154, "Unitec Corporation"),
-    (155, "Kanden Engineering Company"),
-    (156, "Messner Gebäudetechnik GmbH"),
-    (157, "Integrated.CH"),
-    (158, "Price Industries"),
-    (159, "SE-Elektronic GmbH"),
-    (160, "Rockwell Automation"),
-    (161, "Enflex Corp."),
-    (162, "ASI Controls"),
-    (163, "SysMik GmbH Dresden"),
-    (164, "HSC Regelungstechnik GmbH"),
-    (165, "Smart Temp Australia Pty. Ltd."),
-    (166, "Cooper Controls"),
-    (167, "Duksan Mecasys Co., Ltd."),
-    (168, "Fuji IT Co., Ltd."),
-    (169, "Vacon Plc"),
-    (170, "Leader Controls"),
-    (171, "ABB (Formerly Cylon Controls, Ltd)"),
-    (172, "Compas"),
-    (173, "Mitsubishi Electric Building Techno-Service Co., Ltd."),
-    (174, "Building Control Integrators"),
-    (175, "ITG Worldwide (M) Sdn Bhd"),
-    (176, "Lutron Electronics Co., Inc."),
-    (177, "Cooper-Atkins Corporation"),
-    (178, "LOYTEC Electronics GmbH"),
-    (179, "ProLon"),
-    (180, "Mega Controls Limited"),
-    (181, "Micro Control Systems, Inc."),
-    (182, "Kiyon, Inc."),
-    (183, "Dust Networks"),
-    (184, "Advanced Building Automation Systems"),
-    (185, "Hermos AG"),
-    (186, "CEZIM"),
-    (187, "Softing"),
-    (188, "Lynxspring, Inc."),
-    (189, "Schneider Toshiba Inverter Europe"),
-    (190, "Danfoss Drives A/S"),
-    (191, "Eaton Corporation"),
-    (192, "Matyca S.A."),
-    (193, "Botech AB"),
-    (194, "Noveo, Inc."),
-    (195, "AMEV"),
-    (196, "Yokogawa Electric Corporation"),
-    (197, "Bosch Building Automation GmbH"),
-    (198, "Exact Logic"),
-    (
+    VendorInfo::new(100, "Custom Mechanical Equipment, LLC"),
+    VendorInfo::new(101, "ClimateMaster"),
+    VendorInfo::new(102, "ICP Panel-Tec, Inc."),
+    VendorInfo::new(103, "D-Tek Controls"),
+    VendorInfo::new(104, "NEC Engineering, Ltd."),
+    VendorInfo::new(105, "PRIVA BV"),
+    VendorInfo::new(106, "Meidensha Corporation"),
+    VendorInfo::new(107, "JCI Systems Integration Services"),
+    VendorInfo::new(108, "Freedom Corporation"),
+    VendorInfo::new(109, "Neuberger Gebäudeautomation GmbH"),
+    VendorInfo::new(110, "eZi Controls"),
+    VendorInfo::new(111, "Leviton Manufacturing"),
+    VendorInfo::new(112, "Fujitsu Limited"),
+    VendorInfo::new(113, "Vertiv (Formerly Emerson Network Power)"),
+    VendorInfo::new(114, "S. A. Armstrong, Ltd."),
+    VendorInfo::new(115, "Visonet AG"),
+    VendorInfo::new(116, "M&M Systems, Inc."),
+    VendorInfo::new(117, "Custom Software Engineering"),
+    VendorInfo::new(118, "Nittan Company, Limited"),
+    VendorInfo::new(119, "Elutions Inc. (Wizcon Systems SAS)"),
+    VendorInfo::new(120, "Pacom Systems Pty., Ltd."),
+    VendorInfo::new(121, "Unico, Inc."),
+    VendorInfo::new(122, "Ebtron, Inc."),
+    VendorInfo::new(123, "Scada Engine"),
+    VendorInfo::new(124, "Lenze Americas (Formerly: AC Technology Corporation)"),
+    VendorInfo::new(125, "Eagle Technology"),
+    VendorInfo::new(126, "Data Aire, Inc."),
+    VendorInfo::new(127, "ABB, Inc."),
+    VendorInfo::new(128, "Transbit Sp. z o. o."),
+    VendorInfo::new(129, "Carrier Japan Corporation"),
+    VendorInfo::new(130, "Shenzhen Junzhi Hi-Tech Co., Ltd."),
+    VendorInfo::new(131, "Tokai Soft"),
+    VendorInfo::new(132, "Blue Ridge Technologies"),
+    VendorInfo::new(133, "Veris Industries"),
+    VendorInfo::new(134, "Centaurus Prime"),
+    VendorInfo::new(135, "Sand Network Systems"),
+    VendorInfo::new(136, "Regulvar, Inc."),
+    VendorInfo::new(137, "AFDtek Division of Fastek International Inc."),
+    VendorInfo::new(138, "PowerCold Comfort Air Solutions, Inc."),
+    VendorInfo::new(139, "I Controls"),
+    VendorInfo::new(140, "Viconics Electronics, Inc."),
+    VendorInfo::new(141, "Yaskawa America, Inc."),
+    VendorInfo::new(142, "DEOS control systems GmbH"),
+    VendorInfo::new(143, "Digitale Mess- und Steuersysteme AG"),
+    VendorInfo::new(144, "Fujitsu General Limited"),
+    VendorInfo::new(145, "Project Engineering S.r.l."),
+    VendorInfo::new(146, "Sanyo Electric Co., Ltd."),
+    VendorInfo::new(147, "Integrated Information Systems, Inc."),
+    VendorInfo::new(148, "Temco Controls, Ltd."),
+    VendorInfo::new(149, "Airtek International Inc."),
+    VendorInfo::new(150, "Advantech Corporation"),
+    VendorInfo::new(151, "Titan Products, Ltd."),
+    VendorInfo::new(152, "Regel Partners"),
+    VendorInfo::new(153, "National Environmental Product"),
+    VendorInfo::new(154, "Unitec Corporation"),
+    VendorInfo::new(155, "Kanden Engineering Company"),
+    VendorInfo::new(156, "Messner Gebäudetechnik GmbH"),
+    VendorInfo::new(157, "Integrated.CH"),
+    VendorInfo::new(158, "Price Industries"),
+    VendorInfo::new(159, "SE-Elektronic GmbH"),
+    VendorInfo::new(160, "Rockwell Automation"),
+    VendorInfo::new(161, "Enflex Corp."),
+    VendorInfo::new(162, "ASI Controls"),
+    VendorInfo::new(163, "SysMik GmbH Dresden"),
+    VendorInfo::new(164, "HSC Regelungstechnik GmbH"),
+    VendorInfo::new(165, "Smart Temp Australia Pty. Ltd."),
+    VendorInfo::new(166, "Cooper Controls"),
+    VendorInfo::new(167, "Duksan Mecasys Co., Ltd."),
+    VendorInfo::new(168, "Fuji IT Co., Ltd."),
+    VendorInfo::new(169, "Vacon Plc"),
+    VendorInfo::new(170, "Leader Controls"),
+    VendorInfo::new(171, "ABB (Formerly Cylon Controls, Ltd)"),
+    VendorInfo::new(172, "Compas"),
+    VendorInfo::new(173, "Mitsubishi Electric Building Techno-Service Co., Ltd."),
+    VendorInfo::new(174, "Building Control Integrators"),
+    VendorInfo::new(175, "ITG Worldwide (M) Sdn Bhd"),
+    VendorInfo::new(176, "Lutron Electronics Co., Inc."),
+    VendorInfo::new(177, "Cooper-Atkins Corporation"),
+    VendorInfo::new(178, "LOYTEC Electronics GmbH"),
+    VendorInfo::new(179, "ProLon"),
+    VendorInfo::new(180, "Mega Controls Limited"),
+    VendorInfo::new(181, "Micro Control Systems, Inc."),
+    VendorInfo::new(182, "Kiyon, Inc."),
+    VendorInfo::new(183, "Dust Networks"),
+    VendorInfo::new(184, "Advanced Building Automation Systems"),
+    VendorInfo::new(185, "Hermos AG"),
+    VendorInfo::new(186, "CEZIM"),
+    VendorInfo::new(187, "Softing"),
+    VendorInfo::new(188, "Lynxspring, Inc."),
+    VendorInfo::new(189, "Schneider Toshiba Inverter Europe"),
+    VendorInfo::new(190, "Danfoss Drives A/S"),
+    VendorInfo::new(191, "Eaton Corporation"),
+    VendorInfo::new(192, "Matyca S.A."),
+    VendorInfo::new(193, "Botech AB"),
+    VendorInfo::new(194, "Noveo, Inc."),
+    VendorInfo::new(195, "AMEV"),
+    VendorInfo::new(196, "Yokogawa Electric Corporation"),
+    VendorInfo::new(197, "Bosch Building Automation GmbH"),
+    VendorInfo::new(198, "Exact Logic"),
+    VendorInfo::new(
         199,
         "Mass Electronics Pty Ltd dba Innotech Control Systems Australia",
     ),
-    (200, "Kandenko Co., Ltd."),
-    (201, "DTF, Daten-Technik Fries"),
-    (202, "Klimasoft, Ltd."),
-    (203, "Toshiba Schneider Inverter Corporation"),
-    (204, "Control Applications, Ltd."),
-    (205, "CIMON CO., Ltd."),
-    (206, "Onicon Incorporated"),
-    (207, "Automation Displays, Inc."),
-    (208, "Control Solutions, Inc."),
-    (209, "Remsdaq Limited"),
-    (210, "NTT Facilities, Inc."),
-    (211, "VIPA GmbH"),
-    (212, "TSC21 Association of Japan"),
-    (213, "Strato Automation"),
-    (214, "HRW Limited"),
-    (215, "Lighting Control & Design, Inc."),
-    (216, "Mercy Electronic and Electrical Industries"),
-    (217, "Samsung SDS Co., Ltd"),
-    (218, "Impact Facility Solutions, Inc."),
-    (219, "Aircuity"),
-    (220, "Control Techniques, Ltd."),
-    (221, "OpenGeneral Pty., Ltd."),
-    (222, "WAGO Kontakttechnik GmbH & Co. KG"),
-    (223, "Franklin Electric"),
-    (224, "Chloride Power Protection Company"),
-    (225, "Computrols, Inc."),
-    (226, "Phoenix Contact GmbH & Co. KG"),
-    (227, "Grundfos Management A/S"),
-    (228, "Ridder Drive Systems"),
-    (229, "Soft Device SDN BHD"),
-    (230, "Integrated Control Technology Limited"),
-    (231, "AIRxpert Systems, Inc."),
-    (232, "Microtrol Limited"),
-    (233, "Red Lion Controls"),
-    (234, "Digital Electronics Corporation"),
-    (235, "Ennovatis GmbH"),
-    (236, "Serotonin Software Technologies, Inc."),
-    (237, "LS Industrial Systems Co., Ltd."),
-    (238, "Square D Company"),
-    (239, "S Squared Innovations, Inc."),
-    (240, "Aricent Ltd."),
-    (241, "EtherMetrics, LLC"),
-    (242, "Industrial Control Communications, Inc."),
-    (243, "Paragon Controls, Inc."),
-    (244, "A. O. Smith Corporation"),
-    (245, "Contemporary Control Systems, Inc."),
-    (246, "HMS Industrial Networks SLU"),
-    (247, "Ingenieurgesellschaft N. Hartleb mbH"),
-    (248, "Heat-Timer Corporation"),
-    (249, "Ingrasys Technology, Inc."),
-    (250, "Costerm Building Automation"),
-    (251, "WILO SE"),
-    (252, "Embedia Technologies Corp."),
-    (253, "Technilog"),
-    (254, "HR Controls Ltd. & Co. KG"),
-    (255, "Lennox International, Inc."),
-    (256, "RK-Tec Rauchklappen-Steuerungssysteme GmbH & Co. KG"),
-    (257, "Thermomax, Ltd."),
-    (258, "ELCON Electronic Control, Ltd."),
-    (259, "Larmia Control AB"),
-    (260, "BACnet Stack at SourceForge"),
-    (261, "G4S Security Services A/S"),
-    (262, "Exor International S.p.A."),
-    (263, "Cristal Controles"),
-    (264, "Regin AB"),
-    (265, "Dimension Software, Inc."),
-    (266, "SynapSense Corporation"),
-    (267, "Beijing Nantree Electronic Co., Ltd."),
-    (268, "Camus Hydronics Ltd."),
-    (269, "Kawasaki Heavy Industries, Ltd."),
-    (270, "Critical Environment Technologies"),
-    (271, "ILSHIN IBS Co., Ltd."),
-    (272, "ELESTA Energy Control AG"),
-    (273, "KROPMAN Installatietechniek"),
-    (274, "Baldor Electric Company"),
-    (275, "INGA mbH"),
-    (276, "GE Consumer & Industrial"),
-    (277, "Functional Devices, Inc."),
-    (278, "StudioSC"),
-    (279, "MG CO., LTD."),
-    (280, "Yokota Co., Ltd."),
-    (281, "Hitranse Technology Co., LTD"),
-    (282, "Vigilent Corporation"),
-    (283, "Kele, Inc."),
-    (284, "BELIMO Automation AG"),
-    (285, "Gentec"),
-    (286, "Embedded Science Labs, LLC"),
-    (287, "Parker Hannifin Corporation"),
-    (288, "MaCaPS International Limited"),
-    (289, "Link4 Corporation"),
-    (290, "Romutec Steuer-u. Regelsysteme GmbH"),
-    (291, "Pribusin, Inc."),
-    (292, "Advantage Controls"),
-    (293, "Critical Room Control"),
-    (294, "LEGRAND"),
-    (295, "Tongdy Control Technology Co., Ltd."),
-    (296, "ISSARO Integrierte Systemtechnik"),
-    (297, "Pro-Dev Industries"),
-    (298, "DRI-STEEM"),
-    (299, "Creative Electronic GmbH"),
-    (300, "Swegon AB"),
-    (301, "FIRVENA s.r.o."),
-    (302, "Hitachi Appliances, Inc."),
-    (303, "Real Time Automation, Inc."),
-    (304, "ITEC Hankyu-Hanshin Co."),
-    (305, "Cyrus E&M Engineering Co., Ltd."),
-    (306, "Badger Meter"),
-    (307, "Cirrascale Corporation"),
-    (308, "Elesta GmbH Building Automation"),
-    (309, "Securiton"),
-    (310, "OSlsoft, Inc."),
-    (311, "Hanazeder Electronic GmbH"),
-    (312, "Honeywell Security Deutschland, Novar GmbH"),
-    (313, "Siemens Industry, Inc."),
-    (314, "ETM Professional Control GmbH"),
-    (315, "Meitav-tec, Ltd."),
-    (316, "Janitza Electronics GmbH"),
-    (317, "MKS Nordhausen"),
-    (318, "De Gier Drive Systems B.V."),
-    (319, "Cypress Envirosystems"),
-    (320, "SMARTron s.r.o."),
-    (321, "Verari Systems, Inc."),
-    (322, "K-W Electronic Service, Inc."),
-    (323, "ALFA-SMART Energy Management"),
-    (324, "Telkonet, Inc."),
-    (325, "Securiton GmbH"),
-    (326, "Cemtrex, Inc."),
-    (327, "Performance Technologies, Inc."),
-    (328, "Xtralis (Aust) Pty Ltd"),
-    (329, "TROX GmbH"),
-    (330, "Beijing Hysine Technology Co., Ltd"),
-    (331, "RCK Controls, Inc."),
-    (332, "Distech Controls SAS"),
-    (333, "Novar/Honeywell"),
-    (334, "S4 Integration Solutions"),
-    (335, "Schneider Electric"),
-    (336, "LHA Systems"),
-    (337, "GHM engineering Group, Inc."),
-    (338, "Cllimalux S.A."),
-    (339, "VAISALA Oyj"),
-    (340, "COMPLEX (Beijing) Technology, Co., LTD."),
-    (341, "SCADAmetrics"),
-    (342, "POWERPEG NSI Limited"),
-    (343, "BACnet Interoperability Testing Services, Inc."),
-    (344, "Teco a.s."),
-    (345, "Plexus Technology, Inc."),
-    (346, "Energy Focus, Inc."),
-    (347, "Powersmiths International Corp."),
-    (348, "Nichibei Co., Ltd."),
-    (349, "HKC Technology Ltd."),
-    (350, "Ovation Networks, Inc."),
-    (351, "Setra Systems"),
-    (352, "AVG Automation"),
-    (353, "ZXC Ltd."),
-    (354, "Byte Sphere"),
-    (355, "Generiton Co., Ltd."),
-    (356, "Holter Regelarmaturen GmbH & Co. KG"),
-    (357, "Bedford Instruments, LLC"),
-    (358, "Standair Inc."),
-    (359, "WEG Automation – R&D"),
-    (360, "Prolon Control Systems ApS"),
-    (361, "Inneasoft"),
-    (362, "ConneXSoft GmbH"),
-    (363, "CEAG Notlichtsysteme GmbH"),
-    (364, "Distech Controls Inc."),
-    (365, "Industrial Technology Research Institute"),
-    (366, "ICONICS, Inc."),
-    (367, "IQ Controls s.c."),
-    (368, "OJ Electronics A/S"),
-    (369, "Rolbit Ltd."),
-    (370, "Synapsys Solutions Ltd."),
-    (371, "ACME Engineering Prod. Ltd."),
-    (372, "Zener Electric Pty, Ltd."),
-    (373, "Selectronix, Inc."),
-    (374, "Gorbet & Banerjee, LLC."),
-    (375, "IME"),
-    (376, "Stephen H. Dawson Computer Service"),
-    (377, "Accutrol, LLC"),
-    (378, "Schneider Elektronik GmbH"),
-    (379, "Alpha-Inno Tec GmbH"),
-    (380, "ADMMicro, Inc."),
-    (381, "Greystone Energy Systems, Inc."),
-    (382, "CAP Technologie"),
-    (383, "KeRo Systems"),
-    (384, "Domat Control System s.r.o."),
-    (385, "Efektronics Pty. Ltd."),
-    (386, "Hekatron Vertriebs GmbH"),
-    (387, "Securiton AG"),
-    (388, "Carlo Gavazzi Controls SpA"),
-    (389, "Chipkin Automation Systems"),
-    (390, "Savant Systems, LLC"),
-    (391, "Simmtronic Lighting Controls"),
-    (392, "Abelko Innovation AB"),
-    (393, "Seresco Technologies Inc."),
-    (394, "IT Watchdogs"),
-    (395, "Automation Assist Japan Corp."),
-    (396, "Thermokon Sensortechnik GmbH"),
-    (397, "EGauge Systems, LLC"),
-    (398, "Quantum Automation (ASIA) PTE, Ltd."),
-    (399, "Toshiba Lighting & Technology Corp."),
-    (400, "SPIN Engenharia de Automação Ltda."),
-    (401, "Logistics Systems & Software Services India PVT. Ltd."),
-    (402, "Delta Controls Integration Products"),
-    (403, "Focus Media"),
-    (404, "LUMEnergi Inc."),
-    (405, "Kara Systems"),
-    (406, "RF Code, Inc."),
-    (407, "Fatek Automation Corp."),
-    (408, "JANDA Software Company, LLC"),
-    (409, "Open System Solutions Limited"),
-    (410, "Intelec Systems PTY Ltd."),
-    (411, "Ecolodgix, LLC"),
-    (412, "Douglas Lighting Controls"),
-    (413, "iSAtech GmbH"),
-    (414, "AREAL"),
-    (415, "Beckhoff Automation"),
-    (416, "IPAS GmbH"),
-    (417, "KE2 Therm Solutions"),
-    (418, "Base2Products"),
-    (419, "DTL Controls, LLC"),
-    (420, "INNCOM International, Inc."),
-    (421, "METZ CONNECT GmbH"),
-    (422, "Greentrol Automation, Inc"),
-    (423, "BELIMO Automation AG"),
-    (424, "Samsung Heavy Industries Co, Ltd"),
-    (425, "Triacta Power Technologies, Inc."),
-    (426, "Globestar Systems"),
-    (427, "MLB Advanced Media, LP"),
-    (428, "SWG Stuckmann Wirtschaftliche Gebäudesysteme GmbH"),
-    (429, "SensorSwitch"),
-    (430, "Multitek Power Limited"),
-    (431, "Integra Metering AG"),
-    (432, "LG Electronics Inc."),
-    (433, "Electronic Theatre Controls, Inc."),
-    (434, "Mitsubishi Electric Corporation Nagoya Works"),
-    (435, "Delta Electronics, Inc."),
-    (436, "Elma Kurtalj, Ltd."),
-    (437, "Tyco Fire & Security GmbH"),
-    (438, "Nedap Security Management"),
-    (439, "ESC Automation Inc."),
-    (440, "DSP4YOU Ltd."),
-    (441, "GE Sensing and Inspection Technologies"),
-    (442, "Embedded Systems SIA"),
-    (443, "BEFEGA GmbH"),
-    (444, "Baseline Inc."),
-    (445, "Key2Act"),
-    (446, "OEMCtrl"),
-    (447, "Clarkson Controls Limited"),
-    (448, "Rogerwell Control System Limited"),
-    (449, "SCL Elements"),
-    (450, "Hitachi Ltd."),
-    (451, "Newron System SA"),
-    (452, "BEVECO Gebouwautomatisering BV"),
-    (453, "Streamside Solutions"),
-    (454, "Yellowstone Soft"),
-    (455, "Oztech Intelligent Systems Pty Ltd."),
-    (456, "Novelan GmbH"),
-    (457, "Flexim Americas Corporation"),
-    (458, "ICP DAS Co., Ltd."),
-    (459, "CARMA Industries Inc."),
-    (460, "Log-One Ltd."),
-    (461, "TECO Electric & Machinery Co., Ltd."),
-    (462, "ConnectEx, Inc."),
-    (463, "Turbo DDC Südwest"),
-    (464, "Quatrosense Environmental Ltd."),
-    (465, "Fifth Light Technology Ltd."),
-    (466, "Scientific Solutions, Ltd."),
-    (467, "Controller Area Network Solutions (M) Sdn Bhd"),
-    (468, "RESOL – Elektronische Regelungen GmbH"),
-    (469, "RPBUS LLC"),
-    (470, "BRS Sistemas Eletronicos"),
-    (471, "WindowMaster A/S"),
-    (472, "Sunlux Technologies Ltd."),
-    (473, "Measurlogic"),
-    (474, "Frimat GmbH"),
-    (475, "Spirax Sarco"),
-    (476, "Luxtron"),
-    (477, "Raypak Inc"),
-    (478, "Air Monitor Corporation"),
-    (479, "Regler Och Webbteknik Sverige (ROWS)"),
-    (480, "Intelligent Lighting Controls Inc."),
-    (481, "Sanyo Electric Industry Co., Ltd"),
-    (482, "E-Mon Energy Monitoring Products"),
-    (483, "Digital Control Systems"),
-    (484, "ATI Airtest Technologies, Inc."),
-    (485, "SCS SA"),
-    (486, "HMS Industrial Networks AB"),
-    (487, "Shenzhen Universal Intellisys Co Ltd"),
-    (488, "EK Intellisys Sdn Bhd"),
-    (489, "SysCom"),
-    (490, "Firecom, Inc."),
-    (491, "ESA Elektroschaltanlagen Grimma GmbH"),
-    (492, "Kumahira Co Ltd"),
-    (493, "Hotraco"),
-    (494, "SABO Elektronik GmbH"),
-    (495, "Equip'Trans"),
-    (496, "Temperature Control Specialities Co., Inc (TCS)"),
-    (497, "FlowCon International A/S"),
-    (498, "ThyssenKrupp Elevator Americas"),
-    (499, "Abatement Technologies"),
-    (500, "Continental Control Systems, LLC"),
-    (501, "WISAG Automatisierungstechnik GmbH & Co KG"),
-    (502, "EasyIO"),
-    (503, "EAP-Electric GmbH"),
-    (504, "Hardmeier"),
-    (505, "Mircom Group of Companies"),
-    (506, "Quest Controls"),
-    (507, "Mestek, Inc"),
-    (508, "Pulse Energy"),
-    (509, "Tachikawa Corporation"),
-    (510, "University of Nebraska-Lincoln"),
-    (511, "Redwood Systems"),
-    (512, "PASStec Industrie-Elektronik GmbH"),
-    (513, "NgEK, Inc."),
-    (514, "t-mac Technologies"),
-    (515, "Jireh Energy Tech Co., Ltd."),
-    (516, "Enlighted Inc."),
-    (517, "El-Piast Sp. Z o.o"),
-    (518, "NetxAutomation Software GmbH"),
-    (519, "Invertek Drives"),
-    (520, "Deutschmann Automation GmbH & Co. KG"),
-    (521, "EMU Electronic AG"),
-    (522, "Phaedrus Limited"),
-    (523, "Sigmatek GmbH & Co KG"),
-    (524, "Marlin Controls"),
-    (525, "Circutor, SA"),
-    (526, "UTC Fire & Security"),
-    (527, "DENT Instruments, Inc."),
-    (528, "FHP Manufacturing Company – Bosch Group"),
-    (529, "GE Intelligent Platforms"),
-    (530, "Inner Range Pty Ltd"),
-    (531, "GLAS Energy Technology"),
-    (532, "MSR-Electronic-GmbH"),
-    (533, "Energy Control Systems, Inc."),
-    (534, "EMT Controls"),
-    (535, "Daintree"),
-    (536, "EURO ICC d.o.o"),
-    (537, "TE Connectivity Energy"),
-    (538, "GEZE GmbH"),
-    (539, "NEC Corporation"),
-    (540, "Ho Cheung International Company Limited"),
-    (541, "Sharp Manufacturing Systems Corporation"),
-    (542, "DOT CONTROLS a.s."),
-    (543, "BeaconMedæs"),
-    (544, "Midea Commercial Aircon"),
-    (545, "AAON"),
-    (546, "Kamstrup A/S"),
-    (547, "CA Computer Automation GmbH"),
-    (548, "Laars Heating Systems Company"),
-    (549, "Hitachi Systems, Ltd."),
-    (550, "Fushan AKE Electronic Engineering Co., Ltd."),
-    (551, "Toshiba International Corporation"),
-    (552, "Starman Systems, LLC"),
-    (553, "Samsung Techwin Co., Ltd."),
-    (554, "ISAS-Integrated Switchgear and Systems P/L"),
-    (555, "Reserved for ASHRAE"),
-    (556, "Obvius"),
-    (557, "Marek Guzik"),
-    (558, "Vortek Instruments, LLC"),
-    (559, "Universal Lighting Technologies"),
-    (560, "Myers Power Products, Inc."),
-    (561, "Vector Controls GmbH"),
-    (562, "Crestron Electronics, Inc."),
-    (563, "A&E Controls Limited"),
-    (564, "Projektomontaza A.D."),
-    (565, "Freeaire Refrigeration"),
-    (566, "Aqua Cooler Pty Limited"),
-    (567, "Basic Controls"),
-    (568, "GE Measurement and Control Solutions Advanced Sensors"),
-    (569, "EQUAL Networks"),
-    (570, "Millennial Net"),
-    (571, "APLI Ltd"),
-    (572, "Electro Industries/GaugeTech"),
-    (573, "SangMyung University"),
-    (574, "Coppertree Analytics, Inc."),
-    (575, "CoreNetiX GmbH"),
-    (576, "Acutherm"),
-    (577, "Dr. Riedel Automatisierungstechnik GmbH"),
-    (578, "Shina System Co., Ltd"),
-    (579, "Iqapertus"),
-    (580, "PSE Technology"),
-    (581, "BA Systems"),
-    (582, "BTICINO"),
-    (583, "Monico, Inc."),
-    (584, "iCue"),
-    (585, "tekmar Control Systems Ltd."),
-    (586, "Control Technology Corporation"),
-    (587, "GFAE GmbH"),
-    (588, "BeKa Software GmbH"),
-    (589, "Isoil Industria SpA"),
-    (590, "Home Systems Consulting SpA"),
-    (591, "Socomec"),
-    (592, "Everex Communications, Inc."),
-    (593, "CET Electric Technology, Inc."),
-    (594, "Atrila GmbH"),
-    (595, "WingTechs"),
-    (596, "Shenzhen Mek Intellisys Pte Ltd."),
-    (597, "Nestfield Co., Ltd."),
-    (598, "Swissphone Telecom AG"),
-    (599, "PNTECH JSC"),
-    (600, "Horner APG, LLC"),
-    (601, "PVI Industries, LLC"),
-    (602, "Ela-compil"),
-    (603, "Pegasus Automation International LLC"),
-    (604, "Wight Electronic Services Ltd."),
-    (605, "Marcom"),
-    (606, "Exhausto A/S"),
-    (607, "Dwyer Instruments, Inc."),
-    (608, "Link GmbH"),
-    (609, "Oppermann Regelgerate GmbH"),
-    (610, "NuAire, Inc."),
-    (611, "Nortec Humidity, Inc."),
-    (612, "Bigwood Systems, Inc."),
-    (613, "Enbala Power Networks"),
-    (614, "Inter Energy Co., Ltd."),
-    (615, "ETC"),
-    (616, "COMELEC S.A.R.L"),
-    (617, "Pythia Technologies"),
-    (618, "TrendPoint Systems, Inc."),
-    (619, "AWEX"),
-    (620, "Eurevia"),
-    (621, "Kongsberg E-lon AS"),
-    (622, "FlaktWoods"),
-    (623, "E + E Elektronik GES M.B.H."),
-    (624, "ARC Informatique"),
-    (625, "SKIDATA AG"),
-    (626, "WSW Solutions"),
-    (627, "Trefon Electronic GmbH"),
-    (628, "Dongseo System"),
-    (629, "Kanontec Intelligence Technology Co., Ltd."),
-    (630, "EVCO S.p.A."),
-    (631, "Accuenergy (Canada) Inc."),
-    (632, "SoftDEL"),
-    (633, "Orion Energy Systems, Inc."),
-    (634, "Roboticsware"),
-    (635, "DOMIQ Sp. z o.o."),
-    (636, "Solidyne"),
-    (637, "Elecsys Corporation"),
-    (638, "Conditionaire International Pty. Limited"),
-    (639, "Quebec, Inc."),
-    (640, "Homerun Holdings"),
-    (641, "Murata Americas"),
-    (642, "Comptek"),
-    (643, "Westco Systems, Inc."),
-    (644, "Advancis Software & Services GmbH"),
-    (645, "Intergrid, LLC"),
-    (646, "Markerr Controls, Inc."),
-    (647, "Toshiba Elevator and Building Systems Corporation"),
-    (648, "Spectrum Controls, Inc."),
-    (649, "Mkservice"),
-    (650, "Fox Thermal Instruments"),
-    (651, "SyxthSense Ltd"),
-    (652, "DUHA System S R.O."),
-    (653, "NIBE"),
-    (654, "Melink Corporation"),
-    (655, "Fritz-Haber-Institut"),
-    (656, "MTU Onsite Energy GmbH, Gas Power Systems"),
-    (657, "Omega Engineering, Inc."),
-    (658, "Avelon"),
-    (659, "Ywire Technologies, Inc."),
-    (660, "M.R. Engineering Co., Ltd."),
-    (661, "Lochinvar, LLC"),
-    (662, "Sontay Limited"),
-    (663, "GRUPA Slawomir Chelminski"),
-    (664, "Arch Meter Corporation"),
-    (665, "Senva, Inc."),
-    (666, "Reserved for ASHRAE"),
-    (667, "FM-Tec"),
-    (668, "Systems Specialists, Inc."),
-    (669, "SenseAir"),
-    (670, "AB IndustrieTechnik Srl"),
-    (671, "Cortland Research, LLC"),
-    (672, "MediaView"),
-    (673, "VDA Elettronica"),
-    (674, "CSS, Inc."),
-    (675, "Tek-Air Systems, Inc."),
-    (676, "ICDT"),
-    (677, "The Armstrong Monitoring Corporation"),
-    (678, "DIXELL S.r.l"),
-    (679, "Lead System, Inc."),
-    (680, "ISM EuroCenter S.A."),
-    (681, "TDIS"),
-    (682, "Trade FIDES"),
-    (683, "Knürr GmbH (Emerson Network Power)"),
-    (684, "Resource Data Management"),
-    (685, "Abies Technology, Inc."),
-    (686, "UAB Komfovent"),
-    (687, "MIRAE Electrical Mfg. Co., Ltd."),
-    (688, "HunterDouglas Architectural Projects Scandinavia ApS"),
-    (689, "RUNPAQ Group Co., Ltd"),
-    (690, "Unicard SA"),
-    (691, "IE Technologies"),
-    (692, "Ruskin Manufacturing"),
-    (693, "Calon Associates Limited"),
-    (694, "Contec Co., Ltd."),
-    (695, "iT GmbH"),
-    (696, "Autani Corporation"),
-    (697, "Christian Fortin"),
-    (698, "HDL"),
-    (699, "IPID Sp. Z.O.O Limited"),
-    (700, "Fuji Electric Co., Ltd"),
-    (701, "View, Inc."),
-    (702, "Samsung S1 Corporation"),
-    (703, "New Lift"),
-    (704, "VRT Systems"),
-    (705, "Motion Control Engineering, Inc."),
-    (706, "Weiss Klimatechnik GmbH"),
-    (707, "Elkon"),
-    (708, "Eliwell Controls S.r.l."),
-    (709, "Japan Computer Technos Corp"),
-    (710, "Rational Network ehf"),
-    (711, "Magnum Energy Solutions, LLC"),
-    (712, "MelRok"),
-    (713, "VAE Group"),
-    (714, "LGCNS"),
-    (715, "Berghof Automationstechnik GmbH"),
-    (716, "Quark Communications, Inc."),
-    (717, "Sontex"),
-    (718, "mivune AG"),
-    (719, "Panduit"),
-    (720, "Smart Controls, LLC"),
-    (721, "Compu-Aire, Inc."),
-    (722, "Sierra"),
-    (723, "ProtoSense Technologies"),
-    (724, "Eltrac Technologies Pvt Ltd"),
-    (725, "Bektas Invisible Controls GmbH"),
-    (726, "Entelec"),
-    (727, "INNEXIV"),
-    (728, "Covenant"),
-    (729, "Davitor AB"),
-    (730, "TongFang Technovator"),
-    (731, "Building Robotics, Inc."),
-    (732, "HSS-MSR UG"),
-    (733, "FramTack LLC"),
-    (734, "B. L. Acoustics, Ltd."),
-    (735, "Traxxon Rock Drills, Ltd"),
-    (736, "Franke"),
-    (737, "Wurm GmbH & Co"),
-    (738, "AddENERGIE"),
-    (739, "Mirle Automation Corporation"),
-    (740, "Ibis Networks"),
-    (741, "ID-KARTA s.r.o."),
-    (742, "Anaren, Inc."),
-    (743, "Span, Incorporated"),
-    (744, "Bosch Thermotechnology Corp"),
-    (745, "DRC Technology S.A."),
-    (746, "Shanghai Energy Building Technology Co, Ltd"),
-    (747, "Fraport AG"),
-    (748, "Flowgroup"),
-    (749, "Skytron Energy, GmbH"),
-    (750, "ALTEL Wicha, Golda Sp. J."),
-    (751, "Drupal"),
-    (752, "Axiomatic Technology, Ltd"),
-    (753, "Bohnke + Partner"),
-    (754, "Function1"),
-    (755, "Optergy Pty, Ltd"),
-    (756, "LSI Virticus"),
-    (757, "Konzeptpark GmbH"),
-    (758, "NX Lighting Controls"),
-    (759, "eCurv, Inc."),
-    (760, "Agnosys GmbH"),
-    (761, "Shanghai Sunfull Automation Co., LTD"),
-    (762, "Kurz Instruments, Inc."),
-    (763, "Cias Elettronica S.r.l."),
-    (764, "Multiaqua, Inc."),
-    (765, "BlueBox"),
-    (766, "Sensidyne"),
-    (767, "Viessmann Elektronik GmbH"),
-    (768, "ADFweb.com srl"),
-    (769, "Gaylord Industries"),
-    (770, "Majur Ltd."),
-    (771, "Shanghai Huilin Technology Co., Ltd."),
-    (772, "Exotronic"),
-    (773, "SAFECONTROL s.r.o."),
-    (774, "Amatis"),
-    (775, "Universal Electric Corporation"),
-    (776, "iBACnet"),
-    (777, "Reserved for ASHRAE"),
-    (778, "Smartrise Engineering, Inc."),
-    (779, "Miratron, Inc."),
-    (780, "SmartEdge"),
-    (781, "Mitsubishi Electric Australia Pty Ltd"),
-    (782, "Triangle Research International Ptd Ltd"),
-    (783, "Produal Oy"),
-    (784, "Milestone Systems A/S"),
-    (785, "Trustbridge"),
-    (786, "Feedback Solutions"),
-    (787, "IES"),
-    (788, "ABB Power Protection SA"),
-    (789, "Riptide IO"),
-    (790, "Messerschmitt Systems AG"),
-    (791, "Dezem Energy Controlling"),
-    (792, "MechoSystems"),
-    (793, "evon GmbH"),
-    (794, "CS Lab GmbH"),
-    (795, "8760 Enterprises, Inc."),
-    (796, "Touche Controls"),
-    (797, "Ontrol Teknik Malzeme San. ve Tic. A.S."),
-    (798, "Uni Control System Sp. Z o.o."),
-    (799, "Weihai Ploumeter Co., Ltd"),
-    (800, "Elcom International Pvt. Ltd"),
-    (801, "Signify"),
-    (802, "AutomationDirect"),
-    (803, "Paragon Robotics"),
-    (804, "SMT System & Modules Technology AG"),
-    (805, "Radix IoT LLC"),
-    (806, "CMR Controls Ltd"),
-    (807, "Innovari, Inc."),
-    (808, "ABB Control Products"),
-    (809, "Gesellschaft fur Gebäudeautomation mbH"),
-    (810, "RODI Systems Corp."),
-    (811, "Nextek Power Systems"),
-    (812, "Creative Lighting"),
-    (813, "WaterFurnace International"),
-    (814, "Mercury Security"),
-    (815, "Hisense (Shandong) Air-Conditioning Co., Ltd."),
-    (816, "Layered Solutions, Inc."),
-    (817, "Leegood Automatic System, Inc."),
-    (818, "Shanghai Restar Technology Co., Ltd."),
-    (819, "Reimann Ingenieurbüro"),
-    (820, "LynTec"),
-    (821, "HTP"),
-    (822, "Elkor Technologies, Inc."),
-    (823, "Bentrol Pty Ltd"),
-    (824, "Team-Control Oy"),
-    (825, "NextDevice, LLC"),
-    (826, "iSMA CONTROLLI S.p.a."),
-    (827, "King I Electronics Co., Ltd"),
-    (828, "SAMDAV"),
-    (829, "Next Gen Industries Pvt. Ltd."),
-    (830, "Entic LLC"),
-    (831, "ETAP"),
-    (832, "Moralle Electronics Limited"),
-    (833, "Leicom AG"),
-    (834, "Watts Regulator Company"),
-    (835, "S.C. Orbtronics S.R.L."),
-    (836, "Gaussan Technologies"),
-    (837, "WEBfactory GmbH"),
-    (838, "Ocean Controls"),
-    (839, "Messana Air-Ray Conditioning s.r.l."),
-    (840, "Hangzhou BATOWN Technology Co. Ltd."),
-    (841, "Reasonable Controls"),
-    (842, "Servisys, Inc."),
-    (843, "halstrup-walcher GmbH"),
-    (844, "SWG Automation Fuzhou Limited"),
-    (845, "KSB Aktiengesellschaft"),
-    (846, "Hybryd Sp. z o.o."),
-    (847, "Helvatron AG"),
-    (848, "Oderon Sp. Z.O.O."),
-    (849, "mikolab"),
-    (850, "Exodraft"),
-    (851, "Hochhuth GmbH"),
-    (852, "Integrated System Technologies Ltd."),
-    (853, "Shanghai Cellcons Controls Co., Ltd"),
-    (854, "Emme Controls, LLC"),
-    (855, "Field Diagnostic Services, Inc."),
-    (856, "Ges Teknik A.S."),
-    (857, "Global Power Products, Inc."),
-    (858, "Option NV"),
-    (859, "BV-Control AG"),
-    (860, "Sigren Engineering AG"),
-    (861, "Shanghai Jaltone Technology Co., Ltd."),
-    (862, "MaxLine Solutions Ltd"),
-    (863, "Kron Instrumentos Elétricos Ltda"),
-    (864, "Thermo Matrix"),
-    (865, "Infinite Automation Systems, Inc."),
-    (866, "Vantage"),
-    (867, "Elecon Measurements Pvt Ltd"),
-    (868, "TBA"),
-    (869, "Carnes Company"),
-    (870, "Harman Professional"),
-    (871, "Nenutec Asia Pacific Pte Ltd"),
-    (872, "Gia NV"),
-    (873, "Kepware Tehnologies"),
-    (874, "Temperature Electronics Ltd"),
-    (875, "Packet Power"),
-    (876, "Project Haystack Corporation"),
-    (877, "DEOS Controls Americas Inc."),
-    (878, "Senseware Inc"),
-    (879, "MST Systemtechnik AG"),
-    (880, "Lonix Ltd"),
-    (881, "Gossen Metrawatt GmbH"),
-    (882, "Aviosys International Inc."),
-    (883, "Efficient Building Automation Corp."),
-    (884, "Accutron Instruments Inc."),
-    (885, "Vermont Energy Control Systems LLC"),
-    (886, "DCC Dynamics"),
-    (887, "B.E.G. Brück Electronic GmbH"),
-    (888, "Reserved for ASHRAE"),
-    (889, "NGBS Hungary Ltd."),
-    (890, "ILLUM Technology, LLC"),
-    (891, "Delta Controls Germany Limited"),
-    (892, "S+T Service & Technique S.A."),
-    (893, "SimpleSoft"),
-    (894, "Altair Engineering"),
-    (895, "EZEN Solution Inc."),
-    (896, "Fujitec Co. Ltd."),
-    (897, "Terralux"),
-    (898, "Annicom"),
-    (899, "Bihl+Wiedemann GmbH"),
-    (900, "Draper, Inc."),
-    (901, "Schüco International KG"),
-    (902, "Otis Elevator Company"),
-    (903, "Fidelix Oy"),
-    (904, "RAM GmbH Mess- und Regeltechnik"),
-    (905, "WEMS"),
-    (906, "Ravel Electronics Pvt Ltd"),
-    (907, "OmniMagni"),
-    (908, "Echelon"),
-    (909, "Intellimeter Canada, Inc."),
-    (910, "Bithouse Oy"),
-    (911, "Reserved for ASHRAE"),
-    (912, "BuildPulse"),
-    (913, "Shenzhen 1000 Building Automation Co. Ltd"),
-    (914, "AED Engineering GmbH"),
-    (915, "Güntner GmbH & Co. KG"),
-    (916, "KNXlogic"),
-    (917, "CIM Environmental Group"),
-    (918, "Flow Control"),
-    (919, "Lumen Cache, Inc."),
-    (920, "Ecosystem"),
-    (921, "Potter Electric Signal Company, LLC"),
-    (922, "Tyco Fire & Security S.p.A."),
-    (923, "Watanabe Electric Industry Co., Ltd."),
-    (924, "Causam Energy"),
-    (925, "W-tec AG"),
-    (926, "IMI Hydronic Engineering International SA"),
-    (927, "ARIGO Software"),
-    (928, "MSA Safety"),
-    (929, "Smart Solucoes Ltda – MERCATO"),
-    (930, "PIATRA Engineering"),
-    (931, "ODIN Automation Systems, LLC"),
-    (932, "Belparts NV"),
-    (933, "UAB, SALDA"),
-    (934, "Alre-IT Regeltechnik GmbH"),
-    (935, "Ingenieurbüro H. Lertes GmbH & Co. KG"),
-    (936, "Breathing Buildings"),
-    (937, "eWON SA"),
-    (938, "Cav. Uff. Giacomo Cimberio S.p.A"),
-    (939, "PKE Electronics AG"),
-    (940, "Allen"),
-    (941, "Kastle Systems"),
-    (942, "Logical Electro-Mechanical (EM) Systems, Inc."),
-    (943, "ppKinetics Instruments, LLC"),
-    (944, "Cathexis Technologies"),
-    (945, "Sylop sp. Z o.o. sp.k"),
-    (946, "Brauns Control GmbH"),
-    (947, "OMRON SOCIAL SOLUTIONS CO., LTD."),
-    (948, "Wildeboer Bauteile Gmbh"),
-    (949, "Shanghai Biens Technologies Ltd"),
-    (950, "Beijing HZHY Technology Co., Ltd"),
-    (951, "Building Clouds"),
-    (
+    VendorInfo::new(200, "Kandenko Co., Ltd."),
+    VendorInfo::new(201, "DTF, Daten-Technik Fries"),
+    VendorInfo::new(202, "Klimasoft, Ltd."),
+    VendorInfo::new(203, "Toshiba Schneider Inverter Corporation"),
+    VendorInfo::new(204, "Control Applications, Ltd."),
+    VendorInfo::new(205, "CIMON CO., Ltd."),
+    VendorInfo::new(206, "Onicon Incorporated"),
+    VendorInfo::new(207, "Automation Displays, Inc."),
+    VendorInfo::new(208, "Control Solutions, Inc."),
+    VendorInfo::new(209, "Remsdaq Limited"),
+    VendorInfo::new(210, "NTT Facilities, Inc."),
+    VendorInfo::new(211, "VIPA GmbH"),
+    VendorInfo::new(212, "TSC21 Association of Japan"),
+    VendorInfo::new(213, "Strato Automation"),
+    VendorInfo::new(214, "HRW Limited"),
+    VendorInfo::new(215, "Lighting Control & Design, Inc."),
+    VendorInfo::new(216, "Mercy Electronic and Electrical Industries"),
+    VendorInfo::new(217, "Samsung SDS Co., Ltd"),
+    VendorInfo::new(218, "Impact Facility Solutions, Inc."),
+    VendorInfo::new(219, "Aircuity"),
+    VendorInfo::new(220, "Control Techniques, Ltd."),
+    VendorInfo::new(221, "OpenGeneral Pty., Ltd."),
+    VendorInfo::new(222, "WAGO Kontakttechnik GmbH & Co. KG"),
+    VendorInfo::new(223, "Franklin Electric"),
+    VendorInfo::new(224, "Chloride Power Protection Company"),
+    VendorInfo::new(225, "Computrols, Inc."),
+    VendorInfo::new(226, "Phoenix Contact GmbH & Co. KG"),
+    VendorInfo::new(227, "Grundfos Management A/S"),
+    VendorInfo::new(228, "Ridder Drive Systems"),
+    VendorInfo::new(229, "Soft Device SDN BHD"),
+    VendorInfo::new(230, "Integrated Control Technology Limited"),
+    VendorInfo::new(231, "AIRxpert Systems, Inc."),
+    VendorInfo::new(232, "Microtrol Limited"),
+    VendorInfo::new(233, "Red Lion Controls"),
+    VendorInfo::new(234, "Digital Electronics Corporation"),
+    VendorInfo::new(235, "Ennovatis GmbH"),
+    VendorInfo::new(236, "Serotonin Software Technologies, Inc."),
+    VendorInfo::new(237, "LS Industrial Systems Co., Ltd."),
+    VendorInfo::new(238, "Square D Company"),
+    VendorInfo::new(239, "S Squared Innovations, Inc."),
+    VendorInfo::new(240, "Aricent Ltd."),
+    VendorInfo::new(241, "EtherMetrics, LLC"),
+    VendorInfo::new(242, "Industrial Control Communications, Inc."),
+    VendorInfo::new(243, "Paragon Controls, Inc."),
+    VendorInfo::new(244, "A. O. Smith Corporation"),
+    VendorInfo::new(245, "Contemporary Control Systems, Inc."),
+    VendorInfo::new(246, "HMS Industrial Networks SLU"),
+    VendorInfo::new(247, "Ingenieurgesellschaft N. Hartleb mbH"),
+    VendorInfo::new(248, "Heat-Timer Corporation"),
+    VendorInfo::new(249, "Ingrasys Technology, Inc."),
+    VendorInfo::new(250, "Costerm Building Automation"),
+    VendorInfo::new(251, "WILO SE"),
+    VendorInfo::new(252, "Embedia Technologies Corp."),
+    VendorInfo::new(253, "Technilog"),
+    VendorInfo::new(254, "HR Controls Ltd. & Co. KG"),
+    VendorInfo::new(255, "Lennox International, Inc."),
+    VendorInfo::new(256, "RK-Tec Rauchklappen-Steuerungssysteme GmbH & Co. KG"),
+    VendorInfo::new(257, "Thermomax, Ltd."),
+    VendorInfo::new(258, "ELCON Electronic Control, Ltd."),
+    VendorInfo::new(259, "Larmia Control AB"),
+    VendorInfo::new(260, "BACnet Stack at SourceForge"),
+    VendorInfo::new(261, "G4S Security Services A/S"),
+    VendorInfo::new(262, "Exor International S.p.A."),
+    VendorInfo::new(263, "Cristal Controles"),
+    VendorInfo::new(264, "Regin AB"),
+    VendorInfo::new(265, "Dimension Software, Inc."),
+    VendorInfo::new(266, "SynapSense Corporation"),
+    VendorInfo::new(267, "Beijing Nantree Electronic Co., Ltd."),
+    VendorInfo::new(268, "Camus Hydronics Ltd."),
+    VendorInfo::new(269, "Kawasaki Heavy Industries, Ltd."),
+    VendorInfo::new(270, "Critical Environment Technologies"),
+    VendorInfo::new(271, "ILSHIN IBS Co., Ltd."),
+    VendorInfo::new(272, "ELESTA Energy Control AG"),
+    VendorInfo::new(273, "KROPMAN Installatietechniek"),
+    VendorInfo::new(274, "Baldor Electric Company"),
+    VendorInfo::new(275, "INGA mbH"),
+    VendorInfo::new(276, "GE Consumer & Industrial"),
+    VendorInfo::new(277, "Functional Devices, Inc."),
+    VendorInfo::new(278, "StudioSC"),
+    VendorInfo::new(279, "MG CO., LTD."),
+    VendorInfo::new(280, "Yokota Co., Ltd."),
+    VendorInfo::new(281, "Hitranse Technology Co., LTD"),
+    VendorInfo::new(282, "Vigilent Corporation"),
+    VendorInfo::new(283, "Kele, Inc."),
+    VendorInfo::new(284, "BELIMO Automation AG"),
+    VendorInfo::new(285, "Gentec"),
+    VendorInfo::new(286, "Embedded Science Labs, LLC"),
+    VendorInfo::new(287, "Parker Hannifin Corporation"),
+    VendorInfo::new(288, "MaCaPS International Limited"),
+    VendorInfo::new(289, "Link4 Corporation"),
+    VendorInfo::new(290, "Romutec Steuer-u. Regelsysteme GmbH"),
+    VendorInfo::new(291, "Pribusin, Inc."),
+    VendorInfo::new(292, "Advantage Controls"),
+    VendorInfo::new(293, "Critical Room Control"),
+    VendorInfo::new(294, "LEGRAND"),
+    VendorInfo::new(295, "Tongdy Control Technology Co., Ltd."),
+    VendorInfo::new(296, "ISSARO Integrierte Systemtechnik"),
+    VendorInfo::new(297, "Pro-Dev Industries"),
+    VendorInfo::new(298, "DRI-STEEM"),
+    VendorInfo::new(299, "Creative Electronic GmbH"),
+    VendorInfo::new(300, "Swegon AB"),
+    VendorInfo::new(301, "FIRVENA s.r.o."),
+    VendorInfo::new(302, "Hitachi Appliances, Inc."),
+    VendorInfo::new(303, "Real Time Automation, Inc."),
+    VendorInfo::new(304, "ITEC Hankyu-Hanshin Co."),
+    VendorInfo::new(305, "Cyrus E&M Engineering Co., Ltd."),
+    VendorInfo::new(306, "Badger Meter"),
+    VendorInfo::new(307, "Cirrascale Corporation"),
+    VendorInfo::new(308, "Elesta GmbH Building Automation"),
+    VendorInfo::new(309, "Securiton"),
+    VendorInfo::new(310, "OSlsoft, Inc."),
+    VendorInfo::new(311, "Hanazeder Electronic GmbH"),
+    VendorInfo::new(312, "Honeywell Security Deutschland, Novar GmbH"),
+    VendorInfo::new(313, "Siemens Industry, Inc."),
+    VendorInfo::new(314, "ETM Professional Control GmbH"),
+    VendorInfo::new(315, "Meitav-tec, Ltd."),
+    VendorInfo::new(316, "Janitza Electronics GmbH"),
+    VendorInfo::new(317, "MKS Nordhausen"),
+    VendorInfo::new(318, "De Gier Drive Systems B.V."),
+    VendorInfo::new(319, "Cypress Envirosystems"),
+    VendorInfo::new(320, "SMARTron s.r.o."),
+    VendorInfo::new(321, "Verari Systems, Inc."),
+    VendorInfo::new(322, "K-W Electronic Service, Inc."),
+    VendorInfo::new(323, "ALFA-SMART Energy Management"),
+    VendorInfo::new(324, "Telkonet, Inc."),
+    VendorInfo::new(325, "Securiton GmbH"),
+    VendorInfo::new(326, "Cemtrex, Inc."),
+    VendorInfo::new(327, "Performance Technologies, Inc."),
+    VendorInfo::new(328, "Xtralis (Aust) Pty Ltd"),
+    VendorInfo::new(329, "TROX GmbH"),
+    VendorInfo::new(330, "Beijing Hysine Technology Co., Ltd"),
+    VendorInfo::new(331, "RCK Controls, Inc."),
+    VendorInfo::new(332, "Distech Controls SAS"),
+    VendorInfo::new(333, "Novar/Honeywell"),
+    VendorInfo::new(334, "S4 Integration Solutions"),
+    VendorInfo::new(335, "Schneider Electric"),
+    VendorInfo::new(336, "LHA Systems"),
+    VendorInfo::new(337, "GHM engineering Group, Inc."),
+    VendorInfo::new(338, "Cllimalux S.A."),
+    VendorInfo::new(339, "VAISALA Oyj"),
+    VendorInfo::new(340, "COMPLEX (Beijing) Technology, Co., LTD."),
+    VendorInfo::new(341, "SCADAmetrics"),
+    VendorInfo::new(342, "POWERPEG NSI Limited"),
+    VendorInfo::new(343, "BACnet Interoperability Testing Services, Inc."),
+    VendorInfo::new(344, "Teco a.s."),
+    VendorInfo::new(345, "Plexus Technology, Inc."),
+    VendorInfo::new(346, "Energy Focus, Inc."),
+    VendorInfo::new(347, "Powersmiths International Corp."),
+    VendorInfo::new(348, "Nichibei Co., Ltd."),
+    VendorInfo::new(349, "HKC Technology Ltd."),
+    VendorInfo::new(350, "Ovation Networks, Inc."),
+    VendorInfo::new(351, "Setra Systems"),
+    VendorInfo::new(352, "AVG Automation"),
+    VendorInfo::new(353, "ZXC Ltd."),
+    VendorInfo::new(354, "Byte Sphere"),
+    VendorInfo::new(355, "Generiton Co., Ltd."),
+    VendorInfo::new(356, "Holter Regelarmaturen GmbH & Co. KG"),
+    VendorInfo::new(357, "Bedford Instruments, LLC"),
+    VendorInfo::new(358, "Standair Inc."),
+    VendorInfo::new(359, "WEG Automation – R&D"),
+    VendorInfo::new(360, "Prolon Control Systems ApS"),
+    VendorInfo::new(361, "Inneasoft"),
+    VendorInfo::new(362, "ConneXSoft GmbH"),
+    VendorInfo::new(363, "CEAG Notlichtsysteme GmbH"),
+    VendorInfo::new(364, "Distech Controls Inc."),
+    VendorInfo::new(365, "Industrial Technology Research Institute"),
+    VendorInfo::new(366, "ICONICS, Inc."),
+    VendorInfo::new(367, "IQ Controls s.c."),
+    VendorInfo::new(368, "OJ Electronics A/S"),
+    VendorInfo::new(369, "Rolbit Ltd."),
+    VendorInfo::new(370, "Synapsys Solutions Ltd."),
+    VendorInfo::new(371, "ACME Engineering Prod. Ltd."),
+    VendorInfo::new(372, "Zener Electric Pty, Ltd."),
+    VendorInfo::new(373, "Selectronix, Inc."),
+    VendorInfo::new(374, "Gorbet & Banerjee, LLC."),
+    VendorInfo::new(375, "IME"),
+    VendorInfo::new(376, "Stephen H. Dawson Computer Service"),
+    VendorInfo::new(377, "Accutrol, LLC"),
+    VendorInfo::new(378, "Schneider Elektronik GmbH"),
+    VendorInfo::new(379, "Alpha-Inno Tec GmbH"),
+    VendorInfo::new(380, "ADMMicro, Inc."),
+    VendorInfo::new(381, "Greystone Energy Systems, Inc."),
+    VendorInfo::new(382, "CAP Technologie"),
+    VendorInfo::new(383, "KeRo Systems"),
+    VendorInfo::new(384, "Domat Control System s.r.o."),
+    VendorInfo::new(385, "Efektronics Pty. Ltd."),
+    VendorInfo::new(386, "Hekatron Vertriebs GmbH"),
+    VendorInfo::new(387, "Securiton AG"),
+    VendorInfo::new(388, "Carlo Gavazzi Controls SpA"),
+    VendorInfo::new(389, "Chipkin Automation Systems"),
+    VendorInfo::new(390, "Savant Systems, LLC"),
+    VendorInfo::new(391, "Simmtronic Lighting Controls"),
+    VendorInfo::new(392, "Abelko Innovation AB"),
+    VendorInfo::new(393, "Seresco Technologies Inc."),
+    VendorInfo::new(394, "IT Watchdogs"),
+    VendorInfo::new(395, "Automation Assist Japan Corp."),
+    VendorInfo::new(396, "Thermokon Sensortechnik GmbH"),
+    VendorInfo::new(397, "EGauge Systems, LLC"),
+    VendorInfo::new(398, "Quantum Automation (ASIA) PTE, Ltd."),
+    VendorInfo::new(399, "Toshiba Lighting & Technology Corp."),
+    VendorInfo::new(400, "SPIN Engenharia de Automação Ltda."),
+    VendorInfo::new(401, "Logistics Systems & Software Services India PVT. Ltd."),
+    VendorInfo::new(402, "Delta Controls Integration Products"),
+    VendorInfo::new(403, "Focus Media"),
+    VendorInfo::new(404, "LUMEnergi Inc."),
+    VendorInfo::new(405, "Kara Systems"),
+    VendorInfo::new(406, "RF Code, Inc."),
+    VendorInfo::new(407, "Fatek Automation Corp."),
+    VendorInfo::new(408, "JANDA Software Company, LLC"),
+    VendorInfo::new(409, "Open System Solutions Limited"),
+    VendorInfo::new(410, "Intelec Systems PTY Ltd."),
+    VendorInfo::new(411, "Ecolodgix, LLC"),
+    VendorInfo::new(412, "Douglas Lighting Controls"),
+    VendorInfo::new(413, "iSAtech GmbH"),
+    VendorInfo::new(414, "AREAL"),
+    VendorInfo::new(415, "Beckhoff Automation"),
+    VendorInfo::new(416, "IPAS GmbH"),
+    VendorInfo::new(417, "KE2 Therm Solutions"),
+    VendorInfo::new(418, "Base2Products"),
+    VendorInfo::new(419, "DTL Controls, LLC"),
+    VendorInfo::new(420, "INNCOM International, Inc."),
+    VendorInfo::new(421, "METZ CONNECT GmbH"),
+    VendorInfo::new(422, "Greentrol Automation, Inc"),
+    VendorInfo::new(423, "BELIMO Automation AG"),
+    VendorInfo::new(424, "Samsung Heavy Industries Co, Ltd"),
+    VendorInfo::new(425, "Triacta Power Technologies, Inc."),
+    VendorInfo::new(426, "Globestar Systems"),
+    VendorInfo::new(427, "MLB Advanced Media, LP"),
+    VendorInfo::new(428, "SWG Stuckmann Wirtschaftliche Gebäudesysteme GmbH"),
+    VendorInfo::new(429, "SensorSwitch"),
+    VendorInfo::new(430, "Multitek Power Limited"),
+    VendorInfo::new(431, "Integra Metering AG"),
+    VendorInfo::new(432, "LG Electronics Inc."),
+    VendorInfo::new(433, "Electronic Theatre Controls, Inc."),
+    VendorInfo::new(434, "Mitsubishi Electric Corporation Nagoya Works"),
+    VendorInfo::new(435, "Delta Electronics, Inc."),
+    VendorInfo::new(436, "Elma Kurtalj, Ltd."),
+    VendorInfo::new(437, "Tyco Fire & Security GmbH"),
+    VendorInfo::new(438, "Nedap Security Management"),
+    VendorInfo::new(439, "ESC Automation Inc."),
+    VendorInfo::new(440, "DSP4YOU Ltd."),
+    VendorInfo::new(441, "GE Sensing and Inspection Technologies"),
+    VendorInfo::new(442, "Embedded Systems SIA"),
+    VendorInfo::new(443, "BEFEGA GmbH"),
+    VendorInfo::new(444, "Baseline Inc."),
+    VendorInfo::new(445, "Key2Act"),
+    VendorInfo::new(446, "OEMCtrl"),
+    VendorInfo::new(447, "Clarkson Controls Limited"),
+    VendorInfo::new(448, "Rogerwell Control System Limited"),
+    VendorInfo::new(449, "SCL Elements"),
+    VendorInfo::new(450, "Hitachi Ltd."),
+    VendorInfo::new(451, "Newron System SA"),
+    VendorInfo::new(452, "BEVECO Gebouwautomatisering BV"),
+    VendorInfo::new(453, "Streamside Solutions"),
+    VendorInfo::new(454, "Yellowstone Soft"),
+    VendorInfo::new(455, "Oztech Intelligent Systems Pty Ltd."),
+    VendorInfo::new(456, "Novelan GmbH"),
+    VendorInfo::new(457, "Flexim Americas Corporation"),
+    VendorInfo::new(458, "ICP DAS Co., Ltd."),
+    VendorInfo::new(459, "CARMA Industries Inc."),
+    VendorInfo::new(460, "Log-One Ltd."),
+    VendorInfo::new(461, "TECO Electric & Machinery Co., Ltd."),
+    VendorInfo::new(462, "ConnectEx, Inc."),
+    VendorInfo::new(463, "Turbo DDC Südwest"),
+    VendorInfo::new(464, "Quatrosense Environmental Ltd."),
+    VendorInfo::new(465, "Fifth Light Technology Ltd."),
+    VendorInfo::new(466, "Scientific Solutions, Ltd."),
+    VendorInfo::new(467, "Controller Area Network Solutions (M) Sdn Bhd"),
+    VendorInfo::new(468, "RESOL – Elektronische Regelungen GmbH"),
+    VendorInfo::new(469, "RPBUS LLC"),
+    VendorInfo::new(470, "BRS Sistemas Eletronicos"),
+    VendorInfo::new(471, "WindowMaster A/S"),
+    VendorInfo::new(472, "Sunlux Technologies Ltd."),
+    VendorInfo::new(473, "Measurlogic"),
+    VendorInfo::new(474, "Frimat GmbH"),
+    VendorInfo::new(475, "Spirax Sarco"),
+    VendorInfo::new(476, "Luxtron"),
+    VendorInfo::new(477, "Raypak Inc"),
+    VendorInfo::new(478, "Air Monitor Corporation"),
+    VendorInfo::new(479, "Regler Och Webbteknik Sverige (ROWS)"),
+    VendorInfo::new(480, "Intelligent Lighting Controls Inc."),
+    VendorInfo::new(481, "Sanyo Electric Industry Co., Ltd"),
+    VendorInfo::new(482, "E-Mon Energy Monitoring Products"),
+    VendorInfo::new(483, "Digital Control Systems"),
+    VendorInfo::new(484, "ATI Airtest Technologies, Inc."),
+    VendorInfo::new(485, "SCS SA"),
+    VendorInfo::new(486, "HMS Industrial Networks AB"),
+    VendorInfo::new(487, "Shenzhen Universal Intellisys Co Ltd"),
+    VendorInfo::new(488, "EK Intellisys Sdn Bhd"),
+    VendorInfo::new(489, "SysCom"),
+    VendorInfo::new(490, "Firecom, Inc."),
+    VendorInfo::new(491, "ESA Elektroschaltanlagen Grimma GmbH"),
+    VendorInfo::new(492, "Kumahira Co Ltd"),
+    VendorInfo::new(493, "Hotraco"),
+    VendorInfo::new(494, "SABO Elektronik GmbH"),
+    VendorInfo::new(495, "Equip'Trans"),
+    VendorInfo::new(496, "Temperature Control Specialities Co., Inc (TCS)"),
+    VendorInfo::new(497, "FlowCon International A/S"),
+    VendorInfo::new(498, "ThyssenKrupp Elevator Americas"),
+    VendorInfo::new(499, "Abatement Technologies"),
+    VendorInfo::new(500, "Continental Control Systems, LLC"),
+    VendorInfo::new(501, "WISAG Automatisierungstechnik GmbH & Co KG"),
+    VendorInfo::new(502, "EasyIO"),
+    VendorInfo::new(503, "EAP-Electric GmbH"),
+    VendorInfo::new(504, "Hardmeier"),
+    VendorInfo::new(505, "Mircom Group of Companies"),
+    VendorInfo::new(506, "Quest Controls"),
+    VendorInfo::new(507, "Mestek, Inc"),
+    VendorInfo::new(508, "Pulse Energy"),
+    VendorInfo::new(509, "Tachikawa Corporation"),
+    VendorInfo::new(510, "University of Nebraska-Lincoln"),
+    VendorInfo::new(511, "Redwood Systems"),
+    VendorInfo::new(512, "PASStec Industrie-Elektronik GmbH"),
+    VendorInfo::new(513, "NgEK, Inc."),
+    VendorInfo::new(514, "t-mac Technologies"),
+    VendorInfo::new(515, "Jireh Energy Tech Co., Ltd."),
+    VendorInfo::new(516, "Enlighted Inc."),
+    VendorInfo::new(517, "El-Piast Sp. Z o.o"),
+    VendorInfo::new(518, "NetxAutomation Software GmbH"),
+    VendorInfo::new(519, "Invertek Drives"),
+    VendorInfo::new(520, "Deutschmann Automation GmbH & Co. KG"),
+    VendorInfo::new(521, "EMU Electronic AG"),
+    VendorInfo::new(522, "Phaedrus Limited"),
+    VendorInfo::new(523, "Sigmatek GmbH & Co KG"),
+    VendorInfo::new(524, "Marlin Controls"),
+    VendorInfo::new(525, "Circutor, SA"),
+    VendorInfo::new(526, "UTC Fire & Security"),
+    VendorInfo::new(527, "DENT Instruments, Inc."),
+    VendorInfo::new(528, "FHP Manufacturing Company – Bosch Group"),
+    VendorInfo::new(529, "GE Intelligent Platforms"),
+    VendorInfo::new(530, "Inner Range Pty Ltd"),
+    VendorInfo::new(531, "GLAS Energy Technology"),
+    VendorInfo::new(532, "MSR-Electronic-GmbH"),
+    VendorInfo::new(533, "Energy Control Systems, Inc."),
+    VendorInfo::new(534, "EMT Controls"),
+    VendorInfo::new(535, "Daintree"),
+    VendorInfo::new(536, "EURO ICC d.o.o"),
+    VendorInfo::new(537, "TE Connectivity Energy"),
+    VendorInfo::new(538, "GEZE GmbH"),
+    VendorInfo::new(539, "NEC Corporation"),
+    VendorInfo::new(540, "Ho Cheung International Company Limited"),
+    VendorInfo::new(541, "Sharp Manufacturing Systems Corporation"),
+    VendorInfo::new(542, "DOT CONTROLS a.s."),
+    VendorInfo::new(543, "BeaconMedæs"),
+    VendorInfo::new(544, "Midea Commercial Aircon"),
+    VendorInfo::new(545, "AAON"),
+    VendorInfo::new(546, "Kamstrup A/S"),
+    VendorInfo::new(547, "CA Computer Automation GmbH"),
+    VendorInfo::new(548, "Laars Heating Systems Company"),
+    VendorInfo::new(549, "Hitachi Systems, Ltd."),
+    VendorInfo::new(550, "Fushan AKE Electronic Engineering Co., Ltd."),
+    VendorInfo::new(551, "Toshiba International Corporation"),
+    VendorInfo::new(552, "Starman Systems, LLC"),
+    VendorInfo::new(553, "Samsung Techwin Co., Ltd."),
+    VendorInfo::new(554, "ISAS-Integrated Switchgear and Systems P/L"),
+    VendorInfo::new(555, "Reserved for ASHRAE"),
+    VendorInfo::new(556, "Obvius"),
+    VendorInfo::new(557, "Marek Guzik"),
+    VendorInfo::new(558, "Vortek Instruments, LLC"),
+    VendorInfo::new(559, "Universal Lighting Technologies"),
+    VendorInfo::new(560, "Myers Power Products, Inc."),
+    VendorInfo::new(561, "Vector Controls GmbH"),
+    VendorInfo::new(562, "Crestron Electronics, Inc."),
+    VendorInfo::new(563, "A&E Controls Limited"),
+    VendorInfo::new(564, "Projektomontaza A.D."),
+    VendorInfo::new(565, "Freeaire Refrigeration"),
+    VendorInfo::new(566, "Aqua Cooler Pty Limited"),
+    VendorInfo::new(567, "Basic Controls"),
+    VendorInfo::new(568, "GE Measurement and Control Solutions Advanced Sensors"),
+    VendorInfo::new(569, "EQUAL Networks"),
+    VendorInfo::new(570, "Millennial Net"),
+    VendorInfo::new(571, "APLI Ltd"),
+    VendorInfo::new(572, "Electro Industries/GaugeTech"),
+    VendorInfo::new(573, "SangMyung University"),
+    VendorInfo::new(574, "Coppertree Analytics, Inc."),
+    VendorInfo::new(575, "CoreNetiX GmbH"),
+    VendorInfo::new(576, "Acutherm"),
+    VendorInfo::new(577, "Dr. Riedel Automatisierungstechnik GmbH"),
+    VendorInfo::new(578, "Shina System Co., Ltd"),
+    VendorInfo::new(579, "Iqapertus"),
+    VendorInfo::new(580, "PSE Technology"),
+    VendorInfo::new(581, "BA Systems"),
+    VendorInfo::new(582, "BTICINO"),
+    VendorInfo::new(583, "Monico, Inc."),
+    VendorInfo::new(584, "iCue"),
+    VendorInfo::new(585, "tekmar Control Systems Ltd."),
+    VendorInfo::new(586, "Control Technology Corporation"),
+    VendorInfo::new(587, "GFAE GmbH"),
+    VendorInfo::new(588, "BeKa Software GmbH"),
+    VendorInfo::new(589, "Isoil Industria SpA"),
+    VendorInfo::new(590, "Home Systems Consulting SpA"),
+    VendorInfo::new(591, "Socomec"),
+    VendorInfo::new(592, "Everex Communications, Inc."),
+    VendorInfo::new(593, "CET Electric Technology, Inc."),
+    VendorInfo::new(594, "Atrila GmbH"),
+    VendorInfo::new(595, "WingTechs"),
+    VendorInfo::new(596, "Shenzhen Mek Intellisys Pte Ltd."),
+    VendorInfo::new(597, "Nestfield Co., Ltd."),
+    VendorInfo::new(598, "Swissphone Telecom AG"),
+    VendorInfo::new(599, "PNTECH JSC"),
+    VendorInfo::new(600, "Horner APG, LLC"),
+    VendorInfo::new(601, "PVI Industries, LLC"),
+    VendorInfo::new(602, "Ela-compil"),
+    VendorInfo::new(603, "Pegasus Automation International LLC"),
+    VendorInfo::new(604, "Wight Electronic Services Ltd."),
+    VendorInfo::new(605, "Marcom"),
+    VendorInfo::new(606, "Exhausto A/S"),
+    VendorInfo::new(607, "Dwyer Instruments, Inc."),
+    VendorInfo::new(608, "Link GmbH"),
+    VendorInfo::new(609, "Oppermann Regelgerate GmbH"),
+    VendorInfo::new(610, "NuAire, Inc."),
+    VendorInfo::new(611, "Nortec Humidity, Inc."),
+    VendorInfo::new(612, "Bigwood Systems, Inc."),
+    VendorInfo::new(613, "Enbala Power Networks"),
+    VendorInfo::new(614, "Inter Energy Co., Ltd."),
+    VendorInfo::new(615, "ETC"),
+    VendorInfo::new(616, "COMELEC S.A.R.L"),
+    VendorInfo::new(617, "Pythia Technologies"),
+    VendorInfo::new(618, "TrendPoint Systems, Inc."),
+    VendorInfo::new(619, "AWEX"),
+    VendorInfo::new(620, "Eurevia"),
+    VendorInfo::new(621, "Kongsberg E-lon AS"),
+    VendorInfo::new(622, "FlaktWoods"),
+    VendorInfo::new(623, "E + E Elektronik GES M.B.H."),
+    VendorInfo::new(624, "ARC Informatique"),
+    VendorInfo::new(625, "SKIDATA AG"),
+    VendorInfo::new(626, "WSW Solutions"),
+    VendorInfo::new(627, "Trefon Electronic GmbH"),
+    VendorInfo::new(628, "Dongseo System"),
+    VendorInfo::new(629, "Kanontec Intelligence Technology Co., Ltd."),
+    VendorInfo::new(630, "EVCO S.p.A."),
+    VendorInfo::new(631, "Accuenergy (Canada) Inc."),
+    VendorInfo::new(632, "SoftDEL"),
+    VendorInfo::new(633, "Orion Energy Systems, Inc."),
+    VendorInfo::new(634, "Roboticsware"),
+    VendorInfo::new(635, "DOMIQ Sp. z o.o."),
+    VendorInfo::new(636, "Solidyne"),
+    VendorInfo::new(637, "Elecsys Corporation"),
+    VendorInfo::new(638, "Conditionaire International Pty. Limited"),
+    VendorInfo::new(639, "Quebec, Inc."),
+    VendorInfo::new(640, "Homerun Holdings"),
+    VendorInfo::new(641, "Murata Americas"),
+    VendorInfo::new(642, "Comptek"),
+    VendorInfo::new(643, "Westco Systems, Inc."),
+    VendorInfo::new(644, "Advancis Software & Services GmbH"),
+    VendorInfo::new(645, "Intergrid, LLC"),
+    VendorInfo::new(646, "Markerr Controls, Inc."),
+    VendorInfo::new(647, "Toshiba Elevator and Building Systems Corporation"),
+    VendorInfo::new(648, "Spectrum Controls, Inc."),
+    VendorInfo::new(649, "Mkservice"),
+    VendorInfo::new(650, "Fox Thermal Instruments"),
+    VendorInfo::new(651, "SyxthSense Ltd"),
+    VendorInfo::new(652, "DUHA System S R.O."),
+    VendorInfo::new(653, "NIBE"),
+    VendorInfo::new(654, "Melink Corporation"),
+    VendorInfo::new(655, "Fritz-Haber-Institut"),
+    VendorInfo::new(656, "MTU Onsite Energy GmbH, Gas Power Systems"),
+    VendorInfo::new(657, "Omega Engineering, Inc."),
+    VendorInfo::new(658, "Avelon"),
+    VendorInfo::new(659, "Ywire Technologies, Inc."),
+    VendorInfo::new(660, "M.R. Engineering Co., Ltd."),
+    VendorInfo::new(661, "Lochinvar, LLC"),
+    VendorInfo::new(662, "Sontay Limited"),
+    VendorInfo::new(663, "GRUPA Slawomir Chelminski"),
+    VendorInfo::new(664, "Arch Meter Corporation"),
+    VendorInfo::new(665, "Senva, Inc."),
+    VendorInfo::new(666, "Reserved for ASHRAE"),
+    VendorInfo::new(667, "FM-Tec"),
+    VendorInfo::new(668, "Systems Specialists, Inc."),
+    VendorInfo::new(669, "SenseAir"),
+    VendorInfo::new(670, "AB IndustrieTechnik Srl"),
+    VendorInfo::new(671, "Cortland Research, LLC"),
+    VendorInfo::new(672, "MediaView"),
+    VendorInfo::new(673, "VDA Elettronica"),
+    VendorInfo::new(674, "CSS, Inc."),
+    VendorInfo::new(675, "Tek-Air Systems, Inc."),
+    VendorInfo::new(676, "ICDT"),
+    VendorInfo::new(677, "The Armstrong Monitoring Corporation"),
+    VendorInfo::new(678, "DIXELL S.r.l"),
+    VendorInfo::new(679, "Lead System, Inc."),
+    VendorInfo::new(680, "ISM EuroCenter S.A."),
+    VendorInfo::new(681, "TDIS"),
+    VendorInfo::new(682, "Trade FIDES"),
+    VendorInfo::new(683, "Knürr GmbH (Emerson Network Power)"),
+    VendorInfo::new(684, "Resource Data Management"),
+    VendorInfo::new(685, "Abies Technology, Inc."),
+    VendorInfo::new(686, "UAB Komfovent"),
+    VendorInfo::new(687, "MIRAE Electrical Mfg. Co., Ltd."),
+    VendorInfo::new(688, "HunterDouglas Architectural Projects Scandinavia ApS"),
+    VendorInfo::new(689, "RUNPAQ Group Co., Ltd"),
+    VendorInfo::new(690, "Unicard SA"),
+    VendorInfo::new(691, "IE Technologies"),
+    VendorInfo::new(692, "Ruskin Manufacturing"),
+    VendorInfo::new(693, "Calon Associates Limited"),
+    VendorInfo::new(694, "Contec Co., Ltd."),
+    VendorInfo::new(695, "iT GmbH"),
+    VendorInfo::new(696, "Autani Corporation"),
+    VendorInfo::new(697, "Christian Fortin"),
+    VendorInfo::new(698, "HDL"),
+    VendorInfo::new(699, "IPID Sp. Z.O.O Limited"),
+    VendorInfo::new(700, "Fuji Electric Co., Ltd"),
+    VendorInfo::new(701, "View, Inc."),
+    VendorInfo::new(702, "Samsung S1 Corporation"),
+    VendorInfo::new(703, "New Lift"),
+    VendorInfo::new(704, "VRT Systems"),
+    VendorInfo::new(705, "Motion Control Engineering, Inc."),
+    VendorInfo::new(706, "Weiss Klimatechnik GmbH"),
+    VendorInfo::new(707, "Elkon"),
+    VendorInfo::new(708, "Eliwell Controls S.r.l."),
+    VendorInfo::new(709, "Japan Computer Technos Corp"),
+    VendorInfo::new(710, "Rational Network ehf"),
+    VendorInfo::new(711, "Magnum Energy Solutions, LLC"),
+    VendorInfo::new(712, "MelRok"),
+    VendorInfo::new(713, "VAE Group"),
+    VendorInfo::new(714, "LGCNS"),
+    VendorInfo::new(715, "Berghof Automationstechnik GmbH"),
+    VendorInfo::new(716, "Quark Communications, Inc."),
+    VendorInfo::new(717, "Sontex"),
+    VendorInfo::new(718, "mivune AG"),
+    VendorInfo::new(719, "Panduit"),
+    VendorInfo::new(720, "Smart Controls, LLC"),
+    VendorInfo::new(721, "Compu-Aire, Inc."),
+    VendorInfo::new(722, "Sierra"),
+    VendorInfo::new(723, "ProtoSense Technologies"),
+    VendorInfo::new(724, "Eltrac Technologies Pvt Ltd"),
+    VendorInfo::new(725, "Bektas Invisible Controls GmbH"),
+    VendorInfo::new(726, "Entelec"),
+    VendorInfo::new(727, "INNEXIV"),
+    VendorInfo::new(728, "Covenant"),
+    VendorInfo::new(729, "Davitor AB"),
+    VendorInfo::new(730, "TongFang Technovator"),
+    VendorInfo::new(731, "Building Robotics, Inc."),
+    VendorInfo::new(732, "HSS-MSR UG"),
+    VendorInfo::new(733, "FramTack LLC"),
+    VendorInfo::new(734, "B. L. Acoustics, Ltd."),
+    VendorInfo::new(735, "Traxxon Rock Drills, Ltd"),
+    VendorInfo::new(736, "Franke"),
+    VendorInfo::new(737, "Wurm GmbH & Co"),
+    VendorInfo::new(738, "AddENERGIE"),
+    VendorInfo::new(739, "Mirle Automation Corporation"),
+    VendorInfo::new(740, "Ibis Networks"),
+    VendorInfo::new(741, "ID-KARTA s.r.o."),
+    VendorInfo::new(742, "Anaren, Inc."),
+    VendorInfo::new(743, "Span, Incorporated"),
+    VendorInfo::new(744, "Bosch Thermotechnology Corp"),
+    VendorInfo::new(745, "DRC Technology S.A."),
+    VendorInfo::new(746, "Shanghai Energy Building Technology Co, Ltd"),
+    VendorInfo::new(747, "Fraport AG"),
+    VendorInfo::new(748, "Flowgroup"),
+    VendorInfo::new(749, "Skytron Energy, GmbH"),
+    VendorInfo::new(750, "ALTEL Wicha, Golda Sp. J."),
+    VendorInfo::new(751, "Drupal"),
+    VendorInfo::new(752, "Axiomatic Technology, Ltd"),
+    VendorInfo::new(753, "Bohnke + Partner"),
+    VendorInfo::new(754, "Function1"),
+    VendorInfo::new(755, "Optergy Pty, Ltd"),
+    VendorInfo::new(756, "LSI Virticus"),
+    VendorInfo::new(757, "Konzeptpark GmbH"),
+    VendorInfo::new(758, "NX Lighting Controls"),
+    VendorInfo::new(759, "eCurv, Inc."),
+    VendorInfo::new(760, "Agnosys GmbH"),
+    VendorInfo::new(761, "Shanghai Sunfull Automation Co., LTD"),
+    VendorInfo::new(762, "Kurz Instruments, Inc."),
+    VendorInfo::new(763, "Cias Elettronica S.r.l."),
+    VendorInfo::new(764, "Multiaqua, Inc."),
+    VendorInfo::new(765, "BlueBox"),
+    VendorInfo::new(766, "Sensidyne"),
+    VendorInfo::new(767, "Viessmann Elektronik GmbH"),
+    VendorInfo::new(768, "ADFweb.com srl"),
+    VendorInfo::new(769, "Gaylord Industries"),
+    VendorInfo::new(770, "Majur Ltd."),
+    VendorInfo::new(771, "Shanghai Huilin Technology Co., Ltd."),
+    VendorInfo::new(772, "Exotronic"),
+    VendorInfo::new(773, "SAFECONTROL s.r.o."),
+    VendorInfo::new(774, "Amatis"),
+    VendorInfo::new(775, "Universal Electric Corporation"),
+    VendorInfo::new(776, "iBACnet"),
+    VendorInfo::new(777, "Reserved for ASHRAE"),
+    VendorInfo::new(778, "Smartrise Engineering, Inc."),
+    VendorInfo::new(779, "Miratron, Inc."),
+    VendorInfo::new(780, "SmartEdge"),
+    VendorInfo::new(781, "Mitsubishi Electric Australia Pty Ltd"),
+    VendorInfo::new(782, "Triangle Research International Ptd Ltd"),
+    VendorInfo::new(783, "Produal Oy"),
+    VendorInfo::new(784, "Milestone Systems A/S"),
+    VendorInfo::new(785, "Trustbridge"),
+    VendorInfo::new(786, "Feedback Solutions"),
+    VendorInfo::new(787, "IES"),
+    VendorInfo::new(788, "ABB Power Protection SA"),
+    VendorInfo::new(789, "Riptide IO"),
+    VendorInfo::new(790, "Messerschmitt Systems AG"),
+    VendorInfo::new(791, "Dezem Energy Controlling"),
+    VendorInfo::new(792, "MechoSystems"),
+    VendorInfo::new(793, "evon GmbH"),
+    VendorInfo::new(794, "CS Lab GmbH"),
+    VendorInfo::new(795, "8760 Enterprises, Inc."),
+    VendorInfo::new(796, "Touche Controls"),
+    VendorInfo::new(797, "Ontrol Teknik Malzeme San. ve Tic. A.S."),
+    VendorInfo::new(798, "Uni Control System Sp. Z o.o."),
+    VendorInfo::new(799, "Weihai Ploumeter Co., Ltd"),
+    VendorInfo::new(800, "Elcom International Pvt. Ltd"),
+    VendorInfo::new(801, "Signify"),
+    VendorInfo::new(802, "AutomationDirect"),
+    VendorInfo::new(803, "Paragon Robotics"),
+    VendorInfo::new(804, "SMT System & Modules Technology AG"),
+    VendorInfo::new(805, "Radix IoT LLC"),
+    VendorInfo::new(806, "CMR Controls Ltd"),
+    VendorInfo::new(807, "Innovari, Inc."),
+    VendorInfo::new(808, "ABB Control Products"),
+    VendorInfo::new(809, "Gesellschaft fur Gebäudeautomation mbH"),
+    VendorInfo::new(810, "RODI Systems Corp."),
+    VendorInfo::new(811, "Nextek Power Systems"),
+    VendorInfo::new(812, "Creative Lighting"),
+    VendorInfo::new(813, "WaterFurnace International"),
+    VendorInfo::new(814, "Mercury Security"),
+    VendorInfo::new(815, "Hisense (Shandong) Air-Conditioning Co., Ltd."),
+    VendorInfo::new(816, "Layered Solutions, Inc."),
+    VendorInfo::new(817, "Leegood Automatic System, Inc."),
+    VendorInfo::new(818, "Shanghai Restar Technology Co., Ltd."),
+    VendorInfo::new(819, "Reimann Ingenieurbüro"),
+    VendorInfo::new(820, "LynTec"),
+    VendorInfo::new(821, "HTP"),
+    VendorInfo::new(822, "Elkor Technologies, Inc."),
+    VendorInfo::new(823, "Bentrol Pty Ltd"),
+    VendorInfo::new(824, "Team-Control Oy"),
+    VendorInfo::new(825, "NextDevice, LLC"),
+    VendorInfo::new(826, "iSMA CONTROLLI S.p.a."),
+    VendorInfo::new(827, "King I Electronics Co., Ltd"),
+    VendorInfo::new(828, "SAMDAV"),
+    VendorInfo::new(829, "Next Gen Industries Pvt. Ltd."),
+    VendorInfo::new(830, "Entic LLC"),
+    VendorInfo::new(831, "ETAP"),
+    VendorInfo::new(832, "Moralle Electronics Limited"),
+    VendorInfo::new(833, "Leicom AG"),
+    VendorInfo::new(834, "Watts Regulator Company"),
+    VendorInfo::new(835, "S.C. Orbtronics S.R.L."),
+    VendorInfo::new(836, "Gaussan Technologies"),
+    VendorInfo::new(837, "WEBfactory GmbH"),
+    VendorInfo::new(838, "Ocean Controls"),
+    VendorInfo::new(839, "Messana Air-Ray Conditioning s.r.l."),
+    VendorInfo::new(840, "Hangzhou BATOWN Technology Co. Ltd."),
+    VendorInfo::new(841, "Reasonable Controls"),
+    VendorInfo::new(842, "Servisys, Inc."),
+    VendorInfo::new(843, "halstrup-walcher GmbH"),
+    VendorInfo::new(844, "SWG Automation Fuzhou Limited"),
+    VendorInfo::new(845, "KSB Aktiengesellschaft"),
+    VendorInfo::new(846, "Hybryd Sp. z o.o."),
+    VendorInfo::new(847, "Helvatron AG"),
+    VendorInfo::new(848, "Oderon Sp. Z.O.O."),
+    VendorInfo::new(849, "mikolab"),
+    VendorInfo::new(850, "Exodraft"),
+    VendorInfo::new(851, "Hochhuth GmbH"),
+    VendorInfo::new(852, "Integrated System Technologies Ltd."),
+    VendorInfo::new(853, "Shanghai Cellcons Controls Co., Ltd"),
+    VendorInfo::new(854, "Emme Controls, LLC"),
+    VendorInfo::new(855, "Field Diagnostic Services, Inc."),
+    VendorInfo::new(856, "Ges Teknik A.S."),
+    VendorInfo::new(857, "Global Power Products, Inc."),
+    VendorInfo::new(858, "Option NV"),
+    VendorInfo::new(859, "BV-Control AG"),
+    VendorInfo::new(860, "Sigren Engineering AG"),
+    VendorInfo::new(861, "Shanghai Jaltone Technology Co., Ltd."),
+    VendorInfo::new(862, "MaxLine Solutions Ltd"),
+    VendorInfo::new(863, "Kron Instrumentos Elétricos Ltda"),
+    VendorInfo::new(864, "Thermo Matrix"),
+    VendorInfo::new(865, "Infinite Automation Systems, Inc."),
+    VendorInfo::new(866, "Vantage"),
+    VendorInfo::new(867, "Elecon Measurements Pvt Ltd"),
+    VendorInfo::new(868, "TBA"),
+    VendorInfo::new(869, "Carnes Company"),
+    VendorInfo::new(870, "Harman Professional"),
+    VendorInfo::new(871, "Nenutec Asia Pacific Pte Ltd"),
+    VendorInfo::new(872, "Gia NV"),
+    VendorInfo::new(873, "Kepware Tehnologies"),
+    VendorInfo::new(874, "Temperature Electronics Ltd"),
+    VendorInfo::new(875, "Packet Power"),
+    VendorInfo::new(876, "Project Haystack Corporation"),
+    VendorInfo::new(877, "DEOS Controls Americas Inc."),
+    VendorInfo::new(878, "Senseware Inc"),
+    VendorInfo::new(879, "MST Systemtechnik AG"),
+    VendorInfo::new(880, "Lonix Ltd"),
+    VendorInfo::new(881, "Gossen Metrawatt GmbH"),
+    VendorInfo::new(882, "Aviosys International Inc."),
+    VendorInfo::new(883, "Efficient Building Automation Corp."),
+    VendorInfo::new(884, "Accutron Instruments Inc."),
+    VendorInfo::new(885, "Vermont Energy Control Systems LLC"),
+    VendorInfo::new(886, "DCC Dynamics"),
+    VendorInfo::new(887, "B.E.G. Brück Electronic GmbH"),
+    VendorInfo::new(888, "Reserved for ASHRAE"),
+    VendorInfo::new(889, "NGBS Hungary Ltd."),
+    VendorInfo::new(890, "ILLUM Technology, LLC"),
+    VendorInfo::new(891, "Delta Controls Germany Limited"),
+    VendorInfo::new(892, "S+T Service & Technique S.A."),
+    VendorInfo::new(893, "SimpleSoft"),
+    VendorInfo::new(894, "Altair Engineering"),
+    VendorInfo::new(895, "EZEN Solution Inc."),
+    VendorInfo::new(896, "Fujitec Co. Ltd."),
+    VendorInfo::new(897, "Terralux"),
+    VendorInfo::new(898, "Annicom"),
+    VendorInfo::new(899, "Bihl+Wiedemann GmbH"),
+    VendorInfo::new(900, "Draper, Inc."),
+    VendorInfo::new(901, "Schüco International KG"),
+    VendorInfo::new(902, "Otis Elevator Company"),
+    VendorInfo::new(903, "Fidelix Oy"),
+    VendorInfo::new(904, "RAM GmbH Mess- und Regeltechnik"),
+    VendorInfo::new(905, "WEMS"),
+    VendorInfo::new(906, "Ravel Electronics Pvt Ltd"),
+    VendorInfo::new(907, "OmniMagni"),
+    VendorInfo::new(908, "Echelon"),
+    VendorInfo::new(909, "Intellimeter Canada, Inc."),
+    VendorInfo::new(910, "Bithouse Oy"),
+    VendorInfo::new(911, "Reserved for ASHRAE"),
+    VendorInfo::new(912, "BuildPulse"),
+    VendorInfo::new(913, "Shenzhen 1000 Building Automation Co. Ltd"),
+    VendorInfo::new(914, "AED Engineering GmbH"),
+    VendorInfo::new(915, "Güntner GmbH & Co. KG"),
+    VendorInfo::new(916, "KNXlogic"),
+    VendorInfo::new(917, "CIM Environmental Group"),
+    VendorInfo::new(918, "Flow Control"),
+    VendorInfo::new(919, "Lumen Cache, Inc."),
+    VendorInfo::new(920, "Ecosystem"),
+    VendorInfo::new(921, "Potter Electric Signal Company, LLC"),
+    VendorInfo::new(922, "Tyco Fire & Security S.p.A."),
+    VendorInfo::new(923, "Watanabe Electric Industry Co., Ltd."),
+    VendorInfo::new(924, "Causam Energy"),
+    VendorInfo::new(925, "W-tec AG"),
+    VendorInfo::new(926, "IMI Hydronic Engineering International SA"),
+    VendorInfo::new(927, "ARIGO Software"),
+    VendorInfo::new(928, "MSA Safety"),
+    VendorInfo::new(929, "Smart Solucoes Ltda – MERCATO"),
+    VendorInfo::new(930, "PIATRA Engineering"),
+    VendorInfo::new(931, "ODIN Automation Systems, LLC"),
+    VendorInfo::new(932, "Belparts NV"),
+    VendorInfo::new(933, "UAB, SALDA"),
+    VendorInfo::new(934, "Alre-IT Regeltechnik GmbH"),
+    VendorInfo::new(935, "Ingenieurbüro H. Lertes GmbH & Co. KG"),
+    VendorInfo::new(936, "Breathing Buildings"),
+    VendorInfo::new(937, "eWON SA"),
+    VendorInfo::new(938, "Cav. Uff. Giacomo Cimberio S.p.A"),
+    VendorInfo::new(939, "PKE Electronics AG"),
+    VendorInfo::new(940, "Allen"),
+    VendorInfo::new(941, "Kastle Systems"),
+    VendorInfo::new(942, "Logical Electro-Mechanical (EM) Systems, Inc."),
+    VendorInfo::new(943, "ppKinetics Instruments, LLC"),
+    VendorInfo::new(944, "Cathexis Technologies"),
+    VendorInfo::new(945, "Sylop sp. Z o.o. sp.k"),
+    VendorInfo::new(946, "Brauns Control GmbH"),
+    VendorInfo::new(947, "OMRON SOCIAL SOLUTIONS CO., LTD."),
+    VendorInfo::new(948, "Wildeboer Bauteile Gmbh"),
+    VendorInfo::new(949, "Shanghai Biens Technologies Ltd"),
+    VendorInfo::new(950, "Beijing HZHY Technology Co., Ltd"),
+    VendorInfo::new(951, "Building Clouds"),
+    VendorInfo::new(
         952,
         "The University of Sheffield-Department of Electronic and Electrical Engineering",
     ),
-    (953, "Fabtronics Australia Pty Ltd"),
-    (954, "SLAT"),
-    (955, "Software Motor Corporation"),
-    (956, "Armstrong International Inc."),
-    (957, "Steril-Aire, Inc."),
-    (958, "Infinique"),
-    (959, "Arcom"),
-    (960, "Argo Performance, Ltd"),
-    (961, "Dialight"),
-    (962, "Ideal Technical Solutions"),
-    (963, "Neurobat AG"),
-    (964, "Neyer Software Consulting LLC"),
-    (965, "SCADA Technology Development Co., Ltd."),
-    (966, "Demand Logic Limited"),
-    (967, "GWA Group Limited"),
-    (968, "Occitaline"),
-    (969, "NAO Digital Co., Ltd."),
-    (970, "Shenzhen Chanslink Network Technology Co., Ltd."),
-    (971, "Samsung Electronics Co., Ltd."),
-    (972, "Mesa Laboratories, Inc."),
-    (973, "Fischer"),
-    (974, "OpSys Solutions Ltd."),
-    (975, "Advanced Devices Limited"),
-    (976, "Condair"),
-    (977, "INELCOM Ingenieria Electronica Comercial S.A."),
-    (978, "GridPoint, Inc."),
-    (979, "ADF Technologies Sdn Bhd"),
-    (980, "EPM, Inc."),
-    (981, "Lighting Controls Ltd"),
-    (982, "Perix Controls Ltd."),
-    (983, "AERCO International, Inc."),
-    (984, "KONE Inc."),
-    (985, "Ziehl-Abegg SE"),
-    (986, "Robot, S.A."),
-    (987, "Optigo Networks, Inc."),
-    (988, "Openmotics BVBA"),
-    (989, "Metropolitan Industries, Inc."),
-    (990, "Huawei Technologies Co., Ltd."),
-    (991, "Digital Lumens, Inc."),
-    (992, "Vanti"),
-    (993, "Cree Lighting"),
-    (994, "Richmond Heights SDN BHD"),
-    (995, "Payne-Sparkman Lighting Mangement"),
-    (996, "Ashcroft"),
-    (997, "Jet Controls Corp"),
-    (998, "Zumtobel Lighting GmbH"),
-    (999, "Reserved for ASHRAE"),
-    (1000, "Ekon GmbH"),
-    (1001, "Molex"),
-    (1002, "Maco Lighting Pty Ltd."),
-    (1003, "Axecon Corp."),
-    (1004, "Tensor plc"),
-    (
+    VendorInfo::new(953, "Fabtronics Australia Pty Ltd"),
+    VendorInfo::new(954, "SLAT"),
+    VendorInfo::new(955, "Software Motor Corporation"),
+    VendorInfo::new(956, "Armstrong International Inc."),
+    VendorInfo::new(957, "Steril-Aire, Inc."),
+    VendorInfo::new(958, "Infinique"),
+    VendorInfo::new(959, "Arcom"),
+    VendorInfo::new(960, "Argo Performance, Ltd"),
+    VendorInfo::new(961, "Dialight"),
+    VendorInfo::new(962, "Ideal Technical Solutions"),
+    VendorInfo::new(963, "Neurobat AG"),
+    VendorInfo::new(964, "Neyer Software Consulting LLC"),
+    VendorInfo::new(965, "SCADA Technology Development Co., Ltd."),
+    VendorInfo::new(966, "Demand Logic Limited"),
+    VendorInfo::new(967, "GWA Group Limited"),
+    VendorInfo::new(968, "Occitaline"),
+    VendorInfo::new(969, "NAO Digital Co., Ltd."),
+    VendorInfo::new(970, "Shenzhen Chanslink Network Technology Co., Ltd."),
+    VendorInfo::new(971, "Samsung Electronics Co., Ltd."),
+    VendorInfo::new(972, "Mesa Laboratories, Inc."),
+    VendorInfo::new(973, "Fischer"),
+    VendorInfo::new(974, "OpSys Solutions Ltd."),
+    VendorInfo::new(975, "Advanced Devices Limited"),
+    VendorInfo::new(976, "Condair"),
+    VendorInfo::new(977, "INELCOM Ingenieria Electronica Comercial S.A."),
+    VendorInfo::new(978, "GridPoint, Inc."),
+    VendorInfo::new(979, "ADF Technologies Sdn Bhd"),
+    VendorInfo::new(980, "EPM, Inc."),
+    VendorInfo::new(981, "Lighting Controls Ltd"),
+    VendorInfo::new(982, "Perix Controls Ltd."),
+    VendorInfo::new(983, "AERCO International, Inc."),
+    VendorInfo::new(984, "KONE Inc."),
+    VendorInfo::new(985, "Ziehl-Abegg SE"),
+    VendorInfo::new(986, "Robot, S.A."),
+    VendorInfo::new(987, "Optigo Networks, Inc."),
+    VendorInfo::new(988, "Openmotics BVBA"),
+    VendorInfo::new(989, "Metropolitan Industries, Inc."),
+    VendorInfo::new(990, "Huawei Technologies Co., Ltd."),
+    VendorInfo::new(991, "Digital Lumens, Inc."),
+    VendorInfo::new(992, "Vanti"),
+    VendorInfo::new(993, "Cree Lighting"),
+    VendorInfo::new(994, "Richmond Heights SDN BHD"),
+    VendorInfo::new(995, "Payne-Sparkman Lighting Mangement"),
+    VendorInfo::new(996, "Ashcroft"),
+    VendorInfo::new(997, "Jet Controls Corp"),
+    VendorInfo::new(998, "Zumtobel Lighting GmbH"),
+    VendorInfo::new(999, "Reserved for ASHRAE"),
+    VendorInfo::new(1000, "Ekon GmbH"),
+    VendorInfo::new(1001, "Molex"),
+    VendorInfo::new(1002, "Maco Lighting Pty Ltd."),
+    VendorInfo::new(1003, "Axecon Corp."),
+    VendorInfo::new(1004, "Tensor plc"),
+    VendorInfo::new(
         1005,
         "Kaseman Environmental Control Equipment (Shanghai) Limited",
     ),
-    (1006, "AB Axis Industries"),
-    (1007, "Netix Controls"),
-    (1008, "Eldridge Products, Inc."),
-    (1009, "Micronics"),
-    (1010, "Fortecho Solutions Ltd"),
-    (1011, "Sellers Manufacturing Company"),
-    (1012, "Rite-Hite Doors, Inc."),
-    (1013, "Violet Defense LLC"),
-    (1014, "Simna"),
-    (1015, "Multi-Énergie Best Inc."),
-    (1016, "Mega System Technologies, Inc."),
-    (1017, "Rheem"),
-    (1018, "Ing. Punzenberger COPA-DATA GmbH"),
-    (1019, "MEC Electronics GmbH"),
-    (1020, "Taco Comfort Solutions"),
-    (1021, "Alexander Maier GmbH"),
-    (1022, "Ecorithm, Inc."),
-    (1023, "Accurro Ltd"),
-    (1024, "ROMTECK Australia Pty Ltd"),
-    (1025, "Splash Monitoring Limited"),
-    (1026, "Light Application"),
-    (1027, "Logical Building Automation"),
-    (1028, "Exilight Oy"),
-    (1029, "Hager Electro SAS"),
-    (1030, "KLIF Co., LTD"),
-    (1031, "HygroMatik"),
-    (1032, "Daniel Mousseau Programmation & Electronique"),
-    (1033, "Aerionics Inc."),
-    (1034, "M2S Electronique Ltee"),
-    (1035, "Automation Components, Inc."),
-    (1036, "Niobrara Research & Development Corporation"),
-    (1037, "Netcom Sicherheitstechnik GmbH"),
-    (1038, "Lumel S.A."),
-    (1039, "Great Plains Industries, Inc."),
-    (1040, "Domotica Labs S.R.L"),
-    (1041, "Energy Cloud, Inc."),
-    (1042, "Vomatec"),
-    (1043, "Demma Companies"),
-    (1044, "Valsena"),
-    (1045, "Comsys Bärtsch AG"),
-    (1046, "bGrid"),
-    (1047, "MDJ Software Pty Ltd"),
-    (1048, "Dimonoff, Inc."),
-    (1049, "Edomo Systems, GmbH"),
-    (1050, "Effektiv, LLC"),
-    (1051, "SteamOVap"),
-    (1052, "grandcentrix GmbH"),
-    (1053, "Weintek Labs, Inc."),
-    (1054, "Intefox GmbH"),
-    (1055, "Radius22 Automation Company"),
-    (1056, "Ringdale, Inc."),
-    (1057, "Iwaki America"),
-    (1058, "Bractlet"),
-    (1059, "STULZ Air Technology Systems, Inc."),
-    (1060, "Climate Ready Engineering Pty Ltd"),
-    (1061, "Genea Energy Partners"),
-    (1062, "IoTall Chile"),
-    (1063, "IKS Co., Ltd."),
-    (1064, "Yodiwo AB"),
-    (1065, "TITAN electronic GmbH"),
-    (1066, "IDEC Corporation"),
-    (1067, "SIFRI SL"),
-    (1068, "Thermal Gas Systems Inc."),
-    (1069, "Building Automation Products, Inc."),
-    (1070, "Asset Mapping"),
-    (1071, "Smarteh Company"),
-    (1072, "Datapod (Australia) Pty Ltd."),
-    (1073, "Buildings Alive Pty Ltd"),
-    (1074, "Digital Elektronik"),
-    (1075, "Talent Automação e Tecnologia Ltda"),
-    (1076, "Norposh Limited"),
-    (1077, "Merkur Funksysteme AG"),
-    (1078, "Faster CZ spol. S.r.o"),
-    (1079, "Eco-Adapt"),
-    (1080, "Energocentrum Plus, s.r.o"),
-    (1081, "amBX UK Ltd"),
-    (1082, "Western Reserve Controls, Inc."),
-    (1083, "LayerZero Power Systems, Inc."),
-    (1084, "CIC Jan Hřebec s.r.o."),
-    (1085, "Sigrov BV"),
-    (1086, "ISYS-Intelligent Systems"),
-    (1087, "Gas Detection (Australia) Pty Ltd"),
-    (1088, "Kinco Automation (Shanghai) Ltd."),
-    (1089, "Lars Energy, LLC"),
-    (1090, "Flamefast (UK) Ltd."),
-    (1091, "Royal Service Air Conditioning"),
-    (1092, "Ampio Sp. Z o.o."),
-    (1093, "Inovonics Wireless Corporation"),
-    (1094, "Nvent Thermal Management"),
-    (1095, "Sinowell Control System Ltd"),
-    (1096, "Moxa Inc."),
-    (1097, "Matrix iControl SDN BHD"),
-    (1098, "PurpleSwift"),
-    (1099, "OTIM Technologies"),
-    (1100, "FlowMate Limited"),
-    (1101, "Degree Controls, Inc."),
-    (1102, "Fei Xing (Shanghai) Software Technologies Co., Ltd."),
-    (1103, "Berg GmbH"),
-    (1104, "ARENZ.IT"),
-    (1105, "Edelstrom Electronic Devices & Designing LLC"),
-    (1106, "Drive Connect, LLC"),
-    (1107, "DevelopNow"),
-    (1108, "Poort"),
-    (1109, "VMEIL Information (Shanghai) Ltd"),
-    (1110, "Rayleigh Instruments"),
-    (1111, "Reserved for ASHRAE"),
-    (1112, "CODESYS Development"),
-    (1113, "Smartware Technologies Group, LLC"),
-    (1114, "Polar Bear Solutions"),
-    (1115, "Codra"),
-    (1116, "Pharos Architectural Controls Ltd"),
-    (1117, "EngiNear Ltd."),
-    (1118, "Ad Hoc Electronics"),
-    (1119, "Unified Microsystems"),
-    (1120, "Industrieelektronik Brandenburg GmbH"),
-    (1121, "Hartmann GmbH"),
-    (1122, "Piscada"),
-    (1123, "KMB systems, s.r.o."),
-    (1124, "PowerTech Engineering AS"),
-    (1125, "Telefonbau Arthur Schwabe GmbH & Co. KG"),
-    (1126, "Wuxi Fistwelove Technology Co., Ltd."),
-    (1127, "Prysm"),
-    (1128, "STEINEL GmbH"),
-    (1129, "Georg Fischer JRG AG"),
-    (1130, "Make Develop SL"),
-    (1131, "Monnit Corporation"),
-    (1132, "Mirror Life Corporation"),
-    (1133, "Secure Meters Limited"),
-    (1134, "PECO"),
-    (1135, ".CCTECH, Inc."),
-    (1136, "LightFi Limited"),
-    (1137, "Nice Spa"),
-    (1138, "Fiber SenSys, Inc."),
-    (1139, "B&D Buchta und Degeorgi"),
-    (1140, "Ventacity Systems, Inc."),
-    (1141, "Hitachi-Johnson Controls Air Conditioning, Inc."),
-    (1142, "Sage Metering, Inc."),
-    (1143, "Andel Limited"),
-    (1144, "ECOSmart Technologies"),
-    (1145, "S.E.T."),
-    (1146, "Protec Fire Detection Spain SL"),
-    (1147, "AGRAMER UG"),
-    (1148, "Anylink Electronic GmbH"),
-    (1149, "Schindler, Ltd"),
-    (1150, "Jibreel Abdeen Est."),
-    (1151, "Fluidyne Control Systems Pvt. Ltd"),
-    (1152, "Prism Systems, Inc."),
-    (1153, "Enertiv"),
-    (1154, "Mirasoft GmbH & Co. KG"),
-    (1155, "DUALTECH IT"),
-    (1156, "Countlogic, LLC"),
-    (1157, "Kohler"),
-    (1158, "Chen Sen Controls Co., Ltd."),
-    (1159, "Greenheck"),
-    (1160, "Intwine Connect, LLC"),
-    (1161, "Karlborgs Elkontroll"),
-    (1162, "Datakom"),
-    (1163, "Hoga Control AS"),
-    (1164, "Cool Automation"),
-    (1165, "Inter Search Co., Ltd"),
-    (1166, "DABBEL-Automation Intelligence GmbH"),
-    (1167, "Gadgeon Engineering Smartness"),
-    (1168, "Coster Group S.r.l."),
-    (1169, "Walter Müller AG"),
-    (1170, "Fluke"),
-    (1171, "Quintex Systems Ltd"),
-    (1172, "Senfficient SDN BHD"),
-    (1173, "Nube iO Operations Pty Ltd"),
-    (1174, "DAS Integrator Pte Ltd"),
-    (1175, "CREVIS Co., Ltd"),
-    (1176, "iSquared software inc."),
-    (1177, "KTG GmbH"),
-    (1178, "POK Group Oy"),
-    (1179, "Adiscom"),
-    (1180, "Incusense"),
-    (1181, "75F"),
-    (1182, "Anord Mardix, Inc."),
-    (1183, "HOSCH Gebäudeautomation Neue Produkte GmbH"),
-    (1184, "Bosch.IO GmbH"),
-    (1185, "Royal Boon Edam International B.V."),
-    (1186, "Clack Corporation"),
-    (1187, "Unitex Controls LLC"),
-    (1188, "KTC Göteborg AB"),
-    (1189, "Interzon AB"),
-    (1190, "ISDE ING SL"),
-    (1191, "ABM automation building messaging GmbH"),
-    (1192, "Kentec Electronics Ltd"),
-    (1193, "Copeland LP"),
-    (1194, "Powerside"),
-    (1195, "SMC Group"),
-    (1196, "EOS Weather Instruments"),
-    (1197, "Zonex Systems"),
-    (1198, "Generex Systems Computervertriebsgesellschaft mbH"),
-    (1199, "Energy Wall LLC"),
-    (1200, "Thermofin"),
-    (1201, "SDATAWAY SA"),
-    (1202, "Biddle Air Systems Limited"),
-    (1203, "Kessler Ellis Products"),
-    (1204, "Thermoscreens"),
-    (1205, "Modio"),
-    (1206, "Newron Solutions"),
-    (1207, "Unitronics"),
-    (1208, "TRILUX GmbH & Co. KG"),
-    (1209, "Kollmorgen Steuerungstechnik GmbH"),
-    (1210, "Bosch Rexroth AG"),
-    (1211, "Alarko Carrier"),
-    (1212, "Verdigris Technologies"),
-    (1213, "Shanghai SIIC-Longchuang Smartech So., Ltd."),
-    (1214, "Quinda Co."),
-    (1215, "GRUNER AG"),
-    (1216, "BACMOVE"),
-    (1217, "PSIDAC AB"),
-    (1218, "ISICON-Control Automation"),
-    (1219, "Big Ass Fans"),
-    (1220, "din – Dietmar Nocker Facility Management GmbH"),
-    (1221, "Teldio"),
-    (1222, "MIKROKLIMA s.r.o."),
-    (1223, "Density"),
-    (1224, "ICONAG-Leittechnik GmbH"),
-    (1225, "Awair"),
-    (1226, "T&D Engineering, Ltd"),
-    (1227, "Sistemas Digitales"),
-    (1228, "Loxone Electronics GmbH"),
-    (1229, "ActronAir"),
-    (1230, "Inductive Automation"),
-    (1231, "Thor Engineering GmbH"),
-    (1232, "Berner International, LLC"),
-    (1233, "Potsdam Sensors LLC"),
-    (1234, "Kohler Mira Ltd"),
-    (1235, "Tecomon GmbH"),
-    (1236, "Two Dimensional Instruments, LLC"),
-    (1237, "LEFA Technologies Pte. Ltd."),
-    (1238, "EATON CEAG Notlichtsysteme GmbH"),
-    (1239, "Commbox Tecnologia"),
-    (1240, "IPVideo Corporation"),
-    (1241, "Bender GmbH & Co. KG"),
-    (1242, "Rhymebus Corporation"),
-    (1243, "Axon Systems Ltd"),
-    (1244, "Engineered Air"),
-    (1245, "Elipse Software Ltda"),
-    (1246, "Simatix Building Technologies Pvt. Ltd."),
-    (1247, "W.A. Benjamin Electric Co."),
-    (1248, "TROX Air Conditioning Components (Suzhou) Co. Ltd."),
-    (1249, "SC Medical Pty Ltd."),
-    (1250, "Elcanic A/S"),
-    (1251, "Obeo AS"),
-    (1252, "Tapa, Inc."),
-    (1253, "ASE Smart Energy, Inc."),
-    (1254, "Performance Services, Inc."),
-    (1255, "Veridify Security"),
-    (1256, "CD Innovation LTD"),
-    (1257, "Ben Peoples Industries, LLC"),
-    (1258, "UNICOMM Sp. z o.o"),
-    (1259, "Thing Technologies GmbH"),
-    (1260, "Beijing Hailin Control Technology, Inc."),
-    (1261, "Digital Realty"),
-    (1262, "Agrowtek Inc."),
-    (1263, "DSP Innovation BV"),
-    (1264, "STV Electronic GmbH"),
-    (1265, "Elmeasure India Pvt Ltd."),
-    (1266, "Pineshore Energy LLC"),
-    (1267, "Brasch Environmental Technologies, LLC"),
-    (1268, "Lion Controls Co., LTD"),
-    (1269, "Sinux"),
-    (1270, "Avnet Inc."),
-    (1271, "Somfy Activites SA"),
-    (1272, "Amico"),
-    (1273, "SageGlass"),
-    (1274, "AuVerte"),
-    (1275, "Agile Connects Pvt. Ltd."),
-    (1276, "Locimation Pty Ltd"),
-    (1277, "Envio Systems GmbH"),
-    (1278, "Voytech Systems Limited"),
-    (1279, "Davidsmeyer und Paul GmbH"),
-    (1280, "Lusher Engineering Services"),
-    (1281, "CHNT Nanjing Techsel Intelligent Company LTD"),
-    (1282, "Threetronics Pty Ltd"),
-    (1283, "SkyFoundry, LLC"),
-    (1284, "HanilProTech"),
-    (1285, "Sensorscall"),
-    (1286, "Shanghai Jingpu Information Technology, Co., Ltd."),
-    (1287, "Lichtmanufaktur Berlin GmbH"),
-    (1288, "Eco Parking Technologies"),
-    (1289, "Envision Digital International Pte Ltd"),
-    (1290, "Antony Developpement Electronique"),
-    (1291, "i2systems"),
-    (1292, "Thureon International Limited"),
-    (1293, "Pulsafeeder"),
-    (1294, "MegaChips Corporation"),
-    (1295, "TES Controls"),
-    (1296, "Cermate"),
-    (1297, "Grand Valley State University"),
-    (1298, "Symcon Gmbh"),
-    (1299, "The Chicago Faucet Company"),
-    (1300, "Geberit AG"),
-    (1301, "Rex Controls"),
-    (1302, "IVMS GmbH"),
-    (1303, "MNPP Saturn Ltd."),
-    (1304, "Regal Beloit"),
-    (1305, "ACS-Air Conditioning Solutions"),
-    (1306, "GBX Technology, LLC"),
-    (1307, "Kaiterra"),
-    (1308, "ThinKuan loT Technology (Shanghai) Co., Ltd"),
-    (1309, "HoCoSto B.V."),
-    (1310, "Shenzhen AS-AI Technology Co., Ltd."),
-    (1311, "RPS S.p.a."),
-    (1312, "Esmé solutions"),
-    (1313, "IOTech Systems Limited"),
-    (1314, "i-AutoLogic Co., Ltd."),
-    (1315, "New Age Micro, LLC"),
-    (1316, "Guardian Glass"),
-    (1317, "Guangzhou Zhaoyu Information Technology"),
-    (1318, "ACE IoT Solutions LLC"),
-    (1319, "Poris Electronics Co., Ltd."),
-    (1320, "Terminus Technologies Group"),
-    (1321, "Intech 21, Inc."),
-    (1322, "Accurate Electronics"),
-    (1323, "Fluence Bioengineering"),
-    (1324, "Mun Hean Singapore Pte Ltd"),
-    (1325, "Katronic AG & Co. KG"),
-    (1326, "Suzhou XinAo Information Technology Co. Ltd"),
-    (1327, "Linktekk Technology, JSC."),
-    (1328, "Stirling Ultracold"),
-    (1329, "UV Partners, Inc."),
-    (1330, "ProMinent GmbH"),
-    (1331, "Multi-Tech Systems, Inc."),
-    (1332, "JUMO GmbH & Co. KG"),
-    (1333, "Qingdao Huarui Technology Co. Ltd.,"),
-    (1334, "Cairn Systemes"),
-    (1335, "NeuroLogic Research Corp."),
-    (1336, "Transition Technologies Advanced Solutions Sp. z o.o"),
-    (1337, "Xxter bv"),
-    (1338, "PassiveLogic"),
-    (1339, "EnSmart Controls"),
-    (1340, "Watts Heating and Hot Water Solutions, dba Lync"),
-    (1341, "Troposphaira Technologies LLP"),
-    (1342, "Network Thermostat"),
-    (1343, "Titanium Intelligent Solutions, LLC"),
-    (1344, "Numa Products, LLC"),
-    (1345, "WAREMA Renkhoff SE"),
-    (1346, "Frese A/S"),
-    (1347, "Mapped"),
-    (1348, "ELEKTRODESIGN ventilatory s.r.o"),
-    (1349, "AirCare Automation, Inc."),
-    (1350, "Antrum"),
-    (1351, "Bao Linh Connect Technology"),
-    (1352, "Virginia Controls, LLC"),
-    (1353, "Duosys SDN BHD"),
-    (1354, "Onsen SAS"),
-    (1355, "Vaughn Thermal Corporation"),
-    (1356, "Thermoplastic Engineering Ltd (TPE)"),
-    (1357, "Wirth Research Ltd."),
-    (1358, "SST Automation"),
-    (1359, "Shanghai Bencol Electronic Technology Co., Ltd"),
-    (1360, "AIWAA Systems Private Limited"),
-    (1361, "Enless Wireless"),
-    (1362, "Ozuno Engineering Pty Ltd"),
-    (1363, "Hubbell, The Electric Heater Company"),
-    (1364, "Industrial Turnaround Corporation (ITAC)"),
-    (1365, "Wadsworth Control Systems"),
-    (1366, "Services Hilo Inc."),
-    (1367, "iDM Energiesysteme GmbH"),
-    (1368, "BeNext B.V."),
-    (1369, "CleanAir.ai Corporation"),
-    (1370, "Revolution Microelectronics (America) Inc."),
-    (1371, "Real-Time Systems GmbH"),
-    (1372, "ZedBee Technologies Pvt Ltd"),
-    (1373, "Wimate Technology Solutions Pvt. Ltd."),
-    (1374, "Senticon Ltd."),
-    (1375, "Rossaker AB"),
-    (1376, "OPIT Solutions Ltd"),
-    (1377, "Hotowell International Co., Limited"),
-    (1378, "Inim Electronics S.R.L. Unipersonale"),
-    (1379, "Airthings ASA"),
-    (1380, "Analog Devices, Inc."),
-    (1381, "AIDirections DMCC"),
-    (1382, "Prima Electro S.p.A."),
-    (1383, "KLT Control System Ltd."),
-    (1384, "Evolution Controls Inc."),
-    (1385, "Bever Innovations"),
-    (1386, "Pelican Wireless Systems"),
-    (1387, "Control Concepts Inc."),
-    (1388, "Augmatic Technologies Pvt. Ltd."),
-    (1389, "Xiamen Milesight loT Co., Ltd"),
-    (1390, "Tianjin Anjie loT Schience and Technology Co., Ltd"),
-    (1391, "Guangzhou S. Energy Electronics Technology Co. Ltd."),
-    (1392, "AKVO Atmospheric Water Systems Pvt. Ltd."),
-    (1393, "EmFirst Co. Ltd."),
-    (1394, "Iion Systems ApS"),
-    (1396, "SAF Tehnika JSC"),
-    (1397, "Komfort IQ, Inc."),
-    (1398, "CoolTera Limited"),
-    (1399, "Hadron Solutions S.r.l.s"),
-    (1401, "Bitpool"),
-    (1402, "Sonicu, LLC"),
-    (1403, "Rishabh Instruments Limited"),
-    (1404, "Thing Warehouse LLC"),
-    (1405, "Innofriends GmbH"),
-    (1406, "Metronic AKP Sp. J."),
-    (1407, "Techknave"),
-    (1408, "Elsner Elektronik"),
-    (1409, "LEFOO Industrial (Hangzhou) Co., Ltd."),
-    (1410, "Calibration Technologies, Inc."),
-    (1411, "Allorado"),
-    (1412, "Verkada"),
-    (1413, "Wattsense"),
-    (1414, "Emerson Automation Solutions"),
-    (1415, "Growlink"),
-    (1416, "Olympia Electronics"),
-    (1417, "Normal Software, Inc."),
-    (1418, "ST Engineering Solution JSC"),
-    (1419, "Industrial Flow Solutions"),
-    (1420, "Ubiqisense ApS"),
-    (1421, "Tiger-Soft"),
-    (1422, "Ecodom Srl"),
-    (1423, "Bilgipro IoT Systems"),
-    (1424, "planspur netdesign GmbH"),
-    (1425, "Dolphin Solutions Ltd"),
-    (1426, "Mitsubishi Electric Corporation, Kobe Works"),
-    (1427, "Ecovena"),
-    (1428, "Gree Electric Appliances Inc of Zhuhai"),
-    (1429, "Conspec Controls"),
-    (1430, "Hangzhou Hikvision Digital Technology Co., Ltd."),
-    (1431, "Crystal Peak Security"),
-    (1432, "PermAlert"),
-    (1433, "Zhejiang Misilin Technology Co., Ltd."),
-    (1434, "Dekker Vacuum Technologies"),
-    (1435, "Edwards Limited"),
-    (1436, "Leybold GmbH"),
-    (1437, "International Gas Detectors"),
-    (1438, "Atlas Copco Airpower NV"),
-    (1439, "Air Sentry Limited"),
-    (1440, "Aelsys"),
-    (1441, "Granby Consulting LLC"),
-    (1442, "Clever Relay"),
-    (1443, "Monico Monitoring, Inc."),
-    (1444, "Oqdo"),
-    (1445, "Matrix Comsec Private Limited"),
-    (1446, "Resource Solutions"),
-    (1447, "American Gas Safety, LLC"),
-    (1448, "S&S Northern Ltd."),
-    (1449, "Ulbios Techsens"),
-    (1450, "Bowery Farming, Inc."),
-    (1451, "Ryobi Limited"),
-    (1452, "EkkoSense Ltd"),
-    (1453, "ClimaCool"),
-    (1454, "Grid Connect Inc."),
-    (1455, "Ziegler Instrumentation UK Ltd"),
-    (1456, "ControlTec, LLC"),
-    (1457, "Aeterlink Corporation"),
-    (1458, "Alpha Epsilon Automation"),
-    (1459, "Astralite Inc."),
-    (1460, "Delta Fire Ltda."),
-    (1461, "Bock Water Heaters, Inc."),
-    (1462, "Cleaver-Brooks"),
-    (1463, "Scanalytics, Inc."),
-    (1464, "WI6LABS"),
-    (1465, "AC&T System Co., Ltd"),
-    (1466, "VergeSense"),
-    (1467, "Systemair Mfg. Inc."),
-    (1468, "Seeley International"),
-    (1469, "Crane Building Services & Utilities"),
-    (1470, "Brady Corporation"),
-    (
+    VendorInfo::new(1006, "AB Axis Industries"),
+    VendorInfo::new(1007, "Netix Controls"),
+    VendorInfo::new(1008, "Eldridge Products, Inc."),
+    VendorInfo::new(1009, "Micronics"),
+    VendorInfo::new(1010, "Fortecho Solutions Ltd"),
+    VendorInfo::new(1011, "Sellers Manufacturing Company"),
+    VendorInfo::new(1012, "Rite-Hite Doors, Inc."),
+    VendorInfo::new(1013, "Violet Defense LLC"),
+    VendorInfo::new(1014, "Simna"),
+    VendorInfo::new(1015, "Multi-Énergie Best Inc."),
+    VendorInfo::new(1016, "Mega System Technologies, Inc."),
+    VendorInfo::new(1017, "Rheem"),
+    VendorInfo::new(1018, "Ing. Punzenberger COPA-DATA GmbH"),
+    VendorInfo::new(1019, "MEC Electronics GmbH"),
+    VendorInfo::new(1020, "Taco Comfort Solutions"),
+    VendorInfo::new(1021, "Alexander Maier GmbH"),
+    VendorInfo::new(1022, "Ecorithm, Inc."),
+    VendorInfo::new(1023, "Accurro Ltd"),
+    VendorInfo::new(1024, "ROMTECK Australia Pty Ltd"),
+    VendorInfo::new(1025, "Splash Monitoring Limited"),
+    VendorInfo::new(1026, "Light Application"),
+    VendorInfo::new(1027, "Logical Building Automation"),
+    VendorInfo::new(1028, "Exilight Oy"),
+    VendorInfo::new(1029, "Hager Electro SAS"),
+    VendorInfo::new(1030, "KLIF Co., LTD"),
+    VendorInfo::new(1031, "HygroMatik"),
+    VendorInfo::new(1032, "Daniel Mousseau Programmation & Electronique"),
+    VendorInfo::new(1033, "Aerionics Inc."),
+    VendorInfo::new(1034, "M2S Electronique Ltee"),
+    VendorInfo::new(1035, "Automation Components, Inc."),
+    VendorInfo::new(1036, "Niobrara Research & Development Corporation"),
+    VendorInfo::new(1037, "Netcom Sicherheitstechnik GmbH"),
+    VendorInfo::new(1038, "Lumel S.A."),
+    VendorInfo::new(1039, "Great Plains Industries, Inc."),
+    VendorInfo::new(1040, "Domotica Labs S.R.L"),
+    VendorInfo::new(1041, "Energy Cloud, Inc."),
+    VendorInfo::new(1042, "Vomatec"),
+    VendorInfo::new(1043, "Demma Companies"),
+    VendorInfo::new(1044, "Valsena"),
+    VendorInfo::new(1045, "Comsys Bärtsch AG"),
+    VendorInfo::new(1046, "bGrid"),
+    VendorInfo::new(1047, "MDJ Software Pty Ltd"),
+    VendorInfo::new(1048, "Dimonoff, Inc."),
+    VendorInfo::new(1049, "Edomo Systems, GmbH"),
+    VendorInfo::new(1050, "Effektiv, LLC"),
+    VendorInfo::new(1051, "SteamOVap"),
+    VendorInfo::new(1052, "grandcentrix GmbH"),
+    VendorInfo::new(1053, "Weintek Labs, Inc."),
+    VendorInfo::new(1054, "Intefox GmbH"),
+    VendorInfo::new(1055, "Radius22 Automation Company"),
+    VendorInfo::new(1056, "Ringdale, Inc."),
+    VendorInfo::new(1057, "Iwaki America"),
+    VendorInfo::new(1058, "Bractlet"),
+    VendorInfo::new(1059, "STULZ Air Technology Systems, Inc."),
+    VendorInfo::new(1060, "Climate Ready Engineering Pty Ltd"),
+    VendorInfo::new(1061, "Genea Energy Partners"),
+    VendorInfo::new(1062, "IoTall Chile"),
+    VendorInfo::new(1063, "IKS Co., Ltd."),
+    VendorInfo::new(1064, "Yodiwo AB"),
+    VendorInfo::new(1065, "TITAN electronic GmbH"),
+    VendorInfo::new(1066, "IDEC Corporation"),
+    VendorInfo::new(1067, "SIFRI SL"),
+    VendorInfo::new(1068, "Thermal Gas Systems Inc."),
+    VendorInfo::new(1069, "Building Automation Products, Inc."),
+    VendorInfo::new(1070, "Asset Mapping"),
+    VendorInfo::new(1071, "Smarteh Company"),
+    VendorInfo::new(1072, "Datapod (Australia) Pty Ltd."),
+    VendorInfo::new(1073, "Buildings Alive Pty Ltd"),
+    VendorInfo::new(1074, "Digital Elektronik"),
+    VendorInfo::new(1075, "Talent Automação e Tecnologia Ltda"),
+    VendorInfo::new(1076, "Norposh Limited"),
+    VendorInfo::new(1077, "Merkur Funksysteme AG"),
+    VendorInfo::new(1078, "Faster CZ spol. S.r.o"),
+    VendorInfo::new(1079, "Eco-Adapt"),
+    VendorInfo::new(1080, "Energocentrum Plus, s.r.o"),
+    VendorInfo::new(1081, "amBX UK Ltd"),
+    VendorInfo::new(1082, "Western Reserve Controls, Inc."),
+    VendorInfo::new(1083, "LayerZero Power Systems, Inc."),
+    VendorInfo::new(1084, "CIC Jan Hřebec s.r.o."),
+    VendorInfo::new(1085, "Sigrov BV"),
+    VendorInfo::new(1086, "ISYS-Intelligent Systems"),
+    VendorInfo::new(1087, "Gas Detection (Australia) Pty Ltd"),
+    VendorInfo::new(1088, "Kinco Automation (Shanghai) Ltd."),
+    VendorInfo::new(1089, "Lars Energy, LLC"),
+    VendorInfo::new(1090, "Flamefast (UK) Ltd."),
+    VendorInfo::new(1091, "Royal Service Air Conditioning"),
+    VendorInfo::new(1092, "Ampio Sp. Z o.o."),
+    VendorInfo::new(1093, "Inovonics Wireless Corporation"),
+    VendorInfo::new(1094, "Nvent Thermal Management"),
+    VendorInfo::new(1095, "Sinowell Control System Ltd"),
+    VendorInfo::new(1096, "Moxa Inc."),
+    VendorInfo::new(1097, "Matrix iControl SDN BHD"),
+    VendorInfo::new(1098, "PurpleSwift"),
+    VendorInfo::new(1099, "OTIM Technologies"),
+    VendorInfo::new(1100, "FlowMate Limited"),
+    VendorInfo::new(1101, "Degree Controls, Inc."),
+    VendorInfo::new(1102, "Fei Xing (Shanghai) Software Technologies Co., Ltd."),
+    VendorInfo::new(1103, "Berg GmbH"),
+    VendorInfo::new(1104, "ARENZ.IT"),
+    VendorInfo::new(1105, "Edelstrom Electronic Devices & Designing LLC"),
+    VendorInfo::new(1106, "Drive Connect, LLC"),
+    VendorInfo::new(1107, "DevelopNow"),
+    VendorInfo::new(1108, "Poort"),
+    VendorInfo::new(1109, "VMEIL Information (Shanghai) Ltd"),
+    VendorInfo::new(1110, "Rayleigh Instruments"),
+    VendorInfo::new(1111, "Reserved for ASHRAE"),
+    VendorInfo::new(1112, "CODESYS Development"),
+    VendorInfo::new(1113, "Smartware Technologies Group, LLC"),
+    VendorInfo::new(1114, "Polar Bear Solutions"),
+    VendorInfo::new(1115, "Codra"),
+    VendorInfo::new(1116, "Pharos Architectural Controls Ltd"),
+    VendorInfo::new(1117, "EngiNear Ltd."),
+    VendorInfo::new(1118, "Ad Hoc Electronics"),
+    VendorInfo::new(1119, "Unified Microsystems"),
+    VendorInfo::new(1120, "Industrieelektronik Brandenburg GmbH"),
+    VendorInfo::new(1121, "Hartmann GmbH"),
+    VendorInfo::new(1122, "Piscada"),
+    VendorInfo::new(1123, "KMB systems, s.r.o."),
+    VendorInfo::new(1124, "PowerTech Engineering AS"),
+    VendorInfo::new(1125, "Telefonbau Arthur Schwabe GmbH & Co. KG"),
+    VendorInfo::new(1126, "Wuxi Fistwelove Technology Co., Ltd."),
+    VendorInfo::new(1127, "Prysm"),
+    VendorInfo::new(1128, "STEINEL GmbH"),
+    VendorInfo::new(1129, "Georg Fischer JRG AG"),
+    VendorInfo::new(1130, "Make Develop SL"),
+    VendorInfo::new(1131, "Monnit Corporation"),
+    VendorInfo::new(1132, "Mirror Life Corporation"),
+    VendorInfo::new(1133, "Secure Meters Limited"),
+    VendorInfo::new(1134, "PECO"),
+    VendorInfo::new(1135, ".CCTECH, Inc."),
+    VendorInfo::new(1136, "LightFi Limited"),
+    VendorInfo::new(1137, "Nice Spa"),
+    VendorInfo::new(1138, "Fiber SenSys, Inc."),
+    VendorInfo::new(1139, "B&D Buchta und Degeorgi"),
+    VendorInfo::new(1140, "Ventacity Systems, Inc."),
+    VendorInfo::new(1141, "Hitachi-Johnson Controls Air Conditioning, Inc."),
+    VendorInfo::new(1142, "Sage Metering, Inc."),
+    VendorInfo::new(1143, "Andel Limited"),
+    VendorInfo::new(1144, "ECOSmart Technologies"),
+    VendorInfo::new(1145, "S.E.T."),
+    VendorInfo::new(1146, "Protec Fire Detection Spain SL"),
+    VendorInfo::new(1147, "AGRAMER UG"),
+    VendorInfo::new(1148, "Anylink Electronic GmbH"),
+    VendorInfo::new(1149, "Schindler, Ltd"),
+    VendorInfo::new(1150, "Jibreel Abdeen Est."),
+    VendorInfo::new(1151, "Fluidyne Control Systems Pvt. Ltd"),
+    VendorInfo::new(1152, "Prism Systems, Inc."),
+    VendorInfo::new(1153, "Enertiv"),
+    VendorInfo::new(1154, "Mirasoft GmbH & Co. KG"),
+    VendorInfo::new(1155, "DUALTECH IT"),
+    VendorInfo::new(1156, "Countlogic, LLC"),
+    VendorInfo::new(1157, "Kohler"),
+    VendorInfo::new(1158, "Chen Sen Controls Co., Ltd."),
+    VendorInfo::new(1159, "Greenheck"),
+    VendorInfo::new(1160, "Intwine Connect, LLC"),
+    VendorInfo::new(1161, "Karlborgs Elkontroll"),
+    VendorInfo::new(1162, "Datakom"),
+    VendorInfo::new(1163, "Hoga Control AS"),
+    VendorInfo::new(1164, "Cool Automation"),
+    VendorInfo::new(1165, "Inter Search Co., Ltd"),
+    VendorInfo::new(1166, "DABBEL-Automation Intelligence GmbH"),
+    VendorInfo::new(1167, "Gadgeon Engineering Smartness"),
+    VendorInfo::new(1168, "Coster Group S.r.l."),
+    VendorInfo::new(1169, "Walter Müller AG"),
+    VendorInfo::new(1170, "Fluke"),
+    VendorInfo::new(1171, "Quintex Systems Ltd"),
+    VendorInfo::new(1172, "Senfficient SDN BHD"),
+    VendorInfo::new(1173, "Nube iO Operations Pty Ltd"),
+    VendorInfo::new(1174, "DAS Integrator Pte Ltd"),
+    VendorInfo::new(1175, "CREVIS Co., Ltd"),
+    VendorInfo::new(1176, "iSquared software inc."),
+    VendorInfo::new(1177, "KTG GmbH"),
+    VendorInfo::new(1178, "POK Group Oy"),
+    VendorInfo::new(1179, "Adiscom"),
+    VendorInfo::new(1180, "Incusense"),
+    VendorInfo::new(1181, "75F"),
+    VendorInfo::new(1182, "Anord Mardix, Inc."),
+    VendorInfo::new(1183, "HOSCH Gebäudeautomation Neue Produkte GmbH"),
+    VendorInfo::new(1184, "Bosch.IO GmbH"),
+    VendorInfo::new(1185, "Royal Boon Edam International B.V."),
+    VendorInfo::new(1186, "Clack Corporation"),
+    VendorInfo::new(1187, "Unitex Controls LLC"),
+    VendorInfo::new(1188, "KTC Göteborg AB"),
+    VendorInfo::new(1189, "Interzon AB"),
+    VendorInfo::new(1190, "ISDE ING SL"),
+    VendorInfo::new(1191, "ABM automation building messaging GmbH"),
+    VendorInfo::new(1192, "Kentec Electronics Ltd"),
+    VendorInfo::new(1193, "Copeland LP"),
+    VendorInfo::new(1194, "Powerside"),
+    VendorInfo::new(1195, "SMC Group"),
+    VendorInfo::new(1196, "EOS Weather Instruments"),
+    VendorInfo::new(1197, "Zonex Systems"),
+    VendorInfo::new(1198, "Generex Systems Computervertriebsgesellschaft mbH"),
+    VendorInfo::new(1199, "Energy Wall LLC"),
+    VendorInfo::new(1200, "Thermofin"),
+    VendorInfo::new(1201, "SDATAWAY SA"),
+    VendorInfo::new(1202, "Biddle Air Systems Limited"),
+    VendorInfo::new(1203, "Kessler Ellis Products"),
+    VendorInfo::new(1204, "Thermoscreens"),
+    VendorInfo::new(1205, "Modio"),
+    VendorInfo::new(1206, "Newron Solutions"),
+    VendorInfo::new(1207, "Unitronics"),
+    VendorInfo::new(1208, "TRILUX GmbH & Co. KG"),
+    VendorInfo::new(1209, "Kollmorgen Steuerungstechnik GmbH"),
+    VendorInfo::new(1210, "Bosch Rexroth AG"),
+    VendorInfo::new(1211, "Alarko Carrier"),
+    VendorInfo::new(1212, "Verdigris Technologies"),
+    VendorInfo::new(1213, "Shanghai SIIC-Longchuang Smartech So., Ltd."),
+    VendorInfo::new(1214, "Quinda Co."),
+    VendorInfo::new(1215, "GRUNER AG"),
+    VendorInfo::new(1216, "BACMOVE"),
+    VendorInfo::new(1217, "PSIDAC AB"),
+    VendorInfo::new(1218, "ISICON-Control Automation"),
+    VendorInfo::new(1219, "Big Ass Fans"),
+    VendorInfo::new(1220, "din – Dietmar Nocker Facility Management GmbH"),
+    VendorInfo::new(1221, "Teldio"),
+    VendorInfo::new(1222, "MIKROKLIMA s.r.o."),
+    VendorInfo::new(1223, "Density"),
+    VendorInfo::new(1224, "ICONAG-Leittechnik GmbH"),
+    VendorInfo::new(1225, "Awair"),
+    VendorInfo::new(1226, "T&D Engineering, Ltd"),
+    VendorInfo::new(1227, "Sistemas Digitales"),
+    VendorInfo::new(1228, "Loxone Electronics GmbH"),
+    VendorInfo::new(1229, "ActronAir"),
+    VendorInfo::new(1230, "Inductive Automation"),
+    VendorInfo::new(1231, "Thor Engineering GmbH"),
+    VendorInfo::new(1232, "Berner International, LLC"),
+    VendorInfo::new(1233, "Potsdam Sensors LLC"),
+    VendorInfo::new(1234, "Kohler Mira Ltd"),
+    VendorInfo::new(1235, "Tecomon GmbH"),
+    VendorInfo::new(1236, "Two Dimensional Instruments, LLC"),
+    VendorInfo::new(1237, "LEFA Technologies Pte. Ltd."),
+    VendorInfo::new(1238, "EATON CEAG Notlichtsysteme GmbH"),
+    VendorInfo::new(1239, "Commbox Tecnologia"),
+    VendorInfo::new(1240, "IPVideo Corporation"),
+    VendorInfo::new(1241, "Bender GmbH & Co. KG"),
+    VendorInfo::new(1242, "Rhymebus Corporation"),
+    VendorInfo::new(1243, "Axon Systems Ltd"),
+    VendorInfo::new(1244, "Engineered Air"),
+    VendorInfo::new(1245, "Elipse Software Ltda"),
+    VendorInfo::new(1246, "Simatix Building Technologies Pvt. Ltd."),
+    VendorInfo::new(1247, "W.A. Benjamin Electric Co."),
+    VendorInfo::new(1248, "TROX Air Conditioning Components (Suzhou) Co. Ltd."),
+    VendorInfo::new(1249, "SC Medical Pty Ltd."),
+    VendorInfo::new(1250, "Elcanic A/S"),
+    VendorInfo::new(1251, "Obeo AS"),
+    VendorInfo::new(1252, "Tapa, Inc."),
+    VendorInfo::new(1253, "ASE Smart Energy, Inc."),
+    VendorInfo::new(1254, "Performance Services, Inc."),
+    VendorInfo::new(1255, "Veridify Security"),
+    VendorInfo::new(1256, "CD Innovation LTD"),
+    VendorInfo::new(1257, "Ben Peoples Industries, LLC"),
+    VendorInfo::new(1258, "UNICOMM Sp. z o.o"),
+    VendorInfo::new(1259, "Thing Technologies GmbH"),
+    VendorInfo::new(1260, "Beijing Hailin Control Technology, Inc."),
+    VendorInfo::new(1261, "Digital Realty"),
+    VendorInfo::new(1262, "Agrowtek Inc."),
+    VendorInfo::new(1263, "DSP Innovation BV"),
+    VendorInfo::new(1264, "STV Electronic GmbH"),
+    VendorInfo::new(1265, "Elmeasure India Pvt Ltd."),
+    VendorInfo::new(1266, "Pineshore Energy LLC"),
+    VendorInfo::new(1267, "Brasch Environmental Technologies, LLC"),
+    VendorInfo::new(1268, "Lion Controls Co., LTD"),
+    VendorInfo::new(1269, "Sinux"),
+    VendorInfo::new(1270, "Avnet Inc."),
+    VendorInfo::new(1271, "Somfy Activites SA"),
+    VendorInfo::new(1272, "Amico"),
+    VendorInfo::new(1273, "SageGlass"),
+    VendorInfo::new(1274, "AuVerte"),
+    VendorInfo::new(1275, "Agile Connects Pvt. Ltd."),
+    VendorInfo::new(1276, "Locimation Pty Ltd"),
+    VendorInfo::new(1277, "Envio Systems GmbH"),
+    VendorInfo::new(1278, "Voytech Systems Limited"),
+    VendorInfo::new(1279, "Davidsmeyer und Paul GmbH"),
+    VendorInfo::new(1280, "Lusher Engineering Services"),
+    VendorInfo::new(1281, "CHNT Nanjing Techsel Intelligent Company LTD"),
+    VendorInfo::new(1282, "Threetronics Pty Ltd"),
+    VendorInfo::new(1283, "SkyFoundry, LLC"),
+    VendorInfo::new(1284, "HanilProTech"),
+    VendorInfo::new(1285, "Sensorscall"),
+    VendorInfo::new(1286, "Shanghai Jingpu Information Technology, Co., Ltd."),
+    VendorInfo::new(1287, "Lichtmanufaktur Berlin GmbH"),
+    VendorInfo::new(1288, "Eco Parking Technologies"),
+    VendorInfo::new(1289, "Envision Digital International Pte Ltd"),
+    VendorInfo::new(1290, "Antony Developpement Electronique"),
+    VendorInfo::new(1291, "i2systems"),
+    VendorInfo::new(1292, "Thureon International Limited"),
+    VendorInfo::new(1293, "Pulsafeeder"),
+    VendorInfo::new(1294, "MegaChips Corporation"),
+    VendorInfo::new(1295, "TES Controls"),
+    VendorInfo::new(1296, "Cermate"),
+    VendorInfo::new(1297, "Grand Valley State University"),
+    VendorInfo::new(1298, "Symcon Gmbh"),
+    VendorInfo::new(1299, "The Chicago Faucet Company"),
+    VendorInfo::new(1300, "Geberit AG"),
+    VendorInfo::new(1301, "Rex Controls"),
+    VendorInfo::new(1302, "IVMS GmbH"),
+    VendorInfo::new(1303, "MNPP Saturn Ltd."),
+    VendorInfo::new(1304, "Regal Beloit"),
+    VendorInfo::new(1305, "ACS-Air Conditioning Solutions"),
+    VendorInfo::new(1306, "GBX Technology, LLC"),
+    VendorInfo::new(1307, "Kaiterra"),
+    VendorInfo::new(1308, "ThinKuan loT Technology (Shanghai) Co., Ltd"),
+    VendorInfo::new(1309, "HoCoSto B.V."),
+    VendorInfo::new(1310, "Shenzhen AS-AI Technology Co., Ltd."),
+    VendorInfo::new(1311, "RPS S.p.a."),
+    VendorInfo::new(1312, "Esmé solutions"),
+    VendorInfo::new(1313, "IOTech Systems Limited"),
+    VendorInfo::new(1314, "i-AutoLogic Co., Ltd."),
+    VendorInfo::new(1315, "New Age Micro, LLC"),
+    VendorInfo::new(1316, "Guardian Glass"),
+    VendorInfo::new(1317, "Guangzhou Zhaoyu Information Technology"),
+    VendorInfo::new(1318, "ACE IoT Solutions LLC"),
+    VendorInfo::new(1319, "Poris Electronics Co., Ltd."),
+    VendorInfo::new(1320, "Terminus Technologies Group"),
+    VendorInfo::new(1321, "Intech 21, Inc."),
+    VendorInfo::new(1322, "Accurate Electronics"),
+    VendorInfo::new(1323, "Fluence Bioengineering"),
+    VendorInfo::new(1324, "Mun Hean Singapore Pte Ltd"),
+    VendorInfo::new(1325, "Katronic AG & Co. KG"),
+    VendorInfo::new(1326, "Suzhou XinAo Information Technology Co. Ltd"),
+    VendorInfo::new(1327, "Linktekk Technology, JSC."),
+    VendorInfo::new(1328, "Stirling Ultracold"),
+    VendorInfo::new(1329, "UV Partners, Inc."),
+    VendorInfo::new(1330, "ProMinent GmbH"),
+    VendorInfo::new(1331, "Multi-Tech Systems, Inc."),
+    VendorInfo::new(1332, "JUMO GmbH & Co. KG"),
+    VendorInfo::new(1333, "Qingdao Huarui Technology Co. Ltd.,"),
+    VendorInfo::new(1334, "Cairn Systemes"),
+    VendorInfo::new(1335, "NeuroLogic Research Corp."),
+    VendorInfo::new(1336, "Transition Technologies Advanced Solutions Sp. z o.o"),
+    VendorInfo::new(1337, "Xxter bv"),
+    VendorInfo::new(1338, "PassiveLogic"),
+    VendorInfo::new(1339, "EnSmart Controls"),
+    VendorInfo::new(1340, "Watts Heating and Hot Water Solutions, dba Lync"),
+    VendorInfo::new(1341, "Troposphaira Technologies LLP"),
+    VendorInfo::new(1342, "Network Thermostat"),
+    VendorInfo::new(1343, "Titanium Intelligent Solutions, LLC"),
+    VendorInfo::new(1344, "Numa Products, LLC"),
+    VendorInfo::new(1345, "WAREMA Renkhoff SE"),
+    VendorInfo::new(1346, "Frese A/S"),
+    VendorInfo::new(1347, "Mapped"),
+    VendorInfo::new(1348, "ELEKTRODESIGN ventilatory s.r.o"),
+    VendorInfo::new(1349, "AirCare Automation, Inc."),
+    VendorInfo::new(1350, "Antrum"),
+    VendorInfo::new(1351, "Bao Linh Connect Technology"),
+    VendorInfo::new(1352, "Virginia Controls, LLC"),
+    VendorInfo::new(1353, "Duosys SDN BHD"),
+    VendorInfo::new(1354, "Onsen SAS"),
+    VendorInfo::new(1355, "Vaughn Thermal Corporation"),
+    VendorInfo::new(1356, "Thermoplastic Engineering Ltd (TPE)"),
+    VendorInfo::new(1357, "Wirth Research Ltd."),
+    VendorInfo::new(1358, "SST Automation"),
+    VendorInfo::new(1359, "Shanghai Bencol Electronic Technology Co., Ltd"),
+    VendorInfo::new(1360, "AIWAA Systems Private Limited"),
+    VendorInfo::new(1361, "Enless Wireless"),
+    VendorInfo::new(1362, "Ozuno Engineering Pty Ltd"),
+    VendorInfo::new(1363, "Hubbell, The Electric Heater Company"),
+    VendorInfo::new(1364, "Industrial Turnaround Corporation (ITAC)"),
+    VendorInfo::new(1365, "Wadsworth Control Systems"),
+    VendorInfo::new(1366, "Services Hilo Inc."),
+    VendorInfo::new(1367, "iDM Energiesysteme GmbH"),
+    VendorInfo::new(1368, "BeNext B.V."),
+    VendorInfo::new(1369, "CleanAir.ai Corporation"),
+    VendorInfo::new(1370, "Revolution Microelectronics (America) Inc."),
+    VendorInfo::new(1371, "Real-Time Systems GmbH"),
+    VendorInfo::new(1372, "ZedBee Technologies Pvt Ltd"),
+    VendorInfo::new(1373, "Wimate Technology Solutions Pvt. Ltd."),
+    VendorInfo::new(1374, "Senticon Ltd."),
+    VendorInfo::new(1375, "Rossaker AB"),
+    VendorInfo::new(1376, "OPIT Solutions Ltd"),
+    VendorInfo::new(1377, "Hotowell International Co., Limited"),
+    VendorInfo::new(1378, "Inim Electronics S.R.L. Unipersonale"),
+    VendorInfo::new(1379, "Airthings ASA"),
+    VendorInfo::new(1380, "Analog Devices, Inc."),
+    VendorInfo::new(1381, "AIDirections DMCC"),
+    VendorInfo::new(1382, "Prima Electro S.p.A."),
+    VendorInfo::new(1383, "KLT Control System Ltd."),
+    VendorInfo::new(1384, "Evolution Controls Inc."),
+    VendorInfo::new(1385, "Bever Innovations"),
+    VendorInfo::new(1386, "Pelican Wireless Systems"),
+    VendorInfo::new(1387, "Control Concepts Inc."),
+    VendorInfo::new(1388, "Augmatic Technologies Pvt. Ltd."),
+    VendorInfo::new(1389, "Xiamen Milesight loT Co., Ltd"),
+    VendorInfo::new(1390, "Tianjin Anjie loT Schience and Technology Co., Ltd"),
+    VendorInfo::new(1391, "Guangzhou S. Energy Electronics Technology Co. Ltd."),
+    VendorInfo::new(1392, "AKVO Atmospheric Water Systems Pvt. Ltd."),
+    VendorInfo::new(1393, "EmFirst Co. Ltd."),
+    VendorInfo::new(1394, "Iion Systems ApS"),
+    VendorInfo::new(1396, "SAF Tehnika JSC"),
+    VendorInfo::new(1397, "Komfort IQ, Inc."),
+    VendorInfo::new(1398, "CoolTera Limited"),
+    VendorInfo::new(1399, "Hadron Solutions S.r.l.s"),
+    VendorInfo::new(1401, "Bitpool"),
+    VendorInfo::new(1402, "Sonicu, LLC"),
+    VendorInfo::new(1403, "Rishabh Instruments Limited"),
+    VendorInfo::new(1404, "Thing Warehouse LLC"),
+    VendorInfo::new(1405, "Innofriends GmbH"),
+    VendorInfo::new(1406, "Metronic AKP Sp. J."),
+    VendorInfo::new(1407, "Techknave"),
+    VendorInfo::new(1408, "Elsner Elektronik"),
+    VendorInfo::new(1409, "LEFOO Industrial (Hangzhou) Co., Ltd."),
+    VendorInfo::new(1410, "Calibration Technologies, Inc."),
+    VendorInfo::new(1411, "Allorado"),
+    VendorInfo::new(1412, "Verkada"),
+    VendorInfo::new(1413, "Wattsense"),
+    VendorInfo::new(1414, "Emerson Automation Solutions"),
+    VendorInfo::new(1415, "Growlink"),
+    VendorInfo::new(1416, "Olympia Electronics"),
+    VendorInfo::new(1417, "Normal Software, Inc."),
+    VendorInfo::new(1418, "ST Engineering Solution JSC"),
+    VendorInfo::new(1419, "Industrial Flow Solutions"),
+    VendorInfo::new(1420, "Ubiqisense ApS"),
+    VendorInfo::new(1421, "Tiger-Soft"),
+    VendorInfo::new(1422, "Ecodom Srl"),
+    VendorInfo::new(1423, "Bilgipro IoT Systems"),
+    VendorInfo::new(1424, "planspur netdesign GmbH"),
+    VendorInfo::new(1425, "Dolphin Solutions Ltd"),
+    VendorInfo::new(1426, "Mitsubishi Electric Corporation, Kobe Works"),
+    VendorInfo::new(1427, "Ecovena"),
+    VendorInfo::new(1428, "Gree Electric Appliances Inc of Zhuhai"),
+    VendorInfo::new(1429, "Conspec Controls"),
+    VendorInfo::new(1430, "Hangzhou Hikvision Digital Technology Co., Ltd."),
+    VendorInfo::new(1431, "Crystal Peak Security"),
+    VendorInfo::new(1432, "PermAlert"),
+    VendorInfo::new(1433, "Zhejiang Misilin Technology Co., Ltd."),
+    VendorInfo::new(1434, "Dekker Vacuum Technologies"),
+    VendorInfo::new(1435, "Edwards Limited"),
+    VendorInfo::new(1436, "Leybold GmbH"),
+    VendorInfo::new(1437, "International Gas Detectors"),
+    VendorInfo::new(1438, "Atlas Copco Airpower NV"),
+    VendorInfo::new(1439, "Air Sentry Limited"),
+    VendorInfo::new(1440, "Aelsys"),
+    VendorInfo::new(1441, "Granby Consulting LLC"),
+    VendorInfo::new(1442, "Clever Relay"),
+    VendorInfo::new(1443, "Monico Monitoring, Inc."),
+    VendorInfo::new(1444, "Oqdo"),
+    VendorInfo::new(1445, "Matrix Comsec Private Limited"),
+    VendorInfo::new(1446, "Resource Solutions"),
+    VendorInfo::new(1447, "American Gas Safety, LLC"),
+    VendorInfo::new(1448, "S&S Northern Ltd."),
+    VendorInfo::new(1449, "Ulbios Techsens"),
+    VendorInfo::new(1450, "Bowery Farming, Inc."),
+    VendorInfo::new(1451, "Ryobi Limited"),
+    VendorInfo::new(1452, "EkkoSense Ltd"),
+    VendorInfo::new(1453, "ClimaCool"),
+    VendorInfo::new(1454, "Grid Connect Inc."),
+    VendorInfo::new(1455, "Ziegler Instrumentation UK Ltd"),
+    VendorInfo::new(1456, "ControlTec, LLC"),
+    VendorInfo::new(1457, "Aeterlink Corporation"),
+    VendorInfo::new(1458, "Alpha Epsilon Automation"),
+    VendorInfo::new(1459, "Astralite Inc."),
+    VendorInfo::new(1460, "Delta Fire Ltda."),
+    VendorInfo::new(1461, "Bock Water Heaters, Inc."),
+    VendorInfo::new(1462, "Cleaver-Brooks"),
+    VendorInfo::new(1463, "Scanalytics, Inc."),
+    VendorInfo::new(1464, "WI6LABS"),
+    VendorInfo::new(1465, "AC&T System Co., Ltd"),
+    VendorInfo::new(1466, "VergeSense"),
+    VendorInfo::new(1467, "Systemair Mfg. Inc."),
+    VendorInfo::new(1468, "Seeley International"),
+    VendorInfo::new(1469, "Crane Building Services & Utilities"),
+    VendorInfo::new(1470, "Brady Corporation"),
+    VendorInfo::new(
         1471,
         "Qingdao Hisense Hitachi Air-Conditioning Systems Co., Ltd.",
     ),
-    (1472, "Golden Quality Co. Ltd."),
-    (1473, "Elvaco AB"),
-    (1474, "Strong Technology, LLC"),
-    (
+    VendorInfo::new(1472, "Golden Quality Co. Ltd."),
+    VendorInfo::new(1473, "Elvaco AB"),
+    VendorInfo::new(1474, "Strong Technology, LLC"),
+    VendorInfo::new(
         1475,
         "REC Environmental Technology (Guangzhou) Company Limited",
     ),
-    (1476, "Disruptive Technologies Research AS"),
-    (1477, "Nico Consultancy Limited"),
-    (1478, "Horten lot (Jiangsu) Co., Ltd."),
-    (1479, "Paxton Controls Corp."),
-    (1480, "Fell Technology AS"),
-    (1481, "SHLOK Information Systems India Private Limited"),
-    (1482, "Microgro PLC Ltd."),
-    (1483, "Clouder Oy"),
-    (1484, "Gebäude Automatisierung GmbH"),
-    (1485, "solvimus GmbH"),
-    (1486, "GuangZhou Wangkong Ltd."),
-    (1487, "SafeSquare GmbH"),
-    (1488, "Heklatech AB"),
-    (1489, "Silvair"),
-    (1490, "Adveco"),
-    (1491, "Eura Drives Electric Co., Ltd."),
-    (1492, "Caleb Controls"),
-    (1493, "InBiot Monitoring S.L"),
-    (1494, "Qingdao Haier Air Conditioner Electric Co., Ltd."),
-    (1495, "Hangzhou Weiyl Technology Co., Ltd."),
-    (1496, "yord sàrl"),
-    (1497, "Infinitum Electric"),
-    (1498, "Red Bean Systems Limited"),
-    (1499, "Blue Time Concept SA"),
-    (1500, "Shenzhen INVT Electric Co., Ltd."),
-    (1501, "Messung Systems Pvt Ltd."),
-    (1502, "ABB Electrification Canada Inc."),
-    (1503, "Core Controls"),
-    (1504, "Noord Tech d.o.o."),
-    (1505, "Varibits AS"),
-    (1506, "Roger Sp. z o.o. sp.k."),
-    (1507, "Viega GmbH & Co. KG"),
-    (1508, "Astersoft"),
-    (1509, "BIG-EU"),
-    (1510, "Inferrix Limited"),
-    (1511, "Richards Electric Motor Co."),
-    (1512, "dAPPControls"),
-    (1513, "Entouch Controls"),
-    (1514, "Mavili Elektronik Ticaret ve Sanayi Anonim Sirketi"),
-    (1515, "BubblyNet"),
-    (1516, "AlMayssan Technical Services Co. Ltd."),
-    (1517, "Viridi Parente, Inc."),
-    (1518, "Novel Apps Corp."),
-    (1519, "Oventrop (China) HVAC System Technology Co., Ltd."),
-    (1521, "Lacroix Sofrel"),
-    (1522, "MachineSens loT Trading Co., LLC"),
-    (1523, "Elmec Inc."),
-    (1524, "Beijing Haishi Software Co., Ltd."),
-    (1525, "Radix Electrosystems Pvt. Ltd."),
-    (1526, "Värmebaronen AB"),
-    (1527, "Treau Inc., dba Gradient"),
-    (1528, "WEST Solution AG"),
-    (1529, "Trueway Controls Hongkong Limited"),
-    (1530, "BuildingLogiX"),
-    (1531, "Ifesca GmbH"),
-    (1532, "Fellowes Inc."),
-    (1533, "Caleffi S.p.A."),
-    (1534, "JDRF Electromag"),
-    (1535, "Redwire Labs"),
-    (1536, "Wecon Technology Co., Ltd."),
-    (1537, "Acacia Green Technologies Ltd"),
-    (1538, "Degree Analytics"),
-    (1539, "Roltek Teknoloji A.S."),
-    (1540, "Digital Control Technology Limited"),
-    (1541, "Kentima AB"),
-    (1542, "Pergamon Perceptive Technologies"),
-    (1543, "Prefect Controls Ltd."),
-    (1544, "SenTech Corporation"),
-    (1545, "S+S Regeltechnik GmbH"),
-    (1546, "AirBox, Inc."),
-    (1547, "RVE"),
+    VendorInfo::new(1476, "Disruptive Technologies Research AS"),
+    VendorInfo::new(1477, "Nico Consultancy Limited"),
+    VendorInfo::new(1478, "Horten lot (Jiangsu) Co., Ltd."),
+    VendorInfo::new(1479, "Paxton Controls Corp."),
+    VendorInfo::new(1480, "Fell Technology AS"),
+    VendorInfo::new(1481, "SHLOK Information Systems India Private Limited"),
+    VendorInfo::new(1482, "Microgro PLC Ltd."),
+    VendorInfo::new(1483, "Clouder Oy"),
+    VendorInfo::new(1484, "Gebäude Automatisierung GmbH"),
+    VendorInfo::new(1485, "solvimus GmbH"),
+    VendorInfo::new(1486, "GuangZhou Wangkong Ltd."),
+    VendorInfo::new(1487, "SafeSquare GmbH"),
+    VendorInfo::new(1488, "Heklatech AB"),
+    VendorInfo::new(1489, "Silvair"),
+    VendorInfo::new(1490, "Adveco"),
+    VendorInfo::new(1491, "Eura Drives Electric Co., Ltd."),
+    VendorInfo::new(1492, "Caleb Controls"),
+    VendorInfo::new(1493, "InBiot Monitoring S.L"),
+    VendorInfo::new(1494, "Qingdao Haier Air Conditioner Electric Co., Ltd."),
+    VendorInfo::new(1495, "Hangzhou Weiyl Technology Co., Ltd."),
+    VendorInfo::new(1496, "yord sàrl"),
+    VendorInfo::new(1497, "Infinitum Electric"),
+    VendorInfo::new(1498, "Red Bean Systems Limited"),
+    VendorInfo::new(1499, "Blue Time Concept SA"),
+    VendorInfo::new(1500, "Shenzhen INVT Electric Co., Ltd."),
+    VendorInfo::new(1501, "Messung Systems Pvt Ltd."),
+    VendorInfo::new(1502, "ABB Electrification Canada Inc."),
+    VendorInfo::new(1503, "Core Controls"),
+    VendorInfo::new(1504, "Noord Tech d.o.o."),
+    VendorInfo::new(1505, "Varibits AS"),
+    VendorInfo::new(1506, "Roger Sp. z o.o. sp.k."),
+    VendorInfo::new(1507, "Viega GmbH & Co. KG"),
+    VendorInfo::new(1508, "Astersoft"),
+    VendorInfo::new(1509, "BIG-EU"),
+    VendorInfo::new(1510, "Inferrix Limited"),
+    VendorInfo::new(1511, "Richards Electric Motor Co."),
+    VendorInfo::new(1512, "dAPPControls"),
+    VendorInfo::new(1513, "Entouch Controls"),
+    VendorInfo::new(1514, "Mavili Elektronik Ticaret ve Sanayi Anonim Sirketi"),
+    VendorInfo::new(1515, "BubblyNet"),
+    VendorInfo::new(1516, "AlMayssan Technical Services Co. Ltd."),
+    VendorInfo::new(1517, "Viridi Parente, Inc."),
+    VendorInfo::new(1518, "Novel Apps Corp."),
+    VendorInfo::new(1519, "Oventrop (China) HVAC System Technology Co., Ltd."),
+    VendorInfo::new(1521, "Lacroix Sofrel"),
+    VendorInfo::new(1522, "MachineSens loT Trading Co., LLC"),
+    VendorInfo::new(1523, "Elmec Inc."),
+    VendorInfo::new(1524, "Beijing Haishi Software Co., Ltd."),
+    VendorInfo::new(1525, "Radix Electrosystems Pvt. Ltd."),
+    VendorInfo::new(1526, "Värmebaronen AB"),
+    VendorInfo::new(1527, "Treau Inc., dba Gradient"),
+    VendorInfo::new(1528, "WEST Solution AG"),
+    VendorInfo::new(1529, "Trueway Controls Hongkong Limited"),
+    VendorInfo::new(1530, "BuildingLogiX"),
+    VendorInfo::new(1531, "Ifesca GmbH"),
+    VendorInfo::new(1532, "Fellowes Inc."),
+    VendorInfo::new(1533, "Caleffi S.p.A."),
+    VendorInfo::new(1534, "JDRF Electromag"),
+    VendorInfo::new(1535, "Redwire Labs"),
+    VendorInfo::new(1536, "Wecon Technology Co., Ltd."),
+    VendorInfo::new(1537, "Acacia Green Technologies Ltd"),
+    VendorInfo::new(1538, "Degree Analytics"),
+    VendorInfo::new(1539, "Roltek Teknoloji A.S."),
+    VendorInfo::new(1540, "Digital Control Technology Limited"),
+    VendorInfo::new(1541, "Kentima AB"),
+    VendorInfo::new(1542, "Pergamon Perceptive Technologies"),
+    VendorInfo::new(1543, "Prefect Controls Ltd."),
+    VendorInfo::new(1544, "SenTech Corporation"),
+    VendorInfo::new(1545, "S+S Regeltechnik GmbH"),
+    VendorInfo::new(1546, "AirBox, Inc."),
+    VendorInfo::new(1547, "RVE"),
 ];
 
 /// Get vendor information by vendor ID
 pub fn get_vendor_info(vendor_id: u16) -> Option<VendorInfo> {
     BACNET_VENDORS
         .iter()
-        .find(|(id, _)| *id == vendor_id)
-        .map(|(id, name)| VendorInfo { id: *id, name })
+        .find(|vendor| vendor.id == vendor_id)
+        .cloned()
 }
 
 /// Get vendor name by vendor ID
 pub fn get_vendor_name(vendor_id: u16) -> Option<&'static str> {
     BACNET_VENDORS
         .iter()
-        .find(|(id, _)| *id == vendor_id)
-        .map(|(_, name)| *name)
+        .find(|vendor| vendor.id == vendor_id)
+        .map(|vendor| vendor.name)
+}
+
+/// Get the vendor ID for a vendor name
+///
+/// The name must match an official vendor name, ignoring surrounding
+/// whitespace and ASCII case. Some vendors hold several IDs, in which case
+/// the lowest is returned.
+///
+/// ```
+/// use bacnet_rs::vendor::get_vendor_id_by_name;
+///
+/// assert_eq!(get_vendor_id_by_name(" siemens schweiz ag"), Some(7));
+/// ```
+pub fn get_vendor_id_by_name(name: &str) -> Option<u16> {
+    let name = name.trim();
+    BACNET_VENDORS
+        .iter()
+        .find(|vendor| vendor.name.eq_ignore_ascii_case(name))
+        .map(|vendor| vendor.id)
 }
 
 /// Check if a vendor ID is officially assigned
 pub fn is_vendor_id_assigned(vendor_id: u16) -> bool {
-    BACNET_VENDORS.iter().any(|(id, _)| *id == vendor_id)
+    BACNET_VENDORS.iter().any(|vendor| vendor.id == vendor_id)
 }
 
 /// Check if a vendor ID is reserved for ASHRAE testing
@@ -1642,7 +1667,7 @@ pub fn is_vendor_id_reserved(vendor_id: u16) -> bool {
 
 /// Get all vendor IDs (useful for validation or iteration)
 pub fn get_all_vendor_ids() -> Vec<u16> {
-    BACNET_VENDORS.iter().map(|(id, _)| *id).collect()
+    BACNET_VENDORS.iter().map(|vendor| vendor.id).collect()
 }
 
 /// Get vendors by name pattern (case-insensitive partial match)
@@ -1650,17 +1675,37 @@ pub fn find_vendors_by_name(pattern: &str) -> Vec<VendorInfo> {
     let pattern_lower = pattern.to_lowercase();
     BACNET_VENDORS
         .iter()
-        .filter(|(_, name)| name.to_lowercase().contains(&pattern_lower))
-        .map(|(id, name)| VendorInfo { id: *id, name })
+        .filter(|vendor| vendor.name.to_lowercase().contains(&pattern_lower))
+        .cloned()
+        .collect()
+}
+
+/// Search the vendor table for names containing `substring`
+///
+/// The search ignores surrounding whitespace and ASCII case and does not
+/// allocate per entry. Unlike [`find_vendors_by_name`], non-ASCII letters
+/// must match exactly. An empty substring matches every vendor.
+pub fn search_vendors(substring: &str) -> Vec<&'static VendorInfo> {
+    let substring = substring.trim().as_bytes();
+    BACNET_VENDORS
+        .iter()
+        .filter(|vendor| contains_ignore_ascii_case(vendor.name.as_bytes(), substring))
         .collect()
 }
 
+fn contains_ignore_ascii_case(haystack: &[u8], needle: &[u8]) -> bool {
+    needle.is_empty()
+        || haystack
+            .windows(needle.len())
+            .any(|window| window.eq_ignore_ascii_case(needle))
+}
+
 /// Get vendor statistics
 pub fn get_vendor_statistics() -> VendorStatistics {
     let total_vendors = BACNET_VENDORS.len();
     let reserved_count = BACNET_VENDORS
         .iter()
-        .filter(|(id, _)| is_vendor_id_reserved(*id))
+        .filter(|vendor| is_vendor_id_reserved(vendor.id))
         .count();
     let assigned_count = total_vendors - reserved_count;
 
@@ -1766,6 +1811,27 @@ mod tests {
         let trane_vendors = find_vendors_by_name("Trane");
         assert!(!trane_vendors.is_empty());
         assert!(trane_vendors.iter().any(|v| v.name.contains("Trane")));
+
+        // Case folding covers non-ASCII letters too
+        let gebaeude = find_vendors_by_name("GEBÄUDETECHNIK");
+        assert!(gebaeude.iter().any(|v| v.id == 73));
+        assert!(search_vendors("GEBÄUDETECHNIK").is_empty());
+        assert!(search_vendors("GEBäUDETECHNIK").iter().any(|v| v.id == 73));
+    }
+
+    #[test]
+    fn test_vendor_reverse_lookup() {
+        let siemens = search_vendors("Siemens");
+        assert!(siemens.iter().any(|v| v.id == 7));
+        assert!(siemens.iter().any(|v| v.name == "Siemens Industry, Inc."));
+        assert!(siemens.iter().all(|v| v.name.contains("Siemens")));
+        assert_eq!(search_vendors(" siemens "), siemens);
+
+        assert_eq!(get_vendor_id_by_name("Siemens Schweiz AG"), Some(7));
+        assert_eq!(get_vendor_id_by_name("siemens industry, inc.  "), Some(313));
+        assert_eq!(get_vendor_id_by_name("Siemens"), None);
+
+        assert!(search_vendors("no such vendor").is_empty());
     }
 
     #[test]
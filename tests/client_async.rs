@@ -0,0 +1,334 @@
+//! End-to-end tests for the asynchronous client over loopback.
+//!
+//! A mock device runs on a plain thread with a blocking socket and answers the
+//! client's requests with frames built from the crate's own encoders. This
+//! exercises the client's receive task and the pending-request map that
+//! matches replies to the futures waiting for them.
+
+#![cfg(feature = "async")]
+
+use std::net::{SocketAddr, UdpSocket};
+use std::thread;
+use std::time::Duration;
+
+use bacnet_rs::{
+    app::Apdu,
+    client::{AsyncBacnetClient, ClientError},
+    network::Npdu,
+    object::{ObjectIdentifier, ObjectType, PropertyIdentifier, Segmentation},
+    property::PropertyValue,
+    service::{
+        ConfirmedServiceChoice, ErrorClass, ErrorCode, IAmRequest, PropertyResult,
+        PropertyResultValue, ReadAccessResult, ReadPropertyMultipleResponse, ReadPropertyRequest,
+        ReadPropertyResponse, UnconfirmedServiceChoice,
+    },
+};
+
+const TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Wrap an NPDU payload in BVLC (Original-Unicast-NPDU) framing.
+fn wrap(payload: &[u8]) -> Vec<u8> {
+    let mut frame = vec![0x81, 0x0A, 0x00, 0x00];
+    frame.extend_from_slice(&Npdu::new().encode());
+    frame.extend_from_slice(payload);
+    let len = frame.len() as u16;
+    frame[2..4].copy_from_slice(&len.to_be_bytes());
+    frame
+}
+
+/// Decode the APDU of a received frame.
+fn parse_apdu(frame: &[u8]) -> Apdu {
+    let (_npdu, npdu_len) = Npdu::decode(&frame[4..]).expect("decode NPDU");
+    Apdu::decode(&frame[4 + npdu_len..]).expect("decode APDU")
+}
+
+/// Spawn a mock device that collects `count` requests, then sends the reply
+/// frames `respond` builds from them, in the order it returns them.
+fn spawn_device<F>(count: usize, respond: F) -> SocketAddr
+where
+    F: FnOnce(&[Apdu]) -> Vec<Vec<u8>> + Send + 'static,
+{
+    let socket = UdpSocket::bind("127.0.0.1:0").expect("bind device");
+    socket
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    let addr = socket.local_addr().unwrap();
+
+    thread::spawn(move || {
+        let mut requests = Vec::new();
+        let mut buf = [0u8; 1500];
+        let mut peer = None;
+        while requests.len() < count {
+            let Ok((len, src)) = socket.recv_from(&mut buf) else {
+                return;
+            };
+            requests.push(parse_apdu(&buf[..len]));
+            peer = Some(src);
+        }
+
+        let peer = peer.expect("at least one request");
+        for frame in respond(&requests) {
+            socket.send_to(&frame, peer).expect("send response");
+        }
+    });
+
+    addr
+}
+
+fn invoke_id(request: &Apdu) -> u8 {
+    match request {
+        Apdu::ConfirmedRequest { invoke_id, .. } => *invoke_id,
+        other => panic!("expected ConfirmedRequest, got {other:?}"),
+    }
+}
+
+fn complex_ack(invoke_id: u8, service_choice: ConfirmedServiceChoice, data: Vec<u8>) -> Vec<u8> {
+    wrap(
+        &Apdu::ComplexAck {
+            segmented: false,
+            more_follows: false,
+            invoke_id,
+            sequence_number: None,
+            proposed_window_size: None,
+            service_choice,
+            service_data: data,
+        }
+        .encode(),
+    )
+}
+
+/// Build a ReadProperty ComplexAck echoing the requested object.
+fn read_property_ack(request: &Apdu, value: PropertyValue) -> Vec<u8> {
+    let Apdu::ConfirmedRequest {
+        invoke_id,
+        service_data,
+        ..
+    } = request
+    else {
+        panic!("expected ConfirmedRequest");
+    };
+    let request = ReadPropertyRequest::decode(service_data).expect("decode request");
+
+    let response = ReadPropertyResponse::new(
+        request.object_identifier,
+        PropertyIdentifier::PresentValue,
+        vec![value],
+    );
+    let mut data = Vec::new();
+    response.encode(&mut data).expect("encode response");
+    complex_ack(*invoke_id, ConfirmedServiceChoice::ReadProperty, data)
+}
+
+async fn client() -> AsyncBacnetClient {
+    AsyncBacnetClient::bind("127.0.0.1:0")
+        .await
+        .expect("bind client")
+}
+
+#[tokio::test]
+async fn read_and_write_property() {
+    let object = ObjectIdentifier::new(ObjectType::AnalogValue, 1);
+
+    let addr = spawn_device(1, |requests| {
+        vec![read_property_ack(&requests[0], PropertyValue::Real(72.5))]
+    });
+    let values = client()
+        .await
+        .read_property(addr, object, PropertyIdentifier::PresentValue, TIMEOUT)
+        .await
+        .expect("read should succeed");
+    assert_eq!(values, vec![PropertyValue::Real(72.5)]);
+
+    let addr = spawn_device(1, |requests| {
+        let ack = Apdu::SimpleAck {
+            invoke_id: invoke_id(&requests[0]),
+            service_choice: ConfirmedServiceChoice::WriteProperty as u8,
+        };
+        vec![wrap(&ack.encode())]
+    });
+    client()
+        .await
+        .write_property(
+            addr,
+            object,
+            PropertyIdentifier::PresentValue,
+            &PropertyValue::Real(50.0),
+            Some(8),
+            TIMEOUT,
+        )
+        .await
+        .expect("write should succeed");
+}
+
+#[tokio::test]
+async fn concurrent_requests_match_out_of_order_replies() {
+    let first = ObjectIdentifier::new(ObjectType::AnalogValue, 1);
+    let second = ObjectIdentifier::new(ObjectType::AnalogValue, 2);
+
+    // Answer both requests in reverse order, each with its object's instance.
+    let addr = spawn_device(2, |requests| {
+        requests
+            .iter()
+            .rev()
+            .map(|request| {
+                let Apdu::ConfirmedRequest { service_data, .. } = request else {
+                    panic!("expected ConfirmedRequest");
+                };
+                let instance = ReadPropertyRequest::decode(service_data)
+                    .unwrap()
+                    .object_identifier
+                    .instance;
+                read_property_ack(request, PropertyValue::Unsigned(instance as u64))
+            })
+            .collect()
+    });
+
+    let client = client().await;
+    let (a, b) = tokio::join!(
+        client.read_property(addr, first, PropertyIdentifier::PresentValue, TIMEOUT),
+        client.read_property(addr, second, PropertyIdentifier::PresentValue, TIMEOUT),
+    );
+
+    assert_eq!(a.unwrap(), vec![PropertyValue::Unsigned(1)]);
+    assert_eq!(b.unwrap(), vec![PropertyValue::Unsigned(2)]);
+}
+
+#[tokio::test]
+async fn request_times_out_and_surfaces_errors() {
+    let object = ObjectIdentifier::new(ObjectType::AnalogValue, 99);
+    let client = client().await;
+
+    // A device that never answers.
+    let silent = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let err = client
+        .read_property(
+            silent.local_addr().unwrap(),
+            object,
+            PropertyIdentifier::PresentValue,
+            Duration::from_millis(100),
+        )
+        .await
+        .expect_err("no reply");
+    assert!(matches!(err, ClientError::Timeout), "got {err:?}");
+
+    let addr = spawn_device(1, |requests| {
+        let error = Apdu::Error {
+            invoke_id: invoke_id(&requests[0]),
+            service_choice: ConfirmedServiceChoice::ReadProperty,
+            error_class: ErrorClass::Object,
+            error_code: ErrorCode::UnknownObject,
+        };
+        vec![wrap(&error.encode())]
+    });
+    let err = client
+        .read_property(addr, object, PropertyIdentifier::PresentValue, TIMEOUT)
+        .await
+        .expect_err("device returned an error PDU");
+    assert!(
+        matches!(
+            err,
+            ClientError::PropertyError {
+                class: ErrorClass::Object,
+                code: ErrorCode::UnknownObject
+            }
+        ),
+        "got {err:?}"
+    );
+}
+
+#[tokio::test]
+async fn discover_device_and_read_object_list() {
+    let device = ObjectIdentifier::new(ObjectType::Device, 4711);
+    let objects = vec![
+        ObjectIdentifier::new(ObjectType::AnalogInput, 1),
+        ObjectIdentifier::new(ObjectType::BinaryValue, 2),
+    ];
+
+    let addr = spawn_device(1, move |requests| {
+        assert!(matches!(
+            requests[0],
+            Apdu::UnconfirmedRequest {
+                service_choice: UnconfirmedServiceChoice::WhoIs,
+                ..
+            }
+        ));
+
+        let iam = IAmRequest::new(device, 1476, Segmentation::Both, 260);
+        let mut payload = vec![0x10, UnconfirmedServiceChoice::IAm as u8];
+        iam.encode(&mut payload).expect("encode I-Am");
+        vec![wrap(&payload)]
+    });
+
+    let client = client().await;
+    let info = client
+        .discover_device(addr, TIMEOUT)
+        .await
+        .expect("discover");
+    assert_eq!(info.device_id, 4711);
+    assert_eq!(info.address, addr);
+
+    let list = objects.clone();
+    let addr = spawn_device(1, move |requests| {
+        let mut values = vec![PropertyValue::ObjectIdentifier(device)];
+        values.extend(list.into_iter().map(PropertyValue::ObjectIdentifier));
+        let response = ReadPropertyMultipleResponse::new(vec![ReadAccessResult::new(
+            device,
+            vec![PropertyResult {
+                property_identifier: PropertyIdentifier::ObjectList,
+                array_index: None,
+                value: PropertyResultValue::Value(values),
+            }],
+        )]);
+        let mut data = Vec::new();
+        response.encode(&mut data).expect("encode response");
+        vec![complex_ack(
+            invoke_id(&requests[0]),
+            ConfirmedServiceChoice::ReadPropertyMultiple,
+            data,
+        )]
+    });
+
+    assert_eq!(
+        client
+            .read_object_list(addr, 4711, TIMEOUT)
+            .await
+            .expect("read object list"),
+        objects
+    );
+}
+
+#[tokio::test]
+async fn request_is_resent_after_a_dropped_reply() {
+    let object = ObjectIdentifier::new(ObjectType::AnalogValue, 1);
+
+    // Ignore the first request, then answer the resend twice.
+    let addr = spawn_device(2, |requests| {
+        assert_eq!(invoke_id(&requests[0]), invoke_id(&requests[1]));
+        let reply = read_property_ack(&requests[1], PropertyValue::Real(1.0));
+        vec![reply.clone(), reply]
+    });
+
+    let mut client = client().await;
+    client.set_retries(2);
+    let values = client
+        .read_property(
+            addr,
+            object,
+            PropertyIdentifier::PresentValue,
+            Duration::from_millis(200),
+        )
+        .await
+        .expect("resend answered");
+    assert_eq!(values, vec![PropertyValue::Real(1.0)]);
+
+    // The duplicate reply is dropped by the receive task, which keeps
+    // serving later requests.
+    let addr = spawn_device(1, |requests| {
+        vec![read_property_ack(&requests[0], PropertyValue::Real(2.0))]
+    });
+    let values = client
+        .read_property(addr, object, PropertyIdentifier::PresentValue, TIMEOUT)
+        .await
+        .expect("next answered");
+    assert_eq!(values, vec![PropertyValue::Real(2.0)]);
+}
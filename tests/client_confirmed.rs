@@ -6,7 +6,7 @@
 //! using the crate's own encoders. This exercises the real
 //! `send_confirmed_request` transaction path: invoke-ID allocation, the
 //! BVLC/NPDU/APDU framing, and the ComplexAck / SimpleAck / Error handling added
-//! across commits 2-4.
+//! across commits 2-4, and correlation of replies that arrive out of order.
 
 #![cfg(feature = "std")]
 
@@ -15,12 +15,22 @@ use std::thread;
 use std::time::Duration;
 
 use bacnet_rs::{
-    app::Apdu,
+    app::{Apdu, ApplicationError},
     client::{BacnetClient, ClientError, WriteOutcome},
+    encoding::{
+        decode_context_enumerated, decode_context_object_id, decode_signed, decode_unsigned,
+        write_object_identifier, EncodingError,
+    },
     network::Npdu,
-    object::{ObjectIdentifier, ObjectType, PropertyIdentifier},
+    object::{Date, ObjectIdentifier, ObjectType, PropertyIdentifier, Time},
     property::PropertyValue,
-    service::{ConfirmedServiceChoice, ReadPropertyResponse},
+    service::{
+        BacnetDateTime, ConfirmedServiceChoice, CovNotificationRequest, ErrorClass, ErrorCode,
+        LogDatum, LogRecord, PropertyResult, PropertyResultValue, ReadAccessResult,
+        ReadPropertyMultipleRequest, ReadPropertyMultipleResponse, ReadPropertyRequest,
+        ReadPropertyResponse, ReadRangeResponse, RejectReason, ResultFlags,
+        UnconfirmedServiceChoice,
+    },
 };
 
 /// Extract the invoke ID and service choice from a received confirmed-request
@@ -101,6 +111,39 @@ where
     addr
 }
 
+/// Like [`spawn_device_loop`] but also hands `make_response` the request's
+/// service data.
+fn spawn_device_serving<F>(mut make_response: F) -> SocketAddr
+where
+    F: FnMut(u8, ConfirmedServiceChoice, &[u8]) -> Apdu + Send + 'static,
+{
+    let socket = UdpSocket::bind("127.0.0.1:0").expect("bind device");
+    socket
+        .set_read_timeout(Some(Duration::from_secs(1)))
+        .unwrap();
+    let addr = socket.local_addr().unwrap();
+
+    thread::spawn(move || {
+        let mut buf = [0u8; 1500];
+        while let Ok((len, src)) = socket.recv_from(&mut buf) {
+            let (_npdu, npdu_len) = Npdu::decode(&buf[4..len]).expect("decode NPDU");
+            let Apdu::ConfirmedRequest {
+                invoke_id,
+                service_choice,
+                service_data,
+                ..
+            } = Apdu::decode(&buf[4 + npdu_len..len]).expect("decode APDU")
+            else {
+                panic!("expected ConfirmedRequest");
+            };
+            let frame = wrap_response(make_response(invoke_id, service_choice, &service_data));
+            socket.send_to(&frame, src).expect("send response");
+        }
+    });
+
+    addr
+}
+
 /// Build a ComplexAck carrying a ReadProperty response with a single value.
 fn read_property_ack(invoke_id: u8, object: ObjectIdentifier, value: PropertyValue) -> Apdu {
     let response = ReadPropertyResponse::new(object, PropertyIdentifier::PresentValue, vec![value]);
@@ -156,16 +199,50 @@ fn read_property_decodes_complex_ack() {
     assert_eq!(values, vec![PropertyValue::Real(72.5)]);
 }
 
+#[test]
+fn read_property_typed_decodes_present_value() {
+    let object = ObjectIdentifier::new(ObjectType::AnalogInput, 3);
+
+    let addr = spawn_device(move |invoke_id, _service_choice| {
+        read_property_ack(invoke_id, object, PropertyValue::Real(21.25))
+    });
+
+    let value = test_client()
+        .read_property_typed(addr, object, PropertyIdentifier::PresentValue)
+        .expect("read should succeed");
+
+    assert_eq!(value, PropertyValue::Real(21.25));
+}
+
+#[test]
+fn read_property_typed_rejects_wrong_datatype() {
+    // A binary object's Present_Value is Enumerated, not Real.
+    let object = ObjectIdentifier::new(ObjectType::BinaryValue, 3);
+
+    let addr = spawn_device(move |invoke_id, _service_choice| {
+        read_property_ack(invoke_id, object, PropertyValue::Real(1.0))
+    });
+
+    let err = test_client()
+        .read_property_typed(addr, object, PropertyIdentifier::PresentValue)
+        .expect_err("Real is not a binary present value");
+
+    assert!(
+        matches!(err, ClientError::Encoding(EncodingError::InvalidTag)),
+        "expected InvalidTag, got {err:?}"
+    );
+}
+
 #[test]
 fn read_property_surfaces_error_pdu() {
     let object = ObjectIdentifier::new(ObjectType::AnalogValue, 99);
 
-    // Error class 1 (object), code 32 (unknown-object) for example.
+    // Error class 1 (object), code 32 (unknown-property) for example.
     let addr = spawn_device(|invoke_id, _service_choice| Apdu::Error {
         invoke_id,
         service_choice: ConfirmedServiceChoice::ReadProperty,
-        error_class: 1,
-        error_code: 32,
+        error_class: ErrorClass::Object,
+        error_code: ErrorCode::UnknownProperty,
     });
 
     let err = test_client()
@@ -173,7 +250,13 @@ fn read_property_surfaces_error_pdu() {
         .expect_err("device returned an error PDU");
 
     assert!(
-        matches!(err, ClientError::PropertyError { class: 1, code: 32 }),
+        matches!(
+            err,
+            ClientError::PropertyError {
+                class: ErrorClass::Object,
+                code: ErrorCode::UnknownProperty
+            }
+        ),
         "expected PropertyError(1, 32), got {err:?}"
     );
 }
@@ -198,6 +281,36 @@ fn write_property_accepts_simple_ack() {
         .expect("write should be acknowledged");
 }
 
+#[test]
+fn oversized_write_is_refused_before_sending() {
+    let silent = UdpSocket::bind("127.0.0.1:0").expect("bind silent device");
+    silent
+        .set_read_timeout(Some(Duration::from_millis(200)))
+        .unwrap();
+    let object = ObjectIdentifier::new(ObjectType::File, 1);
+
+    let err = test_client()
+        .write_property(
+            silent.local_addr().unwrap(),
+            object,
+            PropertyIdentifier::PresentValue,
+            &PropertyValue::OctetString(vec![0xAA; 2000]),
+            None,
+        )
+        .expect_err("a 2000-byte APDU does not fit in 1476");
+    assert!(
+        matches!(
+            err,
+            ClientError::Application(ApplicationError::MaxApduLengthExceeded)
+        ),
+        "got {err:?}"
+    );
+
+    // Nothing went out on the wire.
+    let mut buf = [0u8; 1500];
+    assert!(silent.recv_from(&mut buf).is_err());
+}
+
 #[test]
 fn write_property_verified_confirms_when_readback_matches() {
     let object = ObjectIdentifier::new(ObjectType::AnalogValue, 1);
@@ -262,3 +375,530 @@ fn write_property_verified_reports_not_effective_when_overridden() {
         }
     );
 }
+
+#[test]
+fn ping_returns_round_trip_time() {
+    let device = ObjectIdentifier::new(ObjectType::Device, 1234);
+
+    let addr = spawn_device(move |invoke_id, service_choice| {
+        assert_eq!(service_choice, ConfirmedServiceChoice::ReadProperty);
+        let response = ReadPropertyResponse::new(
+            device,
+            PropertyIdentifier::ObjectName,
+            vec![PropertyValue::CharacterString("Controller".to_string())],
+        );
+        let mut service_data = Vec::new();
+        response.encode(&mut service_data).expect("encode response");
+
+        Apdu::ComplexAck {
+            segmented: false,
+            more_follows: false,
+            invoke_id,
+            sequence_number: None,
+            proposed_window_size: None,
+            service_choice: ConfirmedServiceChoice::ReadProperty,
+            service_data,
+        }
+    });
+
+    let client = test_client();
+    let rtt = client.ping(addr, 1234).expect("ping should succeed");
+
+    assert!(rtt > Duration::ZERO);
+    assert!(rtt < client.timeout());
+}
+
+#[test]
+fn ping_times_out_when_device_is_silent() {
+    // Bound but never answers.
+    let silent = UdpSocket::bind("127.0.0.1:0").expect("bind silent device");
+    let addr = silent.local_addr().unwrap();
+
+    let client = BacnetClient::builder()
+        .local_addr("127.0.0.1")
+        .timeout(Duration::from_millis(200))
+        .build()
+        .expect("build client");
+
+    let err = client.ping(addr, 1234).expect_err("nobody answers");
+    assert!(matches!(err, ClientError::Timeout), "got {err:?}");
+}
+
+/// Encode a ReadProperty request for the object's present value.
+fn read_present_value(object: ObjectIdentifier) -> Vec<u8> {
+    let mut service_data = Vec::new();
+    ReadPropertyRequest::new(object, PropertyIdentifier::PresentValue)
+        .encode(&mut service_data)
+        .expect("encode request");
+    service_data
+}
+
+#[test]
+fn out_of_order_replies_correlate_by_invoke_id() {
+    let first = ObjectIdentifier::new(ObjectType::AnalogValue, 5);
+    let second = ObjectIdentifier::new(ObjectType::AnalogValue, 6);
+
+    // Receive both requests, then answer the second one first.
+    let socket = UdpSocket::bind("127.0.0.1:0").expect("bind device");
+    socket
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    let addr = socket.local_addr().unwrap();
+    thread::spawn(move || {
+        let mut buf = [0u8; 1500];
+        let mut requests = Vec::new();
+        for _ in 0..2 {
+            let (len, src) = socket.recv_from(&mut buf).expect("receive request");
+            let (invoke_id, _) = parse_confirmed_request(&buf[..len]);
+            requests.push((invoke_id, src));
+        }
+
+        let replies = [(requests[1], second, 6.0), (requests[0], first, 5.0)];
+        for ((invoke_id, src), object, value) in replies {
+            let ack = read_property_ack(invoke_id, object, PropertyValue::Real(value));
+            socket
+                .send_to(&wrap_response(ack), src)
+                .expect("send response");
+        }
+    });
+
+    let client = test_client();
+    let pending_first = client
+        .start_confirmed_request(
+            addr,
+            ConfirmedServiceChoice::ReadProperty,
+            &read_present_value(first),
+        )
+        .unwrap();
+    let pending_second = client
+        .start_confirmed_request(
+            addr,
+            ConfirmedServiceChoice::ReadProperty,
+            &read_present_value(second),
+        )
+        .unwrap();
+
+    let first_ack = client
+        .wait_for_response(pending_first)
+        .expect("first reply");
+    let second_ack = client
+        .wait_for_response(pending_second)
+        .expect("second reply");
+
+    let first_response = ReadPropertyResponse::decode(&first_ack).unwrap();
+    assert_eq!(first_response.object_identifier, first);
+    assert_eq!(
+        first_response.property_values,
+        vec![PropertyValue::Real(5.0)]
+    );
+
+    let second_response = ReadPropertyResponse::decode(&second_ack).unwrap();
+    assert_eq!(second_response.object_identifier, second);
+    assert_eq!(
+        second_response.property_values,
+        vec![PropertyValue::Real(6.0)]
+    );
+}
+
+#[test]
+fn cov_notification_during_request_is_buffered() {
+    let object = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+    let device = ObjectIdentifier::new(ObjectType::Device, 1234);
+
+    // Send a COV notification for subscriber process 17 before the ack.
+    let socket = UdpSocket::bind("127.0.0.1:0").expect("bind device");
+    socket
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    let addr = socket.local_addr().unwrap();
+    thread::spawn(move || {
+        let mut buf = [0u8; 1500];
+        let (len, src) = socket.recv_from(&mut buf).expect("receive request");
+        let (invoke_id, _) = parse_confirmed_request(&buf[..len]);
+
+        let mut service_data = Vec::new();
+        CovNotificationRequest::new(17, device, object, 60, vec![])
+            .encode(&mut service_data)
+            .expect("encode notification");
+        let notification = Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::UnconfirmedCOVNotification,
+            service_data,
+        };
+        socket
+            .send_to(&wrap_response(notification), src)
+            .expect("send notification");
+
+        let ack = read_property_ack(invoke_id, object, PropertyValue::Real(1.0));
+        socket
+            .send_to(&wrap_response(ack), src)
+            .expect("send response");
+    });
+
+    let client = test_client();
+    let values = client
+        .read_property(addr, object, PropertyIdentifier::PresentValue)
+        .expect("read should succeed");
+    assert_eq!(values, vec![PropertyValue::Real(1.0)]);
+
+    assert!(client.take_cov_notifications(99).is_empty());
+    let notifications = client.take_cov_notifications(17);
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(&notifications[0][..2], &[0x09, 17]);
+}
+
+/// Build a ComplexAck carrying one page of a trend log's buffer.
+fn read_range_ack(
+    invoke_id: u8,
+    object: ObjectIdentifier,
+    values: &[f32],
+    first_item: bool,
+    last_item: bool,
+) -> Apdu {
+    let mut item_data = Vec::new();
+    for &value in values {
+        LogRecord {
+            timestamp: BacnetDateTime::new(
+                Date {
+                    year: 2024,
+                    month: 3,
+                    day: 15,
+                    weekday: 5,
+                },
+                Time {
+                    hour: 12,
+                    minute: value as u8,
+                    second: 0,
+                    hundredths: 0,
+                },
+            ),
+            log_datum: LogDatum::Real(value),
+            status_flags: None,
+        }
+        .encode(&mut item_data)
+        .expect("encode record");
+    }
+
+    let response = ReadRangeResponse {
+        object_identifier: object,
+        property_identifier: PropertyIdentifier::LogBuffer,
+        property_array_index: None,
+        result_flags: ResultFlags {
+            first_item,
+            last_item,
+            more_items: !last_item,
+        },
+        item_count: values.len() as u32,
+        item_data,
+        first_sequence_number: None,
+    };
+    let mut service_data = Vec::new();
+    response.encode(&mut service_data).expect("encode response");
+    Apdu::ComplexAck {
+        segmented: false,
+        more_follows: false,
+        invoke_id,
+        sequence_number: None,
+        proposed_window_size: None,
+        service_choice: ConfirmedServiceChoice::ReadRange,
+        service_data,
+    }
+}
+
+/// Decode a by-position ReadRange request into its object, reference index
+/// and count.
+fn decode_read_range_by_position(service_data: &[u8]) -> (ObjectIdentifier, u32, i32) {
+    // Object, property and by-position range (context tag 3)
+    let (object, mut pos) = decode_context_object_id(service_data, 0).unwrap();
+    pos += decode_context_enumerated(&service_data[pos..], 1)
+        .unwrap()
+        .1;
+    assert_eq!(service_data[pos], 0x3E);
+    pos += 1;
+    let (reference_index, consumed) = decode_unsigned(&service_data[pos..]).unwrap();
+    let (count, _) = decode_signed(&service_data[pos + consumed..]).unwrap();
+    (object, reference_index, count)
+}
+
+#[test]
+fn read_trend_log_pages_through_the_buffer() {
+    let log = ObjectIdentifier::new(ObjectType::TrendLog, 1);
+
+    let mut requests = 0;
+    let addr = spawn_device_serving(move |invoke_id, service_choice, service_data| {
+        requests += 1;
+        match (requests, service_choice) {
+            (1, ConfirmedServiceChoice::ReadProperty) => {
+                read_property_ack(invoke_id, log, PropertyValue::Unsigned(3))
+            }
+            (2, ConfirmedServiceChoice::ReadRange) => {
+                assert_eq!(decode_read_range_by_position(service_data), (log, 1, 3));
+                read_range_ack(invoke_id, log, &[1.0, 2.0], true, false)
+            }
+            (3, ConfirmedServiceChoice::ReadRange) => {
+                // The second page continues after the two records received
+                assert_eq!(decode_read_range_by_position(service_data), (log, 3, 1));
+                read_range_ack(invoke_id, log, &[3.0], false, true)
+            }
+            other => panic!("unexpected request {other:?}"),
+        }
+    });
+
+    let client = test_client();
+    let records = client
+        .read_trend_log(addr, log)
+        .expect("download should succeed");
+
+    let values: Vec<_> = records.iter().map(|record| &record.log_datum).collect();
+    assert_eq!(
+        values,
+        vec![
+            &LogDatum::Real(1.0),
+            &LogDatum::Real(2.0),
+            &LogDatum::Real(3.0)
+        ]
+    );
+    assert_eq!(records[2].timestamp.time.minute, 3);
+}
+
+#[test]
+fn request_is_resent_after_a_dropped_reply() {
+    let object = ObjectIdentifier::new(ObjectType::AnalogValue, 1);
+    let socket = UdpSocket::bind("127.0.0.1:0").expect("bind device");
+    socket
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    let addr = socket.local_addr().unwrap();
+
+    let device = thread::spawn(move || {
+        let mut buf = [0u8; 1500];
+
+        // Ignore the first request, as if its reply had been lost.
+        let (len, _) = socket.recv_from(&mut buf).expect("first request");
+        let (first_id, _) = parse_confirmed_request(&buf[..len]);
+
+        // Answer the resend twice; the duplicate is still in flight when the
+        // client moves on to its next request.
+        let (len, src) = socket.recv_from(&mut buf).expect("resent request");
+        let (resent_id, _) = parse_confirmed_request(&buf[..len]);
+        let reply = wrap_response(read_property_ack(
+            resent_id,
+            object,
+            PropertyValue::Real(1.0),
+        ));
+        socket.send_to(&reply, src).unwrap();
+        socket.send_to(&reply, src).unwrap();
+
+        let (len, src) = socket.recv_from(&mut buf).expect("next request");
+        let (next_id, _) = parse_confirmed_request(&buf[..len]);
+        let reply = wrap_response(read_property_ack(next_id, object, PropertyValue::Real(2.0)));
+        socket.send_to(&reply, src).unwrap();
+
+        (first_id, resent_id, next_id)
+    });
+
+    let client = BacnetClient::builder()
+        .local_addr("127.0.0.1")
+        .timeout(Duration::from_millis(200))
+        .retries(2)
+        .build()
+        .expect("build client");
+
+    let read = || client.read_property(addr, object, PropertyIdentifier::PresentValue);
+    assert_eq!(
+        read().expect("resend answered"),
+        vec![PropertyValue::Real(1.0)]
+    );
+    // The late duplicate of the first reply must not answer this request.
+    assert_eq!(
+        read().expect("next answered"),
+        vec![PropertyValue::Real(2.0)]
+    );
+
+    let (first_id, resent_id, next_id) = device.join().unwrap();
+    assert_eq!(first_id, resent_id, "a resend keeps its invoke ID");
+    assert_ne!(next_id, first_id);
+}
+
+const LARGE_DEVICE: u32 = 4711;
+
+/// Object_List of a device with 500 objects besides itself, 1-based.
+fn large_object_list() -> Vec<ObjectIdentifier> {
+    let mut objects = vec![ObjectIdentifier::new(ObjectType::Device, LARGE_DEVICE)];
+    objects
+        .extend((0..500).map(|instance| ObjectIdentifier::new(ObjectType::AnalogValue, instance)));
+    objects
+}
+
+fn complex_ack(
+    invoke_id: u8,
+    service_choice: ConfirmedServiceChoice,
+    service_data: Vec<u8>,
+) -> Apdu {
+    Apdu::ComplexAck {
+        segmented: false,
+        more_follows: false,
+        invoke_id,
+        sequence_number: None,
+        proposed_window_size: None,
+        service_choice,
+        service_data,
+    }
+}
+
+/// Answer a ReadProperty of Object_List array index 0 with the list length.
+fn object_list_length_ack(
+    invoke_id: u8,
+    service_data: &[u8],
+    objects: &[ObjectIdentifier],
+) -> Apdu {
+    let request = ReadPropertyRequest::decode(service_data).expect("decode ReadProperty");
+    assert_eq!(request.property_identifier, PropertyIdentifier::ObjectList);
+    assert_eq!(request.property_array_index, Some(0));
+
+    let response = ReadPropertyResponse::new(
+        request.object_identifier,
+        PropertyIdentifier::ObjectList,
+        vec![PropertyValue::Unsigned(objects.len() as u64)],
+    );
+    let mut data = Vec::new();
+    response.encode(&mut data).expect("encode response");
+    complex_ack(invoke_id, ConfirmedServiceChoice::ReadProperty, data)
+}
+
+#[test]
+fn read_object_list_chunked_uses_read_range() {
+    let objects = large_object_list();
+    let list = objects.clone();
+    let mut read_ranges = 0;
+    let addr =
+        spawn_device_serving(
+            move |invoke_id, service_choice, service_data| match service_choice {
+                ConfirmedServiceChoice::ReadProperty => {
+                    object_list_length_ack(invoke_id, service_data, &list)
+                }
+                ConfirmedServiceChoice::ReadRange => {
+                    read_ranges += 1;
+                    assert!(read_ranges <= 2, "a 1476 byte APDU holds 290 entries");
+
+                    let (object, reference_index, count) =
+                        decode_read_range_by_position(service_data);
+
+                    let first = reference_index as usize - 1;
+                    let last = (first + count as usize).min(list.len());
+                    let mut item_data = Vec::new();
+                    for oid in &list[first..last] {
+                        write_object_identifier(&mut item_data, *oid).unwrap();
+                    }
+                    let response = ReadRangeResponse {
+                        object_identifier: object,
+                        property_identifier: PropertyIdentifier::ObjectList,
+                        property_array_index: None,
+                        result_flags: ResultFlags {
+                            first_item: first == 0,
+                            last_item: last == list.len(),
+                            more_items: last < list.len(),
+                        },
+                        item_count: (last - first) as u32,
+                        item_data,
+                        first_sequence_number: None,
+                    };
+                    let mut data = Vec::new();
+                    response.encode(&mut data).expect("encode response");
+                    complex_ack(invoke_id, ConfirmedServiceChoice::ReadRange, data)
+                }
+                other => panic!("unexpected request {other:?}"),
+            },
+        );
+
+    let client = test_client();
+    let read = client
+        .read_object_list_chunked(addr, LARGE_DEVICE)
+        .expect("read object list");
+    assert_eq!(read, objects[1..]);
+}
+
+#[test]
+fn read_object_list_chunked_falls_back_to_indexed_reads() {
+    let objects = large_object_list();
+    let list = objects.clone();
+    let mut batches = 0;
+    let addr =
+        spawn_device_serving(
+            move |invoke_id, service_choice, service_data| match service_choice {
+                ConfirmedServiceChoice::ReadProperty => {
+                    object_list_length_ack(invoke_id, service_data, &list)
+                }
+                ConfirmedServiceChoice::ReadRange => Apdu::Reject {
+                    invoke_id,
+                    reject_reason: RejectReason::UnrecognizedService,
+                },
+                ConfirmedServiceChoice::ReadPropertyMultiple => {
+                    batches += 1;
+                    assert!(batches <= 5, "a 1476 byte APDU holds 122 indexed entries");
+                    let request =
+                        ReadPropertyMultipleRequest::decode(service_data).expect("decode RPM");
+                    let spec = &request.read_access_specifications[0];
+                    let results = spec
+                        .property_references
+                        .iter()
+                        .map(|reference| {
+                            let index = reference.property_array_index.expect("array index");
+                            PropertyResult {
+                                property_identifier: PropertyIdentifier::ObjectList,
+                                array_index: Some(index),
+                                value: PropertyResultValue::Value(vec![
+                                    PropertyValue::ObjectIdentifier(list[index as usize - 1]),
+                                ]),
+                            }
+                        })
+                        .collect();
+                    let response = ReadPropertyMultipleResponse::new(vec![ReadAccessResult::new(
+                        spec.object_identifier,
+                        results,
+                    )]);
+                    let mut data = Vec::new();
+                    response.encode(&mut data).expect("encode response");
+                    complex_ack(
+                        invoke_id,
+                        ConfirmedServiceChoice::ReadPropertyMultiple,
+                        data,
+                    )
+                }
+                other => panic!("unexpected request {other:?}"),
+            },
+        );
+
+    let client = test_client();
+    let read = client
+        .read_object_list_chunked(addr, LARGE_DEVICE)
+        .expect("read object list");
+    assert_eq!(read, objects[1..]);
+}
+
+#[test]
+fn timeout_is_reported_once_retries_are_exhausted() {
+    let silent = UdpSocket::bind("127.0.0.1:0").expect("bind silent device");
+    let addr = silent.local_addr().unwrap();
+
+    let client = BacnetClient::builder()
+        .local_addr("127.0.0.1")
+        .timeout(Duration::from_millis(100))
+        .retries(1)
+        .build()
+        .expect("build client");
+
+    let object = ObjectIdentifier::new(ObjectType::AnalogValue, 1);
+    let err = client
+        .read_property(addr, object, PropertyIdentifier::PresentValue)
+        .expect_err("nobody answers");
+    assert!(matches!(err, ClientError::Timeout), "got {err:?}");
+
+    // The original request and one resend.
+    silent.set_nonblocking(true).unwrap();
+    let mut buf = [0u8; 1500];
+    let mut received = 0;
+    while silent.recv_from(&mut buf).is_ok() {
+        received += 1;
+    }
+    assert_eq!(received, 2);
+}
@@ -15,21 +15,101 @@ use std::thread;
 use std::time::Duration;
 
 use bacnet_rs::{
-    client::BacnetClient,
+    app::{Apdu, ApplicationError},
+    client::{BacnetClient, ClientError},
     datalink::bip::{BvlcFunction, BvlcHeader},
     network::Npdu,
-    object::{ObjectIdentifier, ObjectType, Segmentation},
-    service::{IAmRequest, UnconfirmedServiceChoice},
+    object::{ObjectIdentifier, ObjectType, PropertyIdentifier, Segmentation},
+    property::PropertyValue,
+    service::{
+        IAmRequest, PropertyResult, PropertyResultValue, ReadAccessResult,
+        ReadPropertyMultipleResponse, UnconfirmedServiceChoice,
+    },
 };
 
 const DEVICE_ID: u32 = 4711;
 const VENDOR_ID: u16 = 260; // BACnet Stack at SourceForge
 
+/// Wrap an NPDU payload in a BVLC Original-Unicast-NPDU header.
+fn wrap(payload: &[u8]) -> Vec<u8> {
+    let mut message = Npdu::new().encode();
+    message.extend_from_slice(payload);
+
+    let header = BvlcHeader::new(BvlcFunction::OriginalUnicastNpdu, 4 + message.len() as u16);
+    let mut frame = header.encode();
+    frame.extend_from_slice(&message);
+    frame
+}
+
+/// Build the ReadPropertyMultiple ComplexAck for the device's Object_List and
+/// Object_Name.
+fn build_object_list_ack(request: &[u8], object_name: &str) -> Vec<u8> {
+    let (_npdu, npdu_len) = Npdu::decode(&request[4..]).expect("decode NPDU");
+    let Apdu::ConfirmedRequest {
+        invoke_id,
+        service_choice,
+        ..
+    } = Apdu::decode(&request[4 + npdu_len..]).expect("decode APDU")
+    else {
+        panic!("expected a confirmed request");
+    };
+
+    let device = ObjectIdentifier::new(ObjectType::Device, DEVICE_ID);
+    let response = ReadPropertyMultipleResponse::new(vec![ReadAccessResult::new(
+        device,
+        vec![
+            PropertyResult {
+                property_identifier: PropertyIdentifier::ObjectList,
+                array_index: None,
+                value: PropertyResultValue::Value(vec![
+                    PropertyValue::ObjectIdentifier(device),
+                    PropertyValue::ObjectIdentifier(ObjectIdentifier::new(
+                        ObjectType::AnalogInput,
+                        1,
+                    )),
+                ]),
+            },
+            PropertyResult {
+                property_identifier: PropertyIdentifier::ObjectName,
+                array_index: None,
+                value: PropertyResultValue::Value(vec![PropertyValue::CharacterString(
+                    object_name.to_string(),
+                )]),
+            },
+        ],
+    )]);
+    let mut service_data = Vec::new();
+    response.encode(&mut service_data).expect("encode response");
+
+    wrap(
+        &Apdu::ComplexAck {
+            segmented: false,
+            more_follows: false,
+            invoke_id,
+            sequence_number: None,
+            proposed_window_size: None,
+            service_choice,
+            service_data,
+        }
+        .encode(),
+    )
+}
+
 /// Build the I-Am datalink frame the fake device replies with.
 fn build_iam_frame() -> Vec<u8> {
+    build_iam_frame_for(DEVICE_ID)
+}
+
+/// Build an I-Am datalink frame for the given device instance.
+fn build_iam_frame_for(device_id: u32) -> Vec<u8> {
+    build_iam_frame_with(device_id, 1476)
+}
+
+/// Build an I-Am datalink frame announcing the given max-APDU.
+fn build_iam_frame_with(device_id: u32, max_apdu: u32) -> Vec<u8> {
     let iam = IAmRequest::new(
-        ObjectIdentifier::new(ObjectType::Device, DEVICE_ID),
-        1476,
+        ObjectIdentifier::new(ObjectType::Device, device_id),
+        max_apdu,
         Segmentation::Both,
         VENDOR_ID,
     );
@@ -84,6 +164,146 @@ fn discover_device_parses_iam_over_loopback() {
     responder.join().unwrap();
 }
 
+#[test]
+fn discovered_device_is_found_by_name() {
+    let device = UdpSocket::bind("127.0.0.1:0").expect("bind device");
+    device
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    let device_addr: SocketAddr = device.local_addr().unwrap();
+
+    // Answer the Who-Is with an I-Am, then the Object_List read.
+    let responder = thread::spawn(move || {
+        let mut buf = [0u8; 1500];
+        let (_len, src) = device.recv_from(&mut buf).expect("receive Who-Is");
+        device.send_to(&build_iam_frame(), src).expect("send I-Am");
+
+        let (len, src) = device.recv_from(&mut buf).expect("receive RPM");
+        let frame = build_object_list_ack(&buf[..len], "Main Plant");
+        device.send_to(&frame, src).expect("send ComplexAck");
+    });
+
+    let client = BacnetClient::builder()
+        .local_addr("127.0.0.1")
+        .timeout(Duration::from_secs(3))
+        .build()
+        .expect("build client");
+
+    client
+        .discover_device(device_addr)
+        .expect("discovery should succeed");
+    let cached = client.device_cache();
+    assert_eq!(
+        cached.find_by_instance(DEVICE_ID).unwrap().address,
+        device_addr
+    );
+    assert!(cached.find_by_name("Main Plant").is_none());
+
+    let objects = client
+        .read_object_list(device_addr, DEVICE_ID)
+        .expect("read object list");
+    assert_eq!(objects, [ObjectIdentifier::new(ObjectType::AnalogInput, 1)]);
+
+    let cache = client.device_cache();
+    let found = cache.find_by_name("Main Plant").expect("device by name");
+    assert_eq!(found.device_id, DEVICE_ID);
+    assert_eq!(found.address, device_addr);
+    assert_eq!(found.vendor_id, VENDOR_ID);
+    assert_eq!(cache.all().count(), 1);
+
+    responder.join().unwrap();
+}
+
+#[test]
+fn who_is_collects_every_i_am_in_range() {
+    let network = UdpSocket::bind("127.0.0.1:0").expect("bind network");
+    network
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    let network_addr = network.local_addr().unwrap();
+
+    // Three devices in range, one of them answering twice, and one device
+    // outside the range that answers anyway.
+    let responder = thread::spawn(move || {
+        let mut buf = [0u8; 1500];
+        let (_len, src) = network.recv_from(&mut buf).expect("receive Who-Is");
+        for device_id in [100, 150, 100, 200, 300] {
+            network
+                .send_to(&build_iam_frame_for(device_id), src)
+                .expect("send I-Am");
+        }
+    });
+
+    let client = BacnetClient::builder()
+        .local_addr("127.0.0.1")
+        .timeout(Duration::from_millis(500))
+        .build()
+        .expect("build client");
+
+    let devices = client
+        .who_is_to(network_addr, Some(100), Some(200))
+        .expect("Who-Is should succeed");
+    let ids: Vec<u32> = devices.iter().map(|device| device.device_id).collect();
+    assert_eq!(ids, [100, 150, 200]);
+    assert_eq!(client.device_cache().len(), 3);
+
+    responder.join().unwrap();
+}
+
+#[test]
+fn requests_are_limited_to_the_discovered_max_apdu() {
+    let device = UdpSocket::bind("127.0.0.1:0").expect("bind device");
+    device
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    let device_addr: SocketAddr = device.local_addr().unwrap();
+
+    let client = BacnetClient::builder()
+        .local_addr("127.0.0.1")
+        .timeout(Duration::from_secs(3))
+        .build()
+        .expect("build client");
+
+    // Answer the Who-Is with an I-Am accepting at most 206 octets.
+    let responder = thread::spawn(move || {
+        let mut buf = [0u8; 1500];
+        let (_len, src) = device.recv_from(&mut buf).expect("receive Who-Is");
+        device
+            .send_to(&build_iam_frame_with(DEVICE_ID, 206), src)
+            .expect("send I-Am");
+        device
+    });
+    let info = client
+        .discover_device(device_addr)
+        .expect("discovery should succeed");
+    assert_eq!(info.max_apdu, 206);
+    let device = responder.join().unwrap();
+
+    let err = client
+        .write_property(
+            device_addr,
+            ObjectIdentifier::new(ObjectType::File, 1),
+            PropertyIdentifier::PresentValue,
+            &PropertyValue::OctetString(vec![0xAA; 300]),
+            None,
+        )
+        .expect_err("a 300-byte value does not fit in 206 octets");
+    assert!(
+        matches!(
+            err,
+            ClientError::Application(ApplicationError::MaxApduLengthExceeded)
+        ),
+        "got {err:?}"
+    );
+
+    // Nothing went out on the wire.
+    device
+        .set_read_timeout(Some(Duration::from_millis(200)))
+        .unwrap();
+    let mut buf = [0u8; 1500];
+    assert!(device.recv_from(&mut buf).is_err());
+}
+
 #[test]
 fn discover_device_times_out_when_no_responder() {
     // A bound-but-silent port: nothing ever replies, so discovery must time out